@@ -1,4 +1,7 @@
-//! AST types
+//! Untyped schema AST.
+//!
+//! This is the output of the parser before any name or type resolution.  Use
+//! [`crate::parse_ast`] to obtain it for a single schema source.
 
 use std::{
     collections::HashMap,
@@ -9,7 +12,7 @@ use std::{
 use thiserror::Error;
 
 use crate::{
-    Identifier, SrcPos, TaggedToktr,
+    Identifier, SrcPos, SrcSpan, TaggedToktr,
     gobbler::Gobbler,
     token_tree::SrcToktr,
     tysys::{Binop, ConstValue},
@@ -91,7 +94,7 @@ impl Module {
 
 /// A definition within a module, in the order it was listed.
 #[derive(Clone, Debug)]
-pub(crate) enum ModuleEntry {
+pub enum ModuleEntry {
     /// An assignment with some name being assigned to a value.  This could be
     /// declaring a const or declaring a type alias.
     Assignment(AssignEntry),
@@ -101,38 +104,99 @@ pub(crate) enum ModuleEntry {
 }
 
 impl ModuleEntry {
-    pub(crate) fn name(&self) -> &Identifier {
+    /// Gets the name being defined by the entry.
+    pub fn name(&self) -> &Identifier {
         match self {
             ModuleEntry::Assignment(d) => d.name(),
             ModuleEntry::Class(d) => d.name(),
         }
     }
+
+    /// Gets the source span of the entry.
+    pub fn span(&self) -> SrcSpan {
+        match self {
+            ModuleEntry::Assignment(d) => d.span(),
+            ModuleEntry::Class(d) => d.span(),
+        }
+    }
+
+    fn set_span(&mut self, span: SrcSpan) {
+        match self {
+            ModuleEntry::Assignment(d) => d.span = span,
+            ModuleEntry::Class(d) => d.span = span,
+        }
+    }
 }
 
 /// A const definition.
 #[derive(Clone, Debug)]
-pub(crate) struct AssignEntry {
+pub struct AssignEntry {
     name: Identifier,
     value: AssignExpr,
+    span: SrcSpan,
 }
 
 impl AssignEntry {
     pub(crate) fn new(name: Identifier, value: AssignExpr) -> Self {
-        Self { name, value }
+        Self {
+            name,
+            value,
+            span: SrcSpan::new(SrcPos::dummy(), SrcPos::dummy()),
+        }
     }
 
-    pub(crate) fn name(&self) -> &Identifier {
+    /// Gets the name being assigned.
+    pub fn name(&self) -> &Identifier {
         &self.name
     }
 
-    pub(crate) fn value(&self) -> &AssignExpr {
+    /// Gets the assigned expression.
+    pub fn value(&self) -> &AssignExpr {
         &self.value
     }
+
+    /// Gets the source span of the assignment.
+    pub fn span(&self) -> SrcSpan {
+        self.span
+    }
+}
+
+/// An `import` or `from ... import` line, as written.
+#[derive(Clone, Debug)]
+pub struct ImportEntry {
+    module_path: PathBuf,
+    alias: Option<Identifier>,
+    names: Vec<Identifier>,
+    span: SrcSpan,
+}
+
+impl ImportEntry {
+    /// Gets the path of the imported module as written, relative to the
+    /// importing schema, with a `..` component for each parent step.
+    pub fn module_path(&self) -> &PathBuf {
+        &self.module_path
+    }
+
+    /// Gets the `as` alias the module is bound to, if any.
+    pub fn alias(&self) -> Option<&Identifier> {
+        self.alias.as_ref()
+    }
+
+    /// Gets the names listed by a `from ... import` line, or nothing for a
+    /// plain `import`.
+    pub fn names(&self) -> &[Identifier] {
+        &self.names
+    }
+
+    /// Gets the source span of the import line.
+    pub fn span(&self) -> SrcSpan {
+        self.span
+    }
 }
 
 /// An expression that we can assign to a name.
 #[derive(Clone, Debug)]
-pub(crate) enum AssignExpr {
+pub enum AssignExpr {
     /// A name.
     ///
     /// This could be another const name or a type expression.
@@ -154,19 +218,30 @@ pub(crate) enum AssignExpr {
 
     /// A binary operation with a named operand (e.g., MAX_LEN + 1)
     SymbolicBinop(Binop, Identifier, u64),
+
+    /// A constant array (e.g., `[0x00, 0x01, BASE + 1]`), whose elements are
+    /// integer expressions.
+    Array(Vec<AssignExpr>),
+
+    /// A byte string (e.g., `b"\x07\x00\x00\x00"` or `"beacon"`).
+    Bytes(Vec<u8>),
+
+    /// A builtin function applied to a name (e.g., `len(DOMAIN_BEACON)`).
+    Call(Identifier, Identifier),
 }
 
 /// A class definition.
 ///
 /// Classes must always have parent types.
 #[derive(Clone, Debug)]
-pub(crate) struct ClassDefEntry {
+pub struct ClassDefEntry {
     name: Identifier,
     parent_ty: TyExprSpec,
     doc: Option<String>,
     doc_comment: Option<String>,
     pragmas: Vec<String>,
     fields: Vec<FieldDef>,
+    span: SrcSpan,
 }
 
 impl ClassDefEntry {
@@ -183,26 +258,32 @@ impl ClassDefEntry {
             doc_comment: None,
             pragmas: Vec::new(),
             fields,
+            span: SrcSpan::new(SrcPos::dummy(), SrcPos::dummy()),
         }
     }
 
-    pub(crate) fn name(&self) -> &Identifier {
+    /// Gets the name of the class.
+    pub fn name(&self) -> &Identifier {
         &self.name
     }
 
-    pub(crate) fn parent_ty(&self) -> &TyExprSpec {
+    /// Gets the parent type expression, e.g. `Container` or `StableContainer[N]`.
+    pub fn parent_ty(&self) -> &TyExprSpec {
         &self.parent_ty
     }
 
-    pub(crate) fn doc(&self) -> Option<&str> {
+    /// Gets the `"""` docstring from the class body, if any.
+    pub fn doc(&self) -> Option<&str> {
         self.doc.as_ref().map(|s| s.as_ref())
     }
 
-    pub(crate) fn doc_comment(&self) -> Option<&str> {
+    /// Gets the `###` doc comment preceding the class, if any.
+    pub fn doc_comment(&self) -> Option<&str> {
         self.doc_comment.as_ref().map(|s| s.as_ref())
     }
 
-    pub(crate) fn pragmas(&self) -> &[String] {
+    /// Gets the `#~#` pragma lines preceding the class.
+    pub fn pragmas(&self) -> &[String] {
         &self.pragmas
     }
 
@@ -214,14 +295,21 @@ impl ClassDefEntry {
         self.pragmas = pragmas;
     }
 
-    pub(crate) fn fields(&self) -> &[FieldDef] {
+    /// Gets the fields in declaration order.
+    pub fn fields(&self) -> &[FieldDef] {
         &self.fields
     }
+
+    /// Gets the source span of the class, from the `class` keyword to the
+    /// last token of its body.
+    pub fn span(&self) -> SrcSpan {
+        self.span
+    }
 }
 
 /// A field definition within a class.
 #[derive(Clone, Debug)]
-pub(crate) struct FieldDef {
+pub struct FieldDef {
     name: Identifier,
     ty: TyExprSpec,
     doc_comment: Option<String>,
@@ -238,19 +326,23 @@ impl FieldDef {
         }
     }
 
-    pub(crate) fn name(&self) -> &Identifier {
+    /// Gets the name of the field.
+    pub fn name(&self) -> &Identifier {
         &self.name
     }
 
-    pub(crate) fn ty(&self) -> &TyExprSpec {
+    /// Gets the type expression of the field.
+    pub fn ty(&self) -> &TyExprSpec {
         &self.ty
     }
 
-    pub(crate) fn doc_comment(&self) -> Option<&str> {
+    /// Gets the `###` doc comment preceding the field, if any.
+    pub fn doc_comment(&self) -> Option<&str> {
         self.doc_comment.as_ref().map(|s| s.as_ref())
     }
 
-    pub(crate) fn pragmas(&self) -> &[String] {
+    /// Gets the `#~#` pragma lines preceding the field.
+    pub fn pragmas(&self) -> &[String] {
         &self.pragmas
     }
 
@@ -267,7 +359,7 @@ impl FieldDef {
 ///
 /// This needs to be further resolved to figure out ambiguous identifiers.
 #[derive(Clone, Debug)]
-pub(crate) enum TyExprSpec {
+pub enum TyExprSpec {
     /// This is an imported type.
     Imported(ImportedTySpec),
 
@@ -286,7 +378,12 @@ pub(crate) enum TyExprSpec {
 }
 
 impl TyExprSpec {
-    pub(crate) fn base_name(&self) -> &Identifier {
+    /// Gets the base name of the type expression.
+    ///
+    /// # Panics
+    ///
+    /// If the type expression is [`TyExprSpec::None`].
+    pub fn base_name(&self) -> &Identifier {
         match self {
             TyExprSpec::Simple(name) => name,
             TyExprSpec::Complex(spec) => &spec.base_name,
@@ -308,6 +405,7 @@ pub struct ImportedTySpec {
 }
 
 impl ImportedTySpec {
+    /// Creates a new imported type spec from the module path and type name.
     pub fn new(module_path: PathBuf, base_name: Identifier) -> Self {
         let module_name = module_path
             .to_str()
@@ -323,18 +421,22 @@ impl ImportedTySpec {
         }
     }
 
+    /// Gets the resolved path of the module the type is imported from.
     pub fn module_path(&self) -> &PathBuf {
         &self.module_path
     }
 
+    /// Gets the last component of the module path.
     pub fn module_name(&self) -> &Identifier {
         &self.module_name
     }
 
+    /// Gets the name of the type within its module.
     pub fn base_name(&self) -> &Identifier {
         &self.base_name
     }
 
+    /// Gets the dotted module path and type name.
     pub fn full_name(&self) -> Identifier {
         Identifier(
             self.module_path
@@ -355,6 +457,7 @@ pub struct ImportedComplexTySpec {
 }
 
 impl ImportedComplexTySpec {
+    /// Creates a new imported type spec with type arguments.
     pub fn new(module_path: PathBuf, base_name: Identifier, args: Vec<TyArgSpec>) -> Self {
         Self {
             imported: ImportedTySpec::new(module_path, base_name),
@@ -362,22 +465,27 @@ impl ImportedComplexTySpec {
         }
     }
 
+    /// Gets the resolved path of the module the type is imported from.
     pub fn module_path(&self) -> &PathBuf {
         self.imported.module_path()
     }
 
+    /// Gets the last component of the module path.
     pub fn module_name(&self) -> &Identifier {
         self.imported.module_name()
     }
 
+    /// Gets the name of the type within its module.
     pub fn base_name(&self) -> &Identifier {
         self.imported.base_name()
     }
 
+    /// Gets the dotted module path and type name.
     pub fn full_name(&self) -> Identifier {
         self.imported.full_name()
     }
 
+    /// Gets the type arguments.
     pub fn args(&self) -> &[TyArgSpec] {
         &self.args
     }
@@ -393,14 +501,17 @@ pub struct ComplexTySpec {
 }
 
 impl ComplexTySpec {
+    /// Creates a new generic type spec.
     pub fn new(base_name: Identifier, args: Vec<TyArgSpec>) -> Self {
         Self { base_name, args }
     }
 
+    /// Gets the name of the generic type.
     pub fn base_name(&self) -> &Identifier {
         &self.base_name
     }
 
+    /// Gets the type arguments.
     pub fn args(&self) -> &[TyArgSpec] {
         &self.args
     }
@@ -476,6 +587,9 @@ pub(crate) struct ModuleManager {
     import_order: Vec<PathBuf>,
     /// External modules that can be imported.
     external_modules: Vec<String>,
+    /// The import lines seen so far, when imports are collected as written
+    /// instead of being resolved and loaded.
+    unresolved_imports: Option<Vec<ImportEntry>>,
 }
 
 impl ModuleManager {
@@ -485,9 +599,24 @@ impl ModuleManager {
             modules: Modules::new(),
             import_order: Vec::new(),
             external_modules: external_modules.iter().map(|s| s.to_string()).collect(),
+            unresolved_imports: None,
         }
     }
 
+    /// Creates a module manager that records import lines as written without
+    /// resolving them or loading the modules they name.
+    pub(crate) fn new_unresolved() -> Self {
+        Self {
+            unresolved_imports: Some(Vec::new()),
+            ..Self::new(&[])
+        }
+    }
+
+    /// Takes the import lines recorded by an unresolved manager.
+    pub(crate) fn take_unresolved_imports(&mut self) -> Vec<ImportEntry> {
+        self.unresolved_imports.take().unwrap_or_default()
+    }
+
     /// Adds a module to the front of the import order.
     pub(crate) fn add_module_to_front<P: AsRef<Path>>(&mut self, path: P) -> bool {
         if self.modules.contains_key(path.as_ref()) {
@@ -591,22 +720,38 @@ pub(crate) fn parse_module_from_toktrs<P: AsRef<Path>>(
                 )?;
             }
 
+            // Lines that start with "from" import items from another module.
+            TaggedToktr::From(_) => {
+                consecutive_newlines = 0;
+                comment_buffer.clear(); // Clear comments before imports
+                let entries = parse_from_import(&mut gob, path, module_manager, entry_point_files)?;
+                module_manager
+                    .get_module_mut(path)
+                    .unwrap()
+                    .mut_entries()
+                    .extend(entries);
+            }
+
             // Lines that start with identifiers are probably assignments.
             TaggedToktr::Identifier(_, _) => {
                 consecutive_newlines = 0;
                 comment_buffer.clear(); // Clear comments before assignments
+                let start = gob.at();
                 let cd = parse_assignment(&mut gob, &import_map)?;
+                let mut entry = ModuleEntry::Assignment(cd);
+                entry.set_span(toktrs_span(&toktrs[start..gob.at()]));
                 module_manager
                     .get_module_mut(path)
                     .unwrap()
                     .mut_entries()
-                    .push(ModuleEntry::Assignment(cd));
+                    .push(entry);
             }
 
             // Lines that start with "class" are always classes.
             TaggedToktr::Class(_) => {
                 consecutive_newlines = 0;
                 // Comments should have been collected into comment_buffer
+                let start = gob.at();
                 let mut cd = parse_class(&mut gob, &import_map)?;
                 // Attach collected comments to the class
                 if let Some(doc) = comment_buffer.take_doc_comment() {
@@ -616,11 +761,13 @@ pub(crate) fn parse_module_from_toktrs<P: AsRef<Path>>(
                 if !pragmas.is_empty() {
                     cd.set_pragmas(pragmas);
                 }
+                let mut entry = ModuleEntry::Class(cd);
+                entry.set_span(toktrs_span(&toktrs[start..gob.at()]));
                 module_manager
                     .get_module_mut(path)
                     .unwrap()
                     .mut_entries()
-                    .push(ModuleEntry::Class(cd));
+                    .push(entry);
             }
 
             // Standalone docstrings are not allowed at module level
@@ -714,6 +861,19 @@ fn parse_assign_expr(
         // Simple integer expression.
         [TaggedToktr::IntegerLiteral(_, v)] => AssignExpr::Value(ConstValue::Int(*v)),
 
+        // Byte string.
+        [TaggedToktr::BytesLiteral(_, bytes)] => AssignExpr::Bytes(bytes.clone()),
+
+        // Builtin function call, like `len(DOMAIN_BEACON)`.
+        [
+            TaggedToktr::Identifier(_, func),
+            TaggedToktr::ParenBlock(sp, arg_toks),
+        ] => match arg_toks.children() {
+            [TaggedToktr::Identifier(_, arg)] => AssignExpr::Call(func.clone(), arg.clone()),
+            [t, ..] => return Err(ParseError::UnexpectedToken(*t.tag())),
+            [] => return Err(ParseError::UnexpectedToken(*sp)),
+        },
+
         // This is a shl value.
         [
             TaggedToktr::IntegerLiteral(_, v),
@@ -764,6 +924,29 @@ fn parse_assign_expr(
             AssignExpr::Imported(ImportedTySpec::new(module_path.clone(), ident.clone()))
         }
 
+        // Constant array.
+        [TaggedToktr::BracketBlock(_, elem_toks)] => {
+            let mut gob = Gobbler::new(elem_toks.children());
+            let mut elems = Vec::new();
+            while gob.has_entry() {
+                let elem_slice = gob.gobble_slice_up_to_or_end(is_toktr_comma);
+                let elem = match parse_assign_expr(elem_slice, import_map)? {
+                    elem @ (AssignExpr::Name(_)
+                    | AssignExpr::Value(_)
+                    | AssignExpr::SymbolicBinop(_, _, _)
+                    | AssignExpr::Call(_, _)) => elem,
+                    _ => return Err(ParseError::UnexpectedToken(*elem_slice[0].tag())),
+                };
+                elems.push(elem);
+
+                // Skip over the `,`, if any.
+                if gob.has_entry() {
+                    gob.gobble_one();
+                }
+            }
+            AssignExpr::Array(elems)
+        }
+
         _ => return Err(ParseError::UnexpectedEnd),
     };
 
@@ -1189,175 +1372,310 @@ fn parse_import<P: AsRef<Path>>(
 ) -> Result<(), ParseError> {
     use TaggedToktr::*;
 
-    let path = path.as_ref();
     let sp = *gob.get_expect().tag();
 
     match gob.view() {
         [Import(_), ..] => {
             gob.gobble_one();
             let path_tokens = gob.gobble_slice_up_to_or_end(is_toktr_newline);
+            let (final_path, import_alias) =
+                load_import_module(path_tokens, path, module_manager, entry_point_files)?;
 
-            let mut path = path
-                .parent()
-                .expect("import: path must have a parent")
-                .to_path_buf();
-            let import_alias;
-
-            // Parse the path of import module
-            let mut path_gob = Gobbler::new(path_tokens);
-            let mut is_first_tok = true;
-            let mut is_external = false;
-            loop {
-                match path_gob.view() {
-                    [Identifier(_, name), Dot(_), ..] => {
-                        if is_first_tok {
-                            is_external = module_manager.external_modules.contains(&name.0);
-                            if is_external {
-                                path = PathBuf::from(&name.0);
-                            } else {
-                                path = path.join(&name.0);
-                            }
-                        } else {
-                            path = path.join(&name.0);
-                        }
-                        path_gob.gobble_exact(2);
-                    }
-                    [Dot(_), Dot(_), ..] => {
-                        path = path
-                            .parent()
-                            .expect("import: path must have a parent")
-                            .to_path_buf();
-                        path_gob.gobble_exact(2);
-                    }
-                    [Identifier(_, name), As(_), Identifier(_, alias)] => {
-                        if is_first_tok {
-                            is_external = module_manager.external_modules.contains(&name.0);
-                            if is_external {
-                                path = PathBuf::from(&name.0);
-                            } else {
-                                path = path.join(&name.0);
-                            }
-                        } else {
-                            path = path.join(&name.0);
-                        }
-                        import_alias = alias.clone();
-                        path_gob.gobble_exact(3);
-                        break;
-                    }
-                    [Identifier(_, name)] => {
-                        if is_first_tok {
-                            is_external = module_manager.external_modules.contains(&name.0);
-                            if is_external {
-                                path = PathBuf::from(&name.0);
-                            } else {
-                                path = path.join(&name.0);
-                            }
-                        } else {
-                            path = path.join(&name.0);
-                        }
-                        import_alias = name.clone();
-                        path_gob.gobble_one();
-                        break;
-                    }
-                    [t, ..] => return Err(ParseError::UnexpectedToken(*t.tag())),
-                    _ => return Err(ParseError::UnexpectedEnd),
-                }
-                is_first_tok = false;
+            if let Some(imports) = &mut module_manager.unresolved_imports {
+                let alias = match path_tokens {
+                    [.., As(_), Identifier(_, alias)] => Some(alias.clone()),
+                    _ => None,
+                };
+                imports.push(ImportEntry {
+                    module_path: final_path.clone(),
+                    alias,
+                    names: Vec::new(),
+                    span: SrcSpan::new(sp, toktrs_span(path_tokens).end()),
+                });
             }
 
-            // Check if this is an entry point first, then check filesystem
-            let ssz_path = path.with_extension("ssz");
-            // Normalize path for comparison (remove extension, as entry points are stored without
-            // .ssz)
-            let path_normalized = path.with_extension("");
-            let (final_path, has_schema, file_content_opt) = if let Some(entry_files) =
-                entry_point_files
-            {
-                // Check if the resolved path matches any entry point
-                // Entry points may be stored with or without .ssz extension, so check both
-                // Use get() for O(1) lookup instead of iterating (more efficient and deterministic)
-                // Track which key matched so we use the same path format
-                let (matched_path, matching_entry) =
-                    if let Some(content) = entry_files.get(&path_normalized) {
-                        (path_normalized.clone(), Some(content))
-                    } else if let Some(content) = entry_files.get(&ssz_path) {
-                        (ssz_path.clone(), Some(content))
-                    } else {
-                        (path_normalized.clone(), None)
-                    };
-
-                if let Some(file_content) = matching_entry {
-                    // Use the matched path (preserving the extension format used by entry_files)
-                    (matched_path, true, Some(file_content.clone()))
-                } else if ssz_path.exists() {
-                    // Fall back to filesystem check
-                    (path.clone(), true, None)
-                } else if !is_external {
-                    // If .ssz file doesn't exist and not external, this is an existing Rust
-                    // module at crate root. Strip parent directories to
-                    // get just the module name (e.g., "ssz/ol" -> "ol")
-                    let module_name = path
-                        .file_name()
-                        .expect("module path should have a file name");
-                    let stripped_path = PathBuf::from(module_name);
-                    (stripped_path, false, None)
-                } else {
-                    // External module without .ssz file - keep full path
-                    (path.clone(), false, None)
-                }
-            } else if ssz_path.exists() {
-                (path.clone(), true, None)
-            } else if !is_external {
-                // If .ssz file doesn't exist and not external, this is an existing Rust module
-                // at crate root. Strip parent directories to get just the
-                // module name (e.g., "ssz/ol" -> "ol")
-                let module_name = path
-                    .file_name()
-                    .expect("module path should have a file name");
-                let stripped_path = PathBuf::from(module_name);
-                (stripped_path, false, None)
-            } else {
-                // External module without .ssz file - keep full path
-                (path.clone(), false, None)
-            };
-
             // Update import_map with the final resolved path
             // This ensures that when types reference this import, they use the correct path
             // (e.g., "state.ssz" instead of just "state")
             if import_map
-                .insert(import_alias.clone(), final_path.clone())
+                .insert(import_alias.clone(), final_path)
                 .is_some()
             {
                 panic!("import: duplicate import alias: {import_alias:?}");
             }
 
-            let add_module_result = module_manager.add_module(&final_path, is_external);
-            if !add_module_result || is_external {
-                return Ok(());
+            Ok(())
+        }
+
+        _ => Err(ParseError::MalformedBlock(sp)),
+    }
+}
+
+/// Parses `from <module> import A, B` statements.
+///
+/// The module is loaded the same way as for a plain import, and each listed
+/// name becomes an assignment re-exporting the item under the same name
+/// (`A = module.A`), so it resolves like any other imported alias or constant.
+fn parse_from_import<P: AsRef<Path>>(
+    gob: &mut Gobbler<'_, SrcToktr>,
+    path: P,
+    module_manager: &mut ModuleManager,
+    entry_point_files: Option<&HashMap<PathBuf, String>>,
+) -> Result<Vec<ModuleEntry>, ParseError> {
+    use TaggedToktr::*;
+
+    let sp = *gob.get_expect().tag();
+    let [From(_), ..] = gob.view() else {
+        return Err(ParseError::MalformedBlock(sp));
+    };
+    gob.gobble_one();
+
+    let line = gob.gobble_slice_up_to_or_end(is_toktr_newline);
+    let Some(import_at) = line.iter().position(|t| matches!(t, Import(_))) else {
+        return Err(ParseError::MalformedBlock(sp));
+    };
+    let (path_tokens, name_tokens) = (&line[..import_at], &line[import_at + 1..]);
+
+    // The names are bound directly, so the module itself can't be aliased.
+    if let Some(t) = path_tokens.iter().find(|t| matches!(t, As(_))) {
+        return Err(ParseError::UnexpectedToken(*t.tag()));
+    }
+
+    let mut names = Vec::new();
+    let mut name_gob = Gobbler::new(name_tokens);
+    loop {
+        match name_gob.view() {
+            [Identifier(sp, name), Comma(_), _, ..] => {
+                names.push((*sp, name.clone()));
+                name_gob.gobble_exact(2);
             }
+            [Identifier(sp, name)] => {
+                names.push((*sp, name.clone()));
+                break;
+            }
+            [t, ..] => return Err(ParseError::UnexpectedToken(*t.tag())),
+            [] => return Err(ParseError::UnexpectedEnd),
+        }
+    }
+
+    let (module_path, _) =
+        load_import_module(path_tokens, path, module_manager, entry_point_files)?;
+
+    // Unresolved, the line is returned as written rather than as the
+    // assignments it stands for.
+    if let Some(imports) = &mut module_manager.unresolved_imports {
+        imports.push(ImportEntry {
+            module_path,
+            alias: None,
+            names: names.into_iter().map(|(_, name)| name).collect(),
+            span: SrcSpan::new(sp, toktrs_span(line).end()),
+        });
+        return Ok(Vec::new());
+    }
+
+    Ok(names
+        .into_iter()
+        .map(|(sp, name)| {
+            let value =
+                AssignExpr::Imported(ImportedTySpec::new(module_path.clone(), name.clone()));
+            let mut entry = ModuleEntry::Assignment(AssignEntry::new(name, value));
+            entry.set_span(SrcSpan::new(sp, sp));
+            entry
+        })
+        .collect())
+}
+
+/// Resolves the module named by an import path, registering it with the
+/// module manager and parsing its schema file if it has one.
+///
+/// Returns the resolved module path along with the name the module is bound
+/// to (the trailing `as` alias, or the last path component).
+fn load_import_module<P: AsRef<Path>>(
+    path_tokens: &[SrcToktr],
+    path: P,
+    module_manager: &mut ModuleManager,
+    entry_point_files: Option<&HashMap<PathBuf, String>>,
+) -> Result<(PathBuf, Identifier), ParseError> {
+    use TaggedToktr::*;
 
-            // Parse .ssz file if it exists
-            if has_schema {
-                let file_content = if let Some(content) = file_content_opt {
-                    content
+    let path = path.as_ref();
+    let resolve = module_manager.unresolved_imports.is_none();
+    let mut path = path
+        .parent()
+        .expect("import: path must have a parent")
+        .to_path_buf();
+    // The path as written, relative to the importing schema.
+    let mut written = PathBuf::new();
+    let import_alias;
+
+    // Parse the path of import module
+    let mut path_gob = Gobbler::new(path_tokens);
+    let mut is_first_tok = true;
+    let mut is_external = false;
+    loop {
+        match path_gob.view() {
+            [Identifier(_, name), Dot(_), ..] => {
+                written.push(&name.0);
+                if is_first_tok {
+                    is_external = module_manager.external_modules.contains(&name.0);
+                    if is_external {
+                        path = PathBuf::from(&name.0);
+                    } else {
+                        path = path.join(&name.0);
+                    }
                 } else {
-                    std::fs::read_to_string(&ssz_path).expect("Failed to read import module file")
-                };
-                let chars = file_content.chars().collect::<Vec<_>>();
-                let toks = crate::token::parse_char_array_to_tokens(&chars)
-                    .expect("import: tokenize string");
-                let tt = crate::token_tree::parse_tokens_to_toktrs(&toks)
-                    .expect("import: treeize tokens");
-                parse_module_from_toktrs(&tt, &final_path, module_manager, entry_point_files)
-                    .expect("import: parse toktrs");
+                    path = path.join(&name.0);
+                }
+                path_gob.gobble_exact(2);
             }
+            [Dot(_), Dot(_), ..] => {
+                written.push("..");
+                if resolve {
+                    path = path
+                        .parent()
+                        .expect("import: path must have a parent")
+                        .to_path_buf();
+                }
+                path_gob.gobble_exact(2);
+            }
+            [Identifier(_, name), As(_), Identifier(_, alias)] => {
+                written.push(&name.0);
+                if is_first_tok {
+                    is_external = module_manager.external_modules.contains(&name.0);
+                    if is_external {
+                        path = PathBuf::from(&name.0);
+                    } else {
+                        path = path.join(&name.0);
+                    }
+                } else {
+                    path = path.join(&name.0);
+                }
+                import_alias = alias.clone();
+                path_gob.gobble_exact(3);
+                break;
+            }
+            [Identifier(_, name)] => {
+                written.push(&name.0);
+                if is_first_tok {
+                    is_external = module_manager.external_modules.contains(&name.0);
+                    if is_external {
+                        path = PathBuf::from(&name.0);
+                    } else {
+                        path = path.join(&name.0);
+                    }
+                } else {
+                    path = path.join(&name.0);
+                }
+                import_alias = name.clone();
+                path_gob.gobble_one();
+                break;
+            }
+            [t, ..] => return Err(ParseError::UnexpectedToken(*t.tag())),
+            _ => return Err(ParseError::UnexpectedEnd),
+        }
+        is_first_tok = false;
+    }
+
+    if !resolve {
+        return Ok((written, import_alias));
+    }
+
+    // Check if this is an entry point first, then check filesystem
+    let ssz_path = path.with_extension("ssz");
+    // Normalize path for comparison (remove extension, as entry points are stored without
+    // .ssz)
+    let path_normalized = path.with_extension("");
+    let (final_path, has_schema, file_content_opt) = if let Some(entry_files) = entry_point_files {
+        // Check if the resolved path matches any entry point
+        // Entry points may be stored with or without .ssz extension, so check both
+        // Use get() for O(1) lookup instead of iterating (more efficient and deterministic)
+        // Track which key matched so we use the same path format
+        let (matched_path, matching_entry) =
+            if let Some(content) = entry_files.get(&path_normalized) {
+                (path_normalized.clone(), Some(content))
+            } else if let Some(content) = entry_files.get(&ssz_path) {
+                (ssz_path.clone(), Some(content))
+            } else {
+                (path_normalized.clone(), None)
+            };
 
-            // Return the import module
-            Ok(())
+        if let Some(file_content) = matching_entry {
+            // Use the matched path (preserving the extension format used by entry_files)
+            (matched_path, true, Some(file_content.clone()))
+        } else if ssz_path.exists() {
+            // Fall back to filesystem check
+            (path.clone(), true, None)
+        } else if !is_external {
+            // If .ssz file doesn't exist and not external, this is an existing Rust
+            // module at crate root. Strip parent directories to
+            // get just the module name (e.g., "ssz/ol" -> "ol")
+            let module_name = path
+                .file_name()
+                .expect("module path should have a file name");
+            let stripped_path = PathBuf::from(module_name);
+            (stripped_path, false, None)
+        } else {
+            // External module without .ssz file - keep full path
+            (path.clone(), false, None)
         }
+    } else if ssz_path.exists() {
+        (path.clone(), true, None)
+    } else if !is_external {
+        // If .ssz file doesn't exist and not external, this is an existing Rust module
+        // at crate root. Strip parent directories to get just the
+        // module name (e.g., "ssz/ol" -> "ol")
+        let module_name = path
+            .file_name()
+            .expect("module path should have a file name");
+        let stripped_path = PathBuf::from(module_name);
+        (stripped_path, false, None)
+    } else {
+        // External module without .ssz file - keep full path
+        (path.clone(), false, None)
+    };
 
-        _ => Err(ParseError::MalformedBlock(sp)),
+    let add_module_result = module_manager.add_module(&final_path, is_external);
+    if !add_module_result || is_external {
+        return Ok((final_path, import_alias));
+    }
+
+    // Parse .ssz file if it exists
+    if has_schema {
+        let file_content = if let Some(content) = file_content_opt {
+            content
+        } else {
+            std::fs::read_to_string(&ssz_path).expect("Failed to read import module file")
+        };
+        let chars = file_content.chars().collect::<Vec<_>>();
+        let toks =
+            crate::token::parse_char_array_to_tokens(&chars).expect("import: tokenize string");
+        let tt = crate::token_tree::parse_tokens_to_toktrs(&toks).expect("import: treeize tokens");
+        parse_module_from_toktrs(&tt, &final_path, module_manager, entry_point_files)
+            .expect("import: parse toktrs");
     }
+
+    Ok((final_path, import_alias))
+}
+
+/// Computes the span covered by a run of token trees.
+///
+/// Tokens only record where they start, so the span ends at the start of the
+/// last (innermost) non-newline token.
+fn toktrs_span(toktrs: &[SrcToktr]) -> SrcSpan {
+    let start = toktrs
+        .first()
+        .map(|t| *t.tag())
+        .unwrap_or_else(SrcPos::dummy);
+    let mut end = start;
+    let mut cur = toktrs;
+    while let Some(last) = cur.iter().rev().find(|t| is_toktr_not_newline(t)) {
+        end = *last.tag();
+        match last.node_data() {
+            Some(data) => cur = data.children(),
+            None => break,
+        }
+    }
+    SrcSpan::new(start, end)
 }
 
 /// Utility function to clean up gobble calls.
@@ -1370,6 +1688,11 @@ fn is_toktr_eq<T>(t: &TaggedToktr<T>) -> bool {
     matches!(t, TaggedToktr::Eq(_))
 }
 
+/// Utility function to clean up gobble calls.
+fn is_toktr_comma<T>(t: &TaggedToktr<T>) -> bool {
+    matches!(t, TaggedToktr::Comma(_))
+}
+
 /// Utility function to clean up gobble calls.
 fn is_toktr_not_newline<T>(t: &TaggedToktr<T>) -> bool {
     !is_toktr_newline(t)
@@ -2,7 +2,7 @@
 
 use crate::{
     Identifier,
-    ty_resolver::{CtorArg, CtorSig, TypeData, TypeResolver},
+    ty_resolver::{CtorArg, CtorSig, IdentTarget, ResolverError, TypeData, TypeResolver},
     tysys::{Ty, TyExpr},
 };
 
@@ -10,7 +10,7 @@ use crate::{
 pub(crate) fn populate_builtin_types<'a>(resolv: &mut TypeResolver<'a>) {
     // Basic types.
     insert_ty(resolv, "boolean");
-    for i in [8, 16, 32, 64, 128, 256] {
+    for i in [8, 16, 24, 32, 40, 48, 56, 64, 128, 256] {
         insert_ty(resolv, &format!("uint{i}"));
     }
 
@@ -58,6 +58,28 @@ pub(crate) fn populate_builtin_types<'a>(resolv: &mut TypeResolver<'a>) {
     }
 }
 
+/// Evaluates a builtin function applied to a name, like `len(DOMAIN_BEACON)`.
+///
+/// The only builtin is `len`, the number of elements of a constant array or
+/// byte string.
+pub(crate) fn eval_builtin_call(
+    resolv: &TypeResolver<'_>,
+    func: &Identifier,
+    arg: &Identifier,
+) -> Result<u64, ResolverError> {
+    match func.0.as_str() {
+        "len" => match resolv.get_ident_referent(arg) {
+            Some(IdentTarget::ConstArray(len)) => Ok(*len as u64),
+            // Anything else that resolves, like an integer or a type, has no length.
+            _ => {
+                resolv.resolve_ident_with_args(arg, None)?;
+                Err(ResolverError::Unsized(arg.clone()))
+            }
+        },
+        _ => Err(ResolverError::UnknownFunction(func.clone())),
+    }
+}
+
 fn make_ident(s: &str) -> Identifier {
     Identifier::try_from(s).expect("builtins: parse ident")
 }
@@ -0,0 +1,703 @@
+//! Opt-in layout diagnostics for schema authors.
+//!
+//! A container is fixed-size only when every one of its fields is, so a
+//! single variable-size field moves the container to the offset-table
+//! encoding.  [`validate_schema`] reports, per container, which fields are
+//! responsible.
+//!
+//! It also estimates the in-memory layout of the generated Rust struct.  SSZ
+//! field order is semantic so codegen never reorders fields, but a struct
+//! emitted with `#[repr(C)]` keeps declaration order and may carry avoidable
+//! padding.
+//!
+//! Separately, [`validate_field_order`] compares classes against the field
+//! order of an upstream spec, given as a [`FieldOrderManifest`].
+
+use std::collections::HashMap;
+
+use thiserror::Error;
+
+use crate::{
+    Identifier, SrcSpan,
+    schema::{ClassDef, SszSchema},
+    tysys::{Ty, TyExpr},
+};
+
+/// SSZ size class of a type, as far as the schema alone can tell.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum SizeClass {
+    Fixed,
+    Variable,
+    /// Imported or external types, whose definition isn't in the schema.
+    Unknown,
+}
+
+impl SizeClass {
+    /// Size class of a composite made of parts of the given size classes.
+    fn combine(parts: impl IntoIterator<Item = SizeClass>) -> SizeClass {
+        parts
+            .into_iter()
+            .fold(SizeClass::Fixed, |acc, part| match (acc, part) {
+                (SizeClass::Variable, _) | (_, SizeClass::Variable) => SizeClass::Variable,
+                (SizeClass::Unknown, _) | (_, SizeClass::Unknown) => SizeClass::Unknown,
+                _ => SizeClass::Fixed,
+            })
+    }
+}
+
+/// Layout report for one `Container` class.
+#[derive(Clone, Debug)]
+pub struct ContainerLayout {
+    name: Identifier,
+    variable_fields: Vec<Identifier>,
+    unknown_fields: Vec<Identifier>,
+    padding_advisory: Option<PaddingAdvisory>,
+}
+
+impl ContainerLayout {
+    /// Name of the container.
+    pub fn name(&self) -> &Identifier {
+        &self.name
+    }
+
+    /// Whether the container is known to be fixed-size.
+    pub fn is_fixed(&self) -> bool {
+        self.variable_fields.is_empty() && self.unknown_fields.is_empty()
+    }
+
+    /// Fields that make the container variable-size, in declaration order.
+    pub fn variable_fields(&self) -> &[Identifier] {
+        &self.variable_fields
+    }
+
+    /// Fields of imported or external types, whose size the schema can't
+    /// determine.
+    pub fn unknown_fields(&self) -> &[Identifier] {
+        &self.unknown_fields
+    }
+
+    /// Advisory emitted when reordering the Rust struct's fields would
+    /// shrink its declaration-order (`#[repr(C)]`) in-memory size.
+    pub fn padding_advisory(&self) -> Option<&PaddingAdvisory> {
+        self.padding_advisory.as_ref()
+    }
+}
+
+/// Estimated in-memory cost of keeping a container's fields in SSZ order.
+///
+/// Sizes are estimates for 64-bit targets and only computed when every field
+/// maps to a Rust type with a known layout.
+#[derive(Clone, Debug)]
+pub struct PaddingAdvisory {
+    declared_size: usize,
+    reordered_size: usize,
+    suggested_order: Vec<Identifier>,
+}
+
+impl PaddingAdvisory {
+    /// Estimated size of the struct with fields in declaration order.
+    pub fn declared_size(&self) -> usize {
+        self.declared_size
+    }
+
+    /// Estimated size of the struct with fields in [`Self::suggested_order`].
+    pub fn reordered_size(&self) -> usize {
+        self.reordered_size
+    }
+
+    /// Field order, by decreasing alignment, that minimizes padding.
+    pub fn suggested_order(&self) -> &[Identifier] {
+        &self.suggested_order
+    }
+}
+
+/// Canonical field order of the types of an upstream spec.
+///
+/// Types are listed by name with their fields in order, inherited fields
+/// included.
+#[derive(Clone, Debug, Default)]
+pub struct FieldOrderManifest {
+    types: Vec<(Identifier, Vec<Identifier>)>,
+}
+
+impl FieldOrderManifest {
+    /// Creates an empty manifest.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a type with its canonical field order.
+    pub fn with_type(mut self, name: Identifier, fields: Vec<Identifier>) -> Self {
+        self.types.push((name, fields));
+        self
+    }
+
+    /// Parses a manifest listing one type per line as `Name: field_a, field_b`.
+    ///
+    /// Blank lines and lines starting with `#` are skipped.
+    pub fn parse(src: &str) -> Result<Self, ManifestError> {
+        let mut manifest = Self::new();
+        for (i, line) in src.lines().enumerate() {
+            let line_no = i + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let Some((name, fields)) = line.split_once(':') else {
+                return Err(ManifestError::MissingColon(line_no));
+            };
+            let ident = |s: &str| {
+                Identifier::try_from(s.trim())
+                    .map_err(|_| ManifestError::InvalidIdentifier(line_no, s.trim().to_owned()))
+            };
+            let name = ident(name)?;
+            if manifest.types.iter().any(|(existing, _)| *existing == name) {
+                return Err(ManifestError::DuplicateType(line_no, name));
+            }
+            let fields = fields
+                .split(',')
+                .filter(|field| !field.trim().is_empty())
+                .map(ident)
+                .collect::<Result<Vec<_>, _>>()?;
+            manifest.types.push((name, fields));
+        }
+        Ok(manifest)
+    }
+}
+
+/// Error parsing a [`FieldOrderManifest`], with the 1-based line number.
+#[derive(Debug, Clone, Eq, PartialEq, Error)]
+pub enum ManifestError {
+    /// A line lacks the `:` separating the type name from its fields.
+    #[error("line {0}: expected `Name: field, ...`")]
+    MissingColon(usize),
+
+    /// A type or field name is not a valid identifier.
+    #[error("line {0}: invalid identifier '{1}'")]
+    InvalidIdentifier(usize, String),
+
+    /// A type is listed more than once.
+    #[error("line {0}: duplicate type '{1:?}'")]
+    DuplicateType(usize, Identifier),
+}
+
+/// A class whose fields diverge from its entry in a [`FieldOrderManifest`].
+#[derive(Clone, Debug)]
+pub struct FieldOrderDiagnostic {
+    name: Identifier,
+    span: Option<SrcSpan>,
+    expected: Vec<Identifier>,
+    actual: Option<Vec<Identifier>>,
+}
+
+impl FieldOrderDiagnostic {
+    /// Name of the type.
+    pub fn name(&self) -> &Identifier {
+        &self.name
+    }
+
+    /// Span of the class definition, or `None` if the schema doesn't define
+    /// the type.
+    pub fn span(&self) -> Option<SrcSpan> {
+        self.span
+    }
+
+    /// Field order listed in the manifest.
+    pub fn expected_fields(&self) -> &[Identifier] {
+        &self.expected
+    }
+
+    /// Field order of the class, inherited fields included, or `None` if the
+    /// schema doesn't define the type.
+    pub fn actual_fields(&self) -> Option<&[Identifier]> {
+        self.actual.as_deref()
+    }
+
+    /// Manifest fields the class lacks.
+    pub fn missing_fields(&self) -> Vec<&Identifier> {
+        let actual = self.actual.as_deref().unwrap_or_default();
+        self.expected
+            .iter()
+            .filter(|field| !actual.contains(field))
+            .collect()
+    }
+
+    /// Class fields the manifest doesn't list.
+    pub fn extra_fields(&self) -> Vec<&Identifier> {
+        let actual = self.actual.as_deref().unwrap_or_default();
+        actual
+            .iter()
+            .filter(|field| !self.expected.contains(field))
+            .collect()
+    }
+}
+
+/// Compares the classes of `schema` against the canonical field order in
+/// `manifest`, returning a diagnostic for every listed type whose fields
+/// differ in order or membership, or that the schema doesn't define.
+///
+/// Classes the manifest doesn't list are not checked.
+pub fn validate_field_order(
+    schema: &SszSchema,
+    manifest: &FieldOrderManifest,
+) -> Vec<FieldOrderDiagnostic> {
+    let sizer = Sizer::new(schema);
+    manifest
+        .types
+        .iter()
+        .filter_map(|(name, expected)| {
+            let class = sizer.classes.get(name);
+            let actual = class.map(|class| {
+                sizer
+                    .fields(class)
+                    .into_iter()
+                    .map(|(field, _)| field.clone())
+                    .collect::<Vec<_>>()
+            });
+            if actual.as_ref() == Some(expected) {
+                return None;
+            }
+            Some(FieldOrderDiagnostic {
+                name: name.clone(),
+                span: class.map(|class| class.span()),
+                expected: expected.clone(),
+                actual,
+            })
+        })
+        .collect()
+}
+
+/// Estimated size and alignment of a Rust type, in bytes.
+#[derive(Copy, Clone, Debug)]
+struct MemLayout {
+    size: usize,
+    align: usize,
+}
+
+impl MemLayout {
+    const fn new(size: usize, align: usize) -> Self {
+        Self { size, align }
+    }
+
+    /// Layout of a `#[repr(C)]` struct with the given fields, in order.
+    fn repr_c(fields: impl IntoIterator<Item = MemLayout>) -> MemLayout {
+        let (size, align) = fields
+            .into_iter()
+            .fold((0usize, 1usize), |(offset, align), field| {
+                (
+                    offset.next_multiple_of(field.align) + field.size,
+                    align.max(field.align),
+                )
+            });
+        MemLayout::new(size.next_multiple_of(align), align)
+    }
+
+    /// Layout of a default-repr struct, which rustc is free to reorder.
+    fn reordered(fields: impl IntoIterator<Item = MemLayout>) -> MemLayout {
+        let mut fields: Vec<_> = fields.into_iter().collect();
+        fields.sort_by_key(|field| std::cmp::Reverse(field.align));
+        MemLayout::repr_c(fields)
+    }
+}
+
+/// Layout of `Vec<T>`, which backs `FixedVector` and `VariableList`.
+const VEC_LAYOUT: MemLayout = MemLayout::new(24, 8);
+
+/// Reports whether each `Container` class in `schema` is fixed- or
+/// variable-size, and which fields cause variability.
+///
+/// This is informational and never fails.  Inherited fields are included,
+/// and `StableContainer`/`Profile`/`Union` classes are not reported since
+/// their encodings are variable-size by construction.
+pub fn validate_schema(schema: &SszSchema) -> Vec<ContainerLayout> {
+    let sizer = Sizer::new(schema);
+    schema
+        .classes()
+        .iter()
+        .filter(|class| sizer.base_name(class) == "Container")
+        .map(|class| {
+            let fields = sizer.fields(class);
+            let mut variable_fields = Vec::new();
+            let mut unknown_fields = Vec::new();
+            for &(name, ty) in &fields {
+                match sizer.ty_size(ty) {
+                    SizeClass::Fixed => {}
+                    SizeClass::Variable => variable_fields.push(name.clone()),
+                    SizeClass::Unknown => unknown_fields.push(name.clone()),
+                }
+            }
+            ContainerLayout {
+                name: class.name().clone(),
+                variable_fields,
+                unknown_fields,
+                padding_advisory: sizer.padding_advisory(&fields),
+            }
+        })
+        .collect()
+}
+
+/// Resolves size classes against the classes and aliases of one schema.
+struct Sizer<'s> {
+    classes: HashMap<&'s Identifier, &'s ClassDef>,
+    aliases: HashMap<&'s Identifier, &'s Ty>,
+}
+
+impl<'s> Sizer<'s> {
+    fn new(schema: &'s SszSchema) -> Self {
+        Self {
+            classes: schema.classes().iter().map(|c| (c.name(), c)).collect(),
+            aliases: schema
+                .aliases()
+                .iter()
+                .map(|a| (a.name(), a.ty()))
+                .collect(),
+        }
+    }
+
+    fn padding_advisory(&self, fields: &[(&Identifier, &Ty)]) -> Option<PaddingAdvisory> {
+        let layouts = fields
+            .iter()
+            .map(|(_, ty)| self.ty_mem_layout(ty))
+            .collect::<Option<Vec<_>>>()?;
+        let declared = MemLayout::repr_c(layouts.iter().copied());
+        let reordered = MemLayout::reordered(layouts.iter().copied());
+        if reordered.size >= declared.size {
+            return None;
+        }
+
+        let mut order: Vec<_> = fields.iter().zip(&layouts).collect();
+        order.sort_by_key(|(_, layout)| std::cmp::Reverse(layout.align));
+        Some(PaddingAdvisory {
+            declared_size: declared.size,
+            reordered_size: reordered.size,
+            suggested_order: order
+                .into_iter()
+                .map(|((name, _), _)| (*name).clone())
+                .collect(),
+        })
+    }
+
+    /// Estimated layout of the Rust type generated for `ty`, if it's one
+    /// whose layout doesn't depend on crate internals.
+    fn ty_mem_layout(&self, ty: &Ty) -> Option<MemLayout> {
+        match ty {
+            Ty::Imported(..) | Ty::ImportedComplex(..) => None,
+            Ty::Simple(name) => {
+                if let Some(ty) = self.aliases.get(name) {
+                    return self.ty_mem_layout(ty);
+                }
+                if let Some(class) = self.classes.get(name) {
+                    if self.base_name(class) != "Container" {
+                        return None;
+                    }
+                    let fields = self.fields(class);
+                    let layouts = fields
+                        .iter()
+                        .map(|(_, ty)| self.ty_mem_layout(ty))
+                        .collect::<Option<Vec<_>>>()?;
+                    return Some(MemLayout::reordered(layouts));
+                }
+                match name.0.as_str() {
+                    "boolean" | "uint8" => Some(MemLayout::new(1, 1)),
+                    "uint16" => Some(MemLayout::new(2, 2)),
+                    "uint32" => Some(MemLayout::new(4, 4)),
+                    // Widths without a primitive are `UintN` wrappers of a `u64`.
+                    "uint24" | "uint40" | "uint48" | "uint56" | "uint64" => {
+                        Some(MemLayout::new(8, 8))
+                    }
+                    // `U128`/`U256` are limb arrays of `u64`.
+                    "uint128" => Some(MemLayout::new(16, 8)),
+                    "uint256" => Some(MemLayout::new(32, 8)),
+                    _ => None,
+                }
+            }
+            Ty::Complex(name, args) => match name.0.as_str() {
+                "Vector" => match args.as_slice() {
+                    // Byte vectors are generated as inline `FixedBytes<N>`.
+                    [TyExpr::Ty(Ty::Simple(elem)), len] if elem.0 == "uint8" => {
+                        let len = match len {
+                            TyExpr::Int(value) => value.eval(),
+                            TyExpr::ConstRef(_, value) => *value,
+                            _ => return None,
+                        };
+                        Some(MemLayout::new(len as usize, 1))
+                    }
+                    _ => Some(VEC_LAYOUT),
+                },
+                "List" => Some(VEC_LAYOUT),
+                _ => None,
+            },
+        }
+    }
+
+    /// Builtin base of `class`, following class inheritance within the
+    /// schema.
+    fn base_name(&self, class: &ClassDef) -> &'s str {
+        let mut parent = class.parent_ty();
+        loop {
+            let name = parent.base_name();
+            match self.classes.get(name) {
+                Some(def) => parent = def.parent_ty(),
+                None => {
+                    return match name.0.as_str() {
+                        "Container" => "Container",
+                        "StableContainer" => "StableContainer",
+                        "Profile" => "Profile",
+                        "Union" => "Union",
+                        _ => "",
+                    };
+                }
+            }
+        }
+    }
+
+    /// Fields of `class` including inherited ones: an overriding field keeps
+    /// its parent's position and new fields are appended.
+    fn fields(&self, class: &'s ClassDef) -> Vec<(&'s Identifier, &'s Ty)> {
+        let mut fields = match self.classes.get(class.parent_ty().base_name()) {
+            Some(parent) => self.fields(parent),
+            None => Vec::new(),
+        };
+        for field in class.fields() {
+            let Some(ty) = field.ty() else { continue };
+            match fields.iter_mut().find(|(name, _)| *name == field.name()) {
+                Some(existing) => existing.1 = ty,
+                None => fields.push((field.name(), ty)),
+            }
+        }
+        fields
+    }
+
+    fn ty_size(&self, ty: &Ty) -> SizeClass {
+        match ty {
+            Ty::Imported(..) | Ty::ImportedComplex(..) => SizeClass::Unknown,
+            Ty::Simple(name) => self.named_size(name),
+            Ty::Complex(name, args) => match name.0.as_str() {
+                "Vector" => match args.first() {
+                    Some(TyExpr::Ty(elem)) => self.ty_size(elem),
+                    _ => SizeClass::Unknown,
+                },
+                "Bitvector" => SizeClass::Fixed,
+                "List" | "Bitlist" | "Union" | "Optional" | "StableContainer" => {
+                    SizeClass::Variable
+                }
+                _ => SizeClass::Unknown,
+            },
+        }
+    }
+
+    fn named_size(&self, name: &Identifier) -> SizeClass {
+        if let Some(ty) = self.aliases.get(name) {
+            return self.ty_size(ty);
+        }
+        let Some(class) = self.classes.get(name) else {
+            return match name.0.as_str() {
+                "boolean" => SizeClass::Fixed,
+                n if n.starts_with("uint") => SizeClass::Fixed,
+                _ => SizeClass::Unknown,
+            };
+        };
+        let field_sizes = self
+            .fields(class)
+            .into_iter()
+            .map(|(_, ty)| self.ty_size(ty));
+        match self.base_name(class) {
+            "Container" => SizeClass::combine(field_sizes),
+            // A Profile without optional fields encodes like a Container.
+            "Profile" => {
+                let has_optional = self
+                    .fields(class)
+                    .iter()
+                    .any(|(_, ty)| matches!(ty, Ty::Complex(name, _) if name.0 == "Optional"));
+                if has_optional {
+                    SizeClass::Variable
+                } else {
+                    SizeClass::combine(field_sizes)
+                }
+            }
+            "StableContainer" | "Union" => SizeClass::Variable,
+            _ => SizeClass::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, path::Path};
+
+    use super::*;
+    use crate::parse_str_schema;
+
+    fn layouts(schema: &str) -> Vec<ContainerLayout> {
+        let files = HashMap::from([(Path::new("test.ssz").to_path_buf(), schema.to_string())]);
+        let (_, schemas) = parse_str_schema(&files, &[]).expect("test: parse schema");
+        validate_schema(&schemas[Path::new("test.ssz")])
+    }
+
+    #[test]
+    fn test_mixed_container_reports_variable_field() {
+        let layouts = layouts(
+            r"
+Root = Bytes32
+Payload = List[byte, 32]
+
+class Header(Container):
+    slot: uint64
+    root: Root
+    flags: Bitvector[8]
+
+class Block(Container):
+    header: Header
+    payload: Payload
+    proposer: uint64
+    extra: Vector[List[uint8, 4], 2]
+",
+        );
+
+        let header = layouts.iter().find(|l| l.name().0 == "Header").unwrap();
+        assert!(header.is_fixed());
+
+        let block = layouts.iter().find(|l| l.name().0 == "Block").unwrap();
+        assert!(!block.is_fixed());
+        let variable: Vec<_> = block
+            .variable_fields()
+            .iter()
+            .map(|f| f.0.as_str())
+            .collect();
+        assert_eq!(variable, ["payload", "extra"]);
+        assert!(block.unknown_fields().is_empty());
+    }
+
+    #[test]
+    fn test_padding_advisory_for_suboptimal_order() {
+        let layouts = layouts(
+            r"
+class Loose(Container):
+    flag: boolean
+    amount: uint64
+    kind: uint8
+    root: Bytes32
+
+class Tight(Container):
+    amount: uint64
+    root: Bytes32
+    flag: boolean
+    kind: uint8
+",
+        );
+
+        let loose = layouts.iter().find(|l| l.name().0 == "Loose").unwrap();
+        let advisory = loose.padding_advisory().expect("test: advisory for Loose");
+        assert_eq!(advisory.declared_size(), 56);
+        assert_eq!(advisory.reordered_size(), 48);
+        let order: Vec<_> = advisory
+            .suggested_order()
+            .iter()
+            .map(|f| f.0.as_str())
+            .collect();
+        assert_eq!(order, ["amount", "flag", "kind", "root"]);
+
+        let tight = layouts.iter().find(|l| l.name().0 == "Tight").unwrap();
+        assert!(tight.padding_advisory().is_none());
+    }
+
+    const ORDER_SCHEMA: &str = r"
+class Checkpoint(Container):
+    epoch: uint64
+    root: Bytes32
+
+class Header(Container):
+    slot: uint64
+    proposer: uint64
+    root: Bytes32
+
+class SignedHeader(Header):
+    signature: Bytes32
+";
+
+    fn field_order(manifest: &str) -> Vec<FieldOrderDiagnostic> {
+        let files = HashMap::from([(
+            Path::new("test.ssz").to_path_buf(),
+            ORDER_SCHEMA.to_string(),
+        )]);
+        let (_, schemas) = parse_str_schema(&files, &[]).expect("test: parse schema");
+        let manifest = FieldOrderManifest::parse(manifest).expect("test: parse manifest");
+        validate_field_order(&schemas[Path::new("test.ssz")], &manifest)
+    }
+
+    #[test]
+    fn test_field_order_matching_manifest() {
+        let diagnostics = field_order(
+            "
+# phase0
+Checkpoint: epoch, root
+SignedHeader: slot, proposer, root, signature
+",
+        );
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_field_order_reordered_field() {
+        let diagnostics = field_order(
+            "
+Checkpoint: epoch, root
+Header: proposer, slot, root
+",
+        );
+        assert_eq!(diagnostics.len(), 1);
+        let header = &diagnostics[0];
+        assert_eq!(header.name().0, "Header");
+        let actual: Vec<_> = header
+            .actual_fields()
+            .unwrap()
+            .iter()
+            .map(|f| f.0.as_str())
+            .collect();
+        assert_eq!(actual, ["slot", "proposer", "root"]);
+        assert!(header.missing_fields().is_empty());
+        assert!(header.extra_fields().is_empty());
+
+        let span = header.span().expect("test: span of Header");
+        let (start, end) = (span.start().off() as usize, span.end().off() as usize);
+        assert!(ORDER_SCHEMA[start..end].starts_with("class Header"));
+        assert!(ORDER_SCHEMA[start..end].contains("proposer: uint64"));
+    }
+
+    #[test]
+    fn test_field_order_missing_type_and_field() {
+        let diagnostics = field_order(
+            "
+Checkpoint: epoch, root, extra
+Fork: epoch
+",
+        );
+        assert_eq!(diagnostics.len(), 2);
+        let missing: Vec<_> = diagnostics[0]
+            .missing_fields()
+            .iter()
+            .map(|f| f.0.as_str())
+            .collect();
+        assert_eq!(missing, ["extra"]);
+        assert_eq!(diagnostics[1].name().0, "Fork");
+        assert!(diagnostics[1].span().is_none());
+        assert!(diagnostics[1].actual_fields().is_none());
+    }
+
+    #[test]
+    fn test_field_order_manifest_errors() {
+        assert_eq!(
+            FieldOrderManifest::parse("Checkpoint epoch").unwrap_err(),
+            ManifestError::MissingColon(1)
+        );
+        assert_eq!(
+            FieldOrderManifest::parse("\nCheckpoint: epoch, 0root").unwrap_err(),
+            ManifestError::InvalidIdentifier(2, "0root".to_owned())
+        );
+        assert!(matches!(
+            FieldOrderManifest::parse("A: x\nA: y").unwrap_err(),
+            ManifestError::DuplicateType(2, _)
+        ));
+    }
+}
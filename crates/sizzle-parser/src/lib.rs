@@ -1,6 +1,6 @@
 //! Pythonic SSZ definition parser.
 
-mod ast;
+pub mod ast;
 
 mod builtins;
 
@@ -25,5 +25,11 @@ pub mod tysys;
 
 mod gobbler;
 
+mod layout;
+pub use layout::{
+    ContainerLayout, FieldOrderDiagnostic, FieldOrderManifest, ManifestError, PaddingAdvisory,
+    validate_field_order, validate_schema,
+};
+
 mod pipeline;
-pub use pipeline::{SszError, parse_str_schema};
+pub use pipeline::{SszError, parse_ast, parse_str_schema};
@@ -2,22 +2,27 @@
 
 use std::{
     collections::{HashMap, HashSet},
-    path::PathBuf,
+    path::{Path, PathBuf},
 };
 
 use thiserror::Error;
 
 use crate::{
-    SszSchema,
+    Identifier, PosTbl, SrcSpan, SszSchema,
     ast::{
-        self, AssignExpr, Module, ModuleEntry, ModuleManager, ParseError, TyArgSpec, TyExprSpec,
+        self, AssignExpr, ImportEntry, Module, ModuleEntry, ModuleManager, ParseError, TyArgSpec,
+        TyExprSpec,
     },
     schema::{self, SchemaError},
     token::{self, TokenError},
     token_tree::{self, ToktrError},
     ty_resolver::{CrossModuleTypeMap, ModuleTypeMap, ResolverError},
+    tysys::Ty,
 };
 
+/// Maximum number of members in a union; SSZ union selectors only go up to 127.
+const MAX_UNION_MEMBERS: usize = 128;
+
 /// Represents an error from any of the phases of parsing a raw schema.
 #[derive(Debug, Clone, Eq, PartialEq, Error)]
 pub enum SszError {
@@ -40,6 +45,121 @@ pub enum SszError {
     /// Error from the schema generator.
     #[error("schema generation: {0}")]
     SchemaGen(#[from] SchemaError),
+
+    /// A union has more members than there are valid selectors.
+    #[error("union '{}' has {count} members, at most {MAX_UNION_MEMBERS} are allowed", name.0)]
+    TooManyUnionMembers {
+        /// The name of the union.
+        name: Identifier,
+        /// The number of members it declares.
+        count: usize,
+    },
+
+    /// Error from one of the other phases, located within a schema file.
+    ///
+    /// Displays as `file:line:col: message` followed by a caret-underlined
+    /// snippet of the offending source line when the position is known.
+    #[error("{}", render_located(path, span.as_ref(), snippet, error))]
+    Located {
+        /// The schema file the error occurred in.
+        path: PathBuf,
+        /// Where in the file the error occurred, if known.
+        span: Option<SrcSpan>,
+        /// Rendered source snippet for `span`, empty if unknown.
+        snippet: String,
+        /// The underlying error.
+        error: Box<SszError>,
+    },
+}
+
+impl SszError {
+    /// Gets the source span of the error, if it is known.
+    pub fn span(&self) -> Option<SrcSpan> {
+        match self {
+            Self::Located { span, .. } => *span,
+            _ => None,
+        }
+    }
+
+    /// Attaches the file path, and the source location where the error
+    /// carries one, to an error raised while parsing `src`.
+    fn locate(self, path: &Path, src: &str) -> Self {
+        if matches!(self, Self::Located { .. }) {
+            return self;
+        }
+
+        let pos = match &self {
+            Self::Token(
+                TokenError::UnexpectedChar(_, idx)
+                | TokenError::InvalidIndent(idx, _)
+                | TokenError::UnrecognizableIndent(idx),
+            ) => PosTbl::generate(src.chars()).get_srcpos(*idx),
+            Self::Parser(
+                ParseError::MalformedBlock(sp)
+                | ParseError::UnexpectedToken(sp)
+                | ParseError::StandaloneDocstring(sp),
+            ) => Some(*sp),
+            _ => None,
+        };
+        let span = pos.map(|sp| SrcSpan::new(sp, sp));
+        let snippet = span.map(|sp| sp.render_snippet(src)).unwrap_or_default();
+
+        Self::Located {
+            path: path.to_path_buf(),
+            span,
+            snippet,
+            error: Box::new(self),
+        }
+    }
+}
+
+/// Renders a located error as `file:line:col: message` plus its snippet.
+///
+/// Line and column are 1-indexed, as editors expect.
+fn render_located(path: &Path, span: Option<&SrcSpan>, snippet: &str, error: &SszError) -> String {
+    // Entry points are keyed without their extension.
+    let path = if path.extension().is_none() {
+        path.with_extension("ssz")
+    } else {
+        path.to_path_buf()
+    };
+    match span {
+        Some(span) => format!(
+            "{}:{}:{}: {error}\n{snippet}",
+            path.display(),
+            span.start().line() + 1,
+            span.start().col() + 1,
+        ),
+        None => format!("{}: {error}", path.display()),
+    }
+}
+
+/// Checks that no union alias or union class in `schema` has more than
+/// [`MAX_UNION_MEMBERS`] members.
+#[allow(clippy::result_large_err)]
+fn check_union_members(schema: &SszSchema) -> Result<(), SszError> {
+    let union_classes = schema
+        .classes()
+        .iter()
+        .filter(|class| class.parent_ty().base_name().0 == "Union")
+        .map(|class| (class.name(), class.fields().len()));
+    let union_aliases = schema
+        .aliases()
+        .iter()
+        .filter_map(|alias| match alias.ty() {
+            Ty::Complex(name, args) if name.0 == "Union" => Some((alias.name(), args.len())),
+            _ => None,
+        });
+
+    for (name, count) in union_classes.chain(union_aliases) {
+        if count > MAX_UNION_MEMBERS {
+            return Err(SszError::TooManyUnionMembers {
+                name: name.clone(),
+                count,
+            });
+        }
+    }
+    Ok(())
 }
 
 /// Helper struct for topological sorting of modules.
@@ -48,6 +168,7 @@ struct TopoSort {
     deps: HashMap<PathBuf, HashSet<PathBuf>>,
     in_progress: HashSet<PathBuf>,
     sorted: Vec<(PathBuf, Module)>,
+    cycle: Option<PathBuf>,
 }
 
 impl TopoSort {
@@ -86,6 +207,7 @@ impl TopoSort {
             deps,
             in_progress: HashSet::new(),
             sorted: Vec::new(),
+            cycle: None,
         }
     }
 
@@ -95,8 +217,9 @@ impl TopoSort {
         if !self.modules.contains_key(&path) {
             return;
         }
-        // Cycle detection: if we're currently processing this path, skip.
+        // Cycle detection: if we're currently processing this path, note it and skip.
         if self.in_progress.contains(&path) {
+            self.cycle.get_or_insert(path);
             return;
         }
 
@@ -117,13 +240,17 @@ impl TopoSort {
         }
     }
 
-    /// Consumes self and returns the topologically sorted modules.
-    fn sort(mut self) -> Vec<(PathBuf, Module)> {
+    /// Consumes self and returns the topologically sorted modules, or the path
+    /// of a module that (transitively) imports itself.
+    fn sort(mut self) -> Result<Vec<(PathBuf, Module)>, PathBuf> {
         let paths: Vec<_> = self.modules.keys().cloned().collect();
         for path in paths {
             self.visit(path);
         }
-        self.sorted
+        match self.cycle {
+            Some(path) => Err(path),
+            None => Ok(self.sorted),
+        }
     }
 }
 
@@ -190,8 +317,12 @@ fn get_module_imports(module: &Module) -> HashSet<PathBuf> {
                         collect_imports_from_ty_arg(arg, &mut imports);
                     }
                 }
-                AssignExpr::Name(_) | AssignExpr::Value(_) | AssignExpr::SymbolicBinop(_, _, _) => {
-                }
+                AssignExpr::Name(_)
+                | AssignExpr::Value(_)
+                | AssignExpr::SymbolicBinop(_, _, _)
+                | AssignExpr::Array(_)
+                | AssignExpr::Bytes(_)
+                | AssignExpr::Call(_, _) => {}
             },
             ModuleEntry::Class(class) => {
                 collect_imports_from_ty_expr(class.parent_ty(), &mut imports);
@@ -205,6 +336,34 @@ fn get_module_imports(module: &Module) -> HashSet<PathBuf> {
     imports
 }
 
+/// Parses a single schema source into its untyped AST, without resolving any
+/// names or types.
+///
+/// Returns the source's `import` and `from ... import` lines, followed by its
+/// entries. Both are in source order and keep their source spans, and entries
+/// keep their doc comments and pragma lines.  Imports are returned as written
+/// and never loaded, so this doesn't touch the filesystem; resolving them is
+/// left to the caller.  A `module.Type` reference is parsed with the module
+/// path of the import it names, also as written.
+#[allow(clippy::result_large_err)]
+pub fn parse_ast(src: &str) -> Result<(Vec<ImportEntry>, Vec<ModuleEntry>), SszError> {
+    let path = Path::new("schema");
+    let mut module_manager = ModuleManager::new_unresolved();
+    module_manager.add_module(path, false);
+
+    let chars = src.chars().collect::<Vec<_>>();
+    let tokens = token::parse_char_array_to_tokens(&chars)?;
+    let toktrs = token_tree::parse_tokens_to_toktrs(&tokens)?;
+    ast::parse_module_from_toktrs(&toktrs, path, &mut module_manager, None)?;
+
+    let imports = module_manager.take_unresolved_imports();
+    let module = module_manager
+        .into_modules()
+        .remove(path)
+        .expect("pipeline: root module registered");
+    Ok((imports, module.entries().to_vec()))
+}
+
 /// High-level parse function.
 #[allow(clippy::result_large_err)]
 pub fn parse_str_schema(
@@ -218,9 +377,12 @@ pub fn parse_str_schema(
         // point)
         if module_manager.add_module_to_front(path.clone()) {
             let chars = content.chars().collect::<Vec<_>>();
-            let tokens = token::parse_char_array_to_tokens(&chars)?;
-            let toktrs = token_tree::parse_tokens_to_toktrs(&tokens)?;
-            ast::parse_module_from_toktrs(&toktrs, path, &mut module_manager, Some(files))?;
+            let locate = |err: SszError| err.locate(path, content);
+            let tokens = token::parse_char_array_to_tokens(&chars).map_err(|e| locate(e.into()))?;
+            let toktrs =
+                token_tree::parse_tokens_to_toktrs(&tokens).map_err(|e| locate(e.into()))?;
+            ast::parse_module_from_toktrs(&toktrs, path, &mut module_manager, Some(files))
+                .map_err(|e| locate(e.into()))?;
         }
     }
 
@@ -239,7 +401,14 @@ pub fn parse_str_schema(
     // This ensures that when we convert a module to a schema, all its imported modules have
     // already been processed and their types are available in cross_module_types.
     let topo_sort = TopoSort::new(module_manager.into_modules());
-    let sorted_modules = topo_sort.sort();
+    let sorted_modules = topo_sort.sort().map_err(|path| {
+        let module_name = path.file_stem().unwrap_or_default().to_string_lossy();
+        let src = files.get(&path).map_or("", String::as_str);
+        SszError::from(SchemaError::CyclicImport(Identifier(
+            module_name.into_owned(),
+        )))
+        .locate(&path, src)
+    })?;
 
     let mut parsing_order = Vec::new();
     for (path, module) in sorted_modules {
@@ -247,7 +416,10 @@ pub fn parse_str_schema(
             cross_module_types.insert(path.clone(), ModuleTypeMap::External);
             continue;
         }
-        let (schema, idents) = schema::conv_module_to_schema(&module, &cross_module_types)?;
+        let src = files.get(&path).map_or("", String::as_str);
+        let (schema, idents) = schema::conv_module_to_schema(&module, &cross_module_types)
+            .map_err(|e| SszError::from(e).locate(&path, src))?;
+        check_union_members(&schema).map_err(|e| e.locate(&path, src))?;
         parsing_order.push(path.clone());
         cross_module_types.insert(path.clone(), ModuleTypeMap::Internal(idents));
         schema_map.insert(path, schema);
@@ -260,7 +432,11 @@ pub fn parse_str_schema(
 mod tests {
     use std::{collections::HashMap, path::Path};
 
-    use crate::pipeline::parse_str_schema;
+    use crate::{
+        ast::{AssignExpr, ModuleEntry},
+        pipeline::{SszError, parse_ast, parse_str_schema},
+        schema::SchemaError,
+    };
 
     /*fn make_ident(s: &str) -> Identifier {
         Identifier::try_from(s.to_owned()).expect("test: make ident")
@@ -589,6 +765,100 @@ class MyContainer(Container):
         eprintln!("Successfully tested add/sub operators with evaluated values: {schema:#?}");
     }
 
+    #[test]
+    fn test_const_arrays() {
+        const SCHEMA: &str = r"
+BASE = 2
+DOMAIN_TYPES = [0x00, 0x01, BASE, BASE + 1, 2 * 3]
+LIMITS = [1 << 10, 0xffffffff]
+
+class Foo(Container):
+    a: uint8
+";
+
+        let files = HashMap::from([(Path::new("test.ssz").to_path_buf(), SCHEMA.to_string())]);
+
+        let (_, schema_map) =
+            parse_str_schema(&files, &[]).expect("test: parse schema with const arrays");
+
+        let schema = schema_map
+            .get(Path::new("test.ssz"))
+            .expect("test: get schema");
+
+        let arrays = schema.const_arrays();
+        assert_eq!(arrays.len(), 2);
+        assert_eq!(arrays[0].name().0, "DOMAIN_TYPES");
+        assert_eq!(arrays[0].values(), &[0, 1, 2, 3, 6]);
+        assert_eq!(arrays[1].name().0, "LIMITS");
+        assert_eq!(arrays[1].values(), &[1024, 0xffff_ffff]);
+
+        // Arrays can't be used as type arguments, and can't hold types.
+        let bad_arg = r"
+LENS = [1, 2]
+
+class Foo(Container):
+    a: List[byte, LENS]
+";
+        let files = HashMap::from([(Path::new("test.ssz").to_path_buf(), bad_arg.to_string())]);
+        assert!(parse_str_schema(&files, &[]).is_err());
+
+        let bad_elem = "BAD = [1, uint8]\n";
+        let files = HashMap::from([(Path::new("test.ssz").to_path_buf(), bad_elem.to_string())]);
+        assert!(parse_str_schema(&files, &[]).is_err());
+    }
+
+    #[test]
+    fn test_byte_strings_and_len() {
+        const SCHEMA: &str = r#"
+DOMAIN_BEACON = b"\x07\x00\x00\x00"
+DOMAIN_NAME = "beacon"
+LIMITS = [1, 2, 3]
+DOMAIN_LEN = len(DOMAIN_BEACON)
+SIZES = [len(DOMAIN_NAME), len(LIMITS)]
+
+class Foo(Container):
+    domain: Vector[byte, DOMAIN_LEN]
+    name: List[byte, DOMAIN_LEN]
+"#;
+
+        let files = HashMap::from([(Path::new("test.ssz").to_path_buf(), SCHEMA.to_string())]);
+
+        let (_, schema_map) =
+            parse_str_schema(&files, &[]).expect("test: parse schema with byte strings");
+
+        let schema = schema_map
+            .get(Path::new("test.ssz"))
+            .expect("test: get schema");
+
+        let arrays = schema.const_arrays();
+        assert_eq!(arrays.len(), 4);
+        assert_eq!(arrays[0].name().0, "DOMAIN_BEACON");
+        assert_eq!(arrays[0].values(), &[7, 0, 0, 0]);
+        assert!(arrays[0].is_bytes());
+        assert_eq!(arrays[1].values(), b"beacon".map(u64::from).as_slice());
+        assert!(arrays[1].is_bytes());
+        assert!(!arrays[2].is_bytes());
+        assert_eq!(arrays[3].name().0, "SIZES");
+        assert_eq!(arrays[3].values(), &[6, 3]);
+
+        let constants = schema.constants();
+        assert_eq!(constants.len(), 1);
+        assert_eq!(constants[0].name().0, "DOMAIN_LEN");
+        assert_eq!(constants[0].value().eval(), 4);
+
+        // `len()` only applies to arrays and byte strings, and is the only builtin.
+        for bad in [
+            "N = 4\nLEN = len(N)\n",
+            "LEN = len(uint8)\n",
+            "LEN = len(MISSING)\n",
+            "A = [1]\nLEN = size(A)\n",
+            "A = [1]\nLEN = len(A, A)\n",
+        ] {
+            let files = HashMap::from([(Path::new("test.ssz").to_path_buf(), bad.to_string())]);
+            assert!(parse_str_schema(&files, &[]).is_err(), "{bad}");
+        }
+    }
+
     #[test]
     fn test_issue_49_example() {
         // Test the actual example from issue #49
@@ -967,4 +1237,292 @@ class ConsumerC(Container):
             "ConsumerC"
         );
     }
+
+    #[test]
+    fn test_parse_ast_keeps_comments_and_spans() {
+        const SCHEMA: &str = r#"MAX_LEN = 32
+Hash = Vector[byte, 32]
+
+### A point in space.
+#~# derive: Hash
+class Point(Container):
+    """Docstring."""
+    ### The x coordinate.
+    x: uint32
+    y: List[uint8, MAX_LEN]
+"#;
+
+        let (imports, entries) = parse_ast(SCHEMA).expect("test: parse ast");
+        assert!(imports.is_empty());
+        assert_eq!(entries.len(), 3);
+
+        let ModuleEntry::Assignment(max_len) = &entries[0] else {
+            panic!("test: expected assignment");
+        };
+        assert_eq!(max_len.name().0, "MAX_LEN");
+        assert!(matches!(max_len.value(), AssignExpr::Value(_)));
+        assert_eq!(max_len.span().start().line(), 0);
+
+        let ModuleEntry::Assignment(hash) = &entries[1] else {
+            panic!("test: expected assignment");
+        };
+        assert!(matches!(hash.value(), AssignExpr::Complex(_)));
+        assert_eq!(hash.span().start().line(), 1);
+
+        let ModuleEntry::Class(point) = &entries[2] else {
+            panic!("test: expected class");
+        };
+        assert_eq!(point.name().0, "Point");
+        assert_eq!(point.doc(), Some("Docstring."));
+        assert_eq!(point.doc_comment(), Some(" A point in space."));
+        assert_eq!(point.pragmas(), ["derive: Hash"]);
+        assert_eq!(point.fields().len(), 2);
+        assert_eq!(point.fields()[0].doc_comment(), Some(" The x coordinate."));
+        assert_eq!(point.span().start().line(), 5);
+        assert_eq!(point.span().end().line(), 9);
+    }
+
+    #[test]
+    fn test_parse_ast_returns_imports_as_written() {
+        const SCHEMA: &str = "import shapes.points as pts
+from ..common import MAX_LEN, Hash
+
+class Line(Container):
+    a: pts.Point
+    b: Hash
+";
+
+        let (imports, entries) = parse_ast(SCHEMA).expect("test: parse ast");
+        assert_eq!(imports.len(), 2);
+
+        assert_eq!(imports[0].module_path(), Path::new("shapes/points"));
+        assert_eq!(imports[0].alias().map(|a| a.0.as_str()), Some("pts"));
+        assert!(imports[0].names().is_empty());
+        assert_eq!(imports[0].span().start().line(), 0);
+
+        assert_eq!(imports[1].module_path(), Path::new("../common"));
+        assert_eq!(imports[1].alias(), None);
+        let names = imports[1].names().iter().map(|n| n.0.as_str());
+        assert_eq!(names.collect::<Vec<_>>(), ["MAX_LEN", "Hash"]);
+        assert_eq!(imports[1].span().start().line(), 1);
+
+        // The `from` line isn't expanded into assignments.
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].name().0, "Line");
+    }
+
+    #[test]
+    fn test_error_display_points_at_source() {
+        const SCHEMA: &str = "FOO = 1\nBAR = 2 $ 3\n";
+
+        let files = HashMap::from([(Path::new("bad").to_path_buf(), SCHEMA.to_string())]);
+        let err = parse_str_schema(&files, &[]).expect_err("test: invalid char");
+
+        let span = err.span().expect("test: tokenizer errors are located");
+        assert_eq!((span.start().line(), span.start().col()), (1, 8));
+        assert_eq!(
+            err.to_string(),
+            "bad.ssz:2:9: tokenizer: unexpected char '$' at pos 16\n  |\n2 | BAR = 2 $ 3\n  |         ^"
+        );
+    }
+
+    #[test]
+    fn test_error_display_without_span() {
+        const SCHEMA: &str = r"
+class Foo(Container):
+    a: uint8
+    a: uint16
+";
+
+        let files = HashMap::from([(Path::new("dup.ssz").to_path_buf(), SCHEMA.to_string())]);
+        let err = parse_str_schema(&files, &[]).expect_err("test: duplicate field");
+
+        assert!(err.span().is_none());
+        assert!(err.to_string().starts_with("dup.ssz: schema generation: "));
+    }
+
+    #[test]
+    fn test_from_import() {
+        const COMMON: &str = r"
+VAL_X = 4
+AliasVecA = Vector[uint8, VAL_X]
+";
+
+        const MAIN: &str = r"
+from common import VAL_X, AliasVecA
+
+class Bar(Container):
+    a: AliasVecA
+    b: List[uint8, VAL_X]
+";
+
+        let files = HashMap::from([
+            (Path::new("common.ssz").to_path_buf(), COMMON.to_string()),
+            (Path::new("main.ssz").to_path_buf(), MAIN.to_string()),
+        ]);
+        let (_, schema_map) = parse_str_schema(&files, &[]).expect("test: parse schema");
+
+        let main = schema_map.get(Path::new("main.ssz")).unwrap();
+        let aliases = main
+            .aliases()
+            .iter()
+            .map(|alias| alias.name().0.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(aliases, ["VAL_X", "AliasVecA"]);
+        assert_eq!(main.classes()[0].name().0, "Bar");
+    }
+
+    #[test]
+    fn test_field_named_from() {
+        const COMMON: &str = r"
+Address = Vector[uint8, 20]
+";
+
+        const MAIN: &str = r"
+from common import Address
+
+class Tx(Container):
+    from: Address
+    to: Address
+    value: uint64
+";
+
+        let files = HashMap::from([
+            (Path::new("common.ssz").to_path_buf(), COMMON.to_string()),
+            (Path::new("main.ssz").to_path_buf(), MAIN.to_string()),
+        ]);
+        let (_, schema_map) = parse_str_schema(&files, &[]).expect("test: parse schema");
+
+        let main = schema_map.get(Path::new("main.ssz")).unwrap();
+        let fields = main.classes()[0]
+            .fields()
+            .iter()
+            .map(|field| field.name().0.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(fields, ["from", "to", "value"]);
+    }
+
+    #[test]
+    fn test_from_import_unknown_item() {
+        let files = HashMap::from([
+            (
+                Path::new("common.ssz").to_path_buf(),
+                "VAL_X = 4\n".to_string(),
+            ),
+            (
+                Path::new("main.ssz").to_path_buf(),
+                "from common import VAL_Y\n".to_string(),
+            ),
+        ]);
+        let err = parse_str_schema(&files, &[]).expect_err("test: missing item");
+
+        let SszError::Located { error, .. } = err else {
+            panic!("test: expected located error");
+        };
+        assert!(matches!(
+            *error,
+            SszError::SchemaGen(SchemaError::UnknownImportItem(_, ref item)) if item.0 == "VAL_Y"
+        ));
+    }
+
+    #[test]
+    fn test_from_import_cycle() {
+        let files = HashMap::from([
+            (
+                Path::new("a.ssz").to_path_buf(),
+                "from b import B_VAL\nA_VAL = 1\n".to_string(),
+            ),
+            (
+                Path::new("b.ssz").to_path_buf(),
+                "from a import A_VAL\nB_VAL = 2\n".to_string(),
+            ),
+        ]);
+        let err = parse_str_schema(&files, &[]).expect_err("test: import cycle");
+
+        let SszError::Located { error, .. } = err else {
+            panic!("test: expected located error");
+        };
+        assert!(matches!(
+            *error,
+            SszError::SchemaGen(SchemaError::CyclicImport(_))
+        ));
+    }
+
+    #[test]
+    fn test_recursion_through_list() {
+        let schema = "class Leaf(Container):\n    value: uint64\n\n\
+            class Tree(Union):\n    Leaf: Leaf\n    Node: List[Tree, 4]\n";
+        let files = HashMap::from([(Path::new("tree.ssz").to_path_buf(), schema.to_string())]);
+        parse_str_schema(&files, &[]).expect("test: recursion through a list");
+    }
+
+    #[test]
+    fn test_mutual_recursion_through_list() {
+        let schema = "class A(Container):\n    x: List[B, 4]\n\n\
+            class B(Container):\n    a: A\n";
+        let files = HashMap::from([(Path::new("tree.ssz").to_path_buf(), schema.to_string())]);
+        let err = parse_str_schema(&files, &[]).expect_err("test: mutual recursion");
+
+        let SszError::Located { error, .. } = err else {
+            panic!("test: expected located error");
+        };
+        assert!(matches!(
+            *error,
+            SszError::SchemaGen(SchemaError::CyclicTypedefs(_))
+        ));
+    }
+
+    #[test]
+    fn test_inline_recursion() {
+        for schema in [
+            "class Tree(Union):\n    Leaf: uint8\n    Node: Vector[Tree, 2]\n",
+            "class A(Container):\n    b: B\n\nclass B(Container):\n    a: Optional[A]\n",
+        ] {
+            let files = HashMap::from([(Path::new("tree.ssz").to_path_buf(), schema.to_string())]);
+            let err = parse_str_schema(&files, &[]).expect_err("test: inline recursion");
+
+            let SszError::Located { error, .. } = err else {
+                panic!("test: expected located error");
+            };
+            assert!(matches!(
+                *error,
+                SszError::SchemaGen(SchemaError::CyclicTypedefs(_))
+            ));
+        }
+    }
+
+    fn union_alias_schema(members: usize) -> String {
+        format!("Wide = Union[{}]\n", vec!["uint8"; members].join(", "))
+    }
+
+    fn union_class_schema(members: usize) -> String {
+        let fields: String = (0..members)
+            .map(|i| format!("    Member{i}: uint8\n"))
+            .collect();
+        format!("class Wide(Union):\n{fields}")
+    }
+
+    #[test]
+    fn test_union_with_128_members() {
+        for schema in [union_alias_schema(128), union_class_schema(128)] {
+            let files = HashMap::from([(Path::new("wide.ssz").to_path_buf(), schema)]);
+            parse_str_schema(&files, &[]).expect("test: 128 members are allowed");
+        }
+    }
+
+    #[test]
+    fn test_union_with_129_members() {
+        for schema in [union_alias_schema(129), union_class_schema(129)] {
+            let files = HashMap::from([(Path::new("wide.ssz").to_path_buf(), schema)]);
+            let err = parse_str_schema(&files, &[]).expect_err("test: too many members");
+
+            let SszError::Located { error, .. } = err else {
+                panic!("test: expected located error");
+            };
+            assert!(matches!(
+                *error,
+                SszError::TooManyUnionMembers { ref name, count: 129 } if name.0 == "Wide"
+            ));
+        }
+    }
 }
@@ -5,11 +5,11 @@ use std::collections::{HashMap, HashSet};
 use thiserror::Error;
 
 use crate::{
-    Identifier,
+    Identifier, SrcSpan,
     ast::{AssignExpr, ClassDefEntry, Module, ModuleEntry, TyExprSpec},
     builtins,
     ty_resolver::{CrossModuleTypeMap, IdentTarget, ResolverError, TypeData, TypeResolver},
-    tysys::{Binop, ConstValue, Ty, TyExpr},
+    tysys::{Binop, ConstValue, Ty, TyExpr, canonical_type_string},
 };
 
 #[derive(Debug, Clone, Eq, PartialEq, Error)]
@@ -32,6 +32,9 @@ pub enum SchemaError {
     #[error("found type cycle including type '{0:?}'")]
     CyclicTypedefs(Identifier),
 
+    #[error("found import cycle including module '{0:?}'")]
+    CyclicImport(Identifier),
+
     #[error("tyresolv: {0}")]
     Ty(#[from] ResolverError),
 }
@@ -40,6 +43,7 @@ pub enum SchemaError {
 #[derive(Clone, Debug)]
 pub struct SszSchema {
     constants: Vec<ConstDef>,
+    const_arrays: Vec<ConstArrayDef>,
     classes: Vec<ClassDef>,
     aliases: Vec<AliasDef>,
 }
@@ -50,6 +54,11 @@ impl SszSchema {
         &self.constants
     }
 
+    /// All constant arrays in the schema.
+    pub fn const_arrays(&self) -> &[ConstArrayDef] {
+        &self.const_arrays
+    }
+
     /// All classes in the schema.
     pub fn classes(&self) -> &[ClassDef] {
         &self.classes
@@ -77,6 +86,33 @@ impl ConstDef {
     }
 }
 
+/// Constant array definition, like `DOMAIN_TYPES = [0x00, 0x01, 0x02]` or
+/// `DOMAIN_BEACON = b"\x07\x00\x00\x00"`.
+#[derive(Clone, Debug)]
+pub struct ConstArrayDef {
+    name: Identifier,
+    values: Vec<u64>,
+    is_bytes: bool,
+}
+
+impl ConstArrayDef {
+    /// Name of the array.
+    pub fn name(&self) -> &Identifier {
+        &self.name
+    }
+
+    /// Evaluated elements of the array.
+    pub fn values(&self) -> &[u64] {
+        &self.values
+    }
+
+    /// Whether the array was written as a byte string or string literal, so
+    /// every value is a byte.
+    pub fn is_bytes(&self) -> bool {
+        self.is_bytes
+    }
+}
+
 /// Class definition.
 #[derive(Clone, Debug)]
 pub struct ClassDef {
@@ -86,6 +122,7 @@ pub struct ClassDef {
     doc_comment: Option<String>,
     pragmas: Vec<String>,
     fields: Vec<ClassFieldDef>,
+    span: SrcSpan,
 }
 
 impl ClassDef {
@@ -118,6 +155,44 @@ impl ClassDef {
     pub fn fields(&self) -> &[ClassFieldDef] {
         &self.fields
     }
+
+    /// Source span of the class, from the `class` keyword to the end of its
+    /// body.
+    pub fn span(&self) -> SrcSpan {
+        self.span
+    }
+
+    /// Canonical SSZ type string of the class's definition.
+    ///
+    /// Unions are shown as `Union[uint8, None, ...]` over their member types,
+    /// and every other class as `Container[name: type, ...]` over its
+    /// declared fields.
+    pub fn canonical_type_string(&self) -> String {
+        if self.parent_ty.base_name().0 == "Union" {
+            let members = self
+                .fields
+                .iter()
+                .map(|field| {
+                    field
+                        .ty()
+                        .map_or_else(|| "None".to_string(), canonical_type_string)
+                })
+                .collect::<Vec<_>>();
+            return format!("Union[{}]", members.join(", "));
+        }
+
+        let fields = self
+            .fields
+            .iter()
+            .map(|field| {
+                let ty = field
+                    .ty()
+                    .map_or_else(|| "None".to_string(), canonical_type_string);
+                format!("{}: {ty}", field.name().0)
+            })
+            .collect::<Vec<_>>();
+        format!("Container[{}]", fields.join(", "))
+    }
 }
 
 /// Class field definition.
@@ -170,6 +245,41 @@ impl AliasDef {
     }
 }
 
+/// Evaluates an integer expression: a literal, a constant name, or a binary
+/// operation on a constant.
+#[allow(clippy::result_large_err)]
+fn eval_int_expr(expr: &AssignExpr, resolver: &TypeResolver<'_>) -> Result<u64, SchemaError> {
+    let (op, ident, literal) = match expr {
+        AssignExpr::Value(val) => return Ok(val.eval()),
+        AssignExpr::Name(ident) => (None, ident, 0),
+        AssignExpr::SymbolicBinop(op, ident, literal) => (Some(*op), ident, *literal),
+        AssignExpr::Call(func, arg) => {
+            return Ok(builtins::eval_builtin_call(resolver, func, arg)?);
+        }
+        _ => panic!("schema: evaluating non-integer expression"),
+    };
+
+    // Look up the identifier's value
+    let base_value = match resolver.resolve_ident_with_args(ident, None)? {
+        TyExpr::Int(cv) => cv.eval(),
+        TyExpr::ConstRef(_, v) => v,
+        _ => {
+            return Err(SchemaError::Ty(ResolverError::MismatchedArgKind(
+                ident.clone(),
+            )));
+        }
+    };
+
+    // Compute result
+    Ok(match op {
+        None => base_value,
+        Some(Binop::Add) => base_value + literal,
+        Some(Binop::Sub) => base_value - literal,
+        Some(Binop::Mul) => base_value * literal,
+        Some(Binop::Shl) => base_value << literal,
+    })
+}
+
 /// Converts a AST module to a full schema.
 #[allow(clippy::result_large_err)]
 pub(crate) fn conv_module_to_schema<'a>(
@@ -182,6 +292,7 @@ pub(crate) fn conv_module_to_schema<'a>(
     // Do a first pass to prepare the type resolver and abort if there's any obvious duplicates.
     let mut idents = HashMap::new();
     let mut constants = Vec::new();
+    let mut const_arrays = Vec::new();
     let mut class_defs = Vec::new();
     let mut aliases = Vec::new();
     for d in m.entries() {
@@ -329,29 +440,10 @@ pub(crate) fn conv_module_to_schema<'a>(
                     })
                 }
 
-                // Symbolic binary operations (e.g., MAX_LEN + 1)
-                AssignExpr::SymbolicBinop(op, ident, literal) => {
-                    // Look up the identifier's value
-                    let resolved = resolver.resolve_ident_with_args(ident, None)?;
-                    let base_value = match resolved {
-                        TyExpr::Int(cv) => cv.eval(),
-                        TyExpr::ConstRef(_, v) => v,
-                        _ => {
-                            return Err(SchemaError::Ty(ResolverError::MismatchedArgKind(
-                                ident.clone(),
-                            )));
-                        }
-                    };
-
-                    // Compute result
-                    let result = match op {
-                        Binop::Add => base_value + literal,
-                        Binop::Sub => base_value - literal,
-                        Binop::Mul => base_value * literal,
-                        Binop::Shl => base_value << literal,
-                    };
-
-                    let const_value = ConstValue::Int(result);
+                // Symbolic binary operations (e.g., MAX_LEN + 1) and builtin calls
+                // (e.g., len(DOMAIN_BEACON))
+                AssignExpr::SymbolicBinop(_, _, _) | AssignExpr::Call(_, _) => {
+                    let const_value = ConstValue::Int(eval_int_expr(def.value(), &resolver)?);
                     resolver.decl_const(name.clone(), const_value.clone())?;
                     idents.insert(name.clone(), IdentTarget::Const(const_value.clone()));
                     constants.push(ConstDef {
@@ -359,6 +451,32 @@ pub(crate) fn conv_module_to_schema<'a>(
                         value: const_value,
                     })
                 }
+
+                // Constant arrays, folding each element to its value.
+                AssignExpr::Array(elems) => {
+                    let values = elems
+                        .iter()
+                        .map(|elem| eval_int_expr(elem, &resolver))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    resolver.decl_const_array(name.clone(), values.len())?;
+                    idents.insert(name.clone(), IdentTarget::ConstArray(values.len()));
+                    const_arrays.push(ConstArrayDef {
+                        name: name.clone(),
+                        values,
+                        is_bytes: false,
+                    })
+                }
+
+                // Byte strings, kept as arrays of their bytes.
+                AssignExpr::Bytes(bytes) => {
+                    resolver.decl_const_array(name.clone(), bytes.len())?;
+                    idents.insert(name.clone(), IdentTarget::ConstArray(bytes.len()));
+                    const_arrays.push(ConstArrayDef {
+                        name: name.clone(),
+                        values: bytes.iter().map(|byte| u64::from(*byte)).collect(),
+                        is_bytes: true,
+                    })
+                }
             },
             ModuleEntry::Class(def) => {
                 resolver.decl_user_type(name.clone())?;
@@ -374,19 +492,21 @@ pub(crate) fn conv_module_to_schema<'a>(
         classes.push(conv_classdef(d, &resolver)?);
     }
 
-    // Check for cycles.
+    // Check for cycles. A type may refer to itself through a `List`, whose
+    // elements are stored out of line, but not inline.
     let class_defs = classes
         .iter()
         .map(|d| (d.name(), d))
         .collect::<HashMap<_, _>>();
     for id in class_defs.keys() {
-        trace_type_for_cycles(id, id, &class_defs)?;
+        trace_type_for_cycles(id, id, &class_defs, &mut HashSet::new())?;
     }
 
     // Create a the final schema.
     let schema = SszSchema {
         classes,
         constants,
+        const_arrays,
         aliases,
     };
 
@@ -434,14 +554,16 @@ fn conv_classdef<'a>(
         doc_comment: def.doc_comment().map(|s| s.to_owned()),
         pragmas: def.pragmas().to_vec(),
         fields,
+        span: def.span(),
     })
 }
 
 #[allow(clippy::result_large_err)]
 fn trace_type_for_cycles<'d>(
-    ident: &Identifier,
+    ident: &'d Identifier,
     root: &Identifier,
     defs: &'d HashMap<&'d Identifier, &'d ClassDef>,
+    visited: &mut HashSet<&'d Identifier>,
 ) -> Result<(), SchemaError> {
     let Some(def) = defs.get(ident) else {
         // We know that there's no undefined identifiers by this point, so it
@@ -449,15 +571,27 @@ fn trace_type_for_cycles<'d>(
         return Ok(());
     };
 
+    // A cycle that doesn't pass through `root` is reported when tracing from
+    // one of its own members.
+    if !visited.insert(ident) {
+        return Ok(());
+    }
+
     for f in def.fields() {
         // Skip unit variants (no type)
         if let Some(ty) = f.ty() {
             for reffed_id in ty.iter_idents() {
                 if reffed_id == root {
+                    // A class may hold itself in a list, whose elements are
+                    // stored out of line. Any other way back to it, including
+                    // a list in another class, is a cycle.
+                    if ident == root && !ty.iter_inline_idents().any(|id| id == root) {
+                        continue;
+                    }
                     return Err(SchemaError::CyclicTypedefs(root.clone()));
                 }
 
-                trace_type_for_cycles(reffed_id, root, defs)?;
+                trace_type_for_cycles(reffed_id, root, defs, visited)?;
             }
         }
     }
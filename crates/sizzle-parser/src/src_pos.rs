@@ -169,4 +169,38 @@ impl SrcSpan {
     pub fn end(&self) -> SrcPos {
         self.end
     }
+
+    /// Renders the source line containing the start of the span, followed by
+    /// a line of carets underlining the spanned columns.
+    ///
+    /// Spans that continue past the first line are underlined to the end of
+    /// that line.  Line numbers in the gutter are 1-indexed.
+    pub fn render_snippet(&self, src: &str) -> String {
+        let line_idx = self.start.line() as usize;
+        let Some(line) = src.lines().nth(line_idx) else {
+            return String::new();
+        };
+
+        let line_len = line.chars().count();
+        let start_col = (self.start.col() as usize).min(line_len);
+        let end_col = if self.end.line() == self.start.line() {
+            (self.end.col() as usize).max(start_col)
+        } else {
+            line_len.saturating_sub(1).max(start_col)
+        };
+
+        // Keep tabs so the carets line up with the rendered source line.
+        let indent: String = line
+            .chars()
+            .take(start_col)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+
+        let gutter = (line_idx + 1).to_string();
+        let pad = " ".repeat(gutter.len());
+        format!(
+            "{pad} |\n{gutter} | {line}\n{pad} | {indent}{}",
+            "^".repeat(end_col - start_col + 1),
+        )
+    }
 }
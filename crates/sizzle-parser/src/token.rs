@@ -24,6 +24,8 @@ pub(crate) type SrcToken = TaggedToken<SrcPos>;
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum TaggedToken<T> {
     // Keywords and structural elements.
+    /// `from` keyword.
+    From(T),
     /// `import` keyword.
     Import(T),
     /// `as` keyword.
@@ -50,6 +52,9 @@ pub enum TaggedToken<T> {
     // Expressions.
     /// An integer literal.
     IntegerLiteral(T, u64),
+    /// A byte string literal, like `b"\x07\x00"`, or a string literal, like
+    /// `"beacon"`, as its UTF-8 bytes.
+    BytesLiteral(T, Vec<u8>),
     /// `<<` operator.
     Shl(T),
     /// `*` operator.
@@ -90,6 +95,7 @@ impl<T> TaggedToken<T> {
     /// Returns the tag on the token.
     pub fn tag(&self) -> &T {
         match self {
+            Self::From(t) => t,
             Self::Import(t) => t,
             Self::As(t) => t,
             Self::Class(t) => t,
@@ -100,6 +106,7 @@ impl<T> TaggedToken<T> {
             Self::Newline(t) => t,
             Self::Identifier(t, _) => t,
             Self::IntegerLiteral(t, _) => t,
+            Self::BytesLiteral(t, _) => t,
             Self::Shl(t) => t,
             Self::Mul(t) => t,
             Self::Add(t) => t,
@@ -121,6 +128,7 @@ impl<T> TaggedToken<T> {
     /// Converts the token to a untagged token.
     pub fn to_untagged(&self) -> Token {
         match self {
+            Self::From(_) => Token::From(()),
             Self::Import(_) => Token::Import(()),
             Self::As(_) => Token::As(()),
             Self::Class(_) => Token::Class(()),
@@ -131,6 +139,7 @@ impl<T> TaggedToken<T> {
             Self::Newline(_) => Token::Newline(()),
             Self::Identifier(_, ident) => Token::Identifier((), ident.clone()),
             Self::IntegerLiteral(_, v) => Token::IntegerLiteral((), *v),
+            Self::BytesLiteral(_, bytes) => Token::BytesLiteral((), bytes.clone()),
             Self::Shl(_) => Token::Shl(()),
             Self::Mul(_) => Token::Mul(()),
             Self::Add(_) => Token::Add(()),
@@ -167,6 +176,9 @@ pub enum TokenError {
     #[error("invalid integer '{0}'")]
     InvalidInt(String),
 
+    #[error("invalid escape in string literal at pos {0}")]
+    InvalidEscape(usize),
+
     #[error("invalid name: {0}")]
     InvalidName(#[from] NameError),
 }
@@ -246,6 +258,16 @@ impl TokenSeqBuilder {
         self.output.push(t);
     }
 
+    /// Whether the next token starts a statement at the top level, where
+    /// imports are.
+    fn at_top_level_statement_start(&self) -> bool {
+        self.indent_level == 0
+            && matches!(
+                self.output.last(),
+                None | Some(TaggedToken::Newline(_) | TaggedToken::Deindent(_))
+            )
+    }
+
     /// Updates the indentation level, producing indent tokens as necessary.
     fn update_indent_level(&mut self, level: usize, sp: SrcPos) {
         let diff = level as isize - self.indent_level as isize;
@@ -378,12 +400,24 @@ pub(crate) fn parse_char_array_to_tokens(s: &[char]) -> Result<Vec<SrcToken>, To
                 }
             }
 
+            // Byte string literal, like `b"\x07\x00"`.
+            'b' if next == Some('"') => {
+                let (bytes, j) = parse_string_literal(s, i + 2, true)?;
+                builder.push_token(SrcToken::BytesLiteral(sp, bytes));
+                i = j;
+                continue;
+            }
+
             c if is_valid_ident_initial_char(c) => {
                 let j = find_satisfying_range(s, i + 1, is_valid_ident_continuing_char);
                 let s = s[i..j].iter().collect::<String>();
 
-                // Keywords are like identifiers, but separated out.
-                if let Some(kwtok) = try_parse_keyword(&s, sp) {
+                // Keywords are like identifiers, but separated out.  `from`
+                // only starts imports, so elsewhere it can name a field.
+                let kwtok = try_parse_keyword(&s, sp).filter(|kwtok| {
+                    !matches!(kwtok, SrcToken::From(_)) || builder.at_top_level_statement_start()
+                });
+                if let Some(kwtok) = kwtok {
                     builder.push_token(kwtok);
                 } else {
                     let ident = Identifier::try_from(s)?;
@@ -394,6 +428,17 @@ pub(crate) fn parse_char_array_to_tokens(s: &[char]) -> Result<Vec<SrcToken>, To
                 continue;
             }
 
+            // Hex integer literal, like `0xff`.
+            '0' if next == Some('x') => {
+                let j = find_satisfying_range(s, i + 2, |c| c.is_ascii_hexdigit());
+                let arr = s[i..j].iter().collect::<String>();
+                let v = u64::from_str_radix(&arr[2..], 16)
+                    .map_err(|_| TokenError::InvalidInt(arr.clone()))?;
+                builder.push_token(SrcToken::IntegerLiteral(sp, v));
+                i = j;
+                continue;
+            }
+
             c if c.is_numeric() => {
                 let j = find_satisfying_range(s, i + 1, char::is_numeric);
                 let arr = s[i..j].iter().collect::<String>();
@@ -486,8 +531,11 @@ pub(crate) fn parse_char_array_to_tokens(s: &[char]) -> Result<Vec<SrcToken>, To
                     i = j + 3;
                     continue;
                 } else {
-                    // Single quote - not supported for docstrings, only """
-                    return Err(TokenError::UnexpectedChar('"', i));
+                    // String literal, like `"beacon"`.
+                    let (bytes, j) = parse_string_literal(s, i + 1, false)?;
+                    builder.push_token(SrcToken::BytesLiteral(sp, bytes));
+                    i = j;
+                    continue;
                 }
             }
 
@@ -500,8 +548,67 @@ pub(crate) fn parse_char_array_to_tokens(s: &[char]) -> Result<Vec<SrcToken>, To
     builder.finish(sp_tbl.expect_end())
 }
 
+/// Parses the body of a string literal starting at `start`, just after the
+/// opening quote, up to the closing quote.  Returns the literal's bytes and the
+/// index just after the closing quote.
+///
+/// Supports the `\\`, `\"`, `\n`, `\t`, `\0` and `\xNN` escapes.  Byte string
+/// literals may only contain ASCII characters; string literals are encoded as
+/// UTF-8.
+fn parse_string_literal(
+    s: &[char],
+    start: usize,
+    is_bytes: bool,
+) -> Result<(Vec<u8>, usize), TokenError> {
+    let mut bytes = Vec::new();
+    let mut i = start;
+
+    loop {
+        let Some(&c) = s.get(i) else {
+            return Err(TokenError::UnexpectedEnd);
+        };
+
+        match c {
+            '"' => return Ok((bytes, i + 1)),
+            '\n' => return Err(TokenError::UnexpectedChar(c, i)),
+            '\\' => {
+                let escaped = match s.get(i + 1) {
+                    Some('\\') => b'\\',
+                    Some('"') => b'"',
+                    Some('n') => b'\n',
+                    Some('t') => b'\t',
+                    Some('0') => 0,
+                    Some('x') => {
+                        let hex = s
+                            .get(i + 2..i + 4)
+                            .ok_or(TokenError::UnexpectedEnd)?
+                            .iter()
+                            .collect::<String>();
+                        let v = u8::from_str_radix(&hex, 16)
+                            .map_err(|_| TokenError::InvalidEscape(i))?;
+                        bytes.push(v);
+                        i += 4;
+                        continue;
+                    }
+                    Some(_) => return Err(TokenError::InvalidEscape(i)),
+                    None => return Err(TokenError::UnexpectedEnd),
+                };
+                bytes.push(escaped);
+                i += 2;
+            }
+            c if is_bytes && !c.is_ascii() => return Err(TokenError::UnexpectedChar(c, i)),
+            c => {
+                let mut buf = [0; 4];
+                bytes.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+                i += 1;
+            }
+        }
+    }
+}
+
 fn try_parse_keyword(s: &str, sp: SrcPos) -> Option<SrcToken> {
     Some(match s {
+        "from" => SrcToken::From(sp),
         "import" => SrcToken::Import(sp),
         "as" => SrcToken::As(sp),
         "class" => SrcToken::Class(sp),
@@ -528,7 +635,7 @@ fn find_satisfying_range(arr: &[char], start: usize, cond: impl Fn(char) -> bool
 
 #[cfg(test)]
 mod tests {
-    use super::{TokenSeqBuilder, parse_char_array_to_tokens};
+    use super::{SrcToken, TokenSeqBuilder, parse_char_array_to_tokens};
 
     #[test]
     fn test_whitespace_spaces() {
@@ -609,6 +716,55 @@ mod tests {
         eprintln!("{toks:#?}");
     }
 
+    #[test]
+    fn test_parse_hex() {
+        let s = "FOO = 0x1f";
+
+        let chars = s.chars().collect::<Vec<_>>();
+
+        let toks =
+            parse_char_array_to_tokens(&chars).expect("test: invoke parse_char_array_to_tokens");
+
+        assert!(matches!(
+            toks.last(),
+            Some(SrcToken::IntegerLiteral(_, 0x1f))
+        ));
+        parse_char_array_to_tokens(&"FOO = 0x".chars().collect::<Vec<_>>())
+            .expect_err("test: hex literal without digits");
+    }
+
+    #[test]
+    fn test_parse_string_literals() {
+        let s = r#"A = b"\x07\x00\\\"" B = "beac\xff""#;
+
+        let chars = s.chars().collect::<Vec<_>>();
+
+        let toks =
+            parse_char_array_to_tokens(&chars).expect("test: invoke parse_char_array_to_tokens");
+
+        let literals = toks
+            .iter()
+            .filter_map(|tok| match tok {
+                SrcToken::BytesLiteral(_, bytes) => Some(bytes.clone()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            literals,
+            vec![vec![0x07, 0x00, b'\\', b'"'], b"beac\xff".to_vec()]
+        );
+
+        for bad in [
+            r#"A = "open"#,
+            r#"A = "\q""#,
+            r#"A = "\x0""#,
+            "A = b\"\u{e9}\"",
+        ] {
+            parse_char_array_to_tokens(&bad.chars().collect::<Vec<_>>())
+                .expect_err("test: malformed string literal");
+        }
+    }
+
     #[test]
     fn test_parse_container_def() {
         let s = "class Point(Container):\n  x_pos: int32\n  y_pos: int32\n";
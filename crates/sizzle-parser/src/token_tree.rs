@@ -70,6 +70,8 @@ pub(crate) type SrcToktr = TaggedToktr<SrcPos>;
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum TaggedToktr<T> {
     // Keywords and structural elements.
+    /// `from` keyword.
+    From(T),
     /// `import` keyword.
     Import(T),
     /// `as` keyword.
@@ -96,6 +98,8 @@ pub enum TaggedToktr<T> {
     // Expressions.
     /// An integer literal.
     IntegerLiteral(T, u64),
+    /// A byte string or string literal, as its bytes.
+    BytesLiteral(T, Vec<u8>),
     /// `<<` operator.
     Shl(T),
     /// `*` operator.
@@ -130,6 +134,7 @@ impl<T> TaggedToktr<T> {
     /// Gets the tag of the token.
     pub fn tag(&self) -> &T {
         match self {
+            Self::From(t) => t,
             Self::Import(t) => t,
             Self::As(t) => t,
             Self::Class(t) => t,
@@ -141,6 +146,7 @@ impl<T> TaggedToktr<T> {
             Self::Null(t) => t,
             Self::Identifier(t, _) => t,
             Self::IntegerLiteral(t, _) => t,
+            Self::BytesLiteral(t, _) => t,
             Self::Shl(t) => t,
             Self::Mul(t) => t,
             Self::Add(t) => t,
@@ -290,6 +296,7 @@ pub(crate) fn parse_tokens_to_toktrs(tokens: &[SrcToken]) -> Result<Vec<SrcToktr
         let tt = match cur {
             TaggedToken::Null(sp) => TaggedToktr::Null(*sp),
             TaggedToken::Class(sp) => TaggedToktr::Class(*sp),
+            TaggedToken::From(sp) => TaggedToktr::From(*sp),
             TaggedToken::Import(sp) => TaggedToktr::Import(*sp),
             TaggedToken::As(sp) => TaggedToktr::As(*sp),
             TaggedToken::Colon(sp) => TaggedToktr::Colon(*sp),
@@ -299,6 +306,7 @@ pub(crate) fn parse_tokens_to_toktrs(tokens: &[SrcToken]) -> Result<Vec<SrcToktr
             TaggedToken::Newline(sp) => TaggedToktr::Newline(*sp),
             TaggedToken::Identifier(sp, ident) => TaggedToktr::Identifier(*sp, ident.clone()),
             TaggedToken::IntegerLiteral(sp, v) => TaggedToktr::IntegerLiteral(*sp, *v),
+            TaggedToken::BytesLiteral(sp, bytes) => TaggedToktr::BytesLiteral(*sp, bytes.clone()),
             TaggedToken::Shl(sp) => TaggedToktr::Shl(*sp),
             TaggedToken::Mul(sp) => TaggedToktr::Mul(*sp),
             TaggedToken::Add(sp) => TaggedToktr::Add(*sp),
@@ -40,6 +40,12 @@ pub enum ResolverError {
 
     #[error("tried to redeclare identifier '{0:?}'")]
     RedeclareIdentifier(Identifier),
+
+    #[error("unknown function '{0:?}'")]
+    UnknownFunction(Identifier),
+
+    #[error("took length of unsized value '{0:?}'")]
+    Unsized(Identifier),
 }
 
 /// Describes information for a concrete type.
@@ -99,6 +105,8 @@ pub enum CtorArg {
 #[derive(Clone, Debug)]
 pub(crate) enum IdentTarget {
     Const(ConstValue),
+    /// A constant array or byte string, with its length.
+    ConstArray(usize),
     Ty(TypeData),
     TyCtor(TypeCtorData),
 }
@@ -236,6 +244,20 @@ impl<'a> TypeResolver<'a> {
         Ok(())
     }
 
+    /// Declares a constant array of `len` elements.
+    ///
+    /// Constant arrays can't be used as type arguments, this only reserves
+    /// the name and records the length for `len()`.
+    pub(crate) fn decl_const_array(
+        &mut self,
+        ident: Identifier,
+        len: usize,
+    ) -> Result<(), ResolverError> {
+        self.check_name_unused(&ident)?;
+        self.idents.insert(ident, IdentTarget::ConstArray(len));
+        Ok(())
+    }
+
     /// Gets an identifier if it's a type.
     pub(crate) fn _get_ident_as_ty(&self, ident: &Identifier) -> Result<&TypeData, ResolverError> {
         match self
@@ -335,6 +357,8 @@ impl<'a> TypeResolver<'a> {
                 }
             }
 
+            IdentTarget::ConstArray(_) => Err(ResolverError::MismatchedArgKind(ident.clone())),
+
             IdentTarget::Ty(_td) => {
                 if args.is_none() {
                     Ok(TyExpr::Ty(Ty::Simple(ident.clone())))
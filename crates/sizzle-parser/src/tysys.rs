@@ -53,6 +53,19 @@ impl TyExpr {
 
         idents.into_iter()
     }
+
+    /// Iterate over the identifiers of the types stored inline in a value of
+    /// this type, i.e. skipping the element types of `List`s, which are
+    /// stored out of line.
+    pub fn iter_inline_idents(&self) -> impl Iterator<Item = &Identifier> {
+        let idents = match self {
+            TyExpr::Ty(t) => t.iter_inline_idents().collect::<Vec<_>>(),
+            TyExpr::ConstRef(id, _) => vec![id],
+            TyExpr::Int(_) | TyExpr::None => Vec::new(),
+        };
+
+        idents.into_iter()
+    }
 }
 
 /// A type expression.
@@ -96,6 +109,20 @@ impl Ty {
 
         std::iter::once(bn).chain(ext.iter().flat_map(|e| e.iter_idents()))
     }
+
+    /// Iterate over the identifiers of the types stored inline in a value of
+    /// this type. See [`TyExpr::iter_inline_idents`].
+    pub fn iter_inline_idents(&self) -> impl Iterator<Item = &Identifier> {
+        let bn = self.base_name();
+
+        let ext = match self {
+            Ty::Complex(name, _) if name.0 == "List" => &[],
+            Ty::Imported(_, _, _) | Ty::Simple(_) => &[],
+            Ty::ImportedComplex(_, _, _, ch) | Ty::Complex(_, ch) => ch.as_slice(),
+        };
+
+        std::iter::once(bn).chain(ext.iter().flat_map(|e| e.iter_inline_idents()))
+    }
 }
 
 /// A constant value.
@@ -139,3 +166,146 @@ impl ConstValue {
         }
     }
 }
+
+/// Returns the SSZ spec's canonical name for a resolved type, e.g.
+/// `List[uint64, 1024]` or `Vector[byte, 32]`.
+///
+/// Builtin shorthands are already expanded, so `Bytes32` is shown as
+/// `Vector[byte, 32]`, and `uint8` elements of vectors and lists are shown as
+/// `byte`, as the spec does. Schema aliases and classes are shown by name; see
+/// [`ClassDef::canonical_type_string`](crate::ClassDef::canonical_type_string)
+/// for class definitions.
+pub fn canonical_type_string(ty: &Ty) -> String {
+    match ty {
+        Ty::Simple(name) | Ty::Imported(_, _, name) => name.0.clone(),
+        Ty::Complex(name, args) | Ty::ImportedComplex(_, _, name, args) => {
+            let byte_elements = matches!(name.0.as_str(), "Vector" | "List");
+            let args = args
+                .iter()
+                .enumerate()
+                .map(|(i, arg)| match arg {
+                    TyExpr::Ty(Ty::Simple(elem))
+                        if i == 0 && byte_elements && elem.0 == "uint8" =>
+                    {
+                        "byte".to_string()
+                    }
+                    arg => canonical_ty_expr_string(arg),
+                })
+                .collect::<Vec<_>>();
+            format!("{}[{}]", name.0, args.join(", "))
+        }
+    }
+}
+
+/// Returns the canonical name of a type argument: a type, `None`, or an
+/// integer, with constants replaced by their value.
+pub fn canonical_ty_expr_string(expr: &TyExpr) -> String {
+    match expr {
+        TyExpr::None => "None".to_string(),
+        TyExpr::Ty(ty) => canonical_type_string(ty),
+        TyExpr::Int(value) => value.eval().to_string(),
+        TyExpr::ConstRef(_, value) => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, path::Path};
+
+    use crate::{SszSchema, parse_str_schema};
+
+    fn schema(schema: &str) -> SszSchema {
+        let files = HashMap::from([(Path::new("test.ssz").to_path_buf(), schema.to_string())]);
+        let (_, mut schemas) = parse_str_schema(&files, &[]).expect("test: parse schema");
+        schemas.remove(Path::new("test.ssz")).expect("test: schema")
+    }
+
+    fn class_string(schema: &SszSchema, name: &str) -> String {
+        schema
+            .classes()
+            .iter()
+            .find(|class| class.name().0 == name)
+            .expect("test: class")
+            .canonical_type_string()
+    }
+
+    fn field_strings(schema: &SszSchema, name: &str) -> Vec<String> {
+        schema
+            .classes()
+            .iter()
+            .find(|class| class.name().0 == name)
+            .expect("test: class")
+            .fields()
+            .iter()
+            .map(|field| super::canonical_type_string(field.ty().expect("test: field type")))
+            .collect()
+    }
+
+    const SCHEMA: &str = r"
+MAX_VALIDATORS = 1024
+
+Root = Bytes32
+
+class Inner(Container):
+    a: uint16
+
+class Sample(Container):
+    balances: List[uint64, MAX_VALIDATORS]
+    root: Bytes32
+    alias: Root
+    raw: Vector[byte, 48]
+    nested: List[Vector[uint8, 4], 16]
+    flags: Bitlist[2048]
+    bits: Bitvector[8]
+    inner: Inner
+    inners: List[Inner, 8]
+    maybe: Union[null, Root]
+
+class Choice(Union):
+    Empty
+    Small: uint8
+    Items: List[uint32, 4]
+";
+
+    #[test]
+    fn test_field_canonical_strings() {
+        let schema = schema(SCHEMA);
+        assert_eq!(
+            field_strings(&schema, "Sample"),
+            [
+                "List[uint64, 1024]",
+                "Vector[byte, 32]",
+                "Root",
+                "Vector[byte, 48]",
+                "List[Vector[byte, 4], 16]",
+                "Bitlist[2048]",
+                "Bitvector[8]",
+                "Inner",
+                "List[Inner, 8]",
+                "Union[None, Root]",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_container_canonical_string() {
+        let schema = schema(SCHEMA);
+        assert_eq!(class_string(&schema, "Inner"), "Container[a: uint16]");
+        assert_eq!(
+            class_string(&schema, "Sample"),
+            "Container[balances: List[uint64, 1024], root: Vector[byte, 32], alias: Root, \
+             raw: Vector[byte, 48], nested: List[Vector[byte, 4], 16], flags: Bitlist[2048], \
+             bits: Bitvector[8], inner: Inner, inners: List[Inner, 8], \
+             maybe: Union[None, Root]]"
+        );
+    }
+
+    #[test]
+    fn test_union_canonical_string() {
+        let schema = schema(SCHEMA);
+        assert_eq!(
+            class_string(&schema, "Choice"),
+            "Union[None, uint8, List[uint32, 4]]"
+        );
+    }
+}
@@ -16,6 +16,8 @@ use ssz::{Decode, Encode};
 use ssz_derive as _;
 use ssz_primitives as _;
 use thiserror as _;
+#[cfg(feature = "tokio")]
+use tokio as _;
 
 fn main() {
     let vec: Vec<u64> = vec![4242; 8196];
@@ -16,6 +16,8 @@ use ssz::{Decode, Encode};
 use ssz_derive::{Decode, Encode};
 use ssz_primitives as _;
 use thiserror as _;
+#[cfg(feature = "tokio")]
+use tokio as _;
 
 /// A struct with 4 fixed length fields
 #[derive(Clone, Copy, Encode, Decode, Debug)]
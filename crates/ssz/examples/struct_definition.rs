@@ -14,6 +14,8 @@ use ssz::{Decode, DecodeError, Encode, SszDecoderBuilder, SszEncoder};
 use ssz_derive as _;
 use ssz_primitives as _;
 use thiserror as _;
+#[cfg(feature = "tokio")]
+use tokio as _;
 
 /// A struct with 3 fields
 #[derive(Debug, PartialEq)]
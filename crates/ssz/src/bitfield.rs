@@ -3,6 +3,11 @@
 
 //! Bitfield implementation
 
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
 use core::marker::PhantomData;
 
 #[cfg(feature = "serde")]
@@ -250,7 +255,7 @@ impl<const N: usize> Bitfield<Variable<N>> {
     ///
     /// Return a new BitList with length equal to the shorter of the two inputs.
     pub fn intersection(&self, other: &Self) -> Self {
-        let min_len = std::cmp::min(self.len(), other.len());
+        let min_len = core::cmp::min(self.len(), other.len());
         let mut result = Self::with_capacity(min_len).expect("min len always less than N");
         // Bitwise-and the bytes together, starting from the left of each vector. This takes care
         // of masking out any entries beyond `min_len` as well, assuming the bitfield doesn't
@@ -265,7 +270,7 @@ impl<const N: usize> Bitfield<Variable<N>> {
     ///
     /// Return a new BitList with length equal to the longer of the two inputs.
     pub fn union(&self, other: &Self) -> Self {
-        let max_len = std::cmp::max(self.len(), other.len());
+        let max_len = core::cmp::max(self.len(), other.len());
         let mut result = Self::with_capacity(max_len).expect("max len always less than N");
         for i in 0..result.bytes.len() {
             result.bytes[i] =
@@ -372,8 +377,8 @@ impl<const N: usize> Bitfield<Fixed<N>> {
     }
 }
 
-impl<const N: usize> std::fmt::Display for Bitfield<Fixed<N>> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<const N: usize> core::fmt::Display for Bitfield<Fixed<N>> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let mut field: String = "".to_string();
         for i in self.iter() {
             if i { field.push('1') } else { field.push('0') }
@@ -517,10 +522,22 @@ impl<T: BitfieldBehaviour> Bitfield<T> {
 
     /// Returns the number of bits that are set to `true`.
     pub fn num_set_bits(&self) -> usize {
-        self.bytes
-            .iter()
-            .map(|byte| byte.count_ones() as usize)
-            .sum()
+        self.count_ones()
+    }
+
+    /// Returns the number of bits that are set to `true`.
+    ///
+    /// Counts eight bytes at a time with a single popcount.
+    pub fn count_ones(&self) -> usize {
+        count_ones(&self.bytes)
+    }
+
+    /// Returns the number of bits below `self.len()` that are set to `false`.
+    ///
+    /// For a `BitList` this counts up to its length, not its capacity.
+    pub fn count_zeros(&self) -> usize {
+        // Bits at or above `len` are always unset, so they never count as ones.
+        self.len - self.count_ones()
     }
 
     /// Compute the difference of this Bitfield and another of potentially different length.
@@ -532,7 +549,7 @@ impl<T: BitfieldBehaviour> Bitfield<T> {
 
     /// Compute the difference of this Bitfield and another of potentially different length.
     pub fn difference_inplace(&mut self, other: &Self) {
-        let min_byte_len = std::cmp::min(self.bytes.len(), other.bytes.len());
+        let min_byte_len = core::cmp::min(self.bytes.len(), other.bytes.len());
 
         for i in 0..min_byte_len {
             self.bytes[i] &= !other.bytes[i];
@@ -560,6 +577,62 @@ impl<T: BitfieldBehaviour> Bitfield<T> {
             })
         }
     }
+
+    /// Shift the bits to lower indices, filling the higher indices with zeroes.
+    ///
+    /// The amount to shift by, `n`, must be less than or equal to `self.len()`.
+    pub fn shift_down(&mut self, n: usize) -> Result<(), Error> {
+        if n > self.len() {
+            return Err(Error::OutOfBounds {
+                i: n,
+                len: self.len(),
+            });
+        }
+
+        let (byte_shift, bit_shift) = (n / 8, n % 8);
+        for i in 0..self.bytes.len() {
+            let low = self.bytes.get(i + byte_shift).copied().unwrap_or(0);
+            let high = self.bytes.get(i + byte_shift + 1).copied().unwrap_or(0);
+            // Bits at or above `len` are always unset, so nothing but zeroes
+            // moves into the top of the bitfield.
+            self.bytes[i] = if bit_shift == 0 {
+                low
+            } else {
+                (low >> bit_shift) | (high << (8 - bit_shift))
+            };
+        }
+        Ok(())
+    }
+
+    /// Rotate the bits `n` places to higher indices, moving the highest bits
+    /// around to the lowest indices.
+    ///
+    /// `n` may exceed `self.len()`, in which case it wraps around.
+    pub fn rotate_up(&mut self, n: usize) {
+        if self.len == 0 {
+            return;
+        }
+        let n = n % self.len;
+        let mut wrapped = self.clone();
+        self.shift_up(n).expect("n is below len");
+        wrapped
+            .shift_down(self.len - n)
+            .expect("len - n is at most len");
+        for (byte, wrapped) in self.bytes.iter_mut().zip(&wrapped.bytes) {
+            *byte |= wrapped;
+        }
+    }
+
+    /// Rotate the bits `n` places to lower indices, moving the lowest bits
+    /// around to the highest indices.
+    ///
+    /// `n` may exceed `self.len()`, in which case it wraps around.
+    pub fn rotate_down(&mut self, n: usize) {
+        if self.len == 0 {
+            return;
+        }
+        self.rotate_up(self.len - n % self.len);
+    }
 }
 
 impl<T> Eq for Bitfield<T> {}
@@ -578,11 +651,24 @@ impl<T> core::hash::Hash for Bitfield<T> {
     }
 }
 
+/// Returns the number of set bits in `bytes`, popcounting eight bytes at a time.
+pub(crate) fn count_ones(bytes: &[u8]) -> usize {
+    let words = bytes.chunks_exact(8);
+    let tail = words.remainder();
+    let word_ones: usize = words
+        .map(|word| {
+            u64::from_le_bytes(word.try_into().expect("chunk is 8 bytes")).count_ones() as usize
+        })
+        .sum();
+    let tail_ones: usize = tail.iter().map(|byte| byte.count_ones() as usize).sum();
+    word_ones + tail_ones
+}
+
 /// Returns the minimum required bytes to represent a given number of bits.
 ///
 /// `bit_len == 0` requires a single byte.
 fn bytes_for_bit_len(bit_len: usize) -> usize {
-    std::cmp::max(1, bit_len.div_ceil(8))
+    core::cmp::max(1, bit_len.div_ceil(8))
 }
 
 /// An iterator over the bits in a `Bitfield`.
@@ -596,12 +682,22 @@ impl<T: BitfieldBehaviour> Iterator for BitIter<'_, T> {
     type Item = bool;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let res = self.bitfield.get(self.i).ok()?;
+        if self.i >= self.bitfield.len {
+            return None;
+        }
+        let res = self.bitfield.bytes[self.i / 8] & (1 << (self.i % 8)) != 0;
         self.i += 1;
         Some(res)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.bitfield.len - self.i;
+        (remaining, Some(remaining))
+    }
 }
 
+impl<T: BitfieldBehaviour> ExactSizeIterator for BitIter<'_, T> {}
+
 impl<const N: usize> Encode for Bitfield<Variable<N>> {
     fn is_ssz_fixed_len() -> bool {
         false
@@ -713,22 +809,30 @@ impl<'de, const N: usize> Deserialize<'de> for Bitfield<Fixed<N>> {
 #[cfg(feature = "arbitrary")]
 impl<const N: usize> arbitrary::Arbitrary<'_> for Bitfield<Fixed<N>> {
     fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Self> {
-        let size = N;
-        let mut vec = smallvec![0u8; size];
-        u.fill_buffer(&mut vec)?;
-        Self::from_bytes(vec).map_err(|_| arbitrary::Error::IncorrectFormat)
+        let mut bitfield = Self::new();
+        for i in 0..N {
+            bitfield
+                .set(i, bool::arbitrary(u)?)
+                .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+        }
+        Ok(bitfield)
     }
 }
 
+/// Picks a length in `0..=N` and fills it with arbitrary bits, so the result always fits the
+/// bitlist's capacity.
 #[cfg(feature = "arbitrary")]
 impl<const N: usize> arbitrary::Arbitrary<'_> for Bitfield<Variable<N>> {
     fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Self> {
-        let max_size = N;
-        let rand = usize::arbitrary(u)?;
-        let size = std::cmp::min(rand, max_size);
-        let mut vec = smallvec![0u8; size];
-        u.fill_buffer(&mut vec)?;
-        Self::from_bytes(vec).map_err(|_| arbitrary::Error::IncorrectFormat)
+        let len = u.int_in_range(0..=N)?;
+        let mut bitfield =
+            Self::with_capacity(len).map_err(|_| arbitrary::Error::IncorrectFormat)?;
+        for i in 0..len {
+            bitfield
+                .set(i, bool::arbitrary(u)?)
+                .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+        }
+        Ok(bitfield)
     }
 }
 
@@ -906,7 +1010,7 @@ mod bitvector {
         assert_round_trip(b);
     }
 
-    fn assert_round_trip<T: Encode + Decode + PartialEq + std::fmt::Debug>(t: T) {
+    fn assert_round_trip<T: Encode + Decode + PartialEq + core::fmt::Debug>(t: T) {
         assert_eq!(T::from_ssz_bytes(&t.as_ssz_bytes()).unwrap(), t);
     }
 
@@ -929,10 +1033,131 @@ mod bitvector {
         assert!(BitVector4::from_ssz_bytes(&bad).is_err());
     }
 
+    #[test]
+    fn count_ones_and_zeros() {
+        let mut bitfield = BitVector64::new();
+        for i in [1, 8, 62, 63] {
+            bitfield.set(i, true).unwrap();
+        }
+
+        assert_eq!(bitfield.count_ones(), 4);
+        assert_eq!(bitfield.count_zeros(), 60);
+        assert_eq!(bitfield.iter().filter(|bit| *bit).count(), 4);
+        assert_eq!(BitVector4::new().count_zeros(), 4);
+    }
+
+    fn bitvector12(set: &[usize]) -> BitVector<12> {
+        let mut bitfield = BitVector::<12>::new();
+        for &i in set {
+            bitfield.set(i, true).unwrap();
+        }
+        bitfield
+    }
+
+    fn set_bits<const N: usize>(bitfield: &BitVector<N>) -> Vec<usize> {
+        bitfield
+            .iter()
+            .enumerate()
+            .filter_map(|(i, bit)| bit.then_some(i))
+            .collect()
+    }
+
+    /// The four bits above index 11 pad the last byte and must stay unset.
+    fn assert_padding_zero(bitfield: &BitVector<12>) {
+        assert_eq!(bitfield.as_slice()[1] & 0xf0, 0);
+    }
+
+    #[test]
+    fn shift_across_byte_boundaries() {
+        let original = bitvector12(&[0, 5, 6, 7, 11]);
+
+        let mut up = original.clone();
+        up.shift_up(3).unwrap();
+        assert_eq!(set_bits(&up), [3, 8, 9, 10]);
+        assert_padding_zero(&up);
+
+        let mut up = original.clone();
+        up.shift_up(8).unwrap();
+        assert_eq!(set_bits(&up), [8]);
+        assert_padding_zero(&up);
+
+        let mut down = original.clone();
+        down.shift_down(3).unwrap();
+        assert_eq!(set_bits(&down), [2, 3, 4, 8]);
+        assert_padding_zero(&down);
+
+        let mut down = original.clone();
+        down.shift_down(8).unwrap();
+        assert_eq!(set_bits(&down), [3]);
+        assert_padding_zero(&down);
+
+        let mut all = original.clone();
+        all.shift_down(12).unwrap();
+        assert!(all.is_zero());
+        assert!(all.clone().shift_down(13).is_err());
+    }
+
+    #[test]
+    fn rotate_across_byte_boundaries() {
+        let original = bitvector12(&[0, 5, 6, 7, 11]);
+
+        let mut up = original.clone();
+        up.rotate_up(3);
+        assert_eq!(set_bits(&up), [2, 3, 8, 9, 10]);
+        assert_padding_zero(&up);
+
+        let mut up = original.clone();
+        up.rotate_up(8);
+        assert_eq!(set_bits(&up), [1, 2, 3, 7, 8]);
+        assert_padding_zero(&up);
+
+        let mut down = original.clone();
+        down.rotate_down(3);
+        assert_eq!(set_bits(&down), [2, 3, 4, 8, 9]);
+        assert_padding_zero(&down);
+        down.rotate_up(3);
+        assert_eq!(down, original);
+
+        let mut wrapped = original.clone();
+        wrapped.rotate_up(12 + 3);
+        assert_eq!(set_bits(&wrapped), [2, 3, 8, 9, 10]);
+
+        let mut empty = BitVector0::new();
+        empty.rotate_up(5);
+        assert!(empty.is_empty());
+    }
+
     // Ensure that stack size of a BitVector is manageable.
     #[test]
     fn size_of() {
-        assert_eq!(std::mem::size_of::<BitVector64>(), SMALLVEC_LEN + 24);
+        assert_eq!(core::mem::size_of::<BitVector64>(), SMALLVEC_LEN + 24);
+    }
+
+    // Pins the spec layout: bit `i` is bit `i % 8` (least significant first) of byte `i / 8`,
+    // and the padding bits of the last byte are zero.
+    #[test]
+    fn wire_layout() {
+        let mut b = BitVector::<9>::new();
+        b.set(0, true).unwrap();
+        b.set(3, true).unwrap();
+        b.set(8, true).unwrap();
+        assert_eq!(b.as_ssz_bytes(), vec![0b0000_1001, 0b0000_0001]);
+        assert_eq!(
+            BitVector::<9>::from_ssz_bytes(&[0b0000_1001, 0b0000_0001]),
+            Ok(b)
+        );
+
+        // Only bit 8 fits in the second byte.
+        assert!(BitVector::<9>::from_ssz_bytes(&[0, 0b0000_0010]).is_err());
+
+        let bytes = [0b0000_1001, 0b0000_0001];
+        let view: crate::view::BitVectorRef<'_, 9> =
+            crate::view::DecodeView::from_ssz_bytes(&bytes).unwrap();
+        let bits: Vec<bool> = (0..9).map(|i| view.get(i).unwrap()).collect();
+        assert_eq!(
+            bits,
+            [true, false, false, true, false, false, false, false, true]
+        );
     }
 }
 
@@ -1081,7 +1306,7 @@ mod bitlist {
         }
     }
 
-    fn assert_round_trip<T: Encode + Decode + PartialEq + std::fmt::Debug>(t: T) {
+    fn assert_round_trip<T: Encode + Decode + PartialEq + core::fmt::Debug>(t: T) {
         assert_eq!(T::from_ssz_bytes(&t.as_ssz_bytes()).unwrap(), t);
     }
 
@@ -1432,6 +1657,58 @@ mod bitlist {
         );
     }
 
+    #[test]
+    fn count_ones_and_zeros() {
+        const SET: [usize; 7] = [0, 7, 63, 64, 1000, 1500, 1999];
+
+        // Logical length 2000 of a 2048-bit capacity, so the last word is partial.
+        let mut bitfield = BitList::<2048>::with_capacity(2000).unwrap();
+        for i in SET {
+            bitfield.set(i, true).unwrap();
+        }
+
+        assert_eq!(bitfield.count_ones(), SET.len());
+        assert_eq!(bitfield.count_zeros(), 2000 - SET.len());
+        assert_eq!(bitfield.num_set_bits(), SET.len());
+
+        let iterated: Vec<usize> = bitfield
+            .iter()
+            .enumerate()
+            .filter_map(|(i, bit)| bit.then_some(i))
+            .collect();
+        assert_eq!(iterated, SET);
+        assert_eq!(bitfield.iter().len(), 2000);
+
+        let empty = BitList::<2048>::with_capacity(0).unwrap();
+        assert_eq!(empty.count_ones(), 0);
+        assert_eq!(empty.count_zeros(), 0);
+    }
+
+    #[test]
+    fn shift_and_rotate_within_len() {
+        // Length 10 of a 16-bit capacity: shifts and rotations wrap at the
+        // length, and the six bits above it stay unset.
+        let mut bitfield = BitList16::with_capacity(10).unwrap();
+        for i in [0, 1, 9] {
+            bitfield.set(i, true).unwrap();
+        }
+
+        let mut down = bitfield.clone();
+        down.shift_down(1).unwrap();
+        assert_eq!(down.into_raw_bytes().as_slice(), [0b0000_0001, 0b0000_0001]);
+
+        let mut rotated = bitfield.clone();
+        rotated.rotate_up(1);
+        assert_eq!(
+            rotated.clone().into_raw_bytes().as_slice(),
+            [0b0000_0111, 0b0000_0000]
+        );
+        assert_eq!(rotated.as_ssz_bytes(), [0b0000_0111, 0b0000_0100]);
+
+        rotated.rotate_down(1);
+        assert_eq!(rotated, bitfield);
+    }
+
     #[test]
     fn ssz_bytes_len() {
         for i in 1..=64 {
@@ -1447,7 +1724,7 @@ mod bitlist {
     // Ensure that the stack size of a BitList is manageable.
     #[test]
     fn size_of() {
-        assert_eq!(std::mem::size_of::<BitList1024>(), SMALLVEC_LEN + 24);
+        assert_eq!(core::mem::size_of::<BitList1024>(), SMALLVEC_LEN + 24);
     }
 
     #[test]
@@ -1472,4 +1749,51 @@ mod bitlist {
         let e = BitList8::with_capacity(9).expect_err("over-sized bit list");
         assert_eq!(e, Error::OutOfBounds { i: 9, len: 8 });
     }
+
+    // Pins the spec layout: bit `i` is bit `i % 8` (least significant first) of byte `i / 8`,
+    // followed by a single delimiter bit at index `len`.
+    #[test]
+    fn wire_layout() {
+        let mut b = BitList::<4>::with_capacity(4).unwrap();
+        b.set(0, true).unwrap();
+        b.set(2, true).unwrap();
+        assert_eq!(b.as_ssz_bytes(), vec![0b0001_0101]);
+        assert_eq!(BitList::<4>::from_ssz_bytes(&[0b0001_0101]), Ok(b));
+
+        // A list of 8 bits puts its delimiter alone in a second byte.
+        let mut b = BitList::<8>::with_capacity(8).unwrap();
+        b.set(7, true).unwrap();
+        assert_eq!(b.as_ssz_bytes(), vec![0b1000_0000, 0b0000_0001]);
+
+        // The delimiter decides the length: the same bits with a higher delimiter are longer.
+        let b = BitList::<8>::from_ssz_bytes(&[0b0100_0101]).unwrap();
+        assert_eq!(b.len(), 6);
+        assert_eq!(
+            b.iter().collect::<Vec<_>>(),
+            [true, false, true, false, false, false]
+        );
+
+        let view: crate::view::BitListRef<'_, 4> =
+            crate::view::DecodeView::from_ssz_bytes(&[0b0001_0101]).unwrap();
+        assert_eq!(view.len(), 4);
+        let bits: Vec<bool> = (0..4).map(|i| view.get(i).unwrap()).collect();
+        assert_eq!(bits, [true, false, true, false]);
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary_respects_capacity() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let data: Vec<u8> = (0..=255).cycle().take(4096).collect();
+        let mut u = Unstructured::new(&data);
+        while !u.is_empty() {
+            let bit_list = BitList16::arbitrary(&mut u).unwrap();
+            assert!(bit_list.len() <= 16);
+            assert_eq!(
+                BitList16::from_ssz_bytes(&bit_list.as_ssz_bytes()).unwrap(),
+                bit_list
+            );
+        }
+    }
 }
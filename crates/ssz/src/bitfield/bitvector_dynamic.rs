@@ -2,6 +2,7 @@
 // Original source licensed under the Apache License 2.0
 
 //! Provides `Bitfield<Dynamic>` (BitVectorDynamic)
+use alloc::{format, vec::Vec};
 use core::marker::PhantomData;
 
 #[cfg(feature = "serde")]
@@ -72,7 +73,7 @@ impl Bitfield<Dynamic> {
 
     /// Compute the intersection of two bitfields.
     pub fn intersection(&self, other: &Self) -> Result<Self, Error> {
-        let max_len = std::cmp::max(self.len(), other.len());
+        let max_len = core::cmp::max(self.len(), other.len());
         let mut result = Self::new(max_len)?;
 
         for (i, byte) in result.bytes.iter_mut().enumerate() {
@@ -84,7 +85,7 @@ impl Bitfield<Dynamic> {
 
     /// Compute the union of two bitfields.
     pub fn union(&self, other: &Self) -> Result<Self, Error> {
-        let max_len = std::cmp::max(self.len(), other.len());
+        let max_len = core::cmp::max(self.len(), other.len());
         let mut result = Self::new(max_len)?;
 
         for (i, byte) in result.bytes.iter_mut().enumerate() {
@@ -425,7 +426,7 @@ mod roundtrip_tests {
     use super::*;
     fn assert_round_trip_bitdyn<T>(t: T) -> Result<(), Error>
     where
-        T: Encode + Decode + PartialEq + std::fmt::Debug,
+        T: Encode + Decode + PartialEq + core::fmt::Debug,
     {
         let bytes = t.as_ssz_bytes();
         let decoded = T::from_ssz_bytes(&bytes).expect("decode failed in test");
@@ -3,7 +3,7 @@
 
 //! SSZ decoding module
 
-use std::cmp::Ordering;
+use core::cmp::Ordering;
 
 use smallvec::{SmallVec, smallvec};
 use thiserror::Error;
@@ -13,8 +13,11 @@ use super::*;
 type SmallVec8<T> = SmallVec<[T; 8]>;
 
 pub(crate) mod impls;
+pub(crate) mod limits;
 pub(crate) mod try_from_iter;
 
+pub use limits::{DecodeLimits, decode_with_limits};
+
 /// Returned when SSZ decoding fails.
 #[derive(Debug, PartialEq, Clone, Error)]
 pub enum DecodeError {
@@ -26,6 +29,18 @@ pub enum DecodeError {
         /// The expected length
         expected: usize,
     },
+    /// The bytes supplied end before the fixed-size portion of the value, so at least `needed`
+    /// more bytes are required to decode it.
+    ///
+    /// Unlike [`DecodeError::InvalidByteLength`], this does not mean the input is corrupt: a
+    /// streaming caller can read more bytes and retry. Only the top-level buffer is reported this
+    /// way; a short field, element or union member is [`DecodeError::InvalidByteLength`] (see
+    /// [`DecodeError::bounded`]).
+    #[error("incomplete input: need at least {needed} more bytes")]
+    Incomplete {
+        /// The number of additional bytes required
+        needed: usize,
+    },
     /// The given bytes were too short to be read as a length prefix.
     #[error("invalid length prefix: got {len} bytes, expected {expected}")]
     InvalidLengthPrefix {
@@ -70,6 +85,20 @@ pub enum DecodeError {
     /// <https://notes.ethereum.org/ruKvDXl6QOW3gnqVYb8ezA?view#4-Offsets-are-out-of-bounds>
     #[error("offset {0} is out of bounds")]
     OffsetOutOfBounds(usize),
+    /// The offset of the variable-size field at `index` points into the fixed portion, before
+    /// the previous field's offset, or past the end of the container.
+    ///
+    /// Returned by the [`layout`](crate::layout) helpers that know which field they are reading;
+    /// the other offset variants are returned where the field is not known.
+    #[error("offset {offset} of variable field {index} is invalid")]
+    OffsetFieldInvalid {
+        /// The position of the field among the container's variable-size fields, in declaration
+        /// order: `0` is the first variable-size field, whatever fixed-size fields precede it.
+        /// Inactive fields of a `StableContainer` or `Profile` are counted too.
+        index: usize,
+        /// The invalid offset
+        offset: usize,
+    },
     /// A variable-length list does not have a fixed portion that is cleanly divisible by
     /// `BYTES_PER_LENGTH_OFFSET`.
     #[error("invalid list fixed bytes length: {0} is not divisible by offset size")]
@@ -88,6 +117,38 @@ pub enum DecodeError {
     NoMatchingVariant,
 }
 
+impl DecodeError {
+    /// Reports an error from decoding a `len`-byte slice bounded by an enclosing value.
+    ///
+    /// The slice of a field, list element or union member is fixed by its container's offsets
+    /// or its element size, so reading more input can't lengthen it. A
+    /// [`DecodeError::Incomplete`] from decoding it therefore becomes
+    /// [`DecodeError::InvalidByteLength`]; other errors are returned unchanged.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ssz::DecodeError;
+    ///
+    /// assert_eq!(
+    ///     DecodeError::Incomplete { needed: 4 }.bounded(4),
+    ///     DecodeError::InvalidByteLength {
+    ///         len: 4,
+    ///         expected: 8
+    ///     }
+    /// );
+    /// ```
+    pub fn bounded(self, len: usize) -> Self {
+        match self {
+            Self::Incomplete { needed } => Self::InvalidByteLength {
+                len,
+                expected: len + needed,
+            },
+            other => other,
+        }
+    }
+}
+
 /// Performs checks on the `offset` based upon the other parameters provided.
 ///
 /// ## Detail
@@ -173,6 +234,7 @@ pub struct SszDecoderBuilder<'a> {
     items: SmallVec8<&'a [u8]>,
     offsets: SmallVec8<Offset>,
     items_index: usize,
+    limits: Option<DecodeLimits>,
 }
 
 impl<'a> SszDecoderBuilder<'a> {
@@ -184,9 +246,21 @@ impl<'a> SszDecoderBuilder<'a> {
             items: smallvec![],
             offsets: smallvec![],
             items_index: 0,
+            limits: None,
         }
     }
 
+    /// Applies `limits` to the items decoded by the built `SszDecoder`,
+    /// including everything nested inside them.
+    ///
+    /// Without this, the decoder inherits the limits of any limited decode
+    /// it runs inside of. See [`DecodeLimits`] for which decodes the limits
+    /// apply to.
+    pub fn with_limits(mut self, limits: DecodeLimits) -> Self {
+        self.limits = Some(limits);
+        self
+    }
+
     /// Registers a variable-length object as the next item in `bytes`, without specifying the
     /// actual type.
     ///
@@ -230,21 +304,23 @@ impl<'a> SszDecoderBuilder<'a> {
             let slice =
                 self.bytes
                     .get(start..self.items_index)
-                    .ok_or(DecodeError::InvalidByteLength {
-                        len: self.bytes.len(),
-                        expected: self.items_index,
+                    .ok_or_else(|| DecodeError::Incomplete {
+                        needed: self.items_index - self.bytes.len(),
                     })?;
 
             self.items.push(slice);
         } else {
+            let offset_end = self.items_index + BYTES_PER_LENGTH_OFFSET;
+            if offset_end > self.bytes.len() {
+                return Err(DecodeError::Incomplete {
+                    needed: offset_end - self.bytes.len(),
+                });
+            }
+            // Offsets are sanitized in `finalize`, once the fixed portion is known to be complete,
+            // so that a short input is reported as incomplete rather than out of bounds.
             self.offsets.push(Offset {
                 position: self.items.len(),
-                offset: sanitize_offset(
-                    read_offset(&self.bytes[self.items_index..])?,
-                    self.offsets.last().map(|o| o.offset),
-                    self.bytes.len(),
-                    None,
-                )?,
+                offset: read_offset(&self.bytes[self.items_index..])?,
             });
 
             // Push an empty slice into items; it will be replaced later.
@@ -257,6 +333,12 @@ impl<'a> SszDecoderBuilder<'a> {
     }
 
     fn finalize(&mut self) -> Result<(), DecodeError> {
+        let mut previous_offset = None;
+        for offset in &self.offsets {
+            sanitize_offset(offset.offset, previous_offset, self.bytes.len(), None)?;
+            previous_offset = Some(offset.offset);
+        }
+
         if let Some(first_offset) = self.offsets.first().map(|o| o.offset) {
             // Check to ensure the first offset points to the byte immediately following the
             // fixed-length bytes.
@@ -298,7 +380,10 @@ impl<'a> SszDecoderBuilder<'a> {
     pub fn build(mut self) -> Result<SszDecoder<'a>, DecodeError> {
         self.finalize()?;
 
-        Ok(SszDecoder { items: self.items })
+        Ok(SszDecoder {
+            items: self.items,
+            limits: self.limits,
+        })
     }
 }
 
@@ -343,6 +428,7 @@ impl<'a> SszDecoderBuilder<'a> {
 #[derive(Debug)]
 pub struct SszDecoder<'a> {
     items: SmallVec8<&'a [u8]>,
+    limits: Option<DecodeLimits>,
 }
 
 impl<'a> SszDecoder<'a> {
@@ -360,7 +446,12 @@ impl<'a> SszDecoder<'a> {
     where
         F: FnOnce(&'a [u8]) -> Result<T, DecodeError>,
     {
-        f(self.items.remove(0))
+        let item = self.items.remove(0);
+        let decode = || limits::nested(|| f(item)).map_err(|e| e.bounded(item.len()));
+        match self.limits {
+            Some(decode_limits) => limits::with_limits(decode_limits, decode),
+            None => decode(),
+        }
     }
 
     /// Decodes the next item as a zero-copy view.
@@ -402,13 +493,17 @@ impl<'a> SszDecoder<'a> {
 ///
 /// Returns an error if:
 ///
-/// - `bytes` is empty.
+/// - `bytes` is empty, which returns [`DecodeError::InvalidByteLength`] expecting the selector
+///   byte.
 /// - the union selector is not a valid value (i.e., larger than the maximum number of variants.
 pub fn split_union_bytes(bytes: &[u8]) -> Result<(UnionSelector, &[u8]), DecodeError> {
     let selector = bytes
         .first()
         .copied()
-        .ok_or(DecodeError::OutOfBoundsByte { i: 0 })
+        .ok_or(DecodeError::InvalidByteLength {
+            len: 0,
+            expected: 1,
+        })
         .and_then(UnionSelector::new)?;
     let body = bytes
         .get(1..)
@@ -416,6 +511,43 @@ pub fn split_union_bytes(bytes: &[u8]) -> Result<(UnionSelector, &[u8]), DecodeE
     Ok((selector, body))
 }
 
+/// Checks that `body`, the bytes of a union member after its selector, are exactly `expected`
+/// bytes long.
+///
+/// A fixed-size member, or a `None` member with `expected` zero, has a single canonical encoding,
+/// so union decoders call this before decoding such a member to reject trailing bytes with a
+/// [`DecodeError::InvalidByteLength`] naming the member's size.
+pub fn check_union_body_len(body: &[u8], expected: usize) -> Result<(), DecodeError> {
+    if body.len() == expected {
+        Ok(())
+    } else {
+        Err(DecodeError::InvalidByteLength {
+            len: body.len(),
+            expected,
+        })
+    }
+}
+
+/// Decodes a value from the start of `bytes`, returning it with the number of bytes it consumed.
+///
+/// Unlike [`Decode::from_ssz_bytes`], bytes after the value are ignored, so values can be read
+/// one after another from a framed stream. Only fixed-size types are supported: the length of a
+/// variable-size value isn't part of its encoding and has to come from the framing instead, so
+/// for those this returns [`DecodeError::BytesInvalid`]. If `bytes` is shorter than the value,
+/// returns [`DecodeError::Incomplete`].
+pub fn decode_prefix<T: Decode>(bytes: &[u8]) -> Result<(T, usize), DecodeError> {
+    if !T::is_ssz_fixed_len() {
+        return Err(DecodeError::BytesInvalid(
+            "decode_prefix requires a fixed-size type".to_string(),
+        ));
+    }
+    let len = T::ssz_fixed_len();
+    let prefix = bytes.get(..len).ok_or_else(|| DecodeError::Incomplete {
+        needed: len - bytes.len(),
+    })?;
+    Ok((T::from_ssz_bytes(prefix)?, len))
+}
+
 /// Reads a `BYTES_PER_LENGTH_OFFSET`-byte length from `bytes`, where `bytes.len() >=
 /// BYTES_PER_LENGTH_OFFSET`.
 pub fn read_offset(bytes: &[u8]) -> Result<usize, DecodeError> {
@@ -436,7 +568,7 @@ fn decode_offset(bytes: &[u8]) -> Result<usize, DecodeError> {
     if len != expected {
         Err(DecodeError::InvalidLengthPrefix { len, expected })
     } else {
-        let mut array: [u8; BYTES_PER_LENGTH_OFFSET] = std::default::Default::default();
+        let mut array: [u8; BYTES_PER_LENGTH_OFFSET] = core::default::Default::default();
         array.clone_from_slice(bytes);
 
         Ok(u32::from_le_bytes(array) as usize)
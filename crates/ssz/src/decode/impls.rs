@@ -3,12 +3,14 @@
 
 //! SSZ decoding implementations for different types
 
-use core::num::NonZeroUsize;
-use std::{
+use alloc::{
     collections::{BTreeMap, BTreeSet},
-    iter::{self, FromIterator},
     sync::Arc,
 };
+use core::{
+    iter::{self, FromIterator},
+    num::NonZeroUsize,
+};
 
 use itertools::process_results;
 use smallvec::SmallVec;
@@ -83,7 +85,7 @@ macro_rules! impl_decodable_for_uint {
                 if len != expected {
                     Err(DecodeError::InvalidByteLength { len, expected })
                 } else {
-                    let mut array: [u8; $bit_size / 8] = std::default::Default::default();
+                    let mut array: [u8; $bit_size / 8] = core::default::Default::default();
                     array.clone_from_slice(bytes);
 
                     Ok(Self::from_le_bytes(array))
@@ -312,8 +314,15 @@ impl<T: Decode> Decode for Option<T> {
     fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
         let (selector, body) = split_union_bytes(bytes)?;
         match selector.into() {
-            0u8 => Ok(None),
-            1u8 => <T as Decode>::from_ssz_bytes(body).map(Some),
+            0u8 => check_union_body_len(body, 0).map(|()| None),
+            1u8 => {
+                if <T as Decode>::is_ssz_fixed_len() {
+                    check_union_body_len(body, <T as Decode>::ssz_fixed_len())?;
+                }
+                <T as Decode>::from_ssz_bytes(body)
+                    .map(Some)
+                    .map_err(|e| e.bounded(body.len()))
+            }
             other => Err(DecodeError::UnionSelectorInvalid(other)),
         }
     }
@@ -368,7 +377,7 @@ impl<T: Decode> Decode for Vec<T> {
         } else if T::is_ssz_fixed_len() {
             bytes
                 .chunks(T::ssz_fixed_len())
-                .map(T::from_ssz_bytes)
+                .map(|chunk| T::from_ssz_bytes(chunk).map_err(|e| e.bounded(chunk.len())))
                 .collect()
         } else {
             decode_list_of_variable_length_items(bytes, None)
@@ -387,7 +396,7 @@ impl<T: Decode, const N: usize> Decode for SmallVec<[T; N]> {
         } else if T::is_ssz_fixed_len() {
             bytes
                 .chunks(T::ssz_fixed_len())
-                .map(T::from_ssz_bytes)
+                .map(|chunk| T::from_ssz_bytes(chunk).map_err(|e| e.bounded(chunk.len())))
                 .collect()
         } else {
             decode_list_of_variable_length_items(bytes, None)
@@ -395,6 +404,10 @@ impl<T: Decode, const N: usize> Decode for SmallVec<[T; N]> {
     }
 }
 
+/// Decodes a list of `(key, value)` containers, as encoded by the `Encode` impl.
+///
+/// Keys must be strictly increasing, so every map has exactly one encoding;
+/// duplicate or unsorted keys are rejected with `DecodeError::BytesInvalid`.
 impl<K, V> Decode for BTreeMap<K, V>
 where
     K: Decode + Ord,
@@ -405,16 +418,14 @@ where
     }
 
     fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
-        if bytes.is_empty() {
-            Ok(Self::from_iter(iter::empty()))
-        } else if <(K, V)>::is_ssz_fixed_len() {
-            bytes
-                .chunks(<(K, V)>::ssz_fixed_len())
-                .map(<(K, V)>::from_ssz_bytes)
-                .collect()
-        } else {
-            decode_list_of_variable_length_items(bytes, None)
+        let entries = Vec::<(K, V)>::from_ssz_bytes(bytes)?;
+        if let Some(i) = entries.windows(2).position(|pair| pair[0].0 >= pair[1].0) {
+            return Err(DecodeError::BytesInvalid(format!(
+                "BTreeMap keys must be strictly increasing, but key {} is not above key {i}",
+                i + 1
+            )));
         }
+        Ok(entries.into_iter().collect())
     }
 }
 
@@ -432,7 +443,7 @@ where
         } else if T::is_ssz_fixed_len() {
             bytes
                 .chunks(T::ssz_fixed_len())
-                .map(T::from_ssz_bytes)
+                .map(|chunk| T::from_ssz_bytes(chunk).map_err(|e| e.bounded(chunk.len())))
                 .collect()
         } else {
             decode_list_of_variable_length_items(bytes, None)
@@ -471,6 +482,7 @@ pub fn decode_list_of_variable_length_items<T: Decode, Container: TryFromIter<T>
             "Variable length list of {num_items} items exceeds maximum of {max_len:?}"
         )));
     }
+    limits::charge_items(num_items)?;
 
     let mut offset = first_offset;
     process_results(
@@ -488,7 +500,7 @@ pub fn decode_list_of_variable_length_items<T: Decode, Container: TryFromIter<T>
             };
 
             let slice = slice_option.ok_or(DecodeError::OutOfBoundsByte { i: offset })?;
-            T::from_ssz_bytes(slice)
+            limits::nested(|| T::from_ssz_bytes(slice)).map_err(|e| e.bounded(slice.len()))
         }),
         |iter| iter.try_collect(),
     )?
@@ -0,0 +1,140 @@
+//! Resource limits for decoding untrusted SSZ.
+//!
+//! [`Decode::from_ssz_bytes`] takes no context, so the limits of the
+//! outermost limited decode are kept in a thread-local for the duration of
+//! that decode. Nested container and list decoding consult it through
+//! [`nested`] and [`charge_items`]. With the `std` feature the thread-local
+//! is a `thread_local!`; without it, a `#[thread_local]` static.
+
+use alloc::format;
+use core::cell::Cell;
+
+use super::{Decode, DecodeError};
+
+/// Limits on the work a single decode may perform.
+///
+/// Nested offset tables let a small payload describe deeply nested or very
+/// numerous variable-length items. Exceeding a limit fails the decode with
+/// [`DecodeError::BytesInvalid`]. The default imposes no limits.
+///
+/// # Scope
+///
+/// [`decode_with_limits`] and
+/// [`SszDecoderBuilder::with_limits`](crate::SszDecoderBuilder::with_limits) keep the limits in
+/// thread-local state while they run, and restore the previous state when they return or unwind.
+/// While active, the limits apply to every container and variable-length list decoded on the same
+/// thread, including decodes that custom [`Decode`] impls start on unrelated
+/// bytes: those count towards the same depth and item totals. Decodes on
+/// other threads are unaffected, and a limited decode started inside
+/// another replaces its limits until it returns.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct DecodeLimits {
+    /// Maximum nesting depth of containers and variable-length lists below
+    /// the decoded object.
+    pub max_depth: Option<usize>,
+    /// Maximum total number of items across all variable-length lists
+    /// decoded.
+    pub max_total_len: Option<usize>,
+}
+
+#[derive(Copy, Clone, Debug)]
+struct ActiveLimits {
+    limits: DecodeLimits,
+    depth: usize,
+    total_len: usize,
+}
+
+#[cfg(feature = "std")]
+thread_local! {
+    static ACTIVE: Cell<Option<ActiveLimits>> = const { Cell::new(None) };
+}
+
+#[cfg(not(feature = "std"))]
+#[thread_local]
+static ACTIVE: Cell<Option<ActiveLimits>> = Cell::new(None);
+
+/// Returns the limits of the limited decode in progress, if any.
+fn active_limits() -> Option<ActiveLimits> {
+    ACTIVE.get()
+}
+
+/// Replaces the limits of the limited decode in progress, returning the
+/// previous ones.
+fn replace_active_limits(limits: Option<ActiveLimits>) -> Option<ActiveLimits> {
+    ACTIVE.replace(limits)
+}
+
+/// Restores the previously active limits when a limited decode ends, even
+/// by unwinding.
+struct RestoreGuard(Option<ActiveLimits>);
+
+impl Drop for RestoreGuard {
+    fn drop(&mut self) {
+        replace_active_limits(self.0);
+    }
+}
+
+/// Runs `f` with `limits` active, starting at depth zero.
+pub(crate) fn with_limits<T>(
+    limits: DecodeLimits,
+    f: impl FnOnce() -> Result<T, DecodeError>,
+) -> Result<T, DecodeError> {
+    let _guard = RestoreGuard(replace_active_limits(Some(ActiveLimits {
+        limits,
+        depth: 0,
+        total_len: 0,
+    })));
+    f()
+}
+
+/// Runs `f` one nesting level deeper, failing if that exceeds the active
+/// depth limit.
+pub(crate) fn nested<T>(f: impl FnOnce() -> Result<T, DecodeError>) -> Result<T, DecodeError> {
+    let Some(mut active) = active_limits() else {
+        return f();
+    };
+    active.depth += 1;
+    if let Some(max_depth) = active.limits.max_depth
+        && active.depth > max_depth
+    {
+        return Err(DecodeError::BytesInvalid(format!(
+            "decode nesting depth exceeds limit of {max_depth}"
+        )));
+    }
+    replace_active_limits(Some(active));
+
+    let result = f();
+
+    // Nested decodes may have charged items, so only unwind the depth.
+    if let Some(mut active) = active_limits() {
+        active.depth -= 1;
+        replace_active_limits(Some(active));
+    }
+    result
+}
+
+/// Charges `items` decoded list items against the active total length
+/// limit.
+pub(crate) fn charge_items(items: usize) -> Result<(), DecodeError> {
+    let Some(mut active) = active_limits() else {
+        return Ok(());
+    };
+    active.total_len = active.total_len.saturating_add(items);
+    if let Some(max_total_len) = active.limits.max_total_len
+        && active.total_len > max_total_len
+    {
+        return Err(DecodeError::BytesInvalid(format!(
+            "decoded list items exceed total limit of {max_total_len}"
+        )));
+    }
+    replace_active_limits(Some(active));
+    Ok(())
+}
+
+/// Decodes `T` from `bytes` with `limits` applied to every nested container
+/// and variable-length list.
+///
+/// See [`DecodeLimits`] for which decodes the limits apply to.
+pub fn decode_with_limits<T: Decode>(bytes: &[u8], limits: DecodeLimits) -> Result<T, DecodeError> {
+    with_limits(limits, || T::from_ssz_bytes(bytes))
+}
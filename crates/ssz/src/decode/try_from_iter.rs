@@ -3,11 +3,11 @@
 
 //! TryFromIter trait definition and implementations
 
-use std::{
+use alloc::{
     collections::{BTreeMap, BTreeSet},
-    convert::Infallible,
-    fmt::Debug,
+    vec::Vec,
 };
+use core::{convert::Infallible, fmt::Debug};
 
 use smallvec::SmallVec;
 
@@ -48,6 +48,32 @@ pub trait Encode {
 
         buf
     }
+
+    /// Writes the full-form encoding of this object to `w`.
+    ///
+    /// Produces the same bytes as `as_ssz_bytes`. The default implementation encodes into a
+    /// buffer first; lists and containers override it to stream their offset table and elements
+    /// without materializing the whole encoding.
+    #[cfg(feature = "std")]
+    fn ssz_write<W: std::io::Write>(&self, w: &mut W) -> std::io::Result<()> {
+        w.write_all(&self.as_ssz_bytes())
+    }
+}
+
+/// Writes the full-form encoding of `val` to the async writer `w`.
+///
+/// Produces the same bytes as `as_ssz_bytes`. Offsets can only be computed once the
+/// variable-length parts are encoded, so the encoding is built in memory and then written in one
+/// go.
+#[cfg(feature = "tokio")]
+pub async fn ssz_write_async<T, W>(val: &T, w: &mut W) -> std::io::Result<()>
+where
+    T: Encode,
+    W: tokio::io::AsyncWrite + Unpin,
+{
+    use tokio::io::AsyncWriteExt;
+
+    w.write_all(&val.as_ssz_bytes()).await
 }
 
 /// Allow for encoding an ordered series of distinct or indistinct objects as SSZ bytes.
@@ -127,6 +153,41 @@ impl<'a> SszEncoder<'a> {
         }
     }
 
+    /// Appends a variable-length byte field of `len` bytes read from `r`, such as a
+    /// `List[byte, N]`, without first collecting the bytes into a separate `Vec`.
+    ///
+    /// The field's offset is written like [`SszEncoder::append`] would, and the bytes are read
+    /// straight into the encoder's variable part. If `r` fails or ends before `len` bytes, the
+    /// encoder is left as it was before the call and the error is returned, with
+    /// [`std::io::ErrorKind::UnexpectedEof`] for a short read.
+    #[cfg(feature = "std")]
+    pub fn append_from_reader<R: std::io::Read>(
+        &mut self,
+        r: &mut R,
+        len: usize,
+    ) -> std::io::Result<()> {
+        let offset_start = self.buf.len();
+        let bytes_start = self.variable_bytes.len();
+        self.buf
+            .extend_from_slice(&encode_length(self.offset + bytes_start));
+
+        self.variable_bytes.reserve(len);
+        let mut limited = std::io::Read::take(r, len as u64);
+        let result = match std::io::Read::read_to_end(&mut limited, &mut self.variable_bytes) {
+            Ok(read) if read == len => Ok(()),
+            Ok(read) => Err(std::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                format!("reader ended after {read} of {len} bytes"),
+            )),
+            Err(e) => Err(e),
+        };
+        if result.is_err() {
+            self.buf.truncate(offset_start);
+            self.variable_bytes.truncate(bytes_start);
+        }
+        result
+    }
+
     /// Write the variable bytes to `self.bytes`.
     ///
     /// This method must be called after the final `append(..)` call when serializing
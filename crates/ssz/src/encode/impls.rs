@@ -3,11 +3,13 @@
 
 //! SSZ encoding implementations for different types
 
-use core::num::NonZeroUsize;
-use std::{
+use alloc::{
     collections::{BTreeMap, BTreeSet},
     sync::Arc,
 };
+use core::num::NonZeroUsize;
+#[cfg(feature = "std")]
+use std::io;
 
 use smallvec::SmallVec;
 use ssz_primitives::{FixedBytes, U128, U256};
@@ -32,6 +34,11 @@ impl<const N: usize> Encode for FixedBytes<N> {
     fn ssz_append(&self, buf: &mut Vec<u8>) {
         <[u8; N] as Encode>::ssz_append(&self.0, buf)
     }
+
+    #[cfg(feature = "std")]
+    fn ssz_write<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
+        w.write_all(&self.0)
+    }
 }
 
 impl Encode for U256 {
@@ -50,6 +57,11 @@ impl Encode for U256 {
     fn ssz_append(&self, buf: &mut Vec<u8>) {
         <[u8; 32] as Encode>::ssz_append(&self.to_le_bytes::<32>(), buf)
     }
+
+    #[cfg(feature = "std")]
+    fn ssz_write<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
+        w.write_all(&self.to_le_bytes::<32>())
+    }
 }
 
 impl Encode for U128 {
@@ -68,6 +80,11 @@ impl Encode for U128 {
     fn ssz_append(&self, buf: &mut Vec<u8>) {
         <[u8; 16] as Encode>::ssz_append(&self.to_le_bytes::<16>(), buf)
     }
+
+    #[cfg(feature = "std")]
+    fn ssz_write<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
+        w.write_all(&self.to_le_bytes::<16>())
+    }
 }
 
 macro_rules! impl_encodable_for_uint {
@@ -88,6 +105,11 @@ macro_rules! impl_encodable_for_uint {
             fn ssz_append(&self, buf: &mut Vec<u8>) {
                 buf.extend_from_slice(&self.to_le_bytes());
             }
+
+            #[cfg(feature = "std")]
+            fn ssz_write<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
+                w.write_all(&self.to_le_bytes())
+            }
         }
     };
 }
@@ -296,6 +318,16 @@ impl<T: Encode> Encode for Option<T> {
                 .expect("encoded length must be less than usize::max_value"),
         }
     }
+    #[cfg(feature = "std")]
+    fn ssz_write<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
+        match self {
+            None => w.write_all(&[0u8]),
+            Some(inner) => {
+                w.write_all(&[1u8])?;
+                inner.ssz_write(w)
+            }
+        }
+    }
 }
 
 impl<T: Encode> Encode for Arc<T> {
@@ -314,6 +346,11 @@ impl<T: Encode> Encode for Arc<T> {
     fn ssz_bytes_len(&self) -> usize {
         self.as_ref().ssz_bytes_len()
     }
+
+    #[cfg(feature = "std")]
+    fn ssz_write<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
+        self.as_ref().ssz_write(w)
+    }
 }
 
 // Encode transparently through references.
@@ -333,6 +370,11 @@ impl<T: Encode> Encode for &T {
     fn ssz_bytes_len(&self) -> usize {
         T::ssz_bytes_len(self)
     }
+
+    #[cfg(feature = "std")]
+    fn ssz_write<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
+        T::ssz_write(self, w)
+    }
 }
 
 /// Compute the encoded length of a vector-like sequence of `T`.
@@ -375,6 +417,72 @@ where
     }
 }
 
+/// Number of bytes of fixed-length elements batched into a single `write_all` call by
+/// [`sequence_ssz_write`].
+#[cfg(feature = "std")]
+const WRITE_CHUNK_LEN: usize = 8 * 1024;
+
+/// Stream a vector-like sequence of `T` into `w`.
+///
+/// Fixed-length elements are encoded in chunks of about [`WRITE_CHUNK_LEN`] bytes. For
+/// variable-length elements the offset table is computed from each element's `ssz_bytes_len`
+/// and written first, followed by the elements themselves.
+#[cfg(feature = "std")]
+fn sequence_ssz_write<I, T, W>(iter: I, w: &mut W) -> io::Result<()>
+where
+    I: Iterator<Item = T> + ExactSizeIterator + Clone,
+    T: Encode,
+    W: io::Write,
+{
+    if T::is_ssz_fixed_len() {
+        let mut chunk = Vec::with_capacity(WRITE_CHUNK_LEN.max(T::ssz_fixed_len()));
+
+        for item in iter {
+            item.ssz_append(&mut chunk);
+            if chunk.len() >= WRITE_CHUNK_LEN {
+                w.write_all(&chunk)?;
+                chunk.clear();
+            }
+        }
+
+        w.write_all(&chunk)
+    } else {
+        let mut offset = iter.len() * BYTES_PER_LENGTH_OFFSET;
+
+        for item in iter.clone() {
+            w.write_all(&encode_length(offset))?;
+            offset += item.ssz_bytes_len();
+        }
+
+        for item in iter {
+            item.ssz_write(w)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Stream a slice of `T` into `w`.
+///
+/// Unlike [`sequence_ssz_write`], fixed-length elements are encoded a whole chunk at a time with
+/// [`sequence_ssz_append`], so the per-element work matches `as_ssz_bytes`.
+#[cfg(feature = "std")]
+fn slice_ssz_write<T: Encode, W: io::Write>(items: &[T], w: &mut W) -> io::Result<()> {
+    if !T::is_ssz_fixed_len() {
+        return sequence_ssz_write(items.iter(), w);
+    }
+
+    let items_per_chunk = (WRITE_CHUNK_LEN / T::ssz_fixed_len().max(1)).max(1);
+    let mut chunk = Vec::with_capacity(items_per_chunk * T::ssz_fixed_len());
+    for items in items.chunks(items_per_chunk) {
+        chunk.clear();
+        sequence_ssz_append(items.iter(), &mut chunk);
+        w.write_all(&chunk)?;
+    }
+
+    Ok(())
+}
+
 impl<T: Encode> Encode for Vec<T> {
     fn is_ssz_fixed_len() -> bool {
         false
@@ -387,6 +495,11 @@ impl<T: Encode> Encode for Vec<T> {
     fn ssz_append(&self, buf: &mut Vec<u8>) {
         sequence_ssz_append(self.iter(), buf)
     }
+
+    #[cfg(feature = "std")]
+    fn ssz_write<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
+        slice_ssz_write(self, w)
+    }
 }
 
 impl<T: Encode, const N: usize> Encode for SmallVec<[T; N]> {
@@ -401,8 +514,14 @@ impl<T: Encode, const N: usize> Encode for SmallVec<[T; N]> {
     fn ssz_append(&self, buf: &mut Vec<u8>) {
         sequence_ssz_append(self.iter(), buf)
     }
+
+    #[cfg(feature = "std")]
+    fn ssz_write<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
+        slice_ssz_write(self, w)
+    }
 }
 
+/// Encodes as a list of `(key, value)` containers in ascending key order.
 impl<K, V> Encode for BTreeMap<K, V>
 where
     K: Encode + Ord,
@@ -419,6 +538,11 @@ where
     fn ssz_append(&self, buf: &mut Vec<u8>) {
         sequence_ssz_append(self.iter(), buf)
     }
+
+    #[cfg(feature = "std")]
+    fn ssz_write<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
+        sequence_ssz_write(self.iter(), w)
+    }
 }
 
 impl<T> Encode for BTreeSet<T>
@@ -436,6 +560,11 @@ where
     fn ssz_append(&self, buf: &mut Vec<u8>) {
         sequence_ssz_append(self.iter(), buf)
     }
+
+    #[cfg(feature = "std")]
+    fn ssz_write<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
+        sequence_ssz_write(self.iter(), w)
+    }
 }
 
 impl Encode for bool {
@@ -454,6 +583,11 @@ impl Encode for bool {
     fn ssz_append(&self, buf: &mut Vec<u8>) {
         buf.extend_from_slice(&(*self as u8).to_le_bytes());
     }
+
+    #[cfg(feature = "std")]
+    fn ssz_write<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
+        w.write_all(&[*self as u8])
+    }
 }
 
 impl Encode for NonZeroUsize {
@@ -466,7 +600,7 @@ impl Encode for NonZeroUsize {
     }
 
     fn ssz_bytes_len(&self) -> usize {
-        std::mem::size_of::<usize>()
+        core::mem::size_of::<usize>()
     }
 
     fn ssz_append(&self, buf: &mut Vec<u8>) {
@@ -490,6 +624,11 @@ impl<const N: usize> Encode for [u8; N] {
     fn ssz_append(&self, buf: &mut Vec<u8>) {
         buf.extend_from_slice(&self[..]);
     }
+
+    #[cfg(feature = "std")]
+    fn ssz_write<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
+        w.write_all(&self[..])
+    }
 }
 
 #[cfg(test)]
@@ -610,4 +749,28 @@ mod tests {
         assert_eq!((10u32, 11u8).as_ssz_bytes(), vec![10, 0, 0, 0, 11]);
         assert_eq!((10u8, 11u8, 12u8).as_ssz_bytes(), vec![10, 11, 12]);
     }
+
+    #[cfg(feature = "std")]
+    fn written<T: Encode>(value: &T) -> Vec<u8> {
+        let mut out = vec![];
+        value.ssz_write(&mut out).unwrap();
+        out
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn ssz_write_matches_as_ssz_bytes() {
+        let bytes: Vec<u8> = (0..3 * WRITE_CHUNK_LEN + 7).map(|i| i as u8).collect();
+        assert_eq!(written(&bytes), bytes.as_ssz_bytes());
+
+        let nested: Vec<Vec<u16>> = vec![vec![], vec![1, 2, 3], vec![], vec![0xffff]];
+        assert_eq!(written(&nested), nested.as_ssz_bytes());
+
+        let options: Vec<Option<u32>> = vec![None, Some(7), None];
+        assert_eq!(written(&options), options.as_ssz_bytes());
+
+        assert_eq!(written(&(true, 5u64)), (true, 5u64).as_ssz_bytes());
+        assert_eq!(written(&[1u8, 2, 3]), vec![1, 2, 3]);
+        assert_eq!(written(&U256::from(9u8)), U256::from(9u8).as_ssz_bytes());
+    }
 }
@@ -11,6 +11,9 @@
 //! - Validation happens at wrap time without materializing field values.
 //! - Field access is lazy - positions are computed on-demand.
 
+use alloc::{string::String, vec::Vec};
+use core::cmp::Ordering;
+
 use crate::{BYTES_PER_LENGTH_OFFSET, DecodeError};
 
 /// Describes how to locate a field within an SSZ container.
@@ -91,13 +94,7 @@ impl ContainerLayout {
     pub fn validate(&self, bytes: &[u8]) -> Result<(), DecodeError> {
         if let Some(expected_size) = self.fixed_size {
             // Fixed-size container: just check the length
-            if bytes.len() != expected_size {
-                return Err(DecodeError::InvalidByteLength {
-                    len: bytes.len(),
-                    expected: expected_size,
-                });
-            }
-            return Ok(());
+            return check_exact_len(bytes.len(), expected_size);
         }
 
         // Variable-size container: validate offset table
@@ -115,9 +112,8 @@ impl ContainerLayout {
 
         // The fixed portion contains offsets for variable fields
         if bytes.len() < self.fixed_portion_size {
-            return Err(DecodeError::InvalidByteLength {
-                len: bytes.len(),
-                expected: self.fixed_portion_size,
+            return Err(DecodeError::Incomplete {
+                needed: self.fixed_portion_size - bytes.len(),
             });
         }
 
@@ -162,9 +158,8 @@ fn validate_offset_table(
         let offset_pos = offset_table_start + (i * BYTES_PER_LENGTH_OFFSET);
 
         if offset_pos + BYTES_PER_LENGTH_OFFSET > bytes.len() {
-            return Err(DecodeError::InvalidByteLength {
-                len: bytes.len(),
-                expected: offset_pos + BYTES_PER_LENGTH_OFFSET,
+            return Err(DecodeError::Incomplete {
+                needed: offset_pos + BYTES_PER_LENGTH_OFFSET - bytes.len(),
             });
         }
 
@@ -220,7 +215,8 @@ fn validate_offset_table(
 ///
 /// # Returns
 ///
-/// The offset value, or an error if out of bounds.
+/// The offset value, or [`DecodeError::OffsetFieldInvalid`] naming `index` if
+/// the offset points into the fixed portion or past the end of `bytes`.
 pub fn read_variable_offset(
     bytes: &[u8],
     fixed_portion_size: usize,
@@ -235,9 +231,8 @@ pub fn read_variable_offset(
     let offset_pos = offset_table_start + (index * BYTES_PER_LENGTH_OFFSET);
 
     if offset_pos + BYTES_PER_LENGTH_OFFSET > bytes.len() {
-        return Err(DecodeError::InvalidByteLength {
-            len: bytes.len(),
-            expected: offset_pos + BYTES_PER_LENGTH_OFFSET,
+        return Err(DecodeError::Incomplete {
+            needed: offset_pos + BYTES_PER_LENGTH_OFFSET - bytes.len(),
         });
     }
 
@@ -249,6 +244,9 @@ pub fn read_variable_offset(
         offset_bytes[3],
     ]) as usize;
 
+    if offset < fixed_portion_size || offset > bytes.len() {
+        return Err(DecodeError::OffsetFieldInvalid { index, offset });
+    }
     Ok(offset)
 }
 
@@ -281,15 +279,27 @@ pub fn read_variable_offset_or_end(
 /// position in the fixed portion.
 pub type FieldInfo = (bool, usize);
 
+/// Checks that a fixed-size value of `expected` bytes spans exactly `len`
+/// bytes: a short input is [`DecodeError::Incomplete`], a long one is
+/// [`DecodeError::InvalidByteLength`].
+fn check_exact_len(len: usize, expected: usize) -> Result<(), DecodeError> {
+    match len.cmp(&expected) {
+        Ordering::Less => Err(DecodeError::Incomplete {
+            needed: expected - len,
+        }),
+        Ordering::Greater => Err(DecodeError::InvalidByteLength { len, expected }),
+        Ordering::Equal => Ok(()),
+    }
+}
+
 /// Reads a 4-byte little-endian offset at `pos`.
 fn read_offset_at(bytes: &[u8], pos: usize) -> Result<usize, DecodeError> {
     let end = pos
         .checked_add(BYTES_PER_LENGTH_OFFSET)
         .ok_or(DecodeError::OutOfBoundsByte { i: pos })?;
     if end > bytes.len() {
-        return Err(DecodeError::InvalidByteLength {
-            len: bytes.len(),
-            expected: end,
+        return Err(DecodeError::Incomplete {
+            needed: end - bytes.len(),
         });
     }
     let offset_bytes = &bytes[pos..end];
@@ -376,9 +386,8 @@ pub fn read_active_field_bytes<'a>(
             .checked_add(fixed_len)
             .ok_or(DecodeError::OutOfBoundsByte { i: pos })?;
         if end > bytes.len() {
-            return Err(DecodeError::InvalidByteLength {
-                len: bytes.len(),
-                expected: end,
+            return Err(DecodeError::Incomplete {
+                needed: end - bytes.len(),
             });
         }
         Ok(Some(&bytes[pos..end]))
@@ -390,6 +399,7 @@ pub fn read_active_field_bytes<'a>(
         // fields.
         let mut next_pos = pos + BYTES_PER_LENGTH_OFFSET;
         let mut end = bytes.len();
+        let mut next_index = index;
         for (i, &(next_is_fixed, next_len)) in fields.iter().enumerate().skip(index + 1) {
             if !active(i) {
                 continue;
@@ -398,17 +408,36 @@ pub fn read_active_field_bytes<'a>(
                 next_pos += next_len;
             } else {
                 end = read_offset_at(bytes, next_pos)?;
+                next_index = i;
                 break;
             }
         }
 
-        if start > end || end > bytes.len() {
-            return Err(DecodeError::OffsetsAreDecreasing(end));
+        if start > bytes.len() || start > end {
+            return Err(DecodeError::OffsetFieldInvalid {
+                index: variable_field_index(fields, index),
+                offset: start,
+            });
+        }
+        if end > bytes.len() {
+            return Err(DecodeError::OffsetFieldInvalid {
+                index: variable_field_index(fields, next_index),
+                offset: end,
+            });
         }
         Ok(Some(&bytes[start..end]))
     }
 }
 
+/// Position of the field at `index` among the variable-size fields of `fields`,
+/// as reported by [`DecodeError::OffsetFieldInvalid`].
+fn variable_field_index(fields: &[FieldInfo], index: usize) -> usize {
+    fields[..index]
+        .iter()
+        .filter(|&&(is_fixed, _)| !is_fixed)
+        .count()
+}
+
 /// Validates container `bytes` against the per-field layout facts.
 ///
 /// For an all-fixed container this checks the exact byte length; otherwise it
@@ -455,19 +484,12 @@ pub fn validate_active_container(
         .count();
 
     if num_variable_fields == 0 {
-        if bytes.len() != fixed_portion_size {
-            return Err(DecodeError::InvalidByteLength {
-                len: bytes.len(),
-                expected: fixed_portion_size,
-            });
-        }
-        return Ok(());
+        return check_exact_len(bytes.len(), fixed_portion_size);
     }
 
     if bytes.len() < fixed_portion_size {
-        return Err(DecodeError::InvalidByteLength {
-            len: bytes.len(),
-            expected: fixed_portion_size,
+        return Err(DecodeError::Incomplete {
+            needed: fixed_portion_size - bytes.len(),
         });
     }
 
@@ -480,19 +502,18 @@ pub fn validate_active_container(
         if !is_fixed {
             let offset = read_offset_at(bytes, pos)?;
 
-            match prev_offset {
-                // First offset must point to the start of the variable portion
-                None if offset != fixed_portion_size => {
-                    return Err(DecodeError::OffsetIntoFixedPortion(offset));
-                }
-                Some(prev) if offset < prev => {
-                    return Err(DecodeError::OffsetsAreDecreasing(offset));
-                }
-                _ => {}
-            }
-
-            if offset > bytes.len() {
-                return Err(DecodeError::OffsetOutOfBounds(offset));
+            // The first offset must point to the start of the variable
+            // portion, later ones must not decrease, and none may exceed the
+            // container length.
+            let in_order = match prev_offset {
+                None => offset == fixed_portion_size,
+                Some(prev) => offset >= prev,
+            };
+            if !in_order || offset > bytes.len() {
+                return Err(DecodeError::OffsetFieldInvalid {
+                    index: variable_field_index(fields, i),
+                    offset,
+                });
             }
 
             prev_offset = Some(offset);
@@ -530,11 +551,20 @@ mod tests {
         // Valid: exactly 2 bytes
         assert!(layout.validate(&[0x01, 0x02]).is_ok());
 
-        // Invalid: too short
-        assert!(layout.validate(&[0x01]).is_err());
+        // Incomplete: too short
+        assert_eq!(
+            layout.validate(&[0x01]),
+            Err(DecodeError::Incomplete { needed: 1 })
+        );
 
         // Invalid: too long
-        assert!(layout.validate(&[0x01, 0x02, 0x03]).is_err());
+        assert_eq!(
+            layout.validate(&[0x01, 0x02, 0x03]),
+            Err(DecodeError::InvalidByteLength {
+                len: 3,
+                expected: 2
+            })
+        );
     }
 
     #[test]
@@ -645,6 +675,23 @@ mod tests {
         assert!(read_variable_offset_or_end(&bytes, 8, 2, 3).is_err());
     }
 
+    #[test]
+    fn read_variable_offset_names_invalid_index() {
+        let bytes = vec![
+            0x08, 0x00, 0x00, 0x00, // offset 0 = 8
+            0x20, 0x00, 0x00, 0x00, // offset 1 = 32, past the end
+            0xAA, 0xBB, 0xCC,
+        ];
+
+        assert_eq!(
+            read_variable_offset_or_end(&bytes, 8, 2, 1),
+            Err(DecodeError::OffsetFieldInvalid {
+                index: 1,
+                offset: 32
+            })
+        );
+    }
+
     #[test]
     fn read_field_bytes_fixed() {
         // Container: [u8][u16] -> 3 fixed bytes, no variable fields
@@ -830,8 +877,15 @@ mod tests {
         let valid = vec![0xAA, 0x05, 0x00, 0x00, 0x00, 0xBB];
         assert!(validate_active_container(&valid, fields, |_| true).is_ok());
 
+        // The list is the first variable-size field, whatever precedes it.
         let invalid = vec![0xAA, 0x03, 0x00, 0x00, 0x00, 0xBB];
-        assert!(validate_active_container(&invalid, fields, |_| true).is_err());
+        assert_eq!(
+            validate_active_container(&invalid, fields, |_| true),
+            Err(DecodeError::OffsetFieldInvalid {
+                index: 0,
+                offset: 3
+            })
+        );
     }
 
     #[test]
@@ -846,6 +900,12 @@ mod tests {
             0x07, 0x00, 0x00, 0x00, // offset 1 = 7 (< 8)
             0xAA,
         ];
-        assert!(validate_container(&invalid, fields).is_err());
+        assert_eq!(
+            validate_container(&invalid, fields),
+            Err(DecodeError::OffsetFieldInvalid {
+                index: 1,
+                offset: 7
+            })
+        );
     }
 }
@@ -184,7 +184,7 @@ mod test {
         assert_eq!(impl_vec_u16::decode::from_ssz_bytes(&bytes).unwrap(), item);
     }
 
-    fn round_trip<T: Encode + Decode + std::fmt::Debug + PartialEq>(items: Vec<T>) {
+    fn round_trip<T: Encode + Decode + core::fmt::Debug + PartialEq>(items: Vec<T>) {
         for item in items {
             let encoded = &item.as_ssz_bytes();
             assert_eq!(item.ssz_bytes_len(), encoded.len());
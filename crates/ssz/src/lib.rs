@@ -2,10 +2,12 @@
 // Original source licensed under the Apache License 2.0
 
 #![feature(generic_const_exprs)]
+#![cfg_attr(not(feature = "std"), feature(thread_local))]
 #![allow(
     incomplete_features,
     reason = "we need generic const exprs for BitVectorRef"
 )]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
 //! Provides encoding (serialization) and decoding (deserialization) in the SimpleSerialize (SSZ)
 //! format designed for use in Ethereum 2.0.
@@ -66,6 +68,27 @@
 //! ```
 //!
 //! See `examples/` for manual implementations and [`view`] module for zero-copy types.
+//!
+//! ## `no_std`
+//!
+//! The `std` feature is on by default. Without it the crate builds on `core` and `alloc`:
+//! [`Encode::ssz_write`] is unavailable, and the derive macros leave it out of their impls.
+//! [`decode_with_limits`] keeps its limits in a `#[thread_local]` static instead of
+//! `thread_local!`.
+//!
+//! ## Async writers
+//!
+//! The `tokio` feature adds `ssz_write_async`, which writes an encoding to a
+//! `tokio::io::AsyncWrite`.
+
+extern crate alloc;
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 
 pub mod bitfield;
 pub mod decode;
@@ -82,10 +105,12 @@ pub use bitfield::bitvector_dynamic::{BitVectorDynamic, Dynamic};
 #[doc(hidden)]
 pub use bitfield::{BitList, BitVector, Bitfield, Error as BitfieldError, Fixed, Variable};
 pub use decode::{
-    Decode, DecodeError, SszDecoder, SszDecoderBuilder,
-    impls::decode_list_of_variable_length_items, read_offset, split_union_bytes,
-    try_from_iter::TryFromIter,
+    Decode, DecodeError, DecodeLimits, SszDecoder, SszDecoderBuilder, check_union_body_len,
+    decode_prefix, decode_with_limits, impls::decode_list_of_variable_length_items, read_offset,
+    split_union_bytes, try_from_iter::TryFromIter,
 };
+#[cfg(feature = "tokio")]
+pub use encode::ssz_write_async;
 pub use encode::{Encode, SszEncoder, encode_length};
 pub use union_selector::UnionSelector;
 
@@ -102,7 +127,44 @@ pub const MAX_LENGTH_VALUE: usize = (u64::MAX >> (8 * (8 - BYTES_PER_LENGTH_OFFS
 pub const BYTES_PER_UNION_SELECTOR: usize = 1;
 /// The highest possible union selector value (higher values are reserved for backwards compatible
 /// extensions).
+#[cfg(not(feature = "union_extended"))]
 pub const MAX_UNION_SELECTOR: u8 = 127;
+/// The highest possible union selector value. The `union_extended` feature opens up the range the
+/// specification reserves for extensions.
+#[cfg(feature = "union_extended")]
+pub const MAX_UNION_SELECTOR: u8 = u8::MAX;
+
+/// `std` items named by the derived [`Encode::ssz_write`], so that it also expands in `no_std`
+/// crates that depend on `ssz` with `std` enabled.
+#[doc(hidden)]
+#[cfg(feature = "std")]
+pub mod __std {
+    pub use std::{io, vec::Vec};
+}
+
+/// Expands to the given items when the `std` feature is enabled, and to nothing otherwise.
+///
+/// `ssz_derive` wraps the derived [`Encode::ssz_write`] in this, since the method only exists
+/// with `std`.
+#[doc(hidden)]
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! __std_only {
+    ($($item:tt)*) => {
+        $($item)*
+    };
+}
+
+/// Expands to the given items when the `std` feature is enabled, and to nothing otherwise.
+///
+/// `ssz_derive` wraps the derived `Encode::ssz_write` in this, since the method only exists
+/// with `std`.
+#[doc(hidden)]
+#[cfg(not(feature = "std"))]
+#[macro_export]
+macro_rules! __std_only {
+    ($($item:tt)*) => {};
+}
 
 /// Convenience function to SSZ encode an object supporting ssz::Encode.
 ///
@@ -1,7 +1,12 @@
 // Modified in 2025 from the original version
 // Original source licensed under the Apache License 2.0
 
-use std::fmt;
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
 
 use serde::de::{self, Visitor};
 
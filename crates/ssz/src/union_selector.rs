@@ -23,6 +23,10 @@ impl PartialEq<u8> for UnionSelector {
 
 impl UnionSelector {
     /// Instantiate `self`, returning an error if `selector > MAX_UNION_SELECTOR`.
+    #[allow(
+        clippy::absurd_extreme_comparisons,
+        reason = "MAX_UNION_SELECTOR is u8::MAX under the union_extended feature"
+    )]
     pub fn new(selector: u8) -> Result<Self, DecodeError> {
         (selector <= MAX_UNION_SELECTOR)
             .then_some(selector)
@@ -30,3 +34,36 @@ impl UnionSelector {
             .ok_or(DecodeError::UnionSelectorInvalid(selector))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spec_range_accepted() {
+        assert!(UnionSelector::new(0).is_ok());
+        assert!(UnionSelector::new(127).is_ok());
+    }
+
+    #[cfg(not(feature = "union_extended"))]
+    #[test]
+    fn reserved_range_rejected() {
+        assert!(matches!(
+            UnionSelector::new(200),
+            Err(DecodeError::UnionSelectorInvalid(200))
+        ));
+        assert!(matches!(
+            split_union_bytes(&[200, 1, 2]),
+            Err(DecodeError::UnionSelectorInvalid(200))
+        ));
+    }
+
+    #[cfg(feature = "union_extended")]
+    #[test]
+    fn reserved_range_accepted() {
+        assert_eq!(UnionSelector::new(200).unwrap(), 200);
+        let (selector, body) = split_union_bytes(&[200, 1, 2]).unwrap();
+        assert_eq!(selector, 200);
+        assert_eq!(body, &[1, 2]);
+    }
+}
@@ -23,6 +23,7 @@
 //! assert_eq!(view.as_bytes(), bytes);
 //! ```
 
+use alloc::{format, string::ToString, vec::Vec};
 use core::marker::PhantomData;
 
 use ssz_primitives::{FixedBytes, U128, U256};
@@ -57,6 +58,15 @@ pub trait SszTypeInfo {
 /// - Avoid copying the payload data.
 /// - Return views that borrow from the input `bytes`.
 ///
+/// ## Empty input
+///
+/// An empty slice is a valid encoding of an empty list, byte list or absent `Optional` and
+/// decodes to `Ok`. Every other type has a minimum encoded length and fails on an empty slice
+/// as it would on any other input too short for it: containers return
+/// [`DecodeError::Incomplete`] for their top-level buffer, and the remaining types return
+/// [`DecodeError::InvalidByteLength`] expecting their minimum length, which [`reject_empty`]
+/// provides for implementations.
+///
 /// ## Example
 ///
 /// ```rust
@@ -77,6 +87,38 @@ pub trait DecodeView<'a>: Sized {
     fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, DecodeError>;
 }
 
+/// Returns [`DecodeError::InvalidByteLength`] expecting `min_len` bytes if `bytes` is empty.
+///
+/// [`DecodeView`] implementations for types whose encoding is at least `min_len` bytes long
+/// call this first, so that decoding `&[]` fails the same way for all of them. The error is not
+/// [`DecodeError::Incomplete`]: these types report any short input as invalid, and an empty
+/// field or union member inside a complete buffer can't be completed by reading more.
+///
+/// ## Example
+///
+/// ```rust
+/// use ssz::{DecodeError, view::reject_empty};
+///
+/// assert_eq!(
+///     reject_empty(&[], 8),
+///     Err(DecodeError::InvalidByteLength {
+///         len: 0,
+///         expected: 8
+///     })
+/// );
+/// assert_eq!(reject_empty(&[0], 8), Ok(()));
+/// ```
+pub const fn reject_empty(bytes: &[u8], min_len: usize) -> Result<(), DecodeError> {
+    if bytes.is_empty() && min_len > 0 {
+        Err(DecodeError::InvalidByteLength {
+            len: 0,
+            expected: min_len,
+        })
+    } else {
+        Ok(())
+    }
+}
+
 /// A reference to a fixed-length byte array in SSZ encoding.
 ///
 /// This is the zero-copy equivalent of `[u8; N]` or [`FixedBytes<N>`](ssz_primitives::FixedBytes).
@@ -125,6 +167,7 @@ impl<'a, const N: usize> FixedBytesRef<'a, N> {
 
 impl<'a, const N: usize> DecodeView<'a> for FixedBytesRef<'a, N> {
     fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, DecodeError> {
+        reject_empty(bytes, N)?;
         let bytes: &'a [u8; N] = bytes
             .try_into()
             .map_err(|_| DecodeError::InvalidByteLength {
@@ -337,104 +380,124 @@ impl<'a, TRef: SszTypeInfo, const N: usize> DecodeView<'a> for ListRef<'a, TRef,
 impl<'a, TRef: SszTypeInfo, const N: usize> ListRef<'a, TRef, N> {
     /// Returns an [`Iterator`] over the list items.
     ///
-    /// Each item is decoded lazily as the iterator advances.
+    /// Each item is decoded lazily as the iterator advances. The list length and item stride
+    /// are computed once up front, and for variable-size items the offset table is walked a
+    /// single time, so this is cheaper than calling [`ListRef::get`] for every index.
     pub fn iter(&self) -> ListRefIter<'a, TRef, N>
     where
         TRef: DecodeView<'a>,
     {
         ListRefIter {
-            list: ListRef {
-                bytes: self.bytes,
-                _marker: PhantomData,
-            },
+            bytes: self.bytes,
+            len: self.len(),
             index: 0,
+            previous_offset: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Gets the item at the specified index.
+    pub fn get(&self, index: usize) -> Result<TRef, DecodeError>
+    where
+        TRef: DecodeView<'a>,
+    {
+        let len = self.len();
+        if index >= len {
+            return Err(DecodeError::OutOfBoundsByte { i: index });
+        }
+
+        if TRef::is_ssz_fixed_len() {
+            let item_size = TRef::ssz_fixed_len();
+            let start = index * item_size;
+            TRef::from_ssz_bytes(&self.bytes[start..start + item_size])
+        } else {
+            let previous_offset = if index == 0 {
+                None
+            } else {
+                Some(read_offset(
+                    &self.bytes[(index - 1) * BYTES_PER_LENGTH_OFFSET..],
+                )?)
+            };
+            let current_offset = read_offset(&self.bytes[index * BYTES_PER_LENGTH_OFFSET..])?;
+            let item_bytes =
+                variable_list_item_bytes(self.bytes, len, index, previous_offset, current_offset)?;
+            TRef::from_ssz_bytes(item_bytes).map_err(|e| e.bounded(item_bytes.len()))
         }
     }
 }
 
+/// Returns the bytes of the variable-size list or vector item at `index`, whose offset is
+/// `current_offset`, after checking it against the previous item's offset and the next one.
+fn variable_list_item_bytes(
+    bytes: &[u8],
+    len: usize,
+    index: usize,
+    previous_offset: Option<usize>,
+    current_offset: usize,
+) -> Result<&[u8], DecodeError> {
+    let next_offset = if index + 1 < len {
+        read_offset(&bytes[(index + 1) * BYTES_PER_LENGTH_OFFSET..])?
+    } else {
+        bytes.len()
+    };
+
+    // The first offset also marks the end of the offset table.
+    let num_fixed = (index == 0).then_some(current_offset);
+    sanitize_offset(current_offset, previous_offset, bytes.len(), num_fixed)?;
+
+    if next_offset < current_offset || next_offset > bytes.len() {
+        return Err(DecodeError::OffsetsAreDecreasing(next_offset));
+    }
+
+    Ok(&bytes[current_offset..next_offset])
+}
+
 /// Iterator over items in a [`ListRef`].
 #[derive(Debug, Clone)]
 pub struct ListRefIter<'a, TRef, const N: usize> {
-    /// The underlying list.
-    list: ListRef<'a, TRef, N>,
+    /// The underlying list bytes.
+    bytes: &'a [u8],
+
+    /// The number of items in the list.
+    len: usize,
 
     /// The current index.
     index: usize,
+
+    /// The offset of the previous variable-size item.
+    previous_offset: Option<usize>,
+
+    /// The type of the items.
+    _marker: PhantomData<TRef>,
 }
 
 impl<'a, TRef: DecodeView<'a> + SszTypeInfo, const N: usize> Iterator for ListRefIter<'a, TRef, N> {
     type Item = Result<TRef, DecodeError>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.index >= self.list.len() {
+        if self.index >= self.len {
             return None;
         }
 
-        let is_fixed_len = TRef::is_ssz_fixed_len();
-        let result = if is_fixed_len {
-            // Fixed-length items: direct indexing
+        let result = if TRef::is_ssz_fixed_len() {
             let item_size = TRef::ssz_fixed_len();
             let start = self.index * item_size;
-            let end = start + item_size;
-            let item_bytes = &self.list.bytes[start..end];
-            TRef::from_ssz_bytes(item_bytes)
+            TRef::from_ssz_bytes(&self.bytes[start..start + item_size])
         } else {
-            // Variable-length items: walk offsets
-            let first_offset = match read_offset(self.list.bytes) {
-                Ok(offset) => offset,
-                Err(e) => return Some(Err(e)),
-            };
-
-            let current_offset_pos = self.index * BYTES_PER_LENGTH_OFFSET;
-            let current_offset = match read_offset(&self.list.bytes[current_offset_pos..]) {
-                Ok(offset) => offset,
-                Err(e) => return Some(Err(e)),
-            };
-
-            let next_offset = if self.index + 1 < self.list.len() {
-                let next_offset_pos = (self.index + 1) * BYTES_PER_LENGTH_OFFSET;
-                match read_offset(&self.list.bytes[next_offset_pos..]) {
-                    Ok(offset) => offset,
-                    Err(e) => return Some(Err(e)),
-                }
-            } else {
-                self.list.bytes.len()
-            };
-
-            // Validate offsets
-            // For the first item, validate against the fixed portion size
-            // For subsequent items, validate against the previous offset
-            let prev_offset = if self.index == 0 {
-                None
-            } else {
-                let prev_offset_pos = (self.index - 1) * BYTES_PER_LENGTH_OFFSET;
-                match read_offset(&self.list.bytes[prev_offset_pos..]) {
-                    Ok(offset) => Some(offset),
-                    Err(e) => return Some(Err(e)),
+            match read_offset(&self.bytes[self.index * BYTES_PER_LENGTH_OFFSET..]) {
+                Ok(current_offset) => {
+                    let item_bytes = variable_list_item_bytes(
+                        self.bytes,
+                        self.len,
+                        self.index,
+                        self.previous_offset,
+                        current_offset,
+                    );
+                    self.previous_offset = Some(current_offset);
+                    item_bytes.and_then(TRef::from_ssz_bytes)
                 }
-            };
-
-            let num_fixed = if self.index == 0 {
-                Some(first_offset)
-            } else {
-                None
-            };
-
-            if let Err(e) = sanitize_offset(
-                current_offset,
-                prev_offset,
-                self.list.bytes.len(),
-                num_fixed,
-            ) {
-                return Some(Err(e));
+                Err(e) => Err(e),
             }
-
-            if next_offset < current_offset || next_offset > self.list.bytes.len() {
-                return Some(Err(DecodeError::OffsetsAreDecreasing(next_offset)));
-            }
-
-            let item_bytes = &self.list.bytes[current_offset..next_offset];
-            TRef::from_ssz_bytes(item_bytes)
         };
 
         self.index += 1;
@@ -442,7 +505,7 @@ impl<'a, TRef: DecodeView<'a> + SszTypeInfo, const N: usize> Iterator for ListRe
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let remaining = self.list.len().saturating_sub(self.index);
+        let remaining = self.len.saturating_sub(self.index);
         (remaining, Some(remaining))
     }
 }
@@ -451,7 +514,7 @@ impl<'a, TRef: DecodeView<'a> + SszTypeInfo, const N: usize> ExactSizeIterator
     for ListRefIter<'a, TRef, N>
 {
     fn len(&self) -> usize {
-        self.list.len().saturating_sub(self.index)
+        self.len.saturating_sub(self.index)
     }
 }
 
@@ -495,6 +558,7 @@ impl<'a, TRef: SszTypeInfo, const N: usize> VectorRef<'a, TRef, N> {
                 return Err(DecodeError::ZeroLengthItem);
             }
             let expected_len = N * item_size;
+            reject_empty(bytes, expected_len)?;
             if bytes.len() != expected_len {
                 return Err(DecodeError::InvalidByteLength {
                     len: bytes.len(),
@@ -504,6 +568,7 @@ impl<'a, TRef: SszTypeInfo, const N: usize> VectorRef<'a, TRef, N> {
         } else {
             // For variable-length items, validate offset structure
             if N > 0 {
+                reject_empty(bytes, N * BYTES_PER_LENGTH_OFFSET)?;
                 if bytes.len() < N * BYTES_PER_LENGTH_OFFSET {
                     return Err(DecodeError::InvalidByteLength {
                         len: bytes.len(),
@@ -545,11 +610,11 @@ impl<'a, TRef: SszTypeInfo, const N: usize> VectorRef<'a, TRef, N> {
         TRef: DecodeView<'a>,
     {
         VectorRefIter {
-            vector: VectorRef {
-                bytes: self.bytes,
-                _marker: PhantomData,
-            },
+            bytes: self.bytes,
             index: 0,
+            item_size: TRef::is_ssz_fixed_len().then(TRef::ssz_fixed_len),
+            previous_offset: None,
+            _marker: PhantomData,
         }
     }
 
@@ -599,7 +664,7 @@ impl<'a, TRef: SszTypeInfo, const N: usize> VectorRef<'a, TRef, N> {
             }
 
             let item_bytes = &self.bytes[current_offset..next_offset];
-            TRef::from_ssz_bytes(item_bytes)
+            TRef::from_ssz_bytes(item_bytes).map_err(|e| e.bounded(item_bytes.len()))
         }
     }
 }
@@ -607,11 +672,20 @@ impl<'a, TRef: SszTypeInfo, const N: usize> VectorRef<'a, TRef, N> {
 /// Iterator over items in a [`VectorRef`].
 #[derive(Debug, Clone)]
 pub struct VectorRefIter<'a, TRef, const N: usize> {
-    /// The underlying vector.
-    vector: VectorRef<'a, TRef, N>,
+    /// The underlying vector bytes.
+    bytes: &'a [u8],
 
     /// The current index.
     index: usize,
+
+    /// The stride of fixed-size items, or `None` for variable-size items.
+    item_size: Option<usize>,
+
+    /// The offset of the previous variable-size item.
+    previous_offset: Option<usize>,
+
+    /// The type of the items.
+    _marker: PhantomData<TRef>,
 }
 
 impl<'a, TRef: DecodeView<'a> + SszTypeInfo, const N: usize> Iterator
@@ -623,7 +697,27 @@ impl<'a, TRef: DecodeView<'a> + SszTypeInfo, const N: usize> Iterator
         if self.index >= N {
             return None;
         }
-        let result = self.vector.get(self.index);
+
+        let result = match self.item_size {
+            Some(item_size) => {
+                let start = self.index * item_size;
+                TRef::from_ssz_bytes(&self.bytes[start..start + item_size])
+            }
+            None => read_offset(&self.bytes[self.index * BYTES_PER_LENGTH_OFFSET..]).and_then(
+                |current_offset| {
+                    let item_bytes = variable_list_item_bytes(
+                        self.bytes,
+                        N,
+                        self.index,
+                        self.previous_offset,
+                        current_offset,
+                    );
+                    self.previous_offset = Some(current_offset);
+                    item_bytes.and_then(TRef::from_ssz_bytes)
+                },
+            ),
+        };
+
         self.index += 1;
         Some(result)
     }
@@ -684,7 +778,7 @@ impl<'a, VRef> UnionRef<'a, VRef> {
 impl<'a, VRef: DecodeView<'a>> UnionRef<'a, VRef> {
     /// Decodes and returns the body as the specified view type.
     pub fn body(&self) -> Result<VRef, DecodeError> {
-        VRef::from_ssz_bytes(self.body)
+        VRef::from_ssz_bytes(self.body).map_err(|e| e.bounded(self.body.len()))
     }
 }
 
@@ -767,6 +861,16 @@ where
         Ok((byte & (1 << bit_index)) != 0)
     }
 
+    /// Returns the number of bits that are set to `true`.
+    pub fn count_ones(&self) -> usize {
+        crate::bitfield::count_ones(self.bytes)
+    }
+
+    /// Returns the number of bits that are set to `false`.
+    pub fn count_zeros(&self) -> usize {
+        N - self.count_ones()
+    }
+
     /// Returns an iterator over the bits.
     pub fn iter(&self) -> BitVectorRefIter<'a, N> {
         BitVectorRefIter {
@@ -788,6 +892,7 @@ where
     /// Decodes the bitvector from the given bytes.
     fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, DecodeError> {
         let expected_bytes = bytes_for_bits(N);
+        reject_empty(bytes, expected_bytes)?;
 
         // Convert to fixed-size array reference
         let bytes: &'a [u8; bytes_for_bits(N)] =
@@ -930,6 +1035,17 @@ impl<'a, const N: usize> BitListRef<'a, N> {
         Ok((byte & (1 << bit_index)) != 0)
     }
 
+    /// Returns the number of bits that are set to `true`, not counting the length bit.
+    pub fn count_ones(&self) -> usize {
+        // Decoding guarantees the length bit is the highest set bit.
+        crate::bitfield::count_ones(self.bytes) - 1
+    }
+
+    /// Returns the number of bits below `self.len()` that are set to `false`.
+    pub fn count_zeros(&self) -> usize {
+        self.bit_len - self.count_ones()
+    }
+
     /// Returns an [`Iterator`] over the bits.
     pub const fn iter(&self) -> BitListRefIter<'a, N> {
         BitListRefIter {
@@ -947,12 +1063,8 @@ impl<'a, const N: usize> BitListRef<'a, N> {
 impl<'a, const N: usize> DecodeView<'a> for BitListRef<'a, N> {
     /// Decodes the bitlist from the given bytes.
     fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, DecodeError> {
-        if bytes.is_empty() {
-            return Err(DecodeError::InvalidByteLength {
-                len: 0,
-                expected: 1,
-            });
-        }
+        // At least the byte holding the length bit
+        reject_empty(bytes, 1)?;
 
         // Find the length bit (highest set bit)
         let mut bit_len = None;
@@ -1035,6 +1147,7 @@ macro_rules! impl_decode_view_for_primitive {
     ($type:ty, $size:expr) => {
         impl<'a> DecodeView<'a> for $type {
             fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, DecodeError> {
+                reject_empty(bytes, $size)?;
                 crate::Decode::from_ssz_bytes(bytes)
             }
         }
@@ -1062,6 +1175,7 @@ impl_decode_view_for_primitive!(bool, 1);
 // Implement DecodeView for ssz_primitives types
 impl<'a, const N: usize> DecodeView<'a> for FixedBytes<N> {
     fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, DecodeError> {
+        reject_empty(bytes, N)?;
         Decode::from_ssz_bytes(bytes)
     }
 }
@@ -1078,6 +1192,7 @@ impl<const N: usize> SszTypeInfo for FixedBytes<N> {
 
 impl<'a> DecodeView<'a> for U256 {
     fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, DecodeError> {
+        reject_empty(bytes, 32)?;
         Decode::from_ssz_bytes(bytes)
     }
 }
@@ -1094,6 +1209,7 @@ impl SszTypeInfo for U256 {
 
 impl<'a> DecodeView<'a> for U128 {
     fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, DecodeError> {
+        reject_empty(bytes, 16)?;
         Decode::from_ssz_bytes(bytes)
     }
 }
@@ -1496,6 +1612,61 @@ mod tests {
         }
     }
 
+    #[test]
+    fn bitlist_ref_counts_match_owned() {
+        use crate::BitList;
+
+        // Lengths around byte and word boundaries, so the length bit lands in every position.
+        for len in [0, 1, 7, 8, 9, 63, 64, 65, 200, 2048] {
+            let mut bl = BitList::<2048>::with_capacity(len).unwrap();
+            for i in (0..len).filter(|i| i % 5 == 0 || i % 7 == 3) {
+                bl.set(i, true).unwrap();
+            }
+            let encoded = bl.as_ssz_bytes();
+
+            let view = BitListRef::<2048>::from_ssz_bytes(&encoded).unwrap();
+            assert_eq!(view.count_ones(), bl.count_ones(), "len = {len}");
+            assert_eq!(view.count_zeros(), bl.count_zeros(), "len = {len}");
+            for i in 0..len {
+                assert_eq!(view.get(i).unwrap(), bl.get(i).unwrap());
+            }
+            assert!(view.get(len).is_err());
+        }
+    }
+
+    #[test]
+    fn bitlist_ref_accepts_what_owned_accepts() {
+        use crate::BitList;
+
+        let inputs = core::iter::once(vec![])
+            .chain((0..=u8::MAX).map(|a| vec![a]))
+            .chain((0..=u16::MAX).map(|ab| ab.to_le_bytes().to_vec()));
+        for bytes in inputs {
+            let view = BitListRef::<12>::from_ssz_bytes(&bytes);
+            let owned = <BitList<12> as crate::Decode>::from_ssz_bytes(&bytes);
+            assert_eq!(view.is_ok(), owned.is_ok(), "bytes = {bytes:?}");
+            if let (Ok(view), Ok(owned)) = (view, owned) {
+                assert_eq!(view.len(), owned.len());
+                assert_eq!(view.count_ones(), owned.count_ones());
+            }
+        }
+    }
+
+    #[test]
+    fn bitvector_ref_counts_match_owned() {
+        use crate::BitVector;
+
+        let mut bv = BitVector::<100>::new();
+        for i in [0, 8, 63, 64, 99] {
+            bv.set(i, true).unwrap();
+        }
+        let encoded = bv.as_ssz_bytes();
+
+        let view = BitVectorRef::<100>::from_ssz_bytes(&encoded).unwrap();
+        assert_eq!(view.count_ones(), bv.count_ones());
+        assert_eq!(view.count_zeros(), bv.count_zeros());
+    }
+
     #[test]
     fn list_ref_iterator_exact_size() {
         let values = vec![1u64, 2, 3, 4, 5];
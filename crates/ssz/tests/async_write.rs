@@ -0,0 +1,61 @@
+//! `ssz_write_async` writes the same bytes as `as_ssz_bytes` to a tokio writer.
+
+#![allow(missing_docs)]
+#![allow(unused_crate_dependencies)]
+
+use std::{
+    pin::pin,
+    task::{Context, Poll, Waker},
+};
+
+use ssz::{Encode, ssz_write_async};
+use ssz_derive::Encode;
+
+#[derive(Encode)]
+struct Fixed {
+    a: u16,
+    b: u64,
+}
+
+#[derive(Encode)]
+struct Variable {
+    a: u16,
+    b: Vec<u16>,
+    c: Vec<Vec<u8>>,
+    d: u32,
+}
+
+/// Runs `fut`, which must not pend. Tokio's `AsyncWrite` for `Vec<u8>` is always ready, so no
+/// runtime is needed.
+fn ready<F: Future>(fut: F) -> F::Output {
+    match pin!(fut).poll(&mut Context::from_waker(Waker::noop())) {
+        Poll::Ready(output) => output,
+        Poll::Pending => panic!("in-memory write pended"),
+    }
+}
+
+#[test]
+fn matches_as_ssz_bytes() {
+    let fixed = Fixed { a: 1, b: 2 };
+    let mut buf = Vec::new();
+    ready(ssz_write_async(&fixed, &mut buf)).unwrap();
+    assert_eq!(buf, fixed.as_ssz_bytes());
+
+    let variable = Variable {
+        a: 42,
+        b: vec![1, 3, 3, 7],
+        c: vec![vec![], vec![9; 5]],
+        d: 99,
+    };
+    let mut buf = Vec::new();
+    ready(ssz_write_async(&variable, &mut buf)).unwrap();
+    assert_eq!(buf, variable.as_ssz_bytes());
+}
+
+#[test]
+fn appends_to_writer() {
+    let mut buf = vec![0xff];
+    ready(ssz_write_async(&vec![1u8, 2, 3], &mut buf)).unwrap();
+    ready(ssz_write_async(&7u16, &mut buf)).unwrap();
+    assert_eq!(buf, [0xff, 1, 2, 3, 7, 0]);
+}
@@ -0,0 +1,53 @@
+//! The derive macros expand in a `#![no_std]` crate, with or without the `std` feature of `ssz`.
+
+#![no_std]
+#![allow(missing_docs)]
+#![allow(unused_crate_dependencies)]
+
+extern crate alloc;
+
+use alloc::{vec, vec::Vec};
+
+use ssz::{Decode, Encode};
+use ssz_derive::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Header {
+    slot: u64,
+    parent: [u8; 4],
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Block {
+    header: Header,
+    txs: Vec<Vec<u8>>,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[ssz(struct_behaviour = "transparent")]
+struct Wrapped(u32);
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[ssz(enum_behaviour = "union")]
+enum Payload {
+    Block(Block),
+    Number(Wrapped),
+}
+
+#[test]
+fn derived_round_trip() {
+    let payload = Payload::Block(Block {
+        header: Header {
+            slot: 7,
+            parent: [1, 2, 3, 4],
+        },
+        txs: vec![vec![5], vec![], vec![6, 7]],
+    });
+    let bytes = payload.as_ssz_bytes();
+    assert_eq!(payload.ssz_bytes_len(), bytes.len());
+    assert_eq!(Payload::from_ssz_bytes(&bytes), Ok(payload));
+
+    let number = Payload::Number(Wrapped(9));
+    assert_eq!(number.as_ssz_bytes(), [1, 9, 0, 0, 0]);
+    assert_eq!(Payload::from_ssz_bytes(&[1, 9, 0, 0, 0]), Ok(number));
+}
@@ -16,6 +16,8 @@ use ssz::{Decode, DecodeError, Encode};
 use ssz_derive::{Decode, Encode};
 use ssz_primitives::{U128, U256};
 use thiserror as _;
+#[cfg(feature = "tokio")]
+use tokio as _;
 
 mod round_trip {
     use std::{
@@ -41,6 +43,14 @@ mod round_trip {
         for item in items {
             let encoded = &item.as_ssz_bytes();
             assert_eq!(item.ssz_bytes_len(), encoded.len());
+
+            #[cfg(feature = "std")]
+            {
+                let mut written = vec![];
+                item.ssz_write(&mut written).unwrap();
+                assert_eq!(&written, encoded);
+            }
+
             assert_eq!(T::from_ssz_bytes(encoded), Ok(item));
         }
     }
@@ -181,6 +191,16 @@ mod round_trip {
         );
     }
 
+    #[test]
+    fn fixed_len_short_bytes_incomplete() {
+        let bytes = FixedLen { a: 1, b: 2, c: 3 }.as_ssz_bytes();
+
+        assert_eq!(
+            FixedLen::from_ssz_bytes(&bytes[..10]),
+            Err(DecodeError::Incomplete { needed: 4 })
+        );
+    }
+
     #[test]
     fn vec_of_fixed_len_struct() {
         let items: Vec<FixedLen> = vec![
@@ -230,6 +250,71 @@ mod round_trip {
         assert!(VariableLen::from_ssz_bytes(&bytes).is_err());
     }
 
+    #[test]
+    fn variable_len_short_fixed_portion_incomplete() {
+        let bytes = VariableLen {
+            a: 1,
+            b: vec![2],
+            c: 3,
+        }
+        .as_ssz_bytes();
+
+        // Cut inside the offset of `b`, then inside `c`.
+        assert_eq!(
+            VariableLen::from_ssz_bytes(&bytes[..4]),
+            Err(DecodeError::Incomplete { needed: 2 })
+        );
+        assert_eq!(
+            VariableLen::from_ssz_bytes(&bytes[..8]),
+            Err(DecodeError::Incomplete { needed: 2 })
+        );
+    }
+
+    #[derive(Debug, PartialEq, Eq, Encode, Decode)]
+    struct Inner {
+        a: u64,
+        b: Vec<u8>,
+    }
+
+    #[derive(Debug, PartialEq, Eq, Encode, Decode)]
+    struct Outer {
+        x: u32,
+        items: Vec<Inner>,
+    }
+
+    #[test]
+    fn short_nested_item_is_invalid_not_incomplete() {
+        let outer = Outer {
+            x: 1,
+            items: vec![Inner { a: 2, b: vec![] }, Inner { a: 3, b: vec![4] }],
+        };
+        let mut bytes = outer.as_ssz_bytes();
+
+        // The list of `items` starts at byte 8; point its second offset 4 bytes after the
+        // first, so the first element's slice is shorter than `Inner`'s fixed portion.
+        bytes[12..16].copy_from_slice(&12u32.to_le_bytes());
+
+        // The whole buffer is present, so this is corrupt rather than short.
+        assert_eq!(
+            Outer::from_ssz_bytes(&bytes),
+            Err(DecodeError::InvalidByteLength {
+                len: 4,
+                expected: 8
+            })
+        );
+        assert_eq!(
+            Inner::from_ssz_bytes(&bytes[16..20]),
+            Err(DecodeError::Incomplete { needed: 4 })
+        );
+        assert_eq!(
+            <Option<Inner>>::from_ssz_bytes(&[1, 0, 0, 0]),
+            Err(DecodeError::InvalidByteLength {
+                len: 3,
+                expected: 8
+            })
+        );
+    }
+
     #[test]
     #[allow(clippy::zero_prefixed_literal)]
     fn first_offset_skips_byte() {
@@ -348,6 +433,42 @@ mod round_trip {
         );
     }
 
+    #[test]
+    #[allow(clippy::zero_prefixed_literal)]
+    fn last_offset_out_of_bounds() {
+        let bytes = vec![
+            //  1   2   3   4   5   6   7   8   9   10  11  12  13  14  15  16  17  18  19
+            //      | offset        | offset        | offset        | b     | c     | d
+            01, 00, 14, 00, 00, 00, 16, 00, 00, 00, 21, 00, 00, 00, 00, 00, 01, 00, 02, 00,
+        ];
+
+        assert_eq!(
+            ThreeVariableLen::from_ssz_bytes(&bytes),
+            Err(DecodeError::OffsetOutOfBounds(21))
+        );
+    }
+
+    /// Offsets are the only boundaries between variable fields, so a table can't leave a gap:
+    /// bytes between two fields belong to the earlier one. Moving a boundary off a field's end
+    /// is caught when the shifted fields fail to decode.
+    #[test]
+    #[allow(clippy::zero_prefixed_literal)]
+    fn offset_shifts_field_boundary() {
+        let bytes = vec![
+            //  1   2   3   4   5   6   7   8   9   10  11  12  13  14  15  16  17  18  19
+            //      | offset        | offset        | offset        | b     | c     | d
+            01, 00, 14, 00, 00, 00, 17, 00, 00, 00, 18, 00, 00, 00, 00, 00, 01, 00, 02, 00,
+        ];
+
+        assert_eq!(
+            ThreeVariableLen::from_ssz_bytes(&bytes),
+            Err(DecodeError::InvalidByteLength {
+                len: 1,
+                expected: 2
+            })
+        );
+    }
+
     #[test]
     fn tuple_u8_u16() {
         let vec: Vec<(u8, u16)> = vec![
@@ -533,6 +654,9 @@ mod round_trip {
 
 /// Decode tests that are expected to fail.
 mod decode_fail {
+    use std::collections::BTreeMap;
+
+    use ssz::{DecodeLimits, SszDecoderBuilder};
     use ssz_primitives::Hash256;
 
     use super::*;
@@ -548,4 +672,224 @@ mod decode_fail {
         let long_bytes = vec![0xff; 33];
         assert!(Hash256::from_ssz_bytes(&long_bytes).is_err());
     }
+
+    #[test]
+    fn btree_map_keys_not_sorted() {
+        let unsorted = vec![(1u8, 10u16), (0, 20)].as_ssz_bytes();
+        assert_eq!(
+            BTreeMap::<u8, u16>::from_ssz_bytes(&unsorted),
+            Err(DecodeError::BytesInvalid(
+                "BTreeMap keys must be strictly increasing, but key 1 is not above key 0"
+                    .to_string()
+            ))
+        );
+
+        let duplicate = vec![(0u8, 10u16), (1, 20), (1, 30)].as_ssz_bytes();
+        assert!(matches!(
+            BTreeMap::<u8, u16>::from_ssz_bytes(&duplicate),
+            Err(DecodeError::BytesInvalid(_))
+        ));
+
+        let variable = vec![(2u64, vec![1u8]), (1, vec![])].as_ssz_bytes();
+        assert!(matches!(
+            BTreeMap::<u64, Vec<u8>>::from_ssz_bytes(&variable),
+            Err(DecodeError::BytesInvalid(_))
+        ));
+    }
+
+    type Nested6 = Vec<Vec<Vec<Vec<Vec<Vec<u8>>>>>>;
+
+    /// A `u16` followed by a list nested six levels deep.
+    fn deeply_nested_payload() -> Vec<u8> {
+        let nested: Nested6 = vec![vec![vec![vec![vec![vec![1]]]]]];
+        let mut bytes = 42u16.as_ssz_bytes();
+        bytes.extend_from_slice(&6u32.to_le_bytes());
+        bytes.extend_from_slice(&nested.as_ssz_bytes());
+        bytes
+    }
+
+    fn decode_with_builder(
+        bytes: &[u8],
+        limits: Option<DecodeLimits>,
+    ) -> Result<(u16, Nested6), DecodeError> {
+        let mut builder = SszDecoderBuilder::new(bytes);
+        if let Some(limits) = limits {
+            builder = builder.with_limits(limits);
+        }
+        builder.register_type::<u16>()?;
+        builder.register_type::<Nested6>()?;
+        let mut decoder = builder.build()?;
+        Ok((decoder.decode_next()?, decoder.decode_next()?))
+    }
+
+    #[test]
+    fn decode_limits_max_depth() {
+        let bytes = deeply_nested_payload();
+        assert!(decode_with_builder(&bytes, None).is_ok());
+        assert!(decode_with_builder(&bytes, Some(DecodeLimits::default())).is_ok());
+
+        let limits = DecodeLimits {
+            max_depth: Some(3),
+            ..DecodeLimits::default()
+        };
+        assert_eq!(
+            decode_with_builder(&bytes, Some(limits)),
+            Err(DecodeError::BytesInvalid(
+                "decode nesting depth exceeds limit of 3".to_string()
+            ))
+        );
+
+        // The field holding the list sits one level below the builder.
+        let limits = DecodeLimits {
+            max_depth: Some(6),
+            ..DecodeLimits::default()
+        };
+        assert!(decode_with_builder(&bytes, Some(limits)).is_ok());
+    }
+
+    #[test]
+    fn decode_limits_max_total_len() {
+        let data: Vec<Vec<u8>> = vec![vec![]; 100];
+        let bytes = data.as_ssz_bytes();
+        let limits = DecodeLimits {
+            max_total_len: Some(10),
+            ..DecodeLimits::default()
+        };
+        assert_eq!(
+            ssz::decode_with_limits::<Vec<Vec<u8>>>(&bytes, limits),
+            Err(DecodeError::BytesInvalid(
+                "decoded list items exceed total limit of 10".to_string()
+            ))
+        );
+
+        let limits = DecodeLimits {
+            max_total_len: Some(100),
+            ..DecodeLimits::default()
+        };
+        assert_eq!(
+            ssz::decode_with_limits::<Vec<Vec<u8>>>(&bytes, limits),
+            Ok(data)
+        );
+    }
+
+    /// Decodes a list from its bytes inside its own `Decode` impl, as a
+    /// wrapper around unrelated data might.
+    #[derive(Debug, PartialEq)]
+    struct Reparsed(Vec<Vec<u8>>);
+
+    impl Decode for Reparsed {
+        fn is_ssz_fixed_len() -> bool {
+            false
+        }
+
+        fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+            Vec::from_ssz_bytes(bytes).map(Self)
+        }
+    }
+
+    #[test]
+    fn decode_limits_are_scoped_to_the_thread_and_call() {
+        let data: Vec<Vec<u8>> = vec![vec![]; 100];
+        let bytes = data.as_ssz_bytes();
+        let limits = DecodeLimits {
+            max_total_len: Some(10),
+            ..DecodeLimits::default()
+        };
+
+        // Decodes started inside a custom impl count towards the limits.
+        assert!(ssz::decode_with_limits::<Reparsed>(&bytes, limits).is_err());
+
+        // The limits end with the limited decode, even a failed one.
+        assert_eq!(Vec::<Vec<u8>>::from_ssz_bytes(&bytes), Ok(data.clone()));
+
+        // Other threads never see them.
+        let other = std::thread::spawn({
+            let bytes = bytes.clone();
+            move || Vec::<Vec<u8>>::from_ssz_bytes(&bytes).is_ok()
+        });
+        assert!(ssz::decode_with_limits::<Reparsed>(&bytes, limits).is_err());
+        assert!(other.join().unwrap());
+    }
+}
+
+#[cfg(feature = "std")]
+mod encoder {
+    use std::io::Cursor;
+
+    use ssz::SszEncoder;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Encode, Decode)]
+    struct Blob {
+        id: u64,
+        tag: Vec<u8>,
+        data: Vec<u8>,
+    }
+
+    fn fixed_len() -> usize {
+        <u64 as Encode>::ssz_fixed_len() + 2 * <Vec<u8> as Encode>::ssz_fixed_len()
+    }
+
+    #[test]
+    fn append_from_reader_streams_trailing_bytes() {
+        let data: Vec<u8> = (0..1 << 20).map(|i| (i % 251) as u8).collect();
+        let blob = Blob {
+            id: 9,
+            tag: vec![1, 2, 3],
+            data: data.clone(),
+        };
+
+        let mut buf = vec![];
+        let mut encoder = SszEncoder::container(&mut buf, fixed_len());
+        encoder.append(&blob.id);
+        encoder.append(&blob.tag);
+        encoder
+            .append_from_reader(&mut Cursor::new(&data), data.len())
+            .unwrap();
+        encoder.finalize();
+
+        assert_eq!(buf, blob.as_ssz_bytes());
+        assert_eq!(Blob::from_ssz_bytes(&buf), Ok(blob));
+    }
+
+    #[test]
+    fn append_from_reader_reads_only_len_bytes() {
+        let mut reader = Cursor::new(vec![4, 5, 6, 7]);
+        let mut buf = vec![];
+        let mut encoder = SszEncoder::container(&mut buf, fixed_len());
+        encoder.append(&1u64);
+        encoder.append_from_reader(&mut reader, 2).unwrap();
+        encoder.append_from_reader(&mut reader, 2).unwrap();
+        encoder.finalize();
+
+        let expected = Blob {
+            id: 1,
+            tag: vec![4, 5],
+            data: vec![6, 7],
+        };
+        assert_eq!(buf, expected.as_ssz_bytes());
+    }
+
+    #[test]
+    fn append_from_reader_short_read_errors() {
+        let mut buf = vec![];
+        let mut encoder = SszEncoder::container(&mut buf, fixed_len());
+        encoder.append(&1u64);
+        encoder.append(&vec![8u8]);
+        let err = encoder
+            .append_from_reader(&mut Cursor::new(vec![1, 2, 3]), 4)
+            .unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+
+        // The failed field left no trace, so the encoding can still be completed.
+        encoder.append(&vec![9u8, 9]);
+        encoder.finalize();
+        let expected = Blob {
+            id: 1,
+            tag: vec![8],
+            data: vec![9, 9],
+        };
+        assert_eq!(buf, expected.as_ssz_bytes());
+    }
 }
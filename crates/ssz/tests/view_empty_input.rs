@@ -0,0 +1,93 @@
+//! Decoding views from an empty slice: types whose encoding can be empty
+//! decode to an empty view, every other type fails with
+//! `DecodeError::InvalidByteLength` expecting its minimum encoded length.
+
+#![allow(missing_docs)]
+#![allow(unused_crate_dependencies)]
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs)]
+
+use ssz::{
+    DecodeError,
+    view::{
+        BitListRef, BitVectorRef, BytesRef, DecodeView, FixedBytesRef, ListRef, UnionRef, VectorRef,
+    },
+};
+use ssz_primitives::{FixedBytes, U128, U256};
+
+fn too_short(expected: usize) -> DecodeError {
+    DecodeError::InvalidByteLength { len: 0, expected }
+}
+
+#[test]
+fn lists_decode_empty() {
+    assert!(BytesRef::<8>::from_ssz_bytes(&[]).unwrap().is_empty());
+    assert!(ListRef::<u64, 8>::from_ssz_bytes(&[]).unwrap().is_empty());
+    assert!(
+        ListRef::<BytesRef<'_, 4>, 8>::from_ssz_bytes(&[])
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[test]
+fn primitives_need_their_width() {
+    assert_eq!(<bool as DecodeView>::from_ssz_bytes(&[]), Err(too_short(1)));
+    assert_eq!(<u8 as DecodeView>::from_ssz_bytes(&[]), Err(too_short(1)));
+    assert_eq!(<u64 as DecodeView>::from_ssz_bytes(&[]), Err(too_short(8)));
+    assert_eq!(
+        <u128 as DecodeView>::from_ssz_bytes(&[]),
+        Err(too_short(16))
+    );
+    assert_eq!(
+        <U128 as DecodeView>::from_ssz_bytes(&[]),
+        Err(too_short(16))
+    );
+    assert_eq!(
+        <U256 as DecodeView>::from_ssz_bytes(&[]),
+        Err(too_short(32))
+    );
+}
+
+#[test]
+fn fixed_bytes_need_their_length() {
+    assert_eq!(
+        FixedBytesRef::<4>::from_ssz_bytes(&[]).unwrap_err(),
+        too_short(4)
+    );
+    assert_eq!(
+        <FixedBytes<32> as DecodeView>::from_ssz_bytes(&[]),
+        Err(too_short(32))
+    );
+}
+
+#[test]
+fn vectors_need_their_fixed_portion() {
+    assert_eq!(VectorRef::<u16, 3>::new(&[]).unwrap_err(), too_short(6));
+    assert_eq!(
+        VectorRef::<BytesRef<'_, 4>, 2>::new(&[]).unwrap_err(),
+        too_short(8)
+    );
+}
+
+#[test]
+fn bitfields_need_bytes() {
+    assert_eq!(
+        BitVectorRef::<12>::from_ssz_bytes(&[]).unwrap_err(),
+        too_short(2)
+    );
+    // Even an empty bitlist has the byte holding its length bit.
+    assert_eq!(
+        BitListRef::<8>::from_ssz_bytes(&[]).unwrap_err(),
+        too_short(1)
+    );
+}
+
+#[test]
+fn unions_need_a_selector() {
+    assert_eq!(
+        UnionRef::<u8>::from_ssz_bytes(&[]).unwrap_err(),
+        too_short(1)
+    );
+    assert_eq!(ssz::split_union_bytes(&[]).unwrap_err(), too_short(1));
+}
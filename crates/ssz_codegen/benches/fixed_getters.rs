@@ -0,0 +1,90 @@
+//! Benchmarks reading every field of a `FixedVectorRef<DeltaRef, N>`, either
+//! through the generated getters, which slice fixed byte ranges directly, or
+//! by locating each field with `ssz::layout::read_field_bytes` as the getters
+//! of containers with runtime layouts do.
+
+#![allow(missing_docs, reason = "criterion macros are annoying clippy")]
+#![allow(
+    unused_crate_dependencies,
+    reason = "criterion macros are annoying clippy"
+)]
+#![allow(dead_code)]
+
+use std::hint::black_box;
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+
+include!("../tests/expected_output/test_vector_view_iter.rs");
+
+use ssz::{DecodeError, Encode, layout::FieldInfo, view::DecodeView};
+use ssz_types::{FixedVector, view::FixedVectorRef};
+use tests::input::test_vector_view_iter::{Delta, DeltaRef};
+
+const LEN: usize = 1024;
+
+/// Layout table of [`Delta`], as passed to `read_field_bytes`.
+const DELTA_LAYOUT: &[FieldInfo] = &[(true, 1), (true, 1)];
+
+/// Helper to generate an encoded vector of [`LEN`] deltas.
+fn encoded_deltas() -> Vec<u8> {
+    FixedVector::<Delta, LEN>::new(
+        (0..LEN)
+            .map(|i| Delta {
+                z: i % 2 == 0,
+                w: i as u8,
+            })
+            .collect(),
+    )
+    .unwrap()
+    .as_ssz_bytes()
+}
+
+/// Validates `bytes` as a [`DeltaRef`] and reads both fields through
+/// `read_field_bytes`.
+fn read_checked(bytes: &[u8]) -> Result<(bool, u8), DecodeError> {
+    DeltaRef::from_ssz_bytes(bytes)?;
+    let z = ssz::layout::read_field_bytes(bytes, DELTA_LAYOUT, 0)?;
+    let w = ssz::layout::read_field_bytes(bytes, DELTA_LAYOUT, 1)?;
+    Ok((
+        DecodeView::from_ssz_bytes(z)?,
+        DecodeView::from_ssz_bytes(w)?,
+    ))
+}
+
+/// Benchmark field access through direct slicing vs `read_field_bytes`.
+fn bench_fixed_getters(c: &mut Criterion) {
+    let mut group = c.benchmark_group("fixed_getters");
+    let encoded = encoded_deltas();
+
+    group.throughput(Throughput::Elements(LEN as u64));
+
+    group.bench_with_input(BenchmarkId::new("direct", LEN), &encoded, |b, encoded| {
+        b.iter(|| {
+            let view = FixedVectorRef::<DeltaRef<'_>, LEN>::from_ssz_bytes(black_box(encoded))
+                .expect("view");
+            for delta in view.iter() {
+                let delta = delta.expect("delta");
+                black_box((delta.z().expect("z"), delta.w().expect("w")));
+            }
+        });
+    });
+
+    group.bench_with_input(
+        BenchmarkId::new("read_field_bytes", LEN),
+        &encoded,
+        |b, encoded| {
+            b.iter(|| {
+                let view = FixedVectorRef::<DeltaRef<'_>, LEN>::from_ssz_bytes(black_box(encoded))
+                    .expect("view");
+                for bytes in view.as_bytes().chunks_exact(2) {
+                    black_box(read_checked(bytes).expect("fields"));
+                }
+            });
+        },
+    );
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_fixed_getters);
+criterion_main!(benches);
@@ -0,0 +1,66 @@
+//! Benchmarks hashing [`COUNT`] `Delta` containers one at a time, with the
+//! `tree_hash_root` generated by default, which allocates a fresh
+//! `MerkleHasher` per container, and with the one generated under
+//! `pooled_tree_hash`, which reuses a thread-local hasher.
+
+#![allow(missing_docs, reason = "criterion macros are annoying clippy")]
+#![allow(
+    unused_crate_dependencies,
+    reason = "criterion macros are annoying clippy"
+)]
+#![allow(dead_code)]
+
+use std::hint::black_box;
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use tree_hash::{Sha256Hasher, TreeHash};
+
+pub mod pooled {
+    include!("../tests/expected_output/test_pooled_tree_hash.rs");
+}
+
+pub mod plain {
+    include!("../tests/expected_output/test_vector_view_iter.rs");
+}
+
+const COUNT: usize = 1_000_000;
+
+/// Benchmark container roots with and without the pooled hasher.
+fn bench_pooled_tree_hash(c: &mut Criterion) {
+    let mut group = c.benchmark_group("pooled_tree_hash");
+    group.sample_size(10);
+    group.throughput(Throughput::Elements(COUNT as u64));
+
+    let plain: Vec<_> = (0..COUNT)
+        .map(|i| plain::tests::input::test_vector_view_iter::Delta {
+            z: i % 2 == 0,
+            w: i as u8,
+        })
+        .collect();
+    group.bench_with_input(BenchmarkId::new("fresh", COUNT), &plain, |b, deltas| {
+        b.iter(|| {
+            for delta in black_box(deltas) {
+                black_box(delta.tree_hash_root::<Sha256Hasher>());
+            }
+        });
+    });
+
+    let pooled: Vec<_> = (0..COUNT)
+        .map(|i| pooled::tests::input::test_pooled_tree_hash::Delta {
+            z: i % 2 == 0,
+            w: i as u8,
+        })
+        .collect();
+    group.bench_with_input(BenchmarkId::new("pooled", COUNT), &pooled, |b, deltas| {
+        b.iter(|| {
+            for delta in black_box(deltas) {
+                black_box(delta.tree_hash_root::<Sha256Hasher>());
+            }
+        });
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_pooled_tree_hash);
+criterion_main!(benches);
@@ -0,0 +1,86 @@
+//! Benchmarks converting many views to owned values, either allocating a
+//! fresh value per view with `to_owned` or reusing one with `to_owned_into`.
+
+#![allow(missing_docs, reason = "criterion macros are annoying clippy")]
+#![allow(
+    unused_crate_dependencies,
+    reason = "criterion macros are annoying clippy"
+)]
+#![allow(dead_code)]
+
+use std::hint::black_box;
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+
+include!("../tests/expected_output/test_to_owned_into.rs");
+
+use ssz::{Encode, view::DecodeView};
+use ssz_types::{FixedBytes, FixedVector, VariableList};
+use tests::input::test_to_owned_into::{Batch, BatchRef, Item};
+
+/// Helper to generate an encoded [`Batch`] whose lists hold `len` items.
+fn encoded_batch(index: u32, len: usize) -> Vec<u8> {
+    Batch {
+        index,
+        payload: VariableList::new(vec![index as u8; len * 4]).unwrap(),
+        values: VariableList::new((0..len as u64).collect()).unwrap(),
+        items: VariableList::new(
+            (0..len as u64)
+                .map(|id| Item {
+                    id,
+                    flag: id % 2 == 0,
+                })
+                .collect(),
+        )
+        .unwrap(),
+        roots: FixedVector::new(vec![index as u64; 4]).unwrap(),
+        tag: FixedBytes([index as u8; 32]),
+        parent: Item {
+            id: index as u64,
+            flag: true,
+        },
+    }
+    .as_ssz_bytes()
+}
+
+/// Benchmark converting a batch of views with `to_owned` vs `to_owned_into`.
+fn bench_views_to_owned(c: &mut Criterion) {
+    let mut group = c.benchmark_group("views_to_owned");
+
+    for len in [4, 16, 64] {
+        let encoded: Vec<Vec<u8>> = (0..256).map(|i| encoded_batch(i, len)).collect();
+
+        group.throughput(Throughput::Elements(encoded.len() as u64));
+
+        group.bench_with_input(BenchmarkId::new("to_owned", len), &encoded, |b, encoded| {
+            b.iter(|| {
+                for bytes in encoded {
+                    let view = BatchRef::from_ssz_bytes(black_box(bytes)).expect("view");
+                    black_box(view.to_owned());
+                }
+            });
+        });
+
+        group.bench_with_input(
+            BenchmarkId::new("to_owned_into", len),
+            &encoded,
+            |b, encoded| {
+                let mut out = BatchRef::from_ssz_bytes(&encoded[0])
+                    .expect("view")
+                    .to_owned();
+                b.iter(|| {
+                    for bytes in encoded {
+                        let view = BatchRef::from_ssz_bytes(black_box(bytes)).expect("view");
+                        view.to_owned_into(&mut out).expect("to_owned_into");
+                        black_box(&out);
+                    }
+                });
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_views_to_owned);
+criterion_main!(benches);
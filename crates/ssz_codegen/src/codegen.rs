@@ -3,19 +3,23 @@
 use std::{
     cell::RefCell,
     collections::{HashMap, HashSet},
-    path::PathBuf,
+    path::{Path, PathBuf},
 };
 
-use proc_macro2::{Span, TokenStream};
+use proc_macro2::{Literal, Span, TokenStream};
 use quote::quote;
-use sizzle_parser::{AliasDef as ParserAliasDef, ClassDef as ParserClassDef, SszSchema, tysys::Ty};
+use sizzle_parser::{
+    AliasDef as ParserAliasDef, ClassDef as ParserClassDef, Identifier, SszSchema,
+    tysys::{Ty, TyExpr},
+};
 use syn::{Ident, parse_quote};
+use tree_hash::{Sha256Hasher, TreeHashDigest};
 
 use crate::{
     ModuleGeneration,
     derive_config::DeriveConfig,
     types::{
-        BaseClass, ClassDef, ClassDefinition, ClassFieldDef, TypeResolutionKind,
+        BaseClass, ClassDef, ClassDefinition, ClassFieldDef, TypeResolution, TypeResolutionKind,
         resolver::TypeResolver,
     },
 };
@@ -40,6 +44,8 @@ struct CircleBufferCodegen<'a> {
     tokens: Vec<TokenStream>,
     /// Derive configuration used during generation
     derive_cfg: &'a DeriveConfig,
+    /// Modules whose definitions are emitted into the same Rust module as this one
+    shared_namespace: &'a HashSet<PathBuf>,
 }
 
 impl<'a> CircleBufferCodegen<'a> {
@@ -49,6 +55,8 @@ impl<'a> CircleBufferCodegen<'a> {
     ///
     /// * `aliases` - The alias definitions to process
     /// * `classes` - The class definitions to process
+    /// * `derive_cfg` - Configuration for derive macros
+    /// * `shared_namespace` - Modules emitted into the same Rust module as this one
     ///
     /// # Returns
     ///
@@ -57,6 +65,7 @@ impl<'a> CircleBufferCodegen<'a> {
         aliases: &'a [ParserAliasDef],
         classes: &'a [ParserClassDef],
         derive_cfg: &'a DeriveConfig,
+        shared_namespace: &'a HashSet<PathBuf>,
     ) -> Self {
         let items: Vec<AliasOrClass<'a>> = aliases
             .iter()
@@ -67,6 +76,7 @@ impl<'a> CircleBufferCodegen<'a> {
             items,
             tokens: Vec::new(),
             derive_cfg,
+            shared_namespace,
         }
     }
 
@@ -92,6 +102,15 @@ impl<'a> CircleBufferCodegen<'a> {
             return false;
         }
 
+        // `from module import Name` re-exports an item under its own name. When both modules
+        // share a Rust module, the original definition is already in scope.
+        if let Ty::Imported(path, name, _) = alias.ty()
+            && name == alias.name()
+            && self.shared_namespace.contains(path)
+        {
+            return true;
+        }
+
         if type_def.is_type()
             && !type_resolver
                 .union_tracker
@@ -105,6 +124,15 @@ impl<'a> CircleBufferCodegen<'a> {
                     pub const #ident: u64 = #ty;
                 });
             } else {
+                // Unions named by an alias share their members' gate, but plain
+                // type aliases carry no pragmas to gate them by.
+                if let Some((gated, feature)) = type_def.feature_gate(type_resolver) {
+                    panic!(
+                        "alias `{ident}` references `{gated}`, which is gated behind feature \
+                         `{feature}`; aliases cannot be gated, so use `{gated}` directly in a \
+                         class with `#~# feature: {feature}`"
+                    );
+                }
                 let ty = if type_def.contains_const_ref() {
                     type_def.unwrap_type_preserving_const_names()
                 } else {
@@ -113,6 +141,19 @@ impl<'a> CircleBufferCodegen<'a> {
                 self.tokens.push(quote! {
                     pub type #ident = #ty;
                 });
+
+                // Getters of fields typed by a from-imported class return the
+                // class's view type under the imported name.
+                if let Ty::Imported(_, name, _) = alias.ty()
+                    && name == alias.name()
+                    && matches!(type_def.resolution, TypeResolutionKind::Class(_))
+                {
+                    let ref_ident = Ident::new(&format!("{ident}Ref"), Span::call_site());
+                    let view_ty = type_def.to_view_type();
+                    self.tokens.push(quote! {
+                        pub type #ref_ident<'a> = #view_ty;
+                    });
+                }
             }
         }
 
@@ -154,6 +195,15 @@ impl<'a> CircleBufferCodegen<'a> {
         parent_class_def.doc_comment = class.doc_comment().map(|s| s.to_string());
         parent_class_def.doc = class.doc().map(|s| s.to_string());
 
+        // A class may refer to itself through a list, so its name has to
+        // resolve while its fields are.
+        let self_referential = class.fields().iter().any(|field| {
+            field
+                .ty()
+                .is_some_and(|ty| ty.iter_idents().any(|id| id == class.name()))
+        });
+        let declared = self_referential && type_resolver.declare_class_type(&ident);
+
         let success = match parent_class_def.base {
             BaseClass::Container | BaseClass::StableContainer(_) => {
                 self.process_simple_inheritance(&mut parent_class_def, class, type_resolver)
@@ -175,21 +225,84 @@ impl<'a> CircleBufferCodegen<'a> {
             }
         };
 
+        if !success && declared {
+            type_resolver.undeclare_class_type(&ident);
+        }
+
         if success {
+            parent_class_def.check_optional_tail(&ident, type_resolver);
+            let pragmas = crate::pragma::ParsedPragma::parse(&parent_class_def.pragmas);
+            let feature = pragmas.feature.as_deref();
+            if let Ty::Simple(parent) | Ty::Complex(parent, _) = parent_ty {
+                check_feature_gate(
+                    &class.name().0,
+                    feature,
+                    type_resolver
+                        .feature_gates
+                        .get(&parent.0)
+                        .map(|gate| (parent.0.clone(), gate.clone())),
+                );
+            }
+            for field in &parent_class_def.fields {
+                check_feature_gate(
+                    &class.name().0,
+                    feature,
+                    field.ty.feature_gate(type_resolver),
+                );
+            }
+            let first_item = self.tokens.len();
+
             // Generate owned struct
             self.tokens
                 .push(parent_class_def.to_token_stream(&ident, self.derive_cfg));
 
+            // Generate Default implementation for owned struct if enabled
+            if self.derive_cfg.default_impl {
+                self.tokens
+                    .push(parent_class_def.to_default_impl(&ident, type_resolver));
+            }
+
+            // Generate fixed field offsets for all-fixed containers
+            self.tokens
+                .push(parent_class_def.to_field_offsets_impl(&ident, type_resolver));
+
+            // Generate field reflection for owned struct if enabled
+            if self.derive_cfg.field_info {
+                self.tokens
+                    .push(parent_class_def.to_field_info_impl(&ident));
+            }
+
+            // Generate hex Debug for owned struct if enabled
+            if self.derive_cfg.hex_debug {
+                self.tokens
+                    .push(parent_class_def.to_hex_debug_impl(&ident, self.derive_cfg));
+            }
+
+            // Generate Merkle-root equality for owned struct if enabled
+            if self.derive_cfg.root_eq {
+                self.tokens.push(parent_class_def.to_root_eq_impl(&ident));
+            }
+
+            // Generate field diffing for owned struct if enabled
+            if self.derive_cfg.diff {
+                self.tokens.push(parent_class_def.to_diff_impl(&ident));
+            }
+
             // Generate generic TreeHash implementation for owned struct
             self.tokens
-                .push(parent_class_def.to_owned_tree_hash_impl(&ident));
+                .push(parent_class_def.to_owned_tree_hash_impl(&ident, self.derive_cfg));
 
             // Generate view struct (thin wrapper)
             self.tokens
                 .push(parent_class_def.to_view_struct(&ident, self.derive_cfg));
 
             // Generate getter methods for view struct
-            self.tokens.push(parent_class_def.to_view_getters(&ident));
+            self.tokens
+                .push(parent_class_def.to_view_getters(&ident, type_resolver));
+
+            // Generate deep validation for view struct
+            self.tokens
+                .push(parent_class_def.to_view_validate_deep_impl(&ident));
 
             // Generate TreeHash implementation for view struct
             self.tokens
@@ -199,6 +312,10 @@ impl<'a> CircleBufferCodegen<'a> {
             self.tokens
                 .push(parent_class_def.to_view_decode_impl(&ident));
 
+            // Generate TryFrom<&[u8]> and as_bytes for the view struct
+            self.tokens
+                .push(parent_class_def.to_view_bytes_impl(&ident));
+
             // Generate SszTypeInfo implementation (required for lists/vectors)
             self.tokens
                 .push(parent_class_def.to_view_ssz_type_info_impl(&ident));
@@ -211,6 +328,59 @@ impl<'a> CircleBufferCodegen<'a> {
             self.tokens
                 .push(parent_class_def.to_view_to_owned_impl(&ident));
 
+            // Generate validate_and_to_owned, which falls back to field defaults
+            if self.derive_cfg.default_impl {
+                self.tokens
+                    .push(parent_class_def.to_view_validate_impl(&ident, type_resolver));
+            }
+
+            // Generate Display/Error implementations for `error_type` classes
+            self.tokens.push(parent_class_def.to_error_impl(&ident));
+
+            // Generate TryFromIter/Extend implementations for `collection` classes
+            self.tokens
+                .push(parent_class_def.to_collection_impl(&ident));
+
+            // Generate AsRef/Borrow/Deref implementations for `newtype` classes
+            self.tokens.push(parent_class_def.to_newtype_impl(&ident));
+
+            // Generate Add/Sub/Ord implementations for `unit` classes
+            self.tokens.push(parent_class_def.to_unit_impl(&ident));
+
+            // Generate narrowing from the StableContainer view for profiles
+            self.tokens
+                .push(parent_class_def.to_profile_narrowing_impl(&ident, type_resolver));
+
+            // Generate conversion up to the base StableContainer for profiles
+            self.tokens
+                .push(parent_class_def.to_profile_widening_impl(&ident, type_resolver));
+
+            // Generate comparisons between the view and the owned struct
+            if self
+                .derive_cfg
+                .owned_derives_partial_eq(&class.name().0, &pragmas)
+            {
+                self.tokens
+                    .push(parent_class_def.to_view_owned_eq_impl(&ident, type_resolver));
+            }
+
+            if self
+                .derive_cfg
+                .owned_derives_hash(&class.name().0, &pragmas)
+            {
+                type_resolver.hash_classes.insert(class.name().0.clone());
+            }
+
+            if let Some(feature) = pragmas.feature {
+                for tokens in &mut self.tokens[first_item..] {
+                    *tokens =
+                        crate::pragma::ParsedPragma::cfg_gate(std::mem::take(tokens), &feature);
+                }
+                type_resolver
+                    .feature_gates
+                    .insert(class.name().0.clone(), feature);
+            }
+
             type_resolver.add_class(&ident, parent_class_def);
             return true;
         }
@@ -319,10 +489,21 @@ impl<'a> CircleBufferCodegen<'a> {
             let field_ty = field.ty();
             // Handle unit variants (no type)
             let (field_type, ty) = if let Some(field_ty) = field_ty {
-                let field_type = type_resolver.resolve_type(field_ty, None);
+                let mut field_type = type_resolver.resolve_type(field_ty, None);
                 if field_type.is_unresolved() {
                     return false;
                 }
+                // An `Optional[T]` field of a plain container is encoded like
+                // `Union[null, T]`: a selector byte, followed by the value
+                // when the selector is 1.
+                if parent_class_def.base == BaseClass::Container
+                    && let TypeResolutionKind::Optional(inner) = field_type.resolution
+                {
+                    field_type = TypeResolution {
+                        ty: None,
+                        resolution: TypeResolutionKind::Option(inner),
+                    };
+                }
                 let ty = field_type.unwrap_type();
                 (field_type, ty)
             } else {
@@ -333,11 +514,7 @@ impl<'a> CircleBufferCodegen<'a> {
 
             // Make sure the field is compatible with the parent class
             match parent_class_def.base {
-                BaseClass::Container => {
-                    if matches!(field_type.resolution, TypeResolutionKind::Optional(_)) {
-                        panic!("Optional fields are not allowed in Container classes");
-                    }
-                }
+                BaseClass::Container => {}
                 BaseClass::StableContainer(_) => {
                     if !matches!(field_type.resolution, TypeResolutionKind::Optional(_))
                         && !matches!(field_type.resolution, TypeResolutionKind::External)
@@ -360,7 +537,7 @@ impl<'a> CircleBufferCodegen<'a> {
                 // Build field token with pragma attributes and doc comment
                 let field_pragmas = crate::pragma::ParsedPragma::parse(field.pragmas());
                 let field_doc = if let Some(doc) = &new_field.doc_comment {
-                    ClassDef::format_doc_comment(doc)
+                    ClassDef::format_doc_comment(doc, self.derive_cfg.doc_comment_style)
                 } else {
                     quote! {}
                 };
@@ -499,7 +676,7 @@ impl<'a> CircleBufferCodegen<'a> {
                 // Build field token with pragma attributes and doc comment
                 let field_pragmas = crate::pragma::ParsedPragma::parse(field.pragmas());
                 let field_doc = if let Some(doc) = &new_field.doc_comment {
-                    ClassDef::format_doc_comment(doc)
+                    ClassDef::format_doc_comment(doc, self.derive_cfg.doc_comment_style)
                 } else {
                     quote! {}
                 };
@@ -570,6 +747,16 @@ impl<'a> CircleBufferCodegen<'a> {
             variant_doc_comments.push(field.doc_comment().map(|s| s.to_string()));
         }
 
+        let pragmas = crate::pragma::ParsedPragma::parse(class.pragmas());
+        let feature = pragmas.feature;
+        for ty in &args {
+            check_feature_gate(
+                &union_name,
+                feature.as_deref(),
+                ty.feature_gate(type_resolver),
+            );
+        }
+
         // Generate doc comments for the union type itself
         let union_doc_comments = {
             let doc = class.doc().map(|s| s.to_string());
@@ -577,10 +764,14 @@ impl<'a> CircleBufferCodegen<'a> {
             match (&doc, &doc_comment) {
                 (Some(docstring), Some(comment)) => {
                     let merged = format!("{}\n\n{}", docstring.trim(), comment.trim());
-                    ClassDef::format_doc_comment(&merged)
+                    ClassDef::format_doc_comment(&merged, self.derive_cfg.doc_comment_style)
+                }
+                (Some(docstring), None) => {
+                    ClassDef::format_doc_comment(docstring, self.derive_cfg.doc_comment_style)
+                }
+                (None, Some(comment)) => {
+                    ClassDef::format_doc_comment(comment, self.derive_cfg.doc_comment_style)
                 }
-                (Some(docstring), None) => ClassDef::format_doc_comment(docstring),
-                (None, Some(comment)) => ClassDef::format_doc_comment(comment),
                 (None, None) => quote! {},
             }
         };
@@ -597,7 +788,7 @@ impl<'a> CircleBufferCodegen<'a> {
                 let variant_doc = variant_doc_comments
                     .get(i)
                     .and_then(|opt| opt.as_ref())
-                    .map(|doc| ClassDef::format_doc_comment(doc))
+                    .map(|doc| ClassDef::format_doc_comment(doc, self.derive_cfg.doc_comment_style))
                     .unwrap_or_else(|| quote! {});
 
                 match ty.resolution {
@@ -658,9 +849,28 @@ impl<'a> CircleBufferCodegen<'a> {
             })
             .collect();
 
+        let arbitrary_derive = self
+            .derive_cfg
+            .arbitrary
+            .then(|| quote! { , arbitrary::Arbitrary });
+        // Hashing covers the selector discriminant and the payload, so it's
+        // derived only when every member can be hashed.
+        let derives_hash = args.iter().all(|ty| ty.is_hashable(type_resolver));
+        let hash_derive = derives_hash.then(|| quote! { , Hash });
+        if derives_hash {
+            type_resolver.hash_classes.insert(union_name.clone());
+        }
+
+        let display_impl =
+            type_resolver.generate_union_display_impl(union_ident, &args, &variant_names);
+        let examples_impl =
+            type_resolver.generate_union_examples_impl(union_ident, &args, &variant_names);
+        let from_impls =
+            type_resolver.generate_union_from_impls(union_ident, &args, &variant_names);
+
         let union_code = quote! {
             #union_doc_comments
-            #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+            #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode #hash_derive #arbitrary_derive)]
             #[ssz(enum_behaviour="union")]
             pub enum #union_ident {
                 #(#variants),*
@@ -685,6 +895,12 @@ impl<'a> CircleBufferCodegen<'a> {
                     }
                 }
             }
+
+            #display_impl
+
+            #from_impls
+
+            #examples_impl
         };
 
         type_resolver
@@ -698,6 +914,7 @@ impl<'a> CircleBufferCodegen<'a> {
             &args,
             &variant_names,
             &variant_pragmas,
+            &variant_doc_comments,
         );
 
         type_resolver
@@ -705,6 +922,17 @@ impl<'a> CircleBufferCodegen<'a> {
             .borrow_mut()
             .insert(format!("{}Ref", union_name), view_union_code);
 
+        if let Some(feature) = feature {
+            let mut tracker = type_resolver.union_tracker.borrow_mut();
+            for key in [union_name.clone(), format!("{}Ref", union_name)] {
+                if let Some(code) = tracker.remove(&key) {
+                    tracker.insert(key, crate::pragma::ParsedPragma::cfg_gate(code, &feature));
+                }
+            }
+            drop(tracker);
+            type_resolver.feature_gates.insert(union_name, feature);
+        }
+
         let class_def = ClassDef {
             base: BaseClass::Union,
             fields: vec![],
@@ -720,6 +948,19 @@ impl<'a> CircleBufferCodegen<'a> {
     }
 }
 
+/// Panics unless a gated class referenced by `class` is gated behind the same
+/// feature as `class` itself.
+fn check_feature_gate(class: &str, feature: Option<&str>, gate: Option<(String, String)>) {
+    if let Some((gated, required)) = gate
+        && feature != Some(required.as_str())
+    {
+        panic!(
+            "type `{class}` references `{gated}`, which is gated behind feature `{required}`; \
+             add `#~# feature: {required}` to `{class}`"
+        );
+    }
+}
+
 /// Represents a node in the module hierarchy
 #[derive(Debug)]
 struct ModuleNode {
@@ -762,9 +1003,12 @@ fn generate_module_code(
 /// Generates a single flat module with all definitions at the root level
 fn single_module_rust_code(
     schema_map: &HashMap<&PathBuf, TokenStream>,
+    schema_hashes: &HashMap<&PathBuf, [u8; 32]>,
     entry_point_paths: &HashSet<PathBuf>,
+    derive_cfg: &DeriveConfig,
 ) -> TokenStream {
     let mut all_tokens = Vec::new();
+    let mut entry_hashes = Vec::new();
 
     // Sort paths to ensure consistent ordering
     let mut paths: Vec<_> = schema_map.keys().collect();
@@ -776,18 +1020,23 @@ fn single_module_rust_code(
             && let Some(tokens) = schema_map.get(path)
         {
             all_tokens.push(tokens.clone());
+            entry_hashes.push(schema_hashes[*path]);
         }
     }
 
+    // All entry points share one module, so they share one hash as well.
+    let schema_hash = match entry_hashes.as_slice() {
+        [hash] => *hash,
+        hashes => Sha256Hasher::hash_fixed(hashes.concat().as_slice()).0,
+    };
+    let schema_hash = schema_hash_const(&schema_hash);
+    let imports = module_imports(derive_cfg);
+
     quote! {
         #![allow(unused_imports, reason = "generated code using ssz-gen")]
-        use ssz_types::*;
-        use ssz_types::view::{FixedVectorRef, VariableListRef};
-        use ssz_primitives::{U128, U256};
-        use ssz_derive::{Encode, Decode};
-        use tree_hash::TreeHashDigest;
-        use tree_hash_derive::TreeHash;
-        use ssz::view::*;
+        #imports
+
+        #schema_hash
 
         #(#all_tokens)*
     }
@@ -796,8 +1045,11 @@ fn single_module_rust_code(
 /// Generates flat modules without deep nesting (one level per file)
 fn flat_modules_rust_code(
     schema_map: &HashMap<&PathBuf, TokenStream>,
+    schema_hashes: &HashMap<&PathBuf, [u8; 32]>,
     entry_point_paths: &HashSet<PathBuf>,
+    derive_cfg: &DeriveConfig,
 ) -> TokenStream {
+    let imports = module_imports(derive_cfg);
     let mut modules = Vec::new();
 
     // Sort paths to ensure consistent ordering
@@ -811,16 +1063,13 @@ fn flat_modules_rust_code(
             let module_ident = Ident::new(&module_name, Span::call_site());
 
             if let Some(content_tokens) = schema_map.get(path) {
+                let schema_hash = schema_hash_const(&schema_hashes[*path]);
                 modules.push(quote! {
                     pub mod #module_ident {
                         #![allow(unused_imports, reason = "generated code using ssz-gen")]
-                        use ssz_types::*;
-                        use ssz_types::view::{FixedVectorRef, VariableListRef};
-                        use ssz_primitives::{U128, U256};
-                        use ssz_derive::{Encode, Decode};
-                        use tree_hash::TreeHashDigest;
-                        use tree_hash_derive::TreeHash;
-                        use ssz::view::*;
+                        #imports
+
+                        #schema_hash
 
                         #content_tokens
                     }
@@ -886,33 +1135,237 @@ fn module_tokens_to_rust_code(schema_map: &HashMap<&PathBuf, TokenStream>) -> To
     }
 }
 
-/// Converts mapping of module path => SSZ schemas into a Rust code token stream
+/// Checks the pragmas of every class, in `parsing_order`, and returns an error
+/// naming the first class that lists a derive its generated type can't have
+/// (see [`ParsedPragma::check_derives`](crate::pragma::ParsedPragma::check_derives))
+/// or is an `error_type` without exactly one `error_message` field (see
+/// [`ParsedPragma::check_error_type`](crate::pragma::ParsedPragma::check_error_type)).
 ///
-/// # Arguments
+/// The `build_ssz_files` entry points run this before generating code.
+pub fn check_class_pragmas(
+    parsing_order: &[PathBuf],
+    schema_map: &HashMap<PathBuf, SszSchema>,
+) -> Result<(), String> {
+    for path in parsing_order {
+        for class in schema_map[path].classes() {
+            let kind = if class.parent_ty().base_name().0 == "Union" {
+                "unions"
+            } else {
+                "containers"
+            };
+            let pragmas = crate::pragma::ParsedPragma::parse(class.pragmas());
+            pragmas.check_derives(&class.name().0, kind)?;
+
+            let message_fields = inherited_fields(class, path, schema_map)
+                .iter()
+                .filter(|field| crate::pragma::ParsedPragma::parse(field.pragmas()).error_message)
+                .count();
+            pragmas.check_error_type(&class.name().0, message_fields)?;
+        }
+    }
+    Ok(())
+}
+
+/// Collects the fields of `class`, defined in the schema at `path`, including
+/// those it inherits. A field redeclared in a subclass replaces the inherited one.
+fn inherited_fields<'a>(
+    class: &'a ParserClassDef,
+    path: &Path,
+    schema_map: &'a HashMap<PathBuf, SszSchema>,
+) -> Vec<&'a sizzle_parser::ClassFieldDef> {
+    let mut fields = find_parser_class(class.parent_ty(), path, schema_map)
+        .map(|(parent_path, parent)| inherited_fields(parent, parent_path, schema_map))
+        .unwrap_or_default();
+    for field in class.fields() {
+        fields.retain(|inherited| inherited.name() != field.name());
+        fields.push(field);
+    }
+    fields
+}
+
+/// Finds the class `ty` refers to from the schema at `path`, following aliases
+/// and imports, along with the path of the schema defining it. Returns `None`
+/// for built-in base classes.
+fn find_parser_class<'a>(
+    ty: &Ty,
+    path: &Path,
+    schema_map: &'a HashMap<PathBuf, SszSchema>,
+) -> Option<(&'a Path, &'a ParserClassDef)> {
+    let (path, name) = match ty {
+        Ty::Simple(name) => (path, name),
+        Ty::Imported(path, name, _) => (path.as_path(), name),
+        _ => return None,
+    };
+    let (path, schema) = schema_map.get_key_value(path)?;
+    if let Some(class) = schema.classes().iter().find(|class| class.name() == name) {
+        return Some((path, class));
+    }
+    let alias = schema.aliases().iter().find(|alias| alias.name() == name)?;
+    find_parser_class(alias.ty(), path, schema_map)
+}
+
+/// Computes a hash identifying an SSZ schema.
 ///
-/// * `parsing_order` - The order in which to process the schemas
-/// * `schema_map` - The mapping of module path => SSZ schema to convert
-/// * `module_generation` - Controls how modules are structured in the generated code
-/// * `derive_cfg` - Configuration for derive macros
-/// * `entry_point_paths` - Set of paths that are actual entry points (vs imported dependencies)
+/// The hash is taken over a normalized rendering of the resolved schema: constants are reduced
+/// to their values and formatting, comments and doc comments are left out. Pragmas are left out
+/// too, except `mix_in_aux`, `aux` and `optional_tail`, which change how values hash or decode.
+/// Any change to a constant, alias, class or field produces a different hash.
 ///
-/// # Returns
+/// An imported type is rendered as its module path and name, followed by the hash of the module
+/// it is defined in, so changing an imported definition also changes the hash of every schema
+/// that imports it. `schema_map` is the map returned by [`sizzle_parser::parse_str_schema`], and
+/// `path` is the key of the schema to hash.
 ///
-/// A TokenStream containing the generated Rust code
-pub fn schema_map_to_rust_code(
-    parsing_order: &[PathBuf],
+/// # Panics
+///
+/// Panics if `path` is not a key of `schema_map`.
+pub fn schema_hash(path: &Path, schema_map: &HashMap<PathBuf, SszSchema>) -> [u8; 32] {
+    let schema = &schema_map[path];
+    let normalized_ty = |ty: &Ty| normalized_ty(ty, schema_map);
+    let mut text = String::new();
+    for constant in schema.constants() {
+        text.push_str(&format!(
+            "const {} = {}\n",
+            constant.name().0,
+            constant.value().eval()
+        ));
+    }
+    for array in schema.const_arrays() {
+        let values = array
+            .values()
+            .iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<_>>();
+        let prefix = if array.is_bytes() { "b" } else { "" };
+        text.push_str(&format!(
+            "const {} = {prefix}[{}]\n",
+            array.name().0,
+            values.join(", ")
+        ));
+    }
+    for alias in schema.aliases() {
+        text.push_str(&format!(
+            "alias {} = {}\n",
+            alias.name().0,
+            normalized_ty(alias.ty())
+        ));
+    }
+    for class in schema.classes() {
+        let pragmas = crate::pragma::ParsedPragma::parse(class.pragmas());
+        let marker = if pragmas.mix_in_aux {
+            " mix_in_aux"
+        } else {
+            ""
+        };
+        text.push_str(&format!(
+            "class {}({}){marker}\n",
+            class.name().0,
+            normalized_ty(class.parent_ty())
+        ));
+        for field in class.fields() {
+            let pragmas = crate::pragma::ParsedPragma::parse(field.pragmas());
+            let marker = match (pragmas.aux, pragmas.optional_tail) {
+                (true, true) => " aux optional_tail",
+                (true, false) => " aux",
+                (false, true) => " optional_tail",
+                (false, false) => "",
+            };
+            match field.ty() {
+                Some(ty) => text.push_str(&format!(
+                    "    {}: {}{marker}\n",
+                    field.name().0,
+                    normalized_ty(ty)
+                )),
+                None => text.push_str(&format!("    {}{marker}\n", field.name().0)),
+            }
+        }
+    }
+
+    Sha256Hasher::hash_fixed(text.as_bytes()).0
+}
+
+/// Renders a type the way it is written in a schema, with constants replaced by their values and
+/// imported names replaced by [`normalized_import`].
+fn normalized_ty(ty: &Ty, schema_map: &HashMap<PathBuf, SszSchema>) -> String {
+    let render_args = |args: &[TyExpr]| {
+        args.iter()
+            .map(|arg| match arg {
+                TyExpr::None => "None".to_string(),
+                TyExpr::Ty(ty) => normalized_ty(ty, schema_map),
+                TyExpr::Int(value) => value.eval().to_string(),
+                TyExpr::ConstRef(_, value) => value.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    match ty {
+        Ty::Simple(name) => name.0.clone(),
+        Ty::Imported(path, name, _) => normalized_import(path, name, schema_map),
+        Ty::Complex(name, args) => format!("{}[{}]", name.0, render_args(args)),
+        Ty::ImportedComplex(path, name, _, args) => format!(
+            "{}[{}]",
+            normalized_import(path, name, schema_map),
+            render_args(args)
+        ),
+    }
+}
+
+/// Renders an imported name as `module/path.Name`, followed by `@` and the hash of the module when
+/// it is an SSZ schema. Names from external crates have no schema and are rendered by path alone.
+fn normalized_import(
+    path: &Path,
+    name: &Identifier,
+    schema_map: &HashMap<PathBuf, SszSchema>,
+) -> String {
+    let module = path
+        .iter()
+        .map(|part| part.to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/");
+    if !schema_map.contains_key(path) {
+        return format!("{module}.{}", name.0);
+    }
+    let hash = schema_hash(path, schema_map)
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect::<String>();
+    format!("{module}.{}@{hash}", name.0)
+}
+
+/// Generates the `SCHEMA_HASH` constant for a module.
+fn schema_hash_const(hash: &[u8; 32]) -> TokenStream {
+    let bytes = hash.iter().map(|byte| Literal::u8_unsuffixed(*byte));
+    quote! {
+        /// Hash of the normalized schema this module was generated from.
+        #[allow(dead_code, reason = "generated code using ssz-gen")]
+        pub const SCHEMA_HASH: [u8; 32] = [#(#bytes),*];
+    }
+}
+
+/// Generates the definitions of every module in `parsing_order`, without any imports.
+///
+/// Returns each module's content along with its schema hash.
+fn generate_module_contents<'p>(
+    parsing_order: &'p [PathBuf],
     schema_map: &HashMap<PathBuf, SszSchema>,
-    module_generation: ModuleGeneration,
     derive_cfg: &DeriveConfig,
-    entry_point_paths: &HashSet<PathBuf>,
-) -> TokenStream {
-    let mut module_tokens = HashMap::new();
-    let mut module_content_tokens = HashMap::new(); // Content without imports for `SingleModule`
+    shared_namespace: &HashSet<PathBuf>,
+    sibling_modules: bool,
+) -> (
+    HashMap<&'p PathBuf, TokenStream>,
+    HashMap<&'p PathBuf, [u8; 32]>,
+) {
+    let mut module_content_tokens = HashMap::new();
+    let mut schema_hashes = HashMap::new();
     let resolvers = RefCell::new(HashMap::new());
 
     for path in parsing_order {
         let schema = schema_map.get(path).unwrap();
         let mut type_resolver = TypeResolver::new_with_builtins(&resolvers);
+        type_resolver.sibling_modules = sibling_modules;
+        type_resolver.arbitrary_unions = derive_cfg.arbitrary;
+        type_resolver.default_classes = derive_cfg.default_impl;
+        type_resolver.doc_comment_style = derive_cfg.doc_comment_style;
 
         // Constants
         let constants = schema
@@ -930,8 +1383,47 @@ pub fn schema_map_to_rust_code(
             })
             .collect::<Vec<_>>();
 
+        // Constant arrays, of `u8` when every element fits in a byte, and byte strings
+        let const_arrays = schema
+            .const_arrays()
+            .iter()
+            .map(|array| {
+                let ident = Ident::new(&array.name().0, Span::call_site());
+                let values = array.values();
+                let len = Literal::usize_unsuffixed(values.len());
+                if array.is_bytes() {
+                    let values = values
+                        .iter()
+                        .map(|value| Literal::u8_unsuffixed(*value as u8));
+                    quote! {
+                        #[allow(dead_code, reason = "generated code using ssz-gen")]
+                        pub const #ident: FixedBytes<#len> = FixedBytes([#(#values),*]);
+                    }
+                } else if values.iter().all(|value| *value <= u64::from(u8::MAX)) {
+                    let values = values
+                        .iter()
+                        .map(|value| Literal::u8_unsuffixed(*value as u8));
+                    quote! {
+                        #[allow(dead_code, reason = "generated code using ssz-gen")]
+                        pub const #ident: [u8; #len] = [#(#values),*];
+                    }
+                } else {
+                    let values = values.iter().map(|value| Literal::u64_unsuffixed(*value));
+                    quote! {
+                        #[allow(dead_code, reason = "generated code using ssz-gen")]
+                        pub const #ident: [u64; #len] = [#(#values),*];
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+
         // Aliases and Classes can reference each other so we need to process them together
-        let codegen = CircleBufferCodegen::new(schema.aliases(), schema.classes(), derive_cfg);
+        let codegen = CircleBufferCodegen::new(
+            schema.aliases(),
+            schema.classes(),
+            derive_cfg,
+            shared_namespace,
+        );
         let tokens = codegen.process(&mut type_resolver);
 
         let union_tracker = type_resolver.union_tracker.borrow();
@@ -948,40 +1440,155 @@ pub fn schema_map_to_rust_code(
             #(#unions)*
 
             #(#constants)*
+            #(#const_arrays)*
 
             #(#tokens)*
         };
 
-        // Store content without imports for SingleModule mode
-        module_content_tokens.insert(path, content_tokens.clone());
-
-        // Store full module with imports for other modes
-        module_tokens.insert(
-            path,
-            quote! {
-                use ssz_types::*;
-                use ssz_types::view::{FixedVectorRef, VariableListRef};
-                use ssz_primitives::{U128, U256};
-                use ssz_derive::{Encode, Decode};
-                use tree_hash::TreeHashDigest;
-                use tree_hash_derive::TreeHash;
-                use ssz::view::*;
-
-                #content_tokens
-            },
-        );
+        module_content_tokens.insert(path, content_tokens);
+        schema_hashes.insert(path, schema_hash(path, schema_map));
 
         drop(union_tracker);
         resolvers.borrow_mut().insert(path.clone(), type_resolver);
     }
 
-    match module_generation {
-        ModuleGeneration::SingleModule => {
-            single_module_rust_code(&module_content_tokens, entry_point_paths)
-        }
-        ModuleGeneration::FlatModules => {
-            flat_modules_rust_code(&module_content_tokens, entry_point_paths)
-        }
-        ModuleGeneration::NestedModules => module_tokens_to_rust_code(&module_tokens),
+    (module_content_tokens, schema_hashes)
+}
+
+/// The imports every generated module starts with.
+fn module_imports(derive_cfg: &DeriveConfig) -> TokenStream {
+    let crate_path_imports = derive_cfg.crate_path_imports();
+    quote! {
+        use ssz_types::*;
+        use ssz_types::view::{FixedVectorRef, VariableListRef};
+        use ssz_primitives::{U128, U256};
+        use ssz_derive::{Encode, Decode};
+        use tree_hash::TreeHashDigest;
+        use tree_hash_derive::TreeHash;
+        use ssz::view::*;
+        #crate_path_imports
+    }
+}
+
+/// Prepends a module's imports and `SCHEMA_HASH` constant to its content.
+fn module_with_imports(
+    content_tokens: &TokenStream,
+    schema_hash: &[u8; 32],
+    derive_cfg: &DeriveConfig,
+) -> TokenStream {
+    let schema_hash = schema_hash_const(schema_hash);
+    let imports = module_imports(derive_cfg);
+    quote! {
+        #imports
+
+        #schema_hash
+
+        #content_tokens
     }
 }
+
+/// Converts mapping of module path => SSZ schemas into a Rust code token stream
+///
+/// # Arguments
+///
+/// * `parsing_order` - The order in which to process the schemas
+/// * `schema_map` - The mapping of module path => SSZ schema to convert
+/// * `module_generation` - Controls how modules are structured in the generated code
+/// * `derive_cfg` - Configuration for derive macros
+/// * `entry_point_paths` - Set of paths that are actual entry points (vs imported dependencies)
+///
+/// # Returns
+///
+/// A TokenStream containing the generated Rust code
+pub fn schema_map_to_rust_code(
+    parsing_order: &[PathBuf],
+    schema_map: &HashMap<PathBuf, SszSchema>,
+    module_generation: ModuleGeneration,
+    derive_cfg: &DeriveConfig,
+    entry_point_paths: &HashSet<PathBuf>,
+) -> TokenStream {
+    let shared_namespace = match module_generation {
+        ModuleGeneration::SingleModule => entry_point_paths.clone(),
+        ModuleGeneration::FlatModules | ModuleGeneration::NestedModules => HashSet::new(),
+    };
+    // Content without imports for `SingleModule` and `FlatModules`
+    let (module_content_tokens, schema_hashes) = generate_module_contents(
+        parsing_order,
+        schema_map,
+        derive_cfg,
+        &shared_namespace,
+        false,
+    );
+
+    let rust_code = match module_generation {
+        ModuleGeneration::SingleModule => single_module_rust_code(
+            &module_content_tokens,
+            &schema_hashes,
+            entry_point_paths,
+            derive_cfg,
+        ),
+        ModuleGeneration::FlatModules => flat_modules_rust_code(
+            &module_content_tokens,
+            &schema_hashes,
+            entry_point_paths,
+            derive_cfg,
+        ),
+        ModuleGeneration::NestedModules => {
+            let module_tokens = module_content_tokens
+                .iter()
+                .map(|(path, content)| {
+                    let tokens = module_with_imports(content, &schema_hashes[path], derive_cfg);
+                    (*path, tokens)
+                })
+                .collect();
+            module_tokens_to_rust_code(&module_tokens)
+        }
+    };
+    derive_cfg.apply_crate_paths(rust_code)
+}
+
+/// Converts mapping of module path => SSZ schemas into one Rust code token stream per module
+///
+/// Each token stream holds a single module's imports and definitions, meant to be written to its
+/// own file and `include!`d into a module. With [`ModuleGeneration::NestedModules`], references to
+/// other generated modules use their full `crate::` path, so the files must be mounted following
+/// the schema directory layout. Otherwise the files are expected to be mounted as sibling modules
+/// named after their schema files, and references use `super::module` paths.
+///
+/// # Arguments
+///
+/// * `parsing_order` - The order in which to process the schemas
+/// * `schema_map` - The mapping of module path => SSZ schema to convert
+/// * `module_generation` - Controls how references to other modules are written
+/// * `derive_cfg` - Configuration for derive macros
+///
+/// # Returns
+///
+/// The generated code for each module path, in `parsing_order`
+pub fn schema_map_to_rust_files(
+    parsing_order: &[PathBuf],
+    schema_map: &HashMap<PathBuf, SszSchema>,
+    module_generation: ModuleGeneration,
+    derive_cfg: &DeriveConfig,
+) -> Vec<(PathBuf, TokenStream)> {
+    let sibling_modules = module_generation != ModuleGeneration::NestedModules;
+    let (module_content_tokens, schema_hashes) = generate_module_contents(
+        parsing_order,
+        schema_map,
+        derive_cfg,
+        &HashSet::new(),
+        sibling_modules,
+    );
+
+    parsing_order
+        .iter()
+        .map(|path| {
+            let tokens = module_with_imports(
+                &module_content_tokens[path],
+                &schema_hashes[path],
+                derive_cfg,
+            );
+            (path.clone(), derive_cfg.apply_crate_paths(tokens))
+        })
+        .collect()
+}
@@ -4,7 +4,7 @@
 //! zero-copy view types.
 use std::collections::{HashMap, HashSet};
 
-use proc_macro2::TokenStream;
+use proc_macro2::{Group, Ident, Span, TokenStream, TokenTree};
 use quote::quote;
 use serde::Deserialize;
 
@@ -20,17 +20,106 @@ pub struct DeriveConfigToml {
     /// Per-type overrides (replace semantics)
     #[serde(default)]
     pub types: HashMap<String, Vec<String>>, // type name -> list
+    /// Whether to generate serde impls for containers
+    #[serde(default)]
+    pub serde: bool,
+    /// Whether to generate `Default` impls for containers
+    #[serde(default)]
+    pub default_impl: bool,
+    /// Whether to derive `arbitrary::Arbitrary` for containers and unions
+    #[serde(default)]
+    pub arbitrary: bool,
+    /// Whether to generate `FIELD_NAMES` and `fields()` for containers
+    #[serde(default)]
+    pub field_info: bool,
+    /// Whether containers with byte fields print them as hex in `Debug`
+    #[serde(default)]
+    pub hex_debug: bool,
+    /// Whether container `tree_hash_root` impls reuse pooled hashers
+    #[serde(default)]
+    pub pooled_tree_hash: bool,
+    /// Whether to generate `root_eq` for containers
+    #[serde(default)]
+    pub root_eq: bool,
+    /// Whether to generate `diff` for containers
+    #[serde(default)]
+    pub diff: bool,
+    /// Style of the doc comments on generated items
+    #[serde(default)]
+    pub comment_style: DocCommentStyle,
+    /// Paths the generated code uses in place of the SSZ crates
+    #[serde(default)]
+    pub crate_paths: HashMap<String, String>, // crate name -> path
 }
 
 /// In-memory derive configuration
+///
+/// Start from [`DeriveConfig::default_defaults`] or `Default` and adjust it with the `with_*`
+/// builders; new options may be added, so the struct can't be built as a literal outside this
+/// crate.
 #[derive(Debug, Clone, Default)]
+#[non_exhaustive]
 pub struct DeriveConfig {
     /// Default derives applied to all generated types when no per-type override is provided.
     pub default: Vec<String>,
     /// Per-type override derives (replace semantics), keyed by the Rust type name.
     pub types: HashMap<String, Vec<String>>, // replace semantics
+    /// Derive `serde::Serialize`/`serde::Deserialize` on generated containers, with field
+    /// attributes matching the consensus spec's JSON format. See [`DeriveConfig::with_serde`].
+    pub serde: bool,
+    /// Generate a `Default` impl for generated containers. See [`DeriveConfig::with_default`].
+    pub default_impl: bool,
+    /// Derive `arbitrary::Arbitrary` on generated containers and unions. See
+    /// [`DeriveConfig::with_arbitrary`].
+    pub arbitrary: bool,
+    /// Generate `FIELD_NAMES` and `fields()` on generated containers. See
+    /// [`DeriveConfig::with_field_info`].
+    pub field_info: bool,
+    /// Print byte fields of generated containers as hex in `Debug`. See
+    /// [`DeriveConfig::with_hex_debug`].
+    pub hex_debug: bool,
+    /// Hash generated containers with hashers from `tree_hash::with_pooled_hasher`. See
+    /// [`DeriveConfig::with_pooled_tree_hash`].
+    pub pooled_tree_hash: bool,
+    /// Generate `root_eq`, comparing tree hash roots, on generated containers. See
+    /// [`DeriveConfig::with_root_eq`].
+    pub root_eq: bool,
+    /// Generate `diff`, listing differing fields, on generated containers. See
+    /// [`DeriveConfig::with_diff`].
+    pub diff: bool,
+    /// Style of the doc comments generated from schema comments and docstrings. See
+    /// [`DeriveConfig::with_doc_comment_style`].
+    pub doc_comment_style: DocCommentStyle,
+    /// Paths the generated code uses in place of the SSZ crates, keyed by crate name. See
+    /// [`DeriveConfig::with_crate_path`].
+    pub crate_paths: HashMap<String, String>,
 }
 
+/// How generated doc comments are written.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DocCommentStyle {
+    /// One `///` comment per line.
+    #[default]
+    Line,
+    /// A single `/** */` block comment with ` * `-prefixed lines.
+    Block,
+}
+
+/// Crates the generated code refers to, which [`DeriveConfig::with_crate_path`] can redirect.
+pub const OVERRIDABLE_CRATES: [&str; 6] = [
+    "ssz",
+    "ssz_types",
+    "ssz_primitives",
+    "ssz_derive",
+    "tree_hash",
+    "tree_hash_derive",
+];
+
+/// Crates named by the expansions of the `ssz_derive` and `tree_hash_derive` macros, which the
+/// generated modules alias when they're overridden.
+const DERIVE_EXPANSION_CRATES: [&str; 3] = ["ssz", "ssz_types", "tree_hash"];
+
 impl DeriveConfig {
     fn parse_derive_path(name: &str) -> syn::Path {
         syn::parse_str::<syn::Path>(name).expect("invalid derive path")
@@ -101,6 +190,16 @@ impl DeriveConfig {
                 cfg.default = default;
             }
             cfg.types.extend(derives.types);
+            cfg.serde = derives.serde;
+            cfg.default_impl = derives.default_impl;
+            cfg.arbitrary = derives.arbitrary;
+            cfg.field_info = derives.field_info;
+            cfg.hex_debug = derives.hex_debug;
+            cfg.pooled_tree_hash = derives.pooled_tree_hash;
+            cfg.root_eq = derives.root_eq;
+            cfg.diff = derives.diff;
+            cfg.doc_comment_style = derives.comment_style;
+            cfg.crate_paths = derives.crate_paths;
         }
         Ok(cfg)
     }
@@ -119,9 +218,241 @@ impl DeriveConfig {
                 "std::cmp::Eq".into(),
             ],
             types: HashMap::new(),
+            serde: false,
+            default_impl: false,
+            arbitrary: false,
+            field_info: false,
+            hex_debug: false,
+            pooled_tree_hash: false,
+            root_eq: false,
+            diff: false,
+            doc_comment_style: DocCommentStyle::Line,
+            crate_paths: HashMap::new(),
         }
     }
 
+    /// Replaces the derives applied to every generated type without a per-type entry.
+    ///
+    /// Each derive is a Rust path, such as `std::fmt::Debug` or `serde::Serialize`.
+    pub fn with_derives(mut self, derives: &[&str]) -> Self {
+        self.default = derives.iter().map(|derive| derive.to_string()).collect();
+        self
+    }
+
+    /// Replaces the derives of the generated type named `type_name`, in place of the
+    /// [`with_derives`](Self::with_derives) list.
+    pub fn with_type_derives(mut self, type_name: &str, derives: &[&str]) -> Self {
+        self.types.insert(
+            type_name.to_string(),
+            derives.iter().map(|derive| derive.to_string()).collect(),
+        );
+        self
+    }
+
+    /// Enables or disables serde impls on generated containers.
+    ///
+    /// When enabled, owned Container, StableContainer and Profile types derive
+    /// `serde::Serialize` and `serde::Deserialize`, and fields are annotated so the JSON matches
+    /// the consensus spec: byte lists and vectors are `0x`-prefixed hex, and `uint64`, `uint128`
+    /// and `uint256` values (including lists and vectors of `uint64`) are quoted decimal strings.
+    /// Fields with a `serde` attribute from a `field_attr` pragma are left as written.
+    ///
+    /// The generated code relies on `ssz_types`' `serde` feature, and every field type must
+    /// implement serde itself; unions don't.
+    pub fn with_serde(mut self, enabled: bool) -> Self {
+        self.serde = enabled;
+        self
+    }
+
+    /// Enables or disables `Default` impls on generated containers.
+    ///
+    /// When enabled, owned Container, StableContainer and Profile types get an `impl Default`
+    /// with zeroed integers and byte vectors, `false` booleans, empty lists and bitlists, and
+    /// `Optional::None` for optional fields. Containers with a union field, directly or through
+    /// a nested container, are skipped since unions have no default variant. External types
+    /// must implement `Default` themselves.
+    pub fn with_default(mut self, enabled: bool) -> Self {
+        self.default_impl = enabled;
+        self
+    }
+
+    /// Enables or disables `arbitrary::Arbitrary` derives on generated types.
+    ///
+    /// When enabled, owned Container, StableContainer and Profile types and union enums derive
+    /// `arbitrary::Arbitrary`. Lists and bitlists never exceed their capacity, and union variants
+    /// are picked uniformly, so every generated value encodes to valid SSZ. The generated code
+    /// relies on `ssz_types`' `arbitrary` feature, and external types must implement
+    /// `Arbitrary` themselves.
+    pub fn with_arbitrary(mut self, enabled: bool) -> Self {
+        self.arbitrary = enabled;
+        self
+    }
+
+    /// Enables or disables field reflection on generated containers.
+    ///
+    /// When enabled, owned Container, StableContainer and Profile types get a
+    /// `FIELD_NAMES` constant listing their fields in declaration order, and a `fields()`
+    /// function returning a `ssz_types::FieldInfo` per field with its name, whether it has a
+    /// fixed SSZ length, and its `TreeHashType`. Every field type must implement
+    /// `ssz::Encode` and `tree_hash::TreeHash`.
+    pub fn with_field_info(mut self, enabled: bool) -> Self {
+        self.field_info = enabled;
+        self
+    }
+
+    /// Enables or disables hex `Debug` output for byte fields of generated containers.
+    ///
+    /// When enabled, owned Container, StableContainer and Profile types with a byte vector or
+    /// byte list field get a hand-written `Debug` impl instead of the derived one. It prints
+    /// those fields as `0x`-prefixed hex through `ssz_types::HexBytes` and every other field
+    /// with its own `Debug`. Containers without byte fields, or whose derives don't include
+    /// `Debug`, keep their derives unchanged.
+    pub fn with_hex_debug(mut self, enabled: bool) -> Self {
+        self.hex_debug = enabled;
+        self
+    }
+
+    /// Enables or disables pooled hashers in generated container tree hashing.
+    ///
+    /// When enabled, the `tree_hash_root` of owned Container types merkleizes its fields with a
+    /// `MerkleHasher` from `tree_hash::with_pooled_hasher` instead of a fresh one, so hashing
+    /// many containers on one thread reuses the hashers' allocations. The root is unchanged.
+    /// Hashers only allocate for containers of more than 256 fields, so narrower containers
+    /// hash faster without this. The generated code needs `tree_hash`'s `std` feature.
+    pub fn with_pooled_tree_hash(mut self, enabled: bool) -> Self {
+        self.pooled_tree_hash = enabled;
+        self
+    }
+
+    /// Enables or disables `root_eq` on generated containers.
+    ///
+    /// When enabled, owned Container, StableContainer and Profile types get a
+    /// `root_eq(&self, other: &Self) -> bool` method comparing the SHA-256 `tree_hash_root`s of
+    /// both values. It agrees with `PartialEq`, which is left as derived.
+    pub fn with_root_eq(mut self, enabled: bool) -> Self {
+        self.root_eq = enabled;
+        self
+    }
+
+    /// Enables or disables `diff` on generated containers.
+    ///
+    /// When enabled, owned Container, StableContainer and Profile types get a
+    /// `diff(&self, other: &Self) -> Vec<ssz_types::FieldDiff>` method returning, for each field
+    /// that differs, its name and the `Debug` output of both values. Every field type must
+    /// implement `PartialEq` and `Debug`, as it does with the default derives.
+    pub fn with_diff(mut self, enabled: bool) -> Self {
+        self.diff = enabled;
+        self
+    }
+
+    /// Selects how doc comments generated from schema comments and docstrings are written.
+    ///
+    /// [`DocCommentStyle::Line`], the default, writes `///` lines; [`DocCommentStyle::Block`]
+    /// writes one `/** */` block per item instead, for codebases whose linters require it. Both
+    /// wrap at the same width and produce the same rustdoc. Block comments are only printed as
+    /// such with the `prettyplease` feature; without it the doc attributes are written as
+    /// `#[doc = "..."]`.
+    pub fn with_doc_comment_style(mut self, style: DocCommentStyle) -> Self {
+        self.doc_comment_style = style;
+        self
+    }
+
+    /// Makes the generated code refer to `krate` through `path`.
+    ///
+    /// `krate` is one of [`OVERRIDABLE_CRATES`], and `path` is a Rust path such as
+    /// `my_crate::ssz` or `crate::deps::tree_hash`. Every generated path starting with `krate`
+    /// starts with `path` instead, so the generated code only needs whichever crate re-exports
+    /// the SSZ crates as a dependency. Since the `ssz_derive` and `tree_hash_derive` expansions
+    /// name `ssz`, `ssz_types` and `tree_hash` directly, generated modules also import those
+    /// overridden paths under the crate names.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `krate` isn't one of [`OVERRIDABLE_CRATES`].
+    pub fn with_crate_path(mut self, krate: &str, path: &str) -> Self {
+        Self::check_overridable_crate(krate);
+        self.crate_paths.insert(krate.to_string(), path.to_string());
+        self
+    }
+
+    fn check_overridable_crate(krate: &str) {
+        assert!(
+            OVERRIDABLE_CRATES.contains(&krate),
+            "crate path override for `{krate}`, which isn't one of {OVERRIDABLE_CRATES:?}"
+        );
+    }
+
+    fn crate_path_tokens(&self) -> HashMap<&str, TokenStream> {
+        self.crate_paths
+            .iter()
+            .map(|(krate, path)| {
+                Self::check_overridable_crate(krate);
+                let path = syn::parse_str::<syn::Path>(path)
+                    .unwrap_or_else(|_| panic!("invalid crate path `{path}` for `{krate}`"));
+                (krate.as_str(), quote!(#path))
+            })
+            .collect()
+    }
+
+    /// Imports the overridden crates that derive macro expansions refer to by name.
+    pub(crate) fn crate_path_imports(&self) -> TokenStream {
+        let paths = self.crate_path_tokens();
+        let imports = DERIVE_EXPANSION_CRATES.iter().filter_map(|krate| {
+            let path = paths.get(krate)?;
+            let ident = Ident::new(krate, Span::call_site());
+            Some(quote! {
+                #[allow(unused_imports)]
+                use #path as #ident;
+            })
+        });
+        quote! { #(#imports)* }
+    }
+
+    /// Rewrites every path in `tokens` that starts with an overridden crate to start with its
+    /// configured path instead.
+    pub(crate) fn apply_crate_paths(&self, tokens: TokenStream) -> TokenStream {
+        if self.crate_paths.is_empty() {
+            return tokens;
+        }
+        Self::rewrite_crate_paths(tokens, &self.crate_path_tokens())
+    }
+
+    fn rewrite_crate_paths(tokens: TokenStream, paths: &HashMap<&str, TokenStream>) -> TokenStream {
+        let trees: Vec<TokenTree> = tokens.into_iter().collect();
+        let mut rewritten = TokenStream::new();
+        for (i, tree) in trees.iter().enumerate() {
+            match tree {
+                TokenTree::Group(group) => {
+                    let mut new_group = Group::new(
+                        group.delimiter(),
+                        Self::rewrite_crate_paths(group.stream(), paths),
+                    );
+                    new_group.set_span(group.span());
+                    rewritten.extend([TokenTree::Group(new_group)]);
+                }
+                TokenTree::Ident(ident) if Self::starts_path(&trees, i) => {
+                    match paths.get(ident.to_string().as_str()) {
+                        Some(path) => rewritten.extend(path.clone()),
+                        None => rewritten.extend([tree.clone()]),
+                    }
+                }
+                _ => rewritten.extend([tree.clone()]),
+            }
+        }
+        rewritten
+    }
+
+    /// Whether the token at `i` is the first segment of a path, i.e. is followed by `::` and
+    /// isn't preceded by `::` or `.`.
+    fn starts_path(trees: &[TokenTree], i: usize) -> bool {
+        let is_punct = |tree: Option<&TokenTree>, ch: char| matches!(tree, Some(TokenTree::Punct(punct)) if punct.as_char() == ch);
+        let followed_by_path_sep =
+            is_punct(trees.get(i + 1), ':') && is_punct(trees.get(i + 2), ':');
+        let preceded_by_path_sep = i > 0 && is_punct(trees.get(i - 1), ':');
+        let preceded_by_dot = i > 0 && is_punct(trees.get(i - 1), '.');
+        followed_by_path_sep && !preceded_by_path_sep && !preceded_by_dot
+    }
+
     /// Returns the derive identifiers for a given Rust type name.
     /// Per-type entry replaces defaults.
     pub fn derives_for_type(&self, type_name: &str) -> Vec<String> {
@@ -176,6 +507,28 @@ impl DeriveConfig {
         pragmas: &ParsedPragma,
         is_container: bool,
     ) -> TokenStream {
+        Self::derive_attr_from_paths(self.owned_derive_paths(type_name, pragmas, is_container))
+    }
+
+    /// Like [`DeriveConfig::owned_derive_attr_with_pragmas_filtered`], but without `Debug`,
+    /// for containers that get a hex `Debug` impl instead.
+    pub fn owned_derive_attr_without_debug(
+        &self,
+        type_name: &str,
+        pragmas: &ParsedPragma,
+        is_container: bool,
+    ) -> TokenStream {
+        let mut paths = self.owned_derive_paths(type_name, pragmas, is_container);
+        paths.retain(|path| path.segments.last().is_none_or(|seg| seg.ident != "Debug"));
+        Self::derive_attr_from_paths(paths)
+    }
+
+    fn owned_derive_paths(
+        &self,
+        type_name: &str,
+        pragmas: &ParsedPragma,
+        is_container: bool,
+    ) -> Vec<syn::Path> {
         // Combine configured derives + pragma derives + required SSZ derives
         let mut combined = self.derives_for_type(type_name);
         combined.extend(pragmas.derives.iter().cloned());
@@ -189,10 +542,51 @@ impl DeriveConfig {
         paths.push(Self::parse_derive_path("std::clone::Clone"));
         paths.push(Self::parse_derive_path("ssz_derive::Encode"));
         paths.push(Self::parse_derive_path("ssz_derive::Decode"));
+        if self.serde {
+            paths.push(Self::parse_derive_path("serde::Serialize"));
+            paths.push(Self::parse_derive_path("serde::Deserialize"));
+        }
+        if self.arbitrary {
+            paths.push(Self::parse_derive_path("arbitrary::Arbitrary"));
+        }
         // Note: TreeHash is NOT included here - we emit a `TreeHash` impl manually
         // in codegen so the impl is shared across all digests.
-        let deduped_paths = Self::dedup_derive_paths(paths);
-        Self::derive_attr_from_paths(deduped_paths)
+        Self::dedup_derive_paths(paths)
+    }
+
+    /// Whether the owned type `type_name` derives `Debug`, either from the
+    /// configuration or from a `derive` pragma.
+    pub fn owned_derives_debug(&self, type_name: &str, pragmas: &ParsedPragma) -> bool {
+        self.owned_derives_trait(type_name, pragmas, "Debug")
+    }
+
+    /// Whether the owned type `type_name` derives `Hash`, either from the
+    /// configuration or from a `derive` pragma.
+    pub fn owned_derives_hash(&self, type_name: &str, pragmas: &ParsedPragma) -> bool {
+        self.owned_derives_trait(type_name, pragmas, "Hash")
+    }
+
+    /// Whether the owned type `type_name` derives `PartialEq`, either from
+    /// the configuration or from a `derive` pragma.
+    pub fn owned_derives_partial_eq(&self, type_name: &str, pragmas: &ParsedPragma) -> bool {
+        self.owned_derives_trait(type_name, pragmas, "PartialEq")
+    }
+
+    fn owned_derives_trait(
+        &self,
+        type_name: &str,
+        pragmas: &ParsedPragma,
+        trait_name: &str,
+    ) -> bool {
+        self.derives_for_type(type_name)
+            .into_iter()
+            .chain(pragmas.derives.iter().cloned())
+            .map(|derive| Self::parse_derive_path(&derive))
+            .any(|path| {
+                path.segments
+                    .last()
+                    .is_some_and(|seg| seg.ident == trait_name)
+            })
     }
 
     /// Build a #[derive(...)] attribute token stream for a view type, incorporating pragmas
@@ -0,0 +1,145 @@
+//! Formatting of the generated code.
+//!
+//! With the default `prettyplease` feature, generated code is re-parsed with
+//! `syn` and printed by `prettyplease`. Without it, [`format_plain`] lays out
+//! the token stream itself: the result isn't rustfmt-style, but it compiles
+//! and puts items, statements and fields on their own lines.
+
+use std::error;
+
+use proc_macro2::{Delimiter, Spacing, TokenStream, TokenTree};
+
+/// Formats generated code for writing to a file, with `prettyplease` when the
+/// feature is enabled and [`format_plain`] otherwise.
+///
+/// # Errors
+///
+/// Returns an error if `prettyplease` is used and the code doesn't parse as a
+/// Rust file.
+pub fn format_code(tokens: &TokenStream) -> Result<String, Box<dyn error::Error>> {
+    #[cfg(feature = "prettyplease")]
+    {
+        Ok(prettyplease::unparse(&syn::parse_str(&tokens.to_string())?))
+    }
+    #[cfg(not(feature = "prettyplease"))]
+    {
+        Ok(format_plain(tokens))
+    }
+}
+
+/// Formats `tokens` without `prettyplease`.
+///
+/// Tokens are separated by single spaces, except after joint punctuation
+/// such as the first `:` of `::`. Brace groups are indented, and a line ends
+/// after every brace, after every attribute, after every `;` outside
+/// parentheses and brackets, and after every `,` directly inside braces.
+pub fn format_plain(tokens: &TokenStream) -> String {
+    let mut printer = PlainPrinter::default();
+    printer.stream(tokens.clone(), Context::Block);
+    printer.newline();
+    printer.out
+}
+
+/// Where a token stream sits, which decides where its lines end.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Context {
+    /// The file itself.
+    Block,
+    /// The contents of a brace group.
+    Braces,
+    /// The contents of a parenthesized or bracketed group.
+    Inline,
+}
+
+/// State of [`format_plain`] while it walks the token tree.
+#[derive(Default)]
+struct PlainPrinter {
+    out: String,
+    indent: usize,
+    /// Whether nothing has been written on the current line yet.
+    at_line_start: bool,
+    /// Whether the previous token must be followed directly by the next one.
+    joint: bool,
+}
+
+impl PlainPrinter {
+    /// Writes a stream of tokens found in `context`.
+    fn stream(&mut self, tokens: TokenStream, context: Context) {
+        let mut after_pound = false;
+        for token in tokens {
+            let is_pound = matches!(&token, TokenTree::Punct(p) if p.as_char() == '#');
+            match token {
+                TokenTree::Group(group) => match group.delimiter() {
+                    Delimiter::Brace => {
+                        self.word("{");
+                        self.indent += 1;
+                        self.newline();
+                        self.stream(group.stream(), Context::Braces);
+                        self.indent -= 1;
+                        self.newline();
+                        self.word("}");
+                        self.newline();
+                    }
+                    Delimiter::Parenthesis => self.delimited("(", group.stream(), ")"),
+                    Delimiter::Bracket => {
+                        self.delimited("[", group.stream(), "]");
+                        // `#[...]` and `#![...]` attributes go on their own line.
+                        if after_pound {
+                            self.newline();
+                        }
+                    }
+                    Delimiter::None => self.stream(group.stream(), context),
+                },
+                TokenTree::Punct(punct) => {
+                    let ch = punct.as_char();
+                    self.word(&ch.to_string());
+                    self.joint = punct.spacing() == Spacing::Joint;
+                    if (ch == ';' && context != Context::Inline)
+                        || (ch == ',' && context == Context::Braces)
+                    {
+                        self.newline();
+                    }
+                }
+                TokenTree::Ident(ident) => self.word(&ident.to_string()),
+                TokenTree::Literal(literal) => self.word(&literal.to_string()),
+            }
+            // The `!` of an inner attribute sits between `#` and `[`.
+            after_pound = is_pound || (after_pound && self.out.ends_with('!'));
+        }
+    }
+
+    /// Writes a parenthesized or bracketed group inline.
+    fn delimited(&mut self, open: &str, tokens: TokenStream, close: &str) {
+        self.word(open);
+        self.stream(tokens, Context::Inline);
+        self.word(close);
+    }
+
+    /// Writes one token, separated from the previous one unless that was
+    /// joint punctuation.
+    fn word(&mut self, word: &str) {
+        // Keep a `;` or `,` that follows a closing brace on the brace's line.
+        if self.at_line_start && matches!(word, ";" | ",") && self.out.ends_with("}\n") {
+            self.out.pop();
+            self.at_line_start = false;
+            self.joint = true;
+        }
+        if self.at_line_start {
+            self.out.push_str(&"    ".repeat(self.indent));
+        } else if !self.joint && !self.out.is_empty() {
+            self.out.push(' ');
+        }
+        self.out.push_str(word);
+        self.at_line_start = false;
+        self.joint = false;
+    }
+
+    /// Ends the current line, unless nothing has been written on it.
+    fn newline(&mut self) {
+        if !self.at_line_start && !self.out.is_empty() {
+            self.out.push('\n');
+            self.at_line_start = true;
+        }
+        self.joint = false;
+    }
+}
@@ -3,20 +3,27 @@
 //! A codegen tool that parses simplified Python SSZ (Simple Serialize) definitions using
 //! `sizzle-parser` and generates Rust code for it utilizing `ssz_derive`'s derive macros.
 
-use std::{collections::HashSet, error, fs, path::Path};
+use std::{
+    collections::{HashMap, HashSet},
+    error, fs,
+    path::{Path, PathBuf},
+};
 
-use prettyplease::unparse;
+#[cfg(test)]
+use arbitrary as _;
+#[cfg(test)]
+use criterion as _;
 #[cfg(any(test, doctest))]
 use serde as _;
-use sizzle_parser::parse_str_schema;
+#[cfg(test)]
+use serde_json as _;
+use sizzle_parser::{SszSchema, parse_str_schema};
 use ssz as _;
 use ssz_derive as _;
 use ssz_primitives as _;
 use ssz_types as _;
-use syn::parse_str;
 #[cfg(any(test, doctest))]
 use toml as _;
-use tree_hash as _;
 use tree_hash_derive as _;
 
 use crate::derive_config::DeriveConfig;
@@ -38,18 +45,34 @@ pub enum ModuleGeneration {
 pub mod codegen;
 pub mod derive_config;
 pub mod files;
+pub mod format;
 pub mod pragma;
 pub mod types;
 
+/// Parses the schema files and checks their class pragmas with
+/// [`codegen::check_class_pragmas`].
+///
+/// A parse error's `Display` form points at the offending source line.
+#[allow(clippy::type_complexity)]
+fn parse_schema(
+    files: &HashMap<PathBuf, String>,
+    crates: &[&str],
+) -> Result<(Vec<PathBuf>, HashMap<PathBuf, SszSchema>), Box<dyn error::Error>> {
+    let (parsing_order, schema_map) = parse_str_schema(files, crates)?;
+    codegen::check_class_pragmas(&parsing_order, &schema_map)?;
+    Ok((parsing_order, schema_map))
+}
+
 /// Run the code generation process in a build script (build.rs).
 ///
 /// This function:
 /// 1. Reads all Pythonic SSZ definition files from the input directory
-/// 2. Generates Rust code for each file separately
-/// 3. Writes the generated code to the output directory, with the same file name but with a .rs
-///    extension
+/// 2. Generates Rust code for all of them, laid out in modules according to `module_generation`
+/// 3. Writes the generated code to a single output file
 /// 4. Outputs Cargo instructions to rerun the build script when any input file changes
 ///
+/// Use [`build_ssz_files_multi`] to write each file's code to its own output file instead.
+///
 /// # Arguments
 ///
 /// * `entry_points` - Paths to the entrypoint SSZ definition files. Entry points can safely import
@@ -87,7 +110,7 @@ pub fn build_ssz_files(
 ) -> Result<(), Box<dyn error::Error>> {
     let files = files::read_entrypoint_ssz(entry_points, base_dir)?;
     println!("cargo:rerun-if-changed={base_dir}");
-    let (parsing_order, schema_map) = parse_str_schema(&files, crates)?;
+    let (parsing_order, schema_map) = parse_schema(&files, crates)?;
 
     // Track which paths are actual entry points (vs imported dependencies)
     let entry_point_paths: HashSet<_> = files.keys().cloned().collect();
@@ -100,7 +123,7 @@ pub fn build_ssz_files(
         &derive_config::DeriveConfig::default_defaults(),
         &entry_point_paths,
     );
-    let pretty_rust_code = prettyplease::unparse(&syn::parse_str(&rust_code.to_string())?);
+    let pretty_rust_code = format::format_code(&rust_code)?;
     let output_path = Path::new(output_file_path);
     if let Some(parent) = output_path.parent() {
         fs::create_dir_all(parent)?;
@@ -125,7 +148,7 @@ pub fn build_ssz_files_with_derives(
         println!("cargo:rerun-if-changed={path}");
     }
 
-    let (parsing_order, schema_map) = parse_str_schema(&files, crates)?;
+    let (parsing_order, schema_map) = parse_schema(&files, crates)?;
 
     // Track which paths are actual entry points (vs imported dependencies)
     let entry_point_paths: HashSet<_> = files.keys().cloned().collect();
@@ -149,7 +172,7 @@ pub fn build_ssz_files_with_derives(
         &cfg,
         &entry_point_paths,
     );
-    let pretty_rust_code = unparse(&parse_str(&rust_code.to_string())?);
+    let pretty_rust_code = format::format_code(&rust_code)?;
     let output_path = Path::new(output_file_path);
     if let Some(parent) = output_path.parent() {
         fs::create_dir_all(parent)?;
@@ -157,3 +180,98 @@ pub fn build_ssz_files_with_derives(
     fs::write(output_path, pretty_rust_code)?;
     Ok(())
 }
+
+/// Same as `build_ssz_files` but writes each schema file's code to its own file in `out_dir`.
+///
+/// Every entry point, and every schema file they import, is written to `out_dir` under the same
+/// file name with a .rs extension, and a `cargo:rerun-if-changed` instruction is emitted for each
+/// schema file. With `ModuleGeneration::NestedModules`, cross-file references use `crate::` paths
+/// following the schema directory layout; otherwise the files must be included as sibling modules
+/// named after the files, and references use `super::` paths.
+///
+/// Returns an error, before writing anything, if two schema files in different directories share
+/// a file name, since their output files would overwrite each other.
+///
+/// # Example
+///
+/// ```ignore
+/// // In build.rs
+/// use ssz_codegen::build_ssz_files_multi;
+/// fn main() {
+///     let out_dir = env::var("OUT_DIR").unwrap();
+///     build_ssz_files_multi(
+///         &["test_1.ssz", "test_2.ssz"],
+///         "specs/",
+///         &[],
+///         &out_dir,
+///         ModuleGeneration::FlatModules,
+///     )
+///     .expect("Failed to generate SSZ types");
+/// }
+///
+/// // In the crate, as sibling modules
+/// #[allow(unused_imports)]
+/// pub mod test_1 {
+///     include!(concat!(env!("OUT_DIR"), "/test_1.rs"));
+/// }
+/// #[allow(unused_imports)]
+/// pub mod test_2 {
+///     include!(concat!(env!("OUT_DIR"), "/test_2.rs"));
+/// }
+/// ```
+pub fn build_ssz_files_multi(
+    entry_points: &[&str],
+    base_dir: &str,
+    crates: &[&str],
+    out_dir: &str,
+    module_generation: ModuleGeneration,
+) -> Result<(), Box<dyn error::Error>> {
+    let files = files::read_entrypoint_ssz(entry_points, base_dir)?;
+    let (parsing_order, schema_map) = parse_schema(&files, crates)?;
+
+    let modules = codegen::schema_map_to_rust_files(
+        &parsing_order,
+        &schema_map,
+        module_generation,
+        &DeriveConfig::default_defaults(),
+    );
+    // Existing Rust modules imported by a schema have no file to generate code from.
+    let modules: Vec<_> = modules
+        .into_iter()
+        .filter(|(path, _)| files.contains_key(path) || path.with_extension("ssz").exists())
+        .collect();
+
+    let mut file_names: HashMap<String, &PathBuf> = HashMap::new();
+    for (path, _) in &modules {
+        let file_name = path
+            .file_stem()
+            .expect("schema path should have a file name")
+            .to_string_lossy()
+            .into_owned();
+        if let Some(other) = file_names.insert(file_name.clone(), path) {
+            return Err(format!(
+                "schema files {} and {} would both be written to {file_name}.rs",
+                other.with_extension("ssz").display(),
+                path.with_extension("ssz").display(),
+            )
+            .into());
+        }
+    }
+
+    let out_dir = Path::new(out_dir);
+    fs::create_dir_all(out_dir)?;
+    for (path, rust_code) in &modules {
+        println!(
+            "cargo:rerun-if-changed={}",
+            path.with_extension("ssz").display()
+        );
+
+        let file_name = path
+            .file_stem()
+            .expect("schema path should have a file name")
+            .to_string_lossy();
+        let pretty_rust_code = format::format_code(rust_code)?;
+        fs::write(out_dir.join(format!("{file_name}.rs")), pretty_rust_code)?;
+    }
+    Ok(())
+}
@@ -13,6 +13,33 @@ pub struct ParsedPragma {
     pub struct_attrs: Vec<TokenStream>,
     /// Field-level attributes (applied to all fields, or via field-specific pragmas)
     pub field_attrs: Vec<TokenStream>,
+    /// Whether the class is marked `error_type` (implements `Display` and
+    /// `std::error::Error`)
+    pub error_type: bool,
+    /// Whether the field is marked `error_message` (supplies the `Display`
+    /// output of an `error_type` class)
+    pub error_message: bool,
+    /// Whether the class is marked `collection` (a wrapper around a single
+    /// list field that implements `TryFromIter` and `Extend`)
+    pub collection: bool,
+    /// Whether the class is marked `newtype` (a wrapper around a single field
+    /// that implements `AsRef`, `Borrow` and `Deref` to it)
+    pub newtype: bool,
+    /// Whether the class is marked `unit` (a wrapper around a single unsigned
+    /// integer field with arithmetic and ordering against itself only)
+    pub unit: bool,
+    /// Whether the class is marked `mix_in_aux` (its root mixes the root of
+    /// its `aux` field into the root of the other fields)
+    pub mix_in_aux: bool,
+    /// Whether the field is marked `aux` (the auxiliary root of a
+    /// `mix_in_aux` class)
+    pub aux: bool,
+    /// Whether the field is marked `optional_tail` (may be absent from the end
+    /// of older, shorter encodings and defaults when it is)
+    pub optional_tail: bool,
+    /// Cargo feature the class is gated behind (`#[cfg(feature = "...")]`
+    /// on every generated item)
+    pub feature: Option<String>,
 }
 
 impl ParsedPragma {
@@ -25,6 +52,15 @@ impl ParsedPragma {
         let mut derives = Vec::new();
         let mut struct_attrs = Vec::new();
         let mut field_attrs = Vec::new();
+        let mut error_type = false;
+        let mut error_message = false;
+        let mut collection = false;
+        let mut newtype = false;
+        let mut unit = false;
+        let mut mix_in_aux = false;
+        let mut aux = false;
+        let mut optional_tail = false;
+        let mut feature = None;
 
         for pragma in pragmas {
             let trimmed = pragma.trim();
@@ -63,12 +99,60 @@ impl ParsedPragma {
                     field_attrs.push(attr);
                 }
             }
+            // Parse error_type (class-level marker)
+            else if trimmed == "error_type" {
+                error_type = true;
+            }
+            // Parse error_message (field-level marker)
+            else if trimmed == "error_message" {
+                error_message = true;
+            }
+            // Parse collection (class-level marker)
+            else if trimmed == "collection" {
+                collection = true;
+            }
+            // Parse newtype (class-level marker)
+            else if trimmed == "newtype" {
+                newtype = true;
+            }
+            // Parse unit (class-level marker)
+            else if trimmed == "unit" {
+                unit = true;
+            }
+            // Parse mix_in_aux (class-level marker)
+            else if trimmed == "mix_in_aux" {
+                mix_in_aux = true;
+            }
+            // Parse aux (field-level marker)
+            else if trimmed == "aux" {
+                aux = true;
+            }
+            // Parse optional_tail (field-level marker, forwarded to the Decode derive)
+            else if trimmed == "optional_tail" {
+                optional_tail = true;
+                field_attrs.push(quote! { #[ssz(optional_tail)] });
+            }
+            // Parse feature: name (class-level gate)
+            else if let Some(rest) = trimmed.strip_prefix("feature:") {
+                let name = rest.trim();
+                assert!(!name.is_empty(), "feature pragma must name a feature");
+                feature = Some(name.to_string());
+            }
         }
 
         Self {
             derives,
             struct_attrs,
             field_attrs,
+            error_type,
+            error_message,
+            collection,
+            newtype,
+            unit,
+            mix_in_aux,
+            aux,
+            optional_tail,
+            feature,
         }
     }
 
@@ -92,6 +176,54 @@ impl ParsedPragma {
         let mut combined_field = other.field_attrs.clone();
         combined_field.extend(self.field_attrs.iter().cloned());
         self.field_attrs = combined_field;
+
+        self.error_type |= other.error_type;
+        self.error_message |= other.error_message;
+        self.collection |= other.collection;
+        self.newtype |= other.newtype;
+        self.unit |= other.unit;
+        self.mix_in_aux |= other.mix_in_aux;
+        self.aux |= other.aux;
+        self.optional_tail |= other.optional_tail;
+        self.feature = self.feature.take().or(other.feature);
+    }
+
+    /// Returns an error if a `derive` pragma on `class` lists `PartialOrd` or
+    /// `Ord`, which the generated `kind` types (containers or unions) can't
+    /// derive.
+    pub fn check_derives(&self, class: &str, kind: &str) -> Result<(), String> {
+        for derive in &self.derives {
+            let path = Self::parse_derive_path(derive);
+            let is_ordering = path
+                .segments
+                .last()
+                .is_some_and(|seg| seg.ident == "PartialOrd" || seg.ident == "Ord");
+            if is_ordering {
+                return Err(format!(
+                    "derive pragma on `{class}` lists `{derive}`, which {kind} can't derive"
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns an error if `class` is marked `error_type` but doesn't have
+    /// exactly one field marked `error_message` among its `message_fields`.
+    pub fn check_error_type(&self, class: &str, message_fields: usize) -> Result<(), String> {
+        if self.error_type && message_fields != 1 {
+            return Err(format!(
+                "error_type class `{class}` must have exactly one error_message field, found \
+                 {message_fields}"
+            ));
+        }
+        Ok(())
+    }
+
+    /// Gate every item in `tokens` behind `#[cfg(feature = "...")]`
+    pub fn cfg_gate(tokens: TokenStream, feature: &str) -> TokenStream {
+        let file: syn::File = syn::parse2(tokens).expect("generated code must parse as items");
+        let items = file.items;
+        quote! { #( #[cfg(feature = #feature)] #items )* }
     }
 
     /// Build additional derive attributes from pragmas
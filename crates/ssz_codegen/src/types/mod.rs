@@ -2,11 +2,15 @@
 
 use std::collections::HashMap;
 
-use proc_macro2::{Span, TokenStream};
+use proc_macro2::{Literal, Span, TokenStream};
 use quote::quote;
 use syn::{Attribute, Ident, LitStr, Path, Type, TypePath, parse::Parser, parse_quote};
 
-use crate::{derive_config::DeriveConfig, pragma::ParsedPragma, types::resolver::TypeResolver};
+use crate::{
+    derive_config::{DeriveConfig, DocCommentStyle},
+    pragma::ParsedPragma,
+    types::resolver::TypeResolver,
+};
 pub mod resolver;
 
 /// Represents a size expression for type parameters
@@ -49,6 +53,18 @@ pub fn primitive_rust_type(base_name: &str) -> Type {
     })
 }
 
+/// Returns the Rust type of a `uint{bits}` schema type.
+fn uint_rust_type(bits: usize) -> Type {
+    match bits {
+        // Use U128/U256 from ssz_primitives for SSZ-specific serialization
+        128 => primitive_rust_type("U128"),
+        256 => primitive_rust_type("U256"),
+        8 | 16 | 32 | 64 => primitive_rust_type(&format!("u{bits}")),
+        // Widths without a primitive, such as `uint24`
+        _ => parse_quote!(UintN<#bits>),
+    }
+}
+
 /// Converts `crate::ssz::` paths to `super::` paths for cross-entry type references.
 ///
 /// When types are referenced across entry points in nested modules, they should use
@@ -145,6 +161,66 @@ impl TypeResolution {
         matches!(self.resolution, TypeResolutionKind::Unresolved)
     }
 
+    /// Returns true if the owned Rust type implements `Hash`.
+    ///
+    /// Classes count only when their owned type derives `Hash` (recorded in
+    /// [`TypeResolver::hash_classes`]); external types are assumed not to.
+    ///
+    /// # Arguments
+    ///
+    /// * `resolver` - The type resolver of the module using the type
+    ///
+    /// # Returns
+    ///
+    /// `true` if the type implements `Hash`, `false` otherwise
+    pub fn is_hashable(&self, resolver: &TypeResolver<'_>) -> bool {
+        match &self.resolution {
+            TypeResolutionKind::None
+            | TypeResolutionKind::Boolean
+            | TypeResolutionKind::UInt(_)
+            | TypeResolutionKind::Bytes(_)
+            | TypeResolutionKind::Bitvector(_)
+            | TypeResolutionKind::Bitlist(_) => true,
+            TypeResolutionKind::Vector(inner, _)
+            | TypeResolutionKind::List(inner, _)
+            | TypeResolutionKind::Option(inner) => inner.is_hashable(resolver),
+            TypeResolutionKind::Union(_, members) => {
+                members.iter().all(|member| member.is_hashable(resolver))
+            }
+            TypeResolutionKind::Class(name) => resolver.hash_classes.contains(name),
+            _ => false,
+        }
+    }
+
+    /// Returns the first class this type references that is gated behind a
+    /// `feature` pragma, along with that feature
+    ///
+    /// # Arguments
+    ///
+    /// * `resolver` - The type resolver of the module using the type
+    ///
+    /// # Returns
+    ///
+    /// The gated class name and its feature, or `None` if the type can be
+    /// used without any feature (only gates recorded in
+    /// [`TypeResolver::feature_gates`] are seen)
+    pub fn feature_gate(&self, resolver: &TypeResolver<'_>) -> Option<(String, String)> {
+        match &self.resolution {
+            TypeResolutionKind::Vector(inner, _)
+            | TypeResolutionKind::List(inner, _)
+            | TypeResolutionKind::Optional(inner)
+            | TypeResolutionKind::Option(inner) => inner.feature_gate(resolver),
+            TypeResolutionKind::Union(_, members) => members
+                .iter()
+                .find_map(|member| member.feature_gate(resolver)),
+            TypeResolutionKind::Class(name) => resolver
+                .feature_gates
+                .get(name)
+                .map(|feature| (name.clone(), feature.clone())),
+            _ => None,
+        }
+    }
+
     /// Returns true if the resolution is not `Unresolved`
     ///
     /// # Returns
@@ -212,14 +288,7 @@ impl TypeResolution {
                 parse_quote!(#class)
             }
             TypeResolutionKind::Boolean => primitive_rust_type("bool"),
-            TypeResolutionKind::UInt(size) => {
-                // Use U128/U256 from ssz_primitives for SSZ-specific serialization
-                match size {
-                    128 => primitive_rust_type("U128"),
-                    256 => primitive_rust_type("U256"),
-                    _ => primitive_rust_type(&format!("u{size}")),
-                }
-            }
+            TypeResolutionKind::UInt(size) => uint_rust_type(*size),
             TypeResolutionKind::Vector(ty, size_expr) => {
                 let size = size_expr.value() as usize;
                 // Special case: Vector[byte, N] -> FixedBytes<N>
@@ -364,14 +433,7 @@ impl TypeResolution {
                 parse_quote!(#class)
             }
             TypeResolutionKind::Boolean => primitive_rust_type("bool"),
-            TypeResolutionKind::UInt(size) => {
-                // Use U128/U256 from ssz_primitives for SSZ-specific serialization
-                match size {
-                    128 => primitive_rust_type("U128"),
-                    256 => primitive_rust_type("U256"),
-                    _ => primitive_rust_type(&format!("u{size}")),
-                }
-            }
+            TypeResolutionKind::UInt(size) => uint_rust_type(*size),
             TypeResolutionKind::Union(ident, _) => {
                 let ident = Ident::new(ident, Span::call_site());
                 parse_quote!(#ident)
@@ -464,6 +526,63 @@ impl TypeResolution {
         self.to_view_type_with_pragmas(&[])
     }
 
+    /// Generates the statements finishing a deep validation of `value`, a
+    /// view of this type as returned by a getter.
+    ///
+    /// Constructing a view only checks its own layout, so this walks what it
+    /// leaves to later accesses: nested container and union views validate
+    /// themselves, and list and vector views decode every item. Returns
+    /// nothing when the getter already checked the whole value.
+    pub fn view_validate_deep_stmt(&self, value: TokenStream) -> TokenStream {
+        match &self.resolution {
+            TypeResolutionKind::Class(_) | TypeResolutionKind::Union(_, _) => {
+                quote! { #value.validate_deep()?; }
+            }
+            TypeResolutionKind::List(inner, _) | TypeResolutionKind::Vector(inner, _)
+                if !matches!(inner.resolution, TypeResolutionKind::UInt(8)) =>
+            {
+                let check = inner.view_validate_deep_stmt(quote! { item });
+                if check.is_empty() {
+                    quote! {
+                        for item in #value.iter() {
+                            item?;
+                        }
+                    }
+                } else {
+                    quote! {
+                        for item in #value.iter() {
+                            let item = item?;
+                            #check
+                        }
+                    }
+                }
+            }
+            TypeResolutionKind::Option(inner) => {
+                let check = inner.view_validate_deep_stmt(quote! { inner });
+                if check.is_empty() {
+                    return quote! {};
+                }
+                quote! {
+                    if let Some(inner) = #value {
+                        #check
+                    }
+                }
+            }
+            TypeResolutionKind::Optional(inner) => {
+                let check = inner.view_validate_deep_stmt(quote! { inner });
+                if check.is_empty() {
+                    return quote! {};
+                }
+                quote! {
+                    if let ssz_types::Optional::Some(inner) = #value {
+                        #check
+                    }
+                }
+            }
+            _ => quote! {},
+        }
+    }
+
     /// Generate the view type with pragma information for external type handling
     pub fn to_view_type_with_pragmas(&self, pragmas: &[String]) -> Type {
         self.to_view_type_inner(false, pragmas)
@@ -502,14 +621,7 @@ impl TypeResolution {
                 }
             }
             TypeResolutionKind::Boolean => primitive_rust_type("bool"),
-            TypeResolutionKind::UInt(size) => {
-                // Use U128/U256 from ssz_primitives for SSZ-specific serialization
-                match size {
-                    128 => primitive_rust_type("U128"),
-                    256 => primitive_rust_type("U256"),
-                    _ => primitive_rust_type(&format!("u{size}")),
-                }
-            }
+            TypeResolutionKind::UInt(size) => uint_rust_type(*size),
             TypeResolutionKind::Vector(ty, size_expr) => {
                 let size = size_expr.value() as usize;
                 // Special case: Vector[byte, N] -> FixedBytesRef<'a, N>
@@ -866,6 +978,43 @@ impl ClassFieldDef {
         })
     }
 
+    /// `#[serde(with = ...)]` attribute giving this field the consensus
+    /// spec's JSON format, used when serde impls are enabled.
+    ///
+    /// Empty when the field's own serde impl already matches, when a
+    /// `field_attr` pragma sets a `serde` attribute, or when an
+    /// `#[ssz(with = ...)]` override means the Rust type may not follow the
+    /// schema type.
+    fn serde_attr(&self) -> TokenStream {
+        let has_serde_pragma = ParsedPragma::parse(&self.pragmas)
+            .field_attrs
+            .iter()
+            .filter_map(|tokens| Attribute::parse_outer.parse2(tokens.clone()).ok())
+            .flatten()
+            .any(|attr| attr.path().is_ident("serde"));
+        if has_serde_pragma || self.ssz_with_module().is_some() {
+            return quote! {};
+        }
+
+        let module = match &self.ty.resolution {
+            TypeResolutionKind::UInt(64) => "ssz_types::serde_utils::quoted_u64::_quoted_u64",
+            TypeResolutionKind::UInt(128 | 256) => "ssz_types::serde_utils::quoted_uint",
+            TypeResolutionKind::List(inner, _) => match inner.resolution {
+                TypeResolutionKind::UInt(8) => "ssz_types::serde_utils::hex_var_list",
+                TypeResolutionKind::UInt(64) => "ssz_types::serde_utils::quoted_u64_var_list",
+                _ => return quote! {},
+            },
+            TypeResolutionKind::Vector(inner, _)
+                if matches!(inner.resolution, TypeResolutionKind::UInt(64)) =>
+            {
+                "ssz_types::serde_utils::quoted_u64_fixed_vec"
+            }
+            // Byte vectors are `FixedBytes`, which serializes as hex itself.
+            _ => return quote! {},
+        };
+        quote! { #[serde(with = #module)] }
+    }
+
     /// Expression for whether this field's owned encoding is fixed-size,
     /// honoring `#[ssz(with = ...)]` overrides.
     fn is_ssz_fixed_len_expr(&self) -> TokenStream {
@@ -994,7 +1143,18 @@ impl ClassDef {
     pub fn to_token_stream(&self, ident: &Ident, derive_cfg: &DeriveConfig) -> TokenStream {
         use crate::pragma::ParsedPragma;
 
-        let field_tokens = &self.field_tokens;
+        let field_tokens: Vec<TokenStream> = if derive_cfg.serde {
+            self.fields
+                .iter()
+                .zip(&self.field_tokens)
+                .map(|(field, tokens)| {
+                    let serde_attr = field.serde_attr();
+                    quote! { #serde_attr #tokens }
+                })
+                .collect()
+        } else {
+            self.field_tokens.clone()
+        };
         let type_name = ident.to_string();
 
         // Parse pragmas
@@ -1004,8 +1164,11 @@ impl ClassDef {
             self.base,
             BaseClass::Container | BaseClass::StableContainer(_) | BaseClass::Profile(_)
         );
-        let owned_derive =
-            derive_cfg.owned_derive_attr_with_pragmas_filtered(&type_name, &pragmas, is_container);
+        let owned_derive = if self.uses_hex_debug(ident, derive_cfg) {
+            derive_cfg.owned_derive_attr_without_debug(&type_name, &pragmas, is_container)
+        } else {
+            derive_cfg.owned_derive_attr_with_pragmas_filtered(&type_name, &pragmas, is_container)
+        };
 
         // Build struct-level attributes from pragmas
         let struct_attrs = if !pragmas.struct_attrs.is_empty() {
@@ -1023,15 +1186,15 @@ impl ClassDef {
             (Some(docstring), Some(doc_comment)) => {
                 // Both exist: docstring first, then blank line, then doc_comment
                 let merged = format!("{}\n\n{}", docstring.trim(), doc_comment.trim());
-                Self::format_doc_comment(&merged)
+                Self::format_doc_comment(&merged, derive_cfg.doc_comment_style)
             }
             (Some(docstring), None) => {
                 // Only docstring
-                Self::format_doc_comment(docstring)
+                Self::format_doc_comment(docstring, derive_cfg.doc_comment_style)
             }
             (None, Some(doc_comment)) => {
                 // Only doc_comment
-                Self::format_doc_comment(doc_comment)
+                Self::format_doc_comment(doc_comment, derive_cfg.doc_comment_style)
             }
             (None, None) => quote! {},
         };
@@ -1078,6 +1241,362 @@ impl ClassDef {
         }
     }
 
+    /// Generates an `impl Default` for the owned struct, or nothing if a field
+    /// has no default value (see [`ClassDef::default_value_expr`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `ident` - The identifier for the class
+    /// * `resolver` - The type resolver, used to look up nested classes
+    ///
+    /// # Returns
+    ///
+    /// A [`TokenStream`] containing the `Default` implementation.
+    pub fn to_default_impl(&self, ident: &Ident, resolver: &TypeResolver<'_>) -> TokenStream {
+        if matches!(self.base, BaseClass::Union) {
+            return quote! {};
+        }
+
+        let Some(field_defaults) = self
+            .fields
+            .iter()
+            .map(|field| {
+                let field_name = Ident::new(&field.name, Span::call_site());
+                let value = Self::default_value_expr(&field.ty, resolver)?;
+                Some(quote! { #field_name: #value })
+            })
+            .collect::<Option<Vec<_>>>()
+        else {
+            return quote! {};
+        };
+
+        quote! {
+            impl Default for #ident {
+                fn default() -> Self {
+                    Self {
+                        #(#field_defaults),*
+                    }
+                }
+            }
+        }
+    }
+
+    /// Generates `FIELD_NAMES` and `fields()` for the owned struct.
+    ///
+    /// The layout of each field comes from the `ssz::Encode` and
+    /// `tree_hash::TreeHash` impls of its owned type, so nested classes and
+    /// external types are described exactly.
+    ///
+    /// # Arguments
+    ///
+    /// * `ident` - The identifier for the owned struct
+    ///
+    /// # Returns
+    ///
+    /// A TokenStream containing the inherent impl, or nothing for unions
+    pub fn to_field_info_impl(&self, ident: &Ident) -> TokenStream {
+        if matches!(self.base, BaseClass::Union) {
+            return quote! {};
+        }
+
+        let names: Vec<&str> = self
+            .fields
+            .iter()
+            .map(|field| field.name.as_str())
+            .collect();
+        let field_count = Literal::usize_unsuffixed(names.len());
+        let infos = self.fields.iter().map(|field| {
+            let name = &field.name;
+            let ty = field.ty.unwrap_type();
+            quote! {
+                ssz_types::FieldInfo {
+                    name: #name,
+                    is_fixed_size: <#ty as ssz::Encode>::is_ssz_fixed_len(),
+                    tree_hash_type: <#ty as tree_hash::TreeHash>::tree_hash_type(),
+                }
+            }
+        });
+
+        quote! {
+            impl #ident {
+                /// Names of the fields, in declaration order.
+                pub const FIELD_NAMES: &[&str] = &[#(#names),*];
+
+                /// SSZ layout of each field, in declaration order.
+                pub fn fields() -> [ssz_types::FieldInfo; #field_count] {
+                    [#(#infos),*]
+                }
+            }
+        }
+    }
+
+    /// Generates a `root_eq` method on the owned struct comparing two
+    /// values by their SHA-256 `tree_hash_root`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ident` - The identifier for the owned struct
+    ///
+    /// # Returns
+    ///
+    /// A TokenStream containing the inherent impl, or nothing for unions
+    pub fn to_root_eq_impl(&self, ident: &Ident) -> TokenStream {
+        if matches!(self.base, BaseClass::Union) {
+            return quote! {};
+        }
+
+        quote! {
+            impl #ident {
+                /// Returns whether `self` and `other` have the same SHA-256 tree hash root.
+                ///
+                /// Agrees with `==`, but hashes both values instead of comparing them field by
+                /// field.
+                pub fn root_eq(&self, other: &Self) -> bool {
+                    tree_hash::TreeHash::tree_hash_root::<tree_hash::Sha256Hasher>(self)
+                        == tree_hash::TreeHash::tree_hash_root::<tree_hash::Sha256Hasher>(other)
+                }
+            }
+        }
+    }
+
+    /// Generates a `diff` method on the owned struct listing the fields that
+    /// differ between two values, with both values `Debug`-formatted.
+    ///
+    /// # Arguments
+    ///
+    /// * `ident` - The identifier for the owned struct
+    ///
+    /// # Returns
+    ///
+    /// A TokenStream containing the inherent impl, or nothing for unions
+    pub fn to_diff_impl(&self, ident: &Ident) -> TokenStream {
+        if matches!(self.base, BaseClass::Union) {
+            return quote! {};
+        }
+
+        let checks = self.fields.iter().map(|field| {
+            let label = &field.name;
+            let field_name = Ident::new(&field.name, Span::call_site());
+            quote! {
+                if self.#field_name != other.#field_name {
+                    diffs.push((
+                        #label,
+                        format!("{:?}", self.#field_name),
+                        format!("{:?}", other.#field_name),
+                    ));
+                }
+            }
+        });
+
+        quote! {
+            impl #ident {
+                /// Returns the name and the `Debug` output of both values of each field that
+                /// differs between `self` and `other`, in declaration order.
+                #[allow(unused_mut, reason = "generated code using ssz-gen")]
+                pub fn diff(&self, other: &Self) -> Vec<ssz_types::FieldDiff> {
+                    let mut diffs = Vec::new();
+                    #(#checks)*
+                    diffs
+                }
+            }
+        }
+    }
+
+    /// Returns the field as a `&[u8]` if it's a byte vector or byte list.
+    fn byte_field_slice(ty: &TypeResolution, field_name: &Ident) -> Option<TokenStream> {
+        match &ty.resolution {
+            TypeResolutionKind::List(inner, _)
+                if matches!(inner.resolution, TypeResolutionKind::UInt(8)) =>
+            {
+                Some(quote! { &self.#field_name })
+            }
+            TypeResolutionKind::Vector(inner, _)
+                if matches!(inner.resolution, TypeResolutionKind::UInt(8)) =>
+            {
+                Some(quote! { self.#field_name.as_ref() })
+            }
+            TypeResolutionKind::Bytes(_) => Some(quote! { self.#field_name.as_ref() }),
+            _ => None,
+        }
+    }
+
+    /// Whether the owned struct gets a hex `Debug` impl in place of the
+    /// derived one: hex debug is enabled, the struct derives `Debug`, and a
+    /// field holds bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `ident` - The identifier for the owned struct
+    /// * `derive_cfg` - The derive configuration
+    ///
+    /// # Returns
+    ///
+    /// `true` if [`ClassDef::to_hex_debug_impl`] generates an impl
+    pub fn uses_hex_debug(&self, ident: &Ident, derive_cfg: &DeriveConfig) -> bool {
+        let pragmas = crate::pragma::ParsedPragma::parse(&self.pragmas);
+        derive_cfg.hex_debug
+            && !matches!(self.base, BaseClass::Union)
+            && derive_cfg.owned_derives_debug(&ident.to_string(), &pragmas)
+            && self.fields.iter().any(|field| {
+                let field_name = Ident::new(&field.name, Span::call_site());
+                Self::byte_field_slice(&field.ty, &field_name).is_some()
+            })
+    }
+
+    /// Generates a `Debug` impl for the owned struct that prints byte fields
+    /// as `0x`-prefixed hex, or nothing unless
+    /// [`ClassDef::uses_hex_debug`].
+    ///
+    /// # Arguments
+    ///
+    /// * `ident` - The identifier for the owned struct
+    /// * `derive_cfg` - The derive configuration
+    ///
+    /// # Returns
+    ///
+    /// A TokenStream containing the `Debug` implementation
+    pub fn to_hex_debug_impl(&self, ident: &Ident, derive_cfg: &DeriveConfig) -> TokenStream {
+        if !self.uses_hex_debug(ident, derive_cfg) {
+            return quote! {};
+        }
+
+        let name = ident.to_string();
+        let fields = self.fields.iter().map(|field| {
+            let label = &field.name;
+            let field_name = Ident::new(&field.name, Span::call_site());
+            match Self::byte_field_slice(&field.ty, &field_name) {
+                Some(bytes) => quote! { .field(#label, &ssz_types::HexBytes(#bytes)) },
+                None => quote! { .field(#label, &self.#field_name) },
+            }
+        });
+
+        quote! {
+            impl std::fmt::Debug for #ident {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    f.debug_struct(#name)
+                        #(#fields)*
+                        .finish()
+                }
+            }
+        }
+    }
+
+    /// Generates `FIELD_OFFSETS` and `FIELD_SIZES` for an all-fixed Container.
+    ///
+    /// # Arguments
+    ///
+    /// * `ident` - The identifier for the owned struct
+    /// * `resolver` - The type resolver used to size nested classes
+    ///
+    /// # Returns
+    ///
+    /// A TokenStream containing the inherent impl, or nothing when some field
+    /// size isn't known at codegen time
+    pub fn to_field_offsets_impl(&self, ident: &Ident, resolver: &TypeResolver<'_>) -> TokenStream {
+        let Some(sizes) = self.known_fixed_field_sizes(resolver) else {
+            return quote! {};
+        };
+        if sizes.is_empty() {
+            return quote! {};
+        }
+
+        let offsets = sizes.iter().scan(0, |offset, size| {
+            let start = *offset;
+            *offset += size;
+            Some(Literal::usize_unsuffixed(start))
+        });
+        let sizes = sizes.iter().map(|size| Literal::usize_unsuffixed(*size));
+
+        quote! {
+            impl #ident {
+                /// Byte offset of each field in the SSZ encoding, in declaration order.
+                pub const FIELD_OFFSETS: &[usize] = &[#(#offsets),*];
+
+                /// Byte size of each field in the SSZ encoding, in declaration order.
+                pub const FIELD_SIZES: &[usize] = &[#(#sizes),*];
+            }
+        }
+    }
+
+    /// Encoded size of every field of a Container whose fields are all
+    /// fixed-size, or `None` if the class isn't a Container or some field's
+    /// size depends on an external type or an `#[ssz(with = ...)]` override.
+    fn known_fixed_field_sizes(&self, resolver: &TypeResolver<'_>) -> Option<Vec<usize>> {
+        if !self.is_container() {
+            return None;
+        }
+        self.fields
+            .iter()
+            .map(|field| {
+                if field.ssz_with_module().is_some() {
+                    return None;
+                }
+                Self::known_fixed_size(&field.ty, resolver)
+            })
+            .collect()
+    }
+
+    /// Encoded size of a fixed-size type, or `None` if it's variable-size or
+    /// not known at codegen time.
+    fn known_fixed_size(ty: &TypeResolution, resolver: &TypeResolver<'_>) -> Option<usize> {
+        match &ty.resolution {
+            TypeResolutionKind::Boolean => Some(1),
+            TypeResolutionKind::UInt(bits) => Some(bits / 8),
+            TypeResolutionKind::Bytes(n) => Some(*n),
+            TypeResolutionKind::Bitvector(size) => Some((size.value() as usize).div_ceil(8)),
+            TypeResolutionKind::Vector(inner, size) => {
+                Some(Self::known_fixed_size(inner, resolver)? * size.value() as usize)
+            }
+            TypeResolutionKind::Class(name) => match resolver.classes.get(name) {
+                Some(ClassDefinition::Custom(class_def)) => Some(
+                    class_def
+                        .known_fixed_field_sizes(resolver)?
+                        .into_iter()
+                        .sum(),
+                ),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Expression for the default value of an owned field of type `ty`.
+    ///
+    /// `FixedBytes`, bitlists and vectors of them don't implement `Default`
+    /// (arrays only do up to 32 elements), so they're built explicitly.
+    /// Returns `None` for unions, which have no natural default, and for
+    /// classes containing one.
+    fn default_value_expr(ty: &TypeResolution, resolver: &TypeResolver<'_>) -> Option<TokenStream> {
+        match &ty.resolution {
+            TypeResolutionKind::Optional(_) => Some(quote! { ssz_types::Optional::None }),
+            TypeResolutionKind::Option(_) => Some(quote! { None }),
+            TypeResolutionKind::Bytes(_) => Some(quote! { ssz_types::FixedBytes::zero() }),
+            TypeResolutionKind::Vector(inner, _) => {
+                if matches!(inner.resolution, TypeResolutionKind::UInt(8)) {
+                    return Some(quote! { ssz_types::FixedBytes::zero() });
+                }
+                let elem = Self::default_value_expr(inner, resolver)?;
+                Some(quote! { ssz_types::FixedVector::from_elem(#elem) })
+            }
+            TypeResolutionKind::Bitlist(_) => Some(quote! {
+                ssz_types::BitList::with_capacity(0).expect("empty bitlist is always valid")
+            }),
+            TypeResolutionKind::Union(_, _) => None,
+            TypeResolutionKind::Class(name) => match resolver.classes.get(name) {
+                Some(ClassDefinition::Custom(class_def)) => {
+                    let has_default = !matches!(class_def.base, BaseClass::Union)
+                        && class_def
+                            .fields
+                            .iter()
+                            .all(|field| Self::default_value_expr(&field.ty, resolver).is_some());
+                    has_default.then(|| quote! { Default::default() })
+                }
+                _ => Some(quote! { Default::default() }),
+            },
+            _ => Some(quote! { Default::default() }),
+        }
+    }
+
     /// Sums a list of `usize` expressions, or `0usize` when empty.
     fn sum_expr(terms: &[TokenStream]) -> TokenStream {
         if terms.is_empty() {
@@ -1137,9 +1656,16 @@ impl ClassDef {
     /// deciding fixed-vs-variable and offset-slot positions from the fields'
     /// owned encoding at runtime rather than at codegen time.
     fn field_bytes_expr(&self, field_index: usize) -> TokenStream {
+        let read = self.read_field_bytes_expr(field_index);
+        quote! { #read? }
+    }
+
+    /// Like [`ClassDef::field_bytes_expr`], but leaves the
+    /// `Result<&[u8], DecodeError>` for the caller to handle.
+    fn read_field_bytes_expr(&self, field_index: usize) -> TokenStream {
         let table = self.field_layout_table_expr();
         quote! {
-            ssz::layout::read_field_bytes(self.bytes, #table, #field_index)?
+            ssz::layout::read_field_bytes(self.bytes, #table, #field_index)
         }
     }
 
@@ -1216,9 +1742,8 @@ impl ClassDef {
                 use ssz::Decode;
                 let bitvector_bytes = #source
                     .get(..#bitvector_length)
-                    .ok_or(ssz::DecodeError::InvalidByteLength {
-                        len: #source.len(),
-                        expected: #bitvector_length,
+                    .ok_or_else(|| ssz::DecodeError::Incomplete {
+                        needed: #bitvector_length - #source.len(),
                     })?;
                 let bitvector = ssz_types::BitVector::<#bitvector_bits>::from_ssz_bytes(
                     bitvector_bytes
@@ -1234,19 +1759,12 @@ impl ClassDef {
         }
     }
 
-    /// Generates the view struct definition for zero-copy decoding.
-    ///
-    /// Creates a thin wrapper around `&[u8]` instead of eagerly decoded fields.
+    /// Formats a doc comment string into doc comments wrapped at 80 characters, `///` lines
+    /// or a single `/** */` block depending on `style`.
     ///
-    /// # Arguments
-    ///
-    /// * `ident` - The base identifier for the class (e.g., `Foo`)
-    ///
-    /// # Returns
-    ///
-    /// Formats a doc comment string into `///` lines with 80-character wrapping.
-    /// The 80-character limit includes the `/// ` prefix.
-    pub fn format_doc_comment(text: &str) -> TokenStream {
+    /// The 80-character limit includes the `/// ` prefix; block comments wrap at the same
+    /// width.
+    pub fn format_doc_comment(text: &str, style: DocCommentStyle) -> TokenStream {
         if text.trim().is_empty() {
             return quote! {};
         }
@@ -1263,10 +1781,10 @@ impl ClassDef {
             if trimmed.is_empty() {
                 // Empty line - add as blank doc comment
                 if !current_line.is_empty() {
-                    lines.push(format!("/// {}", current_line.trim()));
+                    lines.push(current_line.trim().to_string());
                     current_line.clear();
                 }
-                lines.push("///".to_string());
+                lines.push(String::new());
                 continue;
             }
 
@@ -1283,12 +1801,12 @@ impl ClassDef {
                     current_line = test_line;
                 } else {
                     if !current_line.is_empty() {
-                        lines.push(format!("/// {}", current_line.trim()));
+                        lines.push(current_line.trim().to_string());
                     }
                     current_line = word.to_string();
                     // If a single word is too long, we still need to add it
                     if current_line.len() + PREFIX_LENGTH > MAX_LINE_LENGTH {
-                        lines.push(format!("/// {}", current_line.trim()));
+                        lines.push(current_line.trim().to_string());
                         current_line.clear();
                     }
                 }
@@ -1297,13 +1815,34 @@ impl ClassDef {
 
         // Add remaining line
         if !current_line.is_empty() {
-            lines.push(format!("/// {}", current_line.trim()));
+            lines.push(current_line.trim().to_string());
+        }
+
+        if style == DocCommentStyle::Block {
+            // A single multi-line `doc` attribute is printed as a `/** */` block.
+            let mut doc = String::from("\n");
+            for line in &lines {
+                if line.is_empty() {
+                    doc.push_str(" *\n");
+                } else {
+                    doc.push_str(&format!(" * {line}\n"));
+                }
+            }
+            doc.push(' ');
+            return quote! { #[doc = #doc] };
         }
 
         // Parse each line as a doc comment
         let doc_lines: Vec<TokenStream> = lines
             .iter()
-            .map(|line| syn::parse_str::<TokenStream>(line).unwrap_or_else(|_| quote! {}))
+            .map(|line| {
+                let line = if line.is_empty() {
+                    "///".to_string()
+                } else {
+                    format!("/// {line}")
+                };
+                syn::parse_str::<TokenStream>(&line).unwrap_or_else(|_| quote! {})
+            })
             .collect();
 
         quote! {
@@ -1324,7 +1863,7 @@ impl ClassDef {
             Use `.to_owned()` to convert to the owned type when needed.",
             ident
         );
-        let doc_comments = Self::format_doc_comment(&doc_comment);
+        let doc_comments = Self::format_doc_comment(&doc_comment, derive_cfg.doc_comment_style);
         let type_name = ident.to_string();
         let pragmas = ParsedPragma::parse(&self.pragmas);
         // Container, StableContainer, and Profile don't support PartialOrd/Ord
@@ -1377,10 +1916,13 @@ impl ClassDef {
                 // would misalign the `MerkleHasher` leaf stream (it only
                 // forms a leaf per HASH_SIZE bytes), so all fields go through
                 // their getters.
+                let aux_index = self.aux_field_index(ident);
                 let hash_operations: Vec<TokenStream> = self
                     .fields
                     .iter()
-                    .map(|field| {
+                    .enumerate()
+                    .filter(|&(idx, _)| Some(idx) != aux_index)
+                    .map(|(_, field)| {
                         let field_name = Ident::new(&field.name, Span::call_site());
                         quote! {
                             {
@@ -1392,7 +1934,19 @@ impl ClassDef {
                     })
                     .collect();
 
-                let num_fields = self.fields.len();
+                let num_fields = hash_operations.len();
+                let finish = match aux_index {
+                    Some(idx) => {
+                        let aux_name = Ident::new(&self.fields[idx].name, Span::call_site());
+                        quote! {
+                            let body_root = hasher.finish().expect("finish hasher");
+                            let #aux_name = self.#aux_name().expect("valid view");
+                            let aux_root = <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&#aux_name);
+                            tree_hash::mix_in_aux_with_hasher::<H>(&body_root, &aux_root)
+                        }
+                    }
+                    None => quote! { hasher.finish().expect("finish hasher") },
+                };
 
                 quote! {
                     impl<'a> tree_hash::TreeHash for #ref_ident<'a> {
@@ -1414,7 +1968,7 @@ impl ClassDef {
                             let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(#num_fields);
                             #(#hash_operations)*
 
-                            hasher.finish().expect("finish hasher")
+                            #finish
                         }
                     }
                 }
@@ -1579,25 +2133,181 @@ impl ClassDef {
         }
     }
 
+    /// Returns the index of the `aux` field of a class marked `mix_in_aux`,
+    /// whose root is mixed into the root of the remaining fields.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a `mix_in_aux` class is not a container with exactly one
+    /// `aux` field, or if a field is marked `aux` outside such a class.
+    fn aux_field_index(&self, ident: &Ident) -> Option<usize> {
+        let mut aux_fields = self
+            .fields
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| ParsedPragma::parse(&f.pragmas).aux)
+            .map(|(idx, _)| idx);
+
+        if !ParsedPragma::parse(&self.pragmas).mix_in_aux {
+            assert!(
+                aux_fields.next().is_none(),
+                "class `{ident}` has an aux field but is not marked mix_in_aux"
+            );
+            return None;
+        }
+
+        match (&self.base, aux_fields.next(), aux_fields.next()) {
+            (BaseClass::Container, Some(idx), None) => Some(idx),
+            _ => {
+                panic!("mix_in_aux class `{ident}` must be a container with exactly one aux field")
+            }
+        }
+    }
+
+    /// Checks that `optional_tail` fields form a trailing run of container
+    /// fields, after at least one required field, whose types can be
+    /// defaulted when a shorter encoding omits them.
+    ///
+    /// Classes used as such fields need the `default_impl` configuration.
+    ///
+    /// # Panics
+    ///
+    /// Panics with a message naming the class if any of these does not hold.
+    pub fn check_optional_tail(&self, ident: &Ident, resolver: &TypeResolver<'_>) {
+        let Some(first_tail) = self
+            .fields
+            .iter()
+            .position(|f| ParsedPragma::parse(&f.pragmas).optional_tail)
+        else {
+            return;
+        };
+
+        assert!(
+            matches!(self.base, BaseClass::Container),
+            "optional_tail fields of `{ident}` are only supported in containers"
+        );
+        assert!(
+            first_tail > 0,
+            "optional_tail fields of `{ident}` must follow at least one required field"
+        );
+        for field in &self.fields[first_tail..] {
+            assert!(
+                ParsedPragma::parse(&field.pragmas).optional_tail,
+                "optional_tail fields of `{ident}` must be the last fields, but `{}` follows them",
+                field.name
+            );
+            let has_default = match &field.ty.resolution {
+                TypeResolutionKind::Bytes(_) | TypeResolutionKind::Bitlist(_) => false,
+                TypeResolutionKind::Vector(inner, _) => {
+                    !matches!(inner.resolution, TypeResolutionKind::UInt(8))
+                        && Self::default_value_expr(&field.ty, resolver).is_some()
+                }
+                _ => Self::default_value_expr(&field.ty, resolver).is_some(),
+            };
+            assert!(
+                has_default,
+                "optional_tail field `{}` of `{ident}` has a type without a Default implementation",
+                field.name
+            );
+        }
+    }
+
+    /// Merkleizes the roots of `fields` with a hasher from `tree_hash::with_pooled_hasher`.
+    fn pooled_merkle_root(num_leaves: usize, fields: &[&Ident]) -> TokenStream {
+        quote! {
+            tree_hash::with_pooled_hasher::<H, _>(#num_leaves, |hasher| {
+                #(
+                    hasher.write(<_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.#fields).as_ref())?;
+                )*
+                Ok(())
+            })
+            .expect("tree hash derive should not apply too many leaves")
+        }
+    }
+
     /// Generates a `TreeHash` implementation for owned structs.
     ///
     /// # Arguments
     ///
     /// * `ident` - The identifier for the class (e.g., `Foo`)
+    /// * `derive_cfg` - Whether containers hash with pooled hashers
     ///
     /// # Returns
     ///
     /// A [`TokenStream`] containing the generic TreeHash implementation.
-    pub fn to_owned_tree_hash_impl(&self, ident: &Ident) -> TokenStream {
+    pub fn to_owned_tree_hash_impl(&self, ident: &Ident, derive_cfg: &DeriveConfig) -> TokenStream {
         let field_names: Vec<Ident> = self
             .fields
             .iter()
             .map(|f| Ident::new(&f.name, Span::call_site()))
             .collect();
+        let aux_index = self.aux_field_index(ident);
 
         match self.base {
             BaseClass::Container => {
+                if let Some(aux_index) = aux_index {
+                    let aux_name = &field_names[aux_index];
+                    let body_names: Vec<&Ident> = field_names
+                        .iter()
+                        .enumerate()
+                        .filter(|&(idx, _)| idx != aux_index)
+                        .map(|(_, name)| name)
+                        .collect();
+                    let num_leaves = body_names.len();
+                    let body_root = if derive_cfg.pooled_tree_hash {
+                        let body_root = Self::pooled_merkle_root(num_leaves, &body_names);
+                        quote! { let body_root = #body_root; }
+                    } else {
+                        quote! {
+                            let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(#num_leaves);
+                            #(
+                                hasher.write(<_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.#body_names).as_ref())
+                                    .expect("tree hash derive should not apply too many leaves");
+                            )*
+                            let body_root = hasher.finish().expect("tree hash derive should not have a remaining buffer");
+                        }
+                    };
+                    // The root is not a plain merkleization of one leaf per
+                    // field, so no `TreeHashLeaves` impl.
+                    return quote! {
+                        impl tree_hash::TreeHash for #ident {
+                            fn tree_hash_type() -> tree_hash::TreeHashType {
+                                tree_hash::TreeHashType::Container
+                            }
+
+                            fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                                unreachable!("Container should never be packed")
+                            }
+
+                            fn tree_hash_packing_factor() -> usize {
+                                unreachable!("Container should never be packed")
+                            }
+
+                            fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                                use tree_hash::TreeHash;
+                                #body_root
+                                let aux_root = <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.#aux_name);
+                                tree_hash::mix_in_aux_with_hasher::<H>(&body_root, &aux_root)
+                            }
+                        }
+                    };
+                }
+
                 let num_leaves = field_names.len();
+                let leaf_indices = 0..num_leaves;
+                let root = if derive_cfg.pooled_tree_hash {
+                    let field_names: Vec<&Ident> = field_names.iter().collect();
+                    Self::pooled_merkle_root(num_leaves, &field_names)
+                } else {
+                    quote! {
+                        let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(#num_leaves);
+                        #(
+                            hasher.write(<_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.#field_names).as_ref())
+                                .expect("tree hash derive should not apply too many leaves");
+                        )*
+                        hasher.finish().expect("tree hash derive should not have a remaining buffer")
+                    }
+                };
                 quote! {
                     impl tree_hash::TreeHash for #ident {
                         fn tree_hash_type() -> tree_hash::TreeHashType {
@@ -1614,12 +2324,22 @@ impl ClassDef {
 
                         fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
                             use tree_hash::TreeHash;
-                            let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(#num_leaves);
-                            #(
-                                hasher.write(<_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.#field_names).as_ref())
-                                    .expect("tree hash derive should not apply too many leaves");
-                            )*
-                            hasher.finish().expect("tree hash derive should not have a remaining buffer")
+                            #root
+                        }
+                    }
+
+                    impl tree_hash::TreeHashLeaves for #ident {
+                        fn tree_hash_leaf_count() -> usize {
+                            #num_leaves
+                        }
+
+                        fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(&self, index: usize) -> H::Output {
+                            match index {
+                                #(
+                                    #leaf_indices => <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.#field_names),
+                                )*
+                                _ => panic!("leaf index {index} out of range for {} leaves", #num_leaves),
+                            }
                         }
                     }
                 }
@@ -1723,17 +2443,34 @@ impl ClassDef {
     /// view-side counterpart, so their getter decodes the slice to the owned
     /// field type through `module::decode`, mirroring the `ssz_derive`
     /// `Decode` impl.
-    fn container_view_getter(&self, idx: usize, field: &ClassFieldDef) -> TokenStream {
+    ///
+    /// When `fixed_range` is given, the field's byte range is known at
+    /// codegen time and the getter slices it directly: `from_ssz_bytes` has
+    /// already checked the view's length against the fixed size, so the
+    /// range always fits.
+    fn container_view_getter(
+        &self,
+        idx: usize,
+        field: &ClassFieldDef,
+        fixed_range: Option<(usize, usize)>,
+    ) -> TokenStream {
         let field_name = Ident::new(&field.name, Span::call_site());
         let view_ty = field.ty.to_view_type_with_pragmas(&field.pragmas);
-        let field_bytes = self.field_bytes_expr(idx);
+        let field_bytes = match fixed_range {
+            Some((start, end)) => {
+                let start = Literal::usize_unsuffixed(start);
+                let end = Literal::usize_unsuffixed(end);
+                quote! { &self.bytes[#start..#end] }
+            }
+            None => self.field_bytes_expr(idx),
+        };
 
         if let Some(module) = field.ssz_with_module() {
             let owned_ty = field.ty.unwrap_type();
             return quote! {
                 pub fn #field_name(&self) -> Result<#owned_ty, ssz::DecodeError> {
                     let bytes = #field_bytes;
-                    #module::decode::from_ssz_bytes(bytes)
+                    #module::decode::from_ssz_bytes(bytes).map_err(|e| e.bounded(bytes.len()))
                 }
             };
         }
@@ -1742,20 +2479,24 @@ impl ClassDef {
         // a union with a selector byte.
         if let TypeResolutionKind::Option(inner_ty) = &field.ty.resolution {
             let inner_view_ty = inner_ty.to_view_type_with_pragmas(&field.pragmas);
+            let inner_ty = inner_ty.unwrap_type();
             return quote! {
                 pub fn #field_name(&self) -> Result<#view_ty, ssz::DecodeError> {
                     let bytes = #field_bytes;
-                    if bytes.is_empty() {
-                        return Err(ssz::DecodeError::InvalidByteLength {
-                            len: 0,
-                            expected: 1,
-                        });
-                    }
+                    ssz::view::reject_empty(bytes, 1)?;
                     let selector = bytes[0];
                     match selector {
-                        0 => Ok(None),
+                        0 => ssz::check_union_body_len(&bytes[1..], 0).map(|()| None),
                         1 => {
-                            let inner = <#inner_view_ty as ssz::view::DecodeView>::from_ssz_bytes(&bytes[1..])?;
+                            if <#inner_ty as ssz::Encode>::is_ssz_fixed_len() {
+                                ssz::check_union_body_len(
+                                    &bytes[1..],
+                                    <#inner_ty as ssz::Encode>::ssz_fixed_len(),
+                                )?;
+                            }
+                            let body = &bytes[1..];
+                            let inner = <#inner_view_ty as ssz::view::DecodeView>::from_ssz_bytes(body)
+                                .map_err(|e| e.bounded(body.len()))?;
                             Ok(Some(inner))
                         }
                         _ => Err(ssz::DecodeError::BytesInvalid(
@@ -1769,27 +2510,35 @@ impl ClassDef {
         quote! {
             pub fn #field_name(&self) -> Result<#view_ty, ssz::DecodeError> {
                 let bytes = #field_bytes;
-                ssz::view::DecodeView::from_ssz_bytes(bytes)
+                ssz::view::DecodeView::from_ssz_bytes(bytes).map_err(|e| e.bounded(bytes.len()))
             }
         }
     }
 
-    /// Union-with-null selector decode for an `Option` value in `bytes_expr`.
-    fn option_selector_decode(inner_view_ty: &Type, bytes_expr: TokenStream) -> TokenStream {
+    /// Union-with-null selector decode for an `Option` value in `bytes_expr`,
+    /// whose member has owned type `inner_ty` and view type `inner_view_ty`.
+    fn option_selector_decode(
+        inner_ty: &Type,
+        inner_view_ty: &Type,
+        bytes_expr: TokenStream,
+    ) -> TokenStream {
         quote! {
             {
                 let bytes = #bytes_expr;
-                if bytes.is_empty() {
-                    return Err(ssz::DecodeError::InvalidByteLength {
-                        len: 0,
-                        expected: 1,
-                    });
-                }
+                ssz::view::reject_empty(bytes, 1)?;
                 let selector = bytes[0];
                 match selector {
-                    0 => Ok(None),
+                    0 => ssz::check_union_body_len(&bytes[1..], 0).map(|()| None),
                     1 => {
-                        let inner = <#inner_view_ty as ssz::view::DecodeView>::from_ssz_bytes(&bytes[1..])?;
+                        if <#inner_ty as ssz::Encode>::is_ssz_fixed_len() {
+                            ssz::check_union_body_len(
+                                &bytes[1..],
+                                <#inner_ty as ssz::Encode>::ssz_fixed_len(),
+                            )?;
+                        }
+                        let body = &bytes[1..];
+                        let inner = <#inner_view_ty as ssz::view::DecodeView>::from_ssz_bytes(body)
+                            .map_err(|e| e.bounded(body.len()))?;
                         Ok(Some(inner))
                     }
                     _ => Err(ssz::DecodeError::BytesInvalid(
@@ -1835,6 +2584,7 @@ impl ClassDef {
                         None => #absent,
                     };
                     #module::decode::from_ssz_bytes(field_bytes)
+                        .map_err(|e| e.bounded(field_bytes.len()))
                 }
             };
         }
@@ -1844,6 +2594,22 @@ impl ClassDef {
         match &field.ty.resolution {
             TypeResolutionKind::Optional(inner_ty) => {
                 let inner_view_ty = inner_ty.to_view_type_with_pragmas(&field.pragmas);
+                // An `Option` member (from Union[null, T]) has no view type
+                // of its own, so decode its selector here.
+                let decode = match &inner_ty.resolution {
+                    TypeResolutionKind::Option(member_ty) => {
+                        let decode = Self::option_selector_decode(
+                            &member_ty.unwrap_type(),
+                            &member_ty.to_view_type_with_pragmas(&field.pragmas),
+                            quote! { field_bytes },
+                        );
+                        quote! { #decode? }
+                    }
+                    _ => quote! {
+                        <#inner_view_ty as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)
+                            .map_err(|e| e.bounded(field_bytes.len()))?
+                    },
+                };
                 quote! {
                     pub fn #field_name(&self) -> Result<#view_ty, ssz::DecodeError> {
                         #preamble
@@ -1851,7 +2617,7 @@ impl ClassDef {
                             Some(bytes) => bytes,
                             None => return Ok(ssz_types::Optional::None),
                         };
-                        let inner = <#inner_view_ty as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)?;
+                        let inner = #decode;
                         Ok(ssz_types::Optional::Some(inner))
                     }
                 }
@@ -1860,7 +2626,11 @@ impl ClassDef {
             // selector byte.
             TypeResolutionKind::Option(inner_ty) => {
                 let inner_view_ty = inner_ty.to_view_type_with_pragmas(&field.pragmas);
-                let decode = Self::option_selector_decode(&inner_view_ty, quote! { field_bytes });
+                let decode = Self::option_selector_decode(
+                    &inner_ty.unwrap_type(),
+                    &inner_view_ty,
+                    quote! { field_bytes },
+                );
                 quote! {
                     pub fn #field_name(&self) -> Result<#view_ty, ssz::DecodeError> {
                         #preamble
@@ -1880,6 +2650,7 @@ impl ClassDef {
                         None => unreachable!("required field is always active"),
                     };
                     ssz::view::DecodeView::from_ssz_bytes(field_bytes)
+                        .map_err(|e| e.bounded(field_bytes.len()))
                 }
             },
         }
@@ -1893,22 +2664,41 @@ impl ClassDef {
     /// # Arguments
     ///
     /// * `ident` - The base identifier for the class (e.g., `Foo`)
+    /// * `resolver` - The type resolver used to size nested classes
     ///
     /// # Returns
     ///
     /// A [`TokenStream`] containing the impl block with getter methods.
-    pub fn to_view_getters(&self, ident: &Ident) -> TokenStream {
+    pub fn to_view_getters(&self, ident: &Ident, resolver: &TypeResolver<'_>) -> TokenStream {
         let ref_ident = Ident::new(&format!("{}Ref", ident), Span::call_site());
 
         // Plain containers derive their layout from the field types' `Encode`
         // impls at runtime (const-foldable), so views stay in agreement with
-        // the owned encoding even for class or external field types.
+        // the owned encoding even for class or external field types. When
+        // every field size is known at codegen time, the getters slice fixed
+        // ranges instead, relying on the exact length check in
+        // `from_ssz_bytes`.
         if matches!(self.base, BaseClass::Container) {
+            let fixed_ranges: Vec<Option<(usize, usize)>> =
+                match self.known_fixed_field_sizes(resolver) {
+                    Some(sizes) => sizes
+                        .iter()
+                        .scan(0, |offset, size| {
+                            let start = *offset;
+                            *offset += size;
+                            Some(Some((start, *offset)))
+                        })
+                        .collect(),
+                    None => vec![None; self.fields.len()],
+                };
             let getters: Vec<TokenStream> = self
                 .fields
                 .iter()
+                .zip(fixed_ranges)
                 .enumerate()
-                .map(|(idx, field)| self.container_view_getter(idx, field))
+                .map(|(idx, (field, fixed_range))| {
+                    self.container_view_getter(idx, field, fixed_range)
+                })
                 .collect();
 
             return quote! {
@@ -1937,6 +2727,59 @@ impl ClassDef {
         }
     }
 
+    /// Generates `validate_deep` for a Container, StableContainer or Profile
+    /// view, which reads every field through its getter and validates the
+    /// result all the way down (see [`TypeResolution::view_validate_deep_stmt`]),
+    /// returning the first error.
+    ///
+    /// # Arguments
+    ///
+    /// * `ident` - The base identifier for the class
+    ///
+    /// # Returns
+    ///
+    /// A [`TokenStream`] containing the method, or nothing for unions.
+    pub fn to_view_validate_deep_impl(&self, ident: &Ident) -> TokenStream {
+        if matches!(self.base, BaseClass::Union) {
+            return quote! {};
+        }
+        let ref_ident = Ident::new(&format!("{ident}Ref"), Span::call_site());
+
+        let field_checks = self.fields.iter().map(|field| {
+            let field_name = Ident::new(&field.name, Span::call_site());
+            // `#[ssz(with = ...)]` getters decode the owned value outright.
+            let check = if field.ssz_with_module().is_some() {
+                quote! {}
+            } else {
+                field.ty.view_validate_deep_stmt(quote! { value })
+            };
+            if check.is_empty() {
+                quote! { self.#field_name()?; }
+            } else {
+                quote! {
+                    let value = self.#field_name()?;
+                    #check
+                }
+            }
+        });
+
+        quote! {
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> #ref_ident<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    #(#field_checks)*
+                    Ok(())
+                }
+            }
+        }
+    }
+
     /// Generates the [`DecodeView`](ssz::view::DecodeView) implementation for the view struct
     ///
     /// Now performs validation-only construction - no eager field decoding.
@@ -2015,6 +2858,42 @@ impl ClassDef {
         }
     }
 
+    /// Generates `TryFrom<&[u8]>` for the view struct, delegating to
+    /// [`DecodeView`](ssz::view::DecodeView), and an `as_bytes` accessor
+    /// returning the bytes the view was decoded from.
+    ///
+    /// # Arguments
+    ///
+    /// * `ident` - The base identifier for the class
+    ///
+    /// # Returns
+    ///
+    /// A [`TokenStream`] containing the implementations, empty for unions.
+    pub fn to_view_bytes_impl(&self, ident: &Ident) -> TokenStream {
+        if matches!(self.base, BaseClass::Union) {
+            return quote! {};
+        }
+        let ref_ident = Ident::new(&format!("{}Ref", ident), Span::call_site());
+
+        quote! {
+            impl<'a> TryFrom<&'a [u8]> for #ref_ident<'a> {
+                type Error = ssz::DecodeError;
+
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> #ref_ident<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
+        }
+    }
+
     /// Generates the [`SszTypeInfo`](ssz::view::SszTypeInfo) implementation for view structs.
     ///
     /// This is required for view types to be used in
@@ -2122,9 +3001,209 @@ impl ClassDef {
         }
     }
 
+    /// Generates the expression converting a field's view to its owned value.
+    ///
+    /// `getter` is the expression producing the field's view, which lets
+    /// callers choose between panicking and propagating decode errors.
+    fn view_field_to_owned_expr(
+        field: &ClassFieldDef,
+        is_stable_container: bool,
+        getter: TokenStream,
+    ) -> TokenStream {
+        let is_optional = matches!(field.ty.resolution, TypeResolutionKind::Optional(_));
+
+        // `#[ssz(with = ...)]` getters already decode to the owned
+        // field type, so no view conversion is needed.
+        if field.ssz_with_module().is_some() {
+            return getter;
+        }
+
+        // For StableContainer, fields are wrapped in ssz_types::Optional<T>
+        // Getter returns Result<Optional<TRef>, Error>
+        // We need to convert Optional<TRef> to Optional<T>
+        if is_stable_container && is_optional {
+            let inner_resolution = match &field.ty.resolution {
+                TypeResolutionKind::Optional(inner) => &inner.resolution,
+                _ => unreachable!("Optional check guarantees Optional resolution"),
+            };
+            match inner_resolution {
+                TypeResolutionKind::Boolean | TypeResolutionKind::UInt(_) => {
+                    // Primitives: Optional<T> -> Optional<T> (just copy)
+                    getter
+                }
+                TypeResolutionKind::Union(_, _) => {
+                    // Union types: getter returns Optional<UnionRef>
+                    // Need to map the Optional and convert UnionRef to Union
+                    // Use ToOwnedSsz trait method for proper type resolution with external types
+                    quote! {
+                        match #getter {
+                            ssz_types::Optional::Some(inner) => ssz_types::Optional::Some(ssz_types::view::ToOwnedSsz::to_owned(&inner)),
+                            ssz_types::Optional::None => ssz_types::Optional::None,
+                        }
+                    }
+                }
+                _ => {
+                    // Complex types: Optional<TRef> -> Optional<T>
+                    // Use match to convert inner TRef to T
+                    // Use ToOwnedSsz trait method for proper type resolution with external types
+                    quote! {
+                        match #getter {
+                            ssz_types::Optional::Some(inner) => ssz_types::Optional::Some(ssz_types::view::ToOwnedSsz::to_owned(&inner)),
+                            ssz_types::Optional::None => ssz_types::Optional::None,
+                        }
+                    }
+                }
+            }
+        } else {
+            // Regular Container (non-StableContainer)
+            match &field.ty.resolution {
+                TypeResolutionKind::Option(_) => {
+                    // Option types (from Union[null, T]) - getter returns Result<Option<TRef>,
+                    // Error> Need to convert Option<TRef> to Option<T> by
+                    // calling to_owned() on inner if Some Use ToOwnedSsz trait
+                    // method for proper type resolution with external types
+                    quote! {
+                        #getter.map(|inner| ssz_types::view::ToOwnedSsz::to_owned(&inner))
+                    }
+                }
+                TypeResolutionKind::Boolean | TypeResolutionKind::UInt(_) => {
+                    // Primitives can be copied directly from getter
+                    getter
+                }
+                TypeResolutionKind::List(ty, _size) => {
+                    let inner = &**ty;
+                    if matches!(inner.resolution, TypeResolutionKind::UInt(8)) {
+                        quote! {
+                            ssz_types::VariableList::new(#getter.to_owned()).expect("valid view")
+                        }
+                    } else {
+                        quote! {
+                            {
+                                let view = #getter;
+                                let items: Result<Vec<_>, _> = view
+                                    .iter()
+                                    .map(|item_result| {
+                                        item_result.map(|item| ssz_types::view::ToOwnedSsz::to_owned(&item))
+                                    })
+                                    .collect();
+                                let items = items.expect("valid view");
+                                ssz_types::VariableList::new(items).expect("valid view")
+                            }
+                        }
+                    }
+                }
+                TypeResolutionKind::Vector(ty, _size) => {
+                    // Check if it's Vector[byte, N] which uses FixedBytesRef
+                    let inner = &**ty;
+                    if matches!(inner.resolution, TypeResolutionKind::UInt(8)) {
+                        // FixedBytesRef::to_owned() returns [u8; N], need to wrap in FixedBytes<N>
+                        quote! {
+                            ssz_types::FixedBytes(#getter.to_owned())
+                        }
+                    } else {
+                        // FixedVectorRef::to_owned() returns Result<FixedVector<T, N>, Error>
+                        quote! {
+                            #getter.to_owned().expect("valid view")
+                        }
+                    }
+                }
+                TypeResolutionKind::Bitlist(_) | TypeResolutionKind::Bitvector(_) => {
+                    // BitListRef/BitVectorRef have inherent to_owned() methods
+                    quote! {
+                        #getter.to_owned()
+                    }
+                }
+                TypeResolutionKind::Bytes(_) => {
+                    // FixedBytesRef has inherent to_owned() method
+                    quote! {
+                        ssz_types::FixedBytes(#getter.to_owned())
+                    }
+                }
+                TypeResolutionKind::Class(_)
+                | TypeResolutionKind::External
+                | TypeResolutionKind::Union(_, _) => {
+                    // For complex types (containers, unions, external), use ToOwnedSsz trait method
+                    // for proper type resolution with external types
+                    quote! {
+                        {
+                            let view = #getter;
+                            ssz_types::view::ToOwnedSsz::to_owned(&view)
+                        }
+                    }
+                }
+                _ => {
+                    // Fallback for any other types - use inherent to_owned()
+                    quote! {
+                        #getter.to_owned()
+                    }
+                }
+            }
+        }
+    }
+
+    /// Generates the statement that writes a field's owned value into `out`
+    /// for `to_owned_into`.
+    ///
+    /// Lists are cleared and refilled and vectors are overwritten element by
+    /// element, so their existing allocations are reused. Every other field is
+    /// assigned the same value `to_owned` would produce. The statements run
+    /// after `validate_deep`, so converting nested views can't panic.
+    fn view_field_to_owned_into_stmt(
+        field: &ClassFieldDef,
+        is_stable_container: bool,
+    ) -> TokenStream {
+        let field_name = Ident::new(&field.name, Span::call_site());
+        let getter = quote! { self.#field_name()? };
+
+        if field.ssz_with_module().is_none() {
+            match &field.ty.resolution {
+                TypeResolutionKind::List(ty, _size)
+                    if matches!(ty.resolution, TypeResolutionKind::UInt(8)) =>
+                {
+                    return quote! {
+                        let view = #getter;
+                        out.#field_name.clear();
+                        out.#field_name
+                            .try_extend_from_slice(view.as_bytes())
+                            .expect("valid view");
+                    };
+                }
+                TypeResolutionKind::List(_, _) => {
+                    return quote! {
+                        let view = #getter;
+                        out.#field_name.clear();
+                        for item in view.iter() {
+                            out.#field_name
+                                .push(ssz_types::view::ToOwnedSsz::to_owned(&item?))
+                                .expect("valid view");
+                        }
+                    };
+                }
+                TypeResolutionKind::Vector(ty, _size)
+                    if !matches!(ty.resolution, TypeResolutionKind::UInt(8)) =>
+                {
+                    return quote! {
+                        let view = #getter;
+                        for (slot, item) in out.#field_name.iter_mut().zip(view.iter()) {
+                            *slot = ssz_types::view::ToOwnedSsz::to_owned(&item?);
+                        }
+                    };
+                }
+                _ => {}
+            }
+        }
+
+        let value = Self::view_field_to_owned_expr(field, is_stable_container, getter);
+        quote! {
+            out.#field_name = #value;
+        }
+    }
+
     /// Generates the to_owned implementation for converting view to owned
     ///
-    /// Now uses getter methods instead of direct field access.
+    /// Now uses getter methods instead of direct field access. Also generates
+    /// `to_owned_into`, which writes into an existing owned value and reuses
+    /// the backing storage of its lists and vectors.
     ///
     /// # Arguments
     ///
@@ -2132,7 +3211,7 @@ impl ClassDef {
     ///
     /// # Returns
     ///
-    /// A [`TokenStream`] containing the `to_owned` method implementation.
+    /// A [`TokenStream`] containing the `to_owned` and `to_owned_into` method implementations.
     pub fn to_view_to_owned_impl(&self, ident: &Ident) -> TokenStream {
         let ref_ident = Ident::new(&format!("{}Ref", ident), Span::call_site());
 
@@ -2148,146 +3227,18 @@ impl ClassDef {
             .iter()
             .map(|field| {
                 let field_name = Ident::new(&field.name, Span::call_site());
-                let is_optional = matches!(field.ty.resolution, TypeResolutionKind::Optional(_));
-
-                // `#[ssz(with = ...)]` getters already decode to the owned
-                // field type, so no view conversion is needed.
-                if field.ssz_with_module().is_some() {
-                    return quote! {
-                        #field_name: self.#field_name().expect("valid view")
-                    };
-                }
-
-                // For StableContainer, fields are wrapped in ssz_types::Optional<T>
-                // Getter returns Result<Optional<TRef>, Error>
-                // We need to convert Optional<TRef> to Optional<T>
-                if is_stable_container && is_optional {
-                    let inner_resolution = match &field.ty.resolution {
-                        TypeResolutionKind::Optional(inner) => &inner.resolution,
-                        _ => unreachable!("Optional check guarantees Optional resolution"),
-                    };
-                    match inner_resolution {
-                        TypeResolutionKind::Boolean | TypeResolutionKind::UInt(_) => {
-                            // Primitives: Optional<T> -> Optional<T> (just copy)
-                            quote! {
-                                #field_name: self.#field_name().expect("valid view")
-                            }
-                        }
-                        TypeResolutionKind::Union(_, _) => {
-                            // Union types: getter returns Optional<UnionRef>
-                            // Need to map the Optional and convert UnionRef to Union
-                            // Use ToOwnedSsz trait method for proper type resolution with external types
-                            quote! {
-                                #field_name: match self.#field_name().expect("valid view") {
-                                    ssz_types::Optional::Some(inner) => ssz_types::Optional::Some(ssz_types::view::ToOwnedSsz::to_owned(&inner)),
-                                    ssz_types::Optional::None => ssz_types::Optional::None,
-                                }
-                            }
-                        }
-                        _ => {
-                            // Complex types: Optional<TRef> -> Optional<T>
-                            // Use match to convert inner TRef to T
-                            // Use ToOwnedSsz trait method for proper type resolution with external types
-                            quote! {
-                                #field_name: match self.#field_name().expect("valid view") {
-                                    ssz_types::Optional::Some(inner) => ssz_types::Optional::Some(ssz_types::view::ToOwnedSsz::to_owned(&inner)),
-                                    ssz_types::Optional::None => ssz_types::Optional::None,
-                                }
-                            }
-                        }
-                    }
-                } else {
-                    // Regular Container (non-StableContainer)
-                    match &field.ty.resolution {
-                        TypeResolutionKind::Option(_) => {
-                            // Option types (from Union[null, T]) - getter returns Result<Option<TRef>, Error>
-                            // Need to convert Option<TRef> to Option<T> by calling to_owned() on inner if Some
-                            // Use ToOwnedSsz trait method for proper type resolution with external types
-                            quote! {
-                                #field_name: self.#field_name().expect("valid view").map(|inner| ssz_types::view::ToOwnedSsz::to_owned(&inner))
-                            }
-                        }
-                        TypeResolutionKind::Boolean | TypeResolutionKind::UInt(_) => {
-                            // Primitives can be copied directly from getter
-                            quote! {
-                                #field_name: self.#field_name().expect("valid view")
-                            }
-                        }
-                        TypeResolutionKind::List(ty, _size) => {
-                            let inner = &**ty;
-                            if matches!(inner.resolution, TypeResolutionKind::UInt(8)) {
-                                quote! {
-                                    #field_name: ssz_types::VariableList::new(
-                                        self.#field_name().expect("valid view").to_owned(),
-                                    )
-                                    .expect("valid view")
-                                }
-                            } else {
-                                quote! {
-                                    #field_name: {
-                                        let view = self.#field_name().expect("valid view");
-                                        let items: Result<Vec<_>, _> = view
-                                            .iter()
-                                            .map(|item_result| {
-                                                item_result.map(|item| ssz_types::view::ToOwnedSsz::to_owned(&item))
-                                            })
-                                            .collect();
-                                        let items = items.expect("valid view");
-                                        ssz_types::VariableList::new(items).expect("valid view")
-                                    }
-                                }
-                            }
-                        }
-                        TypeResolutionKind::Vector(ty, _size) => {
-                            // Check if it's Vector[byte, N] which uses FixedBytesRef
-                            let inner = &**ty;
-                            if matches!(inner.resolution, TypeResolutionKind::UInt(8)) {
-                                // FixedBytesRef::to_owned() returns [u8; N], need to wrap in FixedBytes<N>
-                                quote! {
-                                    #field_name: ssz_types::FixedBytes(self.#field_name().expect("valid view").to_owned())
-                                }
-                            } else {
-                                // FixedVectorRef::to_owned() returns Result<FixedVector<T, N>, Error>
-                                quote! {
-                                    #field_name: self.#field_name().expect("valid view").to_owned().expect("valid view")
-                                }
-                            }
-                        }
-                        TypeResolutionKind::Bitlist(_) | TypeResolutionKind::Bitvector(_) => {
-                            // BitListRef/BitVectorRef have inherent to_owned() methods
-                            quote! {
-                                #field_name: self.#field_name().expect("valid view").to_owned()
-                            }
-                        }
-                        TypeResolutionKind::Bytes(_) => {
-                            // FixedBytesRef has inherent to_owned() method
-                            quote! {
-                                #field_name: ssz_types::FixedBytes(self.#field_name().expect("valid view").to_owned())
-                            }
-                        }
-                        TypeResolutionKind::Class(_)
-                        | TypeResolutionKind::External
-                        | TypeResolutionKind::Union(_, _) => {
-                            // For complex types (containers, unions, external), use ToOwnedSsz trait method
-                            // for proper type resolution with external types
-                            quote! {
-                                #field_name: {
-                                    let view = self.#field_name().expect("valid view");
-                                    ssz_types::view::ToOwnedSsz::to_owned(&view)
-                                }
-                            }
-                        }
-                        _ => {
-                            // Fallback for any other types - use inherent to_owned()
-                            quote! {
-                                #field_name: self.#field_name().expect("valid view").to_owned()
-                            }
-                        }
-                    }
-                }
+                let getter = quote! { self.#field_name().expect("valid view") };
+                let value = Self::view_field_to_owned_expr(field, is_stable_container, getter);
+                quote! { #field_name: #value }
             })
             .collect();
 
+        let field_writes: Vec<TokenStream> = self
+            .fields
+            .iter()
+            .map(|field| Self::view_field_to_owned_into_stmt(field, is_stable_container))
+            .collect();
+
         quote! {
             #[allow(dead_code, reason = "generated code using ssz-gen")]
             impl<'a> #ref_ident<'a> {
@@ -2297,6 +3248,987 @@ impl ClassDef {
                         #(#field_conversions),*
                     }
                 }
+
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+                pub fn to_owned_into(&self, out: &mut #ident) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    #(#field_writes)*
+                    Ok(())
+                }
+            }
+        }
+    }
+
+    /// Generates `validate_and_to_owned` for a Container view, which converts
+    /// the view to its owned value while collecting every field that fails to
+    /// decode rather than stopping at the first.
+    ///
+    /// Each field is decoded from its slice through its owned `ssz::Decode`
+    /// impl (or its `#[ssz(with = ...)]` module), which checks the field's
+    /// contents all the way down. Nested generated containers recurse instead,
+    /// so their errors are reported under dotted paths like `inner.x`. A field
+    /// that fails takes its default value (see [`ClassDef::default_value_expr`])
+    /// in the returned owned value, so nothing is generated if some field has
+    /// no default.
+    ///
+    /// # Arguments
+    ///
+    /// * `ident` - The base identifier for the class
+    /// * `resolver` - The type resolver holding nested class definitions
+    ///
+    /// # Returns
+    ///
+    /// A [`TokenStream`] containing the method, or nothing for non-Containers.
+    pub fn to_view_validate_impl(&self, ident: &Ident, resolver: &TypeResolver<'_>) -> TokenStream {
+        if !matches!(self.base, BaseClass::Container) {
+            return quote! {};
+        }
+        let Some(defaults) = self
+            .fields
+            .iter()
+            .map(|field| Self::default_value_expr(&field.ty, resolver))
+            .collect::<Option<Vec<_>>>()
+        else {
+            return quote! {};
+        };
+
+        let ref_ident = Ident::new(&format!("{ident}Ref"), Span::call_site());
+        let field_values = self.fields.iter().zip(defaults).enumerate().map(
+            |(idx, (field, default))| {
+                let field_name = Ident::new(&field.name, Span::call_site());
+                let path = &field.name;
+                let read = self.read_field_bytes_expr(idx);
+
+                if field.ssz_with_module().is_none() && Self::has_view_validation(&field.ty, resolver) {
+                    let view_ty = field.ty.to_view_type_with_pragmas(&field.pragmas);
+                    return quote! {
+                        #field_name: match #read.and_then(|bytes| {
+                            <#view_ty as ssz::view::DecodeView>::from_ssz_bytes(bytes)
+                                .map_err(|e| e.bounded(bytes.len()))
+                        }) {
+                            Ok(view) => {
+                                let (value, nested) = view.validate_and_to_owned();
+                                errors.extend(
+                                    nested
+                                        .into_iter()
+                                        .map(|(path, error)| (format!("{}.{}", #path, path), error)),
+                                );
+                                value
+                            }
+                            Err(error) => {
+                                errors.push((#path.to_string(), error));
+                                #default
+                            }
+                        }
+                    };
+                }
+
+                let decode = match field.ssz_with_module() {
+                    Some(module) => quote! { #module::decode::from_ssz_bytes },
+                    None => {
+                        let owned_ty = field.ty.unwrap_type();
+                        quote! { <#owned_ty as ssz::Decode>::from_ssz_bytes }
+                    }
+                };
+                quote! {
+                    #field_name: match #read.and_then(|bytes| #decode(bytes).map_err(|e| e.bounded(bytes.len()))) {
+                        Ok(value) => value,
+                        Err(error) => {
+                            errors.push((#path.to_string(), error));
+                            #default
+                        }
+                    }
+                }
+            },
+        );
+
+        quote! {
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> #ref_ident<'a> {
+                /// Converts this view to an owned value, reading every field and
+                /// collecting the ones that fail to decode.
+                ///
+                /// Each error is paired with the path of its field, such as
+                /// `inner.x` for a field of a nested container, and the failed
+                /// field takes its default value in the returned owned value.
+                #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+                pub fn validate_and_to_owned(&self) -> (#ident, Vec<(String, ssz::DecodeError)>) {
+                    let mut errors = Vec::new();
+                    let value = #ident {
+                        #(#field_values),*
+                    };
+                    (value, errors)
+                }
+            }
+        }
+    }
+
+    /// Whether `ty` is a generated Container whose view has
+    /// `validate_and_to_owned` (see [`ClassDef::to_view_validate_impl`]).
+    fn has_view_validation(ty: &TypeResolution, resolver: &TypeResolver<'_>) -> bool {
+        let TypeResolutionKind::Class(name) = &ty.resolution else {
+            return false;
+        };
+        matches!(
+            resolver.classes.get(name),
+            Some(ClassDefinition::Custom(class_def))
+                if matches!(class_def.base, BaseClass::Container)
+                    && class_def
+                        .fields
+                        .iter()
+                        .all(|field| Self::default_value_expr(&field.ty, resolver).is_some())
+        )
+    }
+
+    /// Generates `PartialEq` between the view and the owned struct, in both
+    /// directions.
+    ///
+    /// Fields are compared one at a time through the view getters, stopping at
+    /// the first mismatch, so the owned value is never fully materialized.
+    /// Nested container fields compare through their own view impl. For a
+    /// StableContainer or Profile, the active-fields bitvector is checked
+    /// against the owned fields' presence before any field is read. A view
+    /// that fails to decode is unequal to every owned value.
+    ///
+    /// Only called for classes whose owned struct derives `PartialEq`, which
+    /// guarantees every field type can be compared.
+    ///
+    /// # Arguments
+    ///
+    /// * `ident` - The base identifier for the class
+    /// * `resolver` - The type resolver holding nested class definitions
+    ///
+    /// # Returns
+    ///
+    /// A [`TokenStream`] containing both `PartialEq` implementations.
+    pub fn to_view_owned_eq_impl(&self, ident: &Ident, resolver: &TypeResolver<'_>) -> TokenStream {
+        let ref_ident = Ident::new(&format!("{ident}Ref"), Span::call_site());
+        let is_stable_container = matches!(
+            self.base,
+            BaseClass::StableContainer(_) | BaseClass::Profile(_)
+        );
+
+        let bitvector_check = match self.base {
+            BaseClass::StableContainer(Some(_)) | BaseClass::Profile(Some(_))
+                if self.active_bitvector_length() > 0 =>
+            {
+                let bitvector_length = self.active_bitvector_length();
+                let bitvector_bits = match self.base {
+                    BaseClass::StableContainer(Some(max_fields)) => max_fields as usize,
+                    _ => self.optional_field_count(),
+                };
+                let optional_fields =
+                    self.fields.iter().enumerate().filter(|(_, f)| {
+                        matches!(f.ty.resolution, TypeResolutionKind::Optional(_))
+                    });
+                let presence_checks: Vec<TokenStream> = optional_fields
+                    .enumerate()
+                    .map(|(optional_idx, (idx, field))| {
+                        // StableContainer bits cover every declared field,
+                        // Profile bits only the optional ones.
+                        let bit = match self.base {
+                            BaseClass::StableContainer(_) => idx,
+                            _ => optional_idx,
+                        };
+                        let field_name = Ident::new(&field.name, Span::call_site());
+                        quote! {
+                            if bitvector.get(#bit).unwrap_or(false) != other.#field_name.is_some() {
+                                return Ok(false);
+                            }
+                        }
+                    })
+                    .collect();
+                quote! {
+                    use ssz::Decode;
+                    let bitvector_bytes = self
+                        .bytes
+                        .get(..#bitvector_length)
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: #bitvector_length - self.bytes.len(),
+                        })?;
+                    let bitvector = ssz_types::BitVector::<#bitvector_bits>::from_ssz_bytes(
+                        bitvector_bytes
+                    )?;
+                    #(#presence_checks)*
+                }
+            }
+            _ => quote! {},
+        };
+
+        // Nested containers have their own view/owned `PartialEq`.
+        let is_view_comparable = |ty: &TypeResolution| match &ty.resolution {
+            TypeResolutionKind::Class(name) => matches!(
+                resolver.classes.get(name),
+                Some(ClassDefinition::Custom(ClassDef {
+                    base: BaseClass::Container
+                        | BaseClass::StableContainer(_)
+                        | BaseClass::Profile(_),
+                    ..
+                }))
+            ),
+            _ => false,
+        };
+
+        let field_checks: Vec<TokenStream> = self
+            .fields
+            .iter()
+            .map(|field| {
+                let field_name = Ident::new(&field.name, Span::call_site());
+                let getter = quote! { self.#field_name()? };
+                let equal = match &field.ty.resolution {
+                    _ if field.ssz_with_module().is_some() => {
+                        quote! { #getter == other.#field_name }
+                    }
+                    TypeResolutionKind::Optional(inner) if is_view_comparable(inner) => quote! {
+                        match (#getter, &other.#field_name) {
+                            (ssz_types::Optional::Some(view), ssz_types::Optional::Some(owned)) => {
+                                view == *owned
+                            }
+                            (ssz_types::Optional::None, ssz_types::Optional::None) => true,
+                            _ => false,
+                        }
+                    },
+                    TypeResolutionKind::Option(inner) if is_view_comparable(inner) => quote! {
+                        match (#getter, &other.#field_name) {
+                            (Some(view), Some(owned)) => view == *owned,
+                            (None, None) => true,
+                            _ => false,
+                        }
+                    },
+                    _ if is_view_comparable(&field.ty) => quote! { #getter == other.#field_name },
+                    _ => {
+                        let owned_ty = field.ty.unwrap_type();
+                        let value =
+                            Self::view_field_to_owned_expr(field, is_stable_container, getter);
+                        quote! {
+                            {
+                                let value: #owned_ty = #value;
+                                value == other.#field_name
+                            }
+                        }
+                    }
+                };
+                quote! {
+                    if !(#equal) {
+                        return Ok(false);
+                    }
+                }
+            })
+            .collect();
+
+        quote! {
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> #ref_ident<'a> {
+                fn eq_owned(&self, other: &#ident) -> Result<bool, ssz::DecodeError> {
+                    #bitvector_check
+                    #(#field_checks)*
+                    Ok(true)
+                }
+            }
+
+            impl<'a> PartialEq<#ident> for #ref_ident<'a> {
+                fn eq(&self, other: &#ident) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+
+            impl<'a> PartialEq<#ref_ident<'a>> for #ident {
+                fn eq(&self, other: &#ref_ident<'a>) -> bool {
+                    other == self
+                }
+            }
+        }
+    }
+
+    /// Generates `TryFrom<StableRef<'a>>` and `TryFrom<Stable>` for a
+    /// Profile, narrowing a decoded view of its StableContainer, or the owned
+    /// StableContainer, to the owned profile.
+    ///
+    /// The view conversion reads the StableContainer's active-fields
+    /// bitvector and the owned one checks each `Optional`; both fail unless
+    /// every field the profile requires is active and every field the profile
+    /// leaves out is inactive. A profile view can't borrow the
+    /// StableContainer's bytes, since the two encodings use different
+    /// bitvectors, so the target is the owned type.
+    ///
+    /// Returns an empty stream when the StableContainer is not defined in the
+    /// same module, or when a field changes type in a way other than
+    /// unwrapping `Optional` or narrowing to a profile of the original
+    /// StableContainer.
+    ///
+    /// # Arguments
+    ///
+    /// * `ident` - The base identifier for the class
+    /// * `resolver` - The type resolver holding the StableContainer definition
+    ///
+    /// # Returns
+    ///
+    /// A [`TokenStream`] containing the `TryFrom` implementations, or an
+    /// empty stream if none are generated.
+    pub fn to_profile_narrowing_impl(
+        &self,
+        ident: &Ident,
+        resolver: &TypeResolver<'_>,
+    ) -> TokenStream {
+        let BaseClass::Profile(Some((stable_name, _))) = &self.base else {
+            return quote! {};
+        };
+        let Some(ClassDefinition::Custom(stable_def)) = resolver.classes.get(stable_name) else {
+            return quote! {};
+        };
+        let BaseClass::StableContainer(Some(max_fields)) = stable_def.base else {
+            return quote! {};
+        };
+        let stable_ident = Ident::new(stable_name, Span::call_site());
+        let stable_ref_ident = Ident::new(&format!("{stable_name}Ref"), Span::call_site());
+        let profile_name = ident.to_string();
+
+        let mut presence_checks = vec![];
+        let mut owned_absence_checks = vec![];
+        for (stable_idx, stable_field) in stable_def.fields.iter().enumerate() {
+            let check = match self.fields.iter().find(|f| f.name == stable_field.name) {
+                Some(field) if !matches!(field.ty.resolution, TypeResolutionKind::Optional(_)) => {
+                    let message = format!(
+                        "field `{}` is required by profile `{profile_name}` but inactive",
+                        field.name
+                    );
+                    quote! {
+                        if !bitvector.get(#stable_idx).unwrap_or(false) {
+                            return Err(ssz::DecodeError::BytesInvalid(#message.to_string()));
+                        }
+                    }
+                }
+                Some(_) => continue,
+                None => {
+                    let message = format!(
+                        "field `{}` is not part of profile `{profile_name}` but active",
+                        stable_field.name
+                    );
+                    let field_name = Ident::new(&stable_field.name, Span::call_site());
+                    owned_absence_checks.push(quote! {
+                        if value.#field_name.is_some() {
+                            return Err(ssz::DecodeError::BytesInvalid(#message.to_string()));
+                        }
+                    });
+                    quote! {
+                        if bitvector.get(#stable_idx).unwrap_or(false) {
+                            return Err(ssz::DecodeError::BytesInvalid(#message.to_string()));
+                        }
+                    }
+                }
+            };
+            presence_checks.push(check);
+        }
+
+        let Some(field_conversions) = self
+            .fields
+            .iter()
+            .map(|field| {
+                let stable_field = &stable_def.fields[*stable_def.field_index.get(&field.name)?];
+                let value = Self::narrowed_field_expr(field, stable_field, resolver)?;
+                let field_name = Ident::new(&field.name, Span::call_site());
+                Some(quote! { #field_name: #value })
+            })
+            .collect::<Option<Vec<_>>>()
+        else {
+            return quote! {};
+        };
+        let Some(owned_field_conversions) = self
+            .fields
+            .iter()
+            .map(|field| {
+                let stable_field = &stable_def.fields[*stable_def.field_index.get(&field.name)?];
+                let message = format!(
+                    "field `{}` is required by profile `{profile_name}` but inactive",
+                    field.name
+                );
+                let value =
+                    Self::narrowed_owned_field_expr(field, stable_field, &message, resolver)?;
+                let field_name = Ident::new(&field.name, Span::call_site());
+                Some(quote! { #field_name: #value })
+            })
+            .collect::<Option<Vec<_>>>()
+        else {
+            return quote! {};
+        };
+
+        // A profile keeping every field as `Optional` accepts any active set.
+        let check_active = if presence_checks.is_empty() {
+            quote! {}
+        } else {
+            let bitvector_length = (max_fields as usize).div_ceil(8);
+            let bitvector_bits = max_fields as usize;
+            quote! {
+                use ssz::Decode;
+                let bitvector_bytes = value
+                    .bytes
+                    .get(..#bitvector_length)
+                    .ok_or_else(|| ssz::DecodeError::Incomplete {
+                        needed: #bitvector_length - value.bytes.len(),
+                    })?;
+                let bitvector = ssz_types::BitVector::<#bitvector_bits>::from_ssz_bytes(
+                    bitvector_bytes
+                )?;
+                #(#presence_checks)*
+            }
+        };
+
+        quote! {
+            impl<'a> TryFrom<#stable_ref_ident<'a>> for #ident {
+                type Error = ssz::DecodeError;
+
+                fn try_from(value: #stable_ref_ident<'a>) -> Result<Self, Self::Error> {
+                    #check_active
+                    Ok(Self {
+                        #(#field_conversions),*
+                    })
+                }
+            }
+
+            impl TryFrom<#stable_ident> for #ident {
+                type Error = ssz::DecodeError;
+
+                fn try_from(value: #stable_ident) -> Result<Self, Self::Error> {
+                    #(#owned_absence_checks)*
+                    Ok(Self {
+                        #(#owned_field_conversions),*
+                    })
+                }
+            }
+        }
+    }
+
+    /// Whether `ty` is a profile of the StableContainer `stable_inner`.
+    fn is_profile_of(
+        ty: &TypeResolution,
+        stable_inner: &TypeResolution,
+        resolver: &TypeResolver<'_>,
+    ) -> bool {
+        match (&ty.resolution, &stable_inner.resolution) {
+            (TypeResolutionKind::Class(profile), TypeResolutionKind::Class(stable)) => {
+                matches!(
+                    resolver.classes.get(profile),
+                    Some(ClassDefinition::Custom(ClassDef {
+                        base: BaseClass::Profile(Some((name, _))),
+                        ..
+                    })) if name == stable
+                )
+            }
+            _ => false,
+        }
+    }
+
+    /// Expression moving `stable_field` out of an owned StableContainer named
+    /// `value` into the owned value of the profile's `field`.
+    ///
+    /// The owned counterpart of [`Self::narrowed_field_expr`]: a required
+    /// field that is `None` returns a `BytesInvalid` error with `message`.
+    fn narrowed_owned_field_expr(
+        field: &ClassFieldDef,
+        stable_field: &ClassFieldDef,
+        message: &str,
+        resolver: &TypeResolver<'_>,
+    ) -> Option<TokenStream> {
+        if field.ssz_with_module().is_some() || stable_field.ssz_with_module().is_some() {
+            return None;
+        }
+        let TypeResolutionKind::Optional(stable_inner) = &stable_field.ty.resolution else {
+            return None;
+        };
+        let field_name = Ident::new(&field.name, Span::call_site());
+        let required = quote! {
+            match value.#field_name {
+                ssz_types::Optional::Some(inner) => inner,
+                ssz_types::Optional::None => {
+                    return Err(ssz::DecodeError::BytesInvalid(#message.to_string()));
+                }
+            }
+        };
+
+        match &field.ty.resolution {
+            TypeResolutionKind::Optional(inner) if inner.resolution == stable_inner.resolution => {
+                Some(quote! { value.#field_name })
+            }
+            TypeResolutionKind::Optional(inner)
+                if Self::is_profile_of(inner, stable_inner, resolver) =>
+            {
+                let profile_ty = inner.unwrap_type();
+                Some(quote! {
+                    match value.#field_name {
+                        ssz_types::Optional::Some(inner) => {
+                            ssz_types::Optional::Some(<#profile_ty>::try_from(inner)?)
+                        }
+                        ssz_types::Optional::None => ssz_types::Optional::None,
+                    }
+                })
+            }
+            TypeResolutionKind::Optional(_) => None,
+            resolution if *resolution == stable_inner.resolution => Some(required),
+            _ if Self::is_profile_of(&field.ty, stable_inner, resolver) => {
+                let profile_ty = field.ty.unwrap_type();
+                Some(quote! { <#profile_ty>::try_from(#required)? })
+            }
+            _ => None,
+        }
+    }
+
+    /// Expression converting `stable_field` of a StableContainer view named
+    /// `value` into the owned value of the profile's `field`.
+    ///
+    /// Required fields have already been checked against the bitvector, so
+    /// their `Optional` is unwrapped. Returns `None` for field type changes
+    /// the conversion doesn't cover.
+    fn narrowed_field_expr(
+        field: &ClassFieldDef,
+        stable_field: &ClassFieldDef,
+        resolver: &TypeResolver<'_>,
+    ) -> Option<TokenStream> {
+        if field.ssz_with_module().is_some() || stable_field.ssz_with_module().is_some() {
+            return None;
+        }
+        let TypeResolutionKind::Optional(stable_inner) = &stable_field.ty.resolution else {
+            return None;
+        };
+        let field_name = Ident::new(&field.name, Span::call_site());
+        let getter = quote! { value.#field_name()? };
+
+        // A nested StableContainer field narrowed to one of its profiles
+        // converts through that profile's own `TryFrom`.
+        let narrows_to_profile =
+            |ty: &TypeResolution| Self::is_profile_of(ty, stable_inner, resolver);
+
+        match &field.ty.resolution {
+            TypeResolutionKind::Optional(inner) if inner.resolution == stable_inner.resolution => {
+                Some(Self::view_field_to_owned_expr(field, true, getter))
+            }
+            TypeResolutionKind::Optional(inner) if narrows_to_profile(inner) => {
+                let profile_ty = inner.unwrap_type();
+                Some(quote! {
+                    match #getter {
+                        ssz_types::Optional::Some(inner) => {
+                            ssz_types::Optional::Some(<#profile_ty>::try_from(inner)?)
+                        }
+                        ssz_types::Optional::None => ssz_types::Optional::None,
+                    }
+                })
+            }
+            TypeResolutionKind::Optional(_) => None,
+            resolution if *resolution == stable_inner.resolution => Some(
+                Self::view_field_to_owned_expr(field, true, quote! { #getter.unwrap() }),
+            ),
+            _ if narrows_to_profile(&field.ty) => {
+                let profile_ty = field.ty.unwrap_type();
+                Some(quote! { <#profile_ty>::try_from(#getter.unwrap())? })
+            }
+            _ => None,
+        }
+    }
+
+    /// Generates `to_stable` on a Profile and its view, converting up to the
+    /// base StableContainer.
+    ///
+    /// Every profile field becomes the active StableContainer field at the
+    /// same index, and the fields the profile leaves out are `None`. This is
+    /// the inverse of the narrowing `TryFrom`, and the result has the same
+    /// tree hash root as the profile. Also generates the matching
+    /// `From<Profile>` for the StableContainer.
+    ///
+    /// Returns an empty stream under the same conditions as
+    /// [`Self::to_profile_narrowing_impl`].
+    ///
+    /// # Arguments
+    ///
+    /// * `ident` - The base identifier for the class
+    /// * `resolver` - The type resolver holding the StableContainer definition
+    ///
+    /// # Returns
+    ///
+    /// A [`TokenStream`] containing the `to_stable` methods, or an empty
+    /// stream if none are generated.
+    pub fn to_profile_widening_impl(
+        &self,
+        ident: &Ident,
+        resolver: &TypeResolver<'_>,
+    ) -> TokenStream {
+        let BaseClass::Profile(Some((stable_name, _))) = &self.base else {
+            return quote! {};
+        };
+        let Some(ClassDefinition::Custom(stable_def)) = resolver.classes.get(stable_name) else {
+            return quote! {};
+        };
+        if !matches!(stable_def.base, BaseClass::StableContainer(Some(_))) {
+            return quote! {};
+        }
+        let stable_ident = Ident::new(stable_name, Span::call_site());
+        let ref_ident = Ident::new(&format!("{ident}Ref"), Span::call_site());
+
+        let Some(field_conversions) = stable_def
+            .fields
+            .iter()
+            .map(|stable_field| {
+                let field_name = Ident::new(&stable_field.name, Span::call_site());
+                let value = match self.field_index.get(&stable_field.name) {
+                    Some(&idx) => {
+                        Self::widened_field_expr(&self.fields[idx], stable_field, resolver)?
+                    }
+                    None => quote! { ssz_types::Optional::None },
+                };
+                Some(quote! { #field_name: #value })
+            })
+            .collect::<Option<Vec<_>>>()
+        else {
+            return quote! {};
+        };
+
+        quote! {
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl #ident {
+                /// Converts this profile to its base StableContainer.
+                pub fn to_stable(&self) -> #stable_ident {
+                    #stable_ident {
+                        #(#field_conversions),*
+                    }
+                }
+            }
+
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> #ref_ident<'a> {
+                /// Converts this profile view to its owned base StableContainer.
+                pub fn to_stable(&self) -> #stable_ident {
+                    self.to_owned().to_stable()
+                }
+            }
+
+            impl From<#ident> for #stable_ident {
+                fn from(value: #ident) -> Self {
+                    value.to_stable()
+                }
+            }
+        }
+    }
+
+    /// Expression converting the profile's `field` on `self` into the owned
+    /// value of the StableContainer's `stable_field`.
+    ///
+    /// The counterpart of [`Self::narrowed_field_expr`]: required fields are
+    /// wrapped in `Optional::Some` and nested profiles convert through their
+    /// own `to_stable`.
+    fn widened_field_expr(
+        field: &ClassFieldDef,
+        stable_field: &ClassFieldDef,
+        resolver: &TypeResolver<'_>,
+    ) -> Option<TokenStream> {
+        if field.ssz_with_module().is_some() || stable_field.ssz_with_module().is_some() {
+            return None;
+        }
+        let TypeResolutionKind::Optional(stable_inner) = &stable_field.ty.resolution else {
+            return None;
+        };
+        let field_name = Ident::new(&field.name, Span::call_site());
+        // Primitives and byte vectors generate `Copy` types.
+        let copied = match &stable_inner.resolution {
+            TypeResolutionKind::Boolean | TypeResolutionKind::UInt(_) => true,
+            TypeResolutionKind::Bytes(_) => true,
+            TypeResolutionKind::Vector(inner, _) => {
+                matches!(inner.resolution, TypeResolutionKind::UInt(8))
+            }
+            _ => false,
+        };
+        let value = if copied {
+            quote! { self.#field_name }
+        } else {
+            quote! { self.#field_name.clone() }
+        };
+
+        let widens_from_profile =
+            |ty: &TypeResolution| Self::is_profile_of(ty, stable_inner, resolver);
+
+        match &field.ty.resolution {
+            TypeResolutionKind::Optional(inner) if inner.resolution == stable_inner.resolution => {
+                Some(value)
+            }
+            TypeResolutionKind::Optional(inner) if widens_from_profile(inner) => Some(quote! {
+                match &self.#field_name {
+                    ssz_types::Optional::Some(inner) => ssz_types::Optional::Some(inner.to_stable()),
+                    ssz_types::Optional::None => ssz_types::Optional::None,
+                }
+            }),
+            TypeResolutionKind::Optional(_) => None,
+            resolution if *resolution == stable_inner.resolution => {
+                Some(quote! { ssz_types::Optional::Some(#value) })
+            }
+            _ if widens_from_profile(&field.ty) => {
+                Some(quote! { ssz_types::Optional::Some(self.#field_name.to_stable()) })
+            }
+            _ => None,
+        }
+    }
+
+    /// Generates the `Display` and [`std::error::Error`] implementations for
+    /// classes marked with the `error_type` pragma.
+    ///
+    /// The `Display` output is the field marked with the `error_message`
+    /// pragma. Byte lists and vectors are shown as (lossy) UTF-8 text; any
+    /// other field type must implement `Display` itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `ident` - The base identifier for the class
+    ///
+    /// # Returns
+    ///
+    /// A [`TokenStream`] containing the implementations, or an empty stream if
+    /// the class is not an `error_type`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an `error_type` class does not have exactly one
+    /// `error_message` field.
+    pub fn to_error_impl(&self, ident: &Ident) -> TokenStream {
+        if !ParsedPragma::parse(&self.pragmas).error_type {
+            return quote! {};
+        }
+
+        let mut message_fields = self
+            .fields
+            .iter()
+            .filter(|f| ParsedPragma::parse(&f.pragmas).error_message);
+        let (Some(field), None) = (message_fields.next(), message_fields.next()) else {
+            // `check_class_pragmas` rejects these before code generation.
+            panic!("error_type class `{ident}` must have exactly one error_message field");
+        };
+        let field_name = Ident::new(&field.name, Span::call_site());
+
+        let write_message = match &field.ty.resolution {
+            TypeResolutionKind::List(inner, _)
+                if matches!(inner.resolution, TypeResolutionKind::UInt(8)) =>
+            {
+                quote! { f.write_str(&String::from_utf8_lossy(&self.#field_name)) }
+            }
+            TypeResolutionKind::Vector(inner, _)
+                if matches!(inner.resolution, TypeResolutionKind::UInt(8)) =>
+            {
+                quote! { f.write_str(&String::from_utf8_lossy(self.#field_name.as_ref())) }
+            }
+            TypeResolutionKind::Bytes(_) => {
+                quote! { f.write_str(&String::from_utf8_lossy(self.#field_name.as_ref())) }
+            }
+            _ => quote! { std::fmt::Display::fmt(&self.#field_name, f) },
+        };
+
+        quote! {
+            impl std::fmt::Display for #ident {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    #write_message
+                }
+            }
+
+            impl std::error::Error for #ident {}
+        }
+    }
+
+    /// Generates [`ssz::TryFromIter`] and [`Extend`] implementations for
+    /// classes marked with the `collection` pragma, forwarding to their single
+    /// list field.
+    ///
+    /// Collecting fails with the list's error once its limit is exceeded,
+    /// while extending past the limit panics, as `Extend` cannot fail.
+    ///
+    /// # Arguments
+    ///
+    /// * `ident` - The base identifier for the class
+    ///
+    /// # Returns
+    ///
+    /// A [`TokenStream`] containing the implementations, or an empty stream if
+    /// the class is not a `collection`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a `collection` class is not a container with exactly one
+    /// `List` field.
+    pub fn to_collection_impl(&self, ident: &Ident) -> TokenStream {
+        if !ParsedPragma::parse(&self.pragmas).collection {
+            return quote! {};
+        }
+
+        let element_ty = match (&self.base, self.fields.as_slice()) {
+            (BaseClass::Container, [field]) => match &field.ty.resolution {
+                TypeResolutionKind::List(inner, _) => Some(inner.unwrap_type()),
+                _ => None,
+            },
+            _ => None,
+        };
+        let Some(element_ty) = element_ty else {
+            panic!("collection class `{ident}` must be a container with exactly one List field");
+        };
+        let field_name = Ident::new(&self.fields[0].name, Span::call_site());
+        let overflow = format!("`{ident}` exceeds its list limit");
+
+        quote! {
+            impl ssz::TryFromIter<#element_ty> for #ident {
+                type Error = ssz_types::Error;
+
+                fn try_from_iter<I>(iter: I) -> Result<Self, Self::Error>
+                where
+                    I: IntoIterator<Item = #element_ty>,
+                {
+                    Ok(Self {
+                        #field_name: ssz::TryFromIter::try_from_iter(iter)?,
+                    })
+                }
+            }
+
+            impl Extend<#element_ty> for #ident {
+                fn extend<I: IntoIterator<Item = #element_ty>>(&mut self, iter: I) {
+                    for item in iter {
+                        self.#field_name.push(item).expect(#overflow);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Generates [`AsRef`], [`Borrow`](std::borrow::Borrow) and
+    /// [`Deref`](std::ops::Deref) implementations for classes marked with the
+    /// `newtype` pragma, exposing their single field.
+    ///
+    /// # Arguments
+    ///
+    /// * `ident` - The base identifier for the class
+    ///
+    /// # Returns
+    ///
+    /// A [`TokenStream`] containing the implementations, or an empty stream if
+    /// the class is not a `newtype`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a `newtype` class is not a container with exactly one field.
+    pub fn to_newtype_impl(&self, ident: &Ident) -> TokenStream {
+        if !ParsedPragma::parse(&self.pragmas).newtype {
+            return quote! {};
+        }
+
+        let field = match (&self.base, self.fields.as_slice()) {
+            (BaseClass::Container, [field]) => field,
+            _ => panic!("newtype class `{ident}` must be a container with exactly one field"),
+        };
+        let field_name = Ident::new(&field.name, Span::call_site());
+        let inner_ty = field.ty.unwrap_type();
+
+        quote! {
+            impl AsRef<#inner_ty> for #ident {
+                fn as_ref(&self) -> &#inner_ty {
+                    &self.#field_name
+                }
+            }
+
+            impl std::borrow::Borrow<#inner_ty> for #ident {
+                fn borrow(&self) -> &#inner_ty {
+                    &self.#field_name
+                }
+            }
+
+            impl std::ops::Deref for #ident {
+                type Target = #inner_ty;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.#field_name
+                }
+            }
+        }
+    }
+
+    /// Generates [`Add`](std::ops::Add), [`Sub`](std::ops::Sub), their
+    /// assigning forms, [`PartialOrd`] and [`Ord`] for classes marked with the
+    /// `unit` pragma. Every impl takes `Self` on both sides, so two different
+    /// units never mix.
+    ///
+    /// # Arguments
+    ///
+    /// * `ident` - The base identifier for the class
+    ///
+    /// # Returns
+    ///
+    /// A [`TokenStream`] containing the implementations, or an empty stream if
+    /// the class is not a `unit`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a `unit` class is not a container with exactly one field of a
+    /// primitive unsigned integer type.
+    pub fn to_unit_impl(&self, ident: &Ident) -> TokenStream {
+        if !ParsedPragma::parse(&self.pragmas).unit {
+            return quote! {};
+        }
+
+        let field = match (&self.base, self.fields.as_slice()) {
+            (BaseClass::Container, [field])
+                if matches!(
+                    field.ty.resolution,
+                    TypeResolutionKind::UInt(8 | 16 | 32 | 64 | 128 | 256)
+                ) =>
+            {
+                field
+            }
+            _ => panic!(
+                "unit class `{ident}` must be a container with exactly one uint8, uint16, \
+                 uint32, uint64, uint128 or uint256 field"
+            ),
+        };
+        let field_name = Ident::new(&field.name, Span::call_site());
+
+        quote! {
+            impl std::ops::Add for #ident {
+                type Output = Self;
+
+                fn add(self, rhs: Self) -> Self {
+                    Self {
+                        #field_name: self.#field_name + rhs.#field_name,
+                    }
+                }
+            }
+
+            impl std::ops::Sub for #ident {
+                type Output = Self;
+
+                fn sub(self, rhs: Self) -> Self {
+                    Self {
+                        #field_name: self.#field_name - rhs.#field_name,
+                    }
+                }
+            }
+
+            impl std::ops::AddAssign for #ident {
+                fn add_assign(&mut self, rhs: Self) {
+                    self.#field_name += rhs.#field_name;
+                }
+            }
+
+            impl std::ops::SubAssign for #ident {
+                fn sub_assign(&mut self, rhs: Self) {
+                    self.#field_name -= rhs.#field_name;
+                }
+            }
+
+            impl PartialOrd for #ident {
+                fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                    Some(self.cmp(other))
+                }
+            }
+
+            impl Ord for #ident {
+                fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                    self.#field_name.cmp(&other.#field_name)
+                }
             }
         }
     }
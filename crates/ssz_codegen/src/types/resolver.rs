@@ -9,13 +9,13 @@
 
 use std::{
     cell::RefCell,
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     path::{Path, PathBuf},
     rc::Rc,
 };
 
 use proc_macro2::{Span, TokenStream};
-use quote::quote;
+use quote::{ToTokens, quote};
 use sizzle_parser::{
     Identifier,
     tysys::{Ty, TyExpr},
@@ -23,7 +23,7 @@ use sizzle_parser::{
 use syn::{AngleBracketedGenericArguments, GenericArgument, Ident, PathArguments, parse_quote};
 
 use super::{BaseClass, ClassDef, ClassDefinition, SizeExpr, TypeDefinition, TypeResolution};
-use crate::types::TypeResolutionKind;
+use crate::{derive_config::DocCommentStyle, pragma::ParsedPragma, types::TypeResolutionKind};
 
 /// Extract a simple type name from a TypeResolution for use as a variant name.
 /// Returns None if the type doesn't have a simple extractable name (e.g., for None or complex
@@ -42,6 +42,38 @@ fn extract_variant_name(ty_resolution: &TypeResolution) -> Option<String> {
     }
 }
 
+/// Suffixes every variant name that occurs more than once with its selector,
+/// so that `Union[Alias, Alias]` yields `Alias0` and `Alias1` rather than two
+/// `Alias` variants.
+///
+/// A suffixed name that is already taken, by another member or an earlier
+/// suffixed name, gets the next free number instead, so `Union[A1, A, A]`
+/// yields `A1`, `A2` and `A3`.
+fn disambiguate_variant_names(names: Vec<String>) -> Vec<String> {
+    let is_duplicate = |name: &String| names.iter().filter(|other| *other == name).count() > 1;
+    let mut taken: HashSet<String> = names
+        .iter()
+        .filter(|name| !is_duplicate(name))
+        .cloned()
+        .collect();
+    names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            if !is_duplicate(name) {
+                return name.clone();
+            }
+            let mut suffix = i;
+            while taken.contains(&format!("{name}{suffix}")) {
+                suffix += 1;
+            }
+            let variant = format!("{name}{suffix}");
+            taken.insert(variant.clone());
+            variant
+        })
+        .collect()
+}
+
 /// Extract a variant name from a TyExpr (original type expression from schema).
 /// This preserves type alias names instead of resolving to underlying types.
 fn extract_variant_name_from_ty_expr(ty_expr: &TyExpr) -> Option<String> {
@@ -106,6 +138,21 @@ pub struct TypeResolver<'a> {
     pub base_classes: HashMap<String, BaseClass>,
     /// Tracker for generated union type definitions
     pub union_tracker: Rc<RefCell<HashMap<String, TokenStream>>>,
+    /// Whether other generated modules are siblings of this one, referenced as
+    /// `super::module` instead of by their full `crate::` path
+    pub sibling_modules: bool,
+    /// Whether generated union enums derive `arbitrary::Arbitrary`
+    pub arbitrary_unions: bool,
+    /// Whether generated containers implement `Default`, so union selector
+    /// examples may build container members from it
+    pub default_classes: bool,
+    /// Style of the doc comments on generated union methods
+    pub doc_comment_style: DocCommentStyle,
+    /// Classes of this module whose owned type implements `Hash`
+    pub hash_classes: HashSet<String>,
+    /// Classes of this module gated behind a `feature` pragma, mapped to the
+    /// feature name
+    pub feature_gates: HashMap<String, String>,
 }
 
 impl<'a> TypeResolver<'a> {
@@ -121,6 +168,12 @@ impl<'a> TypeResolver<'a> {
             classes: HashMap::new(),
             base_classes: HashMap::new(),
             union_tracker: Rc::new(RefCell::new(HashMap::new())),
+            sibling_modules: false,
+            arbitrary_unions: false,
+            default_classes: false,
+            doc_comment_style: DocCommentStyle::Line,
+            hash_classes: HashSet::new(),
+            feature_gates: HashMap::new(),
         }
     }
 
@@ -148,13 +201,19 @@ impl<'a> TypeResolver<'a> {
             classes: HashMap::new(),
             base_classes: HashMap::new(),
             union_tracker: Rc::new(RefCell::new(HashMap::new())),
+            sibling_modules: false,
+            arbitrary_unions: false,
+            default_classes: false,
+            doc_comment_style: DocCommentStyle::Line,
+            hash_classes: HashSet::new(),
+            feature_gates: HashMap::new(),
         };
 
         // Built-in types
         resolver
             .types
             .insert("boolean".to_string(), TypeDefinition::Boolean);
-        for i in [8, 16, 32, 64, 128, 256] {
+        for i in [8, 16, 24, 32, 40, 48, 56, 64, 128, 256] {
             let uint_name = format!("uint{i}");
             resolver.types.insert(uint_name, TypeDefinition::UInt(i));
         }
@@ -463,18 +522,24 @@ impl<'a> TypeResolver<'a> {
                     let ident = alias_ident.unwrap().clone();
                     let ident_str = ident.to_string();
 
-                    // Generate the enum variants using type names when available, falling back to
+                    // Name the enum variants after their types when available, falling back to
                     // Selector{i}
+                    let variant_names = disambiguate_variant_names(
+                        args.iter()
+                            .enumerate()
+                            .map(|(i, ty)| {
+                                extract_variant_name(ty).unwrap_or_else(|| format!("Selector{i}"))
+                            })
+                            .collect(),
+                    );
+
                     let variants: Vec<syn::Variant> = args
                         .iter()
+                        .zip(&variant_names)
                         .enumerate()
-                        .map(|(i, ty)| {
-                            // Try to extract a meaningful name from the type, otherwise use
-                            // Selector{i}
-                            let variant_name =
-                                extract_variant_name(ty).unwrap_or_else(|| format!("Selector{i}"));
+                        .map(|(i, (ty, variant_name))| {
                             let ident =
-                                syn::Ident::new(&variant_name, proc_macro2::Span::call_site());
+                                syn::Ident::new(variant_name, proc_macro2::Span::call_site());
                             match ty.resolution {
                                 TypeResolutionKind::None => {
                                     if i == 0 {
@@ -496,12 +561,11 @@ impl<'a> TypeResolver<'a> {
                     // Generate TreeHash match arms for owned union enum
                     let owned_tree_hash_arms: Vec<TokenStream> = args
                         .iter()
+                        .zip(&variant_names)
                         .enumerate()
-                        .map(|(i, ty)| {
+                        .map(|(i, (ty, variant_name))| {
                             let selector_value = i as u8;
-                            let variant_name = extract_variant_name(ty)
-                                .unwrap_or_else(|| format!("Selector{i}"));
-                            let variant_ident = Ident::new(&variant_name, Span::call_site());
+                            let variant_ident = Ident::new(variant_name, Span::call_site());
 
                             match ty.resolution {
                                 TypeResolutionKind::None => {
@@ -531,11 +595,25 @@ impl<'a> TypeResolver<'a> {
                         })
                         .collect();
 
+                    let arbitrary_derive = self
+                        .arbitrary_unions
+                        .then(|| quote! { , arbitrary::Arbitrary });
+                    let hash_derive = args
+                        .iter()
+                        .all(|ty| ty.is_hashable(self))
+                        .then(|| quote! { , Hash });
+
+                    let display_impl =
+                        self.generate_union_display_impl(&ident, &args, &variant_names);
+                    let examples_impl =
+                        self.generate_union_examples_impl(&ident, &args, &variant_names);
+                    let from_impls = self.generate_union_from_impls(&ident, &args, &variant_names);
+
                     // Generate owned union enum with manual generic TreeHash impl
                     self.union_tracker.borrow_mut().insert(
                         ident_str.clone(),
                         quote! {
-                            #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+                            #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode #hash_derive #arbitrary_derive)]
                             #[ssz(enum_behaviour="union")]
                             pub enum #ident {
                             #(#variants),*
@@ -560,6 +638,12 @@ impl<'a> TypeResolver<'a> {
                                     }
                                 }
                             }
+
+                            #display_impl
+
+                            #from_impls
+
+                            #examples_impl
                         },
                     );
 
@@ -568,6 +652,21 @@ impl<'a> TypeResolver<'a> {
                     let mut view_type_aliases: Vec<TokenStream> = Vec::new();
                     let mut variant_view_types: Vec<(String, TokenStream)> = Vec::new();
 
+                    // View variants are named after the schema expression, which keeps alias
+                    // names that resolution would replace
+                    let view_variant_names = disambiguate_variant_names(
+                        args.iter()
+                            .enumerate()
+                            .map(|(i, ty)| {
+                                original_args
+                                    .get(i)
+                                    .and_then(extract_variant_name_from_ty_expr)
+                                    .or_else(|| extract_variant_name(ty))
+                                    .unwrap_or_else(|| format!("Selector{i}"))
+                            })
+                            .collect(),
+                    );
+
                     for (i, ty) in args.iter().enumerate() {
                         if self.handle_none_variant(ty, &mut variant_view_types) {
                             continue;
@@ -595,17 +694,10 @@ impl<'a> TypeResolver<'a> {
                             underlying_view_ty
                         };
 
-                        let variant_name_from_resolved = extract_variant_name(ty);
-                        let variant_name = variant_name_from_expr
-                            .as_ref()
-                            .or(variant_name_from_resolved.as_ref())
-                            .cloned()
-                            .unwrap_or_else(|| format!("Selector{i}"));
-
                         self.add_variant_view_type(
                             &mut view_type_aliases,
                             &mut variant_view_types,
-                            &variant_name,
+                            &view_variant_names[i],
                             needs_alias,
                             quote! { #underlying_view_ty },
                         );
@@ -615,33 +707,36 @@ impl<'a> TypeResolver<'a> {
                         &ident_str,
                         &args,
                         &variant_view_types,
+                        &[],
                     );
 
-                    let variant_names: Vec<String> = args
-                        .iter()
-                        .enumerate()
-                        .map(|(i, ty)| {
-                            extract_variant_name(ty).unwrap_or_else(|| format!("Selector{i}"))
-                        })
-                        .collect();
                     let to_owned_arms =
                         self.generate_union_to_owned_arms(&ident, &args, &variant_names);
 
-                    let tree_hash_arms = self.generate_union_tree_hash_arms(&args);
-
                     let view_union_code = self.generate_union_view_struct_impl(
                         ref_ident,
                         &ident,
+                        &args,
                         view_type_aliases,
                         selector_methods,
                         to_owned_arms,
-                        tree_hash_arms,
                     );
 
                     self.union_tracker
                         .borrow_mut()
                         .insert(format!("{}Ref", ident_str), view_union_code);
 
+                    // An anonymous union over a gated class is only usable
+                    // where that class is, so it shares the gate.
+                    if let Some((_, feature)) = args.iter().find_map(|ty| ty.feature_gate(self)) {
+                        let mut tracker = self.union_tracker.borrow_mut();
+                        for key in [ident_str.clone(), format!("{}Ref", ident_str)] {
+                            if let Some(code) = tracker.remove(&key) {
+                                tracker.insert(key, ParsedPragma::cfg_gate(code, &feature));
+                            }
+                        }
+                    }
+
                     TypeResolutionKind::Union(ident_str, args)
                 }
             }
@@ -880,18 +975,47 @@ impl<'a> TypeResolver<'a> {
         }
 
         // Add the class to the types map so it can be referenced by other types
-        self.types.entry(class_str.clone()).or_insert_with(|| {
-            TypeDefinition::CustomType(Box::new(TypeResolution {
-                ty: Some(syn::Type::Path(syn::TypePath {
-                    qself: None,
-                    path: syn::Path::from(syn::Ident::new(
-                        &class_str,
-                        proc_macro2::Span::call_site(),
-                    )),
-                })),
-                resolution: TypeResolutionKind::Class(class_str),
-            }))
-        });
+        self.types
+            .entry(class_str.clone())
+            .or_insert_with(|| Self::class_type_definition(class_str));
+    }
+
+    /// Makes a class resolvable as a type before its definition is complete,
+    /// so that its own fields can refer back to it through a `List`.
+    ///
+    /// # Arguments
+    ///
+    /// * `class_ident` - The identifier for the class
+    ///
+    /// # Returns
+    ///
+    /// `false` if the name already resolved to a type, in which case nothing
+    /// changes.
+    pub fn declare_class_type(&mut self, class_ident: &syn::Ident) -> bool {
+        let class_str = class_ident.to_string();
+        if self.types.contains_key(&class_str) {
+            return false;
+        }
+        self.types
+            .insert(class_str.clone(), Self::class_type_definition(class_str));
+        true
+    }
+
+    /// Reverts [`TypeResolver::declare_class_type`] when the class couldn't
+    /// be processed yet.
+    pub fn undeclare_class_type(&mut self, class_ident: &syn::Ident) {
+        self.types.remove(&class_ident.to_string());
+    }
+
+    /// The type definition referring to a generated class by name.
+    fn class_type_definition(class_str: String) -> TypeDefinition {
+        TypeDefinition::CustomType(Box::new(TypeResolution {
+            ty: Some(syn::Type::Path(syn::TypePath {
+                qself: None,
+                path: syn::Path::from(syn::Ident::new(&class_str, proc_macro2::Span::call_site())),
+            })),
+            resolution: TypeResolutionKind::Class(class_str),
+        }))
     }
 
     /// Adds a constant to the resolver's registry
@@ -958,21 +1082,7 @@ impl<'a> TypeResolver<'a> {
 
         // Create a path with the crate prefix for internal imports
         // crate::folder1::folder2::name
-        let path = path.clone();
-        let path_str = path.to_str().unwrap();
-        let mut path_segments = syn::punctuated::Punctuated::new();
-        path_segments.push(syn::PathSegment {
-            ident: syn::Ident::new("crate", proc_macro2::Span::call_site()),
-            arguments: syn::PathArguments::None,
-        });
-        path_segments.extend(
-            path_str
-                .split(std::path::MAIN_SEPARATOR)
-                .map(|s| syn::PathSegment {
-                    ident: syn::Ident::new(s, proc_macro2::Span::call_site()),
-                    arguments: syn::PathArguments::None,
-                }),
-        );
+        let mut path_segments = self.generated_module_segments(path);
         path_segments.push(syn::PathSegment {
             ident: syn::Ident::new(&name.0, proc_macro2::Span::call_site()),
             arguments: syn::PathArguments::None,
@@ -1093,6 +1203,35 @@ impl<'a> TypeResolver<'a> {
         }
     }
 
+    /// Builds the path to the generated module for a schema module path.
+    ///
+    /// This is `crate::folder1::folder2::module`, or `super::module` when generated modules are
+    /// siblings.
+    fn generated_module_segments(
+        &self,
+        path: &Path,
+    ) -> syn::punctuated::Punctuated<syn::PathSegment, syn::Token![::]> {
+        let segment = |s: &str| syn::PathSegment {
+            ident: syn::Ident::new(s, proc_macro2::Span::call_site()),
+            arguments: syn::PathArguments::None,
+        };
+
+        let mut path_segments = syn::punctuated::Punctuated::new();
+        if self.sibling_modules {
+            path_segments.push(segment("super"));
+            path_segments.push(segment(&path.file_stem().unwrap().to_string_lossy()));
+        } else {
+            path_segments.push(segment("crate"));
+            path_segments.extend(
+                path.to_str()
+                    .unwrap()
+                    .split(std::path::MAIN_SEPARATOR)
+                    .map(segment),
+            );
+        }
+        path_segments
+    }
+
     fn build_imported_type_path(
         &self,
         path: &Path,
@@ -1100,22 +1239,18 @@ impl<'a> TypeResolver<'a> {
         generic_args: Option<Vec<GenericArgument>>,
         include_crate: bool,
     ) -> syn::Type {
-        let mut path_segments = syn::punctuated::Punctuated::new();
-        if include_crate {
-            path_segments.push(syn::PathSegment {
-                ident: syn::Ident::new("crate", proc_macro2::Span::call_site()),
-                arguments: syn::PathArguments::None,
-            });
-        }
-        path_segments.extend(
+        let mut path_segments = if include_crate {
+            self.generated_module_segments(path)
+        } else {
             path.to_str()
                 .unwrap()
                 .split(std::path::MAIN_SEPARATOR)
                 .map(|s| syn::PathSegment {
                     ident: syn::Ident::new(s, proc_macro2::Span::call_site()),
                     arguments: syn::PathArguments::None,
-                }),
-        );
+                })
+                .collect()
+        };
         let arguments = match generic_args {
             Some(args) => self.generic_args_to_path_args(args),
             None => syn::PathArguments::None,
@@ -1161,6 +1296,7 @@ impl<'a> TypeResolver<'a> {
     /// * `args` - The resolved type arguments for each union variant
     /// * `variant_names` - The names of each variant (from field names in new syntax)
     /// * `variant_pragmas` - The pragmas for each variant
+    /// * `variant_docs` - The doc comments for each variant, if any
     ///
     /// # Returns
     ///
@@ -1172,6 +1308,7 @@ impl<'a> TypeResolver<'a> {
         args: &[TypeResolution],
         variant_names: &[String],
         variant_pragmas: &[Vec<String>],
+        variant_docs: &[Option<String>],
     ) -> TokenStream {
         let ref_ident = Ident::new(&format!("{}Ref", union_name), Span::call_site());
 
@@ -1227,20 +1364,22 @@ impl<'a> TypeResolver<'a> {
             );
         }
 
-        let selector_methods =
-            self.generate_union_selector_methods(union_name, args, &variant_view_types);
+        let selector_methods = self.generate_union_selector_methods(
+            union_name,
+            args,
+            &variant_view_types,
+            variant_docs,
+        );
 
         let to_owned_arms = self.generate_union_to_owned_arms(union_ident, args, variant_names);
 
-        let tree_hash_arms = self.generate_union_tree_hash_arms(args);
-
         self.generate_union_view_struct_impl(
             ref_ident,
             union_ident,
+            args,
             view_type_aliases,
             selector_methods,
             to_owned_arms,
-            tree_hash_arms,
         )
     }
 
@@ -1306,11 +1445,14 @@ impl<'a> TypeResolver<'a> {
         &self,
         ref_ident: Ident,
         union_ident: &Ident,
+        args: &[TypeResolution],
         view_type_aliases: Vec<TokenStream>,
         selector_methods: Vec<TokenStream>,
         to_owned_arms: Vec<TokenStream>,
-        tree_hash_arms: Vec<TokenStream>,
     ) -> TokenStream {
+        let tree_hash_arms = self.generate_union_tree_hash_arms(args);
+        let validate_deep_arms = self.generate_union_validate_deep_arms(args);
+
         quote! {
             #(#view_type_aliases)*
 
@@ -1332,6 +1474,16 @@ impl<'a> TypeResolver<'a> {
                         _ => panic!("Invalid union selector: {}", self.selector()),
                     }
                 }
+
+                /// Validates the selector and the selected member, recursing
+                /// into nested views and walking every list and vector, and
+                /// returns the first error.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    match self.selector() {
+                        #(#validate_deep_arms,)*
+                        selector => Err(ssz::DecodeError::UnionSelectorInvalid(selector)),
+                    }
+                }
             }
 
             impl<'a> ssz::view::DecodeView<'a> for #ref_ident<'a> {
@@ -1387,6 +1539,8 @@ impl<'a> TypeResolver<'a> {
     /// * `union_name` - The name of the union type
     /// * `args` - The resolved type arguments for the union variants
     /// * `variant_view_types` - The view types for each variant (name, type)
+    /// * `variant_docs` - The doc comments for each variant; missing entries leave the method
+    ///   undocumented
     ///
     /// # Returns
     ///
@@ -1396,6 +1550,7 @@ impl<'a> TypeResolver<'a> {
         union_name: &str,
         args: &[TypeResolution],
         variant_view_types: &[(String, TokenStream)],
+        variant_docs: &[Option<String>],
     ) -> Vec<TokenStream> {
         args.iter()
             .enumerate()
@@ -1403,30 +1558,67 @@ impl<'a> TypeResolver<'a> {
                 let method_name = Ident::new(&format!("as_selector{i}"), Span::call_site());
                 let selector_value = i as u8;
                 let error_msg = format!("Wrong selector for {}: expected {}", union_name, i);
+                let doc = variant_docs
+                    .get(i)
+                    .and_then(|doc| doc.as_deref())
+                    .map(|doc| ClassDef::format_doc_comment(doc, self.doc_comment_style))
+                    .unwrap_or_default();
 
                 match ty.resolution {
                     TypeResolutionKind::None => {
                         quote! {
+                            #doc
                             pub fn #method_name(&self) -> Result<(), ssz::DecodeError> {
                                 if self.selector() != #selector_value {
                                     return Err(ssz::DecodeError::BytesInvalid(
                                         #error_msg.to_string()
                                     ));
                                 }
-                                Ok(())
+                                ssz::check_union_body_len(&self.bytes[1..], 0)
+                            }
+                        }
+                    }
+                    // An `Option` member (from Union[null, T]) has no view
+                    // type of its own, so decode its selector here.
+                    TypeResolutionKind::Option(ref member_ty) => {
+                        let (_, view_ty) = variant_view_types.get(i).unwrap();
+                        let decode = ClassDef::option_selector_decode(
+                            &member_ty.unwrap_type(),
+                            &member_ty.to_view_type(),
+                            quote! { &self.bytes[1..] },
+                        );
+                        quote! {
+                            #doc
+                            pub fn #method_name(&self) -> Result<#view_ty, ssz::DecodeError> {
+                                if self.selector() != #selector_value {
+                                    return Err(ssz::DecodeError::BytesInvalid(
+                                        #error_msg.to_string()
+                                    ));
+                                }
+                                #decode
                             }
                         }
                     }
                     _ => {
                         let (_, view_ty) = variant_view_types.get(i).unwrap();
+                        let owned_ty = ty.unwrap_type();
                         quote! {
+                            #doc
                             pub fn #method_name(&self) -> Result<#view_ty, ssz::DecodeError> {
                                 if self.selector() != #selector_value {
                                     return Err(ssz::DecodeError::BytesInvalid(
                                         #error_msg.to_string()
                                     ));
                                 }
-                                ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                                let body = &self.bytes[1..];
+                                if <#owned_ty as ssz::Encode>::is_ssz_fixed_len() {
+                                    ssz::check_union_body_len(
+                                        body,
+                                        <#owned_ty as ssz::Encode>::ssz_fixed_len(),
+                                    )?;
+                                }
+                                ssz::view::DecodeView::from_ssz_bytes(body)
+                                    .map_err(|e| e.bounded(body.len()))
                             }
                         }
                     }
@@ -1480,6 +1672,44 @@ impl<'a> TypeResolver<'a> {
             .collect()
     }
 
+    /// Generates validate_deep match arms for union view types
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - The resolved type arguments for the union variants
+    ///
+    /// # Returns
+    ///
+    /// A vector of TokenStreams containing the validate_deep match arms
+    pub fn generate_union_validate_deep_arms(&self, args: &[TypeResolution]) -> Vec<TokenStream> {
+        args.iter()
+            .enumerate()
+            .map(|(i, ty)| {
+                let selector_value = i as u8;
+                let method_name = Ident::new(&format!("as_selector{i}"), Span::call_site());
+                let check = ty.view_validate_deep_stmt(quote! { value });
+
+                if ty.resolution == TypeResolutionKind::None {
+                    quote! {
+                        #selector_value => self.#method_name()
+                    }
+                } else if check.is_empty() {
+                    quote! {
+                        #selector_value => self.#method_name().map(|_| ())
+                    }
+                } else {
+                    quote! {
+                        #selector_value => {
+                            let value = self.#method_name()?;
+                            #check
+                            Ok(())
+                        }
+                    }
+                }
+            })
+            .collect()
+    }
+
     /// Generates to_owned match arms for union view types
     ///
     /// # Arguments
@@ -1538,4 +1768,167 @@ impl<'a> TypeResolver<'a> {
             })
             .collect()
     }
+
+    /// Generates `Display` for an owned union enum.
+    ///
+    /// Prints the union and member name followed by the `Debug` form of the
+    /// payload, e.g. `Shape::Circle(Circle { radius: 3 })`.
+    ///
+    /// # Arguments
+    ///
+    /// * `union_ident` - The identifier for the union type
+    /// * `args` - The resolved type arguments for the union variants
+    /// * `variant_names` - The names of each variant
+    ///
+    /// # Returns
+    ///
+    /// A TokenStream containing the `Display` implementation
+    pub fn generate_union_display_impl(
+        &self,
+        union_ident: &Ident,
+        args: &[TypeResolution],
+        variant_names: &[String],
+    ) -> TokenStream {
+        let arms = args.iter().zip(variant_names).map(|(ty, variant_name)| {
+            let variant_ident = Ident::new(variant_name, Span::call_site());
+            let label = format!("{union_ident}::{variant_name}");
+
+            match ty.resolution {
+                TypeResolutionKind::None => quote! {
+                    #union_ident::#variant_ident => f.write_str(#label)
+                },
+                _ => {
+                    let format = format!("{label}({{inner:?}})");
+                    quote! {
+                        #union_ident::#variant_ident(inner) => write!(f, #format)
+                    }
+                }
+            }
+        });
+
+        quote! {
+            impl std::fmt::Display for #union_ident {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    match self {
+                        #(#arms,)*
+                    }
+                }
+            }
+        }
+    }
+
+    /// Generates `From` impls building an owned union enum from each member's
+    /// payload.
+    ///
+    /// Members whose type matches another member's, by resolution or by
+    /// generated Rust type, are skipped: the conversion would be ambiguous and
+    /// the impls would overlap.
+    ///
+    /// # Arguments
+    ///
+    /// * `union_ident` - The identifier for the union type
+    /// * `args` - The resolved type arguments for the union variants
+    /// * `variant_names` - The names of each variant
+    ///
+    /// # Returns
+    ///
+    /// A TokenStream containing one `From` impl per uniquely-typed member
+    pub fn generate_union_from_impls(
+        &self,
+        union_ident: &Ident,
+        args: &[TypeResolution],
+        variant_names: &[String],
+    ) -> TokenStream {
+        let members: Vec<(&TypeResolution, syn::Type, &String)> = args
+            .iter()
+            .zip(variant_names)
+            .filter(|(ty, _)| !matches!(ty.resolution, TypeResolutionKind::None))
+            .map(|(ty, variant_name)| (ty, ty.unwrap_type(), variant_name))
+            .collect();
+
+        let impls = members
+            .iter()
+            .enumerate()
+            .filter_map(|(i, (ty, rust_ty, variant_name))| {
+                let rust_ty_str = rust_ty.to_token_stream().to_string();
+                let ambiguous = members.iter().enumerate().any(|(j, (other, other_ty, _))| {
+                    i != j
+                        && (*other == *ty || other_ty.to_token_stream().to_string() == rust_ty_str)
+                });
+                if ambiguous {
+                    return None;
+                }
+                let variant_ident = Ident::new(variant_name, Span::call_site());
+                Some(quote! {
+                    impl From<#rust_ty> for #union_ident {
+                        fn from(value: #rust_ty) -> Self {
+                            #union_ident::#variant_ident(value)
+                        }
+                    }
+                })
+            });
+
+        quote! { #(#impls)* }
+    }
+
+    /// Generates a test-only `all_selector_examples` for an owned union enum,
+    /// returning one value per selector with each member's default payload.
+    ///
+    /// Returns nothing when some member has no default: nested unions,
+    /// containers with union fields, or any container or external type
+    /// unless containers implement `Default`.
+    ///
+    /// # Arguments
+    ///
+    /// * `union_ident` - The identifier for the union type
+    /// * `args` - The resolved type arguments for the union variants
+    /// * `variant_names` - The names of each variant
+    ///
+    /// # Returns
+    ///
+    /// A TokenStream containing the `#[cfg(test)]` impl, or an empty one
+    pub fn generate_union_examples_impl(
+        &self,
+        union_ident: &Ident,
+        args: &[TypeResolution],
+        variant_names: &[String],
+    ) -> TokenStream {
+        let mut examples = Vec::with_capacity(args.len());
+        for (ty, variant_name) in args.iter().zip(variant_names) {
+            let variant_ident = Ident::new(variant_name, Span::call_site());
+            if matches!(ty.resolution, TypeResolutionKind::None) {
+                examples.push(quote! { #union_ident::#variant_ident });
+                continue;
+            }
+            if !self.default_classes && Self::has_class_payload(ty) {
+                return quote! {};
+            }
+            let Some(value) = ClassDef::default_value_expr(ty, self) else {
+                return quote! {};
+            };
+            examples.push(quote! { #union_ident::#variant_ident(#value) });
+        }
+
+        quote! {
+            #[cfg(test)]
+            impl #union_ident {
+                /// Returns one value per selector, in selector order, each
+                /// holding its member's default. Lets tests check that code
+                /// matching on the union handles every member.
+                pub fn all_selector_examples() -> Vec<Self> {
+                    vec![#(#examples),*]
+                }
+            }
+        }
+    }
+
+    /// Whether building the default of `ty` needs a class's or external
+    /// type's `Default` impl.
+    fn has_class_payload(ty: &TypeResolution) -> bool {
+        match &ty.resolution {
+            TypeResolutionKind::Class(_) | TypeResolutionKind::External => true,
+            TypeResolutionKind::Vector(inner, _) => Self::has_class_payload(inner),
+            _ => false,
+        }
+    }
 }
@@ -0,0 +1,53 @@
+//! Aliases chained through several hops resolve to the underlying primitive in
+//! view getters, including inside lists and vectors.
+
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+include!("expected_output/test_alias_hops.rs");
+
+use ssz::{
+    DecodeError, Encode,
+    view::{DecodeView, ListRef},
+};
+use ssz_types::{FixedVector, Optional, VariableList, view::FixedVectorRef};
+use tests::input::test_alias_hops::{AliasHops, AliasHopsRef};
+
+#[test]
+fn three_hop_alias_view_round_trip() {
+    let value = AliasHops {
+        value: Optional::Some(0xbeef),
+        values: Optional::Some(VariableList::new(vec![1, 2, 3]).unwrap()),
+        aliased_list: Optional::None,
+        vector: Optional::Some(FixedVector::new(vec![4, 5]).unwrap()),
+    };
+    let bytes = value.as_ssz_bytes();
+    let view = AliasHopsRef::from_ssz_bytes(&bytes).expect("view");
+
+    // The annotations pin the getter types to the underlying `u16`.
+    let field: Result<Optional<u16>, DecodeError> = view.value();
+    assert_eq!(field.unwrap(), Optional::Some(0xbeef));
+
+    let values: Optional<ListRef<'_, u16, 8>> = view.values().unwrap();
+    let Optional::Some(values) = values else {
+        panic!("values should be present");
+    };
+    let values: Vec<u16> = values.iter().map(Result::unwrap).collect();
+    assert_eq!(values, [1, 2, 3]);
+
+    let aliased: Optional<ListRef<'_, u16, 8>> = view.aliased_list().unwrap();
+    assert!(matches!(aliased, Optional::None));
+
+    let vector: Optional<FixedVectorRef<'_, u16, 2>> = view.vector().unwrap();
+    let Optional::Some(vector) = vector else {
+        panic!("vector should be present");
+    };
+    assert_eq!(vector.get(1).unwrap(), 5);
+
+    assert_eq!(view.to_owned(), value);
+}
@@ -0,0 +1,78 @@
+//! Containers generated with `DeriveConfig::with_arbitrary(true)` implement
+//! `arbitrary::Arbitrary`, and every value they produce is valid SSZ.
+
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+include!("expected_output/test_arbitrary.rs");
+
+use arbitrary::{Arbitrary, Unstructured};
+use ssz::{Decode, Encode};
+use tests::input::test_arbitrary::{Ballot, Eta, FirstUnion, Vote};
+
+/// Deterministic filler bytes, so failures reproduce.
+fn seed_bytes(len: usize) -> Vec<u8> {
+    let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+    (0..len)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as u8
+        })
+        .collect()
+}
+
+#[test]
+fn arbitrary_eta_round_trips() {
+    let data = seed_bytes(1 << 16);
+    let mut u = Unstructured::new(&data);
+    let mut seen_selectors = [false; 2];
+
+    for _ in 0..256 {
+        let eta = Eta::arbitrary(&mut u).expect("enough input bytes");
+        assert!(eta.m.eee.len() <= 3);
+        match eta.n {
+            FirstUnion::Selector0(_) => seen_selectors[0] = true,
+            FirstUnion::Selector1(_) => seen_selectors[1] = true,
+        }
+
+        let bytes = eta.as_ssz_bytes();
+        assert_eq!(Eta::from_ssz_bytes(&bytes).expect("valid SSZ"), eta);
+    }
+
+    assert_eq!(seen_selectors, [true, true]);
+}
+
+#[test]
+fn arbitrary_ballot_stays_within_capacity() {
+    let data = seed_bytes(1 << 16);
+    let mut u = Unstructured::new(&data);
+    let mut seen_variants = [false; 3];
+
+    for _ in 0..256 {
+        let ballot = Ballot::arbitrary(&mut u).expect("enough input bytes");
+        assert!(ballot.voters.len() <= 42);
+        assert!(ballot.votes.len() <= 8);
+        for vote in ballot.votes.iter() {
+            match vote {
+                Vote::Yes(_) => seen_variants[0] = true,
+                Vote::No(_) => seen_variants[1] = true,
+                Vote::Abstain(reason) => {
+                    assert!(reason.len() <= 4);
+                    seen_variants[2] = true;
+                }
+            }
+        }
+
+        let bytes = ballot.as_ssz_bytes();
+        assert_eq!(Ballot::from_ssz_bytes(&bytes).expect("valid SSZ"), ballot);
+    }
+
+    assert_eq!(seen_variants, [true, true, true]);
+}
@@ -0,0 +1,42 @@
+//! SSZ booleans must be encoded as exactly `0` or `1`; any other byte is
+//! rejected by both the owned decoder and the view getter.
+
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+include!("expected_output/test_bool_field.rs");
+
+use ssz::{Decode, DecodeError, Encode, view::DecodeView};
+use tests::input::test_bool_field::{Flags, FlagsRef};
+
+#[test]
+fn bool_field_round_trips() {
+    for enabled in [false, true] {
+        let value = Flags { id: 7, enabled };
+        let bytes = value.as_ssz_bytes();
+        assert_eq!(bytes, [7, enabled as u8]);
+        assert_eq!(Flags::from_ssz_bytes(&bytes).expect("decode"), value);
+
+        let view = FlagsRef::from_ssz_bytes(&bytes).expect("view");
+        assert_eq!(view.enabled().expect("enabled"), enabled);
+    }
+}
+
+#[test]
+fn bool_field_rejects_out_of_range_byte() {
+    let bytes = [7, 2];
+
+    assert!(matches!(
+        Flags::from_ssz_bytes(&bytes),
+        Err(DecodeError::BytesInvalid(_))
+    ));
+
+    let view = FlagsRef::from_ssz_bytes(&bytes).expect("view");
+    assert_eq!(view.id().expect("id"), 7);
+    assert!(matches!(view.enabled(), Err(DecodeError::BytesInvalid(_))));
+}
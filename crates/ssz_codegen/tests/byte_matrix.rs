@@ -0,0 +1,99 @@
+//! `Vector[Vector[byte, M], N]` fields are fixed-size `M * N` byte matrices
+//! with `FixedVectorRef<FixedBytesRef<M>, N>` views, and lists of byte
+//! vectors view as `ListRef<FixedBytesRef<M>, N>`.
+
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+include!("expected_output/test_byte_matrix.rs");
+
+use ssz::{Decode, Encode, view::DecodeView};
+use ssz_types::{FixedBytes, FixedVector, VariableList};
+use tests::input::test_byte_matrix::{Grid, GridRef, Matrix, MatrixRef};
+use tree_hash::{Sha256Hasher, TreeHash};
+
+fn matrix() -> Matrix {
+    Matrix {
+        rows: FixedVector::new((0..4).map(|i| FixedBytes([i; 32])).collect()).unwrap(),
+        tail: 9,
+    }
+}
+
+#[test]
+fn matrix_layout() {
+    assert!(<Matrix as Encode>::is_ssz_fixed_len());
+    assert_eq!(<Matrix as Encode>::ssz_fixed_len(), 32 * 4 + 1);
+    assert_eq!(Matrix::FIELD_OFFSETS, &[0, 128]);
+    assert_eq!(Matrix::FIELD_SIZES, &[128, 1]);
+}
+
+#[test]
+fn matrix_round_trip() {
+    let value = matrix();
+    let bytes = value.as_ssz_bytes();
+    assert_eq!(bytes.len(), 129);
+    assert_eq!(&bytes[32..64], &[1; 32]);
+    assert_eq!(bytes[128], 9);
+    assert_eq!(Matrix::from_ssz_bytes(&bytes), Ok(value));
+}
+
+#[test]
+fn matrix_tree_hash() {
+    let value = matrix();
+    let rows_root = value.rows.tree_hash_root::<Sha256Hasher>();
+    let mut hasher = tree_hash::MerkleHasher::<Sha256Hasher>::with_leaves(4);
+    for row in value.rows.iter() {
+        hasher.write(&row.0).unwrap();
+    }
+    assert_eq!(rows_root, hasher.finish().unwrap());
+
+    let bytes = value.as_ssz_bytes();
+    let view = MatrixRef::from_ssz_bytes(&bytes).unwrap();
+    assert_eq!(
+        view.tree_hash_root::<Sha256Hasher>(),
+        value.tree_hash_root::<Sha256Hasher>()
+    );
+}
+
+#[test]
+fn matrix_view_iteration() {
+    let value = matrix();
+    let bytes = value.as_ssz_bytes();
+    let view = MatrixRef::from_ssz_bytes(&bytes).unwrap();
+    let rows = view.rows().unwrap();
+    assert_eq!(rows.len(), 4);
+    for (i, row) in rows.iter().enumerate() {
+        assert_eq!(row.unwrap().as_bytes(), &[i as u8; 32]);
+    }
+    assert_eq!(rows.get(3).unwrap().to_owned(), [3; 32]);
+    assert_eq!(view.tail(), Ok(9));
+    assert_eq!(view.to_owned(), value);
+}
+
+#[test]
+fn list_of_byte_vectors() {
+    let value = Grid {
+        cells: VariableList::new(vec![FixedBytes([1, 2, 3, 4]), FixedBytes([5, 6, 7, 8])]).unwrap(),
+        tag: 3,
+    };
+    let bytes = value.as_ssz_bytes();
+    assert_eq!(Grid::from_ssz_bytes(&bytes), Ok(value.clone()));
+
+    let view = GridRef::from_ssz_bytes(&bytes).unwrap();
+    let cells: Vec<[u8; 4]> = view
+        .cells()
+        .unwrap()
+        .iter()
+        .map(|cell| cell.unwrap().to_owned())
+        .collect();
+    assert_eq!(cells, vec![[1, 2, 3, 4], [5, 6, 7, 8]]);
+    assert_eq!(
+        view.tree_hash_root::<Sha256Hasher>(),
+        value.tree_hash_root::<Sha256Hasher>()
+    );
+}
@@ -0,0 +1,48 @@
+//! Generated containers implement `TreeHashLeaves`, so a `CachedTreeHash`
+//! over them only rehashes the fields marked as changed.
+
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+include!("expected_output/test_default_impl.rs");
+
+use tests::input::test_default_impl::{Alpha, Beta};
+use tree_hash::{CachedTreeHash, Sha256Hasher, TreeHash};
+
+#[test]
+fn cached_root_tracks_mutated_field() {
+    let mut cached = CachedTreeHash::<_, Sha256Hasher>::new(Alpha::default());
+    assert_eq!(
+        cached.root(),
+        Alpha::default().tree_hash_root::<Sha256Hasher>()
+    );
+
+    cached.value_mut().b = 0xdead_beef;
+    cached.update_field(1);
+    assert_eq!(
+        cached.root(),
+        cached.value().tree_hash_root::<Sha256Hasher>()
+    );
+    assert_ne!(
+        cached.root(),
+        Alpha::default().tree_hash_root::<Sha256Hasher>()
+    );
+}
+
+#[test]
+fn cached_root_tracks_nested_container() {
+    let mut cached = CachedTreeHash::<_, Sha256Hasher>::new(Beta::default());
+    cached.value_mut().alpha.d = true;
+    cached.value_mut().values.push(7).unwrap();
+    cached.update_field(0);
+    cached.update_field(3);
+    assert_eq!(
+        cached.root(),
+        cached.value().tree_hash_root::<Sha256Hasher>()
+    );
+}
@@ -0,0 +1,51 @@
+//! Classes marked `collection` can be collected from and extended with an
+//! iterator of their list's elements.
+
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+include!("expected_output/test_collection.rs");
+
+use ssz::{Decode, Encode, TryFromIter};
+use tests::input::test_collection::{Balances, Validator, ValidatorSet};
+
+fn validator(index: u64) -> Validator {
+    Validator {
+        index,
+        active: index.is_multiple_of(2),
+    }
+}
+
+#[test]
+fn collects_iterator_into_wrapper() {
+    let set = ValidatorSet::try_from_iter((0..3).map(validator)).unwrap();
+    assert_eq!(set.validators.len(), 3);
+    assert_eq!(set.validators[2], validator(2));
+
+    let decoded = ValidatorSet::from_ssz_bytes(&set.as_ssz_bytes()).unwrap();
+    assert_eq!(decoded, set);
+}
+
+#[test]
+fn collecting_past_limit_fails() {
+    assert!(ValidatorSet::try_from_iter((0..5).map(validator)).is_err());
+}
+
+#[test]
+fn extends_wrapper() {
+    let mut balances = Balances::try_from_iter([1, 2]).unwrap();
+    balances.extend([3, 4, 5]);
+    assert_eq!(&balances.values[..], &[1, 2, 3, 4, 5]);
+}
+
+#[test]
+#[should_panic(expected = "`Balances` exceeds its list limit")]
+fn extending_past_limit_panics() {
+    let mut balances = Balances::try_from_iter(0..8).unwrap();
+    balances.extend([8]);
+}
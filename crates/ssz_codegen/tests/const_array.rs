@@ -0,0 +1,42 @@
+//! Constant arrays are emitted as `u8` arrays when every element fits in a
+//! byte and as `u64` arrays otherwise, with each element folded to its value.
+
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+include!("expected_output/test_const_array.rs");
+
+use ssz::{Decode, Encode};
+use ssz_types::FixedBytes;
+use tests::input::test_const_array::{DOMAIN_COUNT, DOMAIN_TYPES, Domain, LIMITS};
+
+#[test]
+fn folds_byte_array() {
+    let domain_types: [u8; 4] = DOMAIN_TYPES;
+    assert_eq!(domain_types, [0x00, 0x01, 0x02, 0x7f]);
+    assert_eq!(u64::from(DOMAIN_TYPES[2]), DOMAIN_COUNT - 1);
+}
+
+#[test]
+fn widens_to_u64_array() {
+    let limits: [u64; 3] = LIMITS;
+    assert_eq!(limits, [1 << 10, DOMAIN_COUNT + 0x100, 6]);
+}
+
+#[test]
+fn usable_in_values() {
+    let domain = Domain {
+        tag: DOMAIN_TYPES[3],
+        data: FixedBytes::from(
+            <[u8; DOMAIN_COUNT as usize]>::try_from(&DOMAIN_TYPES[..3]).expect("three tags"),
+        ),
+    };
+    let bytes = domain.as_ssz_bytes();
+    assert_eq!(bytes, [0x7f, 0x00, 0x01, 0x02]);
+    assert_eq!(Domain::from_ssz_bytes(&bytes).expect("decode"), domain);
+}
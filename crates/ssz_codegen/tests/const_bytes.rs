@@ -0,0 +1,43 @@
+//! Byte string constants are emitted as `FixedBytes`, and `len()` of them
+//! folds to a `u64` constant usable as a type size.
+
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+include!("expected_output/test_const_bytes.rs");
+
+use ssz::{Decode, Encode};
+use ssz_types::{FixedBytes, VariableList};
+use tests::input::test_const_bytes::{DOMAIN_BEACON, DOMAIN_LEN, DOMAIN_NAME, Domain, NAME_LEN};
+
+#[test]
+fn folds_byte_strings() {
+    let beacon: FixedBytes<4> = DOMAIN_BEACON;
+    assert_eq!(beacon, FixedBytes([0x07, 0x00, 0x00, 0x00]));
+    assert_eq!(DOMAIN_NAME.0, *b"beacon");
+}
+
+#[test]
+fn len_folds_to_length() {
+    assert_eq!(DOMAIN_LEN, 4);
+    assert_eq!(NAME_LEN, DOMAIN_NAME.0.len() as u64);
+}
+
+#[test]
+fn len_sizes_fields() {
+    let domain = Domain {
+        domain: DOMAIN_BEACON,
+        names: VariableList::new(vec![1; NAME_LEN as usize]).unwrap(),
+    };
+    let bytes = domain.as_ssz_bytes();
+    assert_eq!(&bytes[..DOMAIN_LEN as usize], &DOMAIN_BEACON.0);
+    assert_eq!(Domain::from_ssz_bytes(&bytes), Ok(domain));
+
+    let too_long = VariableList::<u16, { NAME_LEN as usize }>::new(vec![1; 7]);
+    assert!(too_long.is_err());
+}
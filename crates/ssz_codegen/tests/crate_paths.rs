@@ -0,0 +1,52 @@
+//! Code generated with every SSZ crate path overridden builds against
+//! re-exports of those crates.
+
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs)]
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+/// Stands in for a crate re-exporting the SSZ crates.
+mod reexports {
+    #![allow(
+        clippy::single_component_path_imports,
+        reason = "the generated code reaches the crates through this module"
+    )]
+
+    pub(crate) use ssz;
+    pub(crate) use ssz_derive;
+    pub(crate) use ssz_primitives;
+    pub(crate) use ssz_types;
+    pub(crate) use tree_hash;
+    pub(crate) use tree_hash_derive;
+}
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+include!("expected_output/test_crate_paths.rs");
+
+use ssz::{Decode, Encode, view::DecodeView};
+use ssz_types::{FixedVector, Optional, VariableList};
+use tests::input::test_alias_hops::{AliasHops, AliasHopsRef};
+use tree_hash::{Sha256Hasher, TreeHash};
+
+#[test]
+fn overridden_paths_round_trip() {
+    let value = AliasHops {
+        value: Optional::Some(0xbeef),
+        values: Optional::Some(VariableList::new(vec![1, 2, 3]).unwrap()),
+        aliased_list: Optional::None,
+        vector: Optional::Some(FixedVector::new(vec![4, 5]).unwrap()),
+    };
+    let bytes = value.as_ssz_bytes();
+    assert_eq!(AliasHops::from_ssz_bytes(&bytes).unwrap(), value);
+
+    let view = AliasHopsRef::from_ssz_bytes(&bytes).unwrap();
+    assert_eq!(
+        view.tree_hash_root::<Sha256Hasher>(),
+        value.tree_hash_root::<Sha256Hasher>()
+    );
+}
@@ -0,0 +1,57 @@
+//! `ssz::decode_prefix` reads a fixed-size container from the start of a
+//! longer buffer and reports how many bytes it used.
+
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+include!("expected_output/test_vector_view_iter.rs");
+
+use ssz::{Decode, DecodeError, Encode, decode_prefix};
+use ssz_types::VariableList;
+use tests::input::test_vector_view_iter::{Delta, Entry};
+
+#[test]
+fn decodes_fixed_size_prefix() {
+    let delta = Delta { z: true, w: 7 };
+    let mut buf = delta.as_ssz_bytes();
+    buf.extend_from_slice(&[0xaa, 0xbb, 0xcc]);
+
+    assert!(Delta::from_ssz_bytes(&buf).is_err());
+    assert_eq!(decode_prefix::<Delta>(&buf), Ok((delta, 2)));
+}
+
+#[test]
+fn decodes_consecutive_values() {
+    let deltas = [Delta { z: true, w: 1 }, Delta { z: false, w: 2 }];
+    let mut buf = Vec::new();
+    for delta in &deltas {
+        delta.ssz_append(&mut buf);
+    }
+
+    let (first, used) = decode_prefix::<Delta>(&buf).unwrap();
+    let (second, rest) = decode_prefix::<Delta>(&buf[used..]).unwrap();
+    assert_eq!([first, second], deltas);
+    assert_eq!(used + rest, buf.len());
+}
+
+#[test]
+fn rejects_short_and_variable_size() {
+    assert_eq!(
+        decode_prefix::<Delta>(&[1]),
+        Err(DecodeError::Incomplete { needed: 1 })
+    );
+
+    let entry = Entry {
+        id: 1,
+        data: VariableList::try_from(vec![1, 2]).unwrap(),
+    };
+    assert!(matches!(
+        decode_prefix::<Entry>(&entry.as_ssz_bytes()),
+        Err(DecodeError::BytesInvalid(_))
+    ));
+}
@@ -0,0 +1,64 @@
+//! Containers generated with `DeriveConfig::with_default(true)` implement
+//! `Default` with zeroed, empty or absent fields.
+
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+include!("expected_output/test_default_impl.rs");
+
+use ssz::{Decode, Encode};
+use ssz_types::{FixedBytes, Optional, U256};
+use tests::input::test_default_impl::{Alpha, Beta, Choice, Gamma, GammaProfile};
+
+#[test]
+fn alpha_default_is_all_zero() {
+    let alpha = Alpha::default();
+    assert_eq!(alpha.a, 0);
+    assert_eq!(alpha.b, 0);
+    assert_eq!(alpha.c, FixedBytes([0; 48]));
+    assert!(!alpha.d);
+    assert!(alpha.as_ssz_bytes().iter().all(|&byte| byte == 0));
+}
+
+#[test]
+fn nested_defaults() {
+    let beta = Beta::default();
+    assert_eq!(beta.alpha, Alpha::default());
+    assert!(beta.roots.iter().all(|root| *root == FixedBytes::zero()));
+    assert!(beta.bits.is_empty());
+    assert!(beta.values.is_empty());
+    assert_eq!(beta.big, U256::ZERO);
+    assert_eq!(Beta::from_ssz_bytes(&beta.as_ssz_bytes()).unwrap(), beta);
+}
+
+#[test]
+fn optional_fields_default_to_none() {
+    let gamma = Gamma::default();
+    assert_eq!(gamma.g, Optional::None);
+    assert_eq!(gamma.h, Optional::None);
+
+    let profile = GammaProfile::default();
+    assert_eq!(profile.g, 0);
+    assert_eq!(profile.h, Optional::None);
+}
+
+#[test]
+fn union_examples_use_container_defaults() {
+    let examples = Choice::all_selector_examples();
+    assert_eq!(
+        examples,
+        [Choice::Selector0(0), Choice::Alpha(Alpha::default())]
+    );
+    for example in examples {
+        let bytes = example.as_ssz_bytes();
+        assert_eq!(
+            Choice::from_ssz_bytes(&bytes).expect("example decodes"),
+            example
+        );
+    }
+}
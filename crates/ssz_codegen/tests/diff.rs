@@ -0,0 +1,58 @@
+//! With `diff`, containers list the fields that differ between two values
+//! with both values `Debug`-formatted.
+
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+include!("expected_output/test_diff.rs");
+
+use ssz_types::{FixedVector, VariableList};
+use tests::input::test_diff::{Alpha, Beta};
+
+fn alpha() -> Alpha {
+    Alpha {
+        a: 7,
+        b: VariableList::new(vec![0xde, 0xad]).expect("within bound"),
+        c: FixedVector::new(vec![1, 2]).expect("exact length"),
+    }
+}
+
+#[test]
+fn equal_containers_have_no_diff() {
+    assert!(alpha().diff(&alpha()).is_empty());
+}
+
+#[test]
+fn reports_the_differing_field() {
+    let mut other = alpha();
+    other.a = 8;
+    assert_eq!(
+        alpha().diff(&other),
+        vec![("a", "7".to_string(), "8".to_string())]
+    );
+}
+
+#[test]
+fn reports_differing_fields_in_order() {
+    let beta = Beta {
+        alpha: alpha(),
+        d: false,
+    };
+    let mut other = beta.clone();
+    other.alpha.c = FixedVector::new(vec![1, 3]).expect("exact length");
+    other.d = true;
+
+    let diffs = beta.diff(&other);
+    assert_eq!(
+        diffs.iter().map(|(name, _, _)| *name).collect::<Vec<_>>(),
+        vec!["alpha", "d"]
+    );
+    assert_eq!(diffs[0].1, format!("{:?}", beta.alpha));
+    assert_eq!(diffs[0].2, format!("{:?}", other.alpha));
+    assert_eq!(diffs[1], ("d", "false".to_string(), "true".to_string()));
+}
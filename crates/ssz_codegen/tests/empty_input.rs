@@ -0,0 +1,75 @@
+//! Generated container views decoding an empty slice fail with
+//! `DecodeError::Incomplete` needing their fixed portion, unions fail with
+//! `DecodeError::InvalidByteLength` expecting the selector, and lists of them
+//! decode to an empty view.
+
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+include!("expected_output/test_empty_input.rs");
+
+use ssz::{DecodeError, view::DecodeView};
+use ssz_types::view::VariableListRef;
+use tests::input::test_empty_input::{ChoiceRef, FixedRef, HolderRef, StableRef, VariableRef};
+
+fn incomplete(needed: usize) -> DecodeError {
+    DecodeError::Incomplete { needed }
+}
+
+#[test]
+fn fixed_container_needs_every_field() {
+    assert_eq!(FixedRef::from_ssz_bytes(&[]).unwrap_err(), incomplete(9));
+}
+
+#[test]
+fn variable_container_needs_fixed_portion() {
+    // `tag` plus the offset of `items`.
+    assert_eq!(VariableRef::from_ssz_bytes(&[]).unwrap_err(), incomplete(6));
+    // Two union offsets.
+    assert_eq!(HolderRef::from_ssz_bytes(&[]).unwrap_err(), incomplete(8));
+}
+
+#[test]
+fn stable_container_needs_bitvector() {
+    assert_eq!(StableRef::from_ssz_bytes(&[]).unwrap_err(), incomplete(1));
+}
+
+#[test]
+fn union_needs_selector() {
+    assert_eq!(
+        ChoiceRef::from_ssz_bytes(&[]).unwrap_err(),
+        DecodeError::InvalidByteLength {
+            len: 0,
+            expected: 1
+        }
+    );
+}
+
+#[test]
+fn empty_union_fields_are_invalid() {
+    // Both offsets point at the end of a complete buffer, so both union
+    // fields are empty.
+    let bytes = [8, 0, 0, 0, 8, 0, 0, 0];
+    let holder = HolderRef::from_ssz_bytes(&bytes).unwrap();
+    let invalid = DecodeError::InvalidByteLength {
+        len: 0,
+        expected: 1,
+    };
+    assert_eq!(holder.choice().unwrap_err(), invalid);
+    assert_eq!(holder.maybe().unwrap_err(), invalid);
+}
+
+#[test]
+fn lists_of_generated_types_decode_empty() {
+    let fixed = VariableListRef::<FixedRef<'_>, 4>::from_ssz_bytes(&[]).unwrap();
+    assert!(fixed.is_empty());
+    let unions = VariableListRef::<ChoiceRef<'_>, 4>::from_ssz_bytes(&[]).unwrap();
+    assert!(unions.is_empty());
+}
@@ -0,0 +1,48 @@
+//! Classes marked `error_type` can be used as `std::error::Error` values, with
+//! `Display` showing their `error_message` field.
+
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+// Include generated code
+include!("expected_output/test_error_type.rs");
+
+use ssz::{Decode, Encode};
+use ssz_types::VariableList;
+use tests::input::test_error_type::{RequestRejected, Timeout};
+
+fn reject(message: &str) -> Result<(), Box<dyn std::error::Error>> {
+    Err(Box::new(RequestRejected {
+        code: 429,
+        message: VariableList::new(message.as_bytes().to_vec()).expect("within bound"),
+    }))
+}
+
+#[test]
+fn error_type_displays_message_field() {
+    let err = reject("rate limited").unwrap_err();
+    assert_eq!(err.to_string(), "rate limited");
+    assert!(err.source().is_none());
+}
+
+#[test]
+fn error_type_displays_non_byte_message_field() {
+    let err = Timeout { elapsed_ms: 1500 };
+    assert_eq!(err.to_string(), "1500");
+}
+
+#[test]
+fn error_type_round_trips_over_the_wire() {
+    let err = RequestRejected {
+        code: 400,
+        message: VariableList::new(b"bad request".to_vec()).expect("within bound"),
+    };
+    let decoded = RequestRejected::from_ssz_bytes(&err.as_ssz_bytes()).expect("decode");
+    assert_eq!(decoded, err);
+    assert_eq!(decoded.to_string(), "bad request");
+}
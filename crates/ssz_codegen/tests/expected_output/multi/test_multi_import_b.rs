@@ -0,0 +1,350 @@
+use ssz_types::*;
+use ssz_types::view::{FixedVectorRef, VariableListRef};
+use ssz_primitives::{U128, U256};
+use ssz_derive::{Encode, Decode};
+use tree_hash::TreeHashDigest;
+use tree_hash_derive::TreeHash;
+use ssz::view::*;
+/// Hash of the normalized schema this module was generated from.
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+pub const SCHEMA_HASH: [u8; 32] = [
+    247, 139, 103, 207, 211, 194, 201, 193, 160, 83, 140, 194, 114, 83, 84, 9, 127, 24,
+    19, 115, 149, 223, 159, 247, 29, 60, 165, 138, 62, 88, 98, 103,
+];
+/// Type B that imports both base and a
+#[derive(
+    std::clone::Clone,
+    std::fmt::Debug,
+    std::cmp::PartialEq,
+    std::cmp::Eq,
+    ssz_derive::Encode,
+    ssz_derive::Decode
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct TypeB {
+    pub base: super::test_multi_import_base::BaseType,
+    pub type_a: super::test_multi_import_a::TypeA,
+    pub extra: u16,
+}
+impl tree_hash::TreeHash for TypeB {
+    fn tree_hash_type() -> tree_hash::TreeHashType {
+        tree_hash::TreeHashType::Container
+    }
+    fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+        unreachable!("Container should never be packed")
+    }
+    fn tree_hash_packing_factor() -> usize {
+        unreachable!("Container should never be packed")
+    }
+    fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+        use tree_hash::TreeHash;
+        let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(3usize);
+        hasher
+            .write(<_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.base).as_ref())
+            .expect("tree hash derive should not apply too many leaves");
+        hasher
+            .write(
+                <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.type_a).as_ref(),
+            )
+            .expect("tree hash derive should not apply too many leaves");
+        hasher
+            .write(<_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.extra).as_ref())
+            .expect("tree hash derive should not apply too many leaves");
+        hasher.finish().expect("tree hash derive should not have a remaining buffer")
+    }
+}
+impl tree_hash::TreeHashLeaves for TypeB {
+    fn tree_hash_leaf_count() -> usize {
+        3usize
+    }
+    fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+        &self,
+        index: usize,
+    ) -> H::Output {
+        match index {
+            0usize => <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.base),
+            1usize => <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.type_a),
+            2usize => <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.extra),
+            _ => panic!("leaf index {index} out of range for {} leaves", 3usize),
+        }
+    }
+}
+/// Zero-copy view over [`TypeB`].
+///
+/// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
+/// via lazy getter methods. Use `.to_owned()` to convert to the owned type when
+/// needed.
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+#[derive(
+    std::clone::Clone,
+    std::fmt::Debug,
+    std::cmp::PartialEq,
+    std::cmp::Eq,
+    std::marker::Copy
+)]
+pub struct TypeBRef<'a> {
+    bytes: &'a [u8],
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> TypeBRef<'a> {
+    pub fn base(
+        &self,
+    ) -> Result<super::test_multi_import_base::BaseTypeRef<'a>, ssz::DecodeError> {
+        let bytes = ssz::layout::read_field_bytes(
+            self.bytes,
+            &[
+                (
+                    <super::test_multi_import_base::BaseType as ssz::Encode>::is_ssz_fixed_len(),
+                    <super::test_multi_import_base::BaseType as ssz::Encode>::ssz_fixed_len(),
+                ),
+                (
+                    <super::test_multi_import_a::TypeA as ssz::Encode>::is_ssz_fixed_len(),
+                    <super::test_multi_import_a::TypeA as ssz::Encode>::ssz_fixed_len(),
+                ),
+                (
+                    <u16 as ssz::Encode>::is_ssz_fixed_len(),
+                    <u16 as ssz::Encode>::ssz_fixed_len(),
+                ),
+            ],
+            0usize,
+        )?;
+        ssz::view::DecodeView::from_ssz_bytes(bytes).map_err(|e| e.bounded(bytes.len()))
+    }
+    pub fn type_a(
+        &self,
+    ) -> Result<super::test_multi_import_a::TypeARef<'a>, ssz::DecodeError> {
+        let bytes = ssz::layout::read_field_bytes(
+            self.bytes,
+            &[
+                (
+                    <super::test_multi_import_base::BaseType as ssz::Encode>::is_ssz_fixed_len(),
+                    <super::test_multi_import_base::BaseType as ssz::Encode>::ssz_fixed_len(),
+                ),
+                (
+                    <super::test_multi_import_a::TypeA as ssz::Encode>::is_ssz_fixed_len(),
+                    <super::test_multi_import_a::TypeA as ssz::Encode>::ssz_fixed_len(),
+                ),
+                (
+                    <u16 as ssz::Encode>::is_ssz_fixed_len(),
+                    <u16 as ssz::Encode>::ssz_fixed_len(),
+                ),
+            ],
+            1usize,
+        )?;
+        ssz::view::DecodeView::from_ssz_bytes(bytes).map_err(|e| e.bounded(bytes.len()))
+    }
+    pub fn extra(&self) -> Result<u16, ssz::DecodeError> {
+        let bytes = ssz::layout::read_field_bytes(
+            self.bytes,
+            &[
+                (
+                    <super::test_multi_import_base::BaseType as ssz::Encode>::is_ssz_fixed_len(),
+                    <super::test_multi_import_base::BaseType as ssz::Encode>::ssz_fixed_len(),
+                ),
+                (
+                    <super::test_multi_import_a::TypeA as ssz::Encode>::is_ssz_fixed_len(),
+                    <super::test_multi_import_a::TypeA as ssz::Encode>::ssz_fixed_len(),
+                ),
+                (
+                    <u16 as ssz::Encode>::is_ssz_fixed_len(),
+                    <u16 as ssz::Encode>::ssz_fixed_len(),
+                ),
+            ],
+            2usize,
+        )?;
+        ssz::view::DecodeView::from_ssz_bytes(bytes).map_err(|e| e.bounded(bytes.len()))
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> TypeBRef<'a> {
+    /// Validates every field of this view, recursing into nested
+    /// views and walking every list and vector, and returns the
+    /// first error.
+    ///
+    /// `from_ssz_bytes` only checks the view's own layout, so this
+    /// rejects bytes that would otherwise fail on a later access.
+    pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+        let value = self.base()?;
+        value.validate_deep()?;
+        let value = self.type_a()?;
+        value.validate_deep()?;
+        self.extra()?;
+        Ok(())
+    }
+}
+impl<'a> tree_hash::TreeHash for TypeBRef<'a> {
+    fn tree_hash_type() -> tree_hash::TreeHashType {
+        tree_hash::TreeHashType::StableContainer
+    }
+    fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+        unreachable!("Container should never be packed")
+    }
+    fn tree_hash_packing_factor() -> usize {
+        unreachable!("Container should never be packed")
+    }
+    fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+        use tree_hash::TreeHash;
+        let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(3usize);
+        {
+            let base = self.base().expect("valid view");
+            let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                H,
+            >(&base);
+            hasher.write(root.as_ref()).expect("write field");
+        }
+        {
+            let type_a = self.type_a().expect("valid view");
+            let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                H,
+            >(&type_a);
+            hasher.write(root.as_ref()).expect("write field");
+        }
+        {
+            let extra = self.extra().expect("valid view");
+            let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                H,
+            >(&extra);
+            hasher.write(root.as_ref()).expect("write field");
+        }
+        hasher.finish().expect("finish hasher")
+    }
+}
+impl<'a> ssz::view::DecodeView<'a> for TypeBRef<'a> {
+    fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
+        ssz::layout::validate_container(
+            bytes,
+            &[
+                (
+                    <super::test_multi_import_base::BaseType as ssz::Encode>::is_ssz_fixed_len(),
+                    <super::test_multi_import_base::BaseType as ssz::Encode>::ssz_fixed_len(),
+                ),
+                (
+                    <super::test_multi_import_a::TypeA as ssz::Encode>::is_ssz_fixed_len(),
+                    <super::test_multi_import_a::TypeA as ssz::Encode>::ssz_fixed_len(),
+                ),
+                (
+                    <u16 as ssz::Encode>::is_ssz_fixed_len(),
+                    <u16 as ssz::Encode>::ssz_fixed_len(),
+                ),
+            ],
+        )?;
+        Ok(Self { bytes })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for TypeBRef<'a> {
+    type Error = ssz::DecodeError;
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> TypeBRef<'a> {
+    /// Returns the SSZ bytes this view was decoded from.
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
+impl<'a> ssz::view::SszTypeInfo for TypeBRef<'a> {
+    fn is_ssz_fixed_len() -> bool {
+        usize::from(
+            !<super::test_multi_import_base::BaseType as ssz::Encode>::is_ssz_fixed_len(),
+        )
+            + usize::from(
+                !<super::test_multi_import_a::TypeA as ssz::Encode>::is_ssz_fixed_len(),
+            ) + usize::from(!<u16 as ssz::Encode>::is_ssz_fixed_len()) == 0
+    }
+    fn ssz_fixed_len() -> usize {
+        if <Self as ssz::view::SszTypeInfo>::is_ssz_fixed_len() {
+            <super::test_multi_import_base::BaseType as ssz::Encode>::ssz_fixed_len()
+                + <super::test_multi_import_a::TypeA as ssz::Encode>::ssz_fixed_len()
+                + <u16 as ssz::Encode>::ssz_fixed_len()
+        } else {
+            0
+        }
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> ssz_types::view::ToOwnedSsz<TypeB> for TypeBRef<'a> {
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    fn to_owned(&self) -> TypeB {
+        <TypeBRef<'a>>::to_owned(self)
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> TypeBRef<'a> {
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    pub fn to_owned(&self) -> TypeB {
+        TypeB {
+            base: {
+                let view = self.base().expect("valid view");
+                ssz_types::view::ToOwnedSsz::to_owned(&view)
+            },
+            type_a: {
+                let view = self.type_a().expect("valid view");
+                ssz_types::view::ToOwnedSsz::to_owned(&view)
+            },
+            extra: self.extra().expect("valid view"),
+        }
+    }
+    /// Converts this view into `out`, reusing the allocations of its
+    /// list and vector fields.
+    ///
+    /// Produces the same value as `to_owned`, but avoids reallocating
+    /// when converting many views in a loop. The view is validated
+    /// with `validate_deep` before anything is written, so on error
+    /// `out` is left unchanged.
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    pub fn to_owned_into(&self, out: &mut TypeB) -> Result<(), ssz::DecodeError> {
+        self.validate_deep()?;
+        out.base = {
+            let view = self.base()?;
+            ssz_types::view::ToOwnedSsz::to_owned(&view)
+        };
+        out.type_a = {
+            let view = self.type_a()?;
+            ssz_types::view::ToOwnedSsz::to_owned(&view)
+        };
+        out.extra = self.extra()?;
+        Ok(())
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> TypeBRef<'a> {
+    fn eq_owned(&self, other: &TypeB) -> Result<bool, ssz::DecodeError> {
+        if !({
+            let value: super::test_multi_import_base::BaseType = {
+                let view = self.base()?;
+                ssz_types::view::ToOwnedSsz::to_owned(&view)
+            };
+            value == other.base
+        }) {
+            return Ok(false);
+        }
+        if !({
+            let value: super::test_multi_import_a::TypeA = {
+                let view = self.type_a()?;
+                ssz_types::view::ToOwnedSsz::to_owned(&view)
+            };
+            value == other.type_a
+        }) {
+            return Ok(false);
+        }
+        if !({
+            let value: u16 = self.extra()?;
+            value == other.extra
+        }) {
+            return Ok(false);
+        }
+        Ok(true)
+    }
+}
+impl<'a> PartialEq<TypeB> for TypeBRef<'a> {
+    fn eq(&self, other: &TypeB) -> bool {
+        self.eq_owned(other).unwrap_or(false)
+    }
+}
+impl<'a> PartialEq<TypeBRef<'a>> for TypeB {
+    fn eq(&self, other: &TypeBRef<'a>) -> bool {
+        other == self
+    }
+}
@@ -0,0 +1,214 @@
+use ssz_types::*;
+use ssz_types::view::{FixedVectorRef, VariableListRef};
+use ssz_primitives::{U128, U256};
+use ssz_derive::{Encode, Decode};
+use tree_hash::TreeHashDigest;
+use tree_hash_derive::TreeHash;
+use ssz::view::*;
+/// Hash of the normalized schema this module was generated from.
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+pub const SCHEMA_HASH: [u8; 32] = [
+    244, 22, 98, 140, 127, 233, 232, 187, 99, 97, 239, 50, 12, 221, 43, 178, 24, 241, 9,
+    255, 161, 228, 10, 129, 8, 187, 81, 188, 130, 138, 51, 66,
+];
+/// Base type shared by multiple importers
+#[derive(
+    std::clone::Clone,
+    std::fmt::Debug,
+    std::cmp::PartialEq,
+    std::cmp::Eq,
+    ssz_derive::Encode,
+    ssz_derive::Decode
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct BaseType {
+    pub value: u64,
+}
+impl BaseType {
+    /// Byte offset of each field in the SSZ encoding, in declaration order.
+    pub const FIELD_OFFSETS: &[usize] = &[0];
+    /// Byte size of each field in the SSZ encoding, in declaration order.
+    pub const FIELD_SIZES: &[usize] = &[8];
+}
+impl tree_hash::TreeHash for BaseType {
+    fn tree_hash_type() -> tree_hash::TreeHashType {
+        tree_hash::TreeHashType::Container
+    }
+    fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+        unreachable!("Container should never be packed")
+    }
+    fn tree_hash_packing_factor() -> usize {
+        unreachable!("Container should never be packed")
+    }
+    fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+        use tree_hash::TreeHash;
+        let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(1usize);
+        hasher
+            .write(<_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.value).as_ref())
+            .expect("tree hash derive should not apply too many leaves");
+        hasher.finish().expect("tree hash derive should not have a remaining buffer")
+    }
+}
+impl tree_hash::TreeHashLeaves for BaseType {
+    fn tree_hash_leaf_count() -> usize {
+        1usize
+    }
+    fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+        &self,
+        index: usize,
+    ) -> H::Output {
+        match index {
+            0usize => <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.value),
+            _ => panic!("leaf index {index} out of range for {} leaves", 1usize),
+        }
+    }
+}
+/// Zero-copy view over [`BaseType`].
+///
+/// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
+/// via lazy getter methods. Use `.to_owned()` to convert to the owned type when
+/// needed.
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+#[derive(
+    std::clone::Clone,
+    std::fmt::Debug,
+    std::cmp::PartialEq,
+    std::cmp::Eq,
+    std::marker::Copy
+)]
+pub struct BaseTypeRef<'a> {
+    bytes: &'a [u8],
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> BaseTypeRef<'a> {
+    pub fn value(&self) -> Result<u64, ssz::DecodeError> {
+        let bytes = &self.bytes[0..8];
+        ssz::view::DecodeView::from_ssz_bytes(bytes).map_err(|e| e.bounded(bytes.len()))
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> BaseTypeRef<'a> {
+    /// Validates every field of this view, recursing into nested
+    /// views and walking every list and vector, and returns the
+    /// first error.
+    ///
+    /// `from_ssz_bytes` only checks the view's own layout, so this
+    /// rejects bytes that would otherwise fail on a later access.
+    pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+        self.value()?;
+        Ok(())
+    }
+}
+impl<'a> tree_hash::TreeHash for BaseTypeRef<'a> {
+    fn tree_hash_type() -> tree_hash::TreeHashType {
+        tree_hash::TreeHashType::StableContainer
+    }
+    fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+        unreachable!("Container should never be packed")
+    }
+    fn tree_hash_packing_factor() -> usize {
+        unreachable!("Container should never be packed")
+    }
+    fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+        use tree_hash::TreeHash;
+        let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(1usize);
+        {
+            let value = self.value().expect("valid view");
+            let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                H,
+            >(&value);
+            hasher.write(root.as_ref()).expect("write field");
+        }
+        hasher.finish().expect("finish hasher")
+    }
+}
+impl<'a> ssz::view::DecodeView<'a> for BaseTypeRef<'a> {
+    fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
+        ssz::layout::validate_container(
+            bytes,
+            &[
+                (
+                    <u64 as ssz::Encode>::is_ssz_fixed_len(),
+                    <u64 as ssz::Encode>::ssz_fixed_len(),
+                ),
+            ],
+        )?;
+        Ok(Self { bytes })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for BaseTypeRef<'a> {
+    type Error = ssz::DecodeError;
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> BaseTypeRef<'a> {
+    /// Returns the SSZ bytes this view was decoded from.
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
+impl<'a> ssz::view::SszTypeInfo for BaseTypeRef<'a> {
+    fn is_ssz_fixed_len() -> bool {
+        usize::from(!<u64 as ssz::Encode>::is_ssz_fixed_len()) == 0
+    }
+    fn ssz_fixed_len() -> usize {
+        if <Self as ssz::view::SszTypeInfo>::is_ssz_fixed_len() {
+            <u64 as ssz::Encode>::ssz_fixed_len()
+        } else {
+            0
+        }
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> ssz_types::view::ToOwnedSsz<BaseType> for BaseTypeRef<'a> {
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    fn to_owned(&self) -> BaseType {
+        <BaseTypeRef<'a>>::to_owned(self)
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> BaseTypeRef<'a> {
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    pub fn to_owned(&self) -> BaseType {
+        BaseType {
+            value: self.value().expect("valid view"),
+        }
+    }
+    /// Converts this view into `out`, reusing the allocations of its
+    /// list and vector fields.
+    ///
+    /// Produces the same value as `to_owned`, but avoids reallocating
+    /// when converting many views in a loop. The view is validated
+    /// with `validate_deep` before anything is written, so on error
+    /// `out` is left unchanged.
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    pub fn to_owned_into(&self, out: &mut BaseType) -> Result<(), ssz::DecodeError> {
+        self.validate_deep()?;
+        out.value = self.value()?;
+        Ok(())
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> BaseTypeRef<'a> {
+    fn eq_owned(&self, other: &BaseType) -> Result<bool, ssz::DecodeError> {
+        if !({
+            let value: u64 = self.value()?;
+            value == other.value
+        }) {
+            return Ok(false);
+        }
+        Ok(true)
+    }
+}
+impl<'a> PartialEq<BaseType> for BaseTypeRef<'a> {
+    fn eq(&self, other: &BaseType) -> bool {
+        self.eq_owned(other).unwrap_or(false)
+    }
+}
+impl<'a> PartialEq<BaseTypeRef<'a>> for BaseType {
+    fn eq(&self, other: &BaseTypeRef<'a>) -> bool {
+        other == self
+    }
+}
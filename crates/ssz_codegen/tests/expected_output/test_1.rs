@@ -11,7 +11,13 @@ pub mod tests {
             use tree_hash::TreeHashDigest;
             use tree_hash_derive::TreeHash;
             use ssz::view::*;
-            #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                71, 245, 149, 164, 58, 245, 112, 220, 202, 16, 235, 68, 187, 143, 79, 35,
+                102, 107, 30, 45, 26, 144, 245, 150, 61, 160, 114, 3, 116, 87, 230, 24,
+            ];
+            #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, Hash)]
             #[ssz(enum_behaviour = "union")]
             pub enum AliasOptionUnion {
                 Selector0(u8),
@@ -46,6 +52,40 @@ pub mod tests {
                     }
                 }
             }
+            impl std::fmt::Display for AliasOptionUnion {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    match self {
+                        AliasOptionUnion::Selector0(inner) => {
+                            write!(f, "AliasOptionUnion::Selector0({inner:?})")
+                        }
+                        AliasOptionUnion::Selector1(inner) => {
+                            write!(f, "AliasOptionUnion::Selector1({inner:?})")
+                        }
+                    }
+                }
+            }
+            impl From<u8> for AliasOptionUnion {
+                fn from(value: u8) -> Self {
+                    AliasOptionUnion::Selector0(value)
+                }
+            }
+            impl From<Option<u16>> for AliasOptionUnion {
+                fn from(value: Option<u16>) -> Self {
+                    AliasOptionUnion::Selector1(value)
+                }
+            }
+            #[cfg(test)]
+            impl AliasOptionUnion {
+                /// Returns one value per selector, in selector order, each
+                /// holding its member's default. Lets tests check that code
+                /// matching on the union handles every member.
+                pub fn all_selector_examples() -> Vec<Self> {
+                    vec![
+                        AliasOptionUnion::Selector0(Default::default()),
+                        AliasOptionUnion::Selector1(None)
+                    ]
+                }
+            }
             #[derive(Debug, Copy, Clone)]
             pub struct AliasOptionUnionRef<'a> {
                 bytes: &'a [u8],
@@ -63,7 +103,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <u8 as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <u8 as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn as_selector1(&self) -> Result<Option<u16>, ssz::DecodeError> {
                     if self.selector() != 1u8 {
@@ -74,7 +122,35 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    {
+                        let bytes = &self.bytes[1..];
+                        ssz::view::reject_empty(bytes, 1)?;
+                        let selector = bytes[0];
+                        match selector {
+                            0 => ssz::check_union_body_len(&bytes[1..], 0).map(|()| None),
+                            1 => {
+                                if <u16 as ssz::Encode>::is_ssz_fixed_len() {
+                                    ssz::check_union_body_len(
+                                        &bytes[1..],
+                                        <u16 as ssz::Encode>::ssz_fixed_len(),
+                                    )?;
+                                }
+                                let body = &bytes[1..];
+                                let inner = <u16 as ssz::view::DecodeView>::from_ssz_bytes(
+                                        body,
+                                    )
+                                    .map_err(|e| e.bounded(body.len()))?;
+                                Ok(Some(inner))
+                            }
+                            _ => {
+                                Err(
+                                    ssz::DecodeError::BytesInvalid(
+                                        format!("Invalid union selector for Option: {}", selector),
+                                    ),
+                                )
+                            }
+                        }
+                    }
                 }
                 pub fn to_owned(&self) -> AliasOptionUnion {
                     match self.selector() {
@@ -92,6 +168,16 @@ pub mod tests {
                         _ => panic!("Invalid union selector: {}", self.selector()),
                     }
                 }
+                /// Validates the selector and the selected member, recursing
+                /// into nested views and walking every list and vector, and
+                /// returns the first error.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    match self.selector() {
+                        0u8 => self.as_selector0().map(|_| ()),
+                        1u8 => self.as_selector1().map(|_| ()),
+                        selector => Err(ssz::DecodeError::UnionSelectorInvalid(selector)),
+                    }
+                }
             }
             impl<'a> ssz::view::DecodeView<'a> for AliasOptionUnionRef<'a> {
                 fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
@@ -149,7 +235,7 @@ pub mod tests {
                     }
                 }
             }
-            #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+            #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, Hash)]
             #[ssz(enum_behaviour = "union")]
             pub enum FirstUnion {
                 Selector0(u8),
@@ -184,6 +270,40 @@ pub mod tests {
                     }
                 }
             }
+            impl std::fmt::Display for FirstUnion {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    match self {
+                        FirstUnion::Selector0(inner) => {
+                            write!(f, "FirstUnion::Selector0({inner:?})")
+                        }
+                        FirstUnion::Selector1(inner) => {
+                            write!(f, "FirstUnion::Selector1({inner:?})")
+                        }
+                    }
+                }
+            }
+            impl From<u8> for FirstUnion {
+                fn from(value: u8) -> Self {
+                    FirstUnion::Selector0(value)
+                }
+            }
+            impl From<u16> for FirstUnion {
+                fn from(value: u16) -> Self {
+                    FirstUnion::Selector1(value)
+                }
+            }
+            #[cfg(test)]
+            impl FirstUnion {
+                /// Returns one value per selector, in selector order, each
+                /// holding its member's default. Lets tests check that code
+                /// matching on the union handles every member.
+                pub fn all_selector_examples() -> Vec<Self> {
+                    vec![
+                        FirstUnion::Selector0(Default::default()),
+                        FirstUnion::Selector1(Default::default())
+                    ]
+                }
+            }
             #[derive(Debug, Copy, Clone)]
             pub struct FirstUnionRef<'a> {
                 bytes: &'a [u8],
@@ -200,7 +320,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <u8 as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <u8 as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn as_selector1(&self) -> Result<u16, ssz::DecodeError> {
                     if self.selector() != 1u8 {
@@ -210,7 +338,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <u16 as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <u16 as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn to_owned(&self) -> FirstUnion {
                     match self.selector() {
@@ -227,6 +363,16 @@ pub mod tests {
                         _ => panic!("Invalid union selector: {}", self.selector()),
                     }
                 }
+                /// Validates the selector and the selected member, recursing
+                /// into nested views and walking every list and vector, and
+                /// returns the first error.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    match self.selector() {
+                        0u8 => self.as_selector0().map(|_| ()),
+                        1u8 => self.as_selector1().map(|_| ()),
+                        selector => Err(ssz::DecodeError::UnionSelectorInvalid(selector)),
+                    }
+                }
             }
             impl<'a> ssz::view::DecodeView<'a> for FirstUnionRef<'a> {
                 fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
@@ -283,7 +429,7 @@ pub mod tests {
                     }
                 }
             }
-            #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+            #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, Hash)]
             #[ssz(enum_behaviour = "union")]
             pub enum TestUnion {
                 Selector0,
@@ -324,6 +470,41 @@ pub mod tests {
                     }
                 }
             }
+            impl std::fmt::Display for TestUnion {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    match self {
+                        TestUnion::Selector0 => f.write_str("TestUnion::Selector0"),
+                        TestUnion::Selector1(inner) => {
+                            write!(f, "TestUnion::Selector1({inner:?})")
+                        }
+                        TestUnion::Selector2(inner) => {
+                            write!(f, "TestUnion::Selector2({inner:?})")
+                        }
+                    }
+                }
+            }
+            impl From<u8> for TestUnion {
+                fn from(value: u8) -> Self {
+                    TestUnion::Selector1(value)
+                }
+            }
+            impl From<u16> for TestUnion {
+                fn from(value: u16) -> Self {
+                    TestUnion::Selector2(value)
+                }
+            }
+            #[cfg(test)]
+            impl TestUnion {
+                /// Returns one value per selector, in selector order, each
+                /// holding its member's default. Lets tests check that code
+                /// matching on the union handles every member.
+                pub fn all_selector_examples() -> Vec<Self> {
+                    vec![
+                        TestUnion::Selector0, TestUnion::Selector1(Default::default()),
+                        TestUnion::Selector2(Default::default())
+                    ]
+                }
+            }
             #[derive(Debug, Copy, Clone)]
             pub struct TestUnionRef<'a> {
                 bytes: &'a [u8],
@@ -340,7 +521,7 @@ pub mod tests {
                             ),
                         );
                     }
-                    Ok(())
+                    ssz::check_union_body_len(&self.bytes[1..], 0)
                 }
                 pub fn as_selector1(&self) -> Result<u8, ssz::DecodeError> {
                     if self.selector() != 1u8 {
@@ -350,7 +531,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <u8 as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <u8 as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn as_selector2(&self) -> Result<u16, ssz::DecodeError> {
                     if self.selector() != 2u8 {
@@ -360,7 +549,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <u16 as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <u16 as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn to_owned(&self) -> TestUnion {
                     match self.selector() {
@@ -381,6 +578,17 @@ pub mod tests {
                         _ => panic!("Invalid union selector: {}", self.selector()),
                     }
                 }
+                /// Validates the selector and the selected member, recursing
+                /// into nested views and walking every list and vector, and
+                /// returns the first error.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    match self.selector() {
+                        0u8 => self.as_selector0(),
+                        1u8 => self.as_selector1().map(|_| ()),
+                        2u8 => self.as_selector2().map(|_| ()),
+                        selector => Err(ssz::DecodeError::UnionSelectorInvalid(selector)),
+                    }
+                }
             }
             impl<'a> ssz::view::DecodeView<'a> for TestUnionRef<'a> {
                 fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
@@ -442,7 +650,7 @@ pub mod tests {
                     }
                 }
             }
-            #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+            #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, Hash)]
             #[ssz(enum_behaviour = "union")]
             pub enum UnionA {
                 Selector0(u8),
@@ -485,6 +693,39 @@ pub mod tests {
                     }
                 }
             }
+            impl std::fmt::Display for UnionA {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    match self {
+                        UnionA::Selector0(inner) => {
+                            write!(f, "UnionA::Selector0({inner:?})")
+                        }
+                        UnionA::Selector1(inner) => {
+                            write!(f, "UnionA::Selector1({inner:?})")
+                        }
+                        UnionA::Selector2(inner) => {
+                            write!(f, "UnionA::Selector2({inner:?})")
+                        }
+                    }
+                }
+            }
+            impl From<u16> for UnionA {
+                fn from(value: u16) -> Self {
+                    UnionA::Selector2(value)
+                }
+            }
+            #[cfg(test)]
+            impl UnionA {
+                /// Returns one value per selector, in selector order, each
+                /// holding its member's default. Lets tests check that code
+                /// matching on the union handles every member.
+                pub fn all_selector_examples() -> Vec<Self> {
+                    vec![
+                        UnionA::Selector0(Default::default()),
+                        UnionA::Selector1(Default::default()),
+                        UnionA::Selector2(Default::default())
+                    ]
+                }
+            }
             #[derive(Debug, Copy, Clone)]
             pub struct UnionARef<'a> {
                 bytes: &'a [u8],
@@ -501,7 +742,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <u8 as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <u8 as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn as_selector1(&self) -> Result<u8, ssz::DecodeError> {
                     if self.selector() != 1u8 {
@@ -511,7 +760,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <u8 as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <u8 as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn as_selector2(&self) -> Result<u16, ssz::DecodeError> {
                     if self.selector() != 2u8 {
@@ -521,7 +778,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <u16 as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <u16 as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn to_owned(&self) -> UnionA {
                     match self.selector() {
@@ -543,6 +808,17 @@ pub mod tests {
                         _ => panic!("Invalid union selector: {}", self.selector()),
                     }
                 }
+                /// Validates the selector and the selected member, recursing
+                /// into nested views and walking every list and vector, and
+                /// returns the first error.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    match self.selector() {
+                        0u8 => self.as_selector0().map(|_| ()),
+                        1u8 => self.as_selector1().map(|_| ()),
+                        2u8 => self.as_selector2().map(|_| ()),
+                        selector => Err(ssz::DecodeError::UnionSelectorInvalid(selector)),
+                    }
+                }
             }
             impl<'a> ssz::view::DecodeView<'a> for UnionARef<'a> {
                 fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
@@ -609,7 +885,7 @@ pub mod tests {
                     }
                 }
             }
-            #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+            #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, Hash)]
             #[ssz(enum_behaviour = "union")]
             pub enum UnionB {
                 Selector0(u8),
@@ -660,6 +936,42 @@ pub mod tests {
                     }
                 }
             }
+            impl std::fmt::Display for UnionB {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    match self {
+                        UnionB::Selector0(inner) => {
+                            write!(f, "UnionB::Selector0({inner:?})")
+                        }
+                        UnionB::UnionA(inner) => write!(f, "UnionB::UnionA({inner:?})"),
+                        UnionB::Selector2(inner) => {
+                            write!(f, "UnionB::Selector2({inner:?})")
+                        }
+                        UnionB::Selector3(inner) => {
+                            write!(f, "UnionB::Selector3({inner:?})")
+                        }
+                    }
+                }
+            }
+            impl From<u8> for UnionB {
+                fn from(value: u8) -> Self {
+                    UnionB::Selector0(value)
+                }
+            }
+            impl From<UnionA> for UnionB {
+                fn from(value: UnionA) -> Self {
+                    UnionB::UnionA(value)
+                }
+            }
+            impl From<u32> for UnionB {
+                fn from(value: u32) -> Self {
+                    UnionB::Selector2(value)
+                }
+            }
+            impl From<VariableList<u8, 12usize>> for UnionB {
+                fn from(value: VariableList<u8, 12usize>) -> Self {
+                    UnionB::Selector3(value)
+                }
+            }
             #[derive(Debug, Copy, Clone)]
             pub struct UnionBRef<'a> {
                 bytes: &'a [u8],
@@ -676,7 +988,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <u8 as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <u8 as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn as_selector1(&self) -> Result<UnionARef<'a>, ssz::DecodeError> {
                     if self.selector() != 1u8 {
@@ -686,7 +1006,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <UnionA as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <UnionA as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn as_selector2(&self) -> Result<u32, ssz::DecodeError> {
                     if self.selector() != 2u8 {
@@ -696,7 +1024,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <u32 as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <u32 as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn as_selector3(
                     &self,
@@ -708,7 +1044,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <VariableList<u8, 12usize> as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <VariableList<u8, 12usize> as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn to_owned(&self) -> UnionB {
                     match self.selector() {
@@ -737,6 +1081,22 @@ pub mod tests {
                         _ => panic!("Invalid union selector: {}", self.selector()),
                     }
                 }
+                /// Validates the selector and the selected member, recursing
+                /// into nested views and walking every list and vector, and
+                /// returns the first error.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    match self.selector() {
+                        0u8 => self.as_selector0().map(|_| ()),
+                        1u8 => {
+                            let value = self.as_selector1()?;
+                            value.validate_deep()?;
+                            Ok(())
+                        }
+                        2u8 => self.as_selector2().map(|_| ()),
+                        3u8 => self.as_selector3().map(|_| ()),
+                        selector => Err(ssz::DecodeError::UnionSelectorInvalid(selector)),
+                    }
+                }
             }
             impl<'a> ssz::view::DecodeView<'a> for UnionBRef<'a> {
                 fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
@@ -813,11 +1173,11 @@ pub mod tests {
                     }
                 }
             }
-            #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+            #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, Hash)]
             #[ssz(enum_behaviour = "union")]
             pub enum UnionC {
-                AliasUintAlias(AliasUintAlias),
-                AliasUintAlias(AliasUintAlias),
+                AliasUintAlias0(AliasUintAlias),
+                AliasUintAlias1(AliasUintAlias),
             }
             impl tree_hash::TreeHash for UnionC {
                 fn tree_hash_type() -> tree_hash::TreeHashType {
@@ -831,14 +1191,14 @@ pub mod tests {
                 }
                 fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
                     match self {
-                        UnionC::AliasUintAlias(inner) => {
+                        UnionC::AliasUintAlias0(inner) => {
                             let root = <_ as tree_hash::TreeHash>::tree_hash_root::<
                                 H,
                             >(inner);
                             tree_hash::mix_in_selector_with_hasher::<H>(&root, 0u8)
                                 .expect("valid selector")
                         }
-                        UnionC::AliasUintAlias(inner) => {
+                        UnionC::AliasUintAlias1(inner) => {
                             let root = <_ as tree_hash::TreeHash>::tree_hash_root::<
                                 H,
                             >(inner);
@@ -848,6 +1208,30 @@ pub mod tests {
                     }
                 }
             }
+            impl std::fmt::Display for UnionC {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    match self {
+                        UnionC::AliasUintAlias0(inner) => {
+                            write!(f, "UnionC::AliasUintAlias0({inner:?})")
+                        }
+                        UnionC::AliasUintAlias1(inner) => {
+                            write!(f, "UnionC::AliasUintAlias1({inner:?})")
+                        }
+                    }
+                }
+            }
+            #[cfg(test)]
+            impl UnionC {
+                /// Returns one value per selector, in selector order, each
+                /// holding its member's default. Lets tests check that code
+                /// matching on the union handles every member.
+                pub fn all_selector_examples() -> Vec<Self> {
+                    vec![
+                        UnionC::AliasUintAlias0(Default::default()),
+                        UnionC::AliasUintAlias1(Default::default())
+                    ]
+                }
+            }
             #[derive(Debug, Copy, Clone)]
             pub struct UnionCRef<'a> {
                 bytes: &'a [u8],
@@ -864,7 +1248,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <AliasUintAlias as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <AliasUintAlias as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn as_selector1(&self) -> Result<u16, ssz::DecodeError> {
                     if self.selector() != 1u8 {
@@ -874,23 +1266,41 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <AliasUintAlias as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <AliasUintAlias as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn to_owned(&self) -> UnionC {
                     match self.selector() {
                         0u8 => {
-                            UnionC::AliasUintAlias(
+                            UnionC::AliasUintAlias0(
                                 self.as_selector0().expect("valid selector"),
                             )
                         }
                         1u8 => {
-                            UnionC::AliasUintAlias(
+                            UnionC::AliasUintAlias1(
                                 self.as_selector1().expect("valid selector"),
                             )
                         }
                         _ => panic!("Invalid union selector: {}", self.selector()),
                     }
                 }
+                /// Validates the selector and the selected member, recursing
+                /// into nested views and walking every list and vector, and
+                /// returns the first error.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    match self.selector() {
+                        0u8 => self.as_selector0().map(|_| ()),
+                        1u8 => self.as_selector1().map(|_| ()),
+                        selector => Err(ssz::DecodeError::UnionSelectorInvalid(selector)),
+                    }
+                }
             }
             impl<'a> ssz::view::DecodeView<'a> for UnionCRef<'a> {
                 fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
@@ -947,11 +1357,11 @@ pub mod tests {
                     }
                 }
             }
-            #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+            #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, Hash)]
             #[ssz(enum_behaviour = "union")]
             pub enum UnionD {
-                AliasUintAlias(AliasUintAlias),
-                AliasUintAlias(AliasUintAlias),
+                AliasUintAlias0(AliasUintAlias),
+                AliasUintAlias1(AliasUintAlias),
             }
             impl tree_hash::TreeHash for UnionD {
                 fn tree_hash_type() -> tree_hash::TreeHashType {
@@ -965,14 +1375,14 @@ pub mod tests {
                 }
                 fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
                     match self {
-                        UnionD::AliasUintAlias(inner) => {
+                        UnionD::AliasUintAlias0(inner) => {
                             let root = <_ as tree_hash::TreeHash>::tree_hash_root::<
                                 H,
                             >(inner);
                             tree_hash::mix_in_selector_with_hasher::<H>(&root, 0u8)
                                 .expect("valid selector")
                         }
-                        UnionD::AliasUintAlias(inner) => {
+                        UnionD::AliasUintAlias1(inner) => {
                             let root = <_ as tree_hash::TreeHash>::tree_hash_root::<
                                 H,
                             >(inner);
@@ -982,6 +1392,30 @@ pub mod tests {
                     }
                 }
             }
+            impl std::fmt::Display for UnionD {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    match self {
+                        UnionD::AliasUintAlias0(inner) => {
+                            write!(f, "UnionD::AliasUintAlias0({inner:?})")
+                        }
+                        UnionD::AliasUintAlias1(inner) => {
+                            write!(f, "UnionD::AliasUintAlias1({inner:?})")
+                        }
+                    }
+                }
+            }
+            #[cfg(test)]
+            impl UnionD {
+                /// Returns one value per selector, in selector order, each
+                /// holding its member's default. Lets tests check that code
+                /// matching on the union handles every member.
+                pub fn all_selector_examples() -> Vec<Self> {
+                    vec![
+                        UnionD::AliasUintAlias0(Default::default()),
+                        UnionD::AliasUintAlias1(Default::default())
+                    ]
+                }
+            }
             #[derive(Debug, Copy, Clone)]
             pub struct UnionDRef<'a> {
                 bytes: &'a [u8],
@@ -998,7 +1432,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <AliasUintAlias as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <AliasUintAlias as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn as_selector1(&self) -> Result<u16, ssz::DecodeError> {
                     if self.selector() != 1u8 {
@@ -1008,23 +1450,41 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <AliasUintAlias as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <AliasUintAlias as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn to_owned(&self) -> UnionD {
                     match self.selector() {
                         0u8 => {
-                            UnionD::AliasUintAlias(
+                            UnionD::AliasUintAlias0(
                                 self.as_selector0().expect("valid selector"),
                             )
                         }
                         1u8 => {
-                            UnionD::AliasUintAlias(
+                            UnionD::AliasUintAlias1(
                                 self.as_selector1().expect("valid selector"),
                             )
                         }
                         _ => panic!("Invalid union selector: {}", self.selector()),
                     }
                 }
+                /// Validates the selector and the selected member, recursing
+                /// into nested views and walking every list and vector, and
+                /// returns the first error.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    match self.selector() {
+                        0u8 => self.as_selector0().map(|_| ()),
+                        1u8 => self.as_selector1().map(|_| ()),
+                        selector => Err(ssz::DecodeError::UnionSelectorInvalid(selector)),
+                    }
+                }
             }
             impl<'a> ssz::view::DecodeView<'a> for UnionDRef<'a> {
                 fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
@@ -1108,6 +1568,12 @@ pub mod tests {
                 pub b: u16,
                 pub c: AliasVecB,
             }
+            impl Alpha {
+                /// Byte offset of each field in the SSZ encoding, in declaration order.
+                pub const FIELD_OFFSETS: &[usize] = &[0, 1, 3];
+                /// Byte size of each field in the SSZ encoding, in declaration order.
+                pub const FIELD_SIZES: &[usize] = &[1, 2, 10];
+            }
             impl tree_hash::TreeHash for Alpha {
                 fn tree_hash_type() -> tree_hash::TreeHashType {
                     tree_hash::TreeHashType::Container
@@ -1144,6 +1610,32 @@ pub mod tests {
                         .expect("tree hash derive should not have a remaining buffer")
                 }
             }
+            impl tree_hash::TreeHashLeaves for Alpha {
+                fn tree_hash_leaf_count() -> usize {
+                    3usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.a)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.b)
+                        }
+                        2usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.c)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 3usize
+                            )
+                        }
+                    }
+                }
+            }
             /// Zero-copy view over [`Alpha`].
             ///
             /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
@@ -1163,67 +1655,34 @@ pub mod tests {
             #[allow(dead_code, reason = "generated code using ssz-gen")]
             impl<'a> AlphaRef<'a> {
                 pub fn a(&self) -> Result<u8, ssz::DecodeError> {
-                    let bytes = ssz::layout::read_field_bytes(
-                        self.bytes,
-                        &[
-                            (
-                                <u8 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u8 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                            (
-                                <u16 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u16 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                            (
-                                <AliasVecB as ssz::Encode>::is_ssz_fixed_len(),
-                                <AliasVecB as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                        ],
-                        0usize,
-                    )?;
+                    let bytes = &self.bytes[0..1];
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn b(&self) -> Result<u16, ssz::DecodeError> {
-                    let bytes = ssz::layout::read_field_bytes(
-                        self.bytes,
-                        &[
-                            (
-                                <u8 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u8 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                            (
-                                <u16 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u16 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                            (
-                                <AliasVecB as ssz::Encode>::is_ssz_fixed_len(),
-                                <AliasVecB as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                        ],
-                        1usize,
-                    )?;
+                    let bytes = &self.bytes[1..3];
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn c(&self) -> Result<FixedBytesRef<'a, 10usize>, ssz::DecodeError> {
-                    let bytes = ssz::layout::read_field_bytes(
-                        self.bytes,
-                        &[
-                            (
-                                <u8 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u8 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                            (
-                                <u16 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u16 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                            (
-                                <AliasVecB as ssz::Encode>::is_ssz_fixed_len(),
-                                <AliasVecB as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                        ],
-                        2usize,
-                    )?;
+                    let bytes = &self.bytes[3..13];
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> AlphaRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.a()?;
+                    self.b()?;
+                    self.c()?;
+                    Ok(())
                 }
             }
             impl<'a> tree_hash::TreeHash for AlphaRef<'a> {
@@ -1285,6 +1744,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for AlphaRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> AlphaRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for AlphaRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     usize::from(!<u8 as ssz::Encode>::is_ssz_fixed_len())
@@ -1327,6 +1799,63 @@ pub mod tests {
                         ),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Alpha,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.a = self.a()?;
+                    out.b = self.b()?;
+                    out.c = ssz_types::FixedBytes(self.c()?.to_owned());
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> AlphaRef<'a> {
+                fn eq_owned(&self, other: &Alpha) -> Result<bool, ssz::DecodeError> {
+                    if !({
+                        let value: u8 = self.a()?;
+                        value == other.a
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: u16 = self.b()?;
+                        value == other.b
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: AliasVecB = ssz_types::FixedBytes(
+                            self.c()?.to_owned(),
+                        );
+                        value == other.c
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<Alpha> for AlphaRef<'a> {
+                fn eq(&self, other: &Alpha) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<AlphaRef<'a>> for Alpha {
+                fn eq(&self, other: &AlphaRef<'a>) -> bool {
+                    other == self
+                }
             }
             #[derive(
                 std::clone::Clone,
@@ -1378,6 +1907,32 @@ pub mod tests {
                         .expect("tree hash derive should not have a remaining buffer")
                 }
             }
+            impl tree_hash::TreeHashLeaves for Beta {
+                fn tree_hash_leaf_count() -> usize {
+                    3usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.d)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.e)
+                        }
+                        2usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.f)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 3usize
+                            )
+                        }
+                    }
+                }
+            }
             /// Zero-copy view over [`Beta`].
             ///
             /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
@@ -1416,6 +1971,7 @@ pub mod tests {
                         0usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn e(&self) -> Result<u8, ssz::DecodeError> {
                     let bytes = ssz::layout::read_field_bytes(
@@ -1437,6 +1993,7 @@ pub mod tests {
                         1usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn f(&self) -> Result<u16, ssz::DecodeError> {
                     let bytes = ssz::layout::read_field_bytes(
@@ -1458,6 +2015,22 @@ pub mod tests {
                         2usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> BetaRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.d()?;
+                    self.e()?;
+                    self.f()?;
+                    Ok(())
                 }
             }
             impl<'a> tree_hash::TreeHash for BetaRef<'a> {
@@ -1519,9 +2092,22 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
-            impl<'a> ssz::view::SszTypeInfo for BetaRef<'a> {
-                fn is_ssz_fixed_len() -> bool {
-                    usize::from(!<AliasListAlias as ssz::Encode>::is_ssz_fixed_len())
+            impl<'a> TryFrom<&'a [u8]> for BetaRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> BetaRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
+            impl<'a> ssz::view::SszTypeInfo for BetaRef<'a> {
+                fn is_ssz_fixed_len() -> bool {
+                    usize::from(!<AliasListAlias as ssz::Encode>::is_ssz_fixed_len())
                         + usize::from(!<u8 as ssz::Encode>::is_ssz_fixed_len())
                         + usize::from(
                             !<AliasUintAlias as ssz::Encode>::is_ssz_fixed_len(),
@@ -1563,6 +2149,66 @@ pub mod tests {
                         f: self.f().expect("valid view"),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Beta,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    let view = self.d()?;
+                    out.d.clear();
+                    out.d.try_extend_from_slice(view.as_bytes()).expect("valid view");
+                    out.e = self.e()?;
+                    out.f = self.f()?;
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> BetaRef<'a> {
+                fn eq_owned(&self, other: &Beta) -> Result<bool, ssz::DecodeError> {
+                    if !({
+                        let value: AliasListAlias = ssz_types::VariableList::new(
+                                self.d()?.to_owned(),
+                            )
+                            .expect("valid view");
+                        value == other.d
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: u8 = self.e()?;
+                        value == other.e
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: AliasUintAlias = self.f()?;
+                        value == other.f
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<Beta> for BetaRef<'a> {
+                fn eq(&self, other: &Beta) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<BetaRef<'a>> for Beta {
+                fn eq(&self, other: &BetaRef<'a>) -> bool {
+                    other == self
+                }
             }
             #[derive(
                 std::clone::Clone,
@@ -1648,9 +2294,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..6usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 6usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 6usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         42usize,
@@ -1684,8 +2329,9 @@ pub mod tests {
                         None => return Ok(ssz_types::Optional::None),
                     };
                     let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(
-                        field_bytes,
-                    )?;
+                            field_bytes,
+                        )
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
                 pub fn h(
@@ -1695,9 +2341,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..6usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 6usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 6usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         42usize,
@@ -1734,10 +2379,30 @@ pub mod tests {
                         'a,
                         u16,
                         8usize,
-                    > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)?;
+                    > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
             }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> GammaRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.g()?;
+                    let value = self.h()?;
+                    if let ssz_types::Optional::Some(inner) = value {
+                        for item in inner.iter() {
+                            item?;
+                        }
+                    }
+                    Ok(())
+                }
+            }
             impl<'a> tree_hash::TreeHash for GammaRef<'a> {
                 fn tree_hash_type() -> tree_hash::TreeHashType {
                     tree_hash::TreeHashType::StableContainer
@@ -1793,9 +2458,8 @@ pub mod tests {
                     use ssz::Decode;
                     let bitvector_bytes = bytes
                         .get(..6usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: bytes.len(),
-                            expected: 6usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 6usize - bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         42usize,
@@ -1837,6 +2501,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for GammaRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> GammaRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for GammaRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     false
@@ -1874,6 +2551,86 @@ pub mod tests {
                         },
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Gamma,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.g = self.g()?;
+                    out.h = match self.h()? {
+                        ssz_types::Optional::Some(inner) => {
+                            ssz_types::Optional::Some(
+                                ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                            )
+                        }
+                        ssz_types::Optional::None => ssz_types::Optional::None,
+                    };
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> GammaRef<'a> {
+                fn eq_owned(&self, other: &Gamma) -> Result<bool, ssz::DecodeError> {
+                    use ssz::Decode;
+                    let bitvector_bytes = self
+                        .bytes
+                        .get(..6usize)
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 6usize - self.bytes.len(),
+                        })?;
+                    let bitvector = ssz_types::BitVector::<
+                        42usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    if bitvector.get(0usize).unwrap_or(false) != other.g.is_some() {
+                        return Ok(false);
+                    }
+                    if bitvector.get(1usize).unwrap_or(false) != other.h.is_some() {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<u8> = self.g()?;
+                        value == other.g
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<VariableList<AliasUintAlias, 8usize>> = match self
+                            .h()?
+                        {
+                            ssz_types::Optional::Some(inner) => {
+                                ssz_types::Optional::Some(
+                                    ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                                )
+                            }
+                            ssz_types::Optional::None => ssz_types::Optional::None,
+                        };
+                        value == other.h
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<Gamma> for GammaRef<'a> {
+                fn eq(&self, other: &Gamma) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<GammaRef<'a>> for Gamma {
+                fn eq(&self, other: &GammaRef<'a>) -> bool {
+                    other == self
+                }
             }
             #[derive(
                 std::clone::Clone,
@@ -1888,6 +2645,12 @@ pub mod tests {
                 pub z: bool,
                 pub w: u8,
             }
+            impl Delta {
+                /// Byte offset of each field in the SSZ encoding, in declaration order.
+                pub const FIELD_OFFSETS: &[usize] = &[0, 1];
+                /// Byte size of each field in the SSZ encoding, in declaration order.
+                pub const FIELD_SIZES: &[usize] = &[1, 1];
+            }
             impl tree_hash::TreeHash for Delta {
                 fn tree_hash_type() -> tree_hash::TreeHashType {
                     tree_hash::TreeHashType::Container
@@ -1918,6 +2681,29 @@ pub mod tests {
                         .expect("tree hash derive should not have a remaining buffer")
                 }
             }
+            impl tree_hash::TreeHashLeaves for Delta {
+                fn tree_hash_leaf_count() -> usize {
+                    2usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.z)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.w)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 2usize
+                            )
+                        }
+                    }
+                }
+            }
             /// Zero-copy view over [`Delta`].
             ///
             /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
@@ -1937,38 +2723,28 @@ pub mod tests {
             #[allow(dead_code, reason = "generated code using ssz-gen")]
             impl<'a> DeltaRef<'a> {
                 pub fn z(&self) -> Result<bool, ssz::DecodeError> {
-                    let bytes = ssz::layout::read_field_bytes(
-                        self.bytes,
-                        &[
-                            (
-                                <bool as ssz::Encode>::is_ssz_fixed_len(),
-                                <bool as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                            (
-                                <u8 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u8 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                        ],
-                        0usize,
-                    )?;
+                    let bytes = &self.bytes[0..1];
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn w(&self) -> Result<u8, ssz::DecodeError> {
-                    let bytes = ssz::layout::read_field_bytes(
-                        self.bytes,
-                        &[
-                            (
-                                <bool as ssz::Encode>::is_ssz_fixed_len(),
-                                <bool as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                            (
-                                <u8 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u8 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                        ],
-                        1usize,
-                    )?;
+                    let bytes = &self.bytes[1..2];
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> DeltaRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.z()?;
+                    self.w()?;
+                    Ok(())
                 }
             }
             impl<'a> tree_hash::TreeHash for DeltaRef<'a> {
@@ -2019,6 +2795,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for DeltaRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> DeltaRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for DeltaRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     usize::from(!<bool as ssz::Encode>::is_ssz_fixed_len())
@@ -2055,6 +2844,54 @@ pub mod tests {
                         w: self.w().expect("valid view"),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Delta,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.z = self.z()?;
+                    out.w = self.w()?;
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> DeltaRef<'a> {
+                fn eq_owned(&self, other: &Delta) -> Result<bool, ssz::DecodeError> {
+                    if !({
+                        let value: bool = self.z()?;
+                        value == other.z
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: u8 = self.w()?;
+                        value == other.w
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<Delta> for DeltaRef<'a> {
+                fn eq(&self, other: &Delta) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<DeltaRef<'a>> for Delta {
+                fn eq(&self, other: &DeltaRef<'a>) -> bool {
+                    other == self
+                }
             }
             #[derive(
                 std::clone::Clone,
@@ -2164,9 +3001,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..6usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 6usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 6usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         42usize,
@@ -2210,8 +3046,9 @@ pub mod tests {
                         None => return Ok(ssz_types::Optional::None),
                     };
                     let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(
-                        field_bytes,
-                    )?;
+                            field_bytes,
+                        )
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
                 pub fn h(
@@ -2221,9 +3058,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..6usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 6usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 6usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         42usize,
@@ -2270,7 +3106,8 @@ pub mod tests {
                         'a,
                         u16,
                         8usize,
-                    > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)?;
+                    > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
                 pub fn i(&self) -> Result<Optional<u8>, ssz::DecodeError> {
@@ -2278,9 +3115,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..6usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 6usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 6usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         42usize,
@@ -2324,8 +3160,9 @@ pub mod tests {
                         None => return Ok(ssz_types::Optional::None),
                     };
                     let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(
-                        field_bytes,
-                    )?;
+                            field_bytes,
+                        )
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
                 pub fn j(&self) -> Result<Optional<u16>, ssz::DecodeError> {
@@ -2333,9 +3170,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..6usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 6usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 6usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         42usize,
@@ -2379,11 +3215,33 @@ pub mod tests {
                         None => return Ok(ssz_types::Optional::None),
                     };
                     let inner = <u16 as ssz::view::DecodeView>::from_ssz_bytes(
-                        field_bytes,
-                    )?;
+                            field_bytes,
+                        )
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
             }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> EpsilonRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.g()?;
+                    let value = self.h()?;
+                    if let ssz_types::Optional::Some(inner) = value {
+                        for item in inner.iter() {
+                            item?;
+                        }
+                    }
+                    self.i()?;
+                    self.j()?;
+                    Ok(())
+                }
+            }
             impl<'a> tree_hash::TreeHash for EpsilonRef<'a> {
                 fn tree_hash_type() -> tree_hash::TreeHashType {
                     tree_hash::TreeHashType::StableContainer
@@ -2463,9 +3321,8 @@ pub mod tests {
                     use ssz::Decode;
                     let bitvector_bytes = bytes
                         .get(..6usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: bytes.len(),
-                            expected: 6usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 6usize - bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         42usize,
@@ -2517,6 +3374,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for EpsilonRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> EpsilonRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for EpsilonRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     false
@@ -2556,6 +3426,106 @@ pub mod tests {
                         j: self.j().expect("valid view"),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Epsilon,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.g = self.g()?;
+                    out.h = match self.h()? {
+                        ssz_types::Optional::Some(inner) => {
+                            ssz_types::Optional::Some(
+                                ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                            )
+                        }
+                        ssz_types::Optional::None => ssz_types::Optional::None,
+                    };
+                    out.i = self.i()?;
+                    out.j = self.j()?;
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> EpsilonRef<'a> {
+                fn eq_owned(&self, other: &Epsilon) -> Result<bool, ssz::DecodeError> {
+                    use ssz::Decode;
+                    let bitvector_bytes = self
+                        .bytes
+                        .get(..6usize)
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 6usize - self.bytes.len(),
+                        })?;
+                    let bitvector = ssz_types::BitVector::<
+                        42usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    if bitvector.get(0usize).unwrap_or(false) != other.g.is_some() {
+                        return Ok(false);
+                    }
+                    if bitvector.get(1usize).unwrap_or(false) != other.h.is_some() {
+                        return Ok(false);
+                    }
+                    if bitvector.get(2usize).unwrap_or(false) != other.i.is_some() {
+                        return Ok(false);
+                    }
+                    if bitvector.get(3usize).unwrap_or(false) != other.j.is_some() {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<u8> = self.g()?;
+                        value == other.g
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<VariableList<AliasUintAlias, 8usize>> = match self
+                            .h()?
+                        {
+                            ssz_types::Optional::Some(inner) => {
+                                ssz_types::Optional::Some(
+                                    ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                                )
+                            }
+                            ssz_types::Optional::None => ssz_types::Optional::None,
+                        };
+                        value == other.h
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<u8> = self.i()?;
+                        value == other.i
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<AliasNested> = self.j()?;
+                        value == other.j
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<Epsilon> for EpsilonRef<'a> {
+                fn eq(&self, other: &Epsilon) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<EpsilonRef<'a>> for Epsilon {
+                fn eq(&self, other: &EpsilonRef<'a>) -> bool {
+                    other == self
+                }
             }
             #[derive(
                 std::clone::Clone,
@@ -2643,9 +3613,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..16usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 16usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 16usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         128usize,
@@ -2683,7 +3652,8 @@ pub mod tests {
                     let inner = <FixedBytesRef<
                         'a,
                         16usize,
-                    > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)?;
+                    > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
                 pub fn v(
@@ -2693,9 +3663,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..16usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 16usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 16usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         128usize,
@@ -2733,10 +3702,25 @@ pub mod tests {
                     let inner = <BytesRef<
                         'a,
                         5usize,
-                    > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)?;
+                    > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
             }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ZetaRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.u()?;
+                    self.v()?;
+                    Ok(())
+                }
+            }
             impl<'a> tree_hash::TreeHash for ZetaRef<'a> {
                 fn tree_hash_type() -> tree_hash::TreeHashType {
                     tree_hash::TreeHashType::StableContainer
@@ -2792,9 +3776,8 @@ pub mod tests {
                     use ssz::Decode;
                     let bitvector_bytes = bytes
                         .get(..16usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: bytes.len(),
-                            expected: 16usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 16usize - bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         128usize,
@@ -2838,6 +3821,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for ZetaRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ZetaRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for ZetaRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     false
@@ -2882,6 +3878,98 @@ pub mod tests {
                         },
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Zeta,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.u = match self.u()? {
+                        ssz_types::Optional::Some(inner) => {
+                            ssz_types::Optional::Some(
+                                ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                            )
+                        }
+                        ssz_types::Optional::None => ssz_types::Optional::None,
+                    };
+                    out.v = match self.v()? {
+                        ssz_types::Optional::Some(inner) => {
+                            ssz_types::Optional::Some(
+                                ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                            )
+                        }
+                        ssz_types::Optional::None => ssz_types::Optional::None,
+                    };
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ZetaRef<'a> {
+                fn eq_owned(&self, other: &Zeta) -> Result<bool, ssz::DecodeError> {
+                    use ssz::Decode;
+                    let bitvector_bytes = self
+                        .bytes
+                        .get(..16usize)
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 16usize - self.bytes.len(),
+                        })?;
+                    let bitvector = ssz_types::BitVector::<
+                        128usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    if bitvector.get(0usize).unwrap_or(false) != other.u.is_some() {
+                        return Ok(false);
+                    }
+                    if bitvector.get(1usize).unwrap_or(false) != other.v.is_some() {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<FixedBytes<16usize>> = match self.u()? {
+                            ssz_types::Optional::Some(inner) => {
+                                ssz_types::Optional::Some(
+                                    ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                                )
+                            }
+                            ssz_types::Optional::None => ssz_types::Optional::None,
+                        };
+                        value == other.u
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<AliasListAlias> = match self.v()? {
+                            ssz_types::Optional::Some(inner) => {
+                                ssz_types::Optional::Some(
+                                    ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                                )
+                            }
+                            ssz_types::Optional::None => ssz_types::Optional::None,
+                        };
+                        value == other.v
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<Zeta> for ZetaRef<'a> {
+                fn eq(&self, other: &Zeta) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<ZetaRef<'a>> for Zeta {
+                fn eq(&self, other: &ZetaRef<'a>) -> bool {
+                    other == self
+                }
             }
             #[derive(
                 std::clone::Clone,
@@ -2951,6 +4039,42 @@ pub mod tests {
                         .expect("tree hash derive should not have a remaining buffer")
                 }
             }
+            impl tree_hash::TreeHashLeaves for TestType {
+                fn tree_hash_leaf_count() -> usize {
+                    5usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.ccc)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.ddd)
+                        }
+                        2usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.eee)
+                        }
+                        3usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<
+                                H,
+                            >(&self.large_int_128)
+                        }
+                        4usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<
+                                H,
+                            >(&self.large_int_256)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 5usize
+                            )
+                        }
+                    }
+                }
+            }
             /// Zero-copy view over [`TestType`].
             ///
             /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
@@ -3000,6 +4124,7 @@ pub mod tests {
                         0usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn ddd(&self) -> Result<u8, ssz::DecodeError> {
                     let bytes = ssz::layout::read_field_bytes(
@@ -3032,6 +4157,7 @@ pub mod tests {
                         1usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn eee(&self) -> Result<ListRef<'a, u16, 3usize>, ssz::DecodeError> {
                     let bytes = ssz::layout::read_field_bytes(
@@ -3064,6 +4190,7 @@ pub mod tests {
                         2usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn large_int_128(&self) -> Result<U128, ssz::DecodeError> {
                     let bytes = ssz::layout::read_field_bytes(
@@ -3096,6 +4223,7 @@ pub mod tests {
                         3usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn large_int_256(&self) -> Result<U256, ssz::DecodeError> {
                     let bytes = ssz::layout::read_field_bytes(
@@ -3128,6 +4256,27 @@ pub mod tests {
                         4usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> TestTypeRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.ccc()?;
+                    self.ddd()?;
+                    let value = self.eee()?;
+                    for item in value.iter() {
+                        item?;
+                    }
+                    self.large_int_128()?;
+                    self.large_int_256()?;
+                    Ok(())
                 }
             }
             impl<'a> tree_hash::TreeHash for TestTypeRef<'a> {
@@ -3214,6 +4363,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for TestTypeRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> TestTypeRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for TestTypeRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     usize::from(!<u8 as ssz::Encode>::is_ssz_fixed_len())
@@ -3274,6 +4436,92 @@ pub mod tests {
                         large_int_256: self.large_int_256().expect("valid view"),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut TestType,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.ccc = self.ccc()?;
+                    out.ddd = self.ddd()?;
+                    let view = self.eee()?;
+                    out.eee.clear();
+                    for item in view.iter() {
+                        out.eee
+                            .push(ssz_types::view::ToOwnedSsz::to_owned(&item?))
+                            .expect("valid view");
+                    }
+                    out.large_int_128 = self.large_int_128()?;
+                    out.large_int_256 = self.large_int_256()?;
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> TestTypeRef<'a> {
+                fn eq_owned(&self, other: &TestType) -> Result<bool, ssz::DecodeError> {
+                    if !({
+                        let value: u8 = self.ccc()?;
+                        value == other.ccc
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: u8 = self.ddd()?;
+                        value == other.ddd
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: VariableList<u16, 3usize> = {
+                            let view = self.eee()?;
+                            let items: Result<Vec<_>, _> = view
+                                .iter()
+                                .map(|item_result| {
+                                    item_result
+                                        .map(|item| ssz_types::view::ToOwnedSsz::to_owned(&item))
+                                })
+                                .collect();
+                            let items = items.expect("valid view");
+                            ssz_types::VariableList::new(items).expect("valid view")
+                        };
+                        value == other.eee
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: U128 = self.large_int_128()?;
+                        value == other.large_int_128
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: U256 = self.large_int_256()?;
+                        value == other.large_int_256
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<TestType> for TestTypeRef<'a> {
+                fn eq(&self, other: &TestType) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<TestTypeRef<'a>> for TestType {
+                fn eq(&self, other: &TestTypeRef<'a>) -> bool {
+                    other == self
+                }
             }
             #[derive(
                 std::clone::Clone,
@@ -3325,6 +4573,32 @@ pub mod tests {
                         .expect("tree hash derive should not have a remaining buffer")
                 }
             }
+            impl tree_hash::TreeHashLeaves for Eta {
+                fn tree_hash_leaf_count() -> usize {
+                    3usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.l)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.m)
+                        }
+                        2usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.n)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 3usize
+                            )
+                        }
+                    }
+                }
+            }
             /// Zero-copy view over [`Eta`].
             ///
             /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
@@ -3363,6 +4637,7 @@ pub mod tests {
                         0usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn m(&self) -> Result<TestTypeRef<'a>, ssz::DecodeError> {
                     let bytes = ssz::layout::read_field_bytes(
@@ -3384,6 +4659,7 @@ pub mod tests {
                         1usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn n(&self) -> Result<FirstUnionRef<'a>, ssz::DecodeError> {
                     let bytes = ssz::layout::read_field_bytes(
@@ -3405,6 +4681,25 @@ pub mod tests {
                         2usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> EtaRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    let value = self.l()?;
+                    value.validate_deep()?;
+                    let value = self.m()?;
+                    value.validate_deep()?;
+                    let value = self.n()?;
+                    value.validate_deep()?;
+                    Ok(())
                 }
             }
             impl<'a> tree_hash::TreeHash for EtaRef<'a> {
@@ -3466,6 +4761,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for EtaRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> EtaRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for EtaRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     usize::from(!<Zeta as ssz::Encode>::is_ssz_fixed_len())
@@ -3515,6 +4823,67 @@ pub mod tests {
                         },
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Eta,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.l = {
+                        let view = self.l()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    out.m = {
+                        let view = self.m()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    out.n = {
+                        let view = self.n()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> EtaRef<'a> {
+                fn eq_owned(&self, other: &Eta) -> Result<bool, ssz::DecodeError> {
+                    if !(self.l()? == other.l) {
+                        return Ok(false);
+                    }
+                    if !(self.m()? == other.m) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: FirstUnion = {
+                            let view = self.n()?;
+                            ssz_types::view::ToOwnedSsz::to_owned(&view)
+                        };
+                        value == other.n
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<Eta> for EtaRef<'a> {
+                fn eq(&self, other: &Eta) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<EtaRef<'a>> for Eta {
+                fn eq(&self, other: &EtaRef<'a>) -> bool {
+                    other == self
+                }
             }
             #[derive(
                 std::clone::Clone,
@@ -3566,6 +4935,32 @@ pub mod tests {
                         .expect("tree hash derive should not have a remaining buffer")
                 }
             }
+            impl tree_hash::TreeHashLeaves for Theta {
+                fn tree_hash_leaf_count() -> usize {
+                    3usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.o)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.p)
+                        }
+                        2usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.q)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 3usize
+                            )
+                        }
+                    }
+                }
+            }
             /// Zero-copy view over [`Theta`].
             ///
             /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
@@ -3604,6 +4999,7 @@ pub mod tests {
                         0usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn p(&self) -> Result<UnionCRef<'a>, ssz::DecodeError> {
                     let bytes = ssz::layout::read_field_bytes(
@@ -3625,6 +5021,7 @@ pub mod tests {
                         1usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn q(&self) -> Result<FixedBytesRef<'a, 10usize>, ssz::DecodeError> {
                     let bytes = ssz::layout::read_field_bytes(
@@ -3646,6 +5043,24 @@ pub mod tests {
                         2usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ThetaRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    let value = self.o()?;
+                    value.validate_deep()?;
+                    let value = self.p()?;
+                    value.validate_deep()?;
+                    self.q()?;
+                    Ok(())
                 }
             }
             impl<'a> tree_hash::TreeHash for ThetaRef<'a> {
@@ -3707,6 +5122,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for ThetaRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ThetaRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for ThetaRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     usize::from(!<UnionB as ssz::Encode>::is_ssz_fixed_len())
@@ -3755,6 +5183,75 @@ pub mod tests {
                         ),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Theta,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.o = {
+                        let view = self.o()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    out.p = {
+                        let view = self.p()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    out.q = ssz_types::FixedBytes(self.q()?.to_owned());
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ThetaRef<'a> {
+                fn eq_owned(&self, other: &Theta) -> Result<bool, ssz::DecodeError> {
+                    if !({
+                        let value: UnionB = {
+                            let view = self.o()?;
+                            ssz_types::view::ToOwnedSsz::to_owned(&view)
+                        };
+                        value == other.o
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: UnionC = {
+                            let view = self.p()?;
+                            ssz_types::view::ToOwnedSsz::to_owned(&view)
+                        };
+                        value == other.p
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: AliasVecA = ssz_types::FixedBytes(
+                            self.q()?.to_owned(),
+                        );
+                        value == other.q
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<Theta> for ThetaRef<'a> {
+                fn eq(&self, other: &Theta) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<ThetaRef<'a>> for Theta {
+                fn eq(&self, other: &ThetaRef<'a>) -> bool {
+                    other == self
+                }
             }
             #[derive(
                 std::clone::Clone,
@@ -3888,9 +5385,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..6usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 6usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 6usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         42usize,
@@ -3948,8 +5444,9 @@ pub mod tests {
                         None => return Ok(ssz_types::Optional::None),
                     };
                     let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(
-                        field_bytes,
-                    )?;
+                            field_bytes,
+                        )
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
                 pub fn h(
@@ -3959,9 +5456,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..6usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 6usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 6usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         42usize,
@@ -4022,7 +5518,8 @@ pub mod tests {
                         'a,
                         u16,
                         8usize,
-                    > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)?;
+                    > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
                 pub fn i(&self) -> Result<Optional<u8>, ssz::DecodeError> {
@@ -4030,9 +5527,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..6usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 6usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 6usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         42usize,
@@ -4090,8 +5586,9 @@ pub mod tests {
                         None => return Ok(ssz_types::Optional::None),
                     };
                     let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(
-                        field_bytes,
-                    )?;
+                            field_bytes,
+                        )
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
                 pub fn j(&self) -> Result<Optional<u16>, ssz::DecodeError> {
@@ -4099,9 +5596,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..6usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 6usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 6usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         42usize,
@@ -4159,8 +5655,9 @@ pub mod tests {
                         None => return Ok(ssz_types::Optional::None),
                     };
                     let inner = <u16 as ssz::view::DecodeView>::from_ssz_bytes(
-                        field_bytes,
-                    )?;
+                            field_bytes,
+                        )
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
                 pub fn r(
@@ -4170,9 +5667,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..6usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 6usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 6usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         42usize,
@@ -4233,7 +5729,8 @@ pub mod tests {
                         'a,
                         u16,
                         2usize,
-                    > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)?;
+                    > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
                 pub fn s(&self) -> Result<Optional<u8>, ssz::DecodeError> {
@@ -4241,9 +5738,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..6usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 6usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 6usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         42usize,
@@ -4301,11 +5797,40 @@ pub mod tests {
                         None => return Ok(ssz_types::Optional::None),
                     };
                     let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(
-                        field_bytes,
-                    )?;
+                            field_bytes,
+                        )
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
             }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> IotaRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.g()?;
+                    let value = self.h()?;
+                    if let ssz_types::Optional::Some(inner) = value {
+                        for item in inner.iter() {
+                            item?;
+                        }
+                    }
+                    self.i()?;
+                    self.j()?;
+                    let value = self.r()?;
+                    if let ssz_types::Optional::Some(inner) = value {
+                        for item in inner.iter() {
+                            item?;
+                        }
+                    }
+                    self.s()?;
+                    Ok(())
+                }
+            }
             impl<'a> tree_hash::TreeHash for IotaRef<'a> {
                 fn tree_hash_type() -> tree_hash::TreeHashType {
                     tree_hash::TreeHashType::StableContainer
@@ -4409,9 +5934,8 @@ pub mod tests {
                     use ssz::Decode;
                     let bitvector_bytes = bytes
                         .get(..6usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: bytes.len(),
-                            expected: 6usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 6usize - bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         42usize,
@@ -4477,6 +6001,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for IotaRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> IotaRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for IotaRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     false
@@ -4525,6 +6062,142 @@ pub mod tests {
                         s: self.s().expect("valid view"),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Iota,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.g = self.g()?;
+                    out.h = match self.h()? {
+                        ssz_types::Optional::Some(inner) => {
+                            ssz_types::Optional::Some(
+                                ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                            )
+                        }
+                        ssz_types::Optional::None => ssz_types::Optional::None,
+                    };
+                    out.i = self.i()?;
+                    out.j = self.j()?;
+                    out.r = match self.r()? {
+                        ssz_types::Optional::Some(inner) => {
+                            ssz_types::Optional::Some(
+                                ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                            )
+                        }
+                        ssz_types::Optional::None => ssz_types::Optional::None,
+                    };
+                    out.s = self.s()?;
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> IotaRef<'a> {
+                fn eq_owned(&self, other: &Iota) -> Result<bool, ssz::DecodeError> {
+                    use ssz::Decode;
+                    let bitvector_bytes = self
+                        .bytes
+                        .get(..6usize)
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 6usize - self.bytes.len(),
+                        })?;
+                    let bitvector = ssz_types::BitVector::<
+                        42usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    if bitvector.get(0usize).unwrap_or(false) != other.g.is_some() {
+                        return Ok(false);
+                    }
+                    if bitvector.get(1usize).unwrap_or(false) != other.h.is_some() {
+                        return Ok(false);
+                    }
+                    if bitvector.get(2usize).unwrap_or(false) != other.i.is_some() {
+                        return Ok(false);
+                    }
+                    if bitvector.get(3usize).unwrap_or(false) != other.j.is_some() {
+                        return Ok(false);
+                    }
+                    if bitvector.get(4usize).unwrap_or(false) != other.r.is_some() {
+                        return Ok(false);
+                    }
+                    if bitvector.get(5usize).unwrap_or(false) != other.s.is_some() {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<u8> = self.g()?;
+                        value == other.g
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<VariableList<AliasUintAlias, 8usize>> = match self
+                            .h()?
+                        {
+                            ssz_types::Optional::Some(inner) => {
+                                ssz_types::Optional::Some(
+                                    ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                                )
+                            }
+                            ssz_types::Optional::None => ssz_types::Optional::None,
+                        };
+                        value == other.h
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<u8> = self.i()?;
+                        value == other.i
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<AliasNested> = self.j()?;
+                        value == other.j
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<VariableList<AliasNested, 2usize>> = match self
+                            .r()?
+                        {
+                            ssz_types::Optional::Some(inner) => {
+                                ssz_types::Optional::Some(
+                                    ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                                )
+                            }
+                            ssz_types::Optional::None => ssz_types::Optional::None,
+                        };
+                        value == other.r
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<u8> = self.s()?;
+                        value == other.s
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<Iota> for IotaRef<'a> {
+                fn eq(&self, other: &Iota) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<IotaRef<'a>> for Iota {
+                fn eq(&self, other: &IotaRef<'a>) -> bool {
+                    other == self
+                }
             }
             #[derive(
                 std::clone::Clone,
@@ -4576,6 +6249,32 @@ pub mod tests {
                         .expect("tree hash derive should not have a remaining buffer")
                 }
             }
+            impl tree_hash::TreeHashLeaves for Kappa {
+                fn tree_hash_leaf_count() -> usize {
+                    3usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.t)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.u)
+                        }
+                        2usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.v)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 3usize
+                            )
+                        }
+                    }
+                }
+            }
             /// Zero-copy view over [`Kappa`].
             ///
             /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
@@ -4614,6 +6313,7 @@ pub mod tests {
                         0usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn u(&self) -> Result<BetaRef<'a>, ssz::DecodeError> {
                     let bytes = ssz::layout::read_field_bytes(
@@ -4635,6 +6335,7 @@ pub mod tests {
                         1usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn v(&self) -> Result<BitVectorRef<'a, 64usize>, ssz::DecodeError> {
                     let bytes = ssz::layout::read_field_bytes(
@@ -4656,6 +6357,24 @@ pub mod tests {
                         2usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> KappaRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    let value = self.t()?;
+                    value.validate_deep()?;
+                    let value = self.u()?;
+                    value.validate_deep()?;
+                    self.v()?;
+                    Ok(())
                 }
             }
             impl<'a> tree_hash::TreeHash for KappaRef<'a> {
@@ -4717,6 +6436,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for KappaRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> KappaRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for KappaRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     usize::from(!<Alpha as ssz::Encode>::is_ssz_fixed_len())
@@ -4764,6 +6496,61 @@ pub mod tests {
                         v: self.v().expect("valid view").to_owned(),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Kappa,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.t = {
+                        let view = self.t()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    out.u = {
+                        let view = self.u()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    out.v = self.v()?.to_owned();
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> KappaRef<'a> {
+                fn eq_owned(&self, other: &Kappa) -> Result<bool, ssz::DecodeError> {
+                    if !(self.t()? == other.t) {
+                        return Ok(false);
+                    }
+                    if !(self.u()? == other.u) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: BitVector<64usize> = self.v()?.to_owned();
+                        value == other.v
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<Kappa> for KappaRef<'a> {
+                fn eq(&self, other: &Kappa) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<KappaRef<'a>> for Kappa {
+                fn eq(&self, other: &KappaRef<'a>) -> bool {
+                    other == self
+                }
             }
             #[derive(
                 std::clone::Clone,
@@ -4849,9 +6636,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         4usize,
@@ -4881,8 +6667,9 @@ pub mod tests {
                         None => return Ok(ssz_types::Optional::None),
                     };
                     let inner = <u16 as ssz::view::DecodeView>::from_ssz_bytes(
-                        field_bytes,
-                    )?;
+                            field_bytes,
+                        )
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
                 pub fn x(&self) -> Result<Optional<u8>, ssz::DecodeError> {
@@ -4890,9 +6677,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         4usize,
@@ -4922,11 +6708,26 @@ pub mod tests {
                         None => return Ok(ssz_types::Optional::None),
                     };
                     let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(
-                        field_bytes,
-                    )?;
+                            field_bytes,
+                        )
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
             }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> LambdaRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.w()?;
+                    self.x()?;
+                    Ok(())
+                }
+            }
             impl<'a> tree_hash::TreeHash for LambdaRef<'a> {
                 fn tree_hash_type() -> tree_hash::TreeHashType {
                     tree_hash::TreeHashType::StableContainer
@@ -4982,9 +6783,8 @@ pub mod tests {
                     use ssz::Decode;
                     let bitvector_bytes = bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         4usize,
@@ -5022,6 +6822,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for LambdaRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> LambdaRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for LambdaRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     false
@@ -5052,6 +6865,70 @@ pub mod tests {
                         x: self.x().expect("valid view"),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Lambda,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.w = self.w()?;
+                    out.x = self.x()?;
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> LambdaRef<'a> {
+                fn eq_owned(&self, other: &Lambda) -> Result<bool, ssz::DecodeError> {
+                    use ssz::Decode;
+                    let bitvector_bytes = self
+                        .bytes
+                        .get(..1usize)
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
+                        })?;
+                    let bitvector = ssz_types::BitVector::<
+                        4usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    if bitvector.get(0usize).unwrap_or(false) != other.w.is_some() {
+                        return Ok(false);
+                    }
+                    if bitvector.get(1usize).unwrap_or(false) != other.x.is_some() {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<u16> = self.w()?;
+                        value == other.w
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<u8> = self.x()?;
+                        value == other.x
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<Lambda> for LambdaRef<'a> {
+                fn eq(&self, other: &Lambda) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<LambdaRef<'a>> for Lambda {
+                fn eq(&self, other: &LambdaRef<'a>) -> bool {
+                    other == self
+                }
             }
             #[derive(
                 std::clone::Clone,
@@ -5096,6 +6973,29 @@ pub mod tests {
                         .expect("tree hash derive should not have a remaining buffer")
                 }
             }
+            impl tree_hash::TreeHashLeaves for Mu {
+                fn tree_hash_leaf_count() -> usize {
+                    2usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.y)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.z)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 2usize
+                            )
+                        }
+                    }
+                }
+            }
             /// Zero-copy view over [`Mu`].
             ///
             /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
@@ -5130,6 +7030,7 @@ pub mod tests {
                         0usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn z(&self) -> Result<UnionARef<'a>, ssz::DecodeError> {
                     let bytes = ssz::layout::read_field_bytes(
@@ -5147,6 +7048,23 @@ pub mod tests {
                         1usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> MuRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    let value = self.y()?;
+                    value.validate_deep()?;
+                    let value = self.z()?;
+                    value.validate_deep()?;
+                    Ok(())
                 }
             }
             impl<'a> tree_hash::TreeHash for MuRef<'a> {
@@ -5197,6 +7115,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for MuRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> MuRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for MuRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     usize::from(!<Lambda as ssz::Encode>::is_ssz_fixed_len())
@@ -5239,6 +7170,60 @@ pub mod tests {
                         },
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Mu,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.y = {
+                        let view = self.y()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    out.z = {
+                        let view = self.z()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> MuRef<'a> {
+                fn eq_owned(&self, other: &Mu) -> Result<bool, ssz::DecodeError> {
+                    if !(self.y()? == other.y) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: UnionA = {
+                            let view = self.z()?;
+                            ssz_types::view::ToOwnedSsz::to_owned(&view)
+                        };
+                        value == other.z
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<Mu> for MuRef<'a> {
+                fn eq(&self, other: &Mu) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<MuRef<'a>> for Mu {
+                fn eq(&self, other: &MuRef<'a>) -> bool {
+                    other == self
+                }
             }
             pub type AliasMu = Mu;
             #[derive(
@@ -5298,6 +7283,35 @@ pub mod tests {
                         .expect("tree hash derive should not have a remaining buffer")
                 }
             }
+            impl tree_hash::TreeHashLeaves for Nu {
+                fn tree_hash_leaf_count() -> usize {
+                    4usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.zz)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.aaa)
+                        }
+                        2usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.bbb)
+                        }
+                        3usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.test)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 4usize
+                            )
+                        }
+                    }
+                }
+            }
             /// Zero-copy view over [`Nu`].
             ///
             /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
@@ -5343,6 +7357,7 @@ pub mod tests {
                         0usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn aaa(
                     &self,
@@ -5373,6 +7388,7 @@ pub mod tests {
                         1usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn bbb(&self) -> Result<BitListRef<'a, 42usize>, ssz::DecodeError> {
                     let bytes = ssz::layout::read_field_bytes(
@@ -5401,6 +7417,7 @@ pub mod tests {
                         2usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn test(&self) -> Result<Option<AliasMuRef<'a>>, ssz::DecodeError> {
                     let bytes = ssz::layout::read_field_bytes(
@@ -5428,19 +7445,22 @@ pub mod tests {
                         ],
                         3usize,
                     )?;
-                    if bytes.is_empty() {
-                        return Err(ssz::DecodeError::InvalidByteLength {
-                            len: 0,
-                            expected: 1,
-                        });
-                    }
+                    ssz::view::reject_empty(bytes, 1)?;
                     let selector = bytes[0];
                     match selector {
-                        0 => Ok(None),
+                        0 => ssz::check_union_body_len(&bytes[1..], 0).map(|()| None),
                         1 => {
+                            if <AliasMu as ssz::Encode>::is_ssz_fixed_len() {
+                                ssz::check_union_body_len(
+                                    &bytes[1..],
+                                    <AliasMu as ssz::Encode>::ssz_fixed_len(),
+                                )?;
+                            }
+                            let body = &bytes[1..];
                             let inner = <AliasMuRef<
                                 'a,
-                            > as ssz::view::DecodeView>::from_ssz_bytes(&bytes[1..])?;
+                            > as ssz::view::DecodeView>::from_ssz_bytes(body)
+                                .map_err(|e| e.bounded(body.len()))?;
                             Ok(Some(inner))
                         }
                         _ => {
@@ -5453,6 +7473,29 @@ pub mod tests {
                     }
                 }
             }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> NuRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    let value = self.zz()?;
+                    value.validate_deep()?;
+                    let value = self.aaa()?;
+                    for item in value.iter() {
+                        item?;
+                    }
+                    self.bbb()?;
+                    let value = self.test()?;
+                    if let Some(inner) = value {
+                        inner.validate_deep()?;
+                    }
+                    Ok(())
+                }
+            }
             impl<'a> tree_hash::TreeHash for NuRef<'a> {
                 fn tree_hash_type() -> tree_hash::TreeHashType {
                     tree_hash::TreeHashType::StableContainer
@@ -5526,6 +7569,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for NuRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> NuRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for NuRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     usize::from(!<AliasMu as ssz::Encode>::is_ssz_fixed_len())
@@ -5584,6 +7640,77 @@ pub mod tests {
                             .map(|inner| ssz_types::view::ToOwnedSsz::to_owned(&inner)),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Nu,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.zz = {
+                        let view = self.zz()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    let view = self.aaa()?;
+                    for (slot, item) in out.aaa.iter_mut().zip(view.iter()) {
+                        *slot = ssz_types::view::ToOwnedSsz::to_owned(&item?);
+                    }
+                    out.bbb = self.bbb()?.to_owned();
+                    out.test = self
+                        .test()?
+                        .map(|inner| ssz_types::view::ToOwnedSsz::to_owned(&inner));
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> NuRef<'a> {
+                fn eq_owned(&self, other: &Nu) -> Result<bool, ssz::DecodeError> {
+                    if !(self.zz()? == other.zz) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: FixedVector<bool, 4usize> = self
+                            .aaa()?
+                            .to_owned()
+                            .expect("valid view");
+                        value == other.aaa
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: BitAlias = self.bbb()?.to_owned();
+                        value == other.bbb
+                    }) {
+                        return Ok(false);
+                    }
+                    if !(match (self.test()?, &other.test) {
+                        (Some(view), Some(owned)) => view == *owned,
+                        (None, None) => true,
+                        _ => false,
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<Nu> for NuRef<'a> {
+                fn eq(&self, other: &Nu) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<NuRef<'a>> for Nu {
+                fn eq(&self, other: &NuRef<'a>) -> bool {
+                    other == self
+                }
             }
         }
     }
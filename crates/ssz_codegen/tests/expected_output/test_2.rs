@@ -11,6 +11,13 @@ pub mod tests {
             use tree_hash::TreeHashDigest;
             use tree_hash_derive::TreeHash;
             use ssz::view::*;
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                113, 244, 143, 192, 205, 245, 250, 207, 169, 154, 91, 71, 201, 109, 118,
+                123, 250, 104, 92, 33, 45, 196, 204, 78, 39, 134, 170, 191, 191, 109,
+                201, 114,
+            ];
             #[derive(
                 std::clone::Clone,
                 std::fmt::Debug,
@@ -95,9 +102,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         2usize,
@@ -129,8 +135,9 @@ pub mod tests {
                         None => return Ok(ssz_types::Optional::None),
                     };
                     let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(
-                        field_bytes,
-                    )?;
+                            field_bytes,
+                        )
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
                 pub fn b(
@@ -140,9 +147,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         2usize,
@@ -176,10 +182,25 @@ pub mod tests {
                     let inner = <BitListRef<
                         'a,
                         32usize,
-                    > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)?;
+                    > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
             }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> AlphaRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.a()?;
+                    self.b()?;
+                    Ok(())
+                }
+            }
             impl<'a> tree_hash::TreeHash for AlphaRef<'a> {
                 fn tree_hash_type() -> tree_hash::TreeHashType {
                     tree_hash::TreeHashType::StableContainer
@@ -235,9 +256,8 @@ pub mod tests {
                     use ssz::Decode;
                     let bitvector_bytes = bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         2usize,
@@ -267,6 +287,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for AlphaRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> AlphaRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for AlphaRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     false
@@ -304,6 +337,84 @@ pub mod tests {
                         },
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Alpha,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.a = self.a()?;
+                    out.b = match self.b()? {
+                        ssz_types::Optional::Some(inner) => {
+                            ssz_types::Optional::Some(
+                                ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                            )
+                        }
+                        ssz_types::Optional::None => ssz_types::Optional::None,
+                    };
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> AlphaRef<'a> {
+                fn eq_owned(&self, other: &Alpha) -> Result<bool, ssz::DecodeError> {
+                    use ssz::Decode;
+                    let bitvector_bytes = self
+                        .bytes
+                        .get(..1usize)
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
+                        })?;
+                    let bitvector = ssz_types::BitVector::<
+                        2usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    if bitvector.get(0usize).unwrap_or(false) != other.a.is_some() {
+                        return Ok(false);
+                    }
+                    if bitvector.get(1usize).unwrap_or(false) != other.b.is_some() {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<u8> = self.a()?;
+                        value == other.a
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<BitList<32usize>> = match self.b()? {
+                            ssz_types::Optional::Some(inner) => {
+                                ssz_types::Optional::Some(
+                                    ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                                )
+                            }
+                            ssz_types::Optional::None => ssz_types::Optional::None,
+                        };
+                        value == other.b
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<Alpha> for AlphaRef<'a> {
+                fn eq(&self, other: &Alpha) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<AlphaRef<'a>> for Alpha {
+                fn eq(&self, other: &AlphaRef<'a>) -> bool {
+                    other == self
+                }
             }
             #[derive(
                 std::clone::Clone,
@@ -413,9 +524,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         8usize,
@@ -463,8 +573,9 @@ pub mod tests {
                         None => return Ok(ssz_types::Optional::None),
                     };
                     let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(
-                        field_bytes,
-                    )?;
+                            field_bytes,
+                        )
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
                 pub fn y(
@@ -474,9 +585,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         8usize,
@@ -526,7 +636,8 @@ pub mod tests {
                     let inner = <BytesRef<
                         'a,
                         4usize,
-                    > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)?;
+                    > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
                 pub fn z(
@@ -536,9 +647,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         8usize,
@@ -588,7 +698,8 @@ pub mod tests {
                     let inner = <BitVectorRef<
                         'a,
                         16usize,
-                    > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)?;
+                    > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
                 pub fn w(&self) -> Result<Optional<AlphaRef<'a>>, ssz::DecodeError> {
@@ -596,9 +707,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         8usize,
@@ -647,10 +757,30 @@ pub mod tests {
                     };
                     let inner = <AlphaRef<
                         'a,
-                    > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)?;
+                    > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
             }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> InnerBaseRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.x()?;
+                    self.y()?;
+                    self.z()?;
+                    let value = self.w()?;
+                    if let ssz_types::Optional::Some(inner) = value {
+                        inner.validate_deep()?;
+                    }
+                    Ok(())
+                }
+            }
             impl<'a> tree_hash::TreeHash for InnerBaseRef<'a> {
                 fn tree_hash_type() -> tree_hash::TreeHashType {
                     tree_hash::TreeHashType::StableContainer
@@ -730,9 +860,8 @@ pub mod tests {
                     use ssz::Decode;
                     let bitvector_bytes = bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         8usize,
@@ -788,6 +917,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for InnerBaseRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> InnerBaseRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for InnerBaseRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     false
@@ -841,6 +983,129 @@ pub mod tests {
                         },
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut InnerBase,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.x = self.x()?;
+                    out.y = match self.y()? {
+                        ssz_types::Optional::Some(inner) => {
+                            ssz_types::Optional::Some(
+                                ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                            )
+                        }
+                        ssz_types::Optional::None => ssz_types::Optional::None,
+                    };
+                    out.z = match self.z()? {
+                        ssz_types::Optional::Some(inner) => {
+                            ssz_types::Optional::Some(
+                                ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                            )
+                        }
+                        ssz_types::Optional::None => ssz_types::Optional::None,
+                    };
+                    out.w = match self.w()? {
+                        ssz_types::Optional::Some(inner) => {
+                            ssz_types::Optional::Some(
+                                ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                            )
+                        }
+                        ssz_types::Optional::None => ssz_types::Optional::None,
+                    };
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> InnerBaseRef<'a> {
+                fn eq_owned(&self, other: &InnerBase) -> Result<bool, ssz::DecodeError> {
+                    use ssz::Decode;
+                    let bitvector_bytes = self
+                        .bytes
+                        .get(..1usize)
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
+                        })?;
+                    let bitvector = ssz_types::BitVector::<
+                        8usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    if bitvector.get(0usize).unwrap_or(false) != other.x.is_some() {
+                        return Ok(false);
+                    }
+                    if bitvector.get(1usize).unwrap_or(false) != other.y.is_some() {
+                        return Ok(false);
+                    }
+                    if bitvector.get(2usize).unwrap_or(false) != other.z.is_some() {
+                        return Ok(false);
+                    }
+                    if bitvector.get(3usize).unwrap_or(false) != other.w.is_some() {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<u8> = self.x()?;
+                        value == other.x
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<VariableList<u8, 4usize>> = match self.y()? {
+                            ssz_types::Optional::Some(inner) => {
+                                ssz_types::Optional::Some(
+                                    ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                                )
+                            }
+                            ssz_types::Optional::None => ssz_types::Optional::None,
+                        };
+                        value == other.y
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<BitVector<16usize>> = match self.z()? {
+                            ssz_types::Optional::Some(inner) => {
+                                ssz_types::Optional::Some(
+                                    ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                                )
+                            }
+                            ssz_types::Optional::None => ssz_types::Optional::None,
+                        };
+                        value == other.z
+                    }) {
+                        return Ok(false);
+                    }
+                    if !(match (self.w()?, &other.w) {
+                        (
+                            ssz_types::Optional::Some(view),
+                            ssz_types::Optional::Some(owned),
+                        ) => view == *owned,
+                        (ssz_types::Optional::None, ssz_types::Optional::None) => true,
+                        _ => false,
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<InnerBase> for InnerBaseRef<'a> {
+                fn eq(&self, other: &InnerBase) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<InnerBaseRef<'a>> for InnerBase {
+                fn eq(&self, other: &InnerBaseRef<'a>) -> bool {
+                    other == self
+                }
             }
             #[derive(
                 std::clone::Clone,
@@ -944,9 +1209,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         3usize,
@@ -994,6 +1258,7 @@ pub mod tests {
                         None => unreachable!("required field is always active"),
                     };
                     ssz::view::DecodeView::from_ssz_bytes(field_bytes)
+                        .map_err(|e| e.bounded(field_bytes.len()))
                 }
                 pub fn y(
                     &self,
@@ -1002,9 +1267,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         3usize,
@@ -1054,7 +1318,8 @@ pub mod tests {
                     let inner = <BytesRef<
                         'a,
                         4usize,
-                    > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)?;
+                    > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
                 pub fn z(
@@ -1064,9 +1329,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         3usize,
@@ -1116,7 +1380,8 @@ pub mod tests {
                     let inner = <BitVectorRef<
                         'a,
                         16usize,
-                    > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)?;
+                    > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
                 pub fn w(&self) -> Result<Optional<AlphaRef<'a>>, ssz::DecodeError> {
@@ -1124,9 +1389,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         3usize,
@@ -1175,10 +1439,30 @@ pub mod tests {
                     };
                     let inner = <AlphaRef<
                         'a,
-                    > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)?;
+                    > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
             }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> InnerProfile1Ref<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.x()?;
+                    self.y()?;
+                    self.z()?;
+                    let value = self.w()?;
+                    if let ssz_types::Optional::Some(inner) = value {
+                        inner.validate_deep()?;
+                    }
+                    Ok(())
+                }
+            }
             impl<'a> tree_hash::TreeHash for InnerProfile1Ref<'a> {
                 fn tree_hash_type() -> tree_hash::TreeHashType {
                     tree_hash::TreeHashType::Container
@@ -1252,9 +1536,8 @@ pub mod tests {
                     use ssz::Decode;
                     let bitvector_bytes = bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         3usize,
@@ -1300,6 +1583,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for InnerProfile1Ref<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> InnerProfile1Ref<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for InnerProfile1Ref<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     false
@@ -1354,6 +1650,224 @@ pub mod tests {
                         },
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut InnerProfile1,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.x = self.x()?;
+                    out.y = match self.y()? {
+                        ssz_types::Optional::Some(inner) => {
+                            ssz_types::Optional::Some(
+                                ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                            )
+                        }
+                        ssz_types::Optional::None => ssz_types::Optional::None,
+                    };
+                    out.z = match self.z()? {
+                        ssz_types::Optional::Some(inner) => {
+                            ssz_types::Optional::Some(
+                                ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                            )
+                        }
+                        ssz_types::Optional::None => ssz_types::Optional::None,
+                    };
+                    out.w = match self.w()? {
+                        ssz_types::Optional::Some(inner) => {
+                            ssz_types::Optional::Some(
+                                ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                            )
+                        }
+                        ssz_types::Optional::None => ssz_types::Optional::None,
+                    };
+                    Ok(())
+                }
+            }
+            impl<'a> TryFrom<InnerBaseRef<'a>> for InnerProfile1 {
+                type Error = ssz::DecodeError;
+                fn try_from(value: InnerBaseRef<'a>) -> Result<Self, Self::Error> {
+                    use ssz::Decode;
+                    let bitvector_bytes = value
+                        .bytes
+                        .get(..1usize)
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - value.bytes.len(),
+                        })?;
+                    let bitvector = ssz_types::BitVector::<
+                        8usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    if !bitvector.get(0usize).unwrap_or(false) {
+                        return Err(
+                            ssz::DecodeError::BytesInvalid(
+                                "field `x` is required by profile `InnerProfile1` but inactive"
+                                    .to_string(),
+                            ),
+                        );
+                    }
+                    Ok(Self {
+                        x: value.x()?.unwrap(),
+                        y: match value.y()? {
+                            ssz_types::Optional::Some(inner) => {
+                                ssz_types::Optional::Some(
+                                    ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                                )
+                            }
+                            ssz_types::Optional::None => ssz_types::Optional::None,
+                        },
+                        z: match value.z()? {
+                            ssz_types::Optional::Some(inner) => {
+                                ssz_types::Optional::Some(
+                                    ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                                )
+                            }
+                            ssz_types::Optional::None => ssz_types::Optional::None,
+                        },
+                        w: match value.w()? {
+                            ssz_types::Optional::Some(inner) => {
+                                ssz_types::Optional::Some(
+                                    ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                                )
+                            }
+                            ssz_types::Optional::None => ssz_types::Optional::None,
+                        },
+                    })
+                }
+            }
+            impl TryFrom<InnerBase> for InnerProfile1 {
+                type Error = ssz::DecodeError;
+                fn try_from(value: InnerBase) -> Result<Self, Self::Error> {
+                    Ok(Self {
+                        x: match value.x {
+                            ssz_types::Optional::Some(inner) => inner,
+                            ssz_types::Optional::None => {
+                                return Err(
+                                    ssz::DecodeError::BytesInvalid(
+                                        "field `x` is required by profile `InnerProfile1` but inactive"
+                                            .to_string(),
+                                    ),
+                                );
+                            }
+                        },
+                        y: value.y,
+                        z: value.z,
+                        w: value.w,
+                    })
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl InnerProfile1 {
+                /// Converts this profile to its base StableContainer.
+                pub fn to_stable(&self) -> InnerBase {
+                    InnerBase {
+                        x: ssz_types::Optional::Some(self.x),
+                        y: self.y.clone(),
+                        z: self.z.clone(),
+                        w: self.w.clone(),
+                    }
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> InnerProfile1Ref<'a> {
+                /// Converts this profile view to its owned base StableContainer.
+                pub fn to_stable(&self) -> InnerBase {
+                    self.to_owned().to_stable()
+                }
+            }
+            impl From<InnerProfile1> for InnerBase {
+                fn from(value: InnerProfile1) -> Self {
+                    value.to_stable()
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> InnerProfile1Ref<'a> {
+                fn eq_owned(
+                    &self,
+                    other: &InnerProfile1,
+                ) -> Result<bool, ssz::DecodeError> {
+                    use ssz::Decode;
+                    let bitvector_bytes = self
+                        .bytes
+                        .get(..1usize)
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
+                        })?;
+                    let bitvector = ssz_types::BitVector::<
+                        3usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    if bitvector.get(0usize).unwrap_or(false) != other.y.is_some() {
+                        return Ok(false);
+                    }
+                    if bitvector.get(1usize).unwrap_or(false) != other.z.is_some() {
+                        return Ok(false);
+                    }
+                    if bitvector.get(2usize).unwrap_or(false) != other.w.is_some() {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: u8 = self.x()?;
+                        value == other.x
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<VariableList<u8, 4usize>> = match self.y()? {
+                            ssz_types::Optional::Some(inner) => {
+                                ssz_types::Optional::Some(
+                                    ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                                )
+                            }
+                            ssz_types::Optional::None => ssz_types::Optional::None,
+                        };
+                        value == other.y
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<BitVector<16usize>> = match self.z()? {
+                            ssz_types::Optional::Some(inner) => {
+                                ssz_types::Optional::Some(
+                                    ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                                )
+                            }
+                            ssz_types::Optional::None => ssz_types::Optional::None,
+                        };
+                        value == other.z
+                    }) {
+                        return Ok(false);
+                    }
+                    if !(match (self.w()?, &other.w) {
+                        (
+                            ssz_types::Optional::Some(view),
+                            ssz_types::Optional::Some(owned),
+                        ) => view == *owned,
+                        (ssz_types::Optional::None, ssz_types::Optional::None) => true,
+                        _ => false,
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<InnerProfile1> for InnerProfile1Ref<'a> {
+                fn eq(&self, other: &InnerProfile1) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<InnerProfile1Ref<'a>> for InnerProfile1 {
+                fn eq(&self, other: &InnerProfile1Ref<'a>) -> bool {
+                    other == self
+                }
             }
             #[derive(
                 std::clone::Clone,
@@ -1439,9 +1953,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         1usize,
@@ -1479,8 +1992,9 @@ pub mod tests {
                         None => return Ok(ssz_types::Optional::None),
                     };
                     let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(
-                        field_bytes,
-                    )?;
+                            field_bytes,
+                        )
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
                 pub fn y(&self) -> Result<BytesRef<'a, 4usize>, ssz::DecodeError> {
@@ -1488,9 +2002,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         1usize,
@@ -1528,15 +2041,15 @@ pub mod tests {
                         None => unreachable!("required field is always active"),
                     };
                     ssz::view::DecodeView::from_ssz_bytes(field_bytes)
+                        .map_err(|e| e.bounded(field_bytes.len()))
                 }
                 pub fn z(&self) -> Result<BitVectorRef<'a, 16usize>, ssz::DecodeError> {
                     use ssz::Decode;
                     let bitvector_bytes = self
                         .bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         1usize,
@@ -1574,6 +2087,22 @@ pub mod tests {
                         None => unreachable!("required field is always active"),
                     };
                     ssz::view::DecodeView::from_ssz_bytes(field_bytes)
+                        .map_err(|e| e.bounded(field_bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> InnerProfile2Ref<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.x()?;
+                    self.y()?;
+                    self.z()?;
+                    Ok(())
                 }
             }
             impl<'a> tree_hash::TreeHash for InnerProfile2Ref<'a> {
@@ -1631,9 +2160,8 @@ pub mod tests {
                     use ssz::Decode;
                     let bitvector_bytes = bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         1usize,
@@ -1669,6 +2197,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for InnerProfile2Ref<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> InnerProfile2Ref<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for InnerProfile2Ref<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     false
@@ -1704,29 +2245,212 @@ pub mod tests {
                         z: self.z().expect("valid view").to_owned(),
                     }
                 }
-            }
-            #[derive(
-                std::clone::Clone,
-                std::fmt::Debug,
-                std::cmp::PartialEq,
-                std::cmp::Eq,
-                ssz_derive::Encode,
-                ssz_derive::Decode
-            )]
-            #[ssz(struct_behaviour = "profile")]
-            pub struct AlphaProfile {
-                pub a: u8,
-                pub b: Optional<BitList<32usize>>,
-            }
-            impl tree_hash::TreeHash for AlphaProfile {
-                fn tree_hash_type() -> tree_hash::TreeHashType {
-                    tree_hash::TreeHashType::StableContainer
-                }
-                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
-                    unreachable!("StableContainer/Profile should never be packed")
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut InnerProfile2,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.x = self.x()?;
+                    let view = self.y()?;
+                    out.y.clear();
+                    out.y.try_extend_from_slice(view.as_bytes()).expect("valid view");
+                    out.z = self.z()?.to_owned();
+                    Ok(())
                 }
-                fn tree_hash_packing_factor() -> usize {
-                    unreachable!("StableContainer/Profile should never be packed")
+            }
+            impl<'a> TryFrom<InnerBaseRef<'a>> for InnerProfile2 {
+                type Error = ssz::DecodeError;
+                fn try_from(value: InnerBaseRef<'a>) -> Result<Self, Self::Error> {
+                    use ssz::Decode;
+                    let bitvector_bytes = value
+                        .bytes
+                        .get(..1usize)
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - value.bytes.len(),
+                        })?;
+                    let bitvector = ssz_types::BitVector::<
+                        8usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    if !bitvector.get(1usize).unwrap_or(false) {
+                        return Err(
+                            ssz::DecodeError::BytesInvalid(
+                                "field `y` is required by profile `InnerProfile2` but inactive"
+                                    .to_string(),
+                            ),
+                        );
+                    }
+                    if !bitvector.get(2usize).unwrap_or(false) {
+                        return Err(
+                            ssz::DecodeError::BytesInvalid(
+                                "field `z` is required by profile `InnerProfile2` but inactive"
+                                    .to_string(),
+                            ),
+                        );
+                    }
+                    if bitvector.get(3usize).unwrap_or(false) {
+                        return Err(
+                            ssz::DecodeError::BytesInvalid(
+                                "field `w` is not part of profile `InnerProfile2` but active"
+                                    .to_string(),
+                            ),
+                        );
+                    }
+                    Ok(Self {
+                        x: value.x()?,
+                        y: ssz_types::VariableList::new(value.y()?.unwrap().to_owned())
+                            .expect("valid view"),
+                        z: value.z()?.unwrap().to_owned(),
+                    })
+                }
+            }
+            impl TryFrom<InnerBase> for InnerProfile2 {
+                type Error = ssz::DecodeError;
+                fn try_from(value: InnerBase) -> Result<Self, Self::Error> {
+                    if value.w.is_some() {
+                        return Err(
+                            ssz::DecodeError::BytesInvalid(
+                                "field `w` is not part of profile `InnerProfile2` but active"
+                                    .to_string(),
+                            ),
+                        );
+                    }
+                    Ok(Self {
+                        x: value.x,
+                        y: match value.y {
+                            ssz_types::Optional::Some(inner) => inner,
+                            ssz_types::Optional::None => {
+                                return Err(
+                                    ssz::DecodeError::BytesInvalid(
+                                        "field `y` is required by profile `InnerProfile2` but inactive"
+                                            .to_string(),
+                                    ),
+                                );
+                            }
+                        },
+                        z: match value.z {
+                            ssz_types::Optional::Some(inner) => inner,
+                            ssz_types::Optional::None => {
+                                return Err(
+                                    ssz::DecodeError::BytesInvalid(
+                                        "field `z` is required by profile `InnerProfile2` but inactive"
+                                            .to_string(),
+                                    ),
+                                );
+                            }
+                        },
+                    })
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl InnerProfile2 {
+                /// Converts this profile to its base StableContainer.
+                pub fn to_stable(&self) -> InnerBase {
+                    InnerBase {
+                        x: self.x,
+                        y: ssz_types::Optional::Some(self.y.clone()),
+                        z: ssz_types::Optional::Some(self.z.clone()),
+                        w: ssz_types::Optional::None,
+                    }
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> InnerProfile2Ref<'a> {
+                /// Converts this profile view to its owned base StableContainer.
+                pub fn to_stable(&self) -> InnerBase {
+                    self.to_owned().to_stable()
+                }
+            }
+            impl From<InnerProfile2> for InnerBase {
+                fn from(value: InnerProfile2) -> Self {
+                    value.to_stable()
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> InnerProfile2Ref<'a> {
+                fn eq_owned(
+                    &self,
+                    other: &InnerProfile2,
+                ) -> Result<bool, ssz::DecodeError> {
+                    use ssz::Decode;
+                    let bitvector_bytes = self
+                        .bytes
+                        .get(..1usize)
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
+                        })?;
+                    let bitvector = ssz_types::BitVector::<
+                        1usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    if bitvector.get(0usize).unwrap_or(false) != other.x.is_some() {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<u8> = self.x()?;
+                        value == other.x
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: VariableList<u8, 4usize> = ssz_types::VariableList::new(
+                                self.y()?.to_owned(),
+                            )
+                            .expect("valid view");
+                        value == other.y
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: BitVector<16usize> = self.z()?.to_owned();
+                        value == other.z
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<InnerProfile2> for InnerProfile2Ref<'a> {
+                fn eq(&self, other: &InnerProfile2) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<InnerProfile2Ref<'a>> for InnerProfile2 {
+                fn eq(&self, other: &InnerProfile2Ref<'a>) -> bool {
+                    other == self
+                }
+            }
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                ssz_derive::Encode,
+                ssz_derive::Decode
+            )]
+            #[ssz(struct_behaviour = "profile")]
+            pub struct AlphaProfile {
+                pub a: u8,
+                pub b: Optional<BitList<32usize>>,
+            }
+            impl tree_hash::TreeHash for AlphaProfile {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::StableContainer
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("StableContainer/Profile should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("StableContainer/Profile should never be packed")
                 }
                 fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
                     use tree_hash::TreeHash;
@@ -1783,9 +2507,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         1usize,
@@ -1817,6 +2540,7 @@ pub mod tests {
                         None => unreachable!("required field is always active"),
                     };
                     ssz::view::DecodeView::from_ssz_bytes(field_bytes)
+                        .map_err(|e| e.bounded(field_bytes.len()))
                 }
                 pub fn b(
                     &self,
@@ -1825,9 +2549,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         1usize,
@@ -1861,10 +2584,25 @@ pub mod tests {
                     let inner = <BitListRef<
                         'a,
                         32usize,
-                    > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)?;
+                    > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
             }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> AlphaProfileRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.a()?;
+                    self.b()?;
+                    Ok(())
+                }
+            }
             impl<'a> tree_hash::TreeHash for AlphaProfileRef<'a> {
                 fn tree_hash_type() -> tree_hash::TreeHashType {
                     tree_hash::TreeHashType::Container
@@ -1914,9 +2652,8 @@ pub mod tests {
                     use ssz::Decode;
                     let bitvector_bytes = bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         1usize,
@@ -1946,6 +2683,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for AlphaProfileRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> AlphaProfileRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for AlphaProfileRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     false
@@ -1983,6 +2733,159 @@ pub mod tests {
                         },
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut AlphaProfile,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.a = self.a()?;
+                    out.b = match self.b()? {
+                        ssz_types::Optional::Some(inner) => {
+                            ssz_types::Optional::Some(
+                                ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                            )
+                        }
+                        ssz_types::Optional::None => ssz_types::Optional::None,
+                    };
+                    Ok(())
+                }
+            }
+            impl<'a> TryFrom<AlphaRef<'a>> for AlphaProfile {
+                type Error = ssz::DecodeError;
+                fn try_from(value: AlphaRef<'a>) -> Result<Self, Self::Error> {
+                    use ssz::Decode;
+                    let bitvector_bytes = value
+                        .bytes
+                        .get(..1usize)
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - value.bytes.len(),
+                        })?;
+                    let bitvector = ssz_types::BitVector::<
+                        2usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    if !bitvector.get(0usize).unwrap_or(false) {
+                        return Err(
+                            ssz::DecodeError::BytesInvalid(
+                                "field `a` is required by profile `AlphaProfile` but inactive"
+                                    .to_string(),
+                            ),
+                        );
+                    }
+                    Ok(Self {
+                        a: value.a()?.unwrap(),
+                        b: match value.b()? {
+                            ssz_types::Optional::Some(inner) => {
+                                ssz_types::Optional::Some(
+                                    ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                                )
+                            }
+                            ssz_types::Optional::None => ssz_types::Optional::None,
+                        },
+                    })
+                }
+            }
+            impl TryFrom<Alpha> for AlphaProfile {
+                type Error = ssz::DecodeError;
+                fn try_from(value: Alpha) -> Result<Self, Self::Error> {
+                    Ok(Self {
+                        a: match value.a {
+                            ssz_types::Optional::Some(inner) => inner,
+                            ssz_types::Optional::None => {
+                                return Err(
+                                    ssz::DecodeError::BytesInvalid(
+                                        "field `a` is required by profile `AlphaProfile` but inactive"
+                                            .to_string(),
+                                    ),
+                                );
+                            }
+                        },
+                        b: value.b,
+                    })
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl AlphaProfile {
+                /// Converts this profile to its base StableContainer.
+                pub fn to_stable(&self) -> Alpha {
+                    Alpha {
+                        a: ssz_types::Optional::Some(self.a),
+                        b: self.b.clone(),
+                    }
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> AlphaProfileRef<'a> {
+                /// Converts this profile view to its owned base StableContainer.
+                pub fn to_stable(&self) -> Alpha {
+                    self.to_owned().to_stable()
+                }
+            }
+            impl From<AlphaProfile> for Alpha {
+                fn from(value: AlphaProfile) -> Self {
+                    value.to_stable()
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> AlphaProfileRef<'a> {
+                fn eq_owned(
+                    &self,
+                    other: &AlphaProfile,
+                ) -> Result<bool, ssz::DecodeError> {
+                    use ssz::Decode;
+                    let bitvector_bytes = self
+                        .bytes
+                        .get(..1usize)
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
+                        })?;
+                    let bitvector = ssz_types::BitVector::<
+                        1usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    if bitvector.get(0usize).unwrap_or(false) != other.b.is_some() {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: u8 = self.a()?;
+                        value == other.a
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<BitList<32usize>> = match self.b()? {
+                            ssz_types::Optional::Some(inner) => {
+                                ssz_types::Optional::Some(
+                                    ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                                )
+                            }
+                            ssz_types::Optional::None => ssz_types::Optional::None,
+                        };
+                        value == other.b
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<AlphaProfile> for AlphaProfileRef<'a> {
+                fn eq(&self, other: &AlphaProfile) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<AlphaProfileRef<'a>> for AlphaProfile {
+                fn eq(&self, other: &AlphaProfileRef<'a>) -> bool {
+                    other == self
+                }
             }
             #[derive(
                 std::clone::Clone,
@@ -2064,6 +2967,21 @@ pub mod tests {
                         None => unreachable!("required field is always active"),
                     };
                     ssz::view::DecodeView::from_ssz_bytes(field_bytes)
+                        .map_err(|e| e.bounded(field_bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> InnerProfile3Ref<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    let value = self.w()?;
+                    value.validate_deep()?;
+                    Ok(())
                 }
             }
             impl<'a> tree_hash::TreeHash for InnerProfile3Ref<'a> {
@@ -2116,6 +3034,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for InnerProfile3Ref<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> InnerProfile3Ref<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for InnerProfile3Ref<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     usize::from(!<AlphaProfile as ssz::Encode>::is_ssz_fixed_len()) == 0
@@ -2153,6 +3084,168 @@ pub mod tests {
                         },
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut InnerProfile3,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.w = {
+                        let view = self.w()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    Ok(())
+                }
+            }
+            impl<'a> TryFrom<InnerBaseRef<'a>> for InnerProfile3 {
+                type Error = ssz::DecodeError;
+                fn try_from(value: InnerBaseRef<'a>) -> Result<Self, Self::Error> {
+                    use ssz::Decode;
+                    let bitvector_bytes = value
+                        .bytes
+                        .get(..1usize)
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - value.bytes.len(),
+                        })?;
+                    let bitvector = ssz_types::BitVector::<
+                        8usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    if bitvector.get(0usize).unwrap_or(false) {
+                        return Err(
+                            ssz::DecodeError::BytesInvalid(
+                                "field `x` is not part of profile `InnerProfile3` but active"
+                                    .to_string(),
+                            ),
+                        );
+                    }
+                    if bitvector.get(1usize).unwrap_or(false) {
+                        return Err(
+                            ssz::DecodeError::BytesInvalid(
+                                "field `y` is not part of profile `InnerProfile3` but active"
+                                    .to_string(),
+                            ),
+                        );
+                    }
+                    if bitvector.get(2usize).unwrap_or(false) {
+                        return Err(
+                            ssz::DecodeError::BytesInvalid(
+                                "field `z` is not part of profile `InnerProfile3` but active"
+                                    .to_string(),
+                            ),
+                        );
+                    }
+                    if !bitvector.get(3usize).unwrap_or(false) {
+                        return Err(
+                            ssz::DecodeError::BytesInvalid(
+                                "field `w` is required by profile `InnerProfile3` but inactive"
+                                    .to_string(),
+                            ),
+                        );
+                    }
+                    Ok(Self {
+                        w: <AlphaProfile>::try_from(value.w()?.unwrap())?,
+                    })
+                }
+            }
+            impl TryFrom<InnerBase> for InnerProfile3 {
+                type Error = ssz::DecodeError;
+                fn try_from(value: InnerBase) -> Result<Self, Self::Error> {
+                    if value.x.is_some() {
+                        return Err(
+                            ssz::DecodeError::BytesInvalid(
+                                "field `x` is not part of profile `InnerProfile3` but active"
+                                    .to_string(),
+                            ),
+                        );
+                    }
+                    if value.y.is_some() {
+                        return Err(
+                            ssz::DecodeError::BytesInvalid(
+                                "field `y` is not part of profile `InnerProfile3` but active"
+                                    .to_string(),
+                            ),
+                        );
+                    }
+                    if value.z.is_some() {
+                        return Err(
+                            ssz::DecodeError::BytesInvalid(
+                                "field `z` is not part of profile `InnerProfile3` but active"
+                                    .to_string(),
+                            ),
+                        );
+                    }
+                    Ok(Self {
+                        w: <AlphaProfile>::try_from(
+                            match value.w {
+                                ssz_types::Optional::Some(inner) => inner,
+                                ssz_types::Optional::None => {
+                                    return Err(
+                                        ssz::DecodeError::BytesInvalid(
+                                            "field `w` is required by profile `InnerProfile3` but inactive"
+                                                .to_string(),
+                                        ),
+                                    );
+                                }
+                            },
+                        )?,
+                    })
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl InnerProfile3 {
+                /// Converts this profile to its base StableContainer.
+                pub fn to_stable(&self) -> InnerBase {
+                    InnerBase {
+                        x: ssz_types::Optional::None,
+                        y: ssz_types::Optional::None,
+                        z: ssz_types::Optional::None,
+                        w: ssz_types::Optional::Some(self.w.to_stable()),
+                    }
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> InnerProfile3Ref<'a> {
+                /// Converts this profile view to its owned base StableContainer.
+                pub fn to_stable(&self) -> InnerBase {
+                    self.to_owned().to_stable()
+                }
+            }
+            impl From<InnerProfile3> for InnerBase {
+                fn from(value: InnerProfile3) -> Self {
+                    value.to_stable()
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> InnerProfile3Ref<'a> {
+                fn eq_owned(
+                    &self,
+                    other: &InnerProfile3,
+                ) -> Result<bool, ssz::DecodeError> {
+                    if !(self.w()? == other.w) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<InnerProfile3> for InnerProfile3Ref<'a> {
+                fn eq(&self, other: &InnerProfile3) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<InnerProfile3Ref<'a>> for InnerProfile3 {
+                fn eq(&self, other: &InnerProfile3Ref<'a>) -> bool {
+                    other == self
+                }
             }
             #[derive(
                 std::clone::Clone,
@@ -2247,6 +3340,7 @@ pub mod tests {
                         None => unreachable!("required field is always active"),
                     };
                     ssz::view::DecodeView::from_ssz_bytes(field_bytes)
+                        .map_err(|e| e.bounded(field_bytes.len()))
                 }
                 pub fn z(&self) -> Result<BitVectorRef<'a, 16usize>, ssz::DecodeError> {
                     let body = self.bytes;
@@ -2274,6 +3368,21 @@ pub mod tests {
                         None => unreachable!("required field is always active"),
                     };
                     ssz::view::DecodeView::from_ssz_bytes(field_bytes)
+                        .map_err(|e| e.bounded(field_bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> InnerProfile4Ref<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.y()?;
+                    self.z()?;
+                    Ok(())
                 }
             }
             impl<'a> tree_hash::TreeHash for InnerProfile4Ref<'a> {
@@ -2339,6 +3448,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for InnerProfile4Ref<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> InnerProfile4Ref<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for InnerProfile4Ref<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     usize::from(
@@ -2383,6 +3505,183 @@ pub mod tests {
                         z: self.z().expect("valid view").to_owned(),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut InnerProfile4,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    let view = self.y()?;
+                    out.y.clear();
+                    out.y.try_extend_from_slice(view.as_bytes()).expect("valid view");
+                    out.z = self.z()?.to_owned();
+                    Ok(())
+                }
+            }
+            impl<'a> TryFrom<InnerBaseRef<'a>> for InnerProfile4 {
+                type Error = ssz::DecodeError;
+                fn try_from(value: InnerBaseRef<'a>) -> Result<Self, Self::Error> {
+                    use ssz::Decode;
+                    let bitvector_bytes = value
+                        .bytes
+                        .get(..1usize)
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - value.bytes.len(),
+                        })?;
+                    let bitvector = ssz_types::BitVector::<
+                        8usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    if bitvector.get(0usize).unwrap_or(false) {
+                        return Err(
+                            ssz::DecodeError::BytesInvalid(
+                                "field `x` is not part of profile `InnerProfile4` but active"
+                                    .to_string(),
+                            ),
+                        );
+                    }
+                    if !bitvector.get(1usize).unwrap_or(false) {
+                        return Err(
+                            ssz::DecodeError::BytesInvalid(
+                                "field `y` is required by profile `InnerProfile4` but inactive"
+                                    .to_string(),
+                            ),
+                        );
+                    }
+                    if !bitvector.get(2usize).unwrap_or(false) {
+                        return Err(
+                            ssz::DecodeError::BytesInvalid(
+                                "field `z` is required by profile `InnerProfile4` but inactive"
+                                    .to_string(),
+                            ),
+                        );
+                    }
+                    if bitvector.get(3usize).unwrap_or(false) {
+                        return Err(
+                            ssz::DecodeError::BytesInvalid(
+                                "field `w` is not part of profile `InnerProfile4` but active"
+                                    .to_string(),
+                            ),
+                        );
+                    }
+                    Ok(Self {
+                        y: ssz_types::VariableList::new(value.y()?.unwrap().to_owned())
+                            .expect("valid view"),
+                        z: value.z()?.unwrap().to_owned(),
+                    })
+                }
+            }
+            impl TryFrom<InnerBase> for InnerProfile4 {
+                type Error = ssz::DecodeError;
+                fn try_from(value: InnerBase) -> Result<Self, Self::Error> {
+                    if value.x.is_some() {
+                        return Err(
+                            ssz::DecodeError::BytesInvalid(
+                                "field `x` is not part of profile `InnerProfile4` but active"
+                                    .to_string(),
+                            ),
+                        );
+                    }
+                    if value.w.is_some() {
+                        return Err(
+                            ssz::DecodeError::BytesInvalid(
+                                "field `w` is not part of profile `InnerProfile4` but active"
+                                    .to_string(),
+                            ),
+                        );
+                    }
+                    Ok(Self {
+                        y: match value.y {
+                            ssz_types::Optional::Some(inner) => inner,
+                            ssz_types::Optional::None => {
+                                return Err(
+                                    ssz::DecodeError::BytesInvalid(
+                                        "field `y` is required by profile `InnerProfile4` but inactive"
+                                            .to_string(),
+                                    ),
+                                );
+                            }
+                        },
+                        z: match value.z {
+                            ssz_types::Optional::Some(inner) => inner,
+                            ssz_types::Optional::None => {
+                                return Err(
+                                    ssz::DecodeError::BytesInvalid(
+                                        "field `z` is required by profile `InnerProfile4` but inactive"
+                                            .to_string(),
+                                    ),
+                                );
+                            }
+                        },
+                    })
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl InnerProfile4 {
+                /// Converts this profile to its base StableContainer.
+                pub fn to_stable(&self) -> InnerBase {
+                    InnerBase {
+                        x: ssz_types::Optional::None,
+                        y: ssz_types::Optional::Some(self.y.clone()),
+                        z: ssz_types::Optional::Some(self.z.clone()),
+                        w: ssz_types::Optional::None,
+                    }
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> InnerProfile4Ref<'a> {
+                /// Converts this profile view to its owned base StableContainer.
+                pub fn to_stable(&self) -> InnerBase {
+                    self.to_owned().to_stable()
+                }
+            }
+            impl From<InnerProfile4> for InnerBase {
+                fn from(value: InnerProfile4) -> Self {
+                    value.to_stable()
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> InnerProfile4Ref<'a> {
+                fn eq_owned(
+                    &self,
+                    other: &InnerProfile4,
+                ) -> Result<bool, ssz::DecodeError> {
+                    if !({
+                        let value: VariableList<u8, 4usize> = ssz_types::VariableList::new(
+                                self.y()?.to_owned(),
+                            )
+                            .expect("valid view");
+                        value == other.y
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: BitVector<16usize> = self.z()?.to_owned();
+                        value == other.z
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<InnerProfile4> for InnerProfile4Ref<'a> {
+                fn eq(&self, other: &InnerProfile4) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<InnerProfile4Ref<'a>> for InnerProfile4 {
+                fn eq(&self, other: &InnerProfile4Ref<'a>) -> bool {
+                    other == self
+                }
             }
             #[derive(
                 std::clone::Clone,
@@ -2484,6 +3783,7 @@ pub mod tests {
                         None => unreachable!("required field is always active"),
                     };
                     ssz::view::DecodeView::from_ssz_bytes(field_bytes)
+                        .map_err(|e| e.bounded(field_bytes.len()))
                 }
                 pub fn z(&self) -> Result<BitVectorRef<'a, 16usize>, ssz::DecodeError> {
                     let body = self.bytes;
@@ -2512,6 +3812,7 @@ pub mod tests {
                         None => unreachable!("required field is always active"),
                     };
                     ssz::view::DecodeView::from_ssz_bytes(field_bytes)
+                        .map_err(|e| e.bounded(field_bytes.len()))
                 }
                 pub fn w(&self) -> Result<AlphaRef<'a>, ssz::DecodeError> {
                     let body = self.bytes;
@@ -2540,6 +3841,23 @@ pub mod tests {
                         None => unreachable!("required field is always active"),
                     };
                     ssz::view::DecodeView::from_ssz_bytes(field_bytes)
+                        .map_err(|e| e.bounded(field_bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> InnerProfile5Ref<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.x()?;
+                    self.z()?;
+                    let value = self.w()?;
+                    value.validate_deep()?;
+                    Ok(())
                 }
             }
             impl<'a> tree_hash::TreeHash for InnerProfile5Ref<'a> {
@@ -2612,6 +3930,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for InnerProfile5Ref<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> InnerProfile5Ref<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for InnerProfile5Ref<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     usize::from(!<u8 as ssz::Encode>::is_ssz_fixed_len())
@@ -2656,6 +3987,191 @@ pub mod tests {
                         },
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut InnerProfile5,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.x = self.x()?;
+                    out.z = self.z()?.to_owned();
+                    out.w = {
+                        let view = self.w()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    Ok(())
+                }
+            }
+            impl<'a> TryFrom<InnerBaseRef<'a>> for InnerProfile5 {
+                type Error = ssz::DecodeError;
+                fn try_from(value: InnerBaseRef<'a>) -> Result<Self, Self::Error> {
+                    use ssz::Decode;
+                    let bitvector_bytes = value
+                        .bytes
+                        .get(..1usize)
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - value.bytes.len(),
+                        })?;
+                    let bitvector = ssz_types::BitVector::<
+                        8usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    if !bitvector.get(0usize).unwrap_or(false) {
+                        return Err(
+                            ssz::DecodeError::BytesInvalid(
+                                "field `x` is required by profile `InnerProfile5` but inactive"
+                                    .to_string(),
+                            ),
+                        );
+                    }
+                    if bitvector.get(1usize).unwrap_or(false) {
+                        return Err(
+                            ssz::DecodeError::BytesInvalid(
+                                "field `y` is not part of profile `InnerProfile5` but active"
+                                    .to_string(),
+                            ),
+                        );
+                    }
+                    if !bitvector.get(2usize).unwrap_or(false) {
+                        return Err(
+                            ssz::DecodeError::BytesInvalid(
+                                "field `z` is required by profile `InnerProfile5` but inactive"
+                                    .to_string(),
+                            ),
+                        );
+                    }
+                    if !bitvector.get(3usize).unwrap_or(false) {
+                        return Err(
+                            ssz::DecodeError::BytesInvalid(
+                                "field `w` is required by profile `InnerProfile5` but inactive"
+                                    .to_string(),
+                            ),
+                        );
+                    }
+                    Ok(Self {
+                        x: value.x()?.unwrap(),
+                        z: value.z()?.unwrap().to_owned(),
+                        w: {
+                            let view = value.w()?.unwrap();
+                            ssz_types::view::ToOwnedSsz::to_owned(&view)
+                        },
+                    })
+                }
+            }
+            impl TryFrom<InnerBase> for InnerProfile5 {
+                type Error = ssz::DecodeError;
+                fn try_from(value: InnerBase) -> Result<Self, Self::Error> {
+                    if value.y.is_some() {
+                        return Err(
+                            ssz::DecodeError::BytesInvalid(
+                                "field `y` is not part of profile `InnerProfile5` but active"
+                                    .to_string(),
+                            ),
+                        );
+                    }
+                    Ok(Self {
+                        x: match value.x {
+                            ssz_types::Optional::Some(inner) => inner,
+                            ssz_types::Optional::None => {
+                                return Err(
+                                    ssz::DecodeError::BytesInvalid(
+                                        "field `x` is required by profile `InnerProfile5` but inactive"
+                                            .to_string(),
+                                    ),
+                                );
+                            }
+                        },
+                        z: match value.z {
+                            ssz_types::Optional::Some(inner) => inner,
+                            ssz_types::Optional::None => {
+                                return Err(
+                                    ssz::DecodeError::BytesInvalid(
+                                        "field `z` is required by profile `InnerProfile5` but inactive"
+                                            .to_string(),
+                                    ),
+                                );
+                            }
+                        },
+                        w: match value.w {
+                            ssz_types::Optional::Some(inner) => inner,
+                            ssz_types::Optional::None => {
+                                return Err(
+                                    ssz::DecodeError::BytesInvalid(
+                                        "field `w` is required by profile `InnerProfile5` but inactive"
+                                            .to_string(),
+                                    ),
+                                );
+                            }
+                        },
+                    })
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl InnerProfile5 {
+                /// Converts this profile to its base StableContainer.
+                pub fn to_stable(&self) -> InnerBase {
+                    InnerBase {
+                        x: ssz_types::Optional::Some(self.x),
+                        y: ssz_types::Optional::None,
+                        z: ssz_types::Optional::Some(self.z.clone()),
+                        w: ssz_types::Optional::Some(self.w.clone()),
+                    }
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> InnerProfile5Ref<'a> {
+                /// Converts this profile view to its owned base StableContainer.
+                pub fn to_stable(&self) -> InnerBase {
+                    self.to_owned().to_stable()
+                }
+            }
+            impl From<InnerProfile5> for InnerBase {
+                fn from(value: InnerProfile5) -> Self {
+                    value.to_stable()
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> InnerProfile5Ref<'a> {
+                fn eq_owned(
+                    &self,
+                    other: &InnerProfile5,
+                ) -> Result<bool, ssz::DecodeError> {
+                    if !({
+                        let value: u8 = self.x()?;
+                        value == other.x
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: BitVector<16usize> = self.z()?.to_owned();
+                        value == other.z
+                    }) {
+                        return Ok(false);
+                    }
+                    if !(self.w()? == other.w) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<InnerProfile5> for InnerProfile5Ref<'a> {
+                fn eq(&self, other: &InnerProfile5) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<InnerProfile5Ref<'a>> for InnerProfile5 {
+                fn eq(&self, other: &InnerProfile5Ref<'a>) -> bool {
+                    other == self
+                }
             }
             #[derive(
                 std::clone::Clone,
@@ -2735,9 +4251,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         1usize,
@@ -2767,8 +4282,9 @@ pub mod tests {
                         None => return Ok(ssz_types::Optional::None),
                     };
                     let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(
-                        field_bytes,
-                    )?;
+                            field_bytes,
+                        )
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
                 pub fn w(&self) -> Result<AlphaProfileRef<'a>, ssz::DecodeError> {
@@ -2776,9 +4292,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         1usize,
@@ -2808,6 +4323,22 @@ pub mod tests {
                         None => unreachable!("required field is always active"),
                     };
                     ssz::view::DecodeView::from_ssz_bytes(field_bytes)
+                        .map_err(|e| e.bounded(field_bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ProfileProfileRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.x()?;
+                    let value = self.w()?;
+                    value.validate_deep()?;
+                    Ok(())
                 }
             }
             impl<'a> tree_hash::TreeHash for ProfileProfileRef<'a> {
@@ -2859,9 +4390,8 @@ pub mod tests {
                     use ssz::Decode;
                     let bitvector_bytes = bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         1usize,
@@ -2889,6 +4419,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for ProfileProfileRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ProfileProfileRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for ProfileProfileRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     false
@@ -2923,6 +4466,174 @@ pub mod tests {
                         },
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut ProfileProfile,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.x = self.x()?;
+                    out.w = {
+                        let view = self.w()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    Ok(())
+                }
+            }
+            impl<'a> TryFrom<InnerBaseRef<'a>> for ProfileProfile {
+                type Error = ssz::DecodeError;
+                fn try_from(value: InnerBaseRef<'a>) -> Result<Self, Self::Error> {
+                    use ssz::Decode;
+                    let bitvector_bytes = value
+                        .bytes
+                        .get(..1usize)
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - value.bytes.len(),
+                        })?;
+                    let bitvector = ssz_types::BitVector::<
+                        8usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    if bitvector.get(1usize).unwrap_or(false) {
+                        return Err(
+                            ssz::DecodeError::BytesInvalid(
+                                "field `y` is not part of profile `ProfileProfile` but active"
+                                    .to_string(),
+                            ),
+                        );
+                    }
+                    if bitvector.get(2usize).unwrap_or(false) {
+                        return Err(
+                            ssz::DecodeError::BytesInvalid(
+                                "field `z` is not part of profile `ProfileProfile` but active"
+                                    .to_string(),
+                            ),
+                        );
+                    }
+                    if !bitvector.get(3usize).unwrap_or(false) {
+                        return Err(
+                            ssz::DecodeError::BytesInvalid(
+                                "field `w` is required by profile `ProfileProfile` but inactive"
+                                    .to_string(),
+                            ),
+                        );
+                    }
+                    Ok(Self {
+                        x: value.x()?,
+                        w: <AlphaProfile>::try_from(value.w()?.unwrap())?,
+                    })
+                }
+            }
+            impl TryFrom<InnerBase> for ProfileProfile {
+                type Error = ssz::DecodeError;
+                fn try_from(value: InnerBase) -> Result<Self, Self::Error> {
+                    if value.y.is_some() {
+                        return Err(
+                            ssz::DecodeError::BytesInvalid(
+                                "field `y` is not part of profile `ProfileProfile` but active"
+                                    .to_string(),
+                            ),
+                        );
+                    }
+                    if value.z.is_some() {
+                        return Err(
+                            ssz::DecodeError::BytesInvalid(
+                                "field `z` is not part of profile `ProfileProfile` but active"
+                                    .to_string(),
+                            ),
+                        );
+                    }
+                    Ok(Self {
+                        x: value.x,
+                        w: <AlphaProfile>::try_from(
+                            match value.w {
+                                ssz_types::Optional::Some(inner) => inner,
+                                ssz_types::Optional::None => {
+                                    return Err(
+                                        ssz::DecodeError::BytesInvalid(
+                                            "field `w` is required by profile `ProfileProfile` but inactive"
+                                                .to_string(),
+                                        ),
+                                    );
+                                }
+                            },
+                        )?,
+                    })
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl ProfileProfile {
+                /// Converts this profile to its base StableContainer.
+                pub fn to_stable(&self) -> InnerBase {
+                    InnerBase {
+                        x: self.x,
+                        y: ssz_types::Optional::None,
+                        z: ssz_types::Optional::None,
+                        w: ssz_types::Optional::Some(self.w.to_stable()),
+                    }
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ProfileProfileRef<'a> {
+                /// Converts this profile view to its owned base StableContainer.
+                pub fn to_stable(&self) -> InnerBase {
+                    self.to_owned().to_stable()
+                }
+            }
+            impl From<ProfileProfile> for InnerBase {
+                fn from(value: ProfileProfile) -> Self {
+                    value.to_stable()
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ProfileProfileRef<'a> {
+                fn eq_owned(
+                    &self,
+                    other: &ProfileProfile,
+                ) -> Result<bool, ssz::DecodeError> {
+                    use ssz::Decode;
+                    let bitvector_bytes = self
+                        .bytes
+                        .get(..1usize)
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
+                        })?;
+                    let bitvector = ssz_types::BitVector::<
+                        1usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    if bitvector.get(0usize).unwrap_or(false) != other.x.is_some() {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<u8> = self.x()?;
+                        value == other.x
+                    }) {
+                        return Ok(false);
+                    }
+                    if !(self.w()? == other.w) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<ProfileProfile> for ProfileProfileRef<'a> {
+                fn eq(&self, other: &ProfileProfile) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<ProfileProfileRef<'a>> for ProfileProfile {
+                fn eq(&self, other: &ProfileProfileRef<'a>) -> bool {
+                    other == self
+                }
             }
             #[derive(
                 std::clone::Clone,
@@ -3080,9 +4791,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         8usize,
@@ -3150,8 +4860,9 @@ pub mod tests {
                         None => return Ok(ssz_types::Optional::None),
                     };
                     let inner = <u16 as ssz::view::DecodeView>::from_ssz_bytes(
-                        field_bytes,
-                    )?;
+                            field_bytes,
+                        )
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
                 pub fn y(
@@ -3161,9 +4872,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         8usize,
@@ -3233,7 +4943,8 @@ pub mod tests {
                     let inner = <BytesRef<
                         'a,
                         4usize,
-                    > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)?;
+                    > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
                 pub fn z(
@@ -3243,9 +4954,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         8usize,
@@ -3315,7 +5025,8 @@ pub mod tests {
                     let inner = <BitVectorRef<
                         'a,
                         16usize,
-                    > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)?;
+                    > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
                 pub fn w(&self) -> Result<Optional<AlphaRef<'a>>, ssz::DecodeError> {
@@ -3323,9 +5034,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         8usize,
@@ -3394,7 +5104,8 @@ pub mod tests {
                     };
                     let inner = <AlphaRef<
                         'a,
-                    > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)?;
+                    > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
                 pub fn a(&self) -> Result<Optional<u8>, ssz::DecodeError> {
@@ -3402,9 +5113,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         8usize,
@@ -3472,8 +5182,9 @@ pub mod tests {
                         None => return Ok(ssz_types::Optional::None),
                     };
                     let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(
-                        field_bytes,
-                    )?;
+                            field_bytes,
+                        )
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
                 pub fn b(&self) -> Result<Optional<u8>, ssz::DecodeError> {
@@ -3481,9 +5192,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         8usize,
@@ -3551,8 +5261,9 @@ pub mod tests {
                         None => return Ok(ssz_types::Optional::None),
                     };
                     let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(
-                        field_bytes,
-                    )?;
+                            field_bytes,
+                        )
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
                 pub fn c(&self) -> Result<Optional<u8>, ssz::DecodeError> {
@@ -3560,9 +5271,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         8usize,
@@ -3630,8 +5340,9 @@ pub mod tests {
                         None => return Ok(ssz_types::Optional::None),
                     };
                     let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(
-                        field_bytes,
-                    )?;
+                            field_bytes,
+                        )
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
                 pub fn d(&self) -> Result<Optional<u8>, ssz::DecodeError> {
@@ -3639,9 +5350,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         8usize,
@@ -3709,11 +5419,35 @@ pub mod tests {
                         None => return Ok(ssz_types::Optional::None),
                     };
                     let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(
-                        field_bytes,
-                    )?;
+                            field_bytes,
+                        )
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
             }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ContainerContainerRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.x()?;
+                    self.y()?;
+                    self.z()?;
+                    let value = self.w()?;
+                    if let ssz_types::Optional::Some(inner) = value {
+                        inner.validate_deep()?;
+                    }
+                    self.a()?;
+                    self.b()?;
+                    self.c()?;
+                    self.d()?;
+                    Ok(())
+                }
+            }
             impl<'a> tree_hash::TreeHash for ContainerContainerRef<'a> {
                 fn tree_hash_type() -> tree_hash::TreeHashType {
                     tree_hash::TreeHashType::StableContainer
@@ -3841,9 +5575,8 @@ pub mod tests {
                     use ssz::Decode;
                     let bitvector_bytes = bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         8usize,
@@ -3909,6 +5642,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for ContainerContainerRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ContainerContainerRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for ContainerContainerRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     false
@@ -3967,6 +5713,172 @@ pub mod tests {
                         d: self.d().expect("valid view"),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut ContainerContainer,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.x = self.x()?;
+                    out.y = match self.y()? {
+                        ssz_types::Optional::Some(inner) => {
+                            ssz_types::Optional::Some(
+                                ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                            )
+                        }
+                        ssz_types::Optional::None => ssz_types::Optional::None,
+                    };
+                    out.z = match self.z()? {
+                        ssz_types::Optional::Some(inner) => {
+                            ssz_types::Optional::Some(
+                                ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                            )
+                        }
+                        ssz_types::Optional::None => ssz_types::Optional::None,
+                    };
+                    out.w = match self.w()? {
+                        ssz_types::Optional::Some(inner) => {
+                            ssz_types::Optional::Some(
+                                ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                            )
+                        }
+                        ssz_types::Optional::None => ssz_types::Optional::None,
+                    };
+                    out.a = self.a()?;
+                    out.b = self.b()?;
+                    out.c = self.c()?;
+                    out.d = self.d()?;
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ContainerContainerRef<'a> {
+                fn eq_owned(
+                    &self,
+                    other: &ContainerContainer,
+                ) -> Result<bool, ssz::DecodeError> {
+                    use ssz::Decode;
+                    let bitvector_bytes = self
+                        .bytes
+                        .get(..1usize)
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
+                        })?;
+                    let bitvector = ssz_types::BitVector::<
+                        8usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    if bitvector.get(0usize).unwrap_or(false) != other.x.is_some() {
+                        return Ok(false);
+                    }
+                    if bitvector.get(1usize).unwrap_or(false) != other.y.is_some() {
+                        return Ok(false);
+                    }
+                    if bitvector.get(2usize).unwrap_or(false) != other.z.is_some() {
+                        return Ok(false);
+                    }
+                    if bitvector.get(3usize).unwrap_or(false) != other.w.is_some() {
+                        return Ok(false);
+                    }
+                    if bitvector.get(4usize).unwrap_or(false) != other.a.is_some() {
+                        return Ok(false);
+                    }
+                    if bitvector.get(5usize).unwrap_or(false) != other.b.is_some() {
+                        return Ok(false);
+                    }
+                    if bitvector.get(6usize).unwrap_or(false) != other.c.is_some() {
+                        return Ok(false);
+                    }
+                    if bitvector.get(7usize).unwrap_or(false) != other.d.is_some() {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<u16> = self.x()?;
+                        value == other.x
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<VariableList<u8, 4usize>> = match self.y()? {
+                            ssz_types::Optional::Some(inner) => {
+                                ssz_types::Optional::Some(
+                                    ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                                )
+                            }
+                            ssz_types::Optional::None => ssz_types::Optional::None,
+                        };
+                        value == other.y
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<BitVector<16usize>> = match self.z()? {
+                            ssz_types::Optional::Some(inner) => {
+                                ssz_types::Optional::Some(
+                                    ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                                )
+                            }
+                            ssz_types::Optional::None => ssz_types::Optional::None,
+                        };
+                        value == other.z
+                    }) {
+                        return Ok(false);
+                    }
+                    if !(match (self.w()?, &other.w) {
+                        (
+                            ssz_types::Optional::Some(view),
+                            ssz_types::Optional::Some(owned),
+                        ) => view == *owned,
+                        (ssz_types::Optional::None, ssz_types::Optional::None) => true,
+                        _ => false,
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<u8> = self.a()?;
+                        value == other.a
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<u8> = self.b()?;
+                        value == other.b
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<u8> = self.c()?;
+                        value == other.c
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<u8> = self.d()?;
+                        value == other.d
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<ContainerContainer> for ContainerContainerRef<'a> {
+                fn eq(&self, other: &ContainerContainer) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<ContainerContainerRef<'a>> for ContainerContainer {
+                fn eq(&self, other: &ContainerContainerRef<'a>) -> bool {
+                    other == self
+                }
             }
         }
     }
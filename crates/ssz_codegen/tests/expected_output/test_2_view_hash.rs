@@ -11,6 +11,13 @@ pub mod tests {
             use tree_hash::TreeHashDigest;
             use tree_hash_derive::TreeHash;
             use ssz::view::*;
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                113, 244, 143, 192, 205, 245, 250, 207, 169, 154, 91, 71, 201, 109, 118,
+                123, 250, 104, 92, 33, 45, 196, 204, 78, 39, 134, 170, 191, 191, 109,
+                201, 114,
+            ];
             #[derive(
                 std::clone::Clone,
                 std::fmt::Debug,
@@ -304,6 +311,30 @@ pub mod tests {
                         },
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Alpha,
+                ) -> Result<(), ssz::DecodeError> {
+                    out.a = self.a()?;
+                    out.b = match self.b()? {
+                        ssz_types::Optional::Some(inner) => {
+                            ssz_types::Optional::Some(
+                                ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                            )
+                        }
+                        ssz_types::Optional::None => ssz_types::Optional::None,
+                    };
+                    Ok(())
+                }
             }
             #[derive(
                 std::clone::Clone,
@@ -841,6 +872,46 @@ pub mod tests {
                         },
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut InnerBase,
+                ) -> Result<(), ssz::DecodeError> {
+                    out.x = self.x()?;
+                    out.y = match self.y()? {
+                        ssz_types::Optional::Some(inner) => {
+                            ssz_types::Optional::Some(
+                                ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                            )
+                        }
+                        ssz_types::Optional::None => ssz_types::Optional::None,
+                    };
+                    out.z = match self.z()? {
+                        ssz_types::Optional::Some(inner) => {
+                            ssz_types::Optional::Some(
+                                ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                            )
+                        }
+                        ssz_types::Optional::None => ssz_types::Optional::None,
+                    };
+                    out.w = match self.w()? {
+                        ssz_types::Optional::Some(inner) => {
+                            ssz_types::Optional::Some(
+                                ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                            )
+                        }
+                        ssz_types::Optional::None => ssz_types::Optional::None,
+                    };
+                    Ok(())
+                }
             }
             #[derive(
                 std::clone::Clone,
@@ -1354,6 +1425,46 @@ pub mod tests {
                         },
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut InnerProfile1,
+                ) -> Result<(), ssz::DecodeError> {
+                    out.x = self.x()?;
+                    out.y = match self.y()? {
+                        ssz_types::Optional::Some(inner) => {
+                            ssz_types::Optional::Some(
+                                ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                            )
+                        }
+                        ssz_types::Optional::None => ssz_types::Optional::None,
+                    };
+                    out.z = match self.z()? {
+                        ssz_types::Optional::Some(inner) => {
+                            ssz_types::Optional::Some(
+                                ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                            )
+                        }
+                        ssz_types::Optional::None => ssz_types::Optional::None,
+                    };
+                    out.w = match self.w()? {
+                        ssz_types::Optional::Some(inner) => {
+                            ssz_types::Optional::Some(
+                                ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                            )
+                        }
+                        ssz_types::Optional::None => ssz_types::Optional::None,
+                    };
+                    Ok(())
+                }
             }
             #[derive(
                 std::clone::Clone,
@@ -1704,6 +1815,26 @@ pub mod tests {
                         z: self.z().expect("valid view").to_owned(),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut InnerProfile2,
+                ) -> Result<(), ssz::DecodeError> {
+                    out.x = self.x()?;
+                    let view = self.y()?;
+                    out.y.clear();
+                    out.y.try_extend_from_slice(view.as_bytes()).expect("valid view");
+                    out.z = self.z()?.to_owned();
+                    Ok(())
+                }
             }
             #[derive(
                 std::clone::Clone,
@@ -1983,6 +2114,30 @@ pub mod tests {
                         },
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut AlphaProfile,
+                ) -> Result<(), ssz::DecodeError> {
+                    out.a = self.a()?;
+                    out.b = match self.b()? {
+                        ssz_types::Optional::Some(inner) => {
+                            ssz_types::Optional::Some(
+                                ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                            )
+                        }
+                        ssz_types::Optional::None => ssz_types::Optional::None,
+                    };
+                    Ok(())
+                }
             }
             #[derive(
                 std::clone::Clone,
@@ -2153,6 +2308,25 @@ pub mod tests {
                         },
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut InnerProfile3,
+                ) -> Result<(), ssz::DecodeError> {
+                    out.w = {
+                        let view = self.w()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    Ok(())
+                }
             }
             #[derive(
                 std::clone::Clone,
@@ -2383,6 +2557,25 @@ pub mod tests {
                         z: self.z().expect("valid view").to_owned(),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut InnerProfile4,
+                ) -> Result<(), ssz::DecodeError> {
+                    let view = self.y()?;
+                    out.y.clear();
+                    out.y.try_extend_from_slice(view.as_bytes()).expect("valid view");
+                    out.z = self.z()?.to_owned();
+                    Ok(())
+                }
             }
             #[derive(
                 std::clone::Clone,
@@ -2656,6 +2849,27 @@ pub mod tests {
                         },
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut InnerProfile5,
+                ) -> Result<(), ssz::DecodeError> {
+                    out.x = self.x()?;
+                    out.z = self.z()?.to_owned();
+                    out.w = {
+                        let view = self.w()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    Ok(())
+                }
             }
             #[derive(
                 std::clone::Clone,
@@ -2923,6 +3137,26 @@ pub mod tests {
                         },
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut ProfileProfile,
+                ) -> Result<(), ssz::DecodeError> {
+                    out.x = self.x()?;
+                    out.w = {
+                        let view = self.w()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    Ok(())
+                }
             }
             #[derive(
                 std::clone::Clone,
@@ -3967,6 +4201,50 @@ pub mod tests {
                         d: self.d().expect("valid view"),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut ContainerContainer,
+                ) -> Result<(), ssz::DecodeError> {
+                    out.x = self.x()?;
+                    out.y = match self.y()? {
+                        ssz_types::Optional::Some(inner) => {
+                            ssz_types::Optional::Some(
+                                ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                            )
+                        }
+                        ssz_types::Optional::None => ssz_types::Optional::None,
+                    };
+                    out.z = match self.z()? {
+                        ssz_types::Optional::Some(inner) => {
+                            ssz_types::Optional::Some(
+                                ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                            )
+                        }
+                        ssz_types::Optional::None => ssz_types::Optional::None,
+                    };
+                    out.w = match self.w()? {
+                        ssz_types::Optional::Some(inner) => {
+                            ssz_types::Optional::Some(
+                                ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                            )
+                        }
+                        ssz_types::Optional::None => ssz_types::Optional::None,
+                    };
+                    out.a = self.a()?;
+                    out.b = self.b()?;
+                    out.c = self.c()?;
+                    out.d = self.d()?;
+                    Ok(())
+                }
             }
         }
     }
@@ -0,0 +1,2167 @@
+pub mod tests {
+    #![allow(unused_imports, reason = "generated code using ssz-gen")]
+    pub mod input {
+        #![allow(unused_imports, reason = "generated code using ssz-gen")]
+        pub mod test_arbitrary {
+            #![allow(unused_imports, reason = "generated code using ssz-gen")]
+            use ssz_types::*;
+            use ssz_types::view::{FixedVectorRef, VariableListRef};
+            use ssz_primitives::{U128, U256};
+            use ssz_derive::{Encode, Decode};
+            use tree_hash::TreeHashDigest;
+            use tree_hash_derive::TreeHash;
+            use ssz::view::*;
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                216, 145, 184, 82, 188, 204, 251, 5, 21, 43, 29, 239, 4, 25, 52, 255, 35,
+                228, 78, 84, 199, 108, 129, 245, 212, 175, 105, 113, 10, 14, 55, 34,
+            ];
+            #[derive(
+                Clone,
+                Debug,
+                PartialEq,
+                Eq,
+                Encode,
+                Decode,
+                Hash,
+                arbitrary::Arbitrary
+            )]
+            #[ssz(enum_behaviour = "union")]
+            pub enum FirstUnion {
+                Selector0(u8),
+                Selector1(u16),
+            }
+            impl tree_hash::TreeHash for FirstUnion {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::Container
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Union should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Union should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    match self {
+                        FirstUnion::Selector0(inner) => {
+                            let root = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                                H,
+                            >(inner);
+                            tree_hash::mix_in_selector_with_hasher::<H>(&root, 0u8)
+                                .expect("valid selector")
+                        }
+                        FirstUnion::Selector1(inner) => {
+                            let root = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                                H,
+                            >(inner);
+                            tree_hash::mix_in_selector_with_hasher::<H>(&root, 1u8)
+                                .expect("valid selector")
+                        }
+                    }
+                }
+            }
+            impl std::fmt::Display for FirstUnion {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    match self {
+                        FirstUnion::Selector0(inner) => {
+                            write!(f, "FirstUnion::Selector0({inner:?})")
+                        }
+                        FirstUnion::Selector1(inner) => {
+                            write!(f, "FirstUnion::Selector1({inner:?})")
+                        }
+                    }
+                }
+            }
+            impl From<u8> for FirstUnion {
+                fn from(value: u8) -> Self {
+                    FirstUnion::Selector0(value)
+                }
+            }
+            impl From<u16> for FirstUnion {
+                fn from(value: u16) -> Self {
+                    FirstUnion::Selector1(value)
+                }
+            }
+            #[cfg(test)]
+            impl FirstUnion {
+                /// Returns one value per selector, in selector order, each
+                /// holding its member's default. Lets tests check that code
+                /// matching on the union handles every member.
+                pub fn all_selector_examples() -> Vec<Self> {
+                    vec![
+                        FirstUnion::Selector0(Default::default()),
+                        FirstUnion::Selector1(Default::default())
+                    ]
+                }
+            }
+            #[derive(Debug, Copy, Clone)]
+            pub struct FirstUnionRef<'a> {
+                bytes: &'a [u8],
+            }
+            impl<'a> FirstUnionRef<'a> {
+                pub fn selector(&self) -> u8 {
+                    self.bytes[0]
+                }
+                pub fn as_selector0(&self) -> Result<u8, ssz::DecodeError> {
+                    if self.selector() != 0u8 {
+                        return Err(
+                            ssz::DecodeError::BytesInvalid(
+                                "Wrong selector for FirstUnion: expected 0".to_string(),
+                            ),
+                        );
+                    }
+                    let body = &self.bytes[1..];
+                    if <u8 as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <u8 as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
+                }
+                pub fn as_selector1(&self) -> Result<u16, ssz::DecodeError> {
+                    if self.selector() != 1u8 {
+                        return Err(
+                            ssz::DecodeError::BytesInvalid(
+                                "Wrong selector for FirstUnion: expected 1".to_string(),
+                            ),
+                        );
+                    }
+                    let body = &self.bytes[1..];
+                    if <u16 as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <u16 as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
+                }
+                pub fn to_owned(&self) -> FirstUnion {
+                    match self.selector() {
+                        0u8 => {
+                            FirstUnion::Selector0(
+                                self.as_selector0().expect("valid selector"),
+                            )
+                        }
+                        1u8 => {
+                            FirstUnion::Selector1(
+                                self.as_selector1().expect("valid selector"),
+                            )
+                        }
+                        _ => panic!("Invalid union selector: {}", self.selector()),
+                    }
+                }
+                /// Validates the selector and the selected member, recursing
+                /// into nested views and walking every list and vector, and
+                /// returns the first error.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    match self.selector() {
+                        0u8 => self.as_selector0().map(|_| ()),
+                        1u8 => self.as_selector1().map(|_| ()),
+                        selector => Err(ssz::DecodeError::UnionSelectorInvalid(selector)),
+                    }
+                }
+            }
+            impl<'a> ssz::view::DecodeView<'a> for FirstUnionRef<'a> {
+                fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
+                    let (_, _) = ssz::split_union_bytes(bytes)?;
+                    Ok(Self { bytes })
+                }
+            }
+            impl<'a> ssz::view::SszTypeInfo for FirstUnionRef<'a> {
+                fn is_ssz_fixed_len() -> bool {
+                    false
+                }
+                fn ssz_fixed_len() -> usize {
+                    0
+                }
+            }
+            impl<'a> ssz_types::view::ToOwnedSsz<FirstUnion> for FirstUnionRef<'a> {
+                fn to_owned(&self) -> FirstUnion {
+                    <FirstUnionRef<'a>>::to_owned(self)
+                }
+            }
+            impl<'a> tree_hash::TreeHash for FirstUnionRef<'a> {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::Vector
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Union should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Union should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    match self.selector() {
+                        0u8 => {
+                            let value = self.as_selector0().expect("valid selector");
+                            tree_hash::mix_in_selector_with_hasher::<
+                                H,
+                            >(
+                                    &<_ as tree_hash::TreeHash>::tree_hash_root::<H>(&value),
+                                    0u8,
+                                )
+                                .expect("valid selector")
+                        }
+                        1u8 => {
+                            let value = self.as_selector1().expect("valid selector");
+                            tree_hash::mix_in_selector_with_hasher::<
+                                H,
+                            >(
+                                    &<_ as tree_hash::TreeHash>::tree_hash_root::<H>(&value),
+                                    1u8,
+                                )
+                                .expect("valid selector")
+                        }
+                        _ => panic!("Invalid union selector: {}", self.selector()),
+                    }
+                }
+            }
+            #[derive(
+                Clone,
+                Debug,
+                PartialEq,
+                Eq,
+                Encode,
+                Decode,
+                Hash,
+                arbitrary::Arbitrary
+            )]
+            #[ssz(enum_behaviour = "union")]
+            pub enum Vote {
+                Yes(u8),
+                No(u32),
+                Abstain(VariableList<u8, 4usize>),
+            }
+            impl tree_hash::TreeHash for Vote {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::Container
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Union should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Union should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    match self {
+                        Vote::Yes(inner) => {
+                            let root = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                                H,
+                            >(inner);
+                            tree_hash::mix_in_selector_with_hasher::<H>(&root, 0u8)
+                                .expect("valid selector")
+                        }
+                        Vote::No(inner) => {
+                            let root = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                                H,
+                            >(inner);
+                            tree_hash::mix_in_selector_with_hasher::<H>(&root, 1u8)
+                                .expect("valid selector")
+                        }
+                        Vote::Abstain(inner) => {
+                            let root = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                                H,
+                            >(inner);
+                            tree_hash::mix_in_selector_with_hasher::<H>(&root, 2u8)
+                                .expect("valid selector")
+                        }
+                    }
+                }
+            }
+            impl std::fmt::Display for Vote {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    match self {
+                        Vote::Yes(inner) => write!(f, "Vote::Yes({inner:?})"),
+                        Vote::No(inner) => write!(f, "Vote::No({inner:?})"),
+                        Vote::Abstain(inner) => write!(f, "Vote::Abstain({inner:?})"),
+                    }
+                }
+            }
+            impl From<u8> for Vote {
+                fn from(value: u8) -> Self {
+                    Vote::Yes(value)
+                }
+            }
+            impl From<u32> for Vote {
+                fn from(value: u32) -> Self {
+                    Vote::No(value)
+                }
+            }
+            impl From<VariableList<u8, 4usize>> for Vote {
+                fn from(value: VariableList<u8, 4usize>) -> Self {
+                    Vote::Abstain(value)
+                }
+            }
+            #[cfg(test)]
+            impl Vote {
+                /// Returns one value per selector, in selector order, each
+                /// holding its member's default. Lets tests check that code
+                /// matching on the union handles every member.
+                pub fn all_selector_examples() -> Vec<Self> {
+                    vec![
+                        Vote::Yes(Default::default()), Vote::No(Default::default()),
+                        Vote::Abstain(Default::default())
+                    ]
+                }
+            }
+            #[derive(Debug, Copy, Clone)]
+            pub struct VoteRef<'a> {
+                bytes: &'a [u8],
+            }
+            impl<'a> VoteRef<'a> {
+                pub fn selector(&self) -> u8 {
+                    self.bytes[0]
+                }
+                pub fn as_selector0(&self) -> Result<u8, ssz::DecodeError> {
+                    if self.selector() != 0u8 {
+                        return Err(
+                            ssz::DecodeError::BytesInvalid(
+                                "Wrong selector for Vote: expected 0".to_string(),
+                            ),
+                        );
+                    }
+                    let body = &self.bytes[1..];
+                    if <u8 as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <u8 as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
+                }
+                pub fn as_selector1(&self) -> Result<u32, ssz::DecodeError> {
+                    if self.selector() != 1u8 {
+                        return Err(
+                            ssz::DecodeError::BytesInvalid(
+                                "Wrong selector for Vote: expected 1".to_string(),
+                            ),
+                        );
+                    }
+                    let body = &self.bytes[1..];
+                    if <u32 as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <u32 as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
+                }
+                pub fn as_selector2(
+                    &self,
+                ) -> Result<BytesRef<'a, 4usize>, ssz::DecodeError> {
+                    if self.selector() != 2u8 {
+                        return Err(
+                            ssz::DecodeError::BytesInvalid(
+                                "Wrong selector for Vote: expected 2".to_string(),
+                            ),
+                        );
+                    }
+                    let body = &self.bytes[1..];
+                    if <VariableList<u8, 4usize> as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <VariableList<u8, 4usize> as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
+                }
+                pub fn to_owned(&self) -> Vote {
+                    match self.selector() {
+                        0u8 => Vote::Yes(self.as_selector0().expect("valid selector")),
+                        1u8 => Vote::No(self.as_selector1().expect("valid selector")),
+                        2u8 => {
+                            Vote::Abstain({
+                                let view = self.as_selector2().expect("valid selector");
+                                ssz_types::view::ToOwnedSsz::to_owned(&view)
+                            })
+                        }
+                        _ => panic!("Invalid union selector: {}", self.selector()),
+                    }
+                }
+                /// Validates the selector and the selected member, recursing
+                /// into nested views and walking every list and vector, and
+                /// returns the first error.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    match self.selector() {
+                        0u8 => self.as_selector0().map(|_| ()),
+                        1u8 => self.as_selector1().map(|_| ()),
+                        2u8 => self.as_selector2().map(|_| ()),
+                        selector => Err(ssz::DecodeError::UnionSelectorInvalid(selector)),
+                    }
+                }
+            }
+            impl<'a> ssz::view::DecodeView<'a> for VoteRef<'a> {
+                fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
+                    let (_, _) = ssz::split_union_bytes(bytes)?;
+                    Ok(Self { bytes })
+                }
+            }
+            impl<'a> ssz::view::SszTypeInfo for VoteRef<'a> {
+                fn is_ssz_fixed_len() -> bool {
+                    false
+                }
+                fn ssz_fixed_len() -> usize {
+                    0
+                }
+            }
+            impl<'a> ssz_types::view::ToOwnedSsz<Vote> for VoteRef<'a> {
+                fn to_owned(&self) -> Vote {
+                    <VoteRef<'a>>::to_owned(self)
+                }
+            }
+            impl<'a> tree_hash::TreeHash for VoteRef<'a> {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::Vector
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Union should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Union should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    match self.selector() {
+                        0u8 => {
+                            let value = self.as_selector0().expect("valid selector");
+                            tree_hash::mix_in_selector_with_hasher::<
+                                H,
+                            >(
+                                    &<_ as tree_hash::TreeHash>::tree_hash_root::<H>(&value),
+                                    0u8,
+                                )
+                                .expect("valid selector")
+                        }
+                        1u8 => {
+                            let value = self.as_selector1().expect("valid selector");
+                            tree_hash::mix_in_selector_with_hasher::<
+                                H,
+                            >(
+                                    &<_ as tree_hash::TreeHash>::tree_hash_root::<H>(&value),
+                                    1u8,
+                                )
+                                .expect("valid selector")
+                        }
+                        2u8 => {
+                            let value = self.as_selector2().expect("valid selector");
+                            tree_hash::mix_in_selector_with_hasher::<
+                                H,
+                            >(
+                                    &<_ as tree_hash::TreeHash>::tree_hash_root::<H>(&value),
+                                    2u8,
+                                )
+                                .expect("valid selector")
+                        }
+                        _ => panic!("Invalid union selector: {}", self.selector()),
+                    }
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const VAL_X: u64 = 42u64;
+            pub type AliasListAlias = VariableList<u8, 5usize>;
+            pub type BitAlias = BitList<{ VAL_X as usize }>;
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                ssz_derive::Encode,
+                ssz_derive::Decode,
+                arbitrary::Arbitrary
+            )]
+            #[ssz(struct_behaviour = "stable_container", max_fields = 128usize)]
+            pub struct Zeta {
+                pub u: Optional<FixedBytes<16usize>>,
+                pub v: Optional<AliasListAlias>,
+            }
+            impl tree_hash::TreeHash for Zeta {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::StableContainer
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("StableContainer/Profile should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("StableContainer/Profile should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    use ssz_types::BitVector;
+                    let mut active_fields = BitVector::<128usize>::new();
+                    if self.u.is_some() {
+                        active_fields
+                            .set(0usize, true)
+                            .expect("Should not be out of bounds");
+                    }
+                    if self.v.is_some() {
+                        active_fields
+                            .set(1usize, true)
+                            .expect("Should not be out of bounds");
+                    }
+                    let mut field_roots: Vec<<H as tree_hash::TreeHashDigest>::Output> = Vec::with_capacity(
+                        128usize,
+                    );
+                    if let ssz_types::Optional::Some(ref inner) = self.u {
+                        field_roots
+                            .push(
+                                <_ as tree_hash::TreeHash>::tree_hash_root::<H>(inner),
+                            );
+                    }
+                    if let ssz_types::Optional::Some(ref inner) = self.v {
+                        field_roots
+                            .push(
+                                <_ as tree_hash::TreeHash>::tree_hash_root::<H>(inner),
+                            );
+                    }
+                    let hash = tree_hash::merkleize_progressive_with_hasher::<
+                        H,
+                    >(&field_roots);
+                    let active_fields_hash = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                        H,
+                    >(&active_fields);
+                    H::hash32_concat(hash.as_ref(), active_fields_hash.as_ref())
+                }
+            }
+            /// Zero-copy view over [`Zeta`].
+            ///
+            /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
+            /// via lazy getter methods. Use `.to_owned()` to convert to the owned type when
+            /// needed.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                std::marker::Copy
+            )]
+            pub struct ZetaRef<'a> {
+                bytes: &'a [u8],
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ZetaRef<'a> {
+                pub fn u(
+                    &self,
+                ) -> Result<Optional<FixedBytesRef<'a, 16usize>>, ssz::DecodeError> {
+                    use ssz::Decode;
+                    let bitvector_bytes = self
+                        .bytes
+                        .get(..16usize)
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 16usize - self.bytes.len(),
+                        })?;
+                    let bitvector = ssz_types::BitVector::<
+                        128usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    let body = &self.bytes[16usize..];
+                    let field_active: &[bool] = &[
+                        bitvector.get(0usize).unwrap_or(false),
+                        bitvector.get(1usize).unwrap_or(false),
+                    ];
+                    let field_layout: &[ssz::layout::FieldInfo] = &[
+                        (
+                            <Optional<
+                                FixedBytes<16usize>,
+                            > as ssz::Encode>::is_ssz_fixed_len(),
+                            <Optional<
+                                FixedBytes<16usize>,
+                            > as ssz::Encode>::ssz_fixed_len(),
+                        ),
+                        (
+                            <Optional<
+                                AliasListAlias,
+                            > as ssz::Encode>::is_ssz_fixed_len(),
+                            <Optional<AliasListAlias> as ssz::Encode>::ssz_fixed_len(),
+                        ),
+                    ];
+                    let field_bytes = match ssz::layout::read_active_field_bytes(
+                        body,
+                        field_layout,
+                        |i| field_active[i],
+                        0usize,
+                    )? {
+                        Some(bytes) => bytes,
+                        None => return Ok(ssz_types::Optional::None),
+                    };
+                    let inner = <FixedBytesRef<
+                        'a,
+                        16usize,
+                    > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
+                    Ok(ssz_types::Optional::Some(inner))
+                }
+                pub fn v(
+                    &self,
+                ) -> Result<Optional<BytesRef<'a, 5usize>>, ssz::DecodeError> {
+                    use ssz::Decode;
+                    let bitvector_bytes = self
+                        .bytes
+                        .get(..16usize)
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 16usize - self.bytes.len(),
+                        })?;
+                    let bitvector = ssz_types::BitVector::<
+                        128usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    let body = &self.bytes[16usize..];
+                    let field_active: &[bool] = &[
+                        bitvector.get(0usize).unwrap_or(false),
+                        bitvector.get(1usize).unwrap_or(false),
+                    ];
+                    let field_layout: &[ssz::layout::FieldInfo] = &[
+                        (
+                            <Optional<
+                                FixedBytes<16usize>,
+                            > as ssz::Encode>::is_ssz_fixed_len(),
+                            <Optional<
+                                FixedBytes<16usize>,
+                            > as ssz::Encode>::ssz_fixed_len(),
+                        ),
+                        (
+                            <Optional<
+                                AliasListAlias,
+                            > as ssz::Encode>::is_ssz_fixed_len(),
+                            <Optional<AliasListAlias> as ssz::Encode>::ssz_fixed_len(),
+                        ),
+                    ];
+                    let field_bytes = match ssz::layout::read_active_field_bytes(
+                        body,
+                        field_layout,
+                        |i| field_active[i],
+                        1usize,
+                    )? {
+                        Some(bytes) => bytes,
+                        None => return Ok(ssz_types::Optional::None),
+                    };
+                    let inner = <BytesRef<
+                        'a,
+                        5usize,
+                    > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
+                    Ok(ssz_types::Optional::Some(inner))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ZetaRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.u()?;
+                    self.v()?;
+                    Ok(())
+                }
+            }
+            impl<'a> tree_hash::TreeHash for ZetaRef<'a> {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::StableContainer
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("StableContainer should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("StableContainer should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    use ssz_types::BitVector;
+                    let u = self.u().expect("valid view");
+                    let v = self.v().expect("valid view");
+                    let mut active_fields = BitVector::<128usize>::new();
+                    if u.is_some() {
+                        active_fields
+                            .set(0usize, true)
+                            .expect("Should not be out of bounds");
+                    }
+                    if v.is_some() {
+                        active_fields
+                            .set(1usize, true)
+                            .expect("Should not be out of bounds");
+                    }
+                    let mut field_roots: Vec<<H as tree_hash::TreeHashDigest>::Output> = Vec::with_capacity(
+                        128usize,
+                    );
+                    if let ssz_types::Optional::Some(ref inner) = u {
+                        field_roots
+                            .push(
+                                <_ as tree_hash::TreeHash>::tree_hash_root::<H>(inner),
+                            );
+                    }
+                    if let ssz_types::Optional::Some(ref inner) = v {
+                        field_roots
+                            .push(
+                                <_ as tree_hash::TreeHash>::tree_hash_root::<H>(inner),
+                            );
+                    }
+                    let hash = tree_hash::merkleize_progressive_with_hasher::<
+                        H,
+                    >(&field_roots);
+                    let active_fields_hash = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                        H,
+                    >(&active_fields);
+                    H::hash32_concat(hash.as_ref(), active_fields_hash.as_ref())
+                }
+            }
+            impl<'a> ssz::view::DecodeView<'a> for ZetaRef<'a> {
+                fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
+                    use ssz::Decode;
+                    let bitvector_bytes = bytes
+                        .get(..16usize)
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 16usize - bytes.len(),
+                        })?;
+                    let bitvector = ssz_types::BitVector::<
+                        128usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    let body = &bytes[16usize..];
+                    let field_active: &[bool] = &[
+                        bitvector.get(0usize).unwrap_or(false),
+                        bitvector.get(1usize).unwrap_or(false),
+                    ];
+                    let field_layout: &[ssz::layout::FieldInfo] = &[
+                        (
+                            <Optional<
+                                FixedBytes<16usize>,
+                            > as ssz::Encode>::is_ssz_fixed_len(),
+                            <Optional<
+                                FixedBytes<16usize>,
+                            > as ssz::Encode>::ssz_fixed_len(),
+                        ),
+                        (
+                            <Optional<
+                                AliasListAlias,
+                            > as ssz::Encode>::is_ssz_fixed_len(),
+                            <Optional<AliasListAlias> as ssz::Encode>::ssz_fixed_len(),
+                        ),
+                    ];
+                    for index in 2usize..128usize {
+                        if bitvector.get(index).unwrap_or(false) {
+                            return Err(
+                                ssz::DecodeError::BytesInvalid(
+                                    "StableContainer has active_fields bits set beyond field count"
+                                        .to_string(),
+                                ),
+                            );
+                        }
+                    }
+                    ssz::layout::validate_active_container(
+                        body,
+                        field_layout,
+                        |i| field_active[i],
+                    )?;
+                    Ok(Self { bytes })
+                }
+            }
+            impl<'a> TryFrom<&'a [u8]> for ZetaRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ZetaRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
+            impl<'a> ssz::view::SszTypeInfo for ZetaRef<'a> {
+                fn is_ssz_fixed_len() -> bool {
+                    false
+                }
+                fn ssz_fixed_len() -> usize {
+                    0
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ssz_types::view::ToOwnedSsz<Zeta> for ZetaRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                fn to_owned(&self) -> Zeta {
+                    <ZetaRef<'a>>::to_owned(self)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ZetaRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned(&self) -> Zeta {
+                    Zeta {
+                        u: match self.u().expect("valid view") {
+                            ssz_types::Optional::Some(inner) => {
+                                ssz_types::Optional::Some(
+                                    ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                                )
+                            }
+                            ssz_types::Optional::None => ssz_types::Optional::None,
+                        },
+                        v: match self.v().expect("valid view") {
+                            ssz_types::Optional::Some(inner) => {
+                                ssz_types::Optional::Some(
+                                    ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                                )
+                            }
+                            ssz_types::Optional::None => ssz_types::Optional::None,
+                        },
+                    }
+                }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Zeta,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.u = match self.u()? {
+                        ssz_types::Optional::Some(inner) => {
+                            ssz_types::Optional::Some(
+                                ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                            )
+                        }
+                        ssz_types::Optional::None => ssz_types::Optional::None,
+                    };
+                    out.v = match self.v()? {
+                        ssz_types::Optional::Some(inner) => {
+                            ssz_types::Optional::Some(
+                                ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                            )
+                        }
+                        ssz_types::Optional::None => ssz_types::Optional::None,
+                    };
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ZetaRef<'a> {
+                fn eq_owned(&self, other: &Zeta) -> Result<bool, ssz::DecodeError> {
+                    use ssz::Decode;
+                    let bitvector_bytes = self
+                        .bytes
+                        .get(..16usize)
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 16usize - self.bytes.len(),
+                        })?;
+                    let bitvector = ssz_types::BitVector::<
+                        128usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    if bitvector.get(0usize).unwrap_or(false) != other.u.is_some() {
+                        return Ok(false);
+                    }
+                    if bitvector.get(1usize).unwrap_or(false) != other.v.is_some() {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<FixedBytes<16usize>> = match self.u()? {
+                            ssz_types::Optional::Some(inner) => {
+                                ssz_types::Optional::Some(
+                                    ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                                )
+                            }
+                            ssz_types::Optional::None => ssz_types::Optional::None,
+                        };
+                        value == other.u
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<AliasListAlias> = match self.v()? {
+                            ssz_types::Optional::Some(inner) => {
+                                ssz_types::Optional::Some(
+                                    ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                                )
+                            }
+                            ssz_types::Optional::None => ssz_types::Optional::None,
+                        };
+                        value == other.v
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<Zeta> for ZetaRef<'a> {
+                fn eq(&self, other: &Zeta) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<ZetaRef<'a>> for Zeta {
+                fn eq(&self, other: &ZetaRef<'a>) -> bool {
+                    other == self
+                }
+            }
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                ssz_derive::Encode,
+                ssz_derive::Decode,
+                arbitrary::Arbitrary
+            )]
+            #[ssz(struct_behaviour = "container")]
+            pub struct TestType {
+                pub ccc: u8,
+                pub ddd: u8,
+                pub eee: VariableList<u16, 3usize>,
+                pub large_int_128: U128,
+                pub large_int_256: U256,
+            }
+            impl tree_hash::TreeHash for TestType {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::Container
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(5usize);
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.ccc)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.ddd)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.eee)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<
+                                H,
+                            >(&self.large_int_128)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<
+                                H,
+                            >(&self.large_int_256)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .finish()
+                        .expect("tree hash derive should not have a remaining buffer")
+                }
+            }
+            impl tree_hash::TreeHashLeaves for TestType {
+                fn tree_hash_leaf_count() -> usize {
+                    5usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.ccc)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.ddd)
+                        }
+                        2usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.eee)
+                        }
+                        3usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<
+                                H,
+                            >(&self.large_int_128)
+                        }
+                        4usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<
+                                H,
+                            >(&self.large_int_256)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 5usize
+                            )
+                        }
+                    }
+                }
+            }
+            /// Zero-copy view over [`TestType`].
+            ///
+            /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
+            /// via lazy getter methods. Use `.to_owned()` to convert to the owned type when
+            /// needed.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                std::marker::Copy
+            )]
+            pub struct TestTypeRef<'a> {
+                bytes: &'a [u8],
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> TestTypeRef<'a> {
+                pub fn ccc(&self) -> Result<u8, ssz::DecodeError> {
+                    let bytes = ssz::layout::read_field_bytes(
+                        self.bytes,
+                        &[
+                            (
+                                <u8 as ssz::Encode>::is_ssz_fixed_len(),
+                                <u8 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <u8 as ssz::Encode>::is_ssz_fixed_len(),
+                                <u8 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <VariableList<
+                                    u16,
+                                    3usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <VariableList<u16, 3usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <U128 as ssz::Encode>::is_ssz_fixed_len(),
+                                <U128 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <U256 as ssz::Encode>::is_ssz_fixed_len(),
+                                <U256 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                        0usize,
+                    )?;
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+                pub fn ddd(&self) -> Result<u8, ssz::DecodeError> {
+                    let bytes = ssz::layout::read_field_bytes(
+                        self.bytes,
+                        &[
+                            (
+                                <u8 as ssz::Encode>::is_ssz_fixed_len(),
+                                <u8 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <u8 as ssz::Encode>::is_ssz_fixed_len(),
+                                <u8 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <VariableList<
+                                    u16,
+                                    3usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <VariableList<u16, 3usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <U128 as ssz::Encode>::is_ssz_fixed_len(),
+                                <U128 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <U256 as ssz::Encode>::is_ssz_fixed_len(),
+                                <U256 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                        1usize,
+                    )?;
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+                pub fn eee(&self) -> Result<ListRef<'a, u16, 3usize>, ssz::DecodeError> {
+                    let bytes = ssz::layout::read_field_bytes(
+                        self.bytes,
+                        &[
+                            (
+                                <u8 as ssz::Encode>::is_ssz_fixed_len(),
+                                <u8 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <u8 as ssz::Encode>::is_ssz_fixed_len(),
+                                <u8 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <VariableList<
+                                    u16,
+                                    3usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <VariableList<u16, 3usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <U128 as ssz::Encode>::is_ssz_fixed_len(),
+                                <U128 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <U256 as ssz::Encode>::is_ssz_fixed_len(),
+                                <U256 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                        2usize,
+                    )?;
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+                pub fn large_int_128(&self) -> Result<U128, ssz::DecodeError> {
+                    let bytes = ssz::layout::read_field_bytes(
+                        self.bytes,
+                        &[
+                            (
+                                <u8 as ssz::Encode>::is_ssz_fixed_len(),
+                                <u8 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <u8 as ssz::Encode>::is_ssz_fixed_len(),
+                                <u8 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <VariableList<
+                                    u16,
+                                    3usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <VariableList<u16, 3usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <U128 as ssz::Encode>::is_ssz_fixed_len(),
+                                <U128 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <U256 as ssz::Encode>::is_ssz_fixed_len(),
+                                <U256 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                        3usize,
+                    )?;
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+                pub fn large_int_256(&self) -> Result<U256, ssz::DecodeError> {
+                    let bytes = ssz::layout::read_field_bytes(
+                        self.bytes,
+                        &[
+                            (
+                                <u8 as ssz::Encode>::is_ssz_fixed_len(),
+                                <u8 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <u8 as ssz::Encode>::is_ssz_fixed_len(),
+                                <u8 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <VariableList<
+                                    u16,
+                                    3usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <VariableList<u16, 3usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <U128 as ssz::Encode>::is_ssz_fixed_len(),
+                                <U128 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <U256 as ssz::Encode>::is_ssz_fixed_len(),
+                                <U256 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                        4usize,
+                    )?;
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> TestTypeRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.ccc()?;
+                    self.ddd()?;
+                    let value = self.eee()?;
+                    for item in value.iter() {
+                        item?;
+                    }
+                    self.large_int_128()?;
+                    self.large_int_256()?;
+                    Ok(())
+                }
+            }
+            impl<'a> tree_hash::TreeHash for TestTypeRef<'a> {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::StableContainer
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(5usize);
+                    {
+                        let ccc = self.ccc().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&ccc);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    {
+                        let ddd = self.ddd().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&ddd);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    {
+                        let eee = self.eee().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&eee);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    {
+                        let large_int_128 = self.large_int_128().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&large_int_128);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    {
+                        let large_int_256 = self.large_int_256().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&large_int_256);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    hasher.finish().expect("finish hasher")
+                }
+            }
+            impl<'a> ssz::view::DecodeView<'a> for TestTypeRef<'a> {
+                fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
+                    ssz::layout::validate_container(
+                        bytes,
+                        &[
+                            (
+                                <u8 as ssz::Encode>::is_ssz_fixed_len(),
+                                <u8 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <u8 as ssz::Encode>::is_ssz_fixed_len(),
+                                <u8 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <VariableList<
+                                    u16,
+                                    3usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <VariableList<u16, 3usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <U128 as ssz::Encode>::is_ssz_fixed_len(),
+                                <U128 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <U256 as ssz::Encode>::is_ssz_fixed_len(),
+                                <U256 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                    )?;
+                    Ok(Self { bytes })
+                }
+            }
+            impl<'a> TryFrom<&'a [u8]> for TestTypeRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> TestTypeRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
+            impl<'a> ssz::view::SszTypeInfo for TestTypeRef<'a> {
+                fn is_ssz_fixed_len() -> bool {
+                    usize::from(!<u8 as ssz::Encode>::is_ssz_fixed_len())
+                        + usize::from(!<u8 as ssz::Encode>::is_ssz_fixed_len())
+                        + usize::from(
+                            !<VariableList<
+                                u16,
+                                3usize,
+                            > as ssz::Encode>::is_ssz_fixed_len(),
+                        ) + usize::from(!<U128 as ssz::Encode>::is_ssz_fixed_len())
+                        + usize::from(!<U256 as ssz::Encode>::is_ssz_fixed_len()) == 0
+                }
+                fn ssz_fixed_len() -> usize {
+                    if <Self as ssz::view::SszTypeInfo>::is_ssz_fixed_len() {
+                        <u8 as ssz::Encode>::ssz_fixed_len()
+                            + <u8 as ssz::Encode>::ssz_fixed_len()
+                            + <VariableList<u16, 3usize> as ssz::Encode>::ssz_fixed_len()
+                            + <U128 as ssz::Encode>::ssz_fixed_len()
+                            + <U256 as ssz::Encode>::ssz_fixed_len()
+                    } else {
+                        0
+                    }
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ssz_types::view::ToOwnedSsz<TestType> for TestTypeRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                fn to_owned(&self) -> TestType {
+                    <TestTypeRef<'a>>::to_owned(self)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> TestTypeRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned(&self) -> TestType {
+                    TestType {
+                        ccc: self.ccc().expect("valid view"),
+                        ddd: self.ddd().expect("valid view"),
+                        eee: {
+                            let view = self.eee().expect("valid view");
+                            let items: Result<Vec<_>, _> = view
+                                .iter()
+                                .map(|item_result| {
+                                    item_result
+                                        .map(|item| ssz_types::view::ToOwnedSsz::to_owned(&item))
+                                })
+                                .collect();
+                            let items = items.expect("valid view");
+                            ssz_types::VariableList::new(items).expect("valid view")
+                        },
+                        large_int_128: self.large_int_128().expect("valid view"),
+                        large_int_256: self.large_int_256().expect("valid view"),
+                    }
+                }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut TestType,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.ccc = self.ccc()?;
+                    out.ddd = self.ddd()?;
+                    let view = self.eee()?;
+                    out.eee.clear();
+                    for item in view.iter() {
+                        out.eee
+                            .push(ssz_types::view::ToOwnedSsz::to_owned(&item?))
+                            .expect("valid view");
+                    }
+                    out.large_int_128 = self.large_int_128()?;
+                    out.large_int_256 = self.large_int_256()?;
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> TestTypeRef<'a> {
+                fn eq_owned(&self, other: &TestType) -> Result<bool, ssz::DecodeError> {
+                    if !({
+                        let value: u8 = self.ccc()?;
+                        value == other.ccc
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: u8 = self.ddd()?;
+                        value == other.ddd
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: VariableList<u16, 3usize> = {
+                            let view = self.eee()?;
+                            let items: Result<Vec<_>, _> = view
+                                .iter()
+                                .map(|item_result| {
+                                    item_result
+                                        .map(|item| ssz_types::view::ToOwnedSsz::to_owned(&item))
+                                })
+                                .collect();
+                            let items = items.expect("valid view");
+                            ssz_types::VariableList::new(items).expect("valid view")
+                        };
+                        value == other.eee
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: U128 = self.large_int_128()?;
+                        value == other.large_int_128
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: U256 = self.large_int_256()?;
+                        value == other.large_int_256
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<TestType> for TestTypeRef<'a> {
+                fn eq(&self, other: &TestType) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<TestTypeRef<'a>> for TestType {
+                fn eq(&self, other: &TestTypeRef<'a>) -> bool {
+                    other == self
+                }
+            }
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                ssz_derive::Encode,
+                ssz_derive::Decode,
+                arbitrary::Arbitrary
+            )]
+            #[ssz(struct_behaviour = "container")]
+            pub struct Eta {
+                pub l: Zeta,
+                pub m: TestType,
+                pub n: FirstUnion,
+            }
+            impl tree_hash::TreeHash for Eta {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::Container
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(3usize);
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.l)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.m)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.n)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .finish()
+                        .expect("tree hash derive should not have a remaining buffer")
+                }
+            }
+            impl tree_hash::TreeHashLeaves for Eta {
+                fn tree_hash_leaf_count() -> usize {
+                    3usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.l)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.m)
+                        }
+                        2usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.n)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 3usize
+                            )
+                        }
+                    }
+                }
+            }
+            /// Zero-copy view over [`Eta`].
+            ///
+            /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
+            /// via lazy getter methods. Use `.to_owned()` to convert to the owned type when
+            /// needed.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                std::marker::Copy
+            )]
+            pub struct EtaRef<'a> {
+                bytes: &'a [u8],
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> EtaRef<'a> {
+                pub fn l(&self) -> Result<ZetaRef<'a>, ssz::DecodeError> {
+                    let bytes = ssz::layout::read_field_bytes(
+                        self.bytes,
+                        &[
+                            (
+                                <Zeta as ssz::Encode>::is_ssz_fixed_len(),
+                                <Zeta as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <TestType as ssz::Encode>::is_ssz_fixed_len(),
+                                <TestType as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <FirstUnion as ssz::Encode>::is_ssz_fixed_len(),
+                                <FirstUnion as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                        0usize,
+                    )?;
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+                pub fn m(&self) -> Result<TestTypeRef<'a>, ssz::DecodeError> {
+                    let bytes = ssz::layout::read_field_bytes(
+                        self.bytes,
+                        &[
+                            (
+                                <Zeta as ssz::Encode>::is_ssz_fixed_len(),
+                                <Zeta as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <TestType as ssz::Encode>::is_ssz_fixed_len(),
+                                <TestType as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <FirstUnion as ssz::Encode>::is_ssz_fixed_len(),
+                                <FirstUnion as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                        1usize,
+                    )?;
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+                pub fn n(&self) -> Result<FirstUnionRef<'a>, ssz::DecodeError> {
+                    let bytes = ssz::layout::read_field_bytes(
+                        self.bytes,
+                        &[
+                            (
+                                <Zeta as ssz::Encode>::is_ssz_fixed_len(),
+                                <Zeta as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <TestType as ssz::Encode>::is_ssz_fixed_len(),
+                                <TestType as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <FirstUnion as ssz::Encode>::is_ssz_fixed_len(),
+                                <FirstUnion as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                        2usize,
+                    )?;
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> EtaRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    let value = self.l()?;
+                    value.validate_deep()?;
+                    let value = self.m()?;
+                    value.validate_deep()?;
+                    let value = self.n()?;
+                    value.validate_deep()?;
+                    Ok(())
+                }
+            }
+            impl<'a> tree_hash::TreeHash for EtaRef<'a> {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::StableContainer
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(3usize);
+                    {
+                        let l = self.l().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&l);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    {
+                        let m = self.m().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&m);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    {
+                        let n = self.n().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&n);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    hasher.finish().expect("finish hasher")
+                }
+            }
+            impl<'a> ssz::view::DecodeView<'a> for EtaRef<'a> {
+                fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
+                    ssz::layout::validate_container(
+                        bytes,
+                        &[
+                            (
+                                <Zeta as ssz::Encode>::is_ssz_fixed_len(),
+                                <Zeta as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <TestType as ssz::Encode>::is_ssz_fixed_len(),
+                                <TestType as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <FirstUnion as ssz::Encode>::is_ssz_fixed_len(),
+                                <FirstUnion as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                    )?;
+                    Ok(Self { bytes })
+                }
+            }
+            impl<'a> TryFrom<&'a [u8]> for EtaRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> EtaRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
+            impl<'a> ssz::view::SszTypeInfo for EtaRef<'a> {
+                fn is_ssz_fixed_len() -> bool {
+                    usize::from(!<Zeta as ssz::Encode>::is_ssz_fixed_len())
+                        + usize::from(!<TestType as ssz::Encode>::is_ssz_fixed_len())
+                        + usize::from(!<FirstUnion as ssz::Encode>::is_ssz_fixed_len())
+                        == 0
+                }
+                fn ssz_fixed_len() -> usize {
+                    if <Self as ssz::view::SszTypeInfo>::is_ssz_fixed_len() {
+                        <Zeta as ssz::Encode>::ssz_fixed_len()
+                            + <TestType as ssz::Encode>::ssz_fixed_len()
+                            + <FirstUnion as ssz::Encode>::ssz_fixed_len()
+                    } else {
+                        0
+                    }
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ssz_types::view::ToOwnedSsz<Eta> for EtaRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                fn to_owned(&self) -> Eta {
+                    <EtaRef<'a>>::to_owned(self)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> EtaRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned(&self) -> Eta {
+                    Eta {
+                        l: {
+                            let view = self.l().expect("valid view");
+                            ssz_types::view::ToOwnedSsz::to_owned(&view)
+                        },
+                        m: {
+                            let view = self.m().expect("valid view");
+                            ssz_types::view::ToOwnedSsz::to_owned(&view)
+                        },
+                        n: {
+                            let view = self.n().expect("valid view");
+                            ssz_types::view::ToOwnedSsz::to_owned(&view)
+                        },
+                    }
+                }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Eta,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.l = {
+                        let view = self.l()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    out.m = {
+                        let view = self.m()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    out.n = {
+                        let view = self.n()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> EtaRef<'a> {
+                fn eq_owned(&self, other: &Eta) -> Result<bool, ssz::DecodeError> {
+                    if !(self.l()? == other.l) {
+                        return Ok(false);
+                    }
+                    if !(self.m()? == other.m) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: FirstUnion = {
+                            let view = self.n()?;
+                            ssz_types::view::ToOwnedSsz::to_owned(&view)
+                        };
+                        value == other.n
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<Eta> for EtaRef<'a> {
+                fn eq(&self, other: &Eta) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<EtaRef<'a>> for Eta {
+                fn eq(&self, other: &EtaRef<'a>) -> bool {
+                    other == self
+                }
+            }
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                ssz_derive::Encode,
+                ssz_derive::Decode,
+                arbitrary::Arbitrary
+            )]
+            #[ssz(struct_behaviour = "container")]
+            pub struct Ballot {
+                pub voters: BitAlias,
+                pub votes: VariableList<Vote, 8usize>,
+            }
+            impl tree_hash::TreeHash for Ballot {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::Container
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(2usize);
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.voters)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.votes)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .finish()
+                        .expect("tree hash derive should not have a remaining buffer")
+                }
+            }
+            impl tree_hash::TreeHashLeaves for Ballot {
+                fn tree_hash_leaf_count() -> usize {
+                    2usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.voters)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.votes)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 2usize
+                            )
+                        }
+                    }
+                }
+            }
+            /// Zero-copy view over [`Ballot`].
+            ///
+            /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
+            /// via lazy getter methods. Use `.to_owned()` to convert to the owned type when
+            /// needed.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                std::marker::Copy
+            )]
+            pub struct BallotRef<'a> {
+                bytes: &'a [u8],
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> BallotRef<'a> {
+                pub fn voters(
+                    &self,
+                ) -> Result<BitListRef<'a, 42usize>, ssz::DecodeError> {
+                    let bytes = ssz::layout::read_field_bytes(
+                        self.bytes,
+                        &[
+                            (
+                                <BitAlias as ssz::Encode>::is_ssz_fixed_len(),
+                                <BitAlias as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <VariableList<
+                                    Vote,
+                                    8usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <VariableList<Vote, 8usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                        0usize,
+                    )?;
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+                pub fn votes(
+                    &self,
+                ) -> Result<ListRef<'a, VoteRef<'a>, 8usize>, ssz::DecodeError> {
+                    let bytes = ssz::layout::read_field_bytes(
+                        self.bytes,
+                        &[
+                            (
+                                <BitAlias as ssz::Encode>::is_ssz_fixed_len(),
+                                <BitAlias as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <VariableList<
+                                    Vote,
+                                    8usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <VariableList<Vote, 8usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                        1usize,
+                    )?;
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> BallotRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.voters()?;
+                    let value = self.votes()?;
+                    for item in value.iter() {
+                        let item = item?;
+                        item.validate_deep()?;
+                    }
+                    Ok(())
+                }
+            }
+            impl<'a> tree_hash::TreeHash for BallotRef<'a> {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::StableContainer
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(2usize);
+                    {
+                        let voters = self.voters().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&voters);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    {
+                        let votes = self.votes().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&votes);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    hasher.finish().expect("finish hasher")
+                }
+            }
+            impl<'a> ssz::view::DecodeView<'a> for BallotRef<'a> {
+                fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
+                    ssz::layout::validate_container(
+                        bytes,
+                        &[
+                            (
+                                <BitAlias as ssz::Encode>::is_ssz_fixed_len(),
+                                <BitAlias as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <VariableList<
+                                    Vote,
+                                    8usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <VariableList<Vote, 8usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                    )?;
+                    Ok(Self { bytes })
+                }
+            }
+            impl<'a> TryFrom<&'a [u8]> for BallotRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> BallotRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
+            impl<'a> ssz::view::SszTypeInfo for BallotRef<'a> {
+                fn is_ssz_fixed_len() -> bool {
+                    usize::from(!<BitAlias as ssz::Encode>::is_ssz_fixed_len())
+                        + usize::from(
+                            !<VariableList<
+                                Vote,
+                                8usize,
+                            > as ssz::Encode>::is_ssz_fixed_len(),
+                        ) == 0
+                }
+                fn ssz_fixed_len() -> usize {
+                    if <Self as ssz::view::SszTypeInfo>::is_ssz_fixed_len() {
+                        <BitAlias as ssz::Encode>::ssz_fixed_len()
+                            + <VariableList<
+                                Vote,
+                                8usize,
+                            > as ssz::Encode>::ssz_fixed_len()
+                    } else {
+                        0
+                    }
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ssz_types::view::ToOwnedSsz<Ballot> for BallotRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                fn to_owned(&self) -> Ballot {
+                    <BallotRef<'a>>::to_owned(self)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> BallotRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned(&self) -> Ballot {
+                    Ballot {
+                        voters: self.voters().expect("valid view").to_owned(),
+                        votes: {
+                            let view = self.votes().expect("valid view");
+                            let items: Result<Vec<_>, _> = view
+                                .iter()
+                                .map(|item_result| {
+                                    item_result
+                                        .map(|item| ssz_types::view::ToOwnedSsz::to_owned(&item))
+                                })
+                                .collect();
+                            let items = items.expect("valid view");
+                            ssz_types::VariableList::new(items).expect("valid view")
+                        },
+                    }
+                }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Ballot,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.voters = self.voters()?.to_owned();
+                    let view = self.votes()?;
+                    out.votes.clear();
+                    for item in view.iter() {
+                        out.votes
+                            .push(ssz_types::view::ToOwnedSsz::to_owned(&item?))
+                            .expect("valid view");
+                    }
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> BallotRef<'a> {
+                fn eq_owned(&self, other: &Ballot) -> Result<bool, ssz::DecodeError> {
+                    if !({
+                        let value: BitAlias = self.voters()?.to_owned();
+                        value == other.voters
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: VariableList<Vote, 8usize> = {
+                            let view = self.votes()?;
+                            let items: Result<Vec<_>, _> = view
+                                .iter()
+                                .map(|item_result| {
+                                    item_result
+                                        .map(|item| ssz_types::view::ToOwnedSsz::to_owned(&item))
+                                })
+                                .collect();
+                            let items = items.expect("valid view");
+                            ssz_types::VariableList::new(items).expect("valid view")
+                        };
+                        value == other.votes
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<Ballot> for BallotRef<'a> {
+                fn eq(&self, other: &Ballot) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<BallotRef<'a>> for Ballot {
+                fn eq(&self, other: &BallotRef<'a>) -> bool {
+                    other == self
+                }
+            }
+        }
+    }
+}
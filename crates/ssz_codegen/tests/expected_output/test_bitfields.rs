@@ -11,6 +11,13 @@ pub mod tests {
             use tree_hash::TreeHashDigest;
             use tree_hash_derive::TreeHash;
             use ssz::view::*;
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                121, 231, 40, 142, 222, 86, 231, 115, 210, 115, 78, 212, 197, 111, 23,
+                101, 144, 33, 203, 220, 109, 189, 11, 181, 206, 104, 158, 16, 19, 210,
+                95, 157,
+            ];
             #[allow(dead_code, reason = "generated code using ssz-gen")]
             pub const SMALL_SIZE: u64 = 1u64;
             #[allow(dead_code, reason = "generated code using ssz-gen")]
@@ -108,6 +115,53 @@ pub mod tests {
                         .expect("tree hash derive should not have a remaining buffer")
                 }
             }
+            impl tree_hash::TreeHashLeaves for BitfieldContainer {
+                fn tree_hash_leaf_count() -> usize {
+                    6usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<
+                                H,
+                            >(&self.tiny_list)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<
+                                H,
+                            >(&self.std_list)
+                        }
+                        2usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<
+                                H,
+                            >(&self.large_list)
+                        }
+                        3usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<
+                                H,
+                            >(&self.tiny_vec)
+                        }
+                        4usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<
+                                H,
+                            >(&self.std_vec)
+                        }
+                        5usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<
+                                H,
+                            >(&self.large_vec)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 6usize
+                            )
+                        }
+                    }
+                }
+            }
             /// Zero-copy view over [`BitfieldContainer`].
             ///
             /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
@@ -160,6 +214,7 @@ pub mod tests {
                         0usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn std_list(
                     &self,
@@ -195,6 +250,7 @@ pub mod tests {
                         1usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn large_list(
                     &self,
@@ -230,6 +286,7 @@ pub mod tests {
                         2usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn tiny_vec(
                     &self,
@@ -265,6 +322,7 @@ pub mod tests {
                         3usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn std_vec(
                     &self,
@@ -300,6 +358,7 @@ pub mod tests {
                         4usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn large_vec(
                     &self,
@@ -335,6 +394,25 @@ pub mod tests {
                         5usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> BitfieldContainerRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.tiny_list()?;
+                    self.std_list()?;
+                    self.large_list()?;
+                    self.tiny_vec()?;
+                    self.std_vec()?;
+                    self.large_vec()?;
+                    Ok(())
                 }
             }
             impl<'a> tree_hash::TreeHash for BitfieldContainerRef<'a> {
@@ -429,6 +507,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for BitfieldContainerRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> BitfieldContainerRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for BitfieldContainerRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     usize::from(!<TinyBitlist as ssz::Encode>::is_ssz_fixed_len())
@@ -486,6 +577,85 @@ pub mod tests {
                         large_vec: self.large_vec().expect("valid view").to_owned(),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut BitfieldContainer,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.tiny_list = self.tiny_list()?.to_owned();
+                    out.std_list = self.std_list()?.to_owned();
+                    out.large_list = self.large_list()?.to_owned();
+                    out.tiny_vec = self.tiny_vec()?.to_owned();
+                    out.std_vec = self.std_vec()?.to_owned();
+                    out.large_vec = self.large_vec()?.to_owned();
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> BitfieldContainerRef<'a> {
+                fn eq_owned(
+                    &self,
+                    other: &BitfieldContainer,
+                ) -> Result<bool, ssz::DecodeError> {
+                    if !({
+                        let value: TinyBitlist = self.tiny_list()?.to_owned();
+                        value == other.tiny_list
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: StandardBitlist = self.std_list()?.to_owned();
+                        value == other.std_list
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: LargeBitlist = self.large_list()?.to_owned();
+                        value == other.large_list
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: TinyBitvector = self.tiny_vec()?.to_owned();
+                        value == other.tiny_vec
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: StandardBitvector = self.std_vec()?.to_owned();
+                        value == other.std_vec
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: LargeBitvector = self.large_vec()?.to_owned();
+                        value == other.large_vec
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<BitfieldContainer> for BitfieldContainerRef<'a> {
+                fn eq(&self, other: &BitfieldContainer) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<BitfieldContainerRef<'a>> for BitfieldContainer {
+                fn eq(&self, other: &BitfieldContainerRef<'a>) -> bool {
+                    other == self
+                }
             }
         }
     }
@@ -11,6 +11,12 @@ pub mod tests {
             use tree_hash::TreeHashDigest;
             use tree_hash_derive::TreeHash;
             use ssz::view::*;
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                51, 191, 249, 231, 116, 199, 0, 72, 125, 45, 41, 249, 171, 31, 251, 51,
+                169, 230, 153, 158, 135, 199, 168, 58, 193, 98, 5, 248, 151, 70, 140, 241,
+            ];
             #[derive(
                 std::clone::Clone,
                 std::fmt::Debug,
@@ -299,6 +305,23 @@ pub mod tests {
                         b: self.b().expect("valid view"),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut BitvectorLenTest,
+                ) -> Result<(), ssz::DecodeError> {
+                    out.a = self.a()?;
+                    out.b = self.b()?;
+                    Ok(())
+                }
             }
         }
     }
@@ -0,0 +1,713 @@
+pub mod tests {
+    #![allow(unused_imports, reason = "generated code using ssz-gen")]
+    pub mod input {
+        #![allow(unused_imports, reason = "generated code using ssz-gen")]
+        pub mod test_docstrings {
+            #![allow(unused_imports, reason = "generated code using ssz-gen")]
+            use ssz_types::*;
+            use ssz_types::view::{FixedVectorRef, VariableListRef};
+            use ssz_primitives::{U128, U256};
+            use ssz_derive::{Encode, Decode};
+            use tree_hash::TreeHashDigest;
+            use tree_hash_derive::TreeHash;
+            use ssz::view::*;
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                161, 139, 140, 131, 144, 46, 66, 21, 100, 102, 247, 120, 171, 217, 19,
+                210, 155, 129, 97, 223, 110, 39, 218, 104, 59, 254, 1, 106, 34, 115, 100,
+                184,
+            ];
+            /**
+ * This is a foo.
+ */
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                ssz_derive::Encode,
+                ssz_derive::Decode
+            )]
+            #[ssz(struct_behaviour = "container")]
+            pub struct Foo {}
+            impl tree_hash::TreeHash for Foo {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::Container
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(0usize);
+                    hasher
+                        .finish()
+                        .expect("tree hash derive should not have a remaining buffer")
+                }
+            }
+            impl tree_hash::TreeHashLeaves for Foo {
+                fn tree_hash_leaf_count() -> usize {
+                    0usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 0usize
+                            )
+                        }
+                    }
+                }
+            }
+            /**
+ * Zero-copy view over [`Foo`].
+ *
+ * This type wraps SSZ-encoded bytes without allocating. Fields are accessed
+ * via lazy getter methods. Use `.to_owned()` to convert to the owned type when
+ * needed.
+ */
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                std::marker::Copy
+            )]
+            pub struct FooRef<'a> {
+                bytes: &'a [u8],
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> FooRef<'a> {}
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> FooRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    Ok(())
+                }
+            }
+            impl<'a> tree_hash::TreeHash for FooRef<'a> {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::StableContainer
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(0usize);
+                    hasher.finish().expect("finish hasher")
+                }
+            }
+            impl<'a> ssz::view::DecodeView<'a> for FooRef<'a> {
+                fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
+                    ssz::layout::validate_container(bytes, &[])?;
+                    Ok(Self { bytes })
+                }
+            }
+            impl<'a> TryFrom<&'a [u8]> for FooRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> FooRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
+            impl<'a> ssz::view::SszTypeInfo for FooRef<'a> {
+                fn is_ssz_fixed_len() -> bool {
+                    0usize == 0
+                }
+                fn ssz_fixed_len() -> usize {
+                    if <Self as ssz::view::SszTypeInfo>::is_ssz_fixed_len() {
+                        0usize
+                    } else {
+                        0
+                    }
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ssz_types::view::ToOwnedSsz<Foo> for FooRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                fn to_owned(&self) -> Foo {
+                    <FooRef<'a>>::to_owned(self)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> FooRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned(&self) -> Foo {
+                    Foo {}
+                }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Foo,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> FooRef<'a> {
+                fn eq_owned(&self, other: &Foo) -> Result<bool, ssz::DecodeError> {
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<Foo> for FooRef<'a> {
+                fn eq(&self, other: &Foo) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<FooRef<'a>> for Foo {
+                fn eq(&self, other: &FooRef<'a>) -> bool {
+                    other == self
+                }
+            }
+            /**
+ * This is a docstring that should come first.
+ *
+ * This is a doc comment
+ */
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                ssz_derive::Encode,
+                ssz_derive::Decode
+            )]
+            #[ssz(struct_behaviour = "container")]
+            pub struct PointWithBoth {
+                /**
+ * X coordinate
+ */
+                pub x: u32,
+                /**
+ * Y coordinate
+ */
+                pub y: u32,
+            }
+            impl PointWithBoth {
+                /// Byte offset of each field in the SSZ encoding, in declaration order.
+                pub const FIELD_OFFSETS: &[usize] = &[0, 4];
+                /// Byte size of each field in the SSZ encoding, in declaration order.
+                pub const FIELD_SIZES: &[usize] = &[4, 4];
+            }
+            impl tree_hash::TreeHash for PointWithBoth {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::Container
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(2usize);
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.x)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.y)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .finish()
+                        .expect("tree hash derive should not have a remaining buffer")
+                }
+            }
+            impl tree_hash::TreeHashLeaves for PointWithBoth {
+                fn tree_hash_leaf_count() -> usize {
+                    2usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.x)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.y)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 2usize
+                            )
+                        }
+                    }
+                }
+            }
+            /**
+ * Zero-copy view over [`PointWithBoth`].
+ *
+ * This type wraps SSZ-encoded bytes without allocating. Fields are accessed
+ * via lazy getter methods. Use `.to_owned()` to convert to the owned type when
+ * needed.
+ */
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                std::marker::Copy
+            )]
+            pub struct PointWithBothRef<'a> {
+                bytes: &'a [u8],
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> PointWithBothRef<'a> {
+                pub fn x(&self) -> Result<u32, ssz::DecodeError> {
+                    let bytes = &self.bytes[0..4];
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+                pub fn y(&self) -> Result<u32, ssz::DecodeError> {
+                    let bytes = &self.bytes[4..8];
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> PointWithBothRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.x()?;
+                    self.y()?;
+                    Ok(())
+                }
+            }
+            impl<'a> tree_hash::TreeHash for PointWithBothRef<'a> {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::StableContainer
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(2usize);
+                    {
+                        let x = self.x().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&x);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    {
+                        let y = self.y().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&y);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    hasher.finish().expect("finish hasher")
+                }
+            }
+            impl<'a> ssz::view::DecodeView<'a> for PointWithBothRef<'a> {
+                fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
+                    ssz::layout::validate_container(
+                        bytes,
+                        &[
+                            (
+                                <u32 as ssz::Encode>::is_ssz_fixed_len(),
+                                <u32 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <u32 as ssz::Encode>::is_ssz_fixed_len(),
+                                <u32 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                    )?;
+                    Ok(Self { bytes })
+                }
+            }
+            impl<'a> TryFrom<&'a [u8]> for PointWithBothRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> PointWithBothRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
+            impl<'a> ssz::view::SszTypeInfo for PointWithBothRef<'a> {
+                fn is_ssz_fixed_len() -> bool {
+                    usize::from(!<u32 as ssz::Encode>::is_ssz_fixed_len())
+                        + usize::from(!<u32 as ssz::Encode>::is_ssz_fixed_len()) == 0
+                }
+                fn ssz_fixed_len() -> usize {
+                    if <Self as ssz::view::SszTypeInfo>::is_ssz_fixed_len() {
+                        <u32 as ssz::Encode>::ssz_fixed_len()
+                            + <u32 as ssz::Encode>::ssz_fixed_len()
+                    } else {
+                        0
+                    }
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ssz_types::view::ToOwnedSsz<PointWithBoth>
+            for PointWithBothRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                fn to_owned(&self) -> PointWithBoth {
+                    <PointWithBothRef<'a>>::to_owned(self)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> PointWithBothRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned(&self) -> PointWithBoth {
+                    PointWithBoth {
+                        x: self.x().expect("valid view"),
+                        y: self.y().expect("valid view"),
+                    }
+                }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut PointWithBoth,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.x = self.x()?;
+                    out.y = self.y()?;
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> PointWithBothRef<'a> {
+                fn eq_owned(
+                    &self,
+                    other: &PointWithBoth,
+                ) -> Result<bool, ssz::DecodeError> {
+                    if !({
+                        let value: u32 = self.x()?;
+                        value == other.x
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: u32 = self.y()?;
+                        value == other.y
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<PointWithBoth> for PointWithBothRef<'a> {
+                fn eq(&self, other: &PointWithBoth) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<PointWithBothRef<'a>> for PointWithBoth {
+                fn eq(&self, other: &PointWithBothRef<'a>) -> bool {
+                    other == self
+                }
+            }
+            /**
+ * First comes the docstring. It has multiple lines.
+ *
+ * This should come after the docstring
+ */
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                ssz_derive::Encode,
+                ssz_derive::Decode
+            )]
+            #[ssz(struct_behaviour = "container")]
+            pub struct TestMerge {
+                pub field: u8,
+            }
+            impl TestMerge {
+                /// Byte offset of each field in the SSZ encoding, in declaration order.
+                pub const FIELD_OFFSETS: &[usize] = &[0];
+                /// Byte size of each field in the SSZ encoding, in declaration order.
+                pub const FIELD_SIZES: &[usize] = &[1];
+            }
+            impl tree_hash::TreeHash for TestMerge {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::Container
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(1usize);
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.field)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .finish()
+                        .expect("tree hash derive should not have a remaining buffer")
+                }
+            }
+            impl tree_hash::TreeHashLeaves for TestMerge {
+                fn tree_hash_leaf_count() -> usize {
+                    1usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.field)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 1usize
+                            )
+                        }
+                    }
+                }
+            }
+            /**
+ * Zero-copy view over [`TestMerge`].
+ *
+ * This type wraps SSZ-encoded bytes without allocating. Fields are accessed
+ * via lazy getter methods. Use `.to_owned()` to convert to the owned type when
+ * needed.
+ */
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                std::marker::Copy
+            )]
+            pub struct TestMergeRef<'a> {
+                bytes: &'a [u8],
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> TestMergeRef<'a> {
+                pub fn field(&self) -> Result<u8, ssz::DecodeError> {
+                    let bytes = &self.bytes[0..1];
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> TestMergeRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.field()?;
+                    Ok(())
+                }
+            }
+            impl<'a> tree_hash::TreeHash for TestMergeRef<'a> {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::StableContainer
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(1usize);
+                    {
+                        let field = self.field().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&field);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    hasher.finish().expect("finish hasher")
+                }
+            }
+            impl<'a> ssz::view::DecodeView<'a> for TestMergeRef<'a> {
+                fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
+                    ssz::layout::validate_container(
+                        bytes,
+                        &[
+                            (
+                                <u8 as ssz::Encode>::is_ssz_fixed_len(),
+                                <u8 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                    )?;
+                    Ok(Self { bytes })
+                }
+            }
+            impl<'a> TryFrom<&'a [u8]> for TestMergeRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> TestMergeRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
+            impl<'a> ssz::view::SszTypeInfo for TestMergeRef<'a> {
+                fn is_ssz_fixed_len() -> bool {
+                    usize::from(!<u8 as ssz::Encode>::is_ssz_fixed_len()) == 0
+                }
+                fn ssz_fixed_len() -> usize {
+                    if <Self as ssz::view::SszTypeInfo>::is_ssz_fixed_len() {
+                        <u8 as ssz::Encode>::ssz_fixed_len()
+                    } else {
+                        0
+                    }
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ssz_types::view::ToOwnedSsz<TestMerge> for TestMergeRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                fn to_owned(&self) -> TestMerge {
+                    <TestMergeRef<'a>>::to_owned(self)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> TestMergeRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned(&self) -> TestMerge {
+                    TestMerge {
+                        field: self.field().expect("valid view"),
+                    }
+                }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut TestMerge,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.field = self.field()?;
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> TestMergeRef<'a> {
+                fn eq_owned(&self, other: &TestMerge) -> Result<bool, ssz::DecodeError> {
+                    if !({
+                        let value: u8 = self.field()?;
+                        value == other.field
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<TestMerge> for TestMergeRef<'a> {
+                fn eq(&self, other: &TestMerge) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<TestMergeRef<'a>> for TestMerge {
+                fn eq(&self, other: &TestMergeRef<'a>) -> bool {
+                    other == self
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,287 @@
+pub mod tests {
+    #![allow(unused_imports, reason = "generated code using ssz-gen")]
+    pub mod input {
+        #![allow(unused_imports, reason = "generated code using ssz-gen")]
+        pub mod test_bool_field {
+            #![allow(unused_imports, reason = "generated code using ssz-gen")]
+            use ssz_types::*;
+            use ssz_types::view::{FixedVectorRef, VariableListRef};
+            use ssz_primitives::{U128, U256};
+            use ssz_derive::{Encode, Decode};
+            use tree_hash::TreeHashDigest;
+            use tree_hash_derive::TreeHash;
+            use ssz::view::*;
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                31, 175, 62, 3, 244, 131, 116, 122, 99, 227, 163, 161, 232, 172, 17, 7,
+                111, 170, 252, 150, 93, 50, 222, 140, 216, 196, 48, 75, 24, 152, 193, 46,
+            ];
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                ssz_derive::Encode,
+                ssz_derive::Decode
+            )]
+            #[ssz(struct_behaviour = "container")]
+            pub struct Flags {
+                pub id: u8,
+                pub enabled: bool,
+            }
+            impl Flags {
+                /// Byte offset of each field in the SSZ encoding, in declaration order.
+                pub const FIELD_OFFSETS: &[usize] = &[0, 1];
+                /// Byte size of each field in the SSZ encoding, in declaration order.
+                pub const FIELD_SIZES: &[usize] = &[1, 1];
+            }
+            impl tree_hash::TreeHash for Flags {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::Container
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(2usize);
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.id)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<
+                                H,
+                            >(&self.enabled)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .finish()
+                        .expect("tree hash derive should not have a remaining buffer")
+                }
+            }
+            impl tree_hash::TreeHashLeaves for Flags {
+                fn tree_hash_leaf_count() -> usize {
+                    2usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.id)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<
+                                H,
+                            >(&self.enabled)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 2usize
+                            )
+                        }
+                    }
+                }
+            }
+            /// Zero-copy view over [`Flags`].
+            ///
+            /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
+            /// via lazy getter methods. Use `.to_owned()` to convert to the owned type when
+            /// needed.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                std::marker::Copy
+            )]
+            pub struct FlagsRef<'a> {
+                bytes: &'a [u8],
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> FlagsRef<'a> {
+                pub fn id(&self) -> Result<u8, ssz::DecodeError> {
+                    let bytes = &self.bytes[0..1];
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+                pub fn enabled(&self) -> Result<bool, ssz::DecodeError> {
+                    let bytes = &self.bytes[1..2];
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> FlagsRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.id()?;
+                    self.enabled()?;
+                    Ok(())
+                }
+            }
+            impl<'a> tree_hash::TreeHash for FlagsRef<'a> {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::StableContainer
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(2usize);
+                    {
+                        let id = self.id().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&id);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    {
+                        let enabled = self.enabled().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&enabled);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    hasher.finish().expect("finish hasher")
+                }
+            }
+            impl<'a> ssz::view::DecodeView<'a> for FlagsRef<'a> {
+                fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
+                    ssz::layout::validate_container(
+                        bytes,
+                        &[
+                            (
+                                <u8 as ssz::Encode>::is_ssz_fixed_len(),
+                                <u8 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <bool as ssz::Encode>::is_ssz_fixed_len(),
+                                <bool as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                    )?;
+                    Ok(Self { bytes })
+                }
+            }
+            impl<'a> TryFrom<&'a [u8]> for FlagsRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> FlagsRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
+            impl<'a> ssz::view::SszTypeInfo for FlagsRef<'a> {
+                fn is_ssz_fixed_len() -> bool {
+                    usize::from(!<u8 as ssz::Encode>::is_ssz_fixed_len())
+                        + usize::from(!<bool as ssz::Encode>::is_ssz_fixed_len()) == 0
+                }
+                fn ssz_fixed_len() -> usize {
+                    if <Self as ssz::view::SszTypeInfo>::is_ssz_fixed_len() {
+                        <u8 as ssz::Encode>::ssz_fixed_len()
+                            + <bool as ssz::Encode>::ssz_fixed_len()
+                    } else {
+                        0
+                    }
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ssz_types::view::ToOwnedSsz<Flags> for FlagsRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                fn to_owned(&self) -> Flags {
+                    <FlagsRef<'a>>::to_owned(self)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> FlagsRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned(&self) -> Flags {
+                    Flags {
+                        id: self.id().expect("valid view"),
+                        enabled: self.enabled().expect("valid view"),
+                    }
+                }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Flags,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.id = self.id()?;
+                    out.enabled = self.enabled()?;
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> FlagsRef<'a> {
+                fn eq_owned(&self, other: &Flags) -> Result<bool, ssz::DecodeError> {
+                    if !({
+                        let value: u8 = self.id()?;
+                        value == other.id
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: bool = self.enabled()?;
+                        value == other.enabled
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<Flags> for FlagsRef<'a> {
+                fn eq(&self, other: &Flags) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<FlagsRef<'a>> for Flags {
+                fn eq(&self, other: &FlagsRef<'a>) -> bool {
+                    other == self
+                }
+            }
+        }
+    }
+}
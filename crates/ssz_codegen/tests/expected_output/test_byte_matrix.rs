@@ -0,0 +1,656 @@
+pub mod tests {
+    #![allow(unused_imports, reason = "generated code using ssz-gen")]
+    pub mod input {
+        #![allow(unused_imports, reason = "generated code using ssz-gen")]
+        pub mod test_byte_matrix {
+            #![allow(unused_imports, reason = "generated code using ssz-gen")]
+            use ssz_types::*;
+            use ssz_types::view::{FixedVectorRef, VariableListRef};
+            use ssz_primitives::{U128, U256};
+            use ssz_derive::{Encode, Decode};
+            use tree_hash::TreeHashDigest;
+            use tree_hash_derive::TreeHash;
+            use ssz::view::*;
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                167, 233, 239, 1, 178, 57, 66, 151, 241, 14, 67, 42, 208, 6, 188, 241,
+                249, 46, 73, 152, 16, 76, 158, 203, 39, 229, 198, 144, 53, 46, 244, 16,
+            ];
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                ssz_derive::Encode,
+                ssz_derive::Decode
+            )]
+            #[ssz(struct_behaviour = "container")]
+            pub struct Matrix {
+                pub rows: FixedVector<FixedBytes<32usize>, 4usize>,
+                pub tail: u8,
+            }
+            impl Matrix {
+                /// Byte offset of each field in the SSZ encoding, in declaration order.
+                pub const FIELD_OFFSETS: &[usize] = &[0, 128];
+                /// Byte size of each field in the SSZ encoding, in declaration order.
+                pub const FIELD_SIZES: &[usize] = &[128, 1];
+            }
+            impl tree_hash::TreeHash for Matrix {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::Container
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(2usize);
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.rows)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.tail)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .finish()
+                        .expect("tree hash derive should not have a remaining buffer")
+                }
+            }
+            impl tree_hash::TreeHashLeaves for Matrix {
+                fn tree_hash_leaf_count() -> usize {
+                    2usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.rows)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.tail)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 2usize
+                            )
+                        }
+                    }
+                }
+            }
+            /// Zero-copy view over [`Matrix`].
+            ///
+            /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
+            /// via lazy getter methods. Use `.to_owned()` to convert to the owned type when
+            /// needed.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                std::marker::Copy
+            )]
+            pub struct MatrixRef<'a> {
+                bytes: &'a [u8],
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> MatrixRef<'a> {
+                pub fn rows(
+                    &self,
+                ) -> Result<
+                    FixedVectorRef<'a, FixedBytesRef<'a, 32usize>, 4usize>,
+                    ssz::DecodeError,
+                > {
+                    let bytes = &self.bytes[0..128];
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+                pub fn tail(&self) -> Result<u8, ssz::DecodeError> {
+                    let bytes = &self.bytes[128..129];
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> MatrixRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    let value = self.rows()?;
+                    for item in value.iter() {
+                        item?;
+                    }
+                    self.tail()?;
+                    Ok(())
+                }
+            }
+            impl<'a> tree_hash::TreeHash for MatrixRef<'a> {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::StableContainer
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(2usize);
+                    {
+                        let rows = self.rows().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&rows);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    {
+                        let tail = self.tail().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&tail);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    hasher.finish().expect("finish hasher")
+                }
+            }
+            impl<'a> ssz::view::DecodeView<'a> for MatrixRef<'a> {
+                fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
+                    ssz::layout::validate_container(
+                        bytes,
+                        &[
+                            (
+                                <FixedVector<
+                                    FixedBytes<32usize>,
+                                    4usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <FixedVector<
+                                    FixedBytes<32usize>,
+                                    4usize,
+                                > as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <u8 as ssz::Encode>::is_ssz_fixed_len(),
+                                <u8 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                    )?;
+                    Ok(Self { bytes })
+                }
+            }
+            impl<'a> TryFrom<&'a [u8]> for MatrixRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> MatrixRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
+            impl<'a> ssz::view::SszTypeInfo for MatrixRef<'a> {
+                fn is_ssz_fixed_len() -> bool {
+                    usize::from(
+                        !<FixedVector<
+                            FixedBytes<32usize>,
+                            4usize,
+                        > as ssz::Encode>::is_ssz_fixed_len(),
+                    ) + usize::from(!<u8 as ssz::Encode>::is_ssz_fixed_len()) == 0
+                }
+                fn ssz_fixed_len() -> usize {
+                    if <Self as ssz::view::SszTypeInfo>::is_ssz_fixed_len() {
+                        <FixedVector<
+                            FixedBytes<32usize>,
+                            4usize,
+                        > as ssz::Encode>::ssz_fixed_len()
+                            + <u8 as ssz::Encode>::ssz_fixed_len()
+                    } else {
+                        0
+                    }
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ssz_types::view::ToOwnedSsz<Matrix> for MatrixRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                fn to_owned(&self) -> Matrix {
+                    <MatrixRef<'a>>::to_owned(self)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> MatrixRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned(&self) -> Matrix {
+                    Matrix {
+                        rows: self
+                            .rows()
+                            .expect("valid view")
+                            .to_owned()
+                            .expect("valid view"),
+                        tail: self.tail().expect("valid view"),
+                    }
+                }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Matrix,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    let view = self.rows()?;
+                    for (slot, item) in out.rows.iter_mut().zip(view.iter()) {
+                        *slot = ssz_types::view::ToOwnedSsz::to_owned(&item?);
+                    }
+                    out.tail = self.tail()?;
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> MatrixRef<'a> {
+                fn eq_owned(&self, other: &Matrix) -> Result<bool, ssz::DecodeError> {
+                    if !({
+                        let value: FixedVector<FixedBytes<32usize>, 4usize> = self
+                            .rows()?
+                            .to_owned()
+                            .expect("valid view");
+                        value == other.rows
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: u8 = self.tail()?;
+                        value == other.tail
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<Matrix> for MatrixRef<'a> {
+                fn eq(&self, other: &Matrix) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<MatrixRef<'a>> for Matrix {
+                fn eq(&self, other: &MatrixRef<'a>) -> bool {
+                    other == self
+                }
+            }
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                ssz_derive::Encode,
+                ssz_derive::Decode
+            )]
+            #[ssz(struct_behaviour = "container")]
+            pub struct Grid {
+                pub cells: VariableList<FixedBytes<4usize>, 8usize>,
+                pub tag: u16,
+            }
+            impl tree_hash::TreeHash for Grid {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::Container
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(2usize);
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.cells)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.tag)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .finish()
+                        .expect("tree hash derive should not have a remaining buffer")
+                }
+            }
+            impl tree_hash::TreeHashLeaves for Grid {
+                fn tree_hash_leaf_count() -> usize {
+                    2usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.cells)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.tag)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 2usize
+                            )
+                        }
+                    }
+                }
+            }
+            /// Zero-copy view over [`Grid`].
+            ///
+            /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
+            /// via lazy getter methods. Use `.to_owned()` to convert to the owned type when
+            /// needed.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                std::marker::Copy
+            )]
+            pub struct GridRef<'a> {
+                bytes: &'a [u8],
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> GridRef<'a> {
+                pub fn cells(
+                    &self,
+                ) -> Result<
+                    ListRef<'a, FixedBytesRef<'a, 4usize>, 8usize>,
+                    ssz::DecodeError,
+                > {
+                    let bytes = ssz::layout::read_field_bytes(
+                        self.bytes,
+                        &[
+                            (
+                                <VariableList<
+                                    FixedBytes<4usize>,
+                                    8usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <VariableList<
+                                    FixedBytes<4usize>,
+                                    8usize,
+                                > as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <u16 as ssz::Encode>::is_ssz_fixed_len(),
+                                <u16 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                        0usize,
+                    )?;
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+                pub fn tag(&self) -> Result<u16, ssz::DecodeError> {
+                    let bytes = ssz::layout::read_field_bytes(
+                        self.bytes,
+                        &[
+                            (
+                                <VariableList<
+                                    FixedBytes<4usize>,
+                                    8usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <VariableList<
+                                    FixedBytes<4usize>,
+                                    8usize,
+                                > as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <u16 as ssz::Encode>::is_ssz_fixed_len(),
+                                <u16 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                        1usize,
+                    )?;
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> GridRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    let value = self.cells()?;
+                    for item in value.iter() {
+                        item?;
+                    }
+                    self.tag()?;
+                    Ok(())
+                }
+            }
+            impl<'a> tree_hash::TreeHash for GridRef<'a> {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::StableContainer
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(2usize);
+                    {
+                        let cells = self.cells().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&cells);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    {
+                        let tag = self.tag().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&tag);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    hasher.finish().expect("finish hasher")
+                }
+            }
+            impl<'a> ssz::view::DecodeView<'a> for GridRef<'a> {
+                fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
+                    ssz::layout::validate_container(
+                        bytes,
+                        &[
+                            (
+                                <VariableList<
+                                    FixedBytes<4usize>,
+                                    8usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <VariableList<
+                                    FixedBytes<4usize>,
+                                    8usize,
+                                > as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <u16 as ssz::Encode>::is_ssz_fixed_len(),
+                                <u16 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                    )?;
+                    Ok(Self { bytes })
+                }
+            }
+            impl<'a> TryFrom<&'a [u8]> for GridRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> GridRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
+            impl<'a> ssz::view::SszTypeInfo for GridRef<'a> {
+                fn is_ssz_fixed_len() -> bool {
+                    usize::from(
+                        !<VariableList<
+                            FixedBytes<4usize>,
+                            8usize,
+                        > as ssz::Encode>::is_ssz_fixed_len(),
+                    ) + usize::from(!<u16 as ssz::Encode>::is_ssz_fixed_len()) == 0
+                }
+                fn ssz_fixed_len() -> usize {
+                    if <Self as ssz::view::SszTypeInfo>::is_ssz_fixed_len() {
+                        <VariableList<
+                            FixedBytes<4usize>,
+                            8usize,
+                        > as ssz::Encode>::ssz_fixed_len()
+                            + <u16 as ssz::Encode>::ssz_fixed_len()
+                    } else {
+                        0
+                    }
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ssz_types::view::ToOwnedSsz<Grid> for GridRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                fn to_owned(&self) -> Grid {
+                    <GridRef<'a>>::to_owned(self)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> GridRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned(&self) -> Grid {
+                    Grid {
+                        cells: {
+                            let view = self.cells().expect("valid view");
+                            let items: Result<Vec<_>, _> = view
+                                .iter()
+                                .map(|item_result| {
+                                    item_result
+                                        .map(|item| ssz_types::view::ToOwnedSsz::to_owned(&item))
+                                })
+                                .collect();
+                            let items = items.expect("valid view");
+                            ssz_types::VariableList::new(items).expect("valid view")
+                        },
+                        tag: self.tag().expect("valid view"),
+                    }
+                }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Grid,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    let view = self.cells()?;
+                    out.cells.clear();
+                    for item in view.iter() {
+                        out.cells
+                            .push(ssz_types::view::ToOwnedSsz::to_owned(&item?))
+                            .expect("valid view");
+                    }
+                    out.tag = self.tag()?;
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> GridRef<'a> {
+                fn eq_owned(&self, other: &Grid) -> Result<bool, ssz::DecodeError> {
+                    if !({
+                        let value: VariableList<FixedBytes<4usize>, 8usize> = {
+                            let view = self.cells()?;
+                            let items: Result<Vec<_>, _> = view
+                                .iter()
+                                .map(|item_result| {
+                                    item_result
+                                        .map(|item| ssz_types::view::ToOwnedSsz::to_owned(&item))
+                                })
+                                .collect();
+                            let items = items.expect("valid view");
+                            ssz_types::VariableList::new(items).expect("valid view")
+                        };
+                        value == other.cells
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: u16 = self.tag()?;
+                        value == other.tag
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<Grid> for GridRef<'a> {
+                fn eq(&self, other: &Grid) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<GridRef<'a>> for Grid {
+                fn eq(&self, other: &GridRef<'a>) -> bool {
+                    other == self
+                }
+            }
+        }
+    }
+}
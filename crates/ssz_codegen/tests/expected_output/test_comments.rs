@@ -11,6 +11,12 @@ pub mod tests {
             use tree_hash::TreeHashDigest;
             use tree_hash_derive::TreeHash;
             use ssz::view::*;
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                176, 168, 84, 204, 39, 75, 56, 99, 219, 230, 73, 49, 147, 177, 126, 29,
+                211, 238, 98, 204, 220, 244, 131, 184, 114, 17, 138, 214, 1, 176, 37, 142,
+            ];
             /// This is a doc comment for the Point class It can span multiple lines
             #[derive(
                 std::clone::Clone,
@@ -28,6 +34,12 @@ pub mod tests {
                 pub y: u32,
                 pub z: u32,
             }
+            impl Point {
+                /// Byte offset of each field in the SSZ encoding, in declaration order.
+                pub const FIELD_OFFSETS: &[usize] = &[0, 4, 8];
+                /// Byte size of each field in the SSZ encoding, in declaration order.
+                pub const FIELD_SIZES: &[usize] = &[4, 4, 4];
+            }
             impl tree_hash::TreeHash for Point {
                 fn tree_hash_type() -> tree_hash::TreeHashType {
                     tree_hash::TreeHashType::Container
@@ -64,6 +76,32 @@ pub mod tests {
                         .expect("tree hash derive should not have a remaining buffer")
                 }
             }
+            impl tree_hash::TreeHashLeaves for Point {
+                fn tree_hash_leaf_count() -> usize {
+                    3usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.x)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.y)
+                        }
+                        2usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.z)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 3usize
+                            )
+                        }
+                    }
+                }
+            }
             /// Zero-copy view over [`Point`].
             ///
             /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
@@ -83,67 +121,34 @@ pub mod tests {
             #[allow(dead_code, reason = "generated code using ssz-gen")]
             impl<'a> PointRef<'a> {
                 pub fn x(&self) -> Result<u32, ssz::DecodeError> {
-                    let bytes = ssz::layout::read_field_bytes(
-                        self.bytes,
-                        &[
-                            (
-                                <u32 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u32 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                            (
-                                <u32 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u32 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                            (
-                                <u32 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u32 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                        ],
-                        0usize,
-                    )?;
+                    let bytes = &self.bytes[0..4];
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn y(&self) -> Result<u32, ssz::DecodeError> {
-                    let bytes = ssz::layout::read_field_bytes(
-                        self.bytes,
-                        &[
-                            (
-                                <u32 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u32 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                            (
-                                <u32 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u32 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                            (
-                                <u32 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u32 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                        ],
-                        1usize,
-                    )?;
+                    let bytes = &self.bytes[4..8];
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn z(&self) -> Result<u32, ssz::DecodeError> {
-                    let bytes = ssz::layout::read_field_bytes(
-                        self.bytes,
-                        &[
-                            (
-                                <u32 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u32 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                            (
-                                <u32 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u32 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                            (
-                                <u32 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u32 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                        ],
-                        2usize,
-                    )?;
+                    let bytes = &self.bytes[8..12];
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> PointRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.x()?;
+                    self.y()?;
+                    self.z()?;
+                    Ok(())
                 }
             }
             impl<'a> tree_hash::TreeHash for PointRef<'a> {
@@ -205,6 +210,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for PointRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> PointRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for PointRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     usize::from(!<u32 as ssz::Encode>::is_ssz_fixed_len())
@@ -244,6 +262,61 @@ pub mod tests {
                         z: self.z().expect("valid view"),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Point,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.x = self.x()?;
+                    out.y = self.y()?;
+                    out.z = self.z()?;
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> PointRef<'a> {
+                fn eq_owned(&self, other: &Point) -> Result<bool, ssz::DecodeError> {
+                    if !({
+                        let value: u32 = self.x()?;
+                        value == other.x
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: u32 = self.y()?;
+                        value == other.y
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: u32 = self.z()?;
+                        value == other.z
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<Point> for PointRef<'a> {
+                fn eq(&self, other: &Point) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<PointRef<'a>> for Point {
+                fn eq(&self, other: &PointRef<'a>) -> bool {
+                    other == self
+                }
             }
             /// A container for coordinates
             #[derive(
@@ -261,6 +334,12 @@ pub mod tests {
                 /// Longitude coordinate
                 pub lon: u64,
             }
+            impl CoordinateContainer {
+                /// Byte offset of each field in the SSZ encoding, in declaration order.
+                pub const FIELD_OFFSETS: &[usize] = &[0, 8];
+                /// Byte size of each field in the SSZ encoding, in declaration order.
+                pub const FIELD_SIZES: &[usize] = &[8, 8];
+            }
             impl tree_hash::TreeHash for CoordinateContainer {
                 fn tree_hash_type() -> tree_hash::TreeHashType {
                     tree_hash::TreeHashType::Container
@@ -291,6 +370,29 @@ pub mod tests {
                         .expect("tree hash derive should not have a remaining buffer")
                 }
             }
+            impl tree_hash::TreeHashLeaves for CoordinateContainer {
+                fn tree_hash_leaf_count() -> usize {
+                    2usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.lat)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.lon)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 2usize
+                            )
+                        }
+                    }
+                }
+            }
             /// Zero-copy view over [`CoordinateContainer`].
             ///
             /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
@@ -310,38 +412,28 @@ pub mod tests {
             #[allow(dead_code, reason = "generated code using ssz-gen")]
             impl<'a> CoordinateContainerRef<'a> {
                 pub fn lat(&self) -> Result<u64, ssz::DecodeError> {
-                    let bytes = ssz::layout::read_field_bytes(
-                        self.bytes,
-                        &[
-                            (
-                                <u64 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u64 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                            (
-                                <u64 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u64 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                        ],
-                        0usize,
-                    )?;
+                    let bytes = &self.bytes[0..8];
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn lon(&self) -> Result<u64, ssz::DecodeError> {
-                    let bytes = ssz::layout::read_field_bytes(
-                        self.bytes,
-                        &[
-                            (
-                                <u64 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u64 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                            (
-                                <u64 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u64 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                        ],
-                        1usize,
-                    )?;
+                    let bytes = &self.bytes[8..16];
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> CoordinateContainerRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.lat()?;
+                    self.lon()?;
+                    Ok(())
                 }
             }
             impl<'a> tree_hash::TreeHash for CoordinateContainerRef<'a> {
@@ -392,6 +484,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for CoordinateContainerRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> CoordinateContainerRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for CoordinateContainerRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     usize::from(!<u64 as ssz::Encode>::is_ssz_fixed_len())
@@ -429,6 +534,57 @@ pub mod tests {
                         lon: self.lon().expect("valid view"),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut CoordinateContainer,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.lat = self.lat()?;
+                    out.lon = self.lon()?;
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> CoordinateContainerRef<'a> {
+                fn eq_owned(
+                    &self,
+                    other: &CoordinateContainer,
+                ) -> Result<bool, ssz::DecodeError> {
+                    if !({
+                        let value: u64 = self.lat()?;
+                        value == other.lat
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: u64 = self.lon()?;
+                        value == other.lon
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<CoordinateContainer> for CoordinateContainerRef<'a> {
+                fn eq(&self, other: &CoordinateContainer) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<CoordinateContainerRef<'a>> for CoordinateContainer {
+                fn eq(&self, other: &CoordinateContainerRef<'a>) -> bool {
+                    other == self
+                }
             }
         }
     }
@@ -0,0 +1,366 @@
+pub mod tests {
+    #![allow(unused_imports, reason = "generated code using ssz-gen")]
+    pub mod input {
+        #![allow(unused_imports, reason = "generated code using ssz-gen")]
+        pub mod test_const_bytes {
+            #![allow(unused_imports, reason = "generated code using ssz-gen")]
+            use ssz_types::*;
+            use ssz_types::view::{FixedVectorRef, VariableListRef};
+            use ssz_primitives::{U128, U256};
+            use ssz_derive::{Encode, Decode};
+            use tree_hash::TreeHashDigest;
+            use tree_hash_derive::TreeHash;
+            use ssz::view::*;
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                85, 183, 210, 60, 76, 213, 228, 120, 117, 49, 55, 220, 149, 215, 66, 34,
+                8, 154, 188, 215, 159, 246, 79, 24, 167, 237, 152, 23, 106, 173, 207, 220,
+            ];
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const DOMAIN_LEN: u64 = 4u64;
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const NAME_LEN: u64 = 6u64;
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const DOMAIN_BEACON: FixedBytes<4> = FixedBytes([7, 0, 0, 0]);
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const DOMAIN_NAME: FixedBytes<6> = FixedBytes([
+                98, 101, 97, 99, 111, 110,
+            ]);
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                ssz_derive::Encode,
+                ssz_derive::Decode
+            )]
+            #[ssz(struct_behaviour = "container")]
+            pub struct Domain {
+                pub domain: FixedBytes<4usize>,
+                pub names: VariableList<u16, 6usize>,
+            }
+            impl tree_hash::TreeHash for Domain {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::Container
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(2usize);
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.domain)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.names)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .finish()
+                        .expect("tree hash derive should not have a remaining buffer")
+                }
+            }
+            impl tree_hash::TreeHashLeaves for Domain {
+                fn tree_hash_leaf_count() -> usize {
+                    2usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.domain)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.names)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 2usize
+                            )
+                        }
+                    }
+                }
+            }
+            /// Zero-copy view over [`Domain`].
+            ///
+            /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
+            /// via lazy getter methods. Use `.to_owned()` to convert to the owned type when
+            /// needed.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                std::marker::Copy
+            )]
+            pub struct DomainRef<'a> {
+                bytes: &'a [u8],
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> DomainRef<'a> {
+                pub fn domain(
+                    &self,
+                ) -> Result<FixedBytesRef<'a, 4usize>, ssz::DecodeError> {
+                    let bytes = ssz::layout::read_field_bytes(
+                        self.bytes,
+                        &[
+                            (
+                                <FixedBytes<4usize> as ssz::Encode>::is_ssz_fixed_len(),
+                                <FixedBytes<4usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <VariableList<
+                                    u16,
+                                    6usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <VariableList<u16, 6usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                        0usize,
+                    )?;
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+                pub fn names(
+                    &self,
+                ) -> Result<ListRef<'a, u16, 6usize>, ssz::DecodeError> {
+                    let bytes = ssz::layout::read_field_bytes(
+                        self.bytes,
+                        &[
+                            (
+                                <FixedBytes<4usize> as ssz::Encode>::is_ssz_fixed_len(),
+                                <FixedBytes<4usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <VariableList<
+                                    u16,
+                                    6usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <VariableList<u16, 6usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                        1usize,
+                    )?;
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> DomainRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.domain()?;
+                    let value = self.names()?;
+                    for item in value.iter() {
+                        item?;
+                    }
+                    Ok(())
+                }
+            }
+            impl<'a> tree_hash::TreeHash for DomainRef<'a> {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::StableContainer
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(2usize);
+                    {
+                        let domain = self.domain().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&domain);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    {
+                        let names = self.names().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&names);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    hasher.finish().expect("finish hasher")
+                }
+            }
+            impl<'a> ssz::view::DecodeView<'a> for DomainRef<'a> {
+                fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
+                    ssz::layout::validate_container(
+                        bytes,
+                        &[
+                            (
+                                <FixedBytes<4usize> as ssz::Encode>::is_ssz_fixed_len(),
+                                <FixedBytes<4usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <VariableList<
+                                    u16,
+                                    6usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <VariableList<u16, 6usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                    )?;
+                    Ok(Self { bytes })
+                }
+            }
+            impl<'a> TryFrom<&'a [u8]> for DomainRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> DomainRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
+            impl<'a> ssz::view::SszTypeInfo for DomainRef<'a> {
+                fn is_ssz_fixed_len() -> bool {
+                    usize::from(!<FixedBytes<4usize> as ssz::Encode>::is_ssz_fixed_len())
+                        + usize::from(
+                            !<VariableList<
+                                u16,
+                                6usize,
+                            > as ssz::Encode>::is_ssz_fixed_len(),
+                        ) == 0
+                }
+                fn ssz_fixed_len() -> usize {
+                    if <Self as ssz::view::SszTypeInfo>::is_ssz_fixed_len() {
+                        <FixedBytes<4usize> as ssz::Encode>::ssz_fixed_len()
+                            + <VariableList<u16, 6usize> as ssz::Encode>::ssz_fixed_len()
+                    } else {
+                        0
+                    }
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ssz_types::view::ToOwnedSsz<Domain> for DomainRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                fn to_owned(&self) -> Domain {
+                    <DomainRef<'a>>::to_owned(self)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> DomainRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned(&self) -> Domain {
+                    Domain {
+                        domain: ssz_types::FixedBytes(
+                            self.domain().expect("valid view").to_owned(),
+                        ),
+                        names: {
+                            let view = self.names().expect("valid view");
+                            let items: Result<Vec<_>, _> = view
+                                .iter()
+                                .map(|item_result| {
+                                    item_result
+                                        .map(|item| ssz_types::view::ToOwnedSsz::to_owned(&item))
+                                })
+                                .collect();
+                            let items = items.expect("valid view");
+                            ssz_types::VariableList::new(items).expect("valid view")
+                        },
+                    }
+                }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Domain,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.domain = ssz_types::FixedBytes(self.domain()?.to_owned());
+                    let view = self.names()?;
+                    out.names.clear();
+                    for item in view.iter() {
+                        out.names
+                            .push(ssz_types::view::ToOwnedSsz::to_owned(&item?))
+                            .expect("valid view");
+                    }
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> DomainRef<'a> {
+                fn eq_owned(&self, other: &Domain) -> Result<bool, ssz::DecodeError> {
+                    if !({
+                        let value: FixedBytes<4usize> = ssz_types::FixedBytes(
+                            self.domain()?.to_owned(),
+                        );
+                        value == other.domain
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: VariableList<u16, 6usize> = {
+                            let view = self.names()?;
+                            let items: Result<Vec<_>, _> = view
+                                .iter()
+                                .map(|item_result| {
+                                    item_result
+                                        .map(|item| ssz_types::view::ToOwnedSsz::to_owned(&item))
+                                })
+                                .collect();
+                            let items = items.expect("valid view");
+                            ssz_types::VariableList::new(items).expect("valid view")
+                        };
+                        value == other.names
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<Domain> for DomainRef<'a> {
+                fn eq(&self, other: &Domain) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<DomainRef<'a>> for Domain {
+                fn eq(&self, other: &DomainRef<'a>) -> bool {
+                    other == self
+                }
+            }
+        }
+    }
+}
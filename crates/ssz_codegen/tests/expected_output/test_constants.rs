@@ -6,6 +6,12 @@ use ssz_derive::{Encode, Decode};
 use tree_hash::TreeHashDigest;
 use tree_hash_derive::TreeHash;
 use ssz::view::*;
+/// Hash of the normalized schema this module was generated from.
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+pub const SCHEMA_HASH: [u8; 32] = [
+    71, 245, 149, 164, 58, 245, 112, 220, 202, 16, 235, 68, 187, 143, 79, 35, 102, 107,
+    30, 45, 26, 144, 245, 150, 61, 160, 114, 3, 116, 87, 230, 24,
+];
 #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
 #[ssz(enum_behaviour = "union")]
 pub enum AliasOptionUnion {
@@ -1157,6 +1163,18 @@ impl<'a> AlphaRef<'a> {
             c: ssz_types::FixedBytes(self.c().expect("valid view").to_owned()),
         }
     }
+    /// Converts this view into `out`, reusing the allocations of its
+    /// list and vector fields.
+    ///
+    /// Produces the same value as `to_owned`, but avoids reallocating
+    /// when converting many views in a loop.
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    pub fn to_owned_into(&self, out: &mut Alpha) -> Result<(), ssz::DecodeError> {
+        out.a = self.a()?;
+        out.b = self.b()?;
+        out.c = ssz_types::FixedBytes(self.c()?.to_owned());
+        Ok(())
+    }
 }
 #[derive(
     std::clone::Clone,
@@ -1372,6 +1390,20 @@ impl<'a> BetaRef<'a> {
             f: self.f().expect("valid view"),
         }
     }
+    /// Converts this view into `out`, reusing the allocations of its
+    /// list and vector fields.
+    ///
+    /// Produces the same value as `to_owned`, but avoids reallocating
+    /// when converting many views in a loop.
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    pub fn to_owned_into(&self, out: &mut Beta) -> Result<(), ssz::DecodeError> {
+        let view = self.d()?;
+        out.d.clear();
+        out.d.try_extend_from_slice(view.as_bytes()).expect("valid view");
+        out.e = self.e()?;
+        out.f = self.f()?;
+        Ok(())
+    }
 }
 #[derive(
     std::clone::Clone,
@@ -1645,6 +1677,22 @@ impl<'a> GammaRef<'a> {
             },
         }
     }
+    /// Converts this view into `out`, reusing the allocations of its
+    /// list and vector fields.
+    ///
+    /// Produces the same value as `to_owned`, but avoids reallocating
+    /// when converting many views in a loop.
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    pub fn to_owned_into(&self, out: &mut Gamma) -> Result<(), ssz::DecodeError> {
+        out.g = self.g()?;
+        out.h = match self.h()? {
+            ssz_types::Optional::Some(inner) => {
+                ssz_types::Optional::Some(ssz_types::view::ToOwnedSsz::to_owned(&inner))
+            }
+            ssz_types::Optional::None => ssz_types::Optional::None,
+        };
+        Ok(())
+    }
 }
 #[derive(
     std::clone::Clone,
@@ -1811,6 +1859,17 @@ impl<'a> DeltaRef<'a> {
             w: self.w().expect("valid view"),
         }
     }
+    /// Converts this view into `out`, reusing the allocations of its
+    /// list and vector fields.
+    ///
+    /// Produces the same value as `to_owned`, but avoids reallocating
+    /// when converting many views in a loop.
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    pub fn to_owned_into(&self, out: &mut Delta) -> Result<(), ssz::DecodeError> {
+        out.z = self.z()?;
+        out.w = self.w()?;
+        Ok(())
+    }
 }
 #[derive(
     std::clone::Clone,
@@ -2250,6 +2309,24 @@ impl<'a> EpsilonRef<'a> {
             j: self.j().expect("valid view"),
         }
     }
+    /// Converts this view into `out`, reusing the allocations of its
+    /// list and vector fields.
+    ///
+    /// Produces the same value as `to_owned`, but avoids reallocating
+    /// when converting many views in a loop.
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    pub fn to_owned_into(&self, out: &mut Epsilon) -> Result<(), ssz::DecodeError> {
+        out.g = self.g()?;
+        out.h = match self.h()? {
+            ssz_types::Optional::Some(inner) => {
+                ssz_types::Optional::Some(ssz_types::view::ToOwnedSsz::to_owned(&inner))
+            }
+            ssz_types::Optional::None => ssz_types::Optional::None,
+        };
+        out.i = self.i()?;
+        out.j = self.j()?;
+        Ok(())
+    }
 }
 #[derive(
     std::clone::Clone,
@@ -2520,6 +2597,27 @@ impl<'a> ZetaRef<'a> {
             },
         }
     }
+    /// Converts this view into `out`, reusing the allocations of its
+    /// list and vector fields.
+    ///
+    /// Produces the same value as `to_owned`, but avoids reallocating
+    /// when converting many views in a loop.
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    pub fn to_owned_into(&self, out: &mut Zeta) -> Result<(), ssz::DecodeError> {
+        out.u = match self.u()? {
+            ssz_types::Optional::Some(inner) => {
+                ssz_types::Optional::Some(ssz_types::view::ToOwnedSsz::to_owned(&inner))
+            }
+            ssz_types::Optional::None => ssz_types::Optional::None,
+        };
+        out.v = match self.v()? {
+            ssz_types::Optional::Some(inner) => {
+                ssz_types::Optional::Some(ssz_types::view::ToOwnedSsz::to_owned(&inner))
+            }
+            ssz_types::Optional::None => ssz_types::Optional::None,
+        };
+        Ok(())
+    }
 }
 #[derive(
     std::clone::Clone,
@@ -2869,6 +2967,26 @@ impl<'a> TestTypeRef<'a> {
             large_int_256: self.large_int_256().expect("valid view"),
         }
     }
+    /// Converts this view into `out`, reusing the allocations of its
+    /// list and vector fields.
+    ///
+    /// Produces the same value as `to_owned`, but avoids reallocating
+    /// when converting many views in a loop.
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    pub fn to_owned_into(&self, out: &mut TestType) -> Result<(), ssz::DecodeError> {
+        out.ccc = self.ccc()?;
+        out.ddd = self.ddd()?;
+        let view = self.eee()?;
+        out.eee.clear();
+        for item in view.iter() {
+            out.eee
+                .push(ssz_types::view::ToOwnedSsz::to_owned(&item?))
+                .expect("valid view");
+        }
+        out.large_int_128 = self.large_int_128()?;
+        out.large_int_256 = self.large_int_256()?;
+        Ok(())
+    }
 }
 #[derive(
     std::clone::Clone,
@@ -3092,6 +3210,27 @@ impl<'a> EtaRef<'a> {
             },
         }
     }
+    /// Converts this view into `out`, reusing the allocations of its
+    /// list and vector fields.
+    ///
+    /// Produces the same value as `to_owned`, but avoids reallocating
+    /// when converting many views in a loop.
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    pub fn to_owned_into(&self, out: &mut Eta) -> Result<(), ssz::DecodeError> {
+        out.l = {
+            let view = self.l()?;
+            ssz_types::view::ToOwnedSsz::to_owned(&view)
+        };
+        out.m = {
+            let view = self.m()?;
+            ssz_types::view::ToOwnedSsz::to_owned(&view)
+        };
+        out.n = {
+            let view = self.n()?;
+            ssz_types::view::ToOwnedSsz::to_owned(&view)
+        };
+        Ok(())
+    }
 }
 #[derive(
     std::clone::Clone,
@@ -3312,6 +3451,24 @@ impl<'a> ThetaRef<'a> {
             q: ssz_types::FixedBytes(self.q().expect("valid view").to_owned()),
         }
     }
+    /// Converts this view into `out`, reusing the allocations of its
+    /// list and vector fields.
+    ///
+    /// Produces the same value as `to_owned`, but avoids reallocating
+    /// when converting many views in a loop.
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    pub fn to_owned_into(&self, out: &mut Theta) -> Result<(), ssz::DecodeError> {
+        out.o = {
+            let view = self.o()?;
+            ssz_types::view::ToOwnedSsz::to_owned(&view)
+        };
+        out.p = {
+            let view = self.p()?;
+            ssz_types::view::ToOwnedSsz::to_owned(&view)
+        };
+        out.q = ssz_types::FixedBytes(self.q()?.to_owned());
+        Ok(())
+    }
 }
 #[derive(
     std::clone::Clone,
@@ -3996,6 +4153,31 @@ impl<'a> IotaRef<'a> {
             s: self.s().expect("valid view"),
         }
     }
+    /// Converts this view into `out`, reusing the allocations of its
+    /// list and vector fields.
+    ///
+    /// Produces the same value as `to_owned`, but avoids reallocating
+    /// when converting many views in a loop.
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    pub fn to_owned_into(&self, out: &mut Iota) -> Result<(), ssz::DecodeError> {
+        out.g = self.g()?;
+        out.h = match self.h()? {
+            ssz_types::Optional::Some(inner) => {
+                ssz_types::Optional::Some(ssz_types::view::ToOwnedSsz::to_owned(&inner))
+            }
+            ssz_types::Optional::None => ssz_types::Optional::None,
+        };
+        out.i = self.i()?;
+        out.j = self.j()?;
+        out.r = match self.r()? {
+            ssz_types::Optional::Some(inner) => {
+                ssz_types::Optional::Some(ssz_types::view::ToOwnedSsz::to_owned(&inner))
+            }
+            ssz_types::Optional::None => ssz_types::Optional::None,
+        };
+        out.s = self.s()?;
+        Ok(())
+    }
 }
 #[derive(
     std::clone::Clone,
@@ -4216,6 +4398,24 @@ impl<'a> KappaRef<'a> {
             v: self.v().expect("valid view").to_owned(),
         }
     }
+    /// Converts this view into `out`, reusing the allocations of its
+    /// list and vector fields.
+    ///
+    /// Produces the same value as `to_owned`, but avoids reallocating
+    /// when converting many views in a loop.
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    pub fn to_owned_into(&self, out: &mut Kappa) -> Result<(), ssz::DecodeError> {
+        out.t = {
+            let view = self.t()?;
+            ssz_types::view::ToOwnedSsz::to_owned(&view)
+        };
+        out.u = {
+            let view = self.u()?;
+            ssz_types::view::ToOwnedSsz::to_owned(&view)
+        };
+        out.v = self.v()?.to_owned();
+        Ok(())
+    }
 }
 #[derive(
     std::clone::Clone,
@@ -4460,6 +4660,17 @@ impl<'a> LambdaRef<'a> {
             x: self.x().expect("valid view"),
         }
     }
+    /// Converts this view into `out`, reusing the allocations of its
+    /// list and vector fields.
+    ///
+    /// Produces the same value as `to_owned`, but avoids reallocating
+    /// when converting many views in a loop.
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    pub fn to_owned_into(&self, out: &mut Lambda) -> Result<(), ssz::DecodeError> {
+        out.w = self.w()?;
+        out.x = self.x()?;
+        Ok(())
+    }
 }
 #[derive(
     std::clone::Clone,
@@ -4633,6 +4844,23 @@ impl<'a> MuRef<'a> {
             },
         }
     }
+    /// Converts this view into `out`, reusing the allocations of its
+    /// list and vector fields.
+    ///
+    /// Produces the same value as `to_owned`, but avoids reallocating
+    /// when converting many views in a loop.
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    pub fn to_owned_into(&self, out: &mut Mu) -> Result<(), ssz::DecodeError> {
+        out.y = {
+            let view = self.y()?;
+            ssz_types::view::ToOwnedSsz::to_owned(&view)
+        };
+        out.z = {
+            let view = self.z()?;
+            ssz_types::view::ToOwnedSsz::to_owned(&view)
+        };
+        Ok(())
+    }
 }
 pub type AliasMu = Mu;
 #[derive(
@@ -4794,12 +5022,7 @@ impl<'a> NuRef<'a> {
             ],
             3usize,
         )?;
-        if bytes.is_empty() {
-            return Err(ssz::DecodeError::InvalidByteLength {
-                len: 0,
-                expected: 1,
-            });
-        }
+        ssz::view::reject_empty(bytes, 1)?;
         let selector = bytes[0];
         match selector {
             0 => Ok(None),
@@ -4932,4 +5155,25 @@ impl<'a> NuRef<'a> {
                 .map(|inner| ssz_types::view::ToOwnedSsz::to_owned(&inner)),
         }
     }
+    /// Converts this view into `out`, reusing the allocations of its
+    /// list and vector fields.
+    ///
+    /// Produces the same value as `to_owned`, but avoids reallocating
+    /// when converting many views in a loop.
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    pub fn to_owned_into(&self, out: &mut Nu) -> Result<(), ssz::DecodeError> {
+        out.zz = {
+            let view = self.zz()?;
+            ssz_types::view::ToOwnedSsz::to_owned(&view)
+        };
+        let view = self.aaa()?;
+        for (slot, item) in out.aaa.iter_mut().zip(view.iter()) {
+            *slot = ssz_types::view::ToOwnedSsz::to_owned(&item?);
+        }
+        out.bbb = self.bbb()?.to_owned();
+        out.test = self
+            .test()?
+            .map(|inner| ssz_types::view::ToOwnedSsz::to_owned(&inner));
+        Ok(())
+    }
 }
@@ -6,6 +6,12 @@ use ssz_derive::{Encode, Decode};
 use tree_hash::TreeHashDigest;
 use tree_hash_derive::TreeHash;
 use ssz::view::*;
+/// Hash of the normalized schema this module was generated from.
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+pub const SCHEMA_HASH: [u8; 32] = [
+    217, 187, 152, 54, 228, 204, 58, 106, 236, 50, 126, 193, 175, 150, 158, 5, 119, 131,
+    96, 130, 15, 223, 221, 206, 211, 11, 224, 53, 162, 197, 71, 106,
+];
 #[derive(
     std::clone::Clone,
     std::fmt::Debug,
@@ -171,6 +177,17 @@ impl<'a> ExportEntryRef<'a> {
             data: self.data().expect("valid view"),
         }
     }
+    /// Converts this view into `out`, reusing the allocations of its
+    /// list and vector fields.
+    ///
+    /// Produces the same value as `to_owned`, but avoids reallocating
+    /// when converting many views in a loop.
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    pub fn to_owned_into(&self, out: &mut ExportEntry) -> Result<(), ssz::DecodeError> {
+        out.value = self.value()?;
+        out.data = self.data()?;
+        Ok(())
+    }
 }
 #[derive(
     std::clone::Clone,
@@ -372,4 +389,24 @@ impl<'a> ExportContainerRef<'a> {
             name: self.name().expect("valid view"),
         }
     }
+    /// Converts this view into `out`, reusing the allocations of its
+    /// list and vector fields.
+    ///
+    /// Produces the same value as `to_owned`, but avoids reallocating
+    /// when converting many views in a loop.
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    pub fn to_owned_into(
+        &self,
+        out: &mut ExportContainer,
+    ) -> Result<(), ssz::DecodeError> {
+        let view = self.entries()?;
+        out.entries.clear();
+        for item in view.iter() {
+            out.entries
+                .push(ssz_types::view::ToOwnedSsz::to_owned(&item?))
+                .expect("valid view");
+        }
+        out.name = self.name()?;
+        Ok(())
+    }
 }
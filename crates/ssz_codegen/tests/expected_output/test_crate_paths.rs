@@ -0,0 +1,872 @@
+pub mod tests {
+    #![allow(unused_imports, reason = "generated code using ssz-gen")]
+    pub mod input {
+        #![allow(unused_imports, reason = "generated code using ssz-gen")]
+        pub mod test_alias_hops {
+            #![allow(unused_imports, reason = "generated code using ssz-gen")]
+            use crate::reexports::ssz_types::*;
+            use crate::reexports::ssz_types::view::{FixedVectorRef, VariableListRef};
+            use crate::reexports::ssz_primitives::{U128, U256};
+            use crate::reexports::ssz_derive::{Encode, Decode};
+            use crate::reexports::tree_hash::TreeHashDigest;
+            use crate::reexports::tree_hash_derive::TreeHash;
+            use crate::reexports::ssz::view::*;
+            #[allow(unused_imports)]
+            use crate::reexports::ssz as ssz;
+            #[allow(unused_imports)]
+            use crate::reexports::ssz_types as ssz_types;
+            #[allow(unused_imports)]
+            use crate::reexports::tree_hash as tree_hash;
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                223, 126, 136, 156, 184, 41, 109, 80, 63, 53, 10, 208, 30, 133, 226, 115,
+                93, 139, 2, 28, 37, 224, 231, 203, 245, 54, 102, 36, 88, 228, 223, 202,
+            ];
+            pub type HopOne = u16;
+            pub type HopTwo = HopOne;
+            pub type HopThree = HopTwo;
+            pub type ListOne = VariableList<HopThree, 8usize>;
+            pub type ListTwo = ListOne;
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                crate::reexports::ssz_derive::Encode,
+                crate::reexports::ssz_derive::Decode
+            )]
+            #[ssz(struct_behaviour = "stable_container", max_fields = 4usize)]
+            pub struct AliasHops {
+                pub value: Optional<HopThree>,
+                pub values: Optional<VariableList<HopThree, 8usize>>,
+                pub aliased_list: Optional<ListTwo>,
+                pub vector: Optional<FixedVector<HopThree, 2usize>>,
+            }
+            impl crate::reexports::tree_hash::TreeHash for AliasHops {
+                fn tree_hash_type() -> crate::reexports::tree_hash::TreeHashType {
+                    crate::reexports::tree_hash::TreeHashType::StableContainer
+                }
+                fn tree_hash_packed_encoding(
+                    &self,
+                ) -> crate::reexports::tree_hash::PackedEncoding {
+                    unreachable!("StableContainer/Profile should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("StableContainer/Profile should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use crate::reexports::tree_hash::TreeHash;
+                    use crate::reexports::ssz_types::BitVector;
+                    let mut active_fields = BitVector::<4usize>::new();
+                    if self.value.is_some() {
+                        active_fields
+                            .set(0usize, true)
+                            .expect("Should not be out of bounds");
+                    }
+                    if self.values.is_some() {
+                        active_fields
+                            .set(1usize, true)
+                            .expect("Should not be out of bounds");
+                    }
+                    if self.aliased_list.is_some() {
+                        active_fields
+                            .set(2usize, true)
+                            .expect("Should not be out of bounds");
+                    }
+                    if self.vector.is_some() {
+                        active_fields
+                            .set(3usize, true)
+                            .expect("Should not be out of bounds");
+                    }
+                    let mut field_roots: Vec<
+                        <H as crate::reexports::tree_hash::TreeHashDigest>::Output,
+                    > = Vec::with_capacity(4usize);
+                    if let crate::reexports::ssz_types::Optional::Some(ref inner) = self
+                        .value
+                    {
+                        field_roots
+                            .push(
+                                <_ as crate::reexports::tree_hash::TreeHash>::tree_hash_root::<
+                                    H,
+                                >(inner),
+                            );
+                    }
+                    if let crate::reexports::ssz_types::Optional::Some(ref inner) = self
+                        .values
+                    {
+                        field_roots
+                            .push(
+                                <_ as crate::reexports::tree_hash::TreeHash>::tree_hash_root::<
+                                    H,
+                                >(inner),
+                            );
+                    }
+                    if let crate::reexports::ssz_types::Optional::Some(ref inner) = self
+                        .aliased_list
+                    {
+                        field_roots
+                            .push(
+                                <_ as crate::reexports::tree_hash::TreeHash>::tree_hash_root::<
+                                    H,
+                                >(inner),
+                            );
+                    }
+                    if let crate::reexports::ssz_types::Optional::Some(ref inner) = self
+                        .vector
+                    {
+                        field_roots
+                            .push(
+                                <_ as crate::reexports::tree_hash::TreeHash>::tree_hash_root::<
+                                    H,
+                                >(inner),
+                            );
+                    }
+                    let hash = crate::reexports::tree_hash::merkleize_progressive_with_hasher::<
+                        H,
+                    >(&field_roots);
+                    let active_fields_hash = <_ as crate::reexports::tree_hash::TreeHash>::tree_hash_root::<
+                        H,
+                    >(&active_fields);
+                    H::hash32_concat(hash.as_ref(), active_fields_hash.as_ref())
+                }
+            }
+            /// Zero-copy view over [`AliasHops`].
+            ///
+            /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
+            /// via lazy getter methods. Use `.to_owned()` to convert to the owned type when
+            /// needed.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                std::marker::Copy
+            )]
+            pub struct AliasHopsRef<'a> {
+                bytes: &'a [u8],
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> AliasHopsRef<'a> {
+                pub fn value(
+                    &self,
+                ) -> Result<Optional<u16>, crate::reexports::ssz::DecodeError> {
+                    use crate::reexports::ssz::Decode;
+                    let bitvector_bytes = self
+                        .bytes
+                        .get(..1usize)
+                        .ok_or_else(|| crate::reexports::ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
+                        })?;
+                    let bitvector = crate::reexports::ssz_types::BitVector::<
+                        4usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    let body = &self.bytes[1usize..];
+                    let field_active: &[bool] = &[
+                        bitvector.get(0usize).unwrap_or(false),
+                        bitvector.get(1usize).unwrap_or(false),
+                        bitvector.get(2usize).unwrap_or(false),
+                        bitvector.get(3usize).unwrap_or(false),
+                    ];
+                    let field_layout: &[crate::reexports::ssz::layout::FieldInfo] = &[
+                        (
+                            <Optional<
+                                HopThree,
+                            > as crate::reexports::ssz::Encode>::is_ssz_fixed_len(),
+                            <Optional<
+                                HopThree,
+                            > as crate::reexports::ssz::Encode>::ssz_fixed_len(),
+                        ),
+                        (
+                            <Optional<
+                                VariableList<HopThree, 8usize>,
+                            > as crate::reexports::ssz::Encode>::is_ssz_fixed_len(),
+                            <Optional<
+                                VariableList<HopThree, 8usize>,
+                            > as crate::reexports::ssz::Encode>::ssz_fixed_len(),
+                        ),
+                        (
+                            <Optional<
+                                ListTwo,
+                            > as crate::reexports::ssz::Encode>::is_ssz_fixed_len(),
+                            <Optional<
+                                ListTwo,
+                            > as crate::reexports::ssz::Encode>::ssz_fixed_len(),
+                        ),
+                        (
+                            <Optional<
+                                FixedVector<HopThree, 2usize>,
+                            > as crate::reexports::ssz::Encode>::is_ssz_fixed_len(),
+                            <Optional<
+                                FixedVector<HopThree, 2usize>,
+                            > as crate::reexports::ssz::Encode>::ssz_fixed_len(),
+                        ),
+                    ];
+                    let field_bytes = match crate::reexports::ssz::layout::read_active_field_bytes(
+                        body,
+                        field_layout,
+                        |i| field_active[i],
+                        0usize,
+                    )? {
+                        Some(bytes) => bytes,
+                        None => return Ok(crate::reexports::ssz_types::Optional::None),
+                    };
+                    let inner = <u16 as crate::reexports::ssz::view::DecodeView>::from_ssz_bytes(
+                            field_bytes,
+                        )
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
+                    Ok(crate::reexports::ssz_types::Optional::Some(inner))
+                }
+                pub fn values(
+                    &self,
+                ) -> Result<
+                    Optional<ListRef<'a, u16, 8usize>>,
+                    crate::reexports::ssz::DecodeError,
+                > {
+                    use crate::reexports::ssz::Decode;
+                    let bitvector_bytes = self
+                        .bytes
+                        .get(..1usize)
+                        .ok_or_else(|| crate::reexports::ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
+                        })?;
+                    let bitvector = crate::reexports::ssz_types::BitVector::<
+                        4usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    let body = &self.bytes[1usize..];
+                    let field_active: &[bool] = &[
+                        bitvector.get(0usize).unwrap_or(false),
+                        bitvector.get(1usize).unwrap_or(false),
+                        bitvector.get(2usize).unwrap_or(false),
+                        bitvector.get(3usize).unwrap_or(false),
+                    ];
+                    let field_layout: &[crate::reexports::ssz::layout::FieldInfo] = &[
+                        (
+                            <Optional<
+                                HopThree,
+                            > as crate::reexports::ssz::Encode>::is_ssz_fixed_len(),
+                            <Optional<
+                                HopThree,
+                            > as crate::reexports::ssz::Encode>::ssz_fixed_len(),
+                        ),
+                        (
+                            <Optional<
+                                VariableList<HopThree, 8usize>,
+                            > as crate::reexports::ssz::Encode>::is_ssz_fixed_len(),
+                            <Optional<
+                                VariableList<HopThree, 8usize>,
+                            > as crate::reexports::ssz::Encode>::ssz_fixed_len(),
+                        ),
+                        (
+                            <Optional<
+                                ListTwo,
+                            > as crate::reexports::ssz::Encode>::is_ssz_fixed_len(),
+                            <Optional<
+                                ListTwo,
+                            > as crate::reexports::ssz::Encode>::ssz_fixed_len(),
+                        ),
+                        (
+                            <Optional<
+                                FixedVector<HopThree, 2usize>,
+                            > as crate::reexports::ssz::Encode>::is_ssz_fixed_len(),
+                            <Optional<
+                                FixedVector<HopThree, 2usize>,
+                            > as crate::reexports::ssz::Encode>::ssz_fixed_len(),
+                        ),
+                    ];
+                    let field_bytes = match crate::reexports::ssz::layout::read_active_field_bytes(
+                        body,
+                        field_layout,
+                        |i| field_active[i],
+                        1usize,
+                    )? {
+                        Some(bytes) => bytes,
+                        None => return Ok(crate::reexports::ssz_types::Optional::None),
+                    };
+                    let inner = <ListRef<
+                        'a,
+                        u16,
+                        8usize,
+                    > as crate::reexports::ssz::view::DecodeView>::from_ssz_bytes(
+                            field_bytes,
+                        )
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
+                    Ok(crate::reexports::ssz_types::Optional::Some(inner))
+                }
+                pub fn aliased_list(
+                    &self,
+                ) -> Result<
+                    Optional<ListRef<'a, u16, 8usize>>,
+                    crate::reexports::ssz::DecodeError,
+                > {
+                    use crate::reexports::ssz::Decode;
+                    let bitvector_bytes = self
+                        .bytes
+                        .get(..1usize)
+                        .ok_or_else(|| crate::reexports::ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
+                        })?;
+                    let bitvector = crate::reexports::ssz_types::BitVector::<
+                        4usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    let body = &self.bytes[1usize..];
+                    let field_active: &[bool] = &[
+                        bitvector.get(0usize).unwrap_or(false),
+                        bitvector.get(1usize).unwrap_or(false),
+                        bitvector.get(2usize).unwrap_or(false),
+                        bitvector.get(3usize).unwrap_or(false),
+                    ];
+                    let field_layout: &[crate::reexports::ssz::layout::FieldInfo] = &[
+                        (
+                            <Optional<
+                                HopThree,
+                            > as crate::reexports::ssz::Encode>::is_ssz_fixed_len(),
+                            <Optional<
+                                HopThree,
+                            > as crate::reexports::ssz::Encode>::ssz_fixed_len(),
+                        ),
+                        (
+                            <Optional<
+                                VariableList<HopThree, 8usize>,
+                            > as crate::reexports::ssz::Encode>::is_ssz_fixed_len(),
+                            <Optional<
+                                VariableList<HopThree, 8usize>,
+                            > as crate::reexports::ssz::Encode>::ssz_fixed_len(),
+                        ),
+                        (
+                            <Optional<
+                                ListTwo,
+                            > as crate::reexports::ssz::Encode>::is_ssz_fixed_len(),
+                            <Optional<
+                                ListTwo,
+                            > as crate::reexports::ssz::Encode>::ssz_fixed_len(),
+                        ),
+                        (
+                            <Optional<
+                                FixedVector<HopThree, 2usize>,
+                            > as crate::reexports::ssz::Encode>::is_ssz_fixed_len(),
+                            <Optional<
+                                FixedVector<HopThree, 2usize>,
+                            > as crate::reexports::ssz::Encode>::ssz_fixed_len(),
+                        ),
+                    ];
+                    let field_bytes = match crate::reexports::ssz::layout::read_active_field_bytes(
+                        body,
+                        field_layout,
+                        |i| field_active[i],
+                        2usize,
+                    )? {
+                        Some(bytes) => bytes,
+                        None => return Ok(crate::reexports::ssz_types::Optional::None),
+                    };
+                    let inner = <ListRef<
+                        'a,
+                        u16,
+                        8usize,
+                    > as crate::reexports::ssz::view::DecodeView>::from_ssz_bytes(
+                            field_bytes,
+                        )
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
+                    Ok(crate::reexports::ssz_types::Optional::Some(inner))
+                }
+                pub fn vector(
+                    &self,
+                ) -> Result<
+                    Optional<FixedVectorRef<'a, u16, 2usize>>,
+                    crate::reexports::ssz::DecodeError,
+                > {
+                    use crate::reexports::ssz::Decode;
+                    let bitvector_bytes = self
+                        .bytes
+                        .get(..1usize)
+                        .ok_or_else(|| crate::reexports::ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
+                        })?;
+                    let bitvector = crate::reexports::ssz_types::BitVector::<
+                        4usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    let body = &self.bytes[1usize..];
+                    let field_active: &[bool] = &[
+                        bitvector.get(0usize).unwrap_or(false),
+                        bitvector.get(1usize).unwrap_or(false),
+                        bitvector.get(2usize).unwrap_or(false),
+                        bitvector.get(3usize).unwrap_or(false),
+                    ];
+                    let field_layout: &[crate::reexports::ssz::layout::FieldInfo] = &[
+                        (
+                            <Optional<
+                                HopThree,
+                            > as crate::reexports::ssz::Encode>::is_ssz_fixed_len(),
+                            <Optional<
+                                HopThree,
+                            > as crate::reexports::ssz::Encode>::ssz_fixed_len(),
+                        ),
+                        (
+                            <Optional<
+                                VariableList<HopThree, 8usize>,
+                            > as crate::reexports::ssz::Encode>::is_ssz_fixed_len(),
+                            <Optional<
+                                VariableList<HopThree, 8usize>,
+                            > as crate::reexports::ssz::Encode>::ssz_fixed_len(),
+                        ),
+                        (
+                            <Optional<
+                                ListTwo,
+                            > as crate::reexports::ssz::Encode>::is_ssz_fixed_len(),
+                            <Optional<
+                                ListTwo,
+                            > as crate::reexports::ssz::Encode>::ssz_fixed_len(),
+                        ),
+                        (
+                            <Optional<
+                                FixedVector<HopThree, 2usize>,
+                            > as crate::reexports::ssz::Encode>::is_ssz_fixed_len(),
+                            <Optional<
+                                FixedVector<HopThree, 2usize>,
+                            > as crate::reexports::ssz::Encode>::ssz_fixed_len(),
+                        ),
+                    ];
+                    let field_bytes = match crate::reexports::ssz::layout::read_active_field_bytes(
+                        body,
+                        field_layout,
+                        |i| field_active[i],
+                        3usize,
+                    )? {
+                        Some(bytes) => bytes,
+                        None => return Ok(crate::reexports::ssz_types::Optional::None),
+                    };
+                    let inner = <FixedVectorRef<
+                        'a,
+                        u16,
+                        2usize,
+                    > as crate::reexports::ssz::view::DecodeView>::from_ssz_bytes(
+                            field_bytes,
+                        )
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
+                    Ok(crate::reexports::ssz_types::Optional::Some(inner))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> AliasHopsRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(
+                    &self,
+                ) -> Result<(), crate::reexports::ssz::DecodeError> {
+                    self.value()?;
+                    let value = self.values()?;
+                    if let crate::reexports::ssz_types::Optional::Some(inner) = value {
+                        for item in inner.iter() {
+                            item?;
+                        }
+                    }
+                    let value = self.aliased_list()?;
+                    if let crate::reexports::ssz_types::Optional::Some(inner) = value {
+                        for item in inner.iter() {
+                            item?;
+                        }
+                    }
+                    let value = self.vector()?;
+                    if let crate::reexports::ssz_types::Optional::Some(inner) = value {
+                        for item in inner.iter() {
+                            item?;
+                        }
+                    }
+                    Ok(())
+                }
+            }
+            impl<'a> crate::reexports::tree_hash::TreeHash for AliasHopsRef<'a> {
+                fn tree_hash_type() -> crate::reexports::tree_hash::TreeHashType {
+                    crate::reexports::tree_hash::TreeHashType::StableContainer
+                }
+                fn tree_hash_packed_encoding(
+                    &self,
+                ) -> crate::reexports::tree_hash::PackedEncoding {
+                    unreachable!("StableContainer should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("StableContainer should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use crate::reexports::tree_hash::TreeHash;
+                    use crate::reexports::ssz_types::BitVector;
+                    let value = self.value().expect("valid view");
+                    let values = self.values().expect("valid view");
+                    let aliased_list = self.aliased_list().expect("valid view");
+                    let vector = self.vector().expect("valid view");
+                    let mut active_fields = BitVector::<4usize>::new();
+                    if value.is_some() {
+                        active_fields
+                            .set(0usize, true)
+                            .expect("Should not be out of bounds");
+                    }
+                    if values.is_some() {
+                        active_fields
+                            .set(1usize, true)
+                            .expect("Should not be out of bounds");
+                    }
+                    if aliased_list.is_some() {
+                        active_fields
+                            .set(2usize, true)
+                            .expect("Should not be out of bounds");
+                    }
+                    if vector.is_some() {
+                        active_fields
+                            .set(3usize, true)
+                            .expect("Should not be out of bounds");
+                    }
+                    let mut field_roots: Vec<
+                        <H as crate::reexports::tree_hash::TreeHashDigest>::Output,
+                    > = Vec::with_capacity(4usize);
+                    if let crate::reexports::ssz_types::Optional::Some(ref inner) = value {
+                        field_roots
+                            .push(
+                                <_ as crate::reexports::tree_hash::TreeHash>::tree_hash_root::<
+                                    H,
+                                >(inner),
+                            );
+                    }
+                    if let crate::reexports::ssz_types::Optional::Some(ref inner) = values {
+                        field_roots
+                            .push(
+                                <_ as crate::reexports::tree_hash::TreeHash>::tree_hash_root::<
+                                    H,
+                                >(inner),
+                            );
+                    }
+                    if let crate::reexports::ssz_types::Optional::Some(ref inner) = aliased_list {
+                        field_roots
+                            .push(
+                                <_ as crate::reexports::tree_hash::TreeHash>::tree_hash_root::<
+                                    H,
+                                >(inner),
+                            );
+                    }
+                    if let crate::reexports::ssz_types::Optional::Some(ref inner) = vector {
+                        field_roots
+                            .push(
+                                <_ as crate::reexports::tree_hash::TreeHash>::tree_hash_root::<
+                                    H,
+                                >(inner),
+                            );
+                    }
+                    let hash = crate::reexports::tree_hash::merkleize_progressive_with_hasher::<
+                        H,
+                    >(&field_roots);
+                    let active_fields_hash = <_ as crate::reexports::tree_hash::TreeHash>::tree_hash_root::<
+                        H,
+                    >(&active_fields);
+                    H::hash32_concat(hash.as_ref(), active_fields_hash.as_ref())
+                }
+            }
+            impl<'a> crate::reexports::ssz::view::DecodeView<'a> for AliasHopsRef<'a> {
+                fn from_ssz_bytes(
+                    bytes: &'a [u8],
+                ) -> Result<Self, crate::reexports::ssz::DecodeError> {
+                    use crate::reexports::ssz::Decode;
+                    let bitvector_bytes = bytes
+                        .get(..1usize)
+                        .ok_or_else(|| crate::reexports::ssz::DecodeError::Incomplete {
+                            needed: 1usize - bytes.len(),
+                        })?;
+                    let bitvector = crate::reexports::ssz_types::BitVector::<
+                        4usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    let body = &bytes[1usize..];
+                    let field_active: &[bool] = &[
+                        bitvector.get(0usize).unwrap_or(false),
+                        bitvector.get(1usize).unwrap_or(false),
+                        bitvector.get(2usize).unwrap_or(false),
+                        bitvector.get(3usize).unwrap_or(false),
+                    ];
+                    let field_layout: &[crate::reexports::ssz::layout::FieldInfo] = &[
+                        (
+                            <Optional<
+                                HopThree,
+                            > as crate::reexports::ssz::Encode>::is_ssz_fixed_len(),
+                            <Optional<
+                                HopThree,
+                            > as crate::reexports::ssz::Encode>::ssz_fixed_len(),
+                        ),
+                        (
+                            <Optional<
+                                VariableList<HopThree, 8usize>,
+                            > as crate::reexports::ssz::Encode>::is_ssz_fixed_len(),
+                            <Optional<
+                                VariableList<HopThree, 8usize>,
+                            > as crate::reexports::ssz::Encode>::ssz_fixed_len(),
+                        ),
+                        (
+                            <Optional<
+                                ListTwo,
+                            > as crate::reexports::ssz::Encode>::is_ssz_fixed_len(),
+                            <Optional<
+                                ListTwo,
+                            > as crate::reexports::ssz::Encode>::ssz_fixed_len(),
+                        ),
+                        (
+                            <Optional<
+                                FixedVector<HopThree, 2usize>,
+                            > as crate::reexports::ssz::Encode>::is_ssz_fixed_len(),
+                            <Optional<
+                                FixedVector<HopThree, 2usize>,
+                            > as crate::reexports::ssz::Encode>::ssz_fixed_len(),
+                        ),
+                    ];
+                    crate::reexports::ssz::layout::validate_active_container(
+                        body,
+                        field_layout,
+                        |i| field_active[i],
+                    )?;
+                    Ok(Self { bytes })
+                }
+            }
+            impl<'a> TryFrom<&'a [u8]> for AliasHopsRef<'a> {
+                type Error = crate::reexports::ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as crate::reexports::ssz::view::DecodeView<
+                        'a,
+                    >>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> AliasHopsRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
+            impl<'a> crate::reexports::ssz::view::SszTypeInfo for AliasHopsRef<'a> {
+                fn is_ssz_fixed_len() -> bool {
+                    false
+                }
+                fn ssz_fixed_len() -> usize {
+                    0
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> crate::reexports::ssz_types::view::ToOwnedSsz<AliasHops>
+            for AliasHopsRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                fn to_owned(&self) -> AliasHops {
+                    <AliasHopsRef<'a>>::to_owned(self)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> AliasHopsRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned(&self) -> AliasHops {
+                    AliasHops {
+                        value: self.value().expect("valid view"),
+                        values: match self.values().expect("valid view") {
+                            crate::reexports::ssz_types::Optional::Some(inner) => {
+                                crate::reexports::ssz_types::Optional::Some(
+                                    crate::reexports::ssz_types::view::ToOwnedSsz::to_owned(
+                                        &inner,
+                                    ),
+                                )
+                            }
+                            crate::reexports::ssz_types::Optional::None => {
+                                crate::reexports::ssz_types::Optional::None
+                            }
+                        },
+                        aliased_list: match self.aliased_list().expect("valid view") {
+                            crate::reexports::ssz_types::Optional::Some(inner) => {
+                                crate::reexports::ssz_types::Optional::Some(
+                                    crate::reexports::ssz_types::view::ToOwnedSsz::to_owned(
+                                        &inner,
+                                    ),
+                                )
+                            }
+                            crate::reexports::ssz_types::Optional::None => {
+                                crate::reexports::ssz_types::Optional::None
+                            }
+                        },
+                        vector: match self.vector().expect("valid view") {
+                            crate::reexports::ssz_types::Optional::Some(inner) => {
+                                crate::reexports::ssz_types::Optional::Some(
+                                    crate::reexports::ssz_types::view::ToOwnedSsz::to_owned(
+                                        &inner,
+                                    ),
+                                )
+                            }
+                            crate::reexports::ssz_types::Optional::None => {
+                                crate::reexports::ssz_types::Optional::None
+                            }
+                        },
+                    }
+                }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut AliasHops,
+                ) -> Result<(), crate::reexports::ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.value = self.value()?;
+                    out.values = match self.values()? {
+                        crate::reexports::ssz_types::Optional::Some(inner) => {
+                            crate::reexports::ssz_types::Optional::Some(
+                                crate::reexports::ssz_types::view::ToOwnedSsz::to_owned(
+                                    &inner,
+                                ),
+                            )
+                        }
+                        crate::reexports::ssz_types::Optional::None => {
+                            crate::reexports::ssz_types::Optional::None
+                        }
+                    };
+                    out.aliased_list = match self.aliased_list()? {
+                        crate::reexports::ssz_types::Optional::Some(inner) => {
+                            crate::reexports::ssz_types::Optional::Some(
+                                crate::reexports::ssz_types::view::ToOwnedSsz::to_owned(
+                                    &inner,
+                                ),
+                            )
+                        }
+                        crate::reexports::ssz_types::Optional::None => {
+                            crate::reexports::ssz_types::Optional::None
+                        }
+                    };
+                    out.vector = match self.vector()? {
+                        crate::reexports::ssz_types::Optional::Some(inner) => {
+                            crate::reexports::ssz_types::Optional::Some(
+                                crate::reexports::ssz_types::view::ToOwnedSsz::to_owned(
+                                    &inner,
+                                ),
+                            )
+                        }
+                        crate::reexports::ssz_types::Optional::None => {
+                            crate::reexports::ssz_types::Optional::None
+                        }
+                    };
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> AliasHopsRef<'a> {
+                fn eq_owned(
+                    &self,
+                    other: &AliasHops,
+                ) -> Result<bool, crate::reexports::ssz::DecodeError> {
+                    use crate::reexports::ssz::Decode;
+                    let bitvector_bytes = self
+                        .bytes
+                        .get(..1usize)
+                        .ok_or_else(|| crate::reexports::ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
+                        })?;
+                    let bitvector = crate::reexports::ssz_types::BitVector::<
+                        4usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    if bitvector.get(0usize).unwrap_or(false) != other.value.is_some() {
+                        return Ok(false);
+                    }
+                    if bitvector.get(1usize).unwrap_or(false) != other.values.is_some() {
+                        return Ok(false);
+                    }
+                    if bitvector.get(2usize).unwrap_or(false)
+                        != other.aliased_list.is_some()
+                    {
+                        return Ok(false);
+                    }
+                    if bitvector.get(3usize).unwrap_or(false) != other.vector.is_some() {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<HopThree> = self.value()?;
+                        value == other.value
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<VariableList<HopThree, 8usize>> = match self
+                            .values()?
+                        {
+                            crate::reexports::ssz_types::Optional::Some(inner) => {
+                                crate::reexports::ssz_types::Optional::Some(
+                                    crate::reexports::ssz_types::view::ToOwnedSsz::to_owned(
+                                        &inner,
+                                    ),
+                                )
+                            }
+                            crate::reexports::ssz_types::Optional::None => {
+                                crate::reexports::ssz_types::Optional::None
+                            }
+                        };
+                        value == other.values
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<ListTwo> = match self.aliased_list()? {
+                            crate::reexports::ssz_types::Optional::Some(inner) => {
+                                crate::reexports::ssz_types::Optional::Some(
+                                    crate::reexports::ssz_types::view::ToOwnedSsz::to_owned(
+                                        &inner,
+                                    ),
+                                )
+                            }
+                            crate::reexports::ssz_types::Optional::None => {
+                                crate::reexports::ssz_types::Optional::None
+                            }
+                        };
+                        value == other.aliased_list
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<FixedVector<HopThree, 2usize>> = match self
+                            .vector()?
+                        {
+                            crate::reexports::ssz_types::Optional::Some(inner) => {
+                                crate::reexports::ssz_types::Optional::Some(
+                                    crate::reexports::ssz_types::view::ToOwnedSsz::to_owned(
+                                        &inner,
+                                    ),
+                                )
+                            }
+                            crate::reexports::ssz_types::Optional::None => {
+                                crate::reexports::ssz_types::Optional::None
+                            }
+                        };
+                        value == other.vector
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<AliasHops> for AliasHopsRef<'a> {
+                fn eq(&self, other: &AliasHops) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<AliasHopsRef<'a>> for AliasHops {
+                fn eq(&self, other: &AliasHopsRef<'a>) -> bool {
+                    other == self
+                }
+            }
+        }
+    }
+}
@@ -11,6 +11,12 @@ pub mod tests {
             use tree_hash::TreeHashDigest;
             use tree_hash_derive::TreeHash;
             use ssz::view::*;
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                123, 22, 232, 143, 54, 165, 186, 97, 185, 3, 228, 139, 97, 42, 127, 119,
+                172, 22, 9, 254, 122, 111, 141, 63, 224, 237, 102, 58, 98, 11, 61, 125,
+            ];
             #[derive(
                 std::clone::Clone,
                 std::fmt::Debug,
@@ -149,6 +155,22 @@ pub mod tests {
                         value: self.value().expect("valid view"),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut CommonTypeA,
+                ) -> Result<(), ssz::DecodeError> {
+                    out.value = self.value()?;
+                    Ok(())
+                }
             }
             #[derive(
                 std::clone::Clone,
@@ -288,6 +310,22 @@ pub mod tests {
                         value: self.value().expect("valid view"),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut CommonTypeB,
+                ) -> Result<(), ssz::DecodeError> {
+                    out.value = self.value()?;
+                    Ok(())
+                }
             }
         }
         pub mod test_cross_entry_local {
@@ -299,6 +337,12 @@ pub mod tests {
             use tree_hash::TreeHashDigest;
             use tree_hash_derive::TreeHash;
             use ssz::view::*;
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                18, 127, 133, 55, 208, 221, 120, 13, 175, 248, 67, 73, 62, 211, 210, 198,
+                183, 196, 82, 33, 46, 78, 35, 247, 220, 66, 174, 253, 248, 147, 4, 193,
+            ];
             #[derive(
                 std::clone::Clone,
                 std::fmt::Debug,
@@ -538,6 +582,32 @@ pub mod tests {
                         },
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut ContainerA,
+                ) -> Result<(), ssz::DecodeError> {
+                    out.field = {
+                        let view = self.field()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    let view = self.list()?;
+                    out.list.clear();
+                    for item in view.iter() {
+                        out.list
+                            .push(ssz_types::view::ToOwnedSsz::to_owned(&item?))
+                            .expect("valid view");
+                    }
+                    Ok(())
+                }
             }
         }
     }
@@ -11,6 +11,12 @@ pub mod tests {
             use tree_hash::TreeHashDigest;
             use tree_hash_derive::TreeHash;
             use ssz::view::*;
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                202, 112, 49, 159, 96, 128, 19, 233, 29, 221, 181, 99, 232, 138, 90, 39,
+                62, 193, 85, 231, 226, 170, 176, 4, 62, 202, 135, 240, 245, 137, 119, 72,
+            ];
             #[allow(dead_code, reason = "generated code using ssz-gen")]
             pub const MAX_VK_BYTES: u64 = 48u64;
             #[derive(
@@ -200,6 +206,23 @@ pub mod tests {
                         counter: self.counter().expect("valid view"),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut State,
+                ) -> Result<(), ssz::DecodeError> {
+                    out.data = ssz_types::FixedBytes(self.data()?.to_owned());
+                    out.counter = self.counter()?;
+                    Ok(())
+                }
             }
         }
         pub mod test_cross_entry_update {
@@ -211,6 +234,13 @@ pub mod tests {
             use tree_hash::TreeHashDigest;
             use tree_hash_derive::TreeHash;
             use ssz::view::*;
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                142, 224, 37, 78, 240, 185, 152, 167, 180, 179, 60, 170, 50, 67, 200,
+                150, 165, 51, 156, 196, 90, 207, 135, 177, 217, 142, 211, 249, 19, 230,
+                253, 60,
+            ];
             #[allow(dead_code, reason = "generated code using ssz-gen")]
             pub const MAX_UPDATES: u64 = 10u64;
             #[derive(
@@ -478,6 +508,31 @@ pub mod tests {
                             .expect("valid view"),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Update,
+                ) -> Result<(), ssz::DecodeError> {
+                    out.state = {
+                        let view = self.state()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    out.timestamp = self.timestamp()?;
+                    let view = self.updates()?;
+                    out.updates.clear();
+                    out.updates
+                        .try_extend_from_slice(view.as_bytes())
+                        .expect("valid view");
+                    Ok(())
+                }
             }
         }
     }
@@ -6,6 +6,12 @@ use ssz_derive::{Encode, Decode};
 use tree_hash::TreeHashDigest;
 use tree_hash_derive::TreeHash;
 use ssz::view::*;
+/// Hash of the normalized schema this module was generated from.
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+pub const SCHEMA_HASH: [u8; 32] = [
+    50, 209, 75, 44, 27, 208, 107, 144, 87, 146, 193, 176, 202, 110, 126, 227, 48, 55,
+    45, 89, 196, 69, 24, 69, 12, 178, 208, 131, 84, 99, 222, 113,
+];
 #[allow(dead_code, reason = "generated code using ssz-gen")]
 pub const MAX_VK_BYTES: u64 = 48u64;
 #[derive(
@@ -176,6 +182,17 @@ impl<'a> StateRef<'a> {
             counter: self.counter().expect("valid view"),
         }
     }
+    /// Converts this view into `out`, reusing the allocations of its
+    /// list and vector fields.
+    ///
+    /// Produces the same value as `to_owned`, but avoids reallocating
+    /// when converting many views in a loop.
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    pub fn to_owned_into(&self, out: &mut State) -> Result<(), ssz::DecodeError> {
+        out.data = ssz_types::FixedBytes(self.data()?.to_owned());
+        out.counter = self.counter()?;
+        Ok(())
+    }
 }
 #[allow(dead_code, reason = "generated code using ssz-gen")]
 pub const MAX_UPDATES: u64 = 10u64;
@@ -410,4 +427,21 @@ impl<'a> UpdateRef<'a> {
                 .expect("valid view"),
         }
     }
+    /// Converts this view into `out`, reusing the allocations of its
+    /// list and vector fields.
+    ///
+    /// Produces the same value as `to_owned`, but avoids reallocating
+    /// when converting many views in a loop.
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    pub fn to_owned_into(&self, out: &mut Update) -> Result<(), ssz::DecodeError> {
+        out.state = {
+            let view = self.state()?;
+            ssz_types::view::ToOwnedSsz::to_owned(&view)
+        };
+        out.timestamp = self.timestamp()?;
+        let view = self.updates()?;
+        out.updates.clear();
+        out.updates.try_extend_from_slice(view.as_bytes()).expect("valid view");
+        Ok(())
+    }
 }
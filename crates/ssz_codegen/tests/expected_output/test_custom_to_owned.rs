@@ -11,6 +11,12 @@ pub mod tests {
             use tree_hash::TreeHashDigest;
             use tree_hash_derive::TreeHash;
             use ssz::view::*;
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                81, 23, 149, 151, 62, 224, 199, 129, 87, 94, 128, 101, 50, 217, 218, 166,
+                213, 85, 145, 149, 63, 31, 41, 68, 7, 39, 39, 10, 198, 28, 228, 125,
+            ];
             #[derive(
                 std::clone::Clone,
                 std::fmt::Debug,
@@ -24,6 +30,12 @@ pub mod tests {
                 pub value: u64,
                 pub hash: FixedBytes<32usize>,
             }
+            impl InnerData {
+                /// Byte offset of each field in the SSZ encoding, in declaration order.
+                pub const FIELD_OFFSETS: &[usize] = &[0, 8];
+                /// Byte size of each field in the SSZ encoding, in declaration order.
+                pub const FIELD_SIZES: &[usize] = &[8, 32];
+            }
             impl tree_hash::TreeHash for InnerData {
                 fn tree_hash_type() -> tree_hash::TreeHashType {
                     tree_hash::TreeHashType::Container
@@ -54,6 +66,29 @@ pub mod tests {
                         .expect("tree hash derive should not have a remaining buffer")
                 }
             }
+            impl tree_hash::TreeHashLeaves for InnerData {
+                fn tree_hash_leaf_count() -> usize {
+                    2usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.value)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.hash)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 2usize
+                            )
+                        }
+                    }
+                }
+            }
             /// Zero-copy view over [`InnerData`].
             ///
             /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
@@ -73,40 +108,30 @@ pub mod tests {
             #[allow(dead_code, reason = "generated code using ssz-gen")]
             impl<'a> InnerDataRef<'a> {
                 pub fn value(&self) -> Result<u64, ssz::DecodeError> {
-                    let bytes = ssz::layout::read_field_bytes(
-                        self.bytes,
-                        &[
-                            (
-                                <u64 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u64 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                            (
-                                <FixedBytes<32usize> as ssz::Encode>::is_ssz_fixed_len(),
-                                <FixedBytes<32usize> as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                        ],
-                        0usize,
-                    )?;
+                    let bytes = &self.bytes[0..8];
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn hash(
                     &self,
                 ) -> Result<FixedBytesRef<'a, 32usize>, ssz::DecodeError> {
-                    let bytes = ssz::layout::read_field_bytes(
-                        self.bytes,
-                        &[
-                            (
-                                <u64 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u64 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                            (
-                                <FixedBytes<32usize> as ssz::Encode>::is_ssz_fixed_len(),
-                                <FixedBytes<32usize> as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                        ],
-                        1usize,
-                    )?;
+                    let bytes = &self.bytes[8..40];
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> InnerDataRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.value()?;
+                    self.hash()?;
+                    Ok(())
                 }
             }
             impl<'a> tree_hash::TreeHash for InnerDataRef<'a> {
@@ -157,6 +182,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for InnerDataRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> InnerDataRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for InnerDataRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     usize::from(!<u64 as ssz::Encode>::is_ssz_fixed_len())
@@ -197,6 +235,56 @@ pub mod tests {
                         ),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut InnerData,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.value = self.value()?;
+                    out.hash = ssz_types::FixedBytes(self.hash()?.to_owned());
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> InnerDataRef<'a> {
+                fn eq_owned(&self, other: &InnerData) -> Result<bool, ssz::DecodeError> {
+                    if !({
+                        let value: u64 = self.value()?;
+                        value == other.value
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: FixedBytes<32usize> = ssz_types::FixedBytes(
+                            self.hash()?.to_owned(),
+                        );
+                        value == other.hash
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<InnerData> for InnerDataRef<'a> {
+                fn eq(&self, other: &InnerData) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<InnerDataRef<'a>> for InnerData {
+                fn eq(&self, other: &InnerDataRef<'a>) -> bool {
+                    other == self
+                }
             }
             #[derive(
                 std::clone::Clone,
@@ -241,6 +329,29 @@ pub mod tests {
                         .expect("tree hash derive should not have a remaining buffer")
                 }
             }
+            impl tree_hash::TreeHashLeaves for OuterContainer {
+                fn tree_hash_leaf_count() -> usize {
+                    2usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.inner)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.items)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 2usize
+                            )
+                        }
+                    }
+                }
+            }
             /// Zero-copy view over [`OuterContainer`].
             ///
             /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
@@ -281,6 +392,7 @@ pub mod tests {
                         0usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn items(
                     &self,
@@ -306,6 +418,26 @@ pub mod tests {
                         1usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> OuterContainerRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    let value = self.inner()?;
+                    value.validate_deep()?;
+                    let value = self.items()?;
+                    for item in value.iter() {
+                        let item = item?;
+                        item.validate_deep()?;
+                    }
+                    Ok(())
                 }
             }
             impl<'a> tree_hash::TreeHash for OuterContainerRef<'a> {
@@ -362,6 +494,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for OuterContainerRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> OuterContainerRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for OuterContainerRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     usize::from(!<InnerData as ssz::Encode>::is_ssz_fixed_len())
@@ -421,6 +566,74 @@ pub mod tests {
                         },
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut OuterContainer,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.inner = {
+                        let view = self.inner()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    let view = self.items()?;
+                    out.items.clear();
+                    for item in view.iter() {
+                        out.items
+                            .push(ssz_types::view::ToOwnedSsz::to_owned(&item?))
+                            .expect("valid view");
+                    }
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> OuterContainerRef<'a> {
+                fn eq_owned(
+                    &self,
+                    other: &OuterContainer,
+                ) -> Result<bool, ssz::DecodeError> {
+                    if !(self.inner()? == other.inner) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: VariableList<InnerData, 10usize> = {
+                            let view = self.items()?;
+                            let items: Result<Vec<_>, _> = view
+                                .iter()
+                                .map(|item_result| {
+                                    item_result
+                                        .map(|item| ssz_types::view::ToOwnedSsz::to_owned(&item))
+                                })
+                                .collect();
+                            let items = items.expect("valid view");
+                            ssz_types::VariableList::new(items).expect("valid view")
+                        };
+                        value == other.items
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<OuterContainer> for OuterContainerRef<'a> {
+                fn eq(&self, other: &OuterContainer) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<OuterContainerRef<'a>> for OuterContainer {
+                fn eq(&self, other: &OuterContainerRef<'a>) -> bool {
+                    other == self
+                }
             }
         }
     }
@@ -0,0 +1,2958 @@
+pub mod tests {
+    #![allow(unused_imports, reason = "generated code using ssz-gen")]
+    pub mod input {
+        #![allow(unused_imports, reason = "generated code using ssz-gen")]
+        pub mod test_default_impl {
+            #![allow(unused_imports, reason = "generated code using ssz-gen")]
+            use ssz_types::*;
+            use ssz_types::view::{FixedVectorRef, VariableListRef};
+            use ssz_primitives::{U128, U256};
+            use ssz_derive::{Encode, Decode};
+            use tree_hash::TreeHashDigest;
+            use tree_hash_derive::TreeHash;
+            use ssz::view::*;
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                249, 217, 72, 27, 135, 187, 199, 185, 131, 193, 35, 89, 83, 136, 73, 175,
+                125, 56, 182, 193, 246, 241, 143, 131, 61, 123, 155, 163, 116, 13, 172,
+                28,
+            ];
+            #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+            #[ssz(enum_behaviour = "union")]
+            pub enum Choice {
+                Selector0(u8),
+                Alpha(Alpha),
+            }
+            impl tree_hash::TreeHash for Choice {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::Container
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Union should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Union should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    match self {
+                        Choice::Selector0(inner) => {
+                            let root = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                                H,
+                            >(inner);
+                            tree_hash::mix_in_selector_with_hasher::<H>(&root, 0u8)
+                                .expect("valid selector")
+                        }
+                        Choice::Alpha(inner) => {
+                            let root = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                                H,
+                            >(inner);
+                            tree_hash::mix_in_selector_with_hasher::<H>(&root, 1u8)
+                                .expect("valid selector")
+                        }
+                    }
+                }
+            }
+            impl std::fmt::Display for Choice {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    match self {
+                        Choice::Selector0(inner) => {
+                            write!(f, "Choice::Selector0({inner:?})")
+                        }
+                        Choice::Alpha(inner) => write!(f, "Choice::Alpha({inner:?})"),
+                    }
+                }
+            }
+            impl From<u8> for Choice {
+                fn from(value: u8) -> Self {
+                    Choice::Selector0(value)
+                }
+            }
+            impl From<Alpha> for Choice {
+                fn from(value: Alpha) -> Self {
+                    Choice::Alpha(value)
+                }
+            }
+            #[cfg(test)]
+            impl Choice {
+                /// Returns one value per selector, in selector order, each
+                /// holding its member's default. Lets tests check that code
+                /// matching on the union handles every member.
+                pub fn all_selector_examples() -> Vec<Self> {
+                    vec![
+                        Choice::Selector0(Default::default()),
+                        Choice::Alpha(Default::default())
+                    ]
+                }
+            }
+            #[derive(Debug, Copy, Clone)]
+            pub struct ChoiceRef<'a> {
+                bytes: &'a [u8],
+            }
+            impl<'a> ChoiceRef<'a> {
+                pub fn selector(&self) -> u8 {
+                    self.bytes[0]
+                }
+                pub fn as_selector0(&self) -> Result<u8, ssz::DecodeError> {
+                    if self.selector() != 0u8 {
+                        return Err(
+                            ssz::DecodeError::BytesInvalid(
+                                "Wrong selector for Choice: expected 0".to_string(),
+                            ),
+                        );
+                    }
+                    let body = &self.bytes[1..];
+                    if <u8 as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <u8 as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
+                }
+                pub fn as_selector1(&self) -> Result<AlphaRef<'a>, ssz::DecodeError> {
+                    if self.selector() != 1u8 {
+                        return Err(
+                            ssz::DecodeError::BytesInvalid(
+                                "Wrong selector for Choice: expected 1".to_string(),
+                            ),
+                        );
+                    }
+                    let body = &self.bytes[1..];
+                    if <Alpha as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <Alpha as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
+                }
+                pub fn to_owned(&self) -> Choice {
+                    match self.selector() {
+                        0u8 => {
+                            Choice::Selector0(
+                                self.as_selector0().expect("valid selector"),
+                            )
+                        }
+                        1u8 => {
+                            Choice::Alpha({
+                                let view = self.as_selector1().expect("valid selector");
+                                ssz_types::view::ToOwnedSsz::to_owned(&view)
+                            })
+                        }
+                        _ => panic!("Invalid union selector: {}", self.selector()),
+                    }
+                }
+                /// Validates the selector and the selected member, recursing
+                /// into nested views and walking every list and vector, and
+                /// returns the first error.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    match self.selector() {
+                        0u8 => self.as_selector0().map(|_| ()),
+                        1u8 => {
+                            let value = self.as_selector1()?;
+                            value.validate_deep()?;
+                            Ok(())
+                        }
+                        selector => Err(ssz::DecodeError::UnionSelectorInvalid(selector)),
+                    }
+                }
+            }
+            impl<'a> ssz::view::DecodeView<'a> for ChoiceRef<'a> {
+                fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
+                    let (_, _) = ssz::split_union_bytes(bytes)?;
+                    Ok(Self { bytes })
+                }
+            }
+            impl<'a> ssz::view::SszTypeInfo for ChoiceRef<'a> {
+                fn is_ssz_fixed_len() -> bool {
+                    false
+                }
+                fn ssz_fixed_len() -> usize {
+                    0
+                }
+            }
+            impl<'a> ssz_types::view::ToOwnedSsz<Choice> for ChoiceRef<'a> {
+                fn to_owned(&self) -> Choice {
+                    <ChoiceRef<'a>>::to_owned(self)
+                }
+            }
+            impl<'a> tree_hash::TreeHash for ChoiceRef<'a> {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::Vector
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Union should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Union should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    match self.selector() {
+                        0u8 => {
+                            let value = self.as_selector0().expect("valid selector");
+                            tree_hash::mix_in_selector_with_hasher::<
+                                H,
+                            >(
+                                    &<_ as tree_hash::TreeHash>::tree_hash_root::<H>(&value),
+                                    0u8,
+                                )
+                                .expect("valid selector")
+                        }
+                        1u8 => {
+                            let value = self.as_selector1().expect("valid selector");
+                            tree_hash::mix_in_selector_with_hasher::<
+                                H,
+                            >(
+                                    &<_ as tree_hash::TreeHash>::tree_hash_root::<H>(&value),
+                                    1u8,
+                                )
+                                .expect("valid selector")
+                        }
+                        _ => panic!("Invalid union selector: {}", self.selector()),
+                    }
+                }
+            }
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                ssz_derive::Encode,
+                ssz_derive::Decode
+            )]
+            #[ssz(struct_behaviour = "container")]
+            pub struct Alpha {
+                pub a: u8,
+                pub b: u64,
+                pub c: FixedBytes<48usize>,
+                pub d: bool,
+            }
+            impl Default for Alpha {
+                fn default() -> Self {
+                    Self {
+                        a: Default::default(),
+                        b: Default::default(),
+                        c: ssz_types::FixedBytes::zero(),
+                        d: Default::default(),
+                    }
+                }
+            }
+            impl Alpha {
+                /// Byte offset of each field in the SSZ encoding, in declaration order.
+                pub const FIELD_OFFSETS: &[usize] = &[0, 1, 9, 57];
+                /// Byte size of each field in the SSZ encoding, in declaration order.
+                pub const FIELD_SIZES: &[usize] = &[1, 8, 48, 1];
+            }
+            impl tree_hash::TreeHash for Alpha {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::Container
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(4usize);
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.a)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.b)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.c)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.d)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .finish()
+                        .expect("tree hash derive should not have a remaining buffer")
+                }
+            }
+            impl tree_hash::TreeHashLeaves for Alpha {
+                fn tree_hash_leaf_count() -> usize {
+                    4usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.a)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.b)
+                        }
+                        2usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.c)
+                        }
+                        3usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.d)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 4usize
+                            )
+                        }
+                    }
+                }
+            }
+            /// Zero-copy view over [`Alpha`].
+            ///
+            /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
+            /// via lazy getter methods. Use `.to_owned()` to convert to the owned type when
+            /// needed.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                std::marker::Copy
+            )]
+            pub struct AlphaRef<'a> {
+                bytes: &'a [u8],
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> AlphaRef<'a> {
+                pub fn a(&self) -> Result<u8, ssz::DecodeError> {
+                    let bytes = &self.bytes[0..1];
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+                pub fn b(&self) -> Result<u64, ssz::DecodeError> {
+                    let bytes = &self.bytes[1..9];
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+                pub fn c(&self) -> Result<FixedBytesRef<'a, 48usize>, ssz::DecodeError> {
+                    let bytes = &self.bytes[9..57];
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+                pub fn d(&self) -> Result<bool, ssz::DecodeError> {
+                    let bytes = &self.bytes[57..58];
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> AlphaRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.a()?;
+                    self.b()?;
+                    self.c()?;
+                    self.d()?;
+                    Ok(())
+                }
+            }
+            impl<'a> tree_hash::TreeHash for AlphaRef<'a> {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::StableContainer
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(4usize);
+                    {
+                        let a = self.a().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&a);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    {
+                        let b = self.b().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&b);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    {
+                        let c = self.c().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&c);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    {
+                        let d = self.d().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&d);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    hasher.finish().expect("finish hasher")
+                }
+            }
+            impl<'a> ssz::view::DecodeView<'a> for AlphaRef<'a> {
+                fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
+                    ssz::layout::validate_container(
+                        bytes,
+                        &[
+                            (
+                                <u8 as ssz::Encode>::is_ssz_fixed_len(),
+                                <u8 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <u64 as ssz::Encode>::is_ssz_fixed_len(),
+                                <u64 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <FixedBytes<48usize> as ssz::Encode>::is_ssz_fixed_len(),
+                                <FixedBytes<48usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <bool as ssz::Encode>::is_ssz_fixed_len(),
+                                <bool as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                    )?;
+                    Ok(Self { bytes })
+                }
+            }
+            impl<'a> TryFrom<&'a [u8]> for AlphaRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> AlphaRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
+            impl<'a> ssz::view::SszTypeInfo for AlphaRef<'a> {
+                fn is_ssz_fixed_len() -> bool {
+                    usize::from(!<u8 as ssz::Encode>::is_ssz_fixed_len())
+                        + usize::from(!<u64 as ssz::Encode>::is_ssz_fixed_len())
+                        + usize::from(
+                            !<FixedBytes<48usize> as ssz::Encode>::is_ssz_fixed_len(),
+                        ) + usize::from(!<bool as ssz::Encode>::is_ssz_fixed_len()) == 0
+                }
+                fn ssz_fixed_len() -> usize {
+                    if <Self as ssz::view::SszTypeInfo>::is_ssz_fixed_len() {
+                        <u8 as ssz::Encode>::ssz_fixed_len()
+                            + <u64 as ssz::Encode>::ssz_fixed_len()
+                            + <FixedBytes<48usize> as ssz::Encode>::ssz_fixed_len()
+                            + <bool as ssz::Encode>::ssz_fixed_len()
+                    } else {
+                        0
+                    }
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ssz_types::view::ToOwnedSsz<Alpha> for AlphaRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                fn to_owned(&self) -> Alpha {
+                    <AlphaRef<'a>>::to_owned(self)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> AlphaRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned(&self) -> Alpha {
+                    Alpha {
+                        a: self.a().expect("valid view"),
+                        b: self.b().expect("valid view"),
+                        c: ssz_types::FixedBytes(
+                            self.c().expect("valid view").to_owned(),
+                        ),
+                        d: self.d().expect("valid view"),
+                    }
+                }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Alpha,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.a = self.a()?;
+                    out.b = self.b()?;
+                    out.c = ssz_types::FixedBytes(self.c()?.to_owned());
+                    out.d = self.d()?;
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> AlphaRef<'a> {
+                /// Converts this view to an owned value, reading every field and
+                /// collecting the ones that fail to decode.
+                ///
+                /// Each error is paired with the path of its field, such as
+                /// `inner.x` for a field of a nested container, and the failed
+                /// field takes its default value in the returned owned value.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn validate_and_to_owned(
+                    &self,
+                ) -> (Alpha, Vec<(String, ssz::DecodeError)>) {
+                    let mut errors = Vec::new();
+                    let value = Alpha {
+                        a: match ssz::layout::read_field_bytes(
+                                self.bytes,
+                                &[
+                                    (
+                                        <u8 as ssz::Encode>::is_ssz_fixed_len(),
+                                        <u8 as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <u64 as ssz::Encode>::is_ssz_fixed_len(),
+                                        <u64 as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <FixedBytes<48usize> as ssz::Encode>::is_ssz_fixed_len(),
+                                        <FixedBytes<48usize> as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <bool as ssz::Encode>::is_ssz_fixed_len(),
+                                        <bool as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                ],
+                                0usize,
+                            )
+                            .and_then(|bytes| {
+                                <u8 as ssz::Decode>::from_ssz_bytes(bytes)
+                                    .map_err(|e| e.bounded(bytes.len()))
+                            })
+                        {
+                            Ok(value) => value,
+                            Err(error) => {
+                                errors.push(("a".to_string(), error));
+                                Default::default()
+                            }
+                        },
+                        b: match ssz::layout::read_field_bytes(
+                                self.bytes,
+                                &[
+                                    (
+                                        <u8 as ssz::Encode>::is_ssz_fixed_len(),
+                                        <u8 as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <u64 as ssz::Encode>::is_ssz_fixed_len(),
+                                        <u64 as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <FixedBytes<48usize> as ssz::Encode>::is_ssz_fixed_len(),
+                                        <FixedBytes<48usize> as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <bool as ssz::Encode>::is_ssz_fixed_len(),
+                                        <bool as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                ],
+                                1usize,
+                            )
+                            .and_then(|bytes| {
+                                <u64 as ssz::Decode>::from_ssz_bytes(bytes)
+                                    .map_err(|e| e.bounded(bytes.len()))
+                            })
+                        {
+                            Ok(value) => value,
+                            Err(error) => {
+                                errors.push(("b".to_string(), error));
+                                Default::default()
+                            }
+                        },
+                        c: match ssz::layout::read_field_bytes(
+                                self.bytes,
+                                &[
+                                    (
+                                        <u8 as ssz::Encode>::is_ssz_fixed_len(),
+                                        <u8 as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <u64 as ssz::Encode>::is_ssz_fixed_len(),
+                                        <u64 as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <FixedBytes<48usize> as ssz::Encode>::is_ssz_fixed_len(),
+                                        <FixedBytes<48usize> as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <bool as ssz::Encode>::is_ssz_fixed_len(),
+                                        <bool as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                ],
+                                2usize,
+                            )
+                            .and_then(|bytes| {
+                                <FixedBytes<48usize> as ssz::Decode>::from_ssz_bytes(bytes)
+                                    .map_err(|e| e.bounded(bytes.len()))
+                            })
+                        {
+                            Ok(value) => value,
+                            Err(error) => {
+                                errors.push(("c".to_string(), error));
+                                ssz_types::FixedBytes::zero()
+                            }
+                        },
+                        d: match ssz::layout::read_field_bytes(
+                                self.bytes,
+                                &[
+                                    (
+                                        <u8 as ssz::Encode>::is_ssz_fixed_len(),
+                                        <u8 as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <u64 as ssz::Encode>::is_ssz_fixed_len(),
+                                        <u64 as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <FixedBytes<48usize> as ssz::Encode>::is_ssz_fixed_len(),
+                                        <FixedBytes<48usize> as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <bool as ssz::Encode>::is_ssz_fixed_len(),
+                                        <bool as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                ],
+                                3usize,
+                            )
+                            .and_then(|bytes| {
+                                <bool as ssz::Decode>::from_ssz_bytes(bytes)
+                                    .map_err(|e| e.bounded(bytes.len()))
+                            })
+                        {
+                            Ok(value) => value,
+                            Err(error) => {
+                                errors.push(("d".to_string(), error));
+                                Default::default()
+                            }
+                        },
+                    };
+                    (value, errors)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> AlphaRef<'a> {
+                fn eq_owned(&self, other: &Alpha) -> Result<bool, ssz::DecodeError> {
+                    if !({
+                        let value: u8 = self.a()?;
+                        value == other.a
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: u64 = self.b()?;
+                        value == other.b
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: FixedBytes<48usize> = ssz_types::FixedBytes(
+                            self.c()?.to_owned(),
+                        );
+                        value == other.c
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: bool = self.d()?;
+                        value == other.d
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<Alpha> for AlphaRef<'a> {
+                fn eq(&self, other: &Alpha) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<AlphaRef<'a>> for Alpha {
+                fn eq(&self, other: &AlphaRef<'a>) -> bool {
+                    other == self
+                }
+            }
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                ssz_derive::Encode,
+                ssz_derive::Decode
+            )]
+            #[ssz(struct_behaviour = "container")]
+            pub struct Beta {
+                pub alpha: Alpha,
+                pub roots: FixedVector<FixedBytes<32usize>, 4usize>,
+                pub bits: BitList<16usize>,
+                pub values: VariableList<u16, 8usize>,
+                pub big: U256,
+            }
+            impl Default for Beta {
+                fn default() -> Self {
+                    Self {
+                        alpha: Default::default(),
+                        roots: ssz_types::FixedVector::from_elem(
+                            ssz_types::FixedBytes::zero(),
+                        ),
+                        bits: ssz_types::BitList::with_capacity(0)
+                            .expect("empty bitlist is always valid"),
+                        values: Default::default(),
+                        big: Default::default(),
+                    }
+                }
+            }
+            impl tree_hash::TreeHash for Beta {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::Container
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(5usize);
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.alpha)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.roots)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.bits)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.values)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.big)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .finish()
+                        .expect("tree hash derive should not have a remaining buffer")
+                }
+            }
+            impl tree_hash::TreeHashLeaves for Beta {
+                fn tree_hash_leaf_count() -> usize {
+                    5usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.alpha)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.roots)
+                        }
+                        2usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.bits)
+                        }
+                        3usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.values)
+                        }
+                        4usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.big)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 5usize
+                            )
+                        }
+                    }
+                }
+            }
+            /// Zero-copy view over [`Beta`].
+            ///
+            /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
+            /// via lazy getter methods. Use `.to_owned()` to convert to the owned type when
+            /// needed.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                std::marker::Copy
+            )]
+            pub struct BetaRef<'a> {
+                bytes: &'a [u8],
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> BetaRef<'a> {
+                pub fn alpha(&self) -> Result<AlphaRef<'a>, ssz::DecodeError> {
+                    let bytes = ssz::layout::read_field_bytes(
+                        self.bytes,
+                        &[
+                            (
+                                <Alpha as ssz::Encode>::is_ssz_fixed_len(),
+                                <Alpha as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <FixedVector<
+                                    FixedBytes<32usize>,
+                                    4usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <FixedVector<
+                                    FixedBytes<32usize>,
+                                    4usize,
+                                > as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <BitList<16usize> as ssz::Encode>::is_ssz_fixed_len(),
+                                <BitList<16usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <VariableList<
+                                    u16,
+                                    8usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <VariableList<u16, 8usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <U256 as ssz::Encode>::is_ssz_fixed_len(),
+                                <U256 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                        0usize,
+                    )?;
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+                pub fn roots(
+                    &self,
+                ) -> Result<
+                    FixedVectorRef<'a, FixedBytesRef<'a, 32usize>, 4usize>,
+                    ssz::DecodeError,
+                > {
+                    let bytes = ssz::layout::read_field_bytes(
+                        self.bytes,
+                        &[
+                            (
+                                <Alpha as ssz::Encode>::is_ssz_fixed_len(),
+                                <Alpha as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <FixedVector<
+                                    FixedBytes<32usize>,
+                                    4usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <FixedVector<
+                                    FixedBytes<32usize>,
+                                    4usize,
+                                > as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <BitList<16usize> as ssz::Encode>::is_ssz_fixed_len(),
+                                <BitList<16usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <VariableList<
+                                    u16,
+                                    8usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <VariableList<u16, 8usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <U256 as ssz::Encode>::is_ssz_fixed_len(),
+                                <U256 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                        1usize,
+                    )?;
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+                pub fn bits(&self) -> Result<BitListRef<'a, 16usize>, ssz::DecodeError> {
+                    let bytes = ssz::layout::read_field_bytes(
+                        self.bytes,
+                        &[
+                            (
+                                <Alpha as ssz::Encode>::is_ssz_fixed_len(),
+                                <Alpha as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <FixedVector<
+                                    FixedBytes<32usize>,
+                                    4usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <FixedVector<
+                                    FixedBytes<32usize>,
+                                    4usize,
+                                > as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <BitList<16usize> as ssz::Encode>::is_ssz_fixed_len(),
+                                <BitList<16usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <VariableList<
+                                    u16,
+                                    8usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <VariableList<u16, 8usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <U256 as ssz::Encode>::is_ssz_fixed_len(),
+                                <U256 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                        2usize,
+                    )?;
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+                pub fn values(
+                    &self,
+                ) -> Result<ListRef<'a, u16, 8usize>, ssz::DecodeError> {
+                    let bytes = ssz::layout::read_field_bytes(
+                        self.bytes,
+                        &[
+                            (
+                                <Alpha as ssz::Encode>::is_ssz_fixed_len(),
+                                <Alpha as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <FixedVector<
+                                    FixedBytes<32usize>,
+                                    4usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <FixedVector<
+                                    FixedBytes<32usize>,
+                                    4usize,
+                                > as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <BitList<16usize> as ssz::Encode>::is_ssz_fixed_len(),
+                                <BitList<16usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <VariableList<
+                                    u16,
+                                    8usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <VariableList<u16, 8usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <U256 as ssz::Encode>::is_ssz_fixed_len(),
+                                <U256 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                        3usize,
+                    )?;
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+                pub fn big(&self) -> Result<U256, ssz::DecodeError> {
+                    let bytes = ssz::layout::read_field_bytes(
+                        self.bytes,
+                        &[
+                            (
+                                <Alpha as ssz::Encode>::is_ssz_fixed_len(),
+                                <Alpha as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <FixedVector<
+                                    FixedBytes<32usize>,
+                                    4usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <FixedVector<
+                                    FixedBytes<32usize>,
+                                    4usize,
+                                > as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <BitList<16usize> as ssz::Encode>::is_ssz_fixed_len(),
+                                <BitList<16usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <VariableList<
+                                    u16,
+                                    8usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <VariableList<u16, 8usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <U256 as ssz::Encode>::is_ssz_fixed_len(),
+                                <U256 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                        4usize,
+                    )?;
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> BetaRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    let value = self.alpha()?;
+                    value.validate_deep()?;
+                    let value = self.roots()?;
+                    for item in value.iter() {
+                        item?;
+                    }
+                    self.bits()?;
+                    let value = self.values()?;
+                    for item in value.iter() {
+                        item?;
+                    }
+                    self.big()?;
+                    Ok(())
+                }
+            }
+            impl<'a> tree_hash::TreeHash for BetaRef<'a> {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::StableContainer
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(5usize);
+                    {
+                        let alpha = self.alpha().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&alpha);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    {
+                        let roots = self.roots().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&roots);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    {
+                        let bits = self.bits().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&bits);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    {
+                        let values = self.values().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&values);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    {
+                        let big = self.big().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&big);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    hasher.finish().expect("finish hasher")
+                }
+            }
+            impl<'a> ssz::view::DecodeView<'a> for BetaRef<'a> {
+                fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
+                    ssz::layout::validate_container(
+                        bytes,
+                        &[
+                            (
+                                <Alpha as ssz::Encode>::is_ssz_fixed_len(),
+                                <Alpha as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <FixedVector<
+                                    FixedBytes<32usize>,
+                                    4usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <FixedVector<
+                                    FixedBytes<32usize>,
+                                    4usize,
+                                > as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <BitList<16usize> as ssz::Encode>::is_ssz_fixed_len(),
+                                <BitList<16usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <VariableList<
+                                    u16,
+                                    8usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <VariableList<u16, 8usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <U256 as ssz::Encode>::is_ssz_fixed_len(),
+                                <U256 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                    )?;
+                    Ok(Self { bytes })
+                }
+            }
+            impl<'a> TryFrom<&'a [u8]> for BetaRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> BetaRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
+            impl<'a> ssz::view::SszTypeInfo for BetaRef<'a> {
+                fn is_ssz_fixed_len() -> bool {
+                    usize::from(!<Alpha as ssz::Encode>::is_ssz_fixed_len())
+                        + usize::from(
+                            !<FixedVector<
+                                FixedBytes<32usize>,
+                                4usize,
+                            > as ssz::Encode>::is_ssz_fixed_len(),
+                        )
+                        + usize::from(
+                            !<BitList<16usize> as ssz::Encode>::is_ssz_fixed_len(),
+                        )
+                        + usize::from(
+                            !<VariableList<
+                                u16,
+                                8usize,
+                            > as ssz::Encode>::is_ssz_fixed_len(),
+                        ) + usize::from(!<U256 as ssz::Encode>::is_ssz_fixed_len()) == 0
+                }
+                fn ssz_fixed_len() -> usize {
+                    if <Self as ssz::view::SszTypeInfo>::is_ssz_fixed_len() {
+                        <Alpha as ssz::Encode>::ssz_fixed_len()
+                            + <FixedVector<
+                                FixedBytes<32usize>,
+                                4usize,
+                            > as ssz::Encode>::ssz_fixed_len()
+                            + <BitList<16usize> as ssz::Encode>::ssz_fixed_len()
+                            + <VariableList<u16, 8usize> as ssz::Encode>::ssz_fixed_len()
+                            + <U256 as ssz::Encode>::ssz_fixed_len()
+                    } else {
+                        0
+                    }
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ssz_types::view::ToOwnedSsz<Beta> for BetaRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                fn to_owned(&self) -> Beta {
+                    <BetaRef<'a>>::to_owned(self)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> BetaRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned(&self) -> Beta {
+                    Beta {
+                        alpha: {
+                            let view = self.alpha().expect("valid view");
+                            ssz_types::view::ToOwnedSsz::to_owned(&view)
+                        },
+                        roots: self
+                            .roots()
+                            .expect("valid view")
+                            .to_owned()
+                            .expect("valid view"),
+                        bits: self.bits().expect("valid view").to_owned(),
+                        values: {
+                            let view = self.values().expect("valid view");
+                            let items: Result<Vec<_>, _> = view
+                                .iter()
+                                .map(|item_result| {
+                                    item_result
+                                        .map(|item| ssz_types::view::ToOwnedSsz::to_owned(&item))
+                                })
+                                .collect();
+                            let items = items.expect("valid view");
+                            ssz_types::VariableList::new(items).expect("valid view")
+                        },
+                        big: self.big().expect("valid view"),
+                    }
+                }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Beta,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.alpha = {
+                        let view = self.alpha()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    let view = self.roots()?;
+                    for (slot, item) in out.roots.iter_mut().zip(view.iter()) {
+                        *slot = ssz_types::view::ToOwnedSsz::to_owned(&item?);
+                    }
+                    out.bits = self.bits()?.to_owned();
+                    let view = self.values()?;
+                    out.values.clear();
+                    for item in view.iter() {
+                        out.values
+                            .push(ssz_types::view::ToOwnedSsz::to_owned(&item?))
+                            .expect("valid view");
+                    }
+                    out.big = self.big()?;
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> BetaRef<'a> {
+                /// Converts this view to an owned value, reading every field and
+                /// collecting the ones that fail to decode.
+                ///
+                /// Each error is paired with the path of its field, such as
+                /// `inner.x` for a field of a nested container, and the failed
+                /// field takes its default value in the returned owned value.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn validate_and_to_owned(
+                    &self,
+                ) -> (Beta, Vec<(String, ssz::DecodeError)>) {
+                    let mut errors = Vec::new();
+                    let value = Beta {
+                        alpha: match ssz::layout::read_field_bytes(
+                                self.bytes,
+                                &[
+                                    (
+                                        <Alpha as ssz::Encode>::is_ssz_fixed_len(),
+                                        <Alpha as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <FixedVector<
+                                            FixedBytes<32usize>,
+                                            4usize,
+                                        > as ssz::Encode>::is_ssz_fixed_len(),
+                                        <FixedVector<
+                                            FixedBytes<32usize>,
+                                            4usize,
+                                        > as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <BitList<16usize> as ssz::Encode>::is_ssz_fixed_len(),
+                                        <BitList<16usize> as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <VariableList<
+                                            u16,
+                                            8usize,
+                                        > as ssz::Encode>::is_ssz_fixed_len(),
+                                        <VariableList<u16, 8usize> as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <U256 as ssz::Encode>::is_ssz_fixed_len(),
+                                        <U256 as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                ],
+                                0usize,
+                            )
+                            .and_then(|bytes| {
+                                <AlphaRef<
+                                    'a,
+                                > as ssz::view::DecodeView>::from_ssz_bytes(bytes)
+                                    .map_err(|e| e.bounded(bytes.len()))
+                            })
+                        {
+                            Ok(view) => {
+                                let (value, nested) = view.validate_and_to_owned();
+                                errors
+                                    .extend(
+                                        nested
+                                            .into_iter()
+                                            .map(|(path, error)| (
+                                                format!("{}.{}", "alpha", path),
+                                                error,
+                                            )),
+                                    );
+                                value
+                            }
+                            Err(error) => {
+                                errors.push(("alpha".to_string(), error));
+                                Default::default()
+                            }
+                        },
+                        roots: match ssz::layout::read_field_bytes(
+                                self.bytes,
+                                &[
+                                    (
+                                        <Alpha as ssz::Encode>::is_ssz_fixed_len(),
+                                        <Alpha as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <FixedVector<
+                                            FixedBytes<32usize>,
+                                            4usize,
+                                        > as ssz::Encode>::is_ssz_fixed_len(),
+                                        <FixedVector<
+                                            FixedBytes<32usize>,
+                                            4usize,
+                                        > as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <BitList<16usize> as ssz::Encode>::is_ssz_fixed_len(),
+                                        <BitList<16usize> as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <VariableList<
+                                            u16,
+                                            8usize,
+                                        > as ssz::Encode>::is_ssz_fixed_len(),
+                                        <VariableList<u16, 8usize> as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <U256 as ssz::Encode>::is_ssz_fixed_len(),
+                                        <U256 as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                ],
+                                1usize,
+                            )
+                            .and_then(|bytes| {
+                                <FixedVector<
+                                    FixedBytes<32usize>,
+                                    4usize,
+                                > as ssz::Decode>::from_ssz_bytes(bytes)
+                                    .map_err(|e| e.bounded(bytes.len()))
+                            })
+                        {
+                            Ok(value) => value,
+                            Err(error) => {
+                                errors.push(("roots".to_string(), error));
+                                ssz_types::FixedVector::from_elem(
+                                    ssz_types::FixedBytes::zero(),
+                                )
+                            }
+                        },
+                        bits: match ssz::layout::read_field_bytes(
+                                self.bytes,
+                                &[
+                                    (
+                                        <Alpha as ssz::Encode>::is_ssz_fixed_len(),
+                                        <Alpha as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <FixedVector<
+                                            FixedBytes<32usize>,
+                                            4usize,
+                                        > as ssz::Encode>::is_ssz_fixed_len(),
+                                        <FixedVector<
+                                            FixedBytes<32usize>,
+                                            4usize,
+                                        > as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <BitList<16usize> as ssz::Encode>::is_ssz_fixed_len(),
+                                        <BitList<16usize> as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <VariableList<
+                                            u16,
+                                            8usize,
+                                        > as ssz::Encode>::is_ssz_fixed_len(),
+                                        <VariableList<u16, 8usize> as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <U256 as ssz::Encode>::is_ssz_fixed_len(),
+                                        <U256 as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                ],
+                                2usize,
+                            )
+                            .and_then(|bytes| {
+                                <BitList<16usize> as ssz::Decode>::from_ssz_bytes(bytes)
+                                    .map_err(|e| e.bounded(bytes.len()))
+                            })
+                        {
+                            Ok(value) => value,
+                            Err(error) => {
+                                errors.push(("bits".to_string(), error));
+                                ssz_types::BitList::with_capacity(0)
+                                    .expect("empty bitlist is always valid")
+                            }
+                        },
+                        values: match ssz::layout::read_field_bytes(
+                                self.bytes,
+                                &[
+                                    (
+                                        <Alpha as ssz::Encode>::is_ssz_fixed_len(),
+                                        <Alpha as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <FixedVector<
+                                            FixedBytes<32usize>,
+                                            4usize,
+                                        > as ssz::Encode>::is_ssz_fixed_len(),
+                                        <FixedVector<
+                                            FixedBytes<32usize>,
+                                            4usize,
+                                        > as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <BitList<16usize> as ssz::Encode>::is_ssz_fixed_len(),
+                                        <BitList<16usize> as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <VariableList<
+                                            u16,
+                                            8usize,
+                                        > as ssz::Encode>::is_ssz_fixed_len(),
+                                        <VariableList<u16, 8usize> as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <U256 as ssz::Encode>::is_ssz_fixed_len(),
+                                        <U256 as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                ],
+                                3usize,
+                            )
+                            .and_then(|bytes| {
+                                <VariableList<
+                                    u16,
+                                    8usize,
+                                > as ssz::Decode>::from_ssz_bytes(bytes)
+                                    .map_err(|e| e.bounded(bytes.len()))
+                            })
+                        {
+                            Ok(value) => value,
+                            Err(error) => {
+                                errors.push(("values".to_string(), error));
+                                Default::default()
+                            }
+                        },
+                        big: match ssz::layout::read_field_bytes(
+                                self.bytes,
+                                &[
+                                    (
+                                        <Alpha as ssz::Encode>::is_ssz_fixed_len(),
+                                        <Alpha as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <FixedVector<
+                                            FixedBytes<32usize>,
+                                            4usize,
+                                        > as ssz::Encode>::is_ssz_fixed_len(),
+                                        <FixedVector<
+                                            FixedBytes<32usize>,
+                                            4usize,
+                                        > as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <BitList<16usize> as ssz::Encode>::is_ssz_fixed_len(),
+                                        <BitList<16usize> as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <VariableList<
+                                            u16,
+                                            8usize,
+                                        > as ssz::Encode>::is_ssz_fixed_len(),
+                                        <VariableList<u16, 8usize> as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <U256 as ssz::Encode>::is_ssz_fixed_len(),
+                                        <U256 as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                ],
+                                4usize,
+                            )
+                            .and_then(|bytes| {
+                                <U256 as ssz::Decode>::from_ssz_bytes(bytes)
+                                    .map_err(|e| e.bounded(bytes.len()))
+                            })
+                        {
+                            Ok(value) => value,
+                            Err(error) => {
+                                errors.push(("big".to_string(), error));
+                                Default::default()
+                            }
+                        },
+                    };
+                    (value, errors)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> BetaRef<'a> {
+                fn eq_owned(&self, other: &Beta) -> Result<bool, ssz::DecodeError> {
+                    if !(self.alpha()? == other.alpha) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: FixedVector<FixedBytes<32usize>, 4usize> = self
+                            .roots()?
+                            .to_owned()
+                            .expect("valid view");
+                        value == other.roots
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: BitList<16usize> = self.bits()?.to_owned();
+                        value == other.bits
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: VariableList<u16, 8usize> = {
+                            let view = self.values()?;
+                            let items: Result<Vec<_>, _> = view
+                                .iter()
+                                .map(|item_result| {
+                                    item_result
+                                        .map(|item| ssz_types::view::ToOwnedSsz::to_owned(&item))
+                                })
+                                .collect();
+                            let items = items.expect("valid view");
+                            ssz_types::VariableList::new(items).expect("valid view")
+                        };
+                        value == other.values
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: U256 = self.big()?;
+                        value == other.big
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<Beta> for BetaRef<'a> {
+                fn eq(&self, other: &Beta) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<BetaRef<'a>> for Beta {
+                fn eq(&self, other: &BetaRef<'a>) -> bool {
+                    other == self
+                }
+            }
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                ssz_derive::Encode,
+                ssz_derive::Decode
+            )]
+            #[ssz(struct_behaviour = "stable_container", max_fields = 4usize)]
+            pub struct Gamma {
+                pub g: Optional<u8>,
+                pub h: Optional<Alpha>,
+            }
+            impl Default for Gamma {
+                fn default() -> Self {
+                    Self {
+                        g: ssz_types::Optional::None,
+                        h: ssz_types::Optional::None,
+                    }
+                }
+            }
+            impl tree_hash::TreeHash for Gamma {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::StableContainer
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("StableContainer/Profile should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("StableContainer/Profile should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    use ssz_types::BitVector;
+                    let mut active_fields = BitVector::<4usize>::new();
+                    if self.g.is_some() {
+                        active_fields
+                            .set(0usize, true)
+                            .expect("Should not be out of bounds");
+                    }
+                    if self.h.is_some() {
+                        active_fields
+                            .set(1usize, true)
+                            .expect("Should not be out of bounds");
+                    }
+                    let mut field_roots: Vec<<H as tree_hash::TreeHashDigest>::Output> = Vec::with_capacity(
+                        4usize,
+                    );
+                    if let ssz_types::Optional::Some(ref inner) = self.g {
+                        field_roots
+                            .push(
+                                <_ as tree_hash::TreeHash>::tree_hash_root::<H>(inner),
+                            );
+                    }
+                    if let ssz_types::Optional::Some(ref inner) = self.h {
+                        field_roots
+                            .push(
+                                <_ as tree_hash::TreeHash>::tree_hash_root::<H>(inner),
+                            );
+                    }
+                    let hash = tree_hash::merkleize_progressive_with_hasher::<
+                        H,
+                    >(&field_roots);
+                    let active_fields_hash = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                        H,
+                    >(&active_fields);
+                    H::hash32_concat(hash.as_ref(), active_fields_hash.as_ref())
+                }
+            }
+            /// Zero-copy view over [`Gamma`].
+            ///
+            /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
+            /// via lazy getter methods. Use `.to_owned()` to convert to the owned type when
+            /// needed.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                std::marker::Copy
+            )]
+            pub struct GammaRef<'a> {
+                bytes: &'a [u8],
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> GammaRef<'a> {
+                pub fn g(&self) -> Result<Optional<u8>, ssz::DecodeError> {
+                    use ssz::Decode;
+                    let bitvector_bytes = self
+                        .bytes
+                        .get(..1usize)
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
+                        })?;
+                    let bitvector = ssz_types::BitVector::<
+                        4usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    let body = &self.bytes[1usize..];
+                    let field_active: &[bool] = &[
+                        bitvector.get(0usize).unwrap_or(false),
+                        bitvector.get(1usize).unwrap_or(false),
+                    ];
+                    let field_layout: &[ssz::layout::FieldInfo] = &[
+                        (
+                            <Optional<u8> as ssz::Encode>::is_ssz_fixed_len(),
+                            <Optional<u8> as ssz::Encode>::ssz_fixed_len(),
+                        ),
+                        (
+                            <Optional<Alpha> as ssz::Encode>::is_ssz_fixed_len(),
+                            <Optional<Alpha> as ssz::Encode>::ssz_fixed_len(),
+                        ),
+                    ];
+                    let field_bytes = match ssz::layout::read_active_field_bytes(
+                        body,
+                        field_layout,
+                        |i| field_active[i],
+                        0usize,
+                    )? {
+                        Some(bytes) => bytes,
+                        None => return Ok(ssz_types::Optional::None),
+                    };
+                    let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(
+                            field_bytes,
+                        )
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
+                    Ok(ssz_types::Optional::Some(inner))
+                }
+                pub fn h(&self) -> Result<Optional<AlphaRef<'a>>, ssz::DecodeError> {
+                    use ssz::Decode;
+                    let bitvector_bytes = self
+                        .bytes
+                        .get(..1usize)
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
+                        })?;
+                    let bitvector = ssz_types::BitVector::<
+                        4usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    let body = &self.bytes[1usize..];
+                    let field_active: &[bool] = &[
+                        bitvector.get(0usize).unwrap_or(false),
+                        bitvector.get(1usize).unwrap_or(false),
+                    ];
+                    let field_layout: &[ssz::layout::FieldInfo] = &[
+                        (
+                            <Optional<u8> as ssz::Encode>::is_ssz_fixed_len(),
+                            <Optional<u8> as ssz::Encode>::ssz_fixed_len(),
+                        ),
+                        (
+                            <Optional<Alpha> as ssz::Encode>::is_ssz_fixed_len(),
+                            <Optional<Alpha> as ssz::Encode>::ssz_fixed_len(),
+                        ),
+                    ];
+                    let field_bytes = match ssz::layout::read_active_field_bytes(
+                        body,
+                        field_layout,
+                        |i| field_active[i],
+                        1usize,
+                    )? {
+                        Some(bytes) => bytes,
+                        None => return Ok(ssz_types::Optional::None),
+                    };
+                    let inner = <AlphaRef<
+                        'a,
+                    > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
+                    Ok(ssz_types::Optional::Some(inner))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> GammaRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.g()?;
+                    let value = self.h()?;
+                    if let ssz_types::Optional::Some(inner) = value {
+                        inner.validate_deep()?;
+                    }
+                    Ok(())
+                }
+            }
+            impl<'a> tree_hash::TreeHash for GammaRef<'a> {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::StableContainer
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("StableContainer should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("StableContainer should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    use ssz_types::BitVector;
+                    let g = self.g().expect("valid view");
+                    let h = self.h().expect("valid view");
+                    let mut active_fields = BitVector::<4usize>::new();
+                    if g.is_some() {
+                        active_fields
+                            .set(0usize, true)
+                            .expect("Should not be out of bounds");
+                    }
+                    if h.is_some() {
+                        active_fields
+                            .set(1usize, true)
+                            .expect("Should not be out of bounds");
+                    }
+                    let mut field_roots: Vec<<H as tree_hash::TreeHashDigest>::Output> = Vec::with_capacity(
+                        4usize,
+                    );
+                    if let ssz_types::Optional::Some(ref inner) = g {
+                        field_roots
+                            .push(
+                                <_ as tree_hash::TreeHash>::tree_hash_root::<H>(inner),
+                            );
+                    }
+                    if let ssz_types::Optional::Some(ref inner) = h {
+                        field_roots
+                            .push(
+                                <_ as tree_hash::TreeHash>::tree_hash_root::<H>(inner),
+                            );
+                    }
+                    let hash = tree_hash::merkleize_progressive_with_hasher::<
+                        H,
+                    >(&field_roots);
+                    let active_fields_hash = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                        H,
+                    >(&active_fields);
+                    H::hash32_concat(hash.as_ref(), active_fields_hash.as_ref())
+                }
+            }
+            impl<'a> ssz::view::DecodeView<'a> for GammaRef<'a> {
+                fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
+                    use ssz::Decode;
+                    let bitvector_bytes = bytes
+                        .get(..1usize)
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - bytes.len(),
+                        })?;
+                    let bitvector = ssz_types::BitVector::<
+                        4usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    let body = &bytes[1usize..];
+                    let field_active: &[bool] = &[
+                        bitvector.get(0usize).unwrap_or(false),
+                        bitvector.get(1usize).unwrap_or(false),
+                    ];
+                    let field_layout: &[ssz::layout::FieldInfo] = &[
+                        (
+                            <Optional<u8> as ssz::Encode>::is_ssz_fixed_len(),
+                            <Optional<u8> as ssz::Encode>::ssz_fixed_len(),
+                        ),
+                        (
+                            <Optional<Alpha> as ssz::Encode>::is_ssz_fixed_len(),
+                            <Optional<Alpha> as ssz::Encode>::ssz_fixed_len(),
+                        ),
+                    ];
+                    for index in 2usize..4usize {
+                        if bitvector.get(index).unwrap_or(false) {
+                            return Err(
+                                ssz::DecodeError::BytesInvalid(
+                                    "StableContainer has active_fields bits set beyond field count"
+                                        .to_string(),
+                                ),
+                            );
+                        }
+                    }
+                    ssz::layout::validate_active_container(
+                        body,
+                        field_layout,
+                        |i| field_active[i],
+                    )?;
+                    Ok(Self { bytes })
+                }
+            }
+            impl<'a> TryFrom<&'a [u8]> for GammaRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> GammaRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
+            impl<'a> ssz::view::SszTypeInfo for GammaRef<'a> {
+                fn is_ssz_fixed_len() -> bool {
+                    false
+                }
+                fn ssz_fixed_len() -> usize {
+                    0
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ssz_types::view::ToOwnedSsz<Gamma> for GammaRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                fn to_owned(&self) -> Gamma {
+                    <GammaRef<'a>>::to_owned(self)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> GammaRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned(&self) -> Gamma {
+                    Gamma {
+                        g: self.g().expect("valid view"),
+                        h: match self.h().expect("valid view") {
+                            ssz_types::Optional::Some(inner) => {
+                                ssz_types::Optional::Some(
+                                    ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                                )
+                            }
+                            ssz_types::Optional::None => ssz_types::Optional::None,
+                        },
+                    }
+                }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Gamma,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.g = self.g()?;
+                    out.h = match self.h()? {
+                        ssz_types::Optional::Some(inner) => {
+                            ssz_types::Optional::Some(
+                                ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                            )
+                        }
+                        ssz_types::Optional::None => ssz_types::Optional::None,
+                    };
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> GammaRef<'a> {
+                fn eq_owned(&self, other: &Gamma) -> Result<bool, ssz::DecodeError> {
+                    use ssz::Decode;
+                    let bitvector_bytes = self
+                        .bytes
+                        .get(..1usize)
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
+                        })?;
+                    let bitvector = ssz_types::BitVector::<
+                        4usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    if bitvector.get(0usize).unwrap_or(false) != other.g.is_some() {
+                        return Ok(false);
+                    }
+                    if bitvector.get(1usize).unwrap_or(false) != other.h.is_some() {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<u8> = self.g()?;
+                        value == other.g
+                    }) {
+                        return Ok(false);
+                    }
+                    if !(match (self.h()?, &other.h) {
+                        (
+                            ssz_types::Optional::Some(view),
+                            ssz_types::Optional::Some(owned),
+                        ) => view == *owned,
+                        (ssz_types::Optional::None, ssz_types::Optional::None) => true,
+                        _ => false,
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<Gamma> for GammaRef<'a> {
+                fn eq(&self, other: &Gamma) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<GammaRef<'a>> for Gamma {
+                fn eq(&self, other: &GammaRef<'a>) -> bool {
+                    other == self
+                }
+            }
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                ssz_derive::Encode,
+                ssz_derive::Decode
+            )]
+            #[ssz(struct_behaviour = "profile")]
+            pub struct GammaProfile {
+                pub g: u8,
+                pub h: Optional<Alpha>,
+            }
+            impl Default for GammaProfile {
+                fn default() -> Self {
+                    Self {
+                        g: Default::default(),
+                        h: ssz_types::Optional::None,
+                    }
+                }
+            }
+            impl tree_hash::TreeHash for GammaProfile {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::StableContainer
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("StableContainer/Profile should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("StableContainer/Profile should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    use ssz_types::BitVector;
+                    let mut active_fields = BitVector::<4usize>::new();
+                    active_fields
+                        .set(0usize, true)
+                        .expect("Should not be out of bounds");
+                    if self.h.is_some() {
+                        active_fields
+                            .set(1usize, true)
+                            .expect("Should not be out of bounds");
+                    }
+                    let mut field_roots: Vec<<H as tree_hash::TreeHashDigest>::Output> = Vec::with_capacity(
+                        4usize,
+                    );
+                    field_roots
+                        .push(<_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.g));
+                    if let ssz_types::Optional::Some(ref inner) = self.h {
+                        field_roots
+                            .push(
+                                <_ as tree_hash::TreeHash>::tree_hash_root::<H>(inner),
+                            );
+                    }
+                    let hash = tree_hash::merkleize_progressive_with_hasher::<
+                        H,
+                    >(&field_roots);
+                    let active_fields_hash = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                        H,
+                    >(&active_fields);
+                    H::hash32_concat(hash.as_ref(), active_fields_hash.as_ref())
+                }
+            }
+            /// Zero-copy view over [`GammaProfile`].
+            ///
+            /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
+            /// via lazy getter methods. Use `.to_owned()` to convert to the owned type when
+            /// needed.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                std::marker::Copy
+            )]
+            pub struct GammaProfileRef<'a> {
+                bytes: &'a [u8],
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> GammaProfileRef<'a> {
+                pub fn g(&self) -> Result<u8, ssz::DecodeError> {
+                    use ssz::Decode;
+                    let bitvector_bytes = self
+                        .bytes
+                        .get(..1usize)
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
+                        })?;
+                    let bitvector = ssz_types::BitVector::<
+                        1usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    let body = &self.bytes[1usize..];
+                    let field_active: &[bool] = &[
+                        true,
+                        bitvector.get(0usize).unwrap_or(false),
+                    ];
+                    let field_layout: &[ssz::layout::FieldInfo] = &[
+                        (
+                            <u8 as ssz::Encode>::is_ssz_fixed_len(),
+                            <u8 as ssz::Encode>::ssz_fixed_len(),
+                        ),
+                        (
+                            <Optional<Alpha> as ssz::Encode>::is_ssz_fixed_len(),
+                            <Optional<Alpha> as ssz::Encode>::ssz_fixed_len(),
+                        ),
+                    ];
+                    let field_bytes = match ssz::layout::read_active_field_bytes(
+                        body,
+                        field_layout,
+                        |i| field_active[i],
+                        0usize,
+                    )? {
+                        Some(bytes) => bytes,
+                        None => unreachable!("required field is always active"),
+                    };
+                    ssz::view::DecodeView::from_ssz_bytes(field_bytes)
+                        .map_err(|e| e.bounded(field_bytes.len()))
+                }
+                pub fn h(&self) -> Result<Optional<AlphaRef<'a>>, ssz::DecodeError> {
+                    use ssz::Decode;
+                    let bitvector_bytes = self
+                        .bytes
+                        .get(..1usize)
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
+                        })?;
+                    let bitvector = ssz_types::BitVector::<
+                        1usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    let body = &self.bytes[1usize..];
+                    let field_active: &[bool] = &[
+                        true,
+                        bitvector.get(0usize).unwrap_or(false),
+                    ];
+                    let field_layout: &[ssz::layout::FieldInfo] = &[
+                        (
+                            <u8 as ssz::Encode>::is_ssz_fixed_len(),
+                            <u8 as ssz::Encode>::ssz_fixed_len(),
+                        ),
+                        (
+                            <Optional<Alpha> as ssz::Encode>::is_ssz_fixed_len(),
+                            <Optional<Alpha> as ssz::Encode>::ssz_fixed_len(),
+                        ),
+                    ];
+                    let field_bytes = match ssz::layout::read_active_field_bytes(
+                        body,
+                        field_layout,
+                        |i| field_active[i],
+                        1usize,
+                    )? {
+                        Some(bytes) => bytes,
+                        None => return Ok(ssz_types::Optional::None),
+                    };
+                    let inner = <AlphaRef<
+                        'a,
+                    > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
+                    Ok(ssz_types::Optional::Some(inner))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> GammaProfileRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.g()?;
+                    let value = self.h()?;
+                    if let ssz_types::Optional::Some(inner) = value {
+                        inner.validate_deep()?;
+                    }
+                    Ok(())
+                }
+            }
+            impl<'a> tree_hash::TreeHash for GammaProfileRef<'a> {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::Container
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Profile should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Profile should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    use ssz_types::BitVector;
+                    let g = self.g().expect("valid view");
+                    let h = self.h().expect("valid view");
+                    let mut active_fields = BitVector::<4usize>::new();
+                    active_fields
+                        .set(0usize, true)
+                        .expect("Should not be out of bounds");
+                    if h.is_some() {
+                        active_fields
+                            .set(1usize, true)
+                            .expect("Should not be out of bounds");
+                    }
+                    let mut field_roots: Vec<<H as tree_hash::TreeHashDigest>::Output> = Vec::with_capacity(
+                        4usize,
+                    );
+                    field_roots
+                        .push(<_ as tree_hash::TreeHash>::tree_hash_root::<H>(&g));
+                    if let ssz_types::Optional::Some(ref inner) = h {
+                        field_roots
+                            .push(
+                                <_ as tree_hash::TreeHash>::tree_hash_root::<H>(inner),
+                            );
+                    }
+                    let hash = tree_hash::merkleize_progressive_with_hasher::<
+                        H,
+                    >(&field_roots);
+                    let active_fields_hash = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                        H,
+                    >(&active_fields);
+                    H::hash32_concat(hash.as_ref(), active_fields_hash.as_ref())
+                }
+            }
+            impl<'a> ssz::view::DecodeView<'a> for GammaProfileRef<'a> {
+                fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
+                    use ssz::Decode;
+                    let bitvector_bytes = bytes
+                        .get(..1usize)
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - bytes.len(),
+                        })?;
+                    let bitvector = ssz_types::BitVector::<
+                        1usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    let body = &bytes[1usize..];
+                    let field_active: &[bool] = &[
+                        true,
+                        bitvector.get(0usize).unwrap_or(false),
+                    ];
+                    let field_layout: &[ssz::layout::FieldInfo] = &[
+                        (
+                            <u8 as ssz::Encode>::is_ssz_fixed_len(),
+                            <u8 as ssz::Encode>::ssz_fixed_len(),
+                        ),
+                        (
+                            <Optional<Alpha> as ssz::Encode>::is_ssz_fixed_len(),
+                            <Optional<Alpha> as ssz::Encode>::ssz_fixed_len(),
+                        ),
+                    ];
+                    ssz::layout::validate_active_container(
+                        body,
+                        field_layout,
+                        |i| field_active[i],
+                    )?;
+                    Ok(Self { bytes })
+                }
+            }
+            impl<'a> TryFrom<&'a [u8]> for GammaProfileRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> GammaProfileRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
+            impl<'a> ssz::view::SszTypeInfo for GammaProfileRef<'a> {
+                fn is_ssz_fixed_len() -> bool {
+                    false
+                }
+                fn ssz_fixed_len() -> usize {
+                    0
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ssz_types::view::ToOwnedSsz<GammaProfile> for GammaProfileRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                fn to_owned(&self) -> GammaProfile {
+                    <GammaProfileRef<'a>>::to_owned(self)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> GammaProfileRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned(&self) -> GammaProfile {
+                    GammaProfile {
+                        g: self.g().expect("valid view"),
+                        h: match self.h().expect("valid view") {
+                            ssz_types::Optional::Some(inner) => {
+                                ssz_types::Optional::Some(
+                                    ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                                )
+                            }
+                            ssz_types::Optional::None => ssz_types::Optional::None,
+                        },
+                    }
+                }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut GammaProfile,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.g = self.g()?;
+                    out.h = match self.h()? {
+                        ssz_types::Optional::Some(inner) => {
+                            ssz_types::Optional::Some(
+                                ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                            )
+                        }
+                        ssz_types::Optional::None => ssz_types::Optional::None,
+                    };
+                    Ok(())
+                }
+            }
+            impl<'a> TryFrom<GammaRef<'a>> for GammaProfile {
+                type Error = ssz::DecodeError;
+                fn try_from(value: GammaRef<'a>) -> Result<Self, Self::Error> {
+                    use ssz::Decode;
+                    let bitvector_bytes = value
+                        .bytes
+                        .get(..1usize)
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - value.bytes.len(),
+                        })?;
+                    let bitvector = ssz_types::BitVector::<
+                        4usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    if !bitvector.get(0usize).unwrap_or(false) {
+                        return Err(
+                            ssz::DecodeError::BytesInvalid(
+                                "field `g` is required by profile `GammaProfile` but inactive"
+                                    .to_string(),
+                            ),
+                        );
+                    }
+                    Ok(Self {
+                        g: value.g()?.unwrap(),
+                        h: match value.h()? {
+                            ssz_types::Optional::Some(inner) => {
+                                ssz_types::Optional::Some(
+                                    ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                                )
+                            }
+                            ssz_types::Optional::None => ssz_types::Optional::None,
+                        },
+                    })
+                }
+            }
+            impl TryFrom<Gamma> for GammaProfile {
+                type Error = ssz::DecodeError;
+                fn try_from(value: Gamma) -> Result<Self, Self::Error> {
+                    Ok(Self {
+                        g: match value.g {
+                            ssz_types::Optional::Some(inner) => inner,
+                            ssz_types::Optional::None => {
+                                return Err(
+                                    ssz::DecodeError::BytesInvalid(
+                                        "field `g` is required by profile `GammaProfile` but inactive"
+                                            .to_string(),
+                                    ),
+                                );
+                            }
+                        },
+                        h: value.h,
+                    })
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl GammaProfile {
+                /// Converts this profile to its base StableContainer.
+                pub fn to_stable(&self) -> Gamma {
+                    Gamma {
+                        g: ssz_types::Optional::Some(self.g),
+                        h: self.h.clone(),
+                    }
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> GammaProfileRef<'a> {
+                /// Converts this profile view to its owned base StableContainer.
+                pub fn to_stable(&self) -> Gamma {
+                    self.to_owned().to_stable()
+                }
+            }
+            impl From<GammaProfile> for Gamma {
+                fn from(value: GammaProfile) -> Self {
+                    value.to_stable()
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> GammaProfileRef<'a> {
+                fn eq_owned(
+                    &self,
+                    other: &GammaProfile,
+                ) -> Result<bool, ssz::DecodeError> {
+                    use ssz::Decode;
+                    let bitvector_bytes = self
+                        .bytes
+                        .get(..1usize)
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
+                        })?;
+                    let bitvector = ssz_types::BitVector::<
+                        1usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    if bitvector.get(0usize).unwrap_or(false) != other.h.is_some() {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: u8 = self.g()?;
+                        value == other.g
+                    }) {
+                        return Ok(false);
+                    }
+                    if !(match (self.h()?, &other.h) {
+                        (
+                            ssz_types::Optional::Some(view),
+                            ssz_types::Optional::Some(owned),
+                        ) => view == *owned,
+                        (ssz_types::Optional::None, ssz_types::Optional::None) => true,
+                        _ => false,
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<GammaProfile> for GammaProfileRef<'a> {
+                fn eq(&self, other: &GammaProfile) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<GammaProfileRef<'a>> for GammaProfile {
+                fn eq(&self, other: &GammaProfileRef<'a>) -> bool {
+                    other == self
+                }
+            }
+            /// Unions have no default, so neither this nor `Outer` implements `Default`.
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                ssz_derive::Encode,
+                ssz_derive::Decode
+            )]
+            #[ssz(struct_behaviour = "container")]
+            pub struct WithUnion {
+                pub choice: Choice,
+            }
+            impl tree_hash::TreeHash for WithUnion {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::Container
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(1usize);
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.choice)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .finish()
+                        .expect("tree hash derive should not have a remaining buffer")
+                }
+            }
+            impl tree_hash::TreeHashLeaves for WithUnion {
+                fn tree_hash_leaf_count() -> usize {
+                    1usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.choice)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 1usize
+                            )
+                        }
+                    }
+                }
+            }
+            /// Zero-copy view over [`WithUnion`].
+            ///
+            /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
+            /// via lazy getter methods. Use `.to_owned()` to convert to the owned type when
+            /// needed.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                std::marker::Copy
+            )]
+            pub struct WithUnionRef<'a> {
+                bytes: &'a [u8],
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> WithUnionRef<'a> {
+                pub fn choice(&self) -> Result<ChoiceRef<'a>, ssz::DecodeError> {
+                    let bytes = ssz::layout::read_field_bytes(
+                        self.bytes,
+                        &[
+                            (
+                                <Choice as ssz::Encode>::is_ssz_fixed_len(),
+                                <Choice as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                        0usize,
+                    )?;
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> WithUnionRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    let value = self.choice()?;
+                    value.validate_deep()?;
+                    Ok(())
+                }
+            }
+            impl<'a> tree_hash::TreeHash for WithUnionRef<'a> {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::StableContainer
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(1usize);
+                    {
+                        let choice = self.choice().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&choice);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    hasher.finish().expect("finish hasher")
+                }
+            }
+            impl<'a> ssz::view::DecodeView<'a> for WithUnionRef<'a> {
+                fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
+                    ssz::layout::validate_container(
+                        bytes,
+                        &[
+                            (
+                                <Choice as ssz::Encode>::is_ssz_fixed_len(),
+                                <Choice as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                    )?;
+                    Ok(Self { bytes })
+                }
+            }
+            impl<'a> TryFrom<&'a [u8]> for WithUnionRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> WithUnionRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
+            impl<'a> ssz::view::SszTypeInfo for WithUnionRef<'a> {
+                fn is_ssz_fixed_len() -> bool {
+                    usize::from(!<Choice as ssz::Encode>::is_ssz_fixed_len()) == 0
+                }
+                fn ssz_fixed_len() -> usize {
+                    if <Self as ssz::view::SszTypeInfo>::is_ssz_fixed_len() {
+                        <Choice as ssz::Encode>::ssz_fixed_len()
+                    } else {
+                        0
+                    }
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ssz_types::view::ToOwnedSsz<WithUnion> for WithUnionRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                fn to_owned(&self) -> WithUnion {
+                    <WithUnionRef<'a>>::to_owned(self)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> WithUnionRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned(&self) -> WithUnion {
+                    WithUnion {
+                        choice: {
+                            let view = self.choice().expect("valid view");
+                            ssz_types::view::ToOwnedSsz::to_owned(&view)
+                        },
+                    }
+                }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut WithUnion,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.choice = {
+                        let view = self.choice()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> WithUnionRef<'a> {
+                fn eq_owned(&self, other: &WithUnion) -> Result<bool, ssz::DecodeError> {
+                    if !({
+                        let value: Choice = {
+                            let view = self.choice()?;
+                            ssz_types::view::ToOwnedSsz::to_owned(&view)
+                        };
+                        value == other.choice
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<WithUnion> for WithUnionRef<'a> {
+                fn eq(&self, other: &WithUnion) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<WithUnionRef<'a>> for WithUnion {
+                fn eq(&self, other: &WithUnionRef<'a>) -> bool {
+                    other == self
+                }
+            }
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                ssz_derive::Encode,
+                ssz_derive::Decode
+            )]
+            #[ssz(struct_behaviour = "container")]
+            pub struct Outer {
+                pub inner: WithUnion,
+            }
+            impl tree_hash::TreeHash for Outer {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::Container
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(1usize);
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.inner)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .finish()
+                        .expect("tree hash derive should not have a remaining buffer")
+                }
+            }
+            impl tree_hash::TreeHashLeaves for Outer {
+                fn tree_hash_leaf_count() -> usize {
+                    1usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.inner)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 1usize
+                            )
+                        }
+                    }
+                }
+            }
+            /// Zero-copy view over [`Outer`].
+            ///
+            /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
+            /// via lazy getter methods. Use `.to_owned()` to convert to the owned type when
+            /// needed.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                std::marker::Copy
+            )]
+            pub struct OuterRef<'a> {
+                bytes: &'a [u8],
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> OuterRef<'a> {
+                pub fn inner(&self) -> Result<WithUnionRef<'a>, ssz::DecodeError> {
+                    let bytes = ssz::layout::read_field_bytes(
+                        self.bytes,
+                        &[
+                            (
+                                <WithUnion as ssz::Encode>::is_ssz_fixed_len(),
+                                <WithUnion as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                        0usize,
+                    )?;
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> OuterRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    let value = self.inner()?;
+                    value.validate_deep()?;
+                    Ok(())
+                }
+            }
+            impl<'a> tree_hash::TreeHash for OuterRef<'a> {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::StableContainer
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(1usize);
+                    {
+                        let inner = self.inner().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&inner);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    hasher.finish().expect("finish hasher")
+                }
+            }
+            impl<'a> ssz::view::DecodeView<'a> for OuterRef<'a> {
+                fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
+                    ssz::layout::validate_container(
+                        bytes,
+                        &[
+                            (
+                                <WithUnion as ssz::Encode>::is_ssz_fixed_len(),
+                                <WithUnion as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                    )?;
+                    Ok(Self { bytes })
+                }
+            }
+            impl<'a> TryFrom<&'a [u8]> for OuterRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> OuterRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
+            impl<'a> ssz::view::SszTypeInfo for OuterRef<'a> {
+                fn is_ssz_fixed_len() -> bool {
+                    usize::from(!<WithUnion as ssz::Encode>::is_ssz_fixed_len()) == 0
+                }
+                fn ssz_fixed_len() -> usize {
+                    if <Self as ssz::view::SszTypeInfo>::is_ssz_fixed_len() {
+                        <WithUnion as ssz::Encode>::ssz_fixed_len()
+                    } else {
+                        0
+                    }
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ssz_types::view::ToOwnedSsz<Outer> for OuterRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                fn to_owned(&self) -> Outer {
+                    <OuterRef<'a>>::to_owned(self)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> OuterRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned(&self) -> Outer {
+                    Outer {
+                        inner: {
+                            let view = self.inner().expect("valid view");
+                            ssz_types::view::ToOwnedSsz::to_owned(&view)
+                        },
+                    }
+                }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Outer,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.inner = {
+                        let view = self.inner()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> OuterRef<'a> {
+                fn eq_owned(&self, other: &Outer) -> Result<bool, ssz::DecodeError> {
+                    if !(self.inner()? == other.inner) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<Outer> for OuterRef<'a> {
+                fn eq(&self, other: &Outer) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<OuterRef<'a>> for Outer {
+                fn eq(&self, other: &OuterRef<'a>) -> bool {
+                    other == self
+                }
+            }
+        }
+    }
+}
@@ -11,6 +11,12 @@ pub mod tests {
             use tree_hash::TreeHashDigest;
             use tree_hash_derive::TreeHash;
             use ssz::view::*;
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                71, 245, 149, 164, 58, 245, 112, 220, 202, 16, 235, 68, 187, 143, 79, 35,
+                102, 107, 30, 45, 26, 144, 245, 150, 61, 160, 114, 3, 116, 87, 230, 24,
+            ];
             #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
             #[ssz(enum_behaviour = "union")]
             pub enum AliasOptionUnion {
@@ -1314,6 +1320,24 @@ pub mod tests {
                         ),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Alpha,
+                ) -> Result<(), ssz::DecodeError> {
+                    out.a = self.a()?;
+                    out.b = self.b()?;
+                    out.c = ssz_types::FixedBytes(self.c()?.to_owned());
+                    Ok(())
+                }
             }
             #[derive(Debug, std::clone::Clone, ssz_derive::Encode, ssz_derive::Decode)]
             #[ssz(struct_behaviour = "container")]
@@ -1537,6 +1561,26 @@ pub mod tests {
                         f: self.f().expect("valid view"),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Beta,
+                ) -> Result<(), ssz::DecodeError> {
+                    let view = self.d()?;
+                    out.d.clear();
+                    out.d.try_extend_from_slice(view.as_bytes()).expect("valid view");
+                    out.e = self.e()?;
+                    out.f = self.f()?;
+                    Ok(())
+                }
             }
             #[derive(Debug, std::clone::Clone, ssz_derive::Encode, ssz_derive::Decode)]
             #[ssz(struct_behaviour = "stable_container", max_fields = 42usize)]
@@ -1835,6 +1879,30 @@ pub mod tests {
                         },
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Gamma,
+                ) -> Result<(), ssz::DecodeError> {
+                    out.g = self.g()?;
+                    out.h = match self.h()? {
+                        ssz_types::Optional::Some(inner) => {
+                            ssz_types::Optional::Some(
+                                ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                            )
+                        }
+                        ssz_types::Optional::None => ssz_types::Optional::None,
+                    };
+                    Ok(())
+                }
             }
             #[derive(Debug, std::clone::Clone, ssz_derive::Encode, ssz_derive::Decode)]
             #[ssz(struct_behaviour = "container")]
@@ -2003,6 +2071,23 @@ pub mod tests {
                         w: self.w().expect("valid view"),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Delta,
+                ) -> Result<(), ssz::DecodeError> {
+                    out.z = self.z()?;
+                    out.w = self.w()?;
+                    Ok(())
+                }
             }
             #[derive(Debug, std::clone::Clone, ssz_derive::Encode, ssz_derive::Decode)]
             #[ssz(struct_behaviour = "stable_container", max_fields = 42usize)]
@@ -2491,6 +2576,32 @@ pub mod tests {
                         j: self.j().expect("valid view"),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Epsilon,
+                ) -> Result<(), ssz::DecodeError> {
+                    out.g = self.g()?;
+                    out.h = match self.h()? {
+                        ssz_types::Optional::Some(inner) => {
+                            ssz_types::Optional::Some(
+                                ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                            )
+                        }
+                        ssz_types::Optional::None => ssz_types::Optional::None,
+                    };
+                    out.i = self.i()?;
+                    out.j = self.j()?;
+                    Ok(())
+                }
             }
             #[derive(Debug, std::clone::Clone, ssz_derive::Encode, ssz_derive::Decode)]
             #[ssz(struct_behaviour = "stable_container", max_fields = 128usize)]
@@ -2804,6 +2915,37 @@ pub mod tests {
                         },
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Zeta,
+                ) -> Result<(), ssz::DecodeError> {
+                    out.u = match self.u()? {
+                        ssz_types::Optional::Some(inner) => {
+                            ssz_types::Optional::Some(
+                                ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                            )
+                        }
+                        ssz_types::Optional::None => ssz_types::Optional::None,
+                    };
+                    out.v = match self.v()? {
+                        ssz_types::Optional::Some(inner) => {
+                            ssz_types::Optional::Some(
+                                ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                            )
+                        }
+                        ssz_types::Optional::None => ssz_types::Optional::None,
+                    };
+                    Ok(())
+                }
             }
             #[derive(Debug, std::clone::Clone, ssz_derive::Encode, ssz_derive::Decode)]
             #[ssz(struct_behaviour = "container")]
@@ -3183,6 +3325,32 @@ pub mod tests {
                         large_int_256: self.large_int_256().expect("valid view"),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut TestType,
+                ) -> Result<(), ssz::DecodeError> {
+                    out.ccc = self.ccc()?;
+                    out.ddd = self.ddd()?;
+                    let view = self.eee()?;
+                    out.eee.clear();
+                    for item in view.iter() {
+                        out.eee
+                            .push(ssz_types::view::ToOwnedSsz::to_owned(&item?))
+                            .expect("valid view");
+                    }
+                    out.large_int_128 = self.large_int_128()?;
+                    out.large_int_256 = self.large_int_256()?;
+                    Ok(())
+                }
             }
             #[derive(Debug, std::clone::Clone, ssz_derive::Encode, ssz_derive::Decode)]
             #[ssz(struct_behaviour = "container")]
@@ -3411,6 +3579,33 @@ pub mod tests {
                         },
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Eta,
+                ) -> Result<(), ssz::DecodeError> {
+                    out.l = {
+                        let view = self.l()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    out.m = {
+                        let view = self.m()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    out.n = {
+                        let view = self.n()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    Ok(())
+                }
             }
             #[derive(Debug, std::clone::Clone, ssz_derive::Encode, ssz_derive::Decode)]
             #[ssz(struct_behaviour = "container")]
@@ -3638,6 +3833,30 @@ pub mod tests {
                         ),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Theta,
+                ) -> Result<(), ssz::DecodeError> {
+                    out.o = {
+                        let view = self.o()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    out.p = {
+                        let view = self.p()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    out.q = ssz_types::FixedBytes(self.q()?.to_owned());
+                    Ok(())
+                }
             }
             #[derive(Debug, std::clone::Clone, ssz_derive::Encode, ssz_derive::Decode)]
             #[ssz(struct_behaviour = "stable_container", max_fields = 42usize)]
@@ -4395,6 +4614,41 @@ pub mod tests {
                         s: self.s().expect("valid view"),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Iota,
+                ) -> Result<(), ssz::DecodeError> {
+                    out.g = self.g()?;
+                    out.h = match self.h()? {
+                        ssz_types::Optional::Some(inner) => {
+                            ssz_types::Optional::Some(
+                                ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                            )
+                        }
+                        ssz_types::Optional::None => ssz_types::Optional::None,
+                    };
+                    out.i = self.i()?;
+                    out.j = self.j()?;
+                    out.r = match self.r()? {
+                        ssz_types::Optional::Some(inner) => {
+                            ssz_types::Optional::Some(
+                                ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                            )
+                        }
+                        ssz_types::Optional::None => ssz_types::Optional::None,
+                    };
+                    out.s = self.s()?;
+                    Ok(())
+                }
             }
             #[derive(Debug, std::clone::Clone, ssz_derive::Encode, ssz_derive::Decode)]
             #[ssz(struct_behaviour = "container")]
@@ -4621,6 +4875,30 @@ pub mod tests {
                         v: self.v().expect("valid view").to_owned(),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Kappa,
+                ) -> Result<(), ssz::DecodeError> {
+                    out.t = {
+                        let view = self.t()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    out.u = {
+                        let view = self.u()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    out.v = self.v()?.to_owned();
+                    Ok(())
+                }
             }
             #[derive(Debug, std::clone::Clone, ssz_derive::Encode, ssz_derive::Decode)]
             #[ssz(struct_behaviour = "stable_container", max_fields = 4usize)]
@@ -4896,6 +5174,23 @@ pub mod tests {
                         x: self.x().expect("valid view"),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Lambda,
+                ) -> Result<(), ssz::DecodeError> {
+                    out.w = self.w()?;
+                    out.x = self.x()?;
+                    Ok(())
+                }
             }
             #[derive(Debug, std::clone::Clone, ssz_derive::Encode, ssz_derive::Decode)]
             #[ssz(struct_behaviour = "container")]
@@ -5070,6 +5365,29 @@ pub mod tests {
                         },
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Mu,
+                ) -> Result<(), ssz::DecodeError> {
+                    out.y = {
+                        let view = self.y()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    out.z = {
+                        let view = self.z()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    Ok(())
+                }
             }
             pub type AliasMu = Mu;
             #[derive(Debug, std::clone::Clone, ssz_derive::Encode, ssz_derive::Decode)]
@@ -5246,12 +5564,7 @@ pub mod tests {
                         ],
                         3usize,
                     )?;
-                    if bytes.is_empty() {
-                        return Err(ssz::DecodeError::InvalidByteLength {
-                            len: 0,
-                            expected: 1,
-                        });
-                    }
+                    ssz::view::reject_empty(bytes, 1)?;
                     let selector = bytes[0];
                     match selector {
                         0 => Ok(None),
@@ -5402,6 +5715,33 @@ pub mod tests {
                             .map(|inner| ssz_types::view::ToOwnedSsz::to_owned(&inner)),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Nu,
+                ) -> Result<(), ssz::DecodeError> {
+                    out.zz = {
+                        let view = self.zz()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    let view = self.aaa()?;
+                    for (slot, item) in out.aaa.iter_mut().zip(view.iter()) {
+                        *slot = ssz_types::view::ToOwnedSsz::to_owned(&item?);
+                    }
+                    out.bbb = self.bbb()?.to_owned();
+                    out.test = self
+                        .test()?
+                        .map(|inner| ssz_types::view::ToOwnedSsz::to_owned(&inner));
+                    Ok(())
+                }
             }
         }
     }
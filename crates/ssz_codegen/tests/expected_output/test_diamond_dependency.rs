@@ -11,6 +11,12 @@ pub mod tests {
             use tree_hash::TreeHashDigest;
             use tree_hash_derive::TreeHash;
             use ssz::view::*;
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                132, 234, 70, 168, 99, 242, 115, 225, 21, 138, 28, 46, 183, 5, 184, 53,
+                138, 15, 217, 234, 255, 252, 5, 65, 101, 68, 59, 226, 205, 142, 188, 102,
+            ];
             /// Type A that imports base
             #[derive(
                 std::clone::Clone,
@@ -201,6 +207,26 @@ pub mod tests {
                         data: self.data().expect("valid view"),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut TypeA,
+                ) -> Result<(), ssz::DecodeError> {
+                    out.base = {
+                        let view = self.base()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    out.data = self.data()?;
+                    Ok(())
+                }
             }
         }
         pub mod test_multi_import_b {
@@ -212,6 +238,12 @@ pub mod tests {
             use tree_hash::TreeHashDigest;
             use tree_hash_derive::TreeHash;
             use ssz::view::*;
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                149, 138, 110, 20, 75, 212, 60, 22, 16, 63, 157, 81, 54, 80, 238, 78,
+                231, 248, 67, 27, 230, 221, 173, 73, 219, 61, 38, 162, 222, 29, 111, 139,
+            ];
             /// Type B that imports both base and a
             #[derive(
                 std::clone::Clone,
@@ -462,6 +494,30 @@ pub mod tests {
                         extra: self.extra().expect("valid view"),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut TypeB,
+                ) -> Result<(), ssz::DecodeError> {
+                    out.base = {
+                        let view = self.base()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    out.type_a = {
+                        let view = self.type_a()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    out.extra = self.extra()?;
+                    Ok(())
+                }
             }
         }
         pub mod test_multi_import_base {
@@ -473,6 +529,12 @@ pub mod tests {
             use tree_hash::TreeHashDigest;
             use tree_hash_derive::TreeHash;
             use ssz::view::*;
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                244, 22, 98, 140, 127, 233, 232, 187, 99, 97, 239, 50, 12, 221, 43, 178,
+                24, 241, 9, 255, 161, 228, 10, 129, 8, 187, 81, 188, 130, 138, 51, 66,
+            ];
             /// Base type shared by multiple importers
             #[derive(
                 std::clone::Clone,
@@ -612,6 +674,22 @@ pub mod tests {
                         value: self.value().expect("valid view"),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut BaseType,
+                ) -> Result<(), ssz::DecodeError> {
+                    out.value = self.value()?;
+                    Ok(())
+                }
             }
         }
     }
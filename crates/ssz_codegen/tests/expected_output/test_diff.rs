@@ -0,0 +1,762 @@
+pub mod tests {
+    #![allow(unused_imports, reason = "generated code using ssz-gen")]
+    pub mod input {
+        #![allow(unused_imports, reason = "generated code using ssz-gen")]
+        pub mod test_diff {
+            #![allow(unused_imports, reason = "generated code using ssz-gen")]
+            use ssz_types::*;
+            use ssz_types::view::{FixedVectorRef, VariableListRef};
+            use ssz_primitives::{U128, U256};
+            use ssz_derive::{Encode, Decode};
+            use tree_hash::TreeHashDigest;
+            use tree_hash_derive::TreeHash;
+            use ssz::view::*;
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                53, 163, 128, 170, 49, 12, 92, 245, 178, 242, 73, 255, 236, 121, 1, 151,
+                212, 3, 33, 162, 154, 202, 253, 172, 65, 185, 216, 249, 226, 242, 116,
+                159,
+            ];
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                ssz_derive::Encode,
+                ssz_derive::Decode
+            )]
+            #[ssz(struct_behaviour = "container")]
+            pub struct Alpha {
+                pub a: u8,
+                pub b: VariableList<u8, 8usize>,
+                pub c: FixedVector<u16, 2usize>,
+            }
+            impl Alpha {
+                /// Returns the name and the `Debug` output of both values of each field that
+                /// differs between `self` and `other`, in declaration order.
+                #[allow(unused_mut, reason = "generated code using ssz-gen")]
+                pub fn diff(&self, other: &Self) -> Vec<ssz_types::FieldDiff> {
+                    let mut diffs = Vec::new();
+                    if self.a != other.a {
+                        diffs
+                            .push((
+                                "a",
+                                format!("{:?}", self.a),
+                                format!("{:?}", other.a),
+                            ));
+                    }
+                    if self.b != other.b {
+                        diffs
+                            .push((
+                                "b",
+                                format!("{:?}", self.b),
+                                format!("{:?}", other.b),
+                            ));
+                    }
+                    if self.c != other.c {
+                        diffs
+                            .push((
+                                "c",
+                                format!("{:?}", self.c),
+                                format!("{:?}", other.c),
+                            ));
+                    }
+                    diffs
+                }
+            }
+            impl tree_hash::TreeHash for Alpha {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::Container
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(3usize);
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.a)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.b)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.c)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .finish()
+                        .expect("tree hash derive should not have a remaining buffer")
+                }
+            }
+            impl tree_hash::TreeHashLeaves for Alpha {
+                fn tree_hash_leaf_count() -> usize {
+                    3usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.a)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.b)
+                        }
+                        2usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.c)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 3usize
+                            )
+                        }
+                    }
+                }
+            }
+            /// Zero-copy view over [`Alpha`].
+            ///
+            /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
+            /// via lazy getter methods. Use `.to_owned()` to convert to the owned type when
+            /// needed.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                std::marker::Copy
+            )]
+            pub struct AlphaRef<'a> {
+                bytes: &'a [u8],
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> AlphaRef<'a> {
+                pub fn a(&self) -> Result<u8, ssz::DecodeError> {
+                    let bytes = ssz::layout::read_field_bytes(
+                        self.bytes,
+                        &[
+                            (
+                                <u8 as ssz::Encode>::is_ssz_fixed_len(),
+                                <u8 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <VariableList<
+                                    u8,
+                                    8usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <VariableList<u8, 8usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <FixedVector<
+                                    u16,
+                                    2usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <FixedVector<u16, 2usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                        0usize,
+                    )?;
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+                pub fn b(&self) -> Result<BytesRef<'a, 8usize>, ssz::DecodeError> {
+                    let bytes = ssz::layout::read_field_bytes(
+                        self.bytes,
+                        &[
+                            (
+                                <u8 as ssz::Encode>::is_ssz_fixed_len(),
+                                <u8 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <VariableList<
+                                    u8,
+                                    8usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <VariableList<u8, 8usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <FixedVector<
+                                    u16,
+                                    2usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <FixedVector<u16, 2usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                        1usize,
+                    )?;
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+                pub fn c(
+                    &self,
+                ) -> Result<FixedVectorRef<'a, u16, 2usize>, ssz::DecodeError> {
+                    let bytes = ssz::layout::read_field_bytes(
+                        self.bytes,
+                        &[
+                            (
+                                <u8 as ssz::Encode>::is_ssz_fixed_len(),
+                                <u8 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <VariableList<
+                                    u8,
+                                    8usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <VariableList<u8, 8usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <FixedVector<
+                                    u16,
+                                    2usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <FixedVector<u16, 2usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                        2usize,
+                    )?;
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> AlphaRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.a()?;
+                    self.b()?;
+                    let value = self.c()?;
+                    for item in value.iter() {
+                        item?;
+                    }
+                    Ok(())
+                }
+            }
+            impl<'a> tree_hash::TreeHash for AlphaRef<'a> {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::StableContainer
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(3usize);
+                    {
+                        let a = self.a().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&a);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    {
+                        let b = self.b().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&b);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    {
+                        let c = self.c().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&c);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    hasher.finish().expect("finish hasher")
+                }
+            }
+            impl<'a> ssz::view::DecodeView<'a> for AlphaRef<'a> {
+                fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
+                    ssz::layout::validate_container(
+                        bytes,
+                        &[
+                            (
+                                <u8 as ssz::Encode>::is_ssz_fixed_len(),
+                                <u8 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <VariableList<
+                                    u8,
+                                    8usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <VariableList<u8, 8usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <FixedVector<
+                                    u16,
+                                    2usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <FixedVector<u16, 2usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                    )?;
+                    Ok(Self { bytes })
+                }
+            }
+            impl<'a> TryFrom<&'a [u8]> for AlphaRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> AlphaRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
+            impl<'a> ssz::view::SszTypeInfo for AlphaRef<'a> {
+                fn is_ssz_fixed_len() -> bool {
+                    usize::from(!<u8 as ssz::Encode>::is_ssz_fixed_len())
+                        + usize::from(
+                            !<VariableList<
+                                u8,
+                                8usize,
+                            > as ssz::Encode>::is_ssz_fixed_len(),
+                        )
+                        + usize::from(
+                            !<FixedVector<
+                                u16,
+                                2usize,
+                            > as ssz::Encode>::is_ssz_fixed_len(),
+                        ) == 0
+                }
+                fn ssz_fixed_len() -> usize {
+                    if <Self as ssz::view::SszTypeInfo>::is_ssz_fixed_len() {
+                        <u8 as ssz::Encode>::ssz_fixed_len()
+                            + <VariableList<u8, 8usize> as ssz::Encode>::ssz_fixed_len()
+                            + <FixedVector<u16, 2usize> as ssz::Encode>::ssz_fixed_len()
+                    } else {
+                        0
+                    }
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ssz_types::view::ToOwnedSsz<Alpha> for AlphaRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                fn to_owned(&self) -> Alpha {
+                    <AlphaRef<'a>>::to_owned(self)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> AlphaRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned(&self) -> Alpha {
+                    Alpha {
+                        a: self.a().expect("valid view"),
+                        b: ssz_types::VariableList::new(
+                                self.b().expect("valid view").to_owned(),
+                            )
+                            .expect("valid view"),
+                        c: self.c().expect("valid view").to_owned().expect("valid view"),
+                    }
+                }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Alpha,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.a = self.a()?;
+                    let view = self.b()?;
+                    out.b.clear();
+                    out.b.try_extend_from_slice(view.as_bytes()).expect("valid view");
+                    let view = self.c()?;
+                    for (slot, item) in out.c.iter_mut().zip(view.iter()) {
+                        *slot = ssz_types::view::ToOwnedSsz::to_owned(&item?);
+                    }
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> AlphaRef<'a> {
+                fn eq_owned(&self, other: &Alpha) -> Result<bool, ssz::DecodeError> {
+                    if !({
+                        let value: u8 = self.a()?;
+                        value == other.a
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: VariableList<u8, 8usize> = ssz_types::VariableList::new(
+                                self.b()?.to_owned(),
+                            )
+                            .expect("valid view");
+                        value == other.b
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: FixedVector<u16, 2usize> = self
+                            .c()?
+                            .to_owned()
+                            .expect("valid view");
+                        value == other.c
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<Alpha> for AlphaRef<'a> {
+                fn eq(&self, other: &Alpha) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<AlphaRef<'a>> for Alpha {
+                fn eq(&self, other: &AlphaRef<'a>) -> bool {
+                    other == self
+                }
+            }
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                ssz_derive::Encode,
+                ssz_derive::Decode
+            )]
+            #[ssz(struct_behaviour = "container")]
+            pub struct Beta {
+                pub alpha: Alpha,
+                pub d: bool,
+            }
+            impl Beta {
+                /// Returns the name and the `Debug` output of both values of each field that
+                /// differs between `self` and `other`, in declaration order.
+                #[allow(unused_mut, reason = "generated code using ssz-gen")]
+                pub fn diff(&self, other: &Self) -> Vec<ssz_types::FieldDiff> {
+                    let mut diffs = Vec::new();
+                    if self.alpha != other.alpha {
+                        diffs
+                            .push((
+                                "alpha",
+                                format!("{:?}", self.alpha),
+                                format!("{:?}", other.alpha),
+                            ));
+                    }
+                    if self.d != other.d {
+                        diffs
+                            .push((
+                                "d",
+                                format!("{:?}", self.d),
+                                format!("{:?}", other.d),
+                            ));
+                    }
+                    diffs
+                }
+            }
+            impl tree_hash::TreeHash for Beta {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::Container
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(2usize);
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.alpha)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.d)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .finish()
+                        .expect("tree hash derive should not have a remaining buffer")
+                }
+            }
+            impl tree_hash::TreeHashLeaves for Beta {
+                fn tree_hash_leaf_count() -> usize {
+                    2usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.alpha)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.d)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 2usize
+                            )
+                        }
+                    }
+                }
+            }
+            /// Zero-copy view over [`Beta`].
+            ///
+            /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
+            /// via lazy getter methods. Use `.to_owned()` to convert to the owned type when
+            /// needed.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                std::marker::Copy
+            )]
+            pub struct BetaRef<'a> {
+                bytes: &'a [u8],
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> BetaRef<'a> {
+                pub fn alpha(&self) -> Result<AlphaRef<'a>, ssz::DecodeError> {
+                    let bytes = ssz::layout::read_field_bytes(
+                        self.bytes,
+                        &[
+                            (
+                                <Alpha as ssz::Encode>::is_ssz_fixed_len(),
+                                <Alpha as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <bool as ssz::Encode>::is_ssz_fixed_len(),
+                                <bool as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                        0usize,
+                    )?;
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+                pub fn d(&self) -> Result<bool, ssz::DecodeError> {
+                    let bytes = ssz::layout::read_field_bytes(
+                        self.bytes,
+                        &[
+                            (
+                                <Alpha as ssz::Encode>::is_ssz_fixed_len(),
+                                <Alpha as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <bool as ssz::Encode>::is_ssz_fixed_len(),
+                                <bool as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                        1usize,
+                    )?;
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> BetaRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    let value = self.alpha()?;
+                    value.validate_deep()?;
+                    self.d()?;
+                    Ok(())
+                }
+            }
+            impl<'a> tree_hash::TreeHash for BetaRef<'a> {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::StableContainer
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(2usize);
+                    {
+                        let alpha = self.alpha().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&alpha);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    {
+                        let d = self.d().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&d);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    hasher.finish().expect("finish hasher")
+                }
+            }
+            impl<'a> ssz::view::DecodeView<'a> for BetaRef<'a> {
+                fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
+                    ssz::layout::validate_container(
+                        bytes,
+                        &[
+                            (
+                                <Alpha as ssz::Encode>::is_ssz_fixed_len(),
+                                <Alpha as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <bool as ssz::Encode>::is_ssz_fixed_len(),
+                                <bool as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                    )?;
+                    Ok(Self { bytes })
+                }
+            }
+            impl<'a> TryFrom<&'a [u8]> for BetaRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> BetaRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
+            impl<'a> ssz::view::SszTypeInfo for BetaRef<'a> {
+                fn is_ssz_fixed_len() -> bool {
+                    usize::from(!<Alpha as ssz::Encode>::is_ssz_fixed_len())
+                        + usize::from(!<bool as ssz::Encode>::is_ssz_fixed_len()) == 0
+                }
+                fn ssz_fixed_len() -> usize {
+                    if <Self as ssz::view::SszTypeInfo>::is_ssz_fixed_len() {
+                        <Alpha as ssz::Encode>::ssz_fixed_len()
+                            + <bool as ssz::Encode>::ssz_fixed_len()
+                    } else {
+                        0
+                    }
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ssz_types::view::ToOwnedSsz<Beta> for BetaRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                fn to_owned(&self) -> Beta {
+                    <BetaRef<'a>>::to_owned(self)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> BetaRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned(&self) -> Beta {
+                    Beta {
+                        alpha: {
+                            let view = self.alpha().expect("valid view");
+                            ssz_types::view::ToOwnedSsz::to_owned(&view)
+                        },
+                        d: self.d().expect("valid view"),
+                    }
+                }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Beta,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.alpha = {
+                        let view = self.alpha()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    out.d = self.d()?;
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> BetaRef<'a> {
+                fn eq_owned(&self, other: &Beta) -> Result<bool, ssz::DecodeError> {
+                    if !(self.alpha()? == other.alpha) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: bool = self.d()?;
+                        value == other.d
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<Beta> for BetaRef<'a> {
+                fn eq(&self, other: &Beta) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<BetaRef<'a>> for Beta {
+                fn eq(&self, other: &BetaRef<'a>) -> bool {
+                    other == self
+                }
+            }
+        }
+    }
+}
@@ -7,6 +7,12 @@ pub mod existing_module {
     use tree_hash::TreeHashDigest;
     use tree_hash_derive::TreeHash;
     use ssz::view::*;
+    /// Hash of the normalized schema this module was generated from.
+    #[allow(dead_code, reason = "generated code using ssz-gen")]
+    pub const SCHEMA_HASH: [u8; 32] = [
+        227, 176, 196, 66, 152, 252, 28, 20, 154, 251, 244, 200, 153, 111, 185, 36, 39,
+        174, 65, 228, 100, 155, 147, 76, 164, 149, 153, 27, 120, 82, 184, 85,
+    ];
 }
 pub mod tests {
     #![allow(unused_imports, reason = "generated code using ssz-gen")]
@@ -21,6 +27,13 @@ pub mod tests {
             use tree_hash::TreeHashDigest;
             use tree_hash_derive::TreeHash;
             use ssz::view::*;
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                160, 229, 95, 201, 153, 11, 107, 41, 130, 171, 222, 175, 162, 182, 110,
+                70, 46, 113, 245, 22, 152, 204, 86, 250, 109, 170, 72, 129, 170, 82, 15,
+                77,
+            ];
             /// Test container that references types from an existing Rust module
             #[derive(
                 std::clone::Clone,
@@ -213,6 +226,26 @@ pub mod tests {
                         slot: self.slot().expect("valid view"),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut TestExistingModule,
+                ) -> Result<(), ssz::DecodeError> {
+                    out.existing_field = {
+                        let view = self.existing_field()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    out.slot = self.slot()?;
+                    Ok(())
+                }
             }
         }
     }
@@ -11,6 +11,12 @@ pub mod tests {
             use tree_hash::TreeHashDigest;
             use tree_hash_derive::TreeHash;
             use ssz::view::*;
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                1, 150, 197, 46, 255, 32, 190, 55, 126, 45, 63, 3, 221, 234, 131, 9, 30,
+                91, 163, 136, 235, 124, 191, 41, 208, 110, 100, 78, 159, 96, 106, 19,
+            ];
             #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
             #[ssz(enum_behaviour = "union")]
             pub enum ExternalUnionA {
@@ -52,6 +58,21 @@ pub mod tests {
                     }
                 }
             }
+            impl std::fmt::Display for ExternalUnionA {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    match self {
+                        ExternalUnionA::Selector0 => {
+                            f.write_str("ExternalUnionA::Selector0")
+                        }
+                        ExternalUnionA::A(inner) => {
+                            write!(f, "ExternalUnionA::A({inner:?})")
+                        }
+                        ExternalUnionA::B(inner) => {
+                            write!(f, "ExternalUnionA::B({inner:?})")
+                        }
+                    }
+                }
+            }
             #[derive(Debug, Copy, Clone)]
             pub struct ExternalUnionARef<'a> {
                 bytes: &'a [u8],
@@ -68,7 +89,7 @@ pub mod tests {
                             ),
                         );
                     }
-                    Ok(())
+                    ssz::check_union_body_len(&self.bytes[1..], 0)
                 }
                 pub fn as_selector1(&self) -> Result<external_ssz::A, ssz::DecodeError> {
                     if self.selector() != 1u8 {
@@ -78,7 +99,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <external_ssz::A as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <external_ssz::A as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn as_selector2(
                     &self,
@@ -90,7 +119,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <external_ssz::module_a::module_b::B as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <external_ssz::module_a::module_b::B as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn to_owned(&self) -> ExternalUnionA {
                     match self.selector() {
@@ -113,6 +150,17 @@ pub mod tests {
                         _ => panic!("Invalid union selector: {}", self.selector()),
                     }
                 }
+                /// Validates the selector and the selected member, recursing
+                /// into nested views and walking every list and vector, and
+                /// returns the first error.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    match self.selector() {
+                        0u8 => self.as_selector0(),
+                        1u8 => self.as_selector1().map(|_| ()),
+                        2u8 => self.as_selector2().map(|_| ()),
+                        selector => Err(ssz::DecodeError::UnionSelectorInvalid(selector)),
+                    }
+                }
             }
             impl<'a> ssz::view::DecodeView<'a> for ExternalUnionARef<'a> {
                 fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
@@ -216,6 +264,21 @@ pub mod tests {
                     }
                 }
             }
+            impl std::fmt::Display for ExternalUnionB {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    match self {
+                        ExternalUnionB::Selector0 => {
+                            f.write_str("ExternalUnionB::Selector0")
+                        }
+                        ExternalUnionB::TestA(inner) => {
+                            write!(f, "ExternalUnionB::TestA({inner:?})")
+                        }
+                        ExternalUnionB::TestB(inner) => {
+                            write!(f, "ExternalUnionB::TestB({inner:?})")
+                        }
+                    }
+                }
+            }
             #[derive(Debug, Copy, Clone)]
             pub struct ExternalUnionBRef<'a> {
                 bytes: &'a [u8],
@@ -232,7 +295,7 @@ pub mod tests {
                             ),
                         );
                     }
-                    Ok(())
+                    ssz::check_union_body_len(&self.bytes[1..], 0)
                 }
                 pub fn as_selector1(&self) -> Result<TestA, ssz::DecodeError> {
                     if self.selector() != 1u8 {
@@ -242,7 +305,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <TestA as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <TestA as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn as_selector2(&self) -> Result<TestB, ssz::DecodeError> {
                     if self.selector() != 2u8 {
@@ -252,7 +323,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <TestB as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <TestB as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn to_owned(&self) -> ExternalUnionB {
                     match self.selector() {
@@ -275,6 +354,17 @@ pub mod tests {
                         _ => panic!("Invalid union selector: {}", self.selector()),
                     }
                 }
+                /// Validates the selector and the selected member, recursing
+                /// into nested views and walking every list and vector, and
+                /// returns the first error.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    match self.selector() {
+                        0u8 => self.as_selector0(),
+                        1u8 => self.as_selector1().map(|_| ()),
+                        2u8 => self.as_selector2().map(|_| ()),
+                        selector => Err(ssz::DecodeError::UnionSelectorInvalid(selector)),
+                    }
+                }
             }
             impl<'a> ssz::view::DecodeView<'a> for ExternalUnionBRef<'a> {
                 fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
@@ -386,6 +476,33 @@ pub mod tests {
                         .expect("tree hash derive should not have a remaining buffer")
                 }
             }
+            impl tree_hash::TreeHashLeaves for ExternalContainer {
+                fn tree_hash_leaf_count() -> usize {
+                    2usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<
+                                H,
+                            >(&self.field_a)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<
+                                H,
+                            >(&self.field_b)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 2usize
+                            )
+                        }
+                    }
+                }
+            }
             /// Zero-copy view over [`ExternalContainer`].
             ///
             /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
@@ -420,6 +537,7 @@ pub mod tests {
                         0usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn field_b(
                     &self,
@@ -439,6 +557,21 @@ pub mod tests {
                         1usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ExternalContainerRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.field_a()?;
+                    self.field_b()?;
+                    Ok(())
                 }
             }
             impl<'a> tree_hash::TreeHash for ExternalContainerRef<'a> {
@@ -489,6 +622,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for ExternalContainerRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ExternalContainerRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for ExternalContainerRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     usize::from(!<external_ssz::A as ssz::Encode>::is_ssz_fixed_len())
@@ -534,6 +680,69 @@ pub mod tests {
                         },
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut ExternalContainer,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.field_a = {
+                        let view = self.field_a()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    out.field_b = {
+                        let view = self.field_b()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ExternalContainerRef<'a> {
+                fn eq_owned(
+                    &self,
+                    other: &ExternalContainer,
+                ) -> Result<bool, ssz::DecodeError> {
+                    if !({
+                        let value: external_ssz::A = {
+                            let view = self.field_a()?;
+                            ssz_types::view::ToOwnedSsz::to_owned(&view)
+                        };
+                        value == other.field_a
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: external_ssz::module_a::module_b::B = {
+                            let view = self.field_b()?;
+                            ssz_types::view::ToOwnedSsz::to_owned(&view)
+                        };
+                        value == other.field_b
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<ExternalContainer> for ExternalContainerRef<'a> {
+                fn eq(&self, other: &ExternalContainer) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<ExternalContainerRef<'a>> for ExternalContainer {
+                fn eq(&self, other: &ExternalContainerRef<'a>) -> bool {
+                    other == self
+                }
             }
         }
     }
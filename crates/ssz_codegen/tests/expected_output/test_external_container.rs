@@ -11,6 +11,12 @@ pub mod tests {
             use tree_hash::TreeHashDigest;
             use tree_hash_derive::TreeHash;
             use ssz::view::*;
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                25, 170, 22, 64, 200, 56, 49, 206, 10, 179, 12, 212, 50, 66, 154, 188,
+                148, 7, 225, 40, 70, 127, 241, 137, 204, 96, 39, 25, 164, 248, 230, 224,
+            ];
             #[derive(
                 std::clone::Clone,
                 std::fmt::Debug,
@@ -24,6 +30,12 @@ pub mod tests {
                 pub height: u32,
                 pub block_hash: FixedBytes<32usize>,
             }
+            impl BlockCommitment {
+                /// Byte offset of each field in the SSZ encoding, in declaration order.
+                pub const FIELD_OFFSETS: &[usize] = &[0, 4];
+                /// Byte size of each field in the SSZ encoding, in declaration order.
+                pub const FIELD_SIZES: &[usize] = &[4, 32];
+            }
             impl tree_hash::TreeHash for BlockCommitment {
                 fn tree_hash_type() -> tree_hash::TreeHashType {
                     tree_hash::TreeHashType::Container
@@ -56,6 +68,31 @@ pub mod tests {
                         .expect("tree hash derive should not have a remaining buffer")
                 }
             }
+            impl tree_hash::TreeHashLeaves for BlockCommitment {
+                fn tree_hash_leaf_count() -> usize {
+                    2usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.height)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<
+                                H,
+                            >(&self.block_hash)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 2usize
+                            )
+                        }
+                    }
+                }
+            }
             /// Zero-copy view over [`BlockCommitment`].
             ///
             /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
@@ -75,40 +112,30 @@ pub mod tests {
             #[allow(dead_code, reason = "generated code using ssz-gen")]
             impl<'a> BlockCommitmentRef<'a> {
                 pub fn height(&self) -> Result<u32, ssz::DecodeError> {
-                    let bytes = ssz::layout::read_field_bytes(
-                        self.bytes,
-                        &[
-                            (
-                                <u32 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u32 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                            (
-                                <FixedBytes<32usize> as ssz::Encode>::is_ssz_fixed_len(),
-                                <FixedBytes<32usize> as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                        ],
-                        0usize,
-                    )?;
+                    let bytes = &self.bytes[0..4];
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn block_hash(
                     &self,
                 ) -> Result<FixedBytesRef<'a, 32usize>, ssz::DecodeError> {
-                    let bytes = ssz::layout::read_field_bytes(
-                        self.bytes,
-                        &[
-                            (
-                                <u32 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u32 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                            (
-                                <FixedBytes<32usize> as ssz::Encode>::is_ssz_fixed_len(),
-                                <FixedBytes<32usize> as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                        ],
-                        1usize,
-                    )?;
+                    let bytes = &self.bytes[4..36];
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> BlockCommitmentRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.height()?;
+                    self.block_hash()?;
+                    Ok(())
                 }
             }
             impl<'a> tree_hash::TreeHash for BlockCommitmentRef<'a> {
@@ -159,6 +186,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for BlockCommitmentRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> BlockCommitmentRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for BlockCommitmentRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     usize::from(!<u32 as ssz::Encode>::is_ssz_fixed_len())
@@ -200,6 +240,61 @@ pub mod tests {
                         ),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut BlockCommitment,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.height = self.height()?;
+                    out.block_hash = ssz_types::FixedBytes(
+                        self.block_hash()?.to_owned(),
+                    );
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> BlockCommitmentRef<'a> {
+                fn eq_owned(
+                    &self,
+                    other: &BlockCommitment,
+                ) -> Result<bool, ssz::DecodeError> {
+                    if !({
+                        let value: u32 = self.height()?;
+                        value == other.height
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: FixedBytes<32usize> = ssz_types::FixedBytes(
+                            self.block_hash()?.to_owned(),
+                        );
+                        value == other.block_hash
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<BlockCommitment> for BlockCommitmentRef<'a> {
+                fn eq(&self, other: &BlockCommitment) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<BlockCommitmentRef<'a>> for BlockCommitment {
+                fn eq(&self, other: &BlockCommitmentRef<'a>) -> bool {
+                    other == self
+                }
             }
         }
         pub mod test_external_outer {
@@ -211,6 +306,12 @@ pub mod tests {
             use tree_hash::TreeHashDigest;
             use tree_hash_derive::TreeHash;
             use ssz::view::*;
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                226, 96, 165, 226, 63, 208, 113, 183, 167, 63, 8, 175, 219, 225, 70, 52,
+                253, 202, 215, 84, 172, 166, 39, 105, 242, 198, 37, 108, 115, 201, 78, 51,
+            ];
             #[derive(
                 std::clone::Clone,
                 std::fmt::Debug,
@@ -254,6 +355,29 @@ pub mod tests {
                         .expect("tree hash derive should not have a remaining buffer")
                 }
             }
+            impl tree_hash::TreeHashLeaves for BlockRange {
+                fn tree_hash_leaf_count() -> usize {
+                    2usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.start)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.end)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 2usize
+                            )
+                        }
+                    }
+                }
+            }
             /// Zero-copy view over [`BlockRange`].
             ///
             /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
@@ -293,6 +417,7 @@ pub mod tests {
                         0usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn end(
                     &self,
@@ -315,6 +440,23 @@ pub mod tests {
                         1usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> BlockRangeRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    let value = self.start()?;
+                    value.validate_deep()?;
+                    let value = self.end()?;
+                    value.validate_deep()?;
+                    Ok(())
                 }
             }
             impl<'a> tree_hash::TreeHash for BlockRangeRef<'a> {
@@ -365,6 +507,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for BlockRangeRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> BlockRangeRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for BlockRangeRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     usize::from(
@@ -411,6 +566,69 @@ pub mod tests {
                         },
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut BlockRange,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.start = {
+                        let view = self.start()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    out.end = {
+                        let view = self.end()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> BlockRangeRef<'a> {
+                fn eq_owned(
+                    &self,
+                    other: &BlockRange,
+                ) -> Result<bool, ssz::DecodeError> {
+                    if !({
+                        let value: crate::tests::input::test_external_inner::BlockCommitment = {
+                            let view = self.start()?;
+                            ssz_types::view::ToOwnedSsz::to_owned(&view)
+                        };
+                        value == other.start
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: crate::tests::input::test_external_inner::BlockCommitment = {
+                            let view = self.end()?;
+                            ssz_types::view::ToOwnedSsz::to_owned(&view)
+                        };
+                        value == other.end
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<BlockRange> for BlockRangeRef<'a> {
+                fn eq(&self, other: &BlockRange) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<BlockRangeRef<'a>> for BlockRange {
+                fn eq(&self, other: &BlockRangeRef<'a>) -> bool {
+                    other == self
+                }
             }
         }
     }
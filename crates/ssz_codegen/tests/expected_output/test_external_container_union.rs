@@ -11,6 +11,12 @@ pub mod tests {
             use tree_hash::TreeHashDigest;
             use tree_hash_derive::TreeHash;
             use ssz::view::*;
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                18, 182, 11, 137, 223, 120, 255, 200, 195, 17, 73, 128, 203, 87, 217, 44,
+                52, 232, 54, 118, 48, 196, 155, 193, 102, 95, 177, 64, 64, 122, 218, 51,
+            ];
             /// Pending input we expect to see in a block Using external container type for
             /// the deposit
             #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
@@ -297,6 +303,28 @@ pub mod tests {
                         },
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut TestContainer,
+                ) -> Result<(), ssz::DecodeError> {
+                    let view = self.pending_inputs()?;
+                    out.pending_inputs.clear();
+                    for item in view.iter() {
+                        out.pending_inputs
+                            .push(ssz_types::view::ToOwnedSsz::to_owned(&item?))
+                            .expect("valid view");
+                    }
+                    Ok(())
+                }
             }
         }
     }
@@ -11,6 +11,13 @@ pub mod tests {
             use tree_hash::TreeHashDigest;
             use tree_hash_derive::TreeHash;
             use ssz::view::*;
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                81, 215, 176, 212, 111, 131, 28, 154, 45, 66, 61, 78, 38, 116, 14, 252,
+                235, 161, 157, 211, 83, 208, 114, 230, 124, 246, 124, 19, 103, 208, 185,
+                103,
+            ];
             /// Test external_kind pragma for container vs primitive external types
             #[derive(
                 std::clone::Clone,
@@ -567,6 +574,47 @@ pub mod tests {
                         },
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut ExternalPragmaTest,
+                ) -> Result<(), ssz::DecodeError> {
+                    out.state = {
+                        let view = self.state()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    out.balance = {
+                        let view = self.balance()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    let view = self.headers()?;
+                    for (slot, item) in out.headers.iter_mut().zip(view.iter()) {
+                        *slot = ssz_types::view::ToOwnedSsz::to_owned(&item?);
+                    }
+                    let view = self.transactions()?;
+                    out.transactions.clear();
+                    for item in view.iter() {
+                        out.transactions
+                            .push(ssz_types::view::ToOwnedSsz::to_owned(&item?))
+                            .expect("valid view");
+                    }
+                    let view = self.account_ids()?;
+                    out.account_ids.clear();
+                    for item in view.iter() {
+                        out.account_ids
+                            .push(ssz_types::view::ToOwnedSsz::to_owned(&item?))
+                            .expect("valid view");
+                    }
+                    Ok(())
+                }
             }
         }
     }
@@ -11,6 +11,12 @@ pub mod tests {
             use tree_hash::TreeHashDigest;
             use tree_hash_derive::TreeHash;
             use ssz::view::*;
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                106, 164, 83, 120, 47, 72, 81, 129, 195, 170, 99, 49, 200, 173, 23, 117,
+                241, 235, 154, 172, 139, 71, 54, 172, 140, 68, 191, 30, 176, 17, 119, 239,
+            ];
             #[derive(
                 std::clone::Clone,
                 std::fmt::Debug,
@@ -310,6 +316,36 @@ pub mod tests {
                         },
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut ContainerWithExternal,
+                ) -> Result<(), ssz::DecodeError> {
+                    out.payload = {
+                        let view = self.payload()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    out.account_id = {
+                        let view = self.account_id()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    let view = self.messages()?;
+                    out.messages.clear();
+                    for item in view.iter() {
+                        out.messages
+                            .push(ssz_types::view::ToOwnedSsz::to_owned(&item?))
+                            .expect("valid view");
+                    }
+                    Ok(())
+                }
             }
         }
     }
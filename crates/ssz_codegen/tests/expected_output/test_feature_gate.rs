@@ -0,0 +1,1373 @@
+pub mod tests {
+    #![allow(unused_imports, reason = "generated code using ssz-gen")]
+    pub mod input {
+        #![allow(unused_imports, reason = "generated code using ssz-gen")]
+        pub mod test_feature_gate {
+            #![allow(unused_imports, reason = "generated code using ssz-gen")]
+            use ssz_types::*;
+            use ssz_types::view::{FixedVectorRef, VariableListRef};
+            use ssz_primitives::{U128, U256};
+            use ssz_derive::{Encode, Decode};
+            use tree_hash::TreeHashDigest;
+            use tree_hash_derive::TreeHash;
+            use ssz::view::*;
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                195, 28, 0, 7, 185, 167, 228, 245, 2, 121, 179, 237, 106, 108, 1, 142, 2,
+                215, 148, 170, 224, 244, 139, 45, 159, 132, 149, 96, 71, 82, 113, 153,
+            ];
+            #[cfg(feature = "altair")]
+            #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+            #[ssz(enum_behaviour = "union")]
+            pub enum AltairExtra {
+                AltairBody(AltairBody),
+                Selector1(u64),
+            }
+            #[cfg(feature = "altair")]
+            impl tree_hash::TreeHash for AltairExtra {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::Container
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Union should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Union should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    match self {
+                        AltairExtra::AltairBody(inner) => {
+                            let root = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                                H,
+                            >(inner);
+                            tree_hash::mix_in_selector_with_hasher::<H>(&root, 0u8)
+                                .expect("valid selector")
+                        }
+                        AltairExtra::Selector1(inner) => {
+                            let root = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                                H,
+                            >(inner);
+                            tree_hash::mix_in_selector_with_hasher::<H>(&root, 1u8)
+                                .expect("valid selector")
+                        }
+                    }
+                }
+            }
+            #[cfg(feature = "altair")]
+            impl std::fmt::Display for AltairExtra {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    match self {
+                        AltairExtra::AltairBody(inner) => {
+                            write!(f, "AltairExtra::AltairBody({inner:?})")
+                        }
+                        AltairExtra::Selector1(inner) => {
+                            write!(f, "AltairExtra::Selector1({inner:?})")
+                        }
+                    }
+                }
+            }
+            #[cfg(feature = "altair")]
+            impl From<AltairBody> for AltairExtra {
+                fn from(value: AltairBody) -> Self {
+                    AltairExtra::AltairBody(value)
+                }
+            }
+            #[cfg(feature = "altair")]
+            impl From<u64> for AltairExtra {
+                fn from(value: u64) -> Self {
+                    AltairExtra::Selector1(value)
+                }
+            }
+            #[cfg(feature = "altair")]
+            #[derive(Debug, Copy, Clone)]
+            pub struct AltairExtraRef<'a> {
+                bytes: &'a [u8],
+            }
+            #[cfg(feature = "altair")]
+            impl<'a> AltairExtraRef<'a> {
+                pub fn selector(&self) -> u8 {
+                    self.bytes[0]
+                }
+                pub fn as_selector0(
+                    &self,
+                ) -> Result<AltairBodyRef<'a>, ssz::DecodeError> {
+                    if self.selector() != 0u8 {
+                        return Err(
+                            ssz::DecodeError::BytesInvalid(
+                                "Wrong selector for AltairExtra: expected 0".to_string(),
+                            ),
+                        );
+                    }
+                    let body = &self.bytes[1..];
+                    if <AltairBody as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <AltairBody as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
+                }
+                pub fn as_selector1(&self) -> Result<u64, ssz::DecodeError> {
+                    if self.selector() != 1u8 {
+                        return Err(
+                            ssz::DecodeError::BytesInvalid(
+                                "Wrong selector for AltairExtra: expected 1".to_string(),
+                            ),
+                        );
+                    }
+                    let body = &self.bytes[1..];
+                    if <u64 as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <u64 as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
+                }
+                pub fn to_owned(&self) -> AltairExtra {
+                    match self.selector() {
+                        0u8 => {
+                            AltairExtra::AltairBody({
+                                let view = self.as_selector0().expect("valid selector");
+                                ssz_types::view::ToOwnedSsz::to_owned(&view)
+                            })
+                        }
+                        1u8 => {
+                            AltairExtra::Selector1(
+                                self.as_selector1().expect("valid selector"),
+                            )
+                        }
+                        _ => panic!("Invalid union selector: {}", self.selector()),
+                    }
+                }
+                /// Validates the selector and the selected member, recursing
+                /// into nested views and walking every list and vector, and
+                /// returns the first error.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    match self.selector() {
+                        0u8 => {
+                            let value = self.as_selector0()?;
+                            value.validate_deep()?;
+                            Ok(())
+                        }
+                        1u8 => self.as_selector1().map(|_| ()),
+                        selector => Err(ssz::DecodeError::UnionSelectorInvalid(selector)),
+                    }
+                }
+            }
+            #[cfg(feature = "altair")]
+            impl<'a> ssz::view::DecodeView<'a> for AltairExtraRef<'a> {
+                fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
+                    let (_, _) = ssz::split_union_bytes(bytes)?;
+                    Ok(Self { bytes })
+                }
+            }
+            #[cfg(feature = "altair")]
+            impl<'a> ssz::view::SszTypeInfo for AltairExtraRef<'a> {
+                fn is_ssz_fixed_len() -> bool {
+                    false
+                }
+                fn ssz_fixed_len() -> usize {
+                    0
+                }
+            }
+            #[cfg(feature = "altair")]
+            impl<'a> ssz_types::view::ToOwnedSsz<AltairExtra> for AltairExtraRef<'a> {
+                fn to_owned(&self) -> AltairExtra {
+                    <AltairExtraRef<'a>>::to_owned(self)
+                }
+            }
+            #[cfg(feature = "altair")]
+            impl<'a> tree_hash::TreeHash for AltairExtraRef<'a> {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::Vector
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Union should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Union should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    match self.selector() {
+                        0u8 => {
+                            let value = self.as_selector0().expect("valid selector");
+                            tree_hash::mix_in_selector_with_hasher::<
+                                H,
+                            >(
+                                    &<_ as tree_hash::TreeHash>::tree_hash_root::<H>(&value),
+                                    0u8,
+                                )
+                                .expect("valid selector")
+                        }
+                        1u8 => {
+                            let value = self.as_selector1().expect("valid selector");
+                            tree_hash::mix_in_selector_with_hasher::<
+                                H,
+                            >(
+                                    &<_ as tree_hash::TreeHash>::tree_hash_root::<H>(&value),
+                                    1u8,
+                                )
+                                .expect("valid selector")
+                        }
+                        _ => panic!("Invalid union selector: {}", self.selector()),
+                    }
+                }
+            }
+            #[cfg(feature = "altair")]
+            #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+            #[ssz(enum_behaviour = "union")]
+            pub enum AltairMessage {
+                Body(AltairBody),
+                Slot(u64),
+            }
+            #[cfg(feature = "altair")]
+            impl tree_hash::TreeHash for AltairMessage {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::Container
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Union should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Union should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    match self {
+                        AltairMessage::Body(inner) => {
+                            let root = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                                H,
+                            >(inner);
+                            tree_hash::mix_in_selector_with_hasher::<H>(&root, 0u8)
+                                .expect("valid selector")
+                        }
+                        AltairMessage::Slot(inner) => {
+                            let root = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                                H,
+                            >(inner);
+                            tree_hash::mix_in_selector_with_hasher::<H>(&root, 1u8)
+                                .expect("valid selector")
+                        }
+                    }
+                }
+            }
+            #[cfg(feature = "altair")]
+            impl std::fmt::Display for AltairMessage {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    match self {
+                        AltairMessage::Body(inner) => {
+                            write!(f, "AltairMessage::Body({inner:?})")
+                        }
+                        AltairMessage::Slot(inner) => {
+                            write!(f, "AltairMessage::Slot({inner:?})")
+                        }
+                    }
+                }
+            }
+            #[cfg(feature = "altair")]
+            impl From<AltairBody> for AltairMessage {
+                fn from(value: AltairBody) -> Self {
+                    AltairMessage::Body(value)
+                }
+            }
+            #[cfg(feature = "altair")]
+            impl From<u64> for AltairMessage {
+                fn from(value: u64) -> Self {
+                    AltairMessage::Slot(value)
+                }
+            }
+            #[cfg(feature = "altair")]
+            pub type BodyRef<'a> = AltairBodyRef<'a>;
+            #[cfg(feature = "altair")]
+            #[derive(Debug, Copy, Clone)]
+            pub struct AltairMessageRef<'a> {
+                bytes: &'a [u8],
+            }
+            #[cfg(feature = "altair")]
+            impl<'a> AltairMessageRef<'a> {
+                pub fn selector(&self) -> u8 {
+                    self.bytes[0]
+                }
+                pub fn as_selector0(&self) -> Result<BodyRef<'_>, ssz::DecodeError> {
+                    if self.selector() != 0u8 {
+                        return Err(
+                            ssz::DecodeError::BytesInvalid(
+                                "Wrong selector for AltairMessage: expected 0".to_string(),
+                            ),
+                        );
+                    }
+                    let body = &self.bytes[1..];
+                    if <AltairBody as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <AltairBody as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
+                }
+                pub fn as_selector1(&self) -> Result<u64, ssz::DecodeError> {
+                    if self.selector() != 1u8 {
+                        return Err(
+                            ssz::DecodeError::BytesInvalid(
+                                "Wrong selector for AltairMessage: expected 1".to_string(),
+                            ),
+                        );
+                    }
+                    let body = &self.bytes[1..];
+                    if <u64 as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <u64 as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
+                }
+                pub fn to_owned(&self) -> AltairMessage {
+                    match self.selector() {
+                        0u8 => {
+                            AltairMessage::Body({
+                                let view = self.as_selector0().expect("valid selector");
+                                ssz_types::view::ToOwnedSsz::to_owned(&view)
+                            })
+                        }
+                        1u8 => {
+                            AltairMessage::Slot(
+                                self.as_selector1().expect("valid selector"),
+                            )
+                        }
+                        _ => panic!("Invalid union selector: {}", self.selector()),
+                    }
+                }
+                /// Validates the selector and the selected member, recursing
+                /// into nested views and walking every list and vector, and
+                /// returns the first error.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    match self.selector() {
+                        0u8 => {
+                            let value = self.as_selector0()?;
+                            value.validate_deep()?;
+                            Ok(())
+                        }
+                        1u8 => self.as_selector1().map(|_| ()),
+                        selector => Err(ssz::DecodeError::UnionSelectorInvalid(selector)),
+                    }
+                }
+            }
+            #[cfg(feature = "altair")]
+            impl<'a> ssz::view::DecodeView<'a> for AltairMessageRef<'a> {
+                fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
+                    let (_, _) = ssz::split_union_bytes(bytes)?;
+                    Ok(Self { bytes })
+                }
+            }
+            #[cfg(feature = "altair")]
+            impl<'a> ssz::view::SszTypeInfo for AltairMessageRef<'a> {
+                fn is_ssz_fixed_len() -> bool {
+                    false
+                }
+                fn ssz_fixed_len() -> usize {
+                    0
+                }
+            }
+            #[cfg(feature = "altair")]
+            impl<'a> ssz_types::view::ToOwnedSsz<AltairMessage>
+            for AltairMessageRef<'a> {
+                fn to_owned(&self) -> AltairMessage {
+                    <AltairMessageRef<'a>>::to_owned(self)
+                }
+            }
+            #[cfg(feature = "altair")]
+            impl<'a> tree_hash::TreeHash for AltairMessageRef<'a> {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::Vector
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Union should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Union should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    match self.selector() {
+                        0u8 => {
+                            let value = self.as_selector0().expect("valid selector");
+                            tree_hash::mix_in_selector_with_hasher::<
+                                H,
+                            >(
+                                    &<_ as tree_hash::TreeHash>::tree_hash_root::<H>(&value),
+                                    0u8,
+                                )
+                                .expect("valid selector")
+                        }
+                        1u8 => {
+                            let value = self.as_selector1().expect("valid selector");
+                            tree_hash::mix_in_selector_with_hasher::<
+                                H,
+                            >(
+                                    &<_ as tree_hash::TreeHash>::tree_hash_root::<H>(&value),
+                                    1u8,
+                                )
+                                .expect("valid selector")
+                        }
+                        _ => panic!("Invalid union selector: {}", self.selector()),
+                    }
+                }
+            }
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                ssz_derive::Encode,
+                ssz_derive::Decode
+            )]
+            #[ssz(struct_behaviour = "container")]
+            pub struct Phase0Body {
+                pub slot: u64,
+            }
+            impl Phase0Body {
+                /// Byte offset of each field in the SSZ encoding, in declaration order.
+                pub const FIELD_OFFSETS: &[usize] = &[0];
+                /// Byte size of each field in the SSZ encoding, in declaration order.
+                pub const FIELD_SIZES: &[usize] = &[8];
+            }
+            impl tree_hash::TreeHash for Phase0Body {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::Container
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(1usize);
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.slot)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .finish()
+                        .expect("tree hash derive should not have a remaining buffer")
+                }
+            }
+            impl tree_hash::TreeHashLeaves for Phase0Body {
+                fn tree_hash_leaf_count() -> usize {
+                    1usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.slot)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 1usize
+                            )
+                        }
+                    }
+                }
+            }
+            /// Zero-copy view over [`Phase0Body`].
+            ///
+            /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
+            /// via lazy getter methods. Use `.to_owned()` to convert to the owned type when
+            /// needed.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                std::marker::Copy
+            )]
+            pub struct Phase0BodyRef<'a> {
+                bytes: &'a [u8],
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> Phase0BodyRef<'a> {
+                pub fn slot(&self) -> Result<u64, ssz::DecodeError> {
+                    let bytes = &self.bytes[0..8];
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> Phase0BodyRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.slot()?;
+                    Ok(())
+                }
+            }
+            impl<'a> tree_hash::TreeHash for Phase0BodyRef<'a> {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::StableContainer
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(1usize);
+                    {
+                        let slot = self.slot().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&slot);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    hasher.finish().expect("finish hasher")
+                }
+            }
+            impl<'a> ssz::view::DecodeView<'a> for Phase0BodyRef<'a> {
+                fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
+                    ssz::layout::validate_container(
+                        bytes,
+                        &[
+                            (
+                                <u64 as ssz::Encode>::is_ssz_fixed_len(),
+                                <u64 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                    )?;
+                    Ok(Self { bytes })
+                }
+            }
+            impl<'a> TryFrom<&'a [u8]> for Phase0BodyRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> Phase0BodyRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
+            impl<'a> ssz::view::SszTypeInfo for Phase0BodyRef<'a> {
+                fn is_ssz_fixed_len() -> bool {
+                    usize::from(!<u64 as ssz::Encode>::is_ssz_fixed_len()) == 0
+                }
+                fn ssz_fixed_len() -> usize {
+                    if <Self as ssz::view::SszTypeInfo>::is_ssz_fixed_len() {
+                        <u64 as ssz::Encode>::ssz_fixed_len()
+                    } else {
+                        0
+                    }
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ssz_types::view::ToOwnedSsz<Phase0Body> for Phase0BodyRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                fn to_owned(&self) -> Phase0Body {
+                    <Phase0BodyRef<'a>>::to_owned(self)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> Phase0BodyRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned(&self) -> Phase0Body {
+                    Phase0Body {
+                        slot: self.slot().expect("valid view"),
+                    }
+                }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Phase0Body,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.slot = self.slot()?;
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> Phase0BodyRef<'a> {
+                fn eq_owned(
+                    &self,
+                    other: &Phase0Body,
+                ) -> Result<bool, ssz::DecodeError> {
+                    if !({
+                        let value: u64 = self.slot()?;
+                        value == other.slot
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<Phase0Body> for Phase0BodyRef<'a> {
+                fn eq(&self, other: &Phase0Body) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<Phase0BodyRef<'a>> for Phase0Body {
+                fn eq(&self, other: &Phase0BodyRef<'a>) -> bool {
+                    other == self
+                }
+            }
+            #[cfg(feature = "altair")]
+            /// Body introduced by the altair fork.
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                ssz_derive::Encode,
+                ssz_derive::Decode
+            )]
+            #[ssz(struct_behaviour = "container")]
+            pub struct AltairBody {
+                pub slot: u64,
+                pub participation: VariableList<u8, 16usize>,
+            }
+            #[cfg(feature = "altair")]
+            impl tree_hash::TreeHash for AltairBody {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::Container
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(2usize);
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.slot)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<
+                                H,
+                            >(&self.participation)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .finish()
+                        .expect("tree hash derive should not have a remaining buffer")
+                }
+            }
+            #[cfg(feature = "altair")]
+            impl tree_hash::TreeHashLeaves for AltairBody {
+                fn tree_hash_leaf_count() -> usize {
+                    2usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.slot)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<
+                                H,
+                            >(&self.participation)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 2usize
+                            )
+                        }
+                    }
+                }
+            }
+            #[cfg(feature = "altair")]
+            /// Zero-copy view over [`AltairBody`].
+            ///
+            /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
+            /// via lazy getter methods. Use `.to_owned()` to convert to the owned type when
+            /// needed.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                std::marker::Copy
+            )]
+            pub struct AltairBodyRef<'a> {
+                bytes: &'a [u8],
+            }
+            #[cfg(feature = "altair")]
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> AltairBodyRef<'a> {
+                pub fn slot(&self) -> Result<u64, ssz::DecodeError> {
+                    let bytes = ssz::layout::read_field_bytes(
+                        self.bytes,
+                        &[
+                            (
+                                <u64 as ssz::Encode>::is_ssz_fixed_len(),
+                                <u64 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <VariableList<
+                                    u8,
+                                    16usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <VariableList<u8, 16usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                        0usize,
+                    )?;
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+                pub fn participation(
+                    &self,
+                ) -> Result<BytesRef<'a, 16usize>, ssz::DecodeError> {
+                    let bytes = ssz::layout::read_field_bytes(
+                        self.bytes,
+                        &[
+                            (
+                                <u64 as ssz::Encode>::is_ssz_fixed_len(),
+                                <u64 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <VariableList<
+                                    u8,
+                                    16usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <VariableList<u8, 16usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                        1usize,
+                    )?;
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[cfg(feature = "altair")]
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> AltairBodyRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.slot()?;
+                    self.participation()?;
+                    Ok(())
+                }
+            }
+            #[cfg(feature = "altair")]
+            impl<'a> tree_hash::TreeHash for AltairBodyRef<'a> {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::StableContainer
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(2usize);
+                    {
+                        let slot = self.slot().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&slot);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    {
+                        let participation = self.participation().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&participation);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    hasher.finish().expect("finish hasher")
+                }
+            }
+            #[cfg(feature = "altair")]
+            impl<'a> ssz::view::DecodeView<'a> for AltairBodyRef<'a> {
+                fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
+                    ssz::layout::validate_container(
+                        bytes,
+                        &[
+                            (
+                                <u64 as ssz::Encode>::is_ssz_fixed_len(),
+                                <u64 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <VariableList<
+                                    u8,
+                                    16usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <VariableList<u8, 16usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                    )?;
+                    Ok(Self { bytes })
+                }
+            }
+            #[cfg(feature = "altair")]
+            impl<'a> TryFrom<&'a [u8]> for AltairBodyRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[cfg(feature = "altair")]
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> AltairBodyRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
+            #[cfg(feature = "altair")]
+            impl<'a> ssz::view::SszTypeInfo for AltairBodyRef<'a> {
+                fn is_ssz_fixed_len() -> bool {
+                    usize::from(!<u64 as ssz::Encode>::is_ssz_fixed_len())
+                        + usize::from(
+                            !<VariableList<
+                                u8,
+                                16usize,
+                            > as ssz::Encode>::is_ssz_fixed_len(),
+                        ) == 0
+                }
+                fn ssz_fixed_len() -> usize {
+                    if <Self as ssz::view::SszTypeInfo>::is_ssz_fixed_len() {
+                        <u64 as ssz::Encode>::ssz_fixed_len()
+                            + <VariableList<u8, 16usize> as ssz::Encode>::ssz_fixed_len()
+                    } else {
+                        0
+                    }
+                }
+            }
+            #[cfg(feature = "altair")]
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ssz_types::view::ToOwnedSsz<AltairBody> for AltairBodyRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                fn to_owned(&self) -> AltairBody {
+                    <AltairBodyRef<'a>>::to_owned(self)
+                }
+            }
+            #[cfg(feature = "altair")]
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> AltairBodyRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned(&self) -> AltairBody {
+                    AltairBody {
+                        slot: self.slot().expect("valid view"),
+                        participation: ssz_types::VariableList::new(
+                                self.participation().expect("valid view").to_owned(),
+                            )
+                            .expect("valid view"),
+                    }
+                }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut AltairBody,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.slot = self.slot()?;
+                    let view = self.participation()?;
+                    out.participation.clear();
+                    out.participation
+                        .try_extend_from_slice(view.as_bytes())
+                        .expect("valid view");
+                    Ok(())
+                }
+            }
+            #[cfg(feature = "altair")]
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> AltairBodyRef<'a> {
+                fn eq_owned(
+                    &self,
+                    other: &AltairBody,
+                ) -> Result<bool, ssz::DecodeError> {
+                    if !({
+                        let value: u64 = self.slot()?;
+                        value == other.slot
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: VariableList<u8, 16usize> = ssz_types::VariableList::new(
+                                self.participation()?.to_owned(),
+                            )
+                            .expect("valid view");
+                        value == other.participation
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            #[cfg(feature = "altair")]
+            impl<'a> PartialEq<AltairBody> for AltairBodyRef<'a> {
+                fn eq(&self, other: &AltairBody) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            #[cfg(feature = "altair")]
+            impl<'a> PartialEq<AltairBodyRef<'a>> for AltairBody {
+                fn eq(&self, other: &AltairBodyRef<'a>) -> bool {
+                    other == self
+                }
+            }
+            #[cfg(feature = "altair")]
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                ssz_derive::Encode,
+                ssz_derive::Decode
+            )]
+            #[ssz(struct_behaviour = "container")]
+            pub struct AltairBlock {
+                pub body: AltairBody,
+                pub extra: AltairExtra,
+                pub message: AltairMessage,
+            }
+            #[cfg(feature = "altair")]
+            impl tree_hash::TreeHash for AltairBlock {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::Container
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(3usize);
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.body)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.extra)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<
+                                H,
+                            >(&self.message)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .finish()
+                        .expect("tree hash derive should not have a remaining buffer")
+                }
+            }
+            #[cfg(feature = "altair")]
+            impl tree_hash::TreeHashLeaves for AltairBlock {
+                fn tree_hash_leaf_count() -> usize {
+                    3usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.body)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.extra)
+                        }
+                        2usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<
+                                H,
+                            >(&self.message)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 3usize
+                            )
+                        }
+                    }
+                }
+            }
+            #[cfg(feature = "altair")]
+            /// Zero-copy view over [`AltairBlock`].
+            ///
+            /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
+            /// via lazy getter methods. Use `.to_owned()` to convert to the owned type when
+            /// needed.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                std::marker::Copy
+            )]
+            pub struct AltairBlockRef<'a> {
+                bytes: &'a [u8],
+            }
+            #[cfg(feature = "altair")]
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> AltairBlockRef<'a> {
+                pub fn body(&self) -> Result<AltairBodyRef<'a>, ssz::DecodeError> {
+                    let bytes = ssz::layout::read_field_bytes(
+                        self.bytes,
+                        &[
+                            (
+                                <AltairBody as ssz::Encode>::is_ssz_fixed_len(),
+                                <AltairBody as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <AltairExtra as ssz::Encode>::is_ssz_fixed_len(),
+                                <AltairExtra as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <AltairMessage as ssz::Encode>::is_ssz_fixed_len(),
+                                <AltairMessage as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                        0usize,
+                    )?;
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+                pub fn extra(&self) -> Result<AltairExtraRef<'a>, ssz::DecodeError> {
+                    let bytes = ssz::layout::read_field_bytes(
+                        self.bytes,
+                        &[
+                            (
+                                <AltairBody as ssz::Encode>::is_ssz_fixed_len(),
+                                <AltairBody as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <AltairExtra as ssz::Encode>::is_ssz_fixed_len(),
+                                <AltairExtra as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <AltairMessage as ssz::Encode>::is_ssz_fixed_len(),
+                                <AltairMessage as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                        1usize,
+                    )?;
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+                pub fn message(&self) -> Result<AltairMessageRef<'a>, ssz::DecodeError> {
+                    let bytes = ssz::layout::read_field_bytes(
+                        self.bytes,
+                        &[
+                            (
+                                <AltairBody as ssz::Encode>::is_ssz_fixed_len(),
+                                <AltairBody as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <AltairExtra as ssz::Encode>::is_ssz_fixed_len(),
+                                <AltairExtra as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <AltairMessage as ssz::Encode>::is_ssz_fixed_len(),
+                                <AltairMessage as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                        2usize,
+                    )?;
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[cfg(feature = "altair")]
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> AltairBlockRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    let value = self.body()?;
+                    value.validate_deep()?;
+                    let value = self.extra()?;
+                    value.validate_deep()?;
+                    let value = self.message()?;
+                    value.validate_deep()?;
+                    Ok(())
+                }
+            }
+            #[cfg(feature = "altair")]
+            impl<'a> tree_hash::TreeHash for AltairBlockRef<'a> {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::StableContainer
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(3usize);
+                    {
+                        let body = self.body().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&body);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    {
+                        let extra = self.extra().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&extra);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    {
+                        let message = self.message().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&message);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    hasher.finish().expect("finish hasher")
+                }
+            }
+            #[cfg(feature = "altair")]
+            impl<'a> ssz::view::DecodeView<'a> for AltairBlockRef<'a> {
+                fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
+                    ssz::layout::validate_container(
+                        bytes,
+                        &[
+                            (
+                                <AltairBody as ssz::Encode>::is_ssz_fixed_len(),
+                                <AltairBody as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <AltairExtra as ssz::Encode>::is_ssz_fixed_len(),
+                                <AltairExtra as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <AltairMessage as ssz::Encode>::is_ssz_fixed_len(),
+                                <AltairMessage as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                    )?;
+                    Ok(Self { bytes })
+                }
+            }
+            #[cfg(feature = "altair")]
+            impl<'a> TryFrom<&'a [u8]> for AltairBlockRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[cfg(feature = "altair")]
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> AltairBlockRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
+            #[cfg(feature = "altair")]
+            impl<'a> ssz::view::SszTypeInfo for AltairBlockRef<'a> {
+                fn is_ssz_fixed_len() -> bool {
+                    usize::from(!<AltairBody as ssz::Encode>::is_ssz_fixed_len())
+                        + usize::from(!<AltairExtra as ssz::Encode>::is_ssz_fixed_len())
+                        + usize::from(
+                            !<AltairMessage as ssz::Encode>::is_ssz_fixed_len(),
+                        ) == 0
+                }
+                fn ssz_fixed_len() -> usize {
+                    if <Self as ssz::view::SszTypeInfo>::is_ssz_fixed_len() {
+                        <AltairBody as ssz::Encode>::ssz_fixed_len()
+                            + <AltairExtra as ssz::Encode>::ssz_fixed_len()
+                            + <AltairMessage as ssz::Encode>::ssz_fixed_len()
+                    } else {
+                        0
+                    }
+                }
+            }
+            #[cfg(feature = "altair")]
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ssz_types::view::ToOwnedSsz<AltairBlock> for AltairBlockRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                fn to_owned(&self) -> AltairBlock {
+                    <AltairBlockRef<'a>>::to_owned(self)
+                }
+            }
+            #[cfg(feature = "altair")]
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> AltairBlockRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned(&self) -> AltairBlock {
+                    AltairBlock {
+                        body: {
+                            let view = self.body().expect("valid view");
+                            ssz_types::view::ToOwnedSsz::to_owned(&view)
+                        },
+                        extra: {
+                            let view = self.extra().expect("valid view");
+                            ssz_types::view::ToOwnedSsz::to_owned(&view)
+                        },
+                        message: {
+                            let view = self.message().expect("valid view");
+                            ssz_types::view::ToOwnedSsz::to_owned(&view)
+                        },
+                    }
+                }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut AltairBlock,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.body = {
+                        let view = self.body()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    out.extra = {
+                        let view = self.extra()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    out.message = {
+                        let view = self.message()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    Ok(())
+                }
+            }
+            #[cfg(feature = "altair")]
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> AltairBlockRef<'a> {
+                fn eq_owned(
+                    &self,
+                    other: &AltairBlock,
+                ) -> Result<bool, ssz::DecodeError> {
+                    if !(self.body()? == other.body) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: AltairExtra = {
+                            let view = self.extra()?;
+                            ssz_types::view::ToOwnedSsz::to_owned(&view)
+                        };
+                        value == other.extra
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: AltairMessage = {
+                            let view = self.message()?;
+                            ssz_types::view::ToOwnedSsz::to_owned(&view)
+                        };
+                        value == other.message
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            #[cfg(feature = "altair")]
+            impl<'a> PartialEq<AltairBlock> for AltairBlockRef<'a> {
+                fn eq(&self, other: &AltairBlock) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            #[cfg(feature = "altair")]
+            impl<'a> PartialEq<AltairBlockRef<'a>> for AltairBlock {
+                fn eq(&self, other: &AltairBlockRef<'a>) -> bool {
+                    other == self
+                }
+            }
+        }
+    }
+}
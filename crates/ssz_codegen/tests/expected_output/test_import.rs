@@ -11,7 +11,13 @@ pub mod tests {
             use tree_hash::TreeHashDigest;
             use tree_hash_derive::TreeHash;
             use ssz::view::*;
-            #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                123, 69, 236, 85, 51, 147, 141, 175, 56, 65, 27, 104, 139, 151, 203, 30,
+                128, 113, 168, 181, 175, 75, 208, 212, 50, 110, 88, 36, 224, 107, 214, 81,
+            ];
+            #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, Hash)]
             #[ssz(enum_behaviour = "union")]
             pub enum AliasUnionUnion {
                 Selector0(u8),
@@ -46,6 +52,40 @@ pub mod tests {
                     }
                 }
             }
+            impl std::fmt::Display for AliasUnionUnion {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    match self {
+                        AliasUnionUnion::Selector0(inner) => {
+                            write!(f, "AliasUnionUnion::Selector0({inner:?})")
+                        }
+                        AliasUnionUnion::AliasUnion(inner) => {
+                            write!(f, "AliasUnionUnion::AliasUnion({inner:?})")
+                        }
+                    }
+                }
+            }
+            impl From<u8> for AliasUnionUnion {
+                fn from(value: u8) -> Self {
+                    AliasUnionUnion::Selector0(value)
+                }
+            }
+            impl From<AliasUnion> for AliasUnionUnion {
+                fn from(value: AliasUnion) -> Self {
+                    AliasUnionUnion::AliasUnion(value)
+                }
+            }
+            #[cfg(test)]
+            impl AliasUnionUnion {
+                /// Returns one value per selector, in selector order, each
+                /// holding its member's default. Lets tests check that code
+                /// matching on the union handles every member.
+                pub fn all_selector_examples() -> Vec<Self> {
+                    vec![
+                        AliasUnionUnion::Selector0(Default::default()),
+                        AliasUnionUnion::AliasUnion(None)
+                    ]
+                }
+            }
             #[derive(Debug, Copy, Clone)]
             pub struct AliasUnionUnionRef<'a> {
                 bytes: &'a [u8],
@@ -62,7 +102,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <u8 as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <u8 as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn as_selector1(&self) -> Result<Option<u8>, ssz::DecodeError> {
                     if self.selector() != 1u8 {
@@ -72,7 +120,35 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    {
+                        let bytes = &self.bytes[1..];
+                        ssz::view::reject_empty(bytes, 1)?;
+                        let selector = bytes[0];
+                        match selector {
+                            0 => ssz::check_union_body_len(&bytes[1..], 0).map(|()| None),
+                            1 => {
+                                if <u8 as ssz::Encode>::is_ssz_fixed_len() {
+                                    ssz::check_union_body_len(
+                                        &bytes[1..],
+                                        <u8 as ssz::Encode>::ssz_fixed_len(),
+                                    )?;
+                                }
+                                let body = &bytes[1..];
+                                let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(
+                                        body,
+                                    )
+                                    .map_err(|e| e.bounded(body.len()))?;
+                                Ok(Some(inner))
+                            }
+                            _ => {
+                                Err(
+                                    ssz::DecodeError::BytesInvalid(
+                                        format!("Invalid union selector for Option: {}", selector),
+                                    ),
+                                )
+                            }
+                        }
+                    }
                 }
                 pub fn to_owned(&self) -> AliasUnionUnion {
                     match self.selector() {
@@ -90,6 +166,16 @@ pub mod tests {
                         _ => panic!("Invalid union selector: {}", self.selector()),
                     }
                 }
+                /// Validates the selector and the selected member, recursing
+                /// into nested views and walking every list and vector, and
+                /// returns the first error.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    match self.selector() {
+                        0u8 => self.as_selector0().map(|_| ()),
+                        1u8 => self.as_selector1().map(|_| ()),
+                        selector => Err(ssz::DecodeError::UnionSelectorInvalid(selector)),
+                    }
+                }
             }
             impl<'a> ssz::view::DecodeView<'a> for AliasUnionUnionRef<'a> {
                 fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
@@ -236,9 +322,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         5usize,
@@ -268,8 +353,9 @@ pub mod tests {
                         None => return Ok(ssz_types::Optional::None),
                     };
                     let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(
-                        field_bytes,
-                    )?;
+                            field_bytes,
+                        )
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
                 pub fn b(&self) -> Result<Optional<Option<u8>>, ssz::DecodeError> {
@@ -277,9 +363,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         5usize,
@@ -308,12 +393,52 @@ pub mod tests {
                         Some(bytes) => bytes,
                         None => return Ok(ssz_types::Optional::None),
                     };
-                    let inner = <Option<
-                        u8,
-                    > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)?;
+                    let inner = {
+                        let bytes = field_bytes;
+                        ssz::view::reject_empty(bytes, 1)?;
+                        let selector = bytes[0];
+                        match selector {
+                            0 => ssz::check_union_body_len(&bytes[1..], 0).map(|()| None),
+                            1 => {
+                                if <u8 as ssz::Encode>::is_ssz_fixed_len() {
+                                    ssz::check_union_body_len(
+                                        &bytes[1..],
+                                        <u8 as ssz::Encode>::ssz_fixed_len(),
+                                    )?;
+                                }
+                                let body = &bytes[1..];
+                                let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(
+                                        body,
+                                    )
+                                    .map_err(|e| e.bounded(body.len()))?;
+                                Ok(Some(inner))
+                            }
+                            _ => {
+                                Err(
+                                    ssz::DecodeError::BytesInvalid(
+                                        format!("Invalid union selector for Option: {}", selector),
+                                    ),
+                                )
+                            }
+                        }
+                    }?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
             }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> StableContainerClassRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.a()?;
+                    self.b()?;
+                    Ok(())
+                }
+            }
             impl<'a> tree_hash::TreeHash for StableContainerClassRef<'a> {
                 fn tree_hash_type() -> tree_hash::TreeHashType {
                     tree_hash::TreeHashType::StableContainer
@@ -369,9 +494,8 @@ pub mod tests {
                     use ssz::Decode;
                     let bitvector_bytes = bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         5usize,
@@ -409,6 +533,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for StableContainerClassRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> StableContainerClassRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for StableContainerClassRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     false
@@ -447,6 +584,87 @@ pub mod tests {
                         },
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut StableContainerClass,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.a = self.a()?;
+                    out.b = match self.b()? {
+                        ssz_types::Optional::Some(inner) => {
+                            ssz_types::Optional::Some(
+                                ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                            )
+                        }
+                        ssz_types::Optional::None => ssz_types::Optional::None,
+                    };
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> StableContainerClassRef<'a> {
+                fn eq_owned(
+                    &self,
+                    other: &StableContainerClass,
+                ) -> Result<bool, ssz::DecodeError> {
+                    use ssz::Decode;
+                    let bitvector_bytes = self
+                        .bytes
+                        .get(..1usize)
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
+                        })?;
+                    let bitvector = ssz_types::BitVector::<
+                        5usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    if bitvector.get(0usize).unwrap_or(false) != other.a.is_some() {
+                        return Ok(false);
+                    }
+                    if bitvector.get(1usize).unwrap_or(false) != other.b.is_some() {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<u8> = self.a()?;
+                        value == other.a
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<AliasUnion> = match self.b()? {
+                            ssz_types::Optional::Some(inner) => {
+                                ssz_types::Optional::Some(
+                                    ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                                )
+                            }
+                            ssz_types::Optional::None => ssz_types::Optional::None,
+                        };
+                        value == other.b
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<StableContainerClass> for StableContainerClassRef<'a> {
+                fn eq(&self, other: &StableContainerClass) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<StableContainerClassRef<'a>> for StableContainerClass {
+                fn eq(&self, other: &StableContainerClassRef<'a>) -> bool {
+                    other == self
+                }
             }
         }
         pub mod test_import_1 {
@@ -458,7 +676,13 @@ pub mod tests {
             use tree_hash::TreeHashDigest;
             use tree_hash_derive::TreeHash;
             use ssz::view::*;
-            #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                173, 220, 216, 161, 43, 64, 16, 254, 116, 188, 184, 198, 122, 11, 180,
+                248, 55, 240, 246, 73, 252, 98, 249, 206, 62, 89, 47, 199, 77, 138, 3, 85,
+            ];
+            #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, Hash)]
             #[ssz(enum_behaviour = "union")]
             pub enum AliasUnionUnion {
                 Selector0,
@@ -499,6 +723,44 @@ pub mod tests {
                     }
                 }
             }
+            impl std::fmt::Display for AliasUnionUnion {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    match self {
+                        AliasUnionUnion::Selector0 => {
+                            f.write_str("AliasUnionUnion::Selector0")
+                        }
+                        AliasUnionUnion::AliasUint8(inner) => {
+                            write!(f, "AliasUnionUnion::AliasUint8({inner:?})")
+                        }
+                        AliasUnionUnion::AliasUnion(inner) => {
+                            write!(f, "AliasUnionUnion::AliasUnion({inner:?})")
+                        }
+                    }
+                }
+            }
+            impl From<crate::tests::input::test_common::AliasUint8> for AliasUnionUnion {
+                fn from(value: crate::tests::input::test_common::AliasUint8) -> Self {
+                    AliasUnionUnion::AliasUint8(value)
+                }
+            }
+            impl From<crate::tests::input::test_common::AliasUnion> for AliasUnionUnion {
+                fn from(value: crate::tests::input::test_common::AliasUnion) -> Self {
+                    AliasUnionUnion::AliasUnion(value)
+                }
+            }
+            #[cfg(test)]
+            impl AliasUnionUnion {
+                /// Returns one value per selector, in selector order, each
+                /// holding its member's default. Lets tests check that code
+                /// matching on the union handles every member.
+                pub fn all_selector_examples() -> Vec<Self> {
+                    vec![
+                        AliasUnionUnion::Selector0,
+                        AliasUnionUnion::AliasUint8(Default::default()),
+                        AliasUnionUnion::AliasUnion(None)
+                    ]
+                }
+            }
             #[derive(Debug, Copy, Clone)]
             pub struct AliasUnionUnionRef<'a> {
                 bytes: &'a [u8],
@@ -515,7 +777,7 @@ pub mod tests {
                             ),
                         );
                     }
-                    Ok(())
+                    ssz::check_union_body_len(&self.bytes[1..], 0)
                 }
                 pub fn as_selector1(&self) -> Result<u8, ssz::DecodeError> {
                     if self.selector() != 1u8 {
@@ -525,7 +787,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <crate::tests::input::test_common::AliasUint8 as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <crate::tests::input::test_common::AliasUint8 as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn as_selector2(&self) -> Result<Option<u8>, ssz::DecodeError> {
                     if self.selector() != 2u8 {
@@ -535,7 +805,35 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    {
+                        let bytes = &self.bytes[1..];
+                        ssz::view::reject_empty(bytes, 1)?;
+                        let selector = bytes[0];
+                        match selector {
+                            0 => ssz::check_union_body_len(&bytes[1..], 0).map(|()| None),
+                            1 => {
+                                if <u8 as ssz::Encode>::is_ssz_fixed_len() {
+                                    ssz::check_union_body_len(
+                                        &bytes[1..],
+                                        <u8 as ssz::Encode>::ssz_fixed_len(),
+                                    )?;
+                                }
+                                let body = &bytes[1..];
+                                let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(
+                                        body,
+                                    )
+                                    .map_err(|e| e.bounded(body.len()))?;
+                                Ok(Some(inner))
+                            }
+                            _ => {
+                                Err(
+                                    ssz::DecodeError::BytesInvalid(
+                                        format!("Invalid union selector for Option: {}", selector),
+                                    ),
+                                )
+                            }
+                        }
+                    }
                 }
                 pub fn to_owned(&self) -> AliasUnionUnion {
                     match self.selector() {
@@ -557,6 +855,17 @@ pub mod tests {
                         _ => panic!("Invalid union selector: {}", self.selector()),
                     }
                 }
+                /// Validates the selector and the selected member, recursing
+                /// into nested views and walking every list and vector, and
+                /// returns the first error.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    match self.selector() {
+                        0u8 => self.as_selector0(),
+                        1u8 => self.as_selector1().map(|_| ()),
+                        2u8 => self.as_selector2().map(|_| ()),
+                        selector => Err(ssz::DecodeError::UnionSelectorInvalid(selector)),
+                    }
+                }
             }
             impl<'a> ssz::view::DecodeView<'a> for AliasUnionUnionRef<'a> {
                 fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
@@ -709,9 +1018,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         5usize,
@@ -740,11 +1048,25 @@ pub mod tests {
                         None => return Ok(ssz_types::Optional::None),
                     };
                     let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(
-                        field_bytes,
-                    )?;
+                            field_bytes,
+                        )
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
             }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> StableContainerClassRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.a()?;
+                    Ok(())
+                }
+            }
             impl<'a> tree_hash::TreeHash for StableContainerClassRef<'a> {
                 fn tree_hash_type() -> tree_hash::TreeHashType {
                     tree_hash::TreeHashType::StableContainer
@@ -788,9 +1110,8 @@ pub mod tests {
                     use ssz::Decode;
                     let bitvector_bytes = bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         5usize,
@@ -827,6 +1148,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for StableContainerClassRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> StableContainerClassRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for StableContainerClassRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     false
@@ -857,6 +1191,65 @@ pub mod tests {
                         a: self.a().expect("valid view"),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut StableContainerClass,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.a = self.a()?;
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> StableContainerClassRef<'a> {
+                fn eq_owned(
+                    &self,
+                    other: &StableContainerClass,
+                ) -> Result<bool, ssz::DecodeError> {
+                    use ssz::Decode;
+                    let bitvector_bytes = self
+                        .bytes
+                        .get(..1usize)
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
+                        })?;
+                    let bitvector = ssz_types::BitVector::<
+                        5usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    if bitvector.get(0usize).unwrap_or(false) != other.a.is_some() {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<
+                            crate::tests::input::test_common::AliasUint8,
+                        > = self.a()?;
+                        value == other.a
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<StableContainerClass> for StableContainerClassRef<'a> {
+                fn eq(&self, other: &StableContainerClass) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<StableContainerClassRef<'a>> for StableContainerClass {
+                fn eq(&self, other: &StableContainerClassRef<'a>) -> bool {
+                    other == self
+                }
             }
         }
         pub mod test_import_2 {
@@ -868,6 +1261,13 @@ pub mod tests {
             use tree_hash::TreeHashDigest;
             use tree_hash_derive::TreeHash;
             use ssz::view::*;
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                161, 68, 188, 228, 252, 117, 112, 232, 131, 124, 165, 200, 193, 24, 228,
+                54, 198, 124, 174, 181, 138, 238, 101, 238, 220, 44, 140, 40, 62, 118,
+                11, 233,
+            ];
             #[derive(
                 std::clone::Clone,
                 std::fmt::Debug,
@@ -946,9 +1346,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         1usize,
@@ -978,15 +1377,15 @@ pub mod tests {
                         None => unreachable!("required field is always active"),
                     };
                     ssz::view::DecodeView::from_ssz_bytes(field_bytes)
+                        .map_err(|e| e.bounded(field_bytes.len()))
                 }
                 pub fn b(&self) -> Result<Optional<Option<u8>>, ssz::DecodeError> {
                     use ssz::Decode;
                     let bitvector_bytes = self
                         .bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         1usize,
@@ -1015,12 +1414,52 @@ pub mod tests {
                         Some(bytes) => bytes,
                         None => return Ok(ssz_types::Optional::None),
                     };
-                    let inner = <Option<
-                        u8,
-                    > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)?;
+                    let inner = {
+                        let bytes = field_bytes;
+                        ssz::view::reject_empty(bytes, 1)?;
+                        let selector = bytes[0];
+                        match selector {
+                            0 => ssz::check_union_body_len(&bytes[1..], 0).map(|()| None),
+                            1 => {
+                                if <u8 as ssz::Encode>::is_ssz_fixed_len() {
+                                    ssz::check_union_body_len(
+                                        &bytes[1..],
+                                        <u8 as ssz::Encode>::ssz_fixed_len(),
+                                    )?;
+                                }
+                                let body = &bytes[1..];
+                                let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(
+                                        body,
+                                    )
+                                    .map_err(|e| e.bounded(body.len()))?;
+                                Ok(Some(inner))
+                            }
+                            _ => {
+                                Err(
+                                    ssz::DecodeError::BytesInvalid(
+                                        format!("Invalid union selector for Option: {}", selector),
+                                    ),
+                                )
+                            }
+                        }
+                    }?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
             }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ProfileInehritanceRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.a()?;
+                    self.b()?;
+                    Ok(())
+                }
+            }
             impl<'a> tree_hash::TreeHash for ProfileInehritanceRef<'a> {
                 fn tree_hash_type() -> tree_hash::TreeHashType {
                     tree_hash::TreeHashType::Container
@@ -1070,9 +1509,8 @@ pub mod tests {
                     use ssz::Decode;
                     let bitvector_bytes = bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         1usize,
@@ -1100,6 +1538,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for ProfileInehritanceRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ProfileInehritanceRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for ProfileInehritanceRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     false
@@ -1138,6 +1589,84 @@ pub mod tests {
                         },
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut ProfileInehritance,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.a = self.a()?;
+                    out.b = match self.b()? {
+                        ssz_types::Optional::Some(inner) => {
+                            ssz_types::Optional::Some(
+                                ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                            )
+                        }
+                        ssz_types::Optional::None => ssz_types::Optional::None,
+                    };
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ProfileInehritanceRef<'a> {
+                fn eq_owned(
+                    &self,
+                    other: &ProfileInehritance,
+                ) -> Result<bool, ssz::DecodeError> {
+                    use ssz::Decode;
+                    let bitvector_bytes = self
+                        .bytes
+                        .get(..1usize)
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
+                        })?;
+                    let bitvector = ssz_types::BitVector::<
+                        1usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    if bitvector.get(0usize).unwrap_or(false) != other.b.is_some() {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: u8 = self.a()?;
+                        value == other.a
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<Option<u8>> = match self.b()? {
+                            ssz_types::Optional::Some(inner) => {
+                                ssz_types::Optional::Some(
+                                    ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                                )
+                            }
+                            ssz_types::Optional::None => ssz_types::Optional::None,
+                        };
+                        value == other.b
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<ProfileInehritance> for ProfileInehritanceRef<'a> {
+                fn eq(&self, other: &ProfileInehritance) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<ProfileInehritanceRef<'a>> for ProfileInehritance {
+                fn eq(&self, other: &ProfileInehritanceRef<'a>) -> bool {
+                    other == self
+                }
             }
         }
     }
@@ -11,7 +11,13 @@ pub mod tests {
             use tree_hash::TreeHashDigest;
             use tree_hash_derive::TreeHash;
             use ssz::view::*;
-            #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                104, 251, 29, 239, 13, 67, 148, 238, 113, 244, 57, 175, 182, 99, 186, 41,
+                176, 146, 194, 171, 226, 24, 152, 122, 174, 83, 128, 1, 204, 172, 40, 197,
+            ];
+            #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, Hash)]
             #[ssz(enum_behaviour = "union")]
             pub enum BigUnion {
                 Selector0(u8),
@@ -78,6 +84,76 @@ pub mod tests {
                     }
                 }
             }
+            impl std::fmt::Display for BigUnion {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    match self {
+                        BigUnion::Selector0(inner) => {
+                            write!(f, "BigUnion::Selector0({inner:?})")
+                        }
+                        BigUnion::Selector1(inner) => {
+                            write!(f, "BigUnion::Selector1({inner:?})")
+                        }
+                        BigUnion::Selector2(inner) => {
+                            write!(f, "BigUnion::Selector2({inner:?})")
+                        }
+                        BigUnion::Selector3(inner) => {
+                            write!(f, "BigUnion::Selector3({inner:?})")
+                        }
+                        BigUnion::Selector4(inner) => {
+                            write!(f, "BigUnion::Selector4({inner:?})")
+                        }
+                        BigUnion::Selector5(inner) => {
+                            write!(f, "BigUnion::Selector5({inner:?})")
+                        }
+                    }
+                }
+            }
+            impl From<u8> for BigUnion {
+                fn from(value: u8) -> Self {
+                    BigUnion::Selector0(value)
+                }
+            }
+            impl From<u16> for BigUnion {
+                fn from(value: u16) -> Self {
+                    BigUnion::Selector1(value)
+                }
+            }
+            impl From<u32> for BigUnion {
+                fn from(value: u32) -> Self {
+                    BigUnion::Selector2(value)
+                }
+            }
+            impl From<u64> for BigUnion {
+                fn from(value: u64) -> Self {
+                    BigUnion::Selector3(value)
+                }
+            }
+            impl From<U128> for BigUnion {
+                fn from(value: U128) -> Self {
+                    BigUnion::Selector4(value)
+                }
+            }
+            impl From<U256> for BigUnion {
+                fn from(value: U256) -> Self {
+                    BigUnion::Selector5(value)
+                }
+            }
+            #[cfg(test)]
+            impl BigUnion {
+                /// Returns one value per selector, in selector order, each
+                /// holding its member's default. Lets tests check that code
+                /// matching on the union handles every member.
+                pub fn all_selector_examples() -> Vec<Self> {
+                    vec![
+                        BigUnion::Selector0(Default::default()),
+                        BigUnion::Selector1(Default::default()),
+                        BigUnion::Selector2(Default::default()),
+                        BigUnion::Selector3(Default::default()),
+                        BigUnion::Selector4(Default::default()),
+                        BigUnion::Selector5(Default::default())
+                    ]
+                }
+            }
             #[derive(Debug, Copy, Clone)]
             pub struct BigUnionRef<'a> {
                 bytes: &'a [u8],
@@ -94,7 +170,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <u8 as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <u8 as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn as_selector1(&self) -> Result<u16, ssz::DecodeError> {
                     if self.selector() != 1u8 {
@@ -104,7 +188,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <u16 as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <u16 as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn as_selector2(&self) -> Result<u32, ssz::DecodeError> {
                     if self.selector() != 2u8 {
@@ -114,7 +206,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <u32 as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <u32 as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn as_selector3(&self) -> Result<u64, ssz::DecodeError> {
                     if self.selector() != 3u8 {
@@ -124,7 +224,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <u64 as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <u64 as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn as_selector4(&self) -> Result<U128, ssz::DecodeError> {
                     if self.selector() != 4u8 {
@@ -134,7 +242,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <U128 as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <U128 as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn as_selector5(&self) -> Result<U256, ssz::DecodeError> {
                     if self.selector() != 5u8 {
@@ -144,7 +260,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <U256 as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <U256 as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn to_owned(&self) -> BigUnion {
                     match self.selector() {
@@ -181,6 +305,20 @@ pub mod tests {
                         _ => panic!("Invalid union selector: {}", self.selector()),
                     }
                 }
+                /// Validates the selector and the selected member, recursing
+                /// into nested views and walking every list and vector, and
+                /// returns the first error.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    match self.selector() {
+                        0u8 => self.as_selector0().map(|_| ()),
+                        1u8 => self.as_selector1().map(|_| ()),
+                        2u8 => self.as_selector2().map(|_| ()),
+                        3u8 => self.as_selector3().map(|_| ()),
+                        4u8 => self.as_selector4().map(|_| ()),
+                        5u8 => self.as_selector5().map(|_| ()),
+                        selector => Err(ssz::DecodeError::UnionSelectorInvalid(selector)),
+                    }
+                }
             }
             impl<'a> ssz::view::DecodeView<'a> for BigUnionRef<'a> {
                 fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
@@ -277,7 +415,7 @@ pub mod tests {
                     }
                 }
             }
-            #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+            #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, Hash)]
             #[ssz(enum_behaviour = "union")]
             pub enum MixedUnion {
                 Selector0(u8),
@@ -328,6 +466,58 @@ pub mod tests {
                     }
                 }
             }
+            impl std::fmt::Display for MixedUnion {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    match self {
+                        MixedUnion::Selector0(inner) => {
+                            write!(f, "MixedUnion::Selector0({inner:?})")
+                        }
+                        MixedUnion::Selector1(inner) => {
+                            write!(f, "MixedUnion::Selector1({inner:?})")
+                        }
+                        MixedUnion::Selector2(inner) => {
+                            write!(f, "MixedUnion::Selector2({inner:?})")
+                        }
+                        MixedUnion::Selector3(inner) => {
+                            write!(f, "MixedUnion::Selector3({inner:?})")
+                        }
+                    }
+                }
+            }
+            impl From<u8> for MixedUnion {
+                fn from(value: u8) -> Self {
+                    MixedUnion::Selector0(value)
+                }
+            }
+            impl From<VariableList<u8, 5usize>> for MixedUnion {
+                fn from(value: VariableList<u8, 5usize>) -> Self {
+                    MixedUnion::Selector1(value)
+                }
+            }
+            impl From<FixedVector<u16, 3usize>> for MixedUnion {
+                fn from(value: FixedVector<u16, 3usize>) -> Self {
+                    MixedUnion::Selector2(value)
+                }
+            }
+            impl From<BitVector<8usize>> for MixedUnion {
+                fn from(value: BitVector<8usize>) -> Self {
+                    MixedUnion::Selector3(value)
+                }
+            }
+            #[cfg(test)]
+            impl MixedUnion {
+                /// Returns one value per selector, in selector order, each
+                /// holding its member's default. Lets tests check that code
+                /// matching on the union handles every member.
+                pub fn all_selector_examples() -> Vec<Self> {
+                    vec![
+                        MixedUnion::Selector0(Default::default()),
+                        MixedUnion::Selector1(Default::default()),
+                        MixedUnion::Selector2(ssz_types::FixedVector::from_elem(Default::default())),
+                        MixedUnion::Selector3(Default::default())
+                    ]
+                }
+            }
             #[derive(Debug, Copy, Clone)]
             pub struct MixedUnionRef<'a> {
                 bytes: &'a [u8],
@@ -344,7 +534,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <u8 as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <u8 as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn as_selector1(
                     &self,
@@ -356,7 +554,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <VariableList<u8, 5usize> as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <VariableList<u8, 5usize> as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn as_selector2(
                     &self,
@@ -368,7 +574,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <FixedVector<u16, 3usize> as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <FixedVector<u16, 3usize> as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn as_selector3(
                     &self,
@@ -380,7 +594,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <BitVector<8usize> as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <BitVector<8usize> as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn to_owned(&self) -> MixedUnion {
                     match self.selector() {
@@ -410,6 +632,24 @@ pub mod tests {
                         _ => panic!("Invalid union selector: {}", self.selector()),
                     }
                 }
+                /// Validates the selector and the selected member, recursing
+                /// into nested views and walking every list and vector, and
+                /// returns the first error.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    match self.selector() {
+                        0u8 => self.as_selector0().map(|_| ()),
+                        1u8 => self.as_selector1().map(|_| ()),
+                        2u8 => {
+                            let value = self.as_selector2()?;
+                            for item in value.iter() {
+                                item?;
+                            }
+                            Ok(())
+                        }
+                        3u8 => self.as_selector3().map(|_| ()),
+                        selector => Err(ssz::DecodeError::UnionSelectorInvalid(selector)),
+                    }
+                }
             }
             impl<'a> ssz::view::DecodeView<'a> for MixedUnionRef<'a> {
                 fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
@@ -486,7 +726,7 @@ pub mod tests {
                     }
                 }
             }
-            #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+            #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, Hash)]
             #[ssz(enum_behaviour = "union")]
             pub enum SameTypeUnion {
                 Selector0(u8),
@@ -537,6 +777,38 @@ pub mod tests {
                     }
                 }
             }
+            impl std::fmt::Display for SameTypeUnion {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    match self {
+                        SameTypeUnion::Selector0(inner) => {
+                            write!(f, "SameTypeUnion::Selector0({inner:?})")
+                        }
+                        SameTypeUnion::Selector1(inner) => {
+                            write!(f, "SameTypeUnion::Selector1({inner:?})")
+                        }
+                        SameTypeUnion::Selector2(inner) => {
+                            write!(f, "SameTypeUnion::Selector2({inner:?})")
+                        }
+                        SameTypeUnion::Selector3(inner) => {
+                            write!(f, "SameTypeUnion::Selector3({inner:?})")
+                        }
+                    }
+                }
+            }
+            #[cfg(test)]
+            impl SameTypeUnion {
+                /// Returns one value per selector, in selector order, each
+                /// holding its member's default. Lets tests check that code
+                /// matching on the union handles every member.
+                pub fn all_selector_examples() -> Vec<Self> {
+                    vec![
+                        SameTypeUnion::Selector0(Default::default()),
+                        SameTypeUnion::Selector1(Default::default()),
+                        SameTypeUnion::Selector2(Default::default()),
+                        SameTypeUnion::Selector3(Default::default())
+                    ]
+                }
+            }
             #[derive(Debug, Copy, Clone)]
             pub struct SameTypeUnionRef<'a> {
                 bytes: &'a [u8],
@@ -553,7 +825,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <u8 as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <u8 as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn as_selector1(&self) -> Result<u8, ssz::DecodeError> {
                     if self.selector() != 1u8 {
@@ -563,7 +843,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <u8 as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <u8 as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn as_selector2(&self) -> Result<u8, ssz::DecodeError> {
                     if self.selector() != 2u8 {
@@ -573,7 +861,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <u8 as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <u8 as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn as_selector3(&self) -> Result<u8, ssz::DecodeError> {
                     if self.selector() != 3u8 {
@@ -583,7 +879,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <u8 as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <u8 as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn to_owned(&self) -> SameTypeUnion {
                     match self.selector() {
@@ -610,6 +914,18 @@ pub mod tests {
                         _ => panic!("Invalid union selector: {}", self.selector()),
                     }
                 }
+                /// Validates the selector and the selected member, recursing
+                /// into nested views and walking every list and vector, and
+                /// returns the first error.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    match self.selector() {
+                        0u8 => self.as_selector0().map(|_| ()),
+                        1u8 => self.as_selector1().map(|_| ()),
+                        2u8 => self.as_selector2().map(|_| ()),
+                        3u8 => self.as_selector3().map(|_| ()),
+                        selector => Err(ssz::DecodeError::UnionSelectorInvalid(selector)),
+                    }
+                }
             }
             impl<'a> ssz::view::DecodeView<'a> for SameTypeUnionRef<'a> {
                 fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
@@ -737,6 +1053,32 @@ pub mod tests {
                         .expect("tree hash derive should not have a remaining buffer")
                 }
             }
+            impl tree_hash::TreeHashLeaves for ContainerWithBigUnions {
+                fn tree_hash_leaf_count() -> usize {
+                    3usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.big)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.same)
+                        }
+                        2usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.mixed)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 3usize
+                            )
+                        }
+                    }
+                }
+            }
             /// Zero-copy view over [`ContainerWithBigUnions`].
             ///
             /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
@@ -775,6 +1117,7 @@ pub mod tests {
                         0usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn same(&self) -> Result<SameTypeUnionRef<'a>, ssz::DecodeError> {
                     let bytes = ssz::layout::read_field_bytes(
@@ -796,6 +1139,7 @@ pub mod tests {
                         1usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn mixed(&self) -> Result<MixedUnionRef<'a>, ssz::DecodeError> {
                     let bytes = ssz::layout::read_field_bytes(
@@ -817,6 +1161,25 @@ pub mod tests {
                         2usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ContainerWithBigUnionsRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    let value = self.big()?;
+                    value.validate_deep()?;
+                    let value = self.same()?;
+                    value.validate_deep()?;
+                    let value = self.mixed()?;
+                    value.validate_deep()?;
+                    Ok(())
                 }
             }
             impl<'a> tree_hash::TreeHash for ContainerWithBigUnionsRef<'a> {
@@ -878,6 +1241,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for ContainerWithBigUnionsRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ContainerWithBigUnionsRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for ContainerWithBigUnionsRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     usize::from(!<BigUnion as ssz::Encode>::is_ssz_fixed_len())
@@ -929,6 +1305,84 @@ pub mod tests {
                         },
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut ContainerWithBigUnions,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.big = {
+                        let view = self.big()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    out.same = {
+                        let view = self.same()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    out.mixed = {
+                        let view = self.mixed()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ContainerWithBigUnionsRef<'a> {
+                fn eq_owned(
+                    &self,
+                    other: &ContainerWithBigUnions,
+                ) -> Result<bool, ssz::DecodeError> {
+                    if !({
+                        let value: BigUnion = {
+                            let view = self.big()?;
+                            ssz_types::view::ToOwnedSsz::to_owned(&view)
+                        };
+                        value == other.big
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: SameTypeUnion = {
+                            let view = self.same()?;
+                            ssz_types::view::ToOwnedSsz::to_owned(&view)
+                        };
+                        value == other.same
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: MixedUnion = {
+                            let view = self.mixed()?;
+                            ssz_types::view::ToOwnedSsz::to_owned(&view)
+                        };
+                        value == other.mixed
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<ContainerWithBigUnions>
+            for ContainerWithBigUnionsRef<'a> {
+                fn eq(&self, other: &ContainerWithBigUnions) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<ContainerWithBigUnionsRef<'a>>
+            for ContainerWithBigUnions {
+                fn eq(&self, other: &ContainerWithBigUnionsRef<'a>) -> bool {
+                    other == self
+                }
             }
         }
     }
@@ -11,6 +11,12 @@ pub mod tests {
             use tree_hash::TreeHashDigest;
             use tree_hash_derive::TreeHash;
             use ssz::view::*;
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                120, 81, 125, 197, 38, 0, 62, 188, 93, 114, 87, 250, 247, 25, 106, 146,
+                149, 134, 225, 240, 36, 133, 96, 117, 201, 78, 233, 136, 208, 240, 31, 87,
+            ];
             #[allow(dead_code, reason = "generated code using ssz-gen")]
             pub const SIZE_1: u64 = 10u64;
             #[allow(dead_code, reason = "generated code using ssz-gen")]
@@ -84,6 +90,35 @@ pub mod tests {
                         .expect("tree hash derive should not have a remaining buffer")
                 }
             }
+            impl tree_hash::TreeHashLeaves for NestedAliasContainer {
+                fn tree_hash_leaf_count() -> usize {
+                    4usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.field1)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.field2)
+                        }
+                        2usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.field3)
+                        }
+                        3usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.field4)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 4usize
+                            )
+                        }
+                    }
+                }
+            }
             /// Zero-copy view over [`NestedAliasContainer`].
             ///
             /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
@@ -126,6 +161,7 @@ pub mod tests {
                         0usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn field2(
                     &self,
@@ -156,6 +192,7 @@ pub mod tests {
                         1usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn field3(&self) -> Result<BytesRef<'a, 10usize>, ssz::DecodeError> {
                     let bytes = ssz::layout::read_field_bytes(
@@ -181,6 +218,7 @@ pub mod tests {
                         2usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn field4(
                     &self,
@@ -211,6 +249,29 @@ pub mod tests {
                         3usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> NestedAliasContainerRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.field1()?;
+                    let value = self.field2()?;
+                    for item in value.iter() {
+                        item?;
+                    }
+                    self.field3()?;
+                    let value = self.field4()?;
+                    for item in value.iter() {
+                        item?;
+                    }
+                    Ok(())
                 }
             }
             impl<'a> tree_hash::TreeHash for NestedAliasContainerRef<'a> {
@@ -283,6 +344,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for NestedAliasContainerRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> NestedAliasContainerRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for NestedAliasContainerRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     usize::from(!<D as ssz::Encode>::is_ssz_fixed_len())
@@ -340,6 +414,91 @@ pub mod tests {
                             .expect("valid view"),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut NestedAliasContainer,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    let view = self.field1()?;
+                    out.field1.clear();
+                    out.field1
+                        .try_extend_from_slice(view.as_bytes())
+                        .expect("valid view");
+                    let view = self.field2()?;
+                    for (slot, item) in out.field2.iter_mut().zip(view.iter()) {
+                        *slot = ssz_types::view::ToOwnedSsz::to_owned(&item?);
+                    }
+                    let view = self.field3()?;
+                    out.field3.clear();
+                    out.field3
+                        .try_extend_from_slice(view.as_bytes())
+                        .expect("valid view");
+                    let view = self.field4()?;
+                    for (slot, item) in out.field4.iter_mut().zip(view.iter()) {
+                        *slot = ssz_types::view::ToOwnedSsz::to_owned(&item?);
+                    }
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> NestedAliasContainerRef<'a> {
+                fn eq_owned(
+                    &self,
+                    other: &NestedAliasContainer,
+                ) -> Result<bool, ssz::DecodeError> {
+                    if !({
+                        let value: D = ssz_types::VariableList::new(
+                                self.field1()?.to_owned(),
+                            )
+                            .expect("valid view");
+                        value == other.field1
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: E = self.field2()?.to_owned().expect("valid view");
+                        value == other.field2
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: F = ssz_types::VariableList::new(
+                                self.field3()?.to_owned(),
+                            )
+                            .expect("valid view");
+                        value == other.field3
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: G = self.field4()?.to_owned().expect("valid view");
+                        value == other.field4
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<NestedAliasContainer> for NestedAliasContainerRef<'a> {
+                fn eq(&self, other: &NestedAliasContainer) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<NestedAliasContainerRef<'a>> for NestedAliasContainer {
+                fn eq(&self, other: &NestedAliasContainerRef<'a>) -> bool {
+                    other == self
+                }
             }
         }
     }
@@ -11,6 +11,12 @@ pub mod tests {
             use tree_hash::TreeHashDigest;
             use tree_hash_derive::TreeHash;
             use ssz::view::*;
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                12, 119, 107, 233, 193, 78, 205, 120, 96, 247, 123, 174, 205, 183, 79,
+                85, 5, 175, 90, 153, 30, 164, 251, 112, 92, 50, 158, 131, 3, 218, 96, 76,
+            ];
             #[allow(dead_code, reason = "generated code using ssz-gen")]
             pub const MAX_TAIL: u64 = 16u64;
             /// A fixed-size inner container (1 byte).
@@ -26,6 +32,12 @@ pub mod tests {
             pub struct FixedInner {
                 pub tag: u8,
             }
+            impl FixedInner {
+                /// Byte offset of each field in the SSZ encoding, in declaration order.
+                pub const FIELD_OFFSETS: &[usize] = &[0];
+                /// Byte size of each field in the SSZ encoding, in declaration order.
+                pub const FIELD_SIZES: &[usize] = &[1];
+            }
             impl tree_hash::TreeHash for FixedInner {
                 fn tree_hash_type() -> tree_hash::TreeHashType {
                     tree_hash::TreeHashType::Container
@@ -50,6 +62,26 @@ pub mod tests {
                         .expect("tree hash derive should not have a remaining buffer")
                 }
             }
+            impl tree_hash::TreeHashLeaves for FixedInner {
+                fn tree_hash_leaf_count() -> usize {
+                    1usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.tag)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 1usize
+                            )
+                        }
+                    }
+                }
+            }
             /// Zero-copy view over [`FixedInner`].
             ///
             /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
@@ -69,17 +101,22 @@ pub mod tests {
             #[allow(dead_code, reason = "generated code using ssz-gen")]
             impl<'a> FixedInnerRef<'a> {
                 pub fn tag(&self) -> Result<u8, ssz::DecodeError> {
-                    let bytes = ssz::layout::read_field_bytes(
-                        self.bytes,
-                        &[
-                            (
-                                <u8 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u8 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                        ],
-                        0usize,
-                    )?;
+                    let bytes = &self.bytes[0..1];
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> FixedInnerRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.tag()?;
+                    Ok(())
                 }
             }
             impl<'a> tree_hash::TreeHash for FixedInnerRef<'a> {
@@ -119,6 +156,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for FixedInnerRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> FixedInnerRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for FixedInnerRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     usize::from(!<u8 as ssz::Encode>::is_ssz_fixed_len()) == 0
@@ -152,6 +202,50 @@ pub mod tests {
                         tag: self.tag().expect("valid view"),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut FixedInner,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.tag = self.tag()?;
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> FixedInnerRef<'a> {
+                fn eq_owned(
+                    &self,
+                    other: &FixedInner,
+                ) -> Result<bool, ssz::DecodeError> {
+                    if !({
+                        let value: u8 = self.tag()?;
+                        value == other.tag
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<FixedInner> for FixedInnerRef<'a> {
+                fn eq(&self, other: &FixedInner) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<FixedInnerRef<'a>> for FixedInner {
+                fn eq(&self, other: &FixedInnerRef<'a>) -> bool {
+                    other == self
+                }
             }
             /// A larger fixed-size inner container (8 bytes).
             #[derive(
@@ -167,6 +261,12 @@ pub mod tests {
                 pub x: u32,
                 pub y: u32,
             }
+            impl FixedPair {
+                /// Byte offset of each field in the SSZ encoding, in declaration order.
+                pub const FIELD_OFFSETS: &[usize] = &[0, 4];
+                /// Byte size of each field in the SSZ encoding, in declaration order.
+                pub const FIELD_SIZES: &[usize] = &[4, 4];
+            }
             impl tree_hash::TreeHash for FixedPair {
                 fn tree_hash_type() -> tree_hash::TreeHashType {
                     tree_hash::TreeHashType::Container
@@ -197,6 +297,29 @@ pub mod tests {
                         .expect("tree hash derive should not have a remaining buffer")
                 }
             }
+            impl tree_hash::TreeHashLeaves for FixedPair {
+                fn tree_hash_leaf_count() -> usize {
+                    2usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.x)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.y)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 2usize
+                            )
+                        }
+                    }
+                }
+            }
             /// Zero-copy view over [`FixedPair`].
             ///
             /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
@@ -216,38 +339,28 @@ pub mod tests {
             #[allow(dead_code, reason = "generated code using ssz-gen")]
             impl<'a> FixedPairRef<'a> {
                 pub fn x(&self) -> Result<u32, ssz::DecodeError> {
-                    let bytes = ssz::layout::read_field_bytes(
-                        self.bytes,
-                        &[
-                            (
-                                <u32 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u32 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                            (
-                                <u32 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u32 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                        ],
-                        0usize,
-                    )?;
+                    let bytes = &self.bytes[0..4];
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn y(&self) -> Result<u32, ssz::DecodeError> {
-                    let bytes = ssz::layout::read_field_bytes(
-                        self.bytes,
-                        &[
-                            (
-                                <u32 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u32 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                            (
-                                <u32 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u32 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                        ],
-                        1usize,
-                    )?;
+                    let bytes = &self.bytes[4..8];
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> FixedPairRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.x()?;
+                    self.y()?;
+                    Ok(())
                 }
             }
             impl<'a> tree_hash::TreeHash for FixedPairRef<'a> {
@@ -298,6 +411,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for FixedPairRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> FixedPairRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for FixedPairRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     usize::from(!<u32 as ssz::Encode>::is_ssz_fixed_len())
@@ -334,6 +460,54 @@ pub mod tests {
                         y: self.y().expect("valid view"),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut FixedPair,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.x = self.x()?;
+                    out.y = self.y()?;
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> FixedPairRef<'a> {
+                fn eq_owned(&self, other: &FixedPair) -> Result<bool, ssz::DecodeError> {
+                    if !({
+                        let value: u32 = self.x()?;
+                        value == other.x
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: u32 = self.y()?;
+                        value == other.y
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<FixedPair> for FixedPairRef<'a> {
+                fn eq(&self, other: &FixedPair) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<FixedPairRef<'a>> for FixedPair {
+                fn eq(&self, other: &FixedPairRef<'a>) -> bool {
+                    other == self
+                }
             }
             /// Mixed container: fixed containers inline, one variable tail.
             #[derive(
@@ -393,6 +567,35 @@ pub mod tests {
                         .expect("tree hash derive should not have a remaining buffer")
                 }
             }
+            impl tree_hash::TreeHashLeaves for MixedOuter {
+                fn tree_hash_leaf_count() -> usize {
+                    4usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.inner)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.count)
+                        }
+                        2usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.pair)
+                        }
+                        3usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.tail)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 4usize
+                            )
+                        }
+                    }
+                }
+            }
             /// Zero-copy view over [`MixedOuter`].
             ///
             /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
@@ -438,6 +641,7 @@ pub mod tests {
                         0usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn count(&self) -> Result<u32, ssz::DecodeError> {
                     let bytes = ssz::layout::read_field_bytes(
@@ -466,6 +670,7 @@ pub mod tests {
                         1usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn pair(&self) -> Result<FixedPairRef<'a>, ssz::DecodeError> {
                     let bytes = ssz::layout::read_field_bytes(
@@ -494,6 +699,7 @@ pub mod tests {
                         2usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn tail(&self) -> Result<BytesRef<'a, 16usize>, ssz::DecodeError> {
                     let bytes = ssz::layout::read_field_bytes(
@@ -522,6 +728,25 @@ pub mod tests {
                         3usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> MixedOuterRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    let value = self.inner()?;
+                    value.validate_deep()?;
+                    self.count()?;
+                    let value = self.pair()?;
+                    value.validate_deep()?;
+                    self.tail()?;
+                    Ok(())
                 }
             }
             impl<'a> tree_hash::TreeHash for MixedOuterRef<'a> {
@@ -597,6 +822,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for MixedOuterRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> MixedOuterRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for MixedOuterRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     usize::from(!<FixedInner as ssz::Encode>::is_ssz_fixed_len())
@@ -653,6 +891,76 @@ pub mod tests {
                             .expect("valid view"),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut MixedOuter,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.inner = {
+                        let view = self.inner()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    out.count = self.count()?;
+                    out.pair = {
+                        let view = self.pair()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    let view = self.tail()?;
+                    out.tail.clear();
+                    out.tail.try_extend_from_slice(view.as_bytes()).expect("valid view");
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> MixedOuterRef<'a> {
+                fn eq_owned(
+                    &self,
+                    other: &MixedOuter,
+                ) -> Result<bool, ssz::DecodeError> {
+                    if !(self.inner()? == other.inner) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: u32 = self.count()?;
+                        value == other.count
+                    }) {
+                        return Ok(false);
+                    }
+                    if !(self.pair()? == other.pair) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: VariableList<u8, 16usize> = ssz_types::VariableList::new(
+                                self.tail()?.to_owned(),
+                            )
+                            .expect("valid view");
+                        value == other.tail
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<MixedOuter> for MixedOuterRef<'a> {
+                fn eq(&self, other: &MixedOuter) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<MixedOuterRef<'a>> for MixedOuter {
+                fn eq(&self, other: &MixedOuterRef<'a>) -> bool {
+                    other == self
+                }
             }
             /// Fully fixed container nesting fixed containers.
             #[derive(
@@ -668,6 +976,12 @@ pub mod tests {
                 pub inner: FixedInner,
                 pub pair: FixedPair,
             }
+            impl FixedOuter {
+                /// Byte offset of each field in the SSZ encoding, in declaration order.
+                pub const FIELD_OFFSETS: &[usize] = &[0, 1];
+                /// Byte size of each field in the SSZ encoding, in declaration order.
+                pub const FIELD_SIZES: &[usize] = &[1, 8];
+            }
             impl tree_hash::TreeHash for FixedOuter {
                 fn tree_hash_type() -> tree_hash::TreeHashType {
                     tree_hash::TreeHashType::Container
@@ -698,6 +1012,29 @@ pub mod tests {
                         .expect("tree hash derive should not have a remaining buffer")
                 }
             }
+            impl tree_hash::TreeHashLeaves for FixedOuter {
+                fn tree_hash_leaf_count() -> usize {
+                    2usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.inner)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.pair)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 2usize
+                            )
+                        }
+                    }
+                }
+            }
             /// Zero-copy view over [`FixedOuter`].
             ///
             /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
@@ -717,38 +1054,30 @@ pub mod tests {
             #[allow(dead_code, reason = "generated code using ssz-gen")]
             impl<'a> FixedOuterRef<'a> {
                 pub fn inner(&self) -> Result<FixedInnerRef<'a>, ssz::DecodeError> {
-                    let bytes = ssz::layout::read_field_bytes(
-                        self.bytes,
-                        &[
-                            (
-                                <FixedInner as ssz::Encode>::is_ssz_fixed_len(),
-                                <FixedInner as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                            (
-                                <FixedPair as ssz::Encode>::is_ssz_fixed_len(),
-                                <FixedPair as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                        ],
-                        0usize,
-                    )?;
+                    let bytes = &self.bytes[0..1];
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn pair(&self) -> Result<FixedPairRef<'a>, ssz::DecodeError> {
-                    let bytes = ssz::layout::read_field_bytes(
-                        self.bytes,
-                        &[
-                            (
-                                <FixedInner as ssz::Encode>::is_ssz_fixed_len(),
-                                <FixedInner as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                            (
-                                <FixedPair as ssz::Encode>::is_ssz_fixed_len(),
-                                <FixedPair as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                        ],
-                        1usize,
-                    )?;
+                    let bytes = &self.bytes[1..9];
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> FixedOuterRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    let value = self.inner()?;
+                    value.validate_deep()?;
+                    let value = self.pair()?;
+                    value.validate_deep()?;
+                    Ok(())
                 }
             }
             impl<'a> tree_hash::TreeHash for FixedOuterRef<'a> {
@@ -799,6 +1128,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for FixedOuterRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> FixedOuterRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for FixedOuterRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     usize::from(!<FixedInner as ssz::Encode>::is_ssz_fixed_len())
@@ -842,6 +1184,57 @@ pub mod tests {
                         },
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut FixedOuter,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.inner = {
+                        let view = self.inner()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    out.pair = {
+                        let view = self.pair()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> FixedOuterRef<'a> {
+                fn eq_owned(
+                    &self,
+                    other: &FixedOuter,
+                ) -> Result<bool, ssz::DecodeError> {
+                    if !(self.inner()? == other.inner) {
+                        return Ok(false);
+                    }
+                    if !(self.pair()? == other.pair) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<FixedOuter> for FixedOuterRef<'a> {
+                fn eq(&self, other: &FixedOuter) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<FixedOuterRef<'a>> for FixedOuter {
+                fn eq(&self, other: &FixedOuterRef<'a>) -> bool {
+                    other == self
+                }
             }
             /// Basic-fields-only container: decodes fine either way, but exercises the view
             /// TreeHash leaf packing.
@@ -858,6 +1251,12 @@ pub mod tests {
                 pub tag: u8,
                 pub b: u32,
             }
+            impl BasicPair {
+                /// Byte offset of each field in the SSZ encoding, in declaration order.
+                pub const FIELD_OFFSETS: &[usize] = &[0, 1];
+                /// Byte size of each field in the SSZ encoding, in declaration order.
+                pub const FIELD_SIZES: &[usize] = &[1, 4];
+            }
             impl tree_hash::TreeHash for BasicPair {
                 fn tree_hash_type() -> tree_hash::TreeHashType {
                     tree_hash::TreeHashType::Container
@@ -888,6 +1287,29 @@ pub mod tests {
                         .expect("tree hash derive should not have a remaining buffer")
                 }
             }
+            impl tree_hash::TreeHashLeaves for BasicPair {
+                fn tree_hash_leaf_count() -> usize {
+                    2usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.tag)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.b)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 2usize
+                            )
+                        }
+                    }
+                }
+            }
             /// Zero-copy view over [`BasicPair`].
             ///
             /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
@@ -907,38 +1329,28 @@ pub mod tests {
             #[allow(dead_code, reason = "generated code using ssz-gen")]
             impl<'a> BasicPairRef<'a> {
                 pub fn tag(&self) -> Result<u8, ssz::DecodeError> {
-                    let bytes = ssz::layout::read_field_bytes(
-                        self.bytes,
-                        &[
-                            (
-                                <u8 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u8 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                            (
-                                <u32 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u32 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                        ],
-                        0usize,
-                    )?;
+                    let bytes = &self.bytes[0..1];
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn b(&self) -> Result<u32, ssz::DecodeError> {
-                    let bytes = ssz::layout::read_field_bytes(
-                        self.bytes,
-                        &[
-                            (
-                                <u8 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u8 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                            (
-                                <u32 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u32 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                        ],
-                        1usize,
-                    )?;
+                    let bytes = &self.bytes[1..5];
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> BasicPairRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.tag()?;
+                    self.b()?;
+                    Ok(())
                 }
             }
             impl<'a> tree_hash::TreeHash for BasicPairRef<'a> {
@@ -989,6 +1401,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for BasicPairRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> BasicPairRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for BasicPairRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     usize::from(!<u8 as ssz::Encode>::is_ssz_fixed_len())
@@ -1025,6 +1450,54 @@ pub mod tests {
                         b: self.b().expect("valid view"),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut BasicPair,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.tag = self.tag()?;
+                    out.b = self.b()?;
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> BasicPairRef<'a> {
+                fn eq_owned(&self, other: &BasicPair) -> Result<bool, ssz::DecodeError> {
+                    if !({
+                        let value: u8 = self.tag()?;
+                        value == other.tag
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: u32 = self.b()?;
+                        value == other.b
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<BasicPair> for BasicPairRef<'a> {
+                fn eq(&self, other: &BasicPair) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<BasicPairRef<'a>> for BasicPair {
+                fn eq(&self, other: &BasicPairRef<'a>) -> bool {
+                    other == self
+                }
             }
             /// Variable-size field before a fixed-size one: the offset entry sits at the
             /// variable field's own position in the fixed portion, not at the end.
@@ -1073,6 +1546,31 @@ pub mod tests {
                         .expect("tree hash derive should not have a remaining buffer")
                 }
             }
+            impl tree_hash::TreeHashLeaves for VarThenFixed {
+                fn tree_hash_leaf_count() -> usize {
+                    2usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<
+                                H,
+                            >(&self.entries)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.name)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 2usize
+                            )
+                        }
+                    }
+                }
+            }
             /// Zero-copy view over [`VarThenFixed`].
             ///
             /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
@@ -1112,6 +1610,7 @@ pub mod tests {
                         0usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn name(&self) -> Result<u32, ssz::DecodeError> {
                     let bytes = ssz::layout::read_field_bytes(
@@ -1132,6 +1631,21 @@ pub mod tests {
                         1usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> VarThenFixedRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.entries()?;
+                    self.name()?;
+                    Ok(())
                 }
             }
             impl<'a> tree_hash::TreeHash for VarThenFixedRef<'a> {
@@ -1185,6 +1699,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for VarThenFixedRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> VarThenFixedRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for VarThenFixedRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     usize::from(
@@ -1225,6 +1752,64 @@ pub mod tests {
                         name: self.name().expect("valid view"),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut VarThenFixed,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    let view = self.entries()?;
+                    out.entries.clear();
+                    out.entries
+                        .try_extend_from_slice(view.as_bytes())
+                        .expect("valid view");
+                    out.name = self.name()?;
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> VarThenFixedRef<'a> {
+                fn eq_owned(
+                    &self,
+                    other: &VarThenFixed,
+                ) -> Result<bool, ssz::DecodeError> {
+                    if !({
+                        let value: VariableList<u8, 16usize> = ssz_types::VariableList::new(
+                                self.entries()?.to_owned(),
+                            )
+                            .expect("valid view");
+                        value == other.entries
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: u32 = self.name()?;
+                        value == other.name
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<VarThenFixed> for VarThenFixedRef<'a> {
+                fn eq(&self, other: &VarThenFixed) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<VarThenFixedRef<'a>> for VarThenFixed {
+                fn eq(&self, other: &VarThenFixedRef<'a>) -> bool {
+                    other == self
+                }
             }
             /// Variable fields interleaved with fixed fields.
             #[derive(
@@ -1277,6 +1862,32 @@ pub mod tests {
                         .expect("tree hash derive should not have a remaining buffer")
                 }
             }
+            impl tree_hash::TreeHashLeaves for Interleaved {
+                fn tree_hash_leaf_count() -> usize {
+                    3usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.head)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.mid)
+                        }
+                        2usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.tail)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 3usize
+                            )
+                        }
+                    }
+                }
+            }
             /// Zero-copy view over [`Interleaved`].
             ///
             /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
@@ -1321,6 +1932,7 @@ pub mod tests {
                         0usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn mid(&self) -> Result<u8, ssz::DecodeError> {
                     let bytes = ssz::layout::read_field_bytes(
@@ -1348,6 +1960,7 @@ pub mod tests {
                         1usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn tail(&self) -> Result<BytesRef<'a, 16usize>, ssz::DecodeError> {
                     let bytes = ssz::layout::read_field_bytes(
@@ -1375,6 +1988,22 @@ pub mod tests {
                         2usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> InterleavedRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.head()?;
+                    self.mid()?;
+                    self.tail()?;
+                    Ok(())
                 }
             }
             impl<'a> tree_hash::TreeHash for InterleavedRef<'a> {
@@ -1442,6 +2071,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for InterleavedRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> InterleavedRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for InterleavedRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     usize::from(
@@ -1493,6 +2135,74 @@ pub mod tests {
                             .expect("valid view"),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Interleaved,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    let view = self.head()?;
+                    out.head.clear();
+                    out.head.try_extend_from_slice(view.as_bytes()).expect("valid view");
+                    out.mid = self.mid()?;
+                    let view = self.tail()?;
+                    out.tail.clear();
+                    out.tail.try_extend_from_slice(view.as_bytes()).expect("valid view");
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> InterleavedRef<'a> {
+                fn eq_owned(
+                    &self,
+                    other: &Interleaved,
+                ) -> Result<bool, ssz::DecodeError> {
+                    if !({
+                        let value: VariableList<u8, 16usize> = ssz_types::VariableList::new(
+                                self.head()?.to_owned(),
+                            )
+                            .expect("valid view");
+                        value == other.head
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: u8 = self.mid()?;
+                        value == other.mid
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: VariableList<u8, 16usize> = ssz_types::VariableList::new(
+                                self.tail()?.to_owned(),
+                            )
+                            .expect("valid view");
+                        value == other.tail
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<Interleaved> for InterleavedRef<'a> {
+                fn eq(&self, other: &Interleaved) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<InterleavedRef<'a>> for Interleaved {
+                fn eq(&self, other: &InterleavedRef<'a>) -> bool {
+                    other == self
+                }
             }
         }
     }
@@ -0,0 +1,455 @@
+pub mod tests {
+    #![allow(unused_imports, reason = "generated code using ssz-gen")]
+    pub mod input {
+        #![allow(unused_imports, reason = "generated code using ssz-gen")]
+        pub mod test_optional_field_container {
+            #![allow(unused_imports, reason = "generated code using ssz-gen")]
+            use ssz_types::*;
+            use ssz_types::view::{FixedVectorRef, VariableListRef};
+            use ssz_primitives::{U128, U256};
+            use ssz_derive::{Encode, Decode};
+            use tree_hash::TreeHashDigest;
+            use tree_hash_derive::TreeHash;
+            use ssz::view::*;
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                80, 35, 237, 105, 178, 85, 156, 82, 24, 8, 14, 108, 119, 132, 6, 92, 164,
+                19, 80, 78, 60, 245, 6, 33, 117, 43, 74, 246, 234, 134, 232, 104,
+            ];
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                ssz_derive::Encode,
+                ssz_derive::Decode
+            )]
+            #[ssz(struct_behaviour = "container")]
+            pub struct Reading {
+                pub sensor: u16,
+                pub value: Option<u8>,
+                pub label: Option<VariableList<u8, 16usize>>,
+            }
+            impl tree_hash::TreeHash for Reading {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::Container
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(3usize);
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.sensor)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.value)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.label)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .finish()
+                        .expect("tree hash derive should not have a remaining buffer")
+                }
+            }
+            impl tree_hash::TreeHashLeaves for Reading {
+                fn tree_hash_leaf_count() -> usize {
+                    3usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.sensor)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.value)
+                        }
+                        2usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.label)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 3usize
+                            )
+                        }
+                    }
+                }
+            }
+            /// Zero-copy view over [`Reading`].
+            ///
+            /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
+            /// via lazy getter methods. Use `.to_owned()` to convert to the owned type when
+            /// needed.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                std::marker::Copy
+            )]
+            pub struct ReadingRef<'a> {
+                bytes: &'a [u8],
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ReadingRef<'a> {
+                pub fn sensor(&self) -> Result<u16, ssz::DecodeError> {
+                    let bytes = ssz::layout::read_field_bytes(
+                        self.bytes,
+                        &[
+                            (
+                                <u16 as ssz::Encode>::is_ssz_fixed_len(),
+                                <u16 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <Option<u8> as ssz::Encode>::is_ssz_fixed_len(),
+                                <Option<u8> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <Option<
+                                    VariableList<u8, 16usize>,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <Option<
+                                    VariableList<u8, 16usize>,
+                                > as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                        0usize,
+                    )?;
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+                pub fn value(&self) -> Result<Option<u8>, ssz::DecodeError> {
+                    let bytes = ssz::layout::read_field_bytes(
+                        self.bytes,
+                        &[
+                            (
+                                <u16 as ssz::Encode>::is_ssz_fixed_len(),
+                                <u16 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <Option<u8> as ssz::Encode>::is_ssz_fixed_len(),
+                                <Option<u8> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <Option<
+                                    VariableList<u8, 16usize>,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <Option<
+                                    VariableList<u8, 16usize>,
+                                > as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                        1usize,
+                    )?;
+                    ssz::view::reject_empty(bytes, 1)?;
+                    let selector = bytes[0];
+                    match selector {
+                        0 => ssz::check_union_body_len(&bytes[1..], 0).map(|()| None),
+                        1 => {
+                            if <u8 as ssz::Encode>::is_ssz_fixed_len() {
+                                ssz::check_union_body_len(
+                                    &bytes[1..],
+                                    <u8 as ssz::Encode>::ssz_fixed_len(),
+                                )?;
+                            }
+                            let body = &bytes[1..];
+                            let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(
+                                    body,
+                                )
+                                .map_err(|e| e.bounded(body.len()))?;
+                            Ok(Some(inner))
+                        }
+                        _ => {
+                            Err(
+                                ssz::DecodeError::BytesInvalid(
+                                    format!("Invalid union selector for Option: {}", selector),
+                                ),
+                            )
+                        }
+                    }
+                }
+                pub fn label(
+                    &self,
+                ) -> Result<Option<BytesRef<'a, 16usize>>, ssz::DecodeError> {
+                    let bytes = ssz::layout::read_field_bytes(
+                        self.bytes,
+                        &[
+                            (
+                                <u16 as ssz::Encode>::is_ssz_fixed_len(),
+                                <u16 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <Option<u8> as ssz::Encode>::is_ssz_fixed_len(),
+                                <Option<u8> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <Option<
+                                    VariableList<u8, 16usize>,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <Option<
+                                    VariableList<u8, 16usize>,
+                                > as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                        2usize,
+                    )?;
+                    ssz::view::reject_empty(bytes, 1)?;
+                    let selector = bytes[0];
+                    match selector {
+                        0 => ssz::check_union_body_len(&bytes[1..], 0).map(|()| None),
+                        1 => {
+                            if <VariableList<
+                                u8,
+                                16usize,
+                            > as ssz::Encode>::is_ssz_fixed_len() {
+                                ssz::check_union_body_len(
+                                    &bytes[1..],
+                                    <VariableList<u8, 16usize> as ssz::Encode>::ssz_fixed_len(),
+                                )?;
+                            }
+                            let body = &bytes[1..];
+                            let inner = <BytesRef<
+                                'a,
+                                16usize,
+                            > as ssz::view::DecodeView>::from_ssz_bytes(body)
+                                .map_err(|e| e.bounded(body.len()))?;
+                            Ok(Some(inner))
+                        }
+                        _ => {
+                            Err(
+                                ssz::DecodeError::BytesInvalid(
+                                    format!("Invalid union selector for Option: {}", selector),
+                                ),
+                            )
+                        }
+                    }
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ReadingRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.sensor()?;
+                    self.value()?;
+                    self.label()?;
+                    Ok(())
+                }
+            }
+            impl<'a> tree_hash::TreeHash for ReadingRef<'a> {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::StableContainer
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(3usize);
+                    {
+                        let sensor = self.sensor().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&sensor);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    {
+                        let value = self.value().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&value);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    {
+                        let label = self.label().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&label);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    hasher.finish().expect("finish hasher")
+                }
+            }
+            impl<'a> ssz::view::DecodeView<'a> for ReadingRef<'a> {
+                fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
+                    ssz::layout::validate_container(
+                        bytes,
+                        &[
+                            (
+                                <u16 as ssz::Encode>::is_ssz_fixed_len(),
+                                <u16 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <Option<u8> as ssz::Encode>::is_ssz_fixed_len(),
+                                <Option<u8> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <Option<
+                                    VariableList<u8, 16usize>,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <Option<
+                                    VariableList<u8, 16usize>,
+                                > as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                    )?;
+                    Ok(Self { bytes })
+                }
+            }
+            impl<'a> TryFrom<&'a [u8]> for ReadingRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ReadingRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
+            impl<'a> ssz::view::SszTypeInfo for ReadingRef<'a> {
+                fn is_ssz_fixed_len() -> bool {
+                    usize::from(!<u16 as ssz::Encode>::is_ssz_fixed_len())
+                        + usize::from(!<Option<u8> as ssz::Encode>::is_ssz_fixed_len())
+                        + usize::from(
+                            !<Option<
+                                VariableList<u8, 16usize>,
+                            > as ssz::Encode>::is_ssz_fixed_len(),
+                        ) == 0
+                }
+                fn ssz_fixed_len() -> usize {
+                    if <Self as ssz::view::SszTypeInfo>::is_ssz_fixed_len() {
+                        <u16 as ssz::Encode>::ssz_fixed_len()
+                            + <Option<u8> as ssz::Encode>::ssz_fixed_len()
+                            + <Option<
+                                VariableList<u8, 16usize>,
+                            > as ssz::Encode>::ssz_fixed_len()
+                    } else {
+                        0
+                    }
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ssz_types::view::ToOwnedSsz<Reading> for ReadingRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                fn to_owned(&self) -> Reading {
+                    <ReadingRef<'a>>::to_owned(self)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ReadingRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned(&self) -> Reading {
+                    Reading {
+                        sensor: self.sensor().expect("valid view"),
+                        value: self
+                            .value()
+                            .expect("valid view")
+                            .map(|inner| ssz_types::view::ToOwnedSsz::to_owned(&inner)),
+                        label: self
+                            .label()
+                            .expect("valid view")
+                            .map(|inner| ssz_types::view::ToOwnedSsz::to_owned(&inner)),
+                    }
+                }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Reading,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.sensor = self.sensor()?;
+                    out.value = self
+                        .value()?
+                        .map(|inner| ssz_types::view::ToOwnedSsz::to_owned(&inner));
+                    out.label = self
+                        .label()?
+                        .map(|inner| ssz_types::view::ToOwnedSsz::to_owned(&inner));
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ReadingRef<'a> {
+                fn eq_owned(&self, other: &Reading) -> Result<bool, ssz::DecodeError> {
+                    if !({
+                        let value: u16 = self.sensor()?;
+                        value == other.sensor
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Option<u8> = self
+                            .value()?
+                            .map(|inner| ssz_types::view::ToOwnedSsz::to_owned(&inner));
+                        value == other.value
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Option<VariableList<u8, 16usize>> = self
+                            .label()?
+                            .map(|inner| ssz_types::view::ToOwnedSsz::to_owned(&inner));
+                        value == other.label
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<Reading> for ReadingRef<'a> {
+                fn eq(&self, other: &Reading) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<ReadingRef<'a>> for Reading {
+                fn eq(&self, other: &ReadingRef<'a>) -> bool {
+                    other == self
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,666 @@
+pub mod tests {
+    # ! [ allow ( unused_imports , reason = "generated code using ssz-gen" ) ]
+    pub mod input {
+        # ! [ allow ( unused_imports , reason = "generated code using ssz-gen" ) ]
+        pub mod test_vector_view_iter {
+            # ! [ allow ( unused_imports , reason = "generated code using ssz-gen" ) ]
+            use ssz_types :: * ;
+            use ssz_types :: view :: {
+                FixedVectorRef ,
+                VariableListRef
+            };
+            use ssz_primitives :: {
+                U128 ,
+                U256
+            };
+            use ssz_derive :: {
+                Encode ,
+                Decode
+            };
+            use tree_hash :: TreeHashDigest ;
+            use tree_hash_derive :: TreeHash ;
+            use ssz :: view :: * ;
+            # [ doc = r" Hash of the normalized schema this module was generated from." ]
+            # [ allow ( dead_code , reason = "generated code using ssz-gen" ) ]
+            pub const SCHEMA_HASH : [ u8 ; 32 ] = [ 175 , 73 , 11 , 187 , 210 , 144 , 114 , 112 , 32 , 28 , 244 , 185 , 159 , 220 , 252 , 228 , 47 , 51 , 106 , 178 , 53 , 255 , 92 , 19 , 109 , 191 , 178 , 241 , 184 , 106 , 245 , 176 ] ;
+            # [ doc = " Fixed-size element: the iterator strides over it." ]
+            # [ derive ( std :: clone :: Clone , std :: fmt :: Debug , std :: cmp :: PartialEq , std :: cmp :: Eq , ssz_derive :: Encode , ssz_derive :: Decode ) ]
+            # [ ssz ( struct_behaviour = "container" ) ]
+            pub struct Delta {
+                pub z : bool ,
+                pub w : u8
+            }
+            impl Delta {
+                # [ doc = r" Byte offset of each field in the SSZ encoding, in declaration order." ]
+                pub const FIELD_OFFSETS : & [ usize ] = & [ 0 , 1 ] ;
+                # [ doc = r" Byte size of each field in the SSZ encoding, in declaration order." ]
+                pub const FIELD_SIZES : & [ usize ] = & [ 1 , 1 ] ;
+            }
+            impl tree_hash :: TreeHash for Delta {
+                fn tree_hash_type ( ) -> tree_hash :: TreeHashType {
+                    tree_hash :: TreeHashType :: Container
+                }
+                fn tree_hash_packed_encoding ( & self ) -> tree_hash :: PackedEncoding {
+                    unreachable ! ( "Container should never be packed" )
+                }
+                fn tree_hash_packing_factor ( ) -> usize {
+                    unreachable ! ( "Container should never be packed" )
+                }
+                fn tree_hash_root < H : tree_hash :: TreeHashDigest > ( & self ) -> H :: Output {
+                    use tree_hash :: TreeHash ;
+                    let mut hasher = tree_hash :: MerkleHasher :: < H > :: with_leaves ( 2usize ) ;
+                    hasher . write ( < _ as tree_hash :: TreeHash > :: tree_hash_root :: < H > ( & self . z ) . as_ref ( ) ) . expect ( "tree hash derive should not apply too many leaves" ) ;
+                    hasher . write ( < _ as tree_hash :: TreeHash > :: tree_hash_root :: < H > ( & self . w ) . as_ref ( ) ) . expect ( "tree hash derive should not apply too many leaves" ) ;
+                    hasher . finish ( ) . expect ( "tree hash derive should not have a remaining buffer" )
+                }
+            }
+            impl tree_hash :: TreeHashLeaves for Delta {
+                fn tree_hash_leaf_count ( ) -> usize {
+                    2usize
+                }
+                fn tree_hash_leaf_root < H : tree_hash :: TreeHashDigest > ( & self , index : usize ) -> H :: Output {
+                    match index {
+                        0usize => < _ as tree_hash :: TreeHash > :: tree_hash_root :: < H > ( & self . z ) ,
+                        1usize => < _ as tree_hash :: TreeHash > :: tree_hash_root :: < H > ( & self . w ) ,
+                        _ => panic ! ( "leaf index {index} out of range for {} leaves" , 2usize ) ,
+                    }
+                }
+            }
+            # [ doc = " Zero-copy view over [`Delta`]." ]
+            # [ doc = "" ]
+            # [ doc = " This type wraps SSZ-encoded bytes without allocating. Fields are accessed" ]
+            # [ doc = " via lazy getter methods. Use `.to_owned()` to convert to the owned type when" ]
+            # [ doc = " needed." ]
+            # [ allow ( dead_code , reason = "generated code using ssz-gen" ) ]
+            # [ derive ( std :: clone :: Clone , std :: fmt :: Debug , std :: cmp :: PartialEq , std :: cmp :: Eq , std :: marker :: Copy ) ]
+            pub struct DeltaRef < 'a > {
+                bytes : & 'a [ u8 ] ,
+            }
+            # [ allow ( dead_code , reason = "generated code using ssz-gen" ) ]
+            impl < 'a > DeltaRef < 'a > {
+                pub fn z ( & self ) -> Result < bool ,
+                ssz :: DecodeError > {
+                    let bytes = & self . bytes [ 0 .. 1 ] ;
+                    ssz :: view :: DecodeView :: from_ssz_bytes ( bytes ) . map_err ( | e | e . bounded ( bytes . len ( ) ) )
+                }
+                pub fn w ( & self ) -> Result < u8 ,
+                ssz :: DecodeError > {
+                    let bytes = & self . bytes [ 1 .. 2 ] ;
+                    ssz :: view :: DecodeView :: from_ssz_bytes ( bytes ) . map_err ( | e | e . bounded ( bytes . len ( ) ) )
+                }
+            }
+            # [ allow ( dead_code , reason = "generated code using ssz-gen" ) ]
+            impl < 'a > DeltaRef < 'a > {
+                # [ doc = r" Validates every field of this view, recursing into nested" ]
+                # [ doc = r" views and walking every list and vector, and returns the" ]
+                # [ doc = r" first error." ]
+                # [ doc = r"" ]
+                # [ doc = r" `from_ssz_bytes` only checks the view's own layout, so this" ]
+                # [ doc = r" rejects bytes that would otherwise fail on a later access." ]
+                pub fn validate_deep ( & self ) -> Result < ( ) ,
+                ssz :: DecodeError > {
+                    self . z ( ) ? ;
+                    self . w ( ) ? ;
+                    Ok ( ( ) )
+                }
+            }
+            impl < 'a > tree_hash :: TreeHash for DeltaRef < 'a > {
+                fn tree_hash_type ( ) -> tree_hash :: TreeHashType {
+                    tree_hash :: TreeHashType :: StableContainer
+                }
+                fn tree_hash_packed_encoding ( & self ) -> tree_hash :: PackedEncoding {
+                    unreachable ! ( "Container should never be packed" )
+                }
+                fn tree_hash_packing_factor ( ) -> usize {
+                    unreachable ! ( "Container should never be packed" )
+                }
+                fn tree_hash_root < H : tree_hash :: TreeHashDigest > ( & self ) -> H :: Output {
+                    use tree_hash :: TreeHash ;
+                    let mut hasher = tree_hash :: MerkleHasher :: < H > :: with_leaves ( 2usize ) ;
+                    {
+                        let z = self . z ( ) . expect ( "valid view" ) ;
+                        let root : < H as tree_hash :: TreeHashDigest > :: Output = < _ as tree_hash :: TreeHash > :: tree_hash_root :: < H > ( & z ) ;
+                        hasher . write ( root . as_ref ( ) ) . expect ( "write field" ) ;
+                    }
+                    {
+                        let w = self . w ( ) . expect ( "valid view" ) ;
+                        let root : < H as tree_hash :: TreeHashDigest > :: Output = < _ as tree_hash :: TreeHash > :: tree_hash_root :: < H > ( & w ) ;
+                        hasher . write ( root . as_ref ( ) ) . expect ( "write field" ) ;
+                    }
+                    hasher . finish ( ) . expect ( "finish hasher" )
+                }
+            }
+            impl < 'a > ssz :: view :: DecodeView < 'a > for DeltaRef < 'a > {
+                fn from_ssz_bytes ( bytes : & 'a [ u8 ] ) -> Result < Self ,
+                ssz :: DecodeError > {
+                    ssz :: layout :: validate_container ( bytes , & [ ( < bool as ssz :: Encode > :: is_ssz_fixed_len ( ) , < bool as ssz :: Encode > :: ssz_fixed_len ( ) ) , ( < u8 as ssz :: Encode > :: is_ssz_fixed_len ( ) , < u8 as ssz :: Encode > :: ssz_fixed_len ( ) ) ] ) ? ;
+                    Ok ( Self {
+                        bytes
+                    }
+                    )
+                }
+            }
+            impl < 'a > TryFrom < & 'a [ u8 ] > for DeltaRef < 'a > {
+                type Error = ssz :: DecodeError ;
+                fn try_from ( bytes : & 'a [ u8 ] ) -> Result < Self ,
+                Self :: Error > {
+                    < Self as ssz :: view :: DecodeView < 'a >> :: from_ssz_bytes ( bytes )
+                }
+            }
+            # [ allow ( dead_code , reason = "generated code using ssz-gen" ) ]
+            impl < 'a > DeltaRef < 'a > {
+                # [ doc = r" Returns the SSZ bytes this view was decoded from." ]
+                pub const fn as_bytes ( & self ) -> & 'a [ u8 ] {
+                    self . bytes
+                }
+            }
+            impl < 'a > ssz :: view :: SszTypeInfo for DeltaRef < 'a > {
+                fn is_ssz_fixed_len ( ) -> bool {
+                    usize :: from ( ! < bool as ssz :: Encode > :: is_ssz_fixed_len ( ) ) + usize :: from ( ! < u8 as ssz :: Encode > :: is_ssz_fixed_len ( ) ) == 0
+                }
+                fn ssz_fixed_len ( ) -> usize {
+                    if < Self as ssz :: view :: SszTypeInfo > :: is_ssz_fixed_len ( ) {
+                        < bool as ssz :: Encode > :: ssz_fixed_len ( ) + < u8 as ssz :: Encode > :: ssz_fixed_len ( )
+                    }
+                    else {
+                        0
+                    }
+                }
+            }
+            # [ allow ( dead_code , reason = "generated code using ssz-gen" ) ]
+            impl < 'a > ssz_types :: view :: ToOwnedSsz < Delta > for DeltaRef < 'a > {
+                # [ allow ( clippy :: wrong_self_convention , reason = "API convention for view types" ) ]
+                fn to_owned ( & self ) -> Delta {
+                    < DeltaRef < 'a >> :: to_owned ( self )
+                }
+            }
+            # [ allow ( dead_code , reason = "generated code using ssz-gen" ) ]
+            impl < 'a > DeltaRef < 'a > {
+                # [ allow ( clippy :: wrong_self_convention , reason = "API convention for view types" ) ]
+                pub fn to_owned ( & self ) -> Delta {
+                    Delta {
+                        z : self . z ( ) . expect ( "valid view" ) ,
+                        w : self . w ( ) . expect ( "valid view" )
+                    }
+                }
+                # [ doc = r" Converts this view into `out`, reusing the allocations of its" ]
+                # [ doc = r" list and vector fields." ]
+                # [ doc = r"" ]
+                # [ doc = r" Produces the same value as `to_owned`, but avoids reallocating" ]
+                # [ doc = r" when converting many views in a loop. The view is validated" ]
+                # [ doc = r" with `validate_deep` before anything is written, so on error" ]
+                # [ doc = r" `out` is left unchanged." ]
+                # [ allow ( clippy :: wrong_self_convention , reason = "API convention for view types" ) ]
+                pub fn to_owned_into ( & self , out : & mut Delta ) -> Result < ( ) ,
+                ssz :: DecodeError > {
+                    self . validate_deep ( ) ? ;
+                    out . z = self . z ( ) ? ;
+                    out . w = self . w ( ) ? ;
+                    Ok ( ( ) )
+                }
+            }
+            # [ allow ( dead_code , reason = "generated code using ssz-gen" ) ]
+            impl < 'a > DeltaRef < 'a > {
+                fn eq_owned ( & self , other : & Delta ) -> Result < bool ,
+                ssz :: DecodeError > {
+                    if ! ( {
+                        let value : bool = self . z ( ) ? ;
+                        value == other . z
+                    }
+                    ) {
+                        return Ok ( false ) ;
+                    }
+                    if ! ( {
+                        let value : u8 = self . w ( ) ? ;
+                        value == other . w
+                    }
+                    ) {
+                        return Ok ( false ) ;
+                    }
+                    Ok ( true )
+                }
+            }
+            impl < 'a > PartialEq < Delta > for DeltaRef < 'a > {
+                fn eq ( & self , other : & Delta ) -> bool {
+                    self . eq_owned ( other ) . unwrap_or ( false )
+                }
+            }
+            impl < 'a > PartialEq < DeltaRef < 'a >> for Delta {
+                fn eq ( & self , other : & DeltaRef < 'a > ) -> bool {
+                    other == self
+                }
+            }
+            # [ doc = " Variable-size element: the iterator walks the offset table." ]
+            # [ derive ( std :: clone :: Clone , std :: fmt :: Debug , std :: cmp :: PartialEq , std :: cmp :: Eq , ssz_derive :: Encode , ssz_derive :: Decode ) ]
+            # [ ssz ( struct_behaviour = "container" ) ]
+            pub struct Entry {
+                pub id : u16 ,
+                pub data : VariableList < u8 ,
+                8usize >
+            }
+            impl tree_hash :: TreeHash for Entry {
+                fn tree_hash_type ( ) -> tree_hash :: TreeHashType {
+                    tree_hash :: TreeHashType :: Container
+                }
+                fn tree_hash_packed_encoding ( & self ) -> tree_hash :: PackedEncoding {
+                    unreachable ! ( "Container should never be packed" )
+                }
+                fn tree_hash_packing_factor ( ) -> usize {
+                    unreachable ! ( "Container should never be packed" )
+                }
+                fn tree_hash_root < H : tree_hash :: TreeHashDigest > ( & self ) -> H :: Output {
+                    use tree_hash :: TreeHash ;
+                    let mut hasher = tree_hash :: MerkleHasher :: < H > :: with_leaves ( 2usize ) ;
+                    hasher . write ( < _ as tree_hash :: TreeHash > :: tree_hash_root :: < H > ( & self . id ) . as_ref ( ) ) . expect ( "tree hash derive should not apply too many leaves" ) ;
+                    hasher . write ( < _ as tree_hash :: TreeHash > :: tree_hash_root :: < H > ( & self . data ) . as_ref ( ) ) . expect ( "tree hash derive should not apply too many leaves" ) ;
+                    hasher . finish ( ) . expect ( "tree hash derive should not have a remaining buffer" )
+                }
+            }
+            impl tree_hash :: TreeHashLeaves for Entry {
+                fn tree_hash_leaf_count ( ) -> usize {
+                    2usize
+                }
+                fn tree_hash_leaf_root < H : tree_hash :: TreeHashDigest > ( & self , index : usize ) -> H :: Output {
+                    match index {
+                        0usize => < _ as tree_hash :: TreeHash > :: tree_hash_root :: < H > ( & self . id ) ,
+                        1usize => < _ as tree_hash :: TreeHash > :: tree_hash_root :: < H > ( & self . data ) ,
+                        _ => panic ! ( "leaf index {index} out of range for {} leaves" , 2usize ) ,
+                    }
+                }
+            }
+            # [ doc = " Zero-copy view over [`Entry`]." ]
+            # [ doc = "" ]
+            # [ doc = " This type wraps SSZ-encoded bytes without allocating. Fields are accessed" ]
+            # [ doc = " via lazy getter methods. Use `.to_owned()` to convert to the owned type when" ]
+            # [ doc = " needed." ]
+            # [ allow ( dead_code , reason = "generated code using ssz-gen" ) ]
+            # [ derive ( std :: clone :: Clone , std :: fmt :: Debug , std :: cmp :: PartialEq , std :: cmp :: Eq , std :: marker :: Copy ) ]
+            pub struct EntryRef < 'a > {
+                bytes : & 'a [ u8 ] ,
+            }
+            # [ allow ( dead_code , reason = "generated code using ssz-gen" ) ]
+            impl < 'a > EntryRef < 'a > {
+                pub fn id ( & self ) -> Result < u16 ,
+                ssz :: DecodeError > {
+                    let bytes = ssz :: layout :: read_field_bytes ( self . bytes , & [ ( < u16 as ssz :: Encode > :: is_ssz_fixed_len ( ) , < u16 as ssz :: Encode > :: ssz_fixed_len ( ) ) , ( < VariableList < u8 , 8usize > as ssz :: Encode > :: is_ssz_fixed_len ( ) , < VariableList < u8 , 8usize > as ssz :: Encode > :: ssz_fixed_len ( ) ) ] , 0usize ) ? ;
+                    ssz :: view :: DecodeView :: from_ssz_bytes ( bytes ) . map_err ( | e | e . bounded ( bytes . len ( ) ) )
+                }
+                pub fn data ( & self ) -> Result < BytesRef < 'a ,
+                8usize > ,
+                ssz :: DecodeError > {
+                    let bytes = ssz :: layout :: read_field_bytes ( self . bytes , & [ ( < u16 as ssz :: Encode > :: is_ssz_fixed_len ( ) , < u16 as ssz :: Encode > :: ssz_fixed_len ( ) ) , ( < VariableList < u8 , 8usize > as ssz :: Encode > :: is_ssz_fixed_len ( ) , < VariableList < u8 , 8usize > as ssz :: Encode > :: ssz_fixed_len ( ) ) ] , 1usize ) ? ;
+                    ssz :: view :: DecodeView :: from_ssz_bytes ( bytes ) . map_err ( | e | e . bounded ( bytes . len ( ) ) )
+                }
+            }
+            # [ allow ( dead_code , reason = "generated code using ssz-gen" ) ]
+            impl < 'a > EntryRef < 'a > {
+                # [ doc = r" Validates every field of this view, recursing into nested" ]
+                # [ doc = r" views and walking every list and vector, and returns the" ]
+                # [ doc = r" first error." ]
+                # [ doc = r"" ]
+                # [ doc = r" `from_ssz_bytes` only checks the view's own layout, so this" ]
+                # [ doc = r" rejects bytes that would otherwise fail on a later access." ]
+                pub fn validate_deep ( & self ) -> Result < ( ) ,
+                ssz :: DecodeError > {
+                    self . id ( ) ? ;
+                    self . data ( ) ? ;
+                    Ok ( ( ) )
+                }
+            }
+            impl < 'a > tree_hash :: TreeHash for EntryRef < 'a > {
+                fn tree_hash_type ( ) -> tree_hash :: TreeHashType {
+                    tree_hash :: TreeHashType :: StableContainer
+                }
+                fn tree_hash_packed_encoding ( & self ) -> tree_hash :: PackedEncoding {
+                    unreachable ! ( "Container should never be packed" )
+                }
+                fn tree_hash_packing_factor ( ) -> usize {
+                    unreachable ! ( "Container should never be packed" )
+                }
+                fn tree_hash_root < H : tree_hash :: TreeHashDigest > ( & self ) -> H :: Output {
+                    use tree_hash :: TreeHash ;
+                    let mut hasher = tree_hash :: MerkleHasher :: < H > :: with_leaves ( 2usize ) ;
+                    {
+                        let id = self . id ( ) . expect ( "valid view" ) ;
+                        let root : < H as tree_hash :: TreeHashDigest > :: Output = < _ as tree_hash :: TreeHash > :: tree_hash_root :: < H > ( & id ) ;
+                        hasher . write ( root . as_ref ( ) ) . expect ( "write field" ) ;
+                    }
+                    {
+                        let data = self . data ( ) . expect ( "valid view" ) ;
+                        let root : < H as tree_hash :: TreeHashDigest > :: Output = < _ as tree_hash :: TreeHash > :: tree_hash_root :: < H > ( & data ) ;
+                        hasher . write ( root . as_ref ( ) ) . expect ( "write field" ) ;
+                    }
+                    hasher . finish ( ) . expect ( "finish hasher" )
+                }
+            }
+            impl < 'a > ssz :: view :: DecodeView < 'a > for EntryRef < 'a > {
+                fn from_ssz_bytes ( bytes : & 'a [ u8 ] ) -> Result < Self ,
+                ssz :: DecodeError > {
+                    ssz :: layout :: validate_container ( bytes , & [ ( < u16 as ssz :: Encode > :: is_ssz_fixed_len ( ) , < u16 as ssz :: Encode > :: ssz_fixed_len ( ) ) , ( < VariableList < u8 , 8usize > as ssz :: Encode > :: is_ssz_fixed_len ( ) , < VariableList < u8 , 8usize > as ssz :: Encode > :: ssz_fixed_len ( ) ) ] ) ? ;
+                    Ok ( Self {
+                        bytes
+                    }
+                    )
+                }
+            }
+            impl < 'a > TryFrom < & 'a [ u8 ] > for EntryRef < 'a > {
+                type Error = ssz :: DecodeError ;
+                fn try_from ( bytes : & 'a [ u8 ] ) -> Result < Self ,
+                Self :: Error > {
+                    < Self as ssz :: view :: DecodeView < 'a >> :: from_ssz_bytes ( bytes )
+                }
+            }
+            # [ allow ( dead_code , reason = "generated code using ssz-gen" ) ]
+            impl < 'a > EntryRef < 'a > {
+                # [ doc = r" Returns the SSZ bytes this view was decoded from." ]
+                pub const fn as_bytes ( & self ) -> & 'a [ u8 ] {
+                    self . bytes
+                }
+            }
+            impl < 'a > ssz :: view :: SszTypeInfo for EntryRef < 'a > {
+                fn is_ssz_fixed_len ( ) -> bool {
+                    usize :: from ( ! < u16 as ssz :: Encode > :: is_ssz_fixed_len ( ) ) + usize :: from ( ! < VariableList < u8 , 8usize > as ssz :: Encode > :: is_ssz_fixed_len ( ) ) == 0
+                }
+                fn ssz_fixed_len ( ) -> usize {
+                    if < Self as ssz :: view :: SszTypeInfo > :: is_ssz_fixed_len ( ) {
+                        < u16 as ssz :: Encode > :: ssz_fixed_len ( ) + < VariableList < u8 ,
+                        8usize > as ssz :: Encode > :: ssz_fixed_len ( )
+                    }
+                    else {
+                        0
+                    }
+                }
+            }
+            # [ allow ( dead_code , reason = "generated code using ssz-gen" ) ]
+            impl < 'a > ssz_types :: view :: ToOwnedSsz < Entry > for EntryRef < 'a > {
+                # [ allow ( clippy :: wrong_self_convention , reason = "API convention for view types" ) ]
+                fn to_owned ( & self ) -> Entry {
+                    < EntryRef < 'a >> :: to_owned ( self )
+                }
+            }
+            # [ allow ( dead_code , reason = "generated code using ssz-gen" ) ]
+            impl < 'a > EntryRef < 'a > {
+                # [ allow ( clippy :: wrong_self_convention , reason = "API convention for view types" ) ]
+                pub fn to_owned ( & self ) -> Entry {
+                    Entry {
+                        id : self . id ( ) . expect ( "valid view" ) ,
+                        data : ssz_types :: VariableList :: new ( self . data ( ) . expect ( "valid view" ) . to_owned ( ) ) . expect ( "valid view" )
+                    }
+                }
+                # [ doc = r" Converts this view into `out`, reusing the allocations of its" ]
+                # [ doc = r" list and vector fields." ]
+                # [ doc = r"" ]
+                # [ doc = r" Produces the same value as `to_owned`, but avoids reallocating" ]
+                # [ doc = r" when converting many views in a loop. The view is validated" ]
+                # [ doc = r" with `validate_deep` before anything is written, so on error" ]
+                # [ doc = r" `out` is left unchanged." ]
+                # [ allow ( clippy :: wrong_self_convention , reason = "API convention for view types" ) ]
+                pub fn to_owned_into ( & self , out : & mut Entry ) -> Result < ( ) ,
+                ssz :: DecodeError > {
+                    self . validate_deep ( ) ? ;
+                    out . id = self . id ( ) ? ;
+                    let view = self . data ( ) ? ;
+                    out . data . clear ( ) ;
+                    out . data . try_extend_from_slice ( view . as_bytes ( ) ) . expect ( "valid view" ) ;
+                    Ok ( ( ) )
+                }
+            }
+            # [ allow ( dead_code , reason = "generated code using ssz-gen" ) ]
+            impl < 'a > EntryRef < 'a > {
+                fn eq_owned ( & self , other : & Entry ) -> Result < bool ,
+                ssz :: DecodeError > {
+                    if ! ( {
+                        let value : u16 = self . id ( ) ? ;
+                        value == other . id
+                    }
+                    ) {
+                        return Ok ( false ) ;
+                    }
+                    if ! ( {
+                        let value : VariableList < u8 ,
+                        8usize > = ssz_types :: VariableList :: new ( self . data ( ) ? . to_owned ( ) ) . expect ( "valid view" ) ;
+                        value == other . data
+                    }
+                    ) {
+                        return Ok ( false ) ;
+                    }
+                    Ok ( true )
+                }
+            }
+            impl < 'a > PartialEq < Entry > for EntryRef < 'a > {
+                fn eq ( & self , other : & Entry ) -> bool {
+                    self . eq_owned ( other ) . unwrap_or ( false )
+                }
+            }
+            impl < 'a > PartialEq < EntryRef < 'a >> for Entry {
+                fn eq ( & self , other : & EntryRef < 'a > ) -> bool {
+                    other == self
+                }
+            }
+            # [ derive ( std :: clone :: Clone , std :: fmt :: Debug , std :: cmp :: PartialEq , std :: cmp :: Eq , ssz_derive :: Encode , ssz_derive :: Decode ) ]
+            # [ ssz ( struct_behaviour = "container" ) ]
+            pub struct Holder {
+                pub deltas : FixedVector < Delta ,
+                4usize > ,
+                pub entries : FixedVector < Entry ,
+                3usize >
+            }
+            impl tree_hash :: TreeHash for Holder {
+                fn tree_hash_type ( ) -> tree_hash :: TreeHashType {
+                    tree_hash :: TreeHashType :: Container
+                }
+                fn tree_hash_packed_encoding ( & self ) -> tree_hash :: PackedEncoding {
+                    unreachable ! ( "Container should never be packed" )
+                }
+                fn tree_hash_packing_factor ( ) -> usize {
+                    unreachable ! ( "Container should never be packed" )
+                }
+                fn tree_hash_root < H : tree_hash :: TreeHashDigest > ( & self ) -> H :: Output {
+                    use tree_hash :: TreeHash ;
+                    let mut hasher = tree_hash :: MerkleHasher :: < H > :: with_leaves ( 2usize ) ;
+                    hasher . write ( < _ as tree_hash :: TreeHash > :: tree_hash_root :: < H > ( & self . deltas ) . as_ref ( ) ) . expect ( "tree hash derive should not apply too many leaves" ) ;
+                    hasher . write ( < _ as tree_hash :: TreeHash > :: tree_hash_root :: < H > ( & self . entries ) . as_ref ( ) ) . expect ( "tree hash derive should not apply too many leaves" ) ;
+                    hasher . finish ( ) . expect ( "tree hash derive should not have a remaining buffer" )
+                }
+            }
+            impl tree_hash :: TreeHashLeaves for Holder {
+                fn tree_hash_leaf_count ( ) -> usize {
+                    2usize
+                }
+                fn tree_hash_leaf_root < H : tree_hash :: TreeHashDigest > ( & self , index : usize ) -> H :: Output {
+                    match index {
+                        0usize => < _ as tree_hash :: TreeHash > :: tree_hash_root :: < H > ( & self . deltas ) ,
+                        1usize => < _ as tree_hash :: TreeHash > :: tree_hash_root :: < H > ( & self . entries ) ,
+                        _ => panic ! ( "leaf index {index} out of range for {} leaves" , 2usize ) ,
+                    }
+                }
+            }
+            # [ doc = " Zero-copy view over [`Holder`]." ]
+            # [ doc = "" ]
+            # [ doc = " This type wraps SSZ-encoded bytes without allocating. Fields are accessed" ]
+            # [ doc = " via lazy getter methods. Use `.to_owned()` to convert to the owned type when" ]
+            # [ doc = " needed." ]
+            # [ allow ( dead_code , reason = "generated code using ssz-gen" ) ]
+            # [ derive ( std :: clone :: Clone , std :: fmt :: Debug , std :: cmp :: PartialEq , std :: cmp :: Eq , std :: marker :: Copy ) ]
+            pub struct HolderRef < 'a > {
+                bytes : & 'a [ u8 ] ,
+            }
+            # [ allow ( dead_code , reason = "generated code using ssz-gen" ) ]
+            impl < 'a > HolderRef < 'a > {
+                pub fn deltas ( & self ) -> Result < FixedVectorRef < 'a ,
+                DeltaRef < 'a > ,
+                4usize > ,
+                ssz :: DecodeError > {
+                    let bytes = ssz :: layout :: read_field_bytes ( self . bytes , & [ ( < FixedVector < Delta , 4usize > as ssz :: Encode > :: is_ssz_fixed_len ( ) , < FixedVector < Delta , 4usize > as ssz :: Encode > :: ssz_fixed_len ( ) ) , ( < FixedVector < Entry , 3usize > as ssz :: Encode > :: is_ssz_fixed_len ( ) , < FixedVector < Entry , 3usize > as ssz :: Encode > :: ssz_fixed_len ( ) ) ] , 0usize ) ? ;
+                    ssz :: view :: DecodeView :: from_ssz_bytes ( bytes ) . map_err ( | e | e . bounded ( bytes . len ( ) ) )
+                }
+                pub fn entries ( & self ) -> Result < FixedVectorRef < 'a ,
+                EntryRef < 'a > ,
+                3usize > ,
+                ssz :: DecodeError > {
+                    let bytes = ssz :: layout :: read_field_bytes ( self . bytes , & [ ( < FixedVector < Delta , 4usize > as ssz :: Encode > :: is_ssz_fixed_len ( ) , < FixedVector < Delta , 4usize > as ssz :: Encode > :: ssz_fixed_len ( ) ) , ( < FixedVector < Entry , 3usize > as ssz :: Encode > :: is_ssz_fixed_len ( ) , < FixedVector < Entry , 3usize > as ssz :: Encode > :: ssz_fixed_len ( ) ) ] , 1usize ) ? ;
+                    ssz :: view :: DecodeView :: from_ssz_bytes ( bytes ) . map_err ( | e | e . bounded ( bytes . len ( ) ) )
+                }
+            }
+            # [ allow ( dead_code , reason = "generated code using ssz-gen" ) ]
+            impl < 'a > HolderRef < 'a > {
+                # [ doc = r" Validates every field of this view, recursing into nested" ]
+                # [ doc = r" views and walking every list and vector, and returns the" ]
+                # [ doc = r" first error." ]
+                # [ doc = r"" ]
+                # [ doc = r" `from_ssz_bytes` only checks the view's own layout, so this" ]
+                # [ doc = r" rejects bytes that would otherwise fail on a later access." ]
+                pub fn validate_deep ( & self ) -> Result < ( ) ,
+                ssz :: DecodeError > {
+                    let value = self . deltas ( ) ? ;
+                    for item in value . iter ( ) {
+                        let item = item ? ;
+                        item . validate_deep ( ) ? ;
+                    }
+                    let value = self . entries ( ) ? ;
+                    for item in value . iter ( ) {
+                        let item = item ? ;
+                        item . validate_deep ( ) ? ;
+                    }
+                    Ok ( ( ) )
+                }
+            }
+            impl < 'a > tree_hash :: TreeHash for HolderRef < 'a > {
+                fn tree_hash_type ( ) -> tree_hash :: TreeHashType {
+                    tree_hash :: TreeHashType :: StableContainer
+                }
+                fn tree_hash_packed_encoding ( & self ) -> tree_hash :: PackedEncoding {
+                    unreachable ! ( "Container should never be packed" )
+                }
+                fn tree_hash_packing_factor ( ) -> usize {
+                    unreachable ! ( "Container should never be packed" )
+                }
+                fn tree_hash_root < H : tree_hash :: TreeHashDigest > ( & self ) -> H :: Output {
+                    use tree_hash :: TreeHash ;
+                    let mut hasher = tree_hash :: MerkleHasher :: < H > :: with_leaves ( 2usize ) ;
+                    {
+                        let deltas = self . deltas ( ) . expect ( "valid view" ) ;
+                        let root : < H as tree_hash :: TreeHashDigest > :: Output = < _ as tree_hash :: TreeHash > :: tree_hash_root :: < H > ( & deltas ) ;
+                        hasher . write ( root . as_ref ( ) ) . expect ( "write field" ) ;
+                    }
+                    {
+                        let entries = self . entries ( ) . expect ( "valid view" ) ;
+                        let root : < H as tree_hash :: TreeHashDigest > :: Output = < _ as tree_hash :: TreeHash > :: tree_hash_root :: < H > ( & entries ) ;
+                        hasher . write ( root . as_ref ( ) ) . expect ( "write field" ) ;
+                    }
+                    hasher . finish ( ) . expect ( "finish hasher" )
+                }
+            }
+            impl < 'a > ssz :: view :: DecodeView < 'a > for HolderRef < 'a > {
+                fn from_ssz_bytes ( bytes : & 'a [ u8 ] ) -> Result < Self ,
+                ssz :: DecodeError > {
+                    ssz :: layout :: validate_container ( bytes , & [ ( < FixedVector < Delta , 4usize > as ssz :: Encode > :: is_ssz_fixed_len ( ) , < FixedVector < Delta , 4usize > as ssz :: Encode > :: ssz_fixed_len ( ) ) , ( < FixedVector < Entry , 3usize > as ssz :: Encode > :: is_ssz_fixed_len ( ) , < FixedVector < Entry , 3usize > as ssz :: Encode > :: ssz_fixed_len ( ) ) ] ) ? ;
+                    Ok ( Self {
+                        bytes
+                    }
+                    )
+                }
+            }
+            impl < 'a > TryFrom < & 'a [ u8 ] > for HolderRef < 'a > {
+                type Error = ssz :: DecodeError ;
+                fn try_from ( bytes : & 'a [ u8 ] ) -> Result < Self ,
+                Self :: Error > {
+                    < Self as ssz :: view :: DecodeView < 'a >> :: from_ssz_bytes ( bytes )
+                }
+            }
+            # [ allow ( dead_code , reason = "generated code using ssz-gen" ) ]
+            impl < 'a > HolderRef < 'a > {
+                # [ doc = r" Returns the SSZ bytes this view was decoded from." ]
+                pub const fn as_bytes ( & self ) -> & 'a [ u8 ] {
+                    self . bytes
+                }
+            }
+            impl < 'a > ssz :: view :: SszTypeInfo for HolderRef < 'a > {
+                fn is_ssz_fixed_len ( ) -> bool {
+                    usize :: from ( ! < FixedVector < Delta , 4usize > as ssz :: Encode > :: is_ssz_fixed_len ( ) ) + usize :: from ( ! < FixedVector < Entry , 3usize > as ssz :: Encode > :: is_ssz_fixed_len ( ) ) == 0
+                }
+                fn ssz_fixed_len ( ) -> usize {
+                    if < Self as ssz :: view :: SszTypeInfo > :: is_ssz_fixed_len ( ) {
+                        < FixedVector < Delta ,
+                        4usize > as ssz :: Encode > :: ssz_fixed_len ( ) + < FixedVector < Entry ,
+                        3usize > as ssz :: Encode > :: ssz_fixed_len ( )
+                    }
+                    else {
+                        0
+                    }
+                }
+            }
+            # [ allow ( dead_code , reason = "generated code using ssz-gen" ) ]
+            impl < 'a > ssz_types :: view :: ToOwnedSsz < Holder > for HolderRef < 'a > {
+                # [ allow ( clippy :: wrong_self_convention , reason = "API convention for view types" ) ]
+                fn to_owned ( & self ) -> Holder {
+                    < HolderRef < 'a >> :: to_owned ( self )
+                }
+            }
+            # [ allow ( dead_code , reason = "generated code using ssz-gen" ) ]
+            impl < 'a > HolderRef < 'a > {
+                # [ allow ( clippy :: wrong_self_convention , reason = "API convention for view types" ) ]
+                pub fn to_owned ( & self ) -> Holder {
+                    Holder {
+                        deltas : self . deltas ( ) . expect ( "valid view" ) . to_owned ( ) . expect ( "valid view" ) ,
+                        entries : self . entries ( ) . expect ( "valid view" ) . to_owned ( ) . expect ( "valid view" )
+                    }
+                }
+                # [ doc = r" Converts this view into `out`, reusing the allocations of its" ]
+                # [ doc = r" list and vector fields." ]
+                # [ doc = r"" ]
+                # [ doc = r" Produces the same value as `to_owned`, but avoids reallocating" ]
+                # [ doc = r" when converting many views in a loop. The view is validated" ]
+                # [ doc = r" with `validate_deep` before anything is written, so on error" ]
+                # [ doc = r" `out` is left unchanged." ]
+                # [ allow ( clippy :: wrong_self_convention , reason = "API convention for view types" ) ]
+                pub fn to_owned_into ( & self , out : & mut Holder ) -> Result < ( ) ,
+                ssz :: DecodeError > {
+                    self . validate_deep ( ) ? ;
+                    let view = self . deltas ( ) ? ;
+                    for ( slot , item ) in out . deltas . iter_mut ( ) . zip ( view . iter ( ) ) {
+                        * slot = ssz_types :: view :: ToOwnedSsz :: to_owned ( & item ? ) ;
+                    }
+                    let view = self . entries ( ) ? ;
+                    for ( slot , item ) in out . entries . iter_mut ( ) . zip ( view . iter ( ) ) {
+                        * slot = ssz_types :: view :: ToOwnedSsz :: to_owned ( & item ? ) ;
+                    }
+                    Ok ( ( ) )
+                }
+            }
+            # [ allow ( dead_code , reason = "generated code using ssz-gen" ) ]
+            impl < 'a > HolderRef < 'a > {
+                fn eq_owned ( & self , other : & Holder ) -> Result < bool ,
+                ssz :: DecodeError > {
+                    if ! ( {
+                        let value : FixedVector < Delta ,
+                        4usize > = self . deltas ( ) ? . to_owned ( ) . expect ( "valid view" ) ;
+                        value == other . deltas
+                    }
+                    ) {
+                        return Ok ( false ) ;
+                    }
+                    if ! ( {
+                        let value : FixedVector < Entry ,
+                        3usize > = self . entries ( ) ? . to_owned ( ) . expect ( "valid view" ) ;
+                        value == other . entries
+                    }
+                    ) {
+                        return Ok ( false ) ;
+                    }
+                    Ok ( true )
+                }
+            }
+            impl < 'a > PartialEq < Holder > for HolderRef < 'a > {
+                fn eq ( & self , other : & Holder ) -> bool {
+                    self . eq_owned ( other ) . unwrap_or ( false )
+                }
+            }
+            impl < 'a > PartialEq < HolderRef < 'a >> for Holder {
+                fn eq ( & self , other : & HolderRef < 'a > ) -> bool {
+                    other == self
+                }
+            }
+        }
+    }
+}
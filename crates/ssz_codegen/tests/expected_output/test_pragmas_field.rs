@@ -11,6 +11,12 @@ pub mod tests {
             use tree_hash::TreeHashDigest;
             use tree_hash_derive::TreeHash;
             use ssz::view::*;
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                96, 43, 6, 193, 44, 188, 144, 76, 0, 227, 2, 167, 152, 169, 221, 164, 29,
+                124, 49, 100, 193, 133, 118, 88, 205, 18, 35, 123, 75, 227, 198, 153,
+            ];
             /// Test field-level pragmas
             #[derive(
                 std::clone::Clone,
@@ -28,6 +34,12 @@ pub mod tests {
                 #[cfg(test)]
                 pub multi_pragma_field: u32,
             }
+            impl FieldPragmaContainer {
+                /// Byte offset of each field in the SSZ encoding, in declaration order.
+                pub const FIELD_OFFSETS: &[usize] = &[0, 1, 3];
+                /// Byte size of each field in the SSZ encoding, in declaration order.
+                pub const FIELD_SIZES: &[usize] = &[1, 2, 4];
+            }
             impl tree_hash::TreeHash for FieldPragmaContainer {
                 fn tree_hash_type() -> tree_hash::TreeHashType {
                     tree_hash::TreeHashType::Container
@@ -70,6 +82,38 @@ pub mod tests {
                         .expect("tree hash derive should not have a remaining buffer")
                 }
             }
+            impl tree_hash::TreeHashLeaves for FieldPragmaContainer {
+                fn tree_hash_leaf_count() -> usize {
+                    3usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<
+                                H,
+                            >(&self.normal_field)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<
+                                H,
+                            >(&self.pragma_field)
+                        }
+                        2usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<
+                                H,
+                            >(&self.multi_pragma_field)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 3usize
+                            )
+                        }
+                    }
+                }
+            }
             /// Zero-copy view over [`FieldPragmaContainer`].
             ///
             /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
@@ -89,67 +133,34 @@ pub mod tests {
             #[allow(dead_code, reason = "generated code using ssz-gen")]
             impl<'a> FieldPragmaContainerRef<'a> {
                 pub fn normal_field(&self) -> Result<u8, ssz::DecodeError> {
-                    let bytes = ssz::layout::read_field_bytes(
-                        self.bytes,
-                        &[
-                            (
-                                <u8 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u8 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                            (
-                                <u16 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u16 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                            (
-                                <u32 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u32 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                        ],
-                        0usize,
-                    )?;
+                    let bytes = &self.bytes[0..1];
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn pragma_field(&self) -> Result<u16, ssz::DecodeError> {
-                    let bytes = ssz::layout::read_field_bytes(
-                        self.bytes,
-                        &[
-                            (
-                                <u8 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u8 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                            (
-                                <u16 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u16 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                            (
-                                <u32 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u32 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                        ],
-                        1usize,
-                    )?;
+                    let bytes = &self.bytes[1..3];
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn multi_pragma_field(&self) -> Result<u32, ssz::DecodeError> {
-                    let bytes = ssz::layout::read_field_bytes(
-                        self.bytes,
-                        &[
-                            (
-                                <u8 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u8 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                            (
-                                <u16 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u16 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                            (
-                                <u32 as ssz::Encode>::is_ssz_fixed_len(),
-                                <u32 as ssz::Encode>::ssz_fixed_len(),
-                            ),
-                        ],
-                        2usize,
-                    )?;
+                    let bytes = &self.bytes[3..7];
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> FieldPragmaContainerRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.normal_field()?;
+                    self.pragma_field()?;
+                    self.multi_pragma_field()?;
+                    Ok(())
                 }
             }
             impl<'a> tree_hash::TreeHash for FieldPragmaContainerRef<'a> {
@@ -213,6 +224,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for FieldPragmaContainerRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> FieldPragmaContainerRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for FieldPragmaContainerRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     usize::from(!<u8 as ssz::Encode>::is_ssz_fixed_len())
@@ -255,6 +279,64 @@ pub mod tests {
                             .expect("valid view"),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut FieldPragmaContainer,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.normal_field = self.normal_field()?;
+                    out.pragma_field = self.pragma_field()?;
+                    out.multi_pragma_field = self.multi_pragma_field()?;
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> FieldPragmaContainerRef<'a> {
+                fn eq_owned(
+                    &self,
+                    other: &FieldPragmaContainer,
+                ) -> Result<bool, ssz::DecodeError> {
+                    if !({
+                        let value: u8 = self.normal_field()?;
+                        value == other.normal_field
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: u16 = self.pragma_field()?;
+                        value == other.pragma_field
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: u32 = self.multi_pragma_field()?;
+                        value == other.multi_pragma_field
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<FieldPragmaContainer> for FieldPragmaContainerRef<'a> {
+                fn eq(&self, other: &FieldPragmaContainer) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<FieldPragmaContainerRef<'a>> for FieldPragmaContainer {
+                fn eq(&self, other: &FieldPragmaContainerRef<'a>) -> bool {
+                    other == self
+                }
             }
         }
     }
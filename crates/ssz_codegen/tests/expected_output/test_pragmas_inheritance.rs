@@ -11,6 +11,12 @@ pub mod tests {
             use tree_hash::TreeHashDigest;
             use tree_hash_derive::TreeHash;
             use ssz::view::*;
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                55, 51, 139, 245, 79, 168, 183, 198, 136, 130, 157, 3, 18, 209, 91, 11,
+                31, 52, 247, 96, 165, 254, 232, 98, 55, 115, 54, 229, 23, 95, 114, 128,
+            ];
             /// Test pragmas with inheritance
             #[derive(
                 std::clone::Clone,
@@ -98,9 +104,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         5usize,
@@ -130,8 +135,9 @@ pub mod tests {
                         None => return Ok(ssz_types::Optional::None),
                     };
                     let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(
-                        field_bytes,
-                    )?;
+                            field_bytes,
+                        )
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
                 pub fn b(&self) -> Result<Optional<u8>, ssz::DecodeError> {
@@ -139,9 +145,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         5usize,
@@ -171,11 +176,26 @@ pub mod tests {
                         None => return Ok(ssz_types::Optional::None),
                     };
                     let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(
-                        field_bytes,
-                    )?;
+                            field_bytes,
+                        )
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
             }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ParentRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.a()?;
+                    self.b()?;
+                    Ok(())
+                }
+            }
             impl<'a> tree_hash::TreeHash for ParentRef<'a> {
                 fn tree_hash_type() -> tree_hash::TreeHashType {
                     tree_hash::TreeHashType::StableContainer
@@ -231,9 +251,8 @@ pub mod tests {
                     use ssz::Decode;
                     let bitvector_bytes = bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         5usize,
@@ -271,6 +290,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for ParentRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ParentRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for ParentRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     false
@@ -301,6 +333,70 @@ pub mod tests {
                         b: self.b().expect("valid view"),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Parent,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.a = self.a()?;
+                    out.b = self.b()?;
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ParentRef<'a> {
+                fn eq_owned(&self, other: &Parent) -> Result<bool, ssz::DecodeError> {
+                    use ssz::Decode;
+                    let bitvector_bytes = self
+                        .bytes
+                        .get(..1usize)
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
+                        })?;
+                    let bitvector = ssz_types::BitVector::<
+                        5usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    if bitvector.get(0usize).unwrap_or(false) != other.a.is_some() {
+                        return Ok(false);
+                    }
+                    if bitvector.get(1usize).unwrap_or(false) != other.b.is_some() {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<u8> = self.a()?;
+                        value == other.a
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<u8> = self.b()?;
+                        value == other.b
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<Parent> for ParentRef<'a> {
+                fn eq(&self, other: &Parent) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<ParentRef<'a>> for Parent {
+                fn eq(&self, other: &ParentRef<'a>) -> bool {
+                    other == self
+                }
             }
             #[derive(
                 std::clone::Clone,
@@ -401,9 +497,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         5usize,
@@ -438,8 +533,9 @@ pub mod tests {
                         None => return Ok(ssz_types::Optional::None),
                     };
                     let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(
-                        field_bytes,
-                    )?;
+                            field_bytes,
+                        )
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
                 pub fn b(&self) -> Result<Optional<u16>, ssz::DecodeError> {
@@ -447,9 +543,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         5usize,
@@ -484,8 +579,9 @@ pub mod tests {
                         None => return Ok(ssz_types::Optional::None),
                     };
                     let inner = <u16 as ssz::view::DecodeView>::from_ssz_bytes(
-                        field_bytes,
-                    )?;
+                            field_bytes,
+                        )
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
                 pub fn c(&self) -> Result<Optional<u8>, ssz::DecodeError> {
@@ -493,9 +589,8 @@ pub mod tests {
                     let bitvector_bytes = self
                         .bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: self.bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         5usize,
@@ -530,11 +625,27 @@ pub mod tests {
                         None => return Ok(ssz_types::Optional::None),
                     };
                     let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(
-                        field_bytes,
-                    )?;
+                            field_bytes,
+                        )
+                        .map_err(|e| e.bounded(field_bytes.len()))?;
                     Ok(ssz_types::Optional::Some(inner))
                 }
             }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ChildRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.a()?;
+                    self.b()?;
+                    self.c()?;
+                    Ok(())
+                }
+            }
             impl<'a> tree_hash::TreeHash for ChildRef<'a> {
                 fn tree_hash_type() -> tree_hash::TreeHashType {
                     tree_hash::TreeHashType::StableContainer
@@ -602,9 +713,8 @@ pub mod tests {
                     use ssz::Decode;
                     let bitvector_bytes = bytes
                         .get(..1usize)
-                        .ok_or(ssz::DecodeError::InvalidByteLength {
-                            len: bytes.len(),
-                            expected: 1usize,
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - bytes.len(),
                         })?;
                     let bitvector = ssz_types::BitVector::<
                         5usize,
@@ -647,6 +757,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for ChildRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ChildRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for ChildRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     false
@@ -678,6 +801,80 @@ pub mod tests {
                         c: self.c().expect("valid view"),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Child,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.a = self.a()?;
+                    out.b = self.b()?;
+                    out.c = self.c()?;
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ChildRef<'a> {
+                fn eq_owned(&self, other: &Child) -> Result<bool, ssz::DecodeError> {
+                    use ssz::Decode;
+                    let bitvector_bytes = self
+                        .bytes
+                        .get(..1usize)
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: 1usize - self.bytes.len(),
+                        })?;
+                    let bitvector = ssz_types::BitVector::<
+                        5usize,
+                    >::from_ssz_bytes(bitvector_bytes)?;
+                    if bitvector.get(0usize).unwrap_or(false) != other.a.is_some() {
+                        return Ok(false);
+                    }
+                    if bitvector.get(1usize).unwrap_or(false) != other.b.is_some() {
+                        return Ok(false);
+                    }
+                    if bitvector.get(2usize).unwrap_or(false) != other.c.is_some() {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<u8> = self.a()?;
+                        value == other.a
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<u16> = self.b()?;
+                        value == other.b
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: Optional<u8> = self.c()?;
+                        value == other.c
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<Child> for ChildRef<'a> {
+                fn eq(&self, other: &Child) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<ChildRef<'a>> for Child {
+                fn eq(&self, other: &ChildRef<'a>) -> bool {
+                    other == self
+                }
             }
         }
     }
@@ -7,6 +7,12 @@ pub mod test_serde_derives {
     use tree_hash::TreeHashDigest;
     use tree_hash_derive::TreeHash;
     use ssz::view::*;
+    /// Hash of the normalized schema this module was generated from.
+    #[allow(dead_code, reason = "generated code using ssz-gen")]
+    pub const SCHEMA_HASH: [u8; 32] = [
+        30, 39, 163, 115, 43, 148, 117, 242, 248, 91, 2, 114, 189, 37, 101, 129, 32, 132,
+        204, 11, 138, 100, 113, 156, 22, 99, 209, 118, 89, 77, 144, 117,
+    ];
     pub type Slot = u64;
     /// Test that serde derives use fully qualified paths (mirrors identifiers use
     /// case)
@@ -189,6 +195,20 @@ pub mod test_serde_derives {
                 blkid: self.blkid().expect("valid view"),
             }
         }
+        /// Converts this view into `out`, reusing the allocations of its
+        /// list and vector fields.
+        ///
+        /// Produces the same value as `to_owned`, but avoids reallocating
+        /// when converting many views in a loop.
+        #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+        pub fn to_owned_into(
+            &self,
+            out: &mut BlockCommitment,
+        ) -> Result<(), ssz::DecodeError> {
+            out.slot = self.slot()?;
+            out.blkid = self.blkid()?;
+            Ok(())
+        }
     }
     #[derive(
         std::clone::Clone,
@@ -319,5 +339,18 @@ pub mod test_serde_derives {
                 value: self.value().expect("valid view"),
             }
         }
+        /// Converts this view into `out`, reusing the allocations of its
+        /// list and vector fields.
+        ///
+        /// Produces the same value as `to_owned`, but avoids reallocating
+        /// when converting many views in a loop.
+        #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+        pub fn to_owned_into(
+            &self,
+            out: &mut OtherType,
+        ) -> Result<(), ssz::DecodeError> {
+            out.value = self.value()?;
+            Ok(())
+        }
     }
 }
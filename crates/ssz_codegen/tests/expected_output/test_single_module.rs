@@ -6,7 +6,13 @@ use ssz_derive::{Encode, Decode};
 use tree_hash::TreeHashDigest;
 use tree_hash_derive::TreeHash;
 use ssz::view::*;
-#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+/// Hash of the normalized schema this module was generated from.
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+pub const SCHEMA_HASH: [u8; 32] = [
+    71, 245, 149, 164, 58, 245, 112, 220, 202, 16, 235, 68, 187, 143, 79, 35, 102, 107,
+    30, 45, 26, 144, 245, 150, 61, 160, 114, 3, 116, 87, 230, 24,
+];
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, Hash)]
 #[ssz(enum_behaviour = "union")]
 pub enum AliasOptionUnion {
     Selector0(u8),
@@ -37,6 +43,40 @@ impl tree_hash::TreeHash for AliasOptionUnion {
         }
     }
 }
+impl std::fmt::Display for AliasOptionUnion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AliasOptionUnion::Selector0(inner) => {
+                write!(f, "AliasOptionUnion::Selector0({inner:?})")
+            }
+            AliasOptionUnion::Selector1(inner) => {
+                write!(f, "AliasOptionUnion::Selector1({inner:?})")
+            }
+        }
+    }
+}
+impl From<u8> for AliasOptionUnion {
+    fn from(value: u8) -> Self {
+        AliasOptionUnion::Selector0(value)
+    }
+}
+impl From<Option<u16>> for AliasOptionUnion {
+    fn from(value: Option<u16>) -> Self {
+        AliasOptionUnion::Selector1(value)
+    }
+}
+#[cfg(test)]
+impl AliasOptionUnion {
+    /// Returns one value per selector, in selector order, each
+    /// holding its member's default. Lets tests check that code
+    /// matching on the union handles every member.
+    pub fn all_selector_examples() -> Vec<Self> {
+        vec![
+            AliasOptionUnion::Selector0(Default::default()),
+            AliasOptionUnion::Selector1(None)
+        ]
+    }
+}
 #[derive(Debug, Copy, Clone)]
 pub struct AliasOptionUnionRef<'a> {
     bytes: &'a [u8],
@@ -53,7 +93,11 @@ impl<'a> AliasOptionUnionRef<'a> {
                 ),
             );
         }
-        ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+        let body = &self.bytes[1..];
+        if <u8 as ssz::Encode>::is_ssz_fixed_len() {
+            ssz::check_union_body_len(body, <u8 as ssz::Encode>::ssz_fixed_len())?;
+        }
+        ssz::view::DecodeView::from_ssz_bytes(body).map_err(|e| e.bounded(body.len()))
     }
     pub fn as_selector1(&self) -> Result<Option<u16>, ssz::DecodeError> {
         if self.selector() != 1u8 {
@@ -63,7 +107,33 @@ impl<'a> AliasOptionUnionRef<'a> {
                 ),
             );
         }
-        ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+        {
+            let bytes = &self.bytes[1..];
+            ssz::view::reject_empty(bytes, 1)?;
+            let selector = bytes[0];
+            match selector {
+                0 => ssz::check_union_body_len(&bytes[1..], 0).map(|()| None),
+                1 => {
+                    if <u16 as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            &bytes[1..],
+                            <u16 as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    let body = &bytes[1..];
+                    let inner = <u16 as ssz::view::DecodeView>::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))?;
+                    Ok(Some(inner))
+                }
+                _ => {
+                    Err(
+                        ssz::DecodeError::BytesInvalid(
+                            format!("Invalid union selector for Option: {}", selector),
+                        ),
+                    )
+                }
+            }
+        }
     }
     pub fn to_owned(&self) -> AliasOptionUnion {
         match self.selector() {
@@ -79,6 +149,16 @@ impl<'a> AliasOptionUnionRef<'a> {
             _ => panic!("Invalid union selector: {}", self.selector()),
         }
     }
+    /// Validates the selector and the selected member, recursing
+    /// into nested views and walking every list and vector, and
+    /// returns the first error.
+    pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+        match self.selector() {
+            0u8 => self.as_selector0().map(|_| ()),
+            1u8 => self.as_selector1().map(|_| ()),
+            selector => Err(ssz::DecodeError::UnionSelectorInvalid(selector)),
+        }
+    }
 }
 impl<'a> ssz::view::DecodeView<'a> for AliasOptionUnionRef<'a> {
     fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
@@ -129,7 +209,7 @@ impl<'a> tree_hash::TreeHash for AliasOptionUnionRef<'a> {
         }
     }
 }
-#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, Hash)]
 #[ssz(enum_behaviour = "union")]
 pub enum FirstUnion {
     Selector0(u8),
@@ -160,6 +240,36 @@ impl tree_hash::TreeHash for FirstUnion {
         }
     }
 }
+impl std::fmt::Display for FirstUnion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FirstUnion::Selector0(inner) => write!(f, "FirstUnion::Selector0({inner:?})"),
+            FirstUnion::Selector1(inner) => write!(f, "FirstUnion::Selector1({inner:?})"),
+        }
+    }
+}
+impl From<u8> for FirstUnion {
+    fn from(value: u8) -> Self {
+        FirstUnion::Selector0(value)
+    }
+}
+impl From<u16> for FirstUnion {
+    fn from(value: u16) -> Self {
+        FirstUnion::Selector1(value)
+    }
+}
+#[cfg(test)]
+impl FirstUnion {
+    /// Returns one value per selector, in selector order, each
+    /// holding its member's default. Lets tests check that code
+    /// matching on the union handles every member.
+    pub fn all_selector_examples() -> Vec<Self> {
+        vec![
+            FirstUnion::Selector0(Default::default()),
+            FirstUnion::Selector1(Default::default())
+        ]
+    }
+}
 #[derive(Debug, Copy, Clone)]
 pub struct FirstUnionRef<'a> {
     bytes: &'a [u8],
@@ -176,7 +286,11 @@ impl<'a> FirstUnionRef<'a> {
                 ),
             );
         }
-        ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+        let body = &self.bytes[1..];
+        if <u8 as ssz::Encode>::is_ssz_fixed_len() {
+            ssz::check_union_body_len(body, <u8 as ssz::Encode>::ssz_fixed_len())?;
+        }
+        ssz::view::DecodeView::from_ssz_bytes(body).map_err(|e| e.bounded(body.len()))
     }
     pub fn as_selector1(&self) -> Result<u16, ssz::DecodeError> {
         if self.selector() != 1u8 {
@@ -186,7 +300,11 @@ impl<'a> FirstUnionRef<'a> {
                 ),
             );
         }
-        ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+        let body = &self.bytes[1..];
+        if <u16 as ssz::Encode>::is_ssz_fixed_len() {
+            ssz::check_union_body_len(body, <u16 as ssz::Encode>::ssz_fixed_len())?;
+        }
+        ssz::view::DecodeView::from_ssz_bytes(body).map_err(|e| e.bounded(body.len()))
     }
     pub fn to_owned(&self) -> FirstUnion {
         match self.selector() {
@@ -195,6 +313,16 @@ impl<'a> FirstUnionRef<'a> {
             _ => panic!("Invalid union selector: {}", self.selector()),
         }
     }
+    /// Validates the selector and the selected member, recursing
+    /// into nested views and walking every list and vector, and
+    /// returns the first error.
+    pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+        match self.selector() {
+            0u8 => self.as_selector0().map(|_| ()),
+            1u8 => self.as_selector1().map(|_| ()),
+            selector => Err(ssz::DecodeError::UnionSelectorInvalid(selector)),
+        }
+    }
 }
 impl<'a> ssz::view::DecodeView<'a> for FirstUnionRef<'a> {
     fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
@@ -245,7 +373,7 @@ impl<'a> tree_hash::TreeHash for FirstUnionRef<'a> {
         }
     }
 }
-#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, Hash)]
 #[ssz(enum_behaviour = "union")]
 pub enum TestUnion {
     Selector0,
@@ -282,6 +410,37 @@ impl tree_hash::TreeHash for TestUnion {
         }
     }
 }
+impl std::fmt::Display for TestUnion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TestUnion::Selector0 => f.write_str("TestUnion::Selector0"),
+            TestUnion::Selector1(inner) => write!(f, "TestUnion::Selector1({inner:?})"),
+            TestUnion::Selector2(inner) => write!(f, "TestUnion::Selector2({inner:?})"),
+        }
+    }
+}
+impl From<u8> for TestUnion {
+    fn from(value: u8) -> Self {
+        TestUnion::Selector1(value)
+    }
+}
+impl From<u16> for TestUnion {
+    fn from(value: u16) -> Self {
+        TestUnion::Selector2(value)
+    }
+}
+#[cfg(test)]
+impl TestUnion {
+    /// Returns one value per selector, in selector order, each
+    /// holding its member's default. Lets tests check that code
+    /// matching on the union handles every member.
+    pub fn all_selector_examples() -> Vec<Self> {
+        vec![
+            TestUnion::Selector0, TestUnion::Selector1(Default::default()),
+            TestUnion::Selector2(Default::default())
+        ]
+    }
+}
 #[derive(Debug, Copy, Clone)]
 pub struct TestUnionRef<'a> {
     bytes: &'a [u8],
@@ -298,7 +457,7 @@ impl<'a> TestUnionRef<'a> {
                 ),
             );
         }
-        Ok(())
+        ssz::check_union_body_len(&self.bytes[1..], 0)
     }
     pub fn as_selector1(&self) -> Result<u8, ssz::DecodeError> {
         if self.selector() != 1u8 {
@@ -308,7 +467,11 @@ impl<'a> TestUnionRef<'a> {
                 ),
             );
         }
-        ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+        let body = &self.bytes[1..];
+        if <u8 as ssz::Encode>::is_ssz_fixed_len() {
+            ssz::check_union_body_len(body, <u8 as ssz::Encode>::ssz_fixed_len())?;
+        }
+        ssz::view::DecodeView::from_ssz_bytes(body).map_err(|e| e.bounded(body.len()))
     }
     pub fn as_selector2(&self) -> Result<u16, ssz::DecodeError> {
         if self.selector() != 2u8 {
@@ -318,7 +481,11 @@ impl<'a> TestUnionRef<'a> {
                 ),
             );
         }
-        ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+        let body = &self.bytes[1..];
+        if <u16 as ssz::Encode>::is_ssz_fixed_len() {
+            ssz::check_union_body_len(body, <u16 as ssz::Encode>::ssz_fixed_len())?;
+        }
+        ssz::view::DecodeView::from_ssz_bytes(body).map_err(|e| e.bounded(body.len()))
     }
     pub fn to_owned(&self) -> TestUnion {
         match self.selector() {
@@ -331,6 +498,17 @@ impl<'a> TestUnionRef<'a> {
             _ => panic!("Invalid union selector: {}", self.selector()),
         }
     }
+    /// Validates the selector and the selected member, recursing
+    /// into nested views and walking every list and vector, and
+    /// returns the first error.
+    pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+        match self.selector() {
+            0u8 => self.as_selector0(),
+            1u8 => self.as_selector1().map(|_| ()),
+            2u8 => self.as_selector2().map(|_| ()),
+            selector => Err(ssz::DecodeError::UnionSelectorInvalid(selector)),
+        }
+    }
 }
 impl<'a> ssz::view::DecodeView<'a> for TestUnionRef<'a> {
     fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
@@ -386,7 +564,7 @@ impl<'a> tree_hash::TreeHash for TestUnionRef<'a> {
         }
     }
 }
-#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, Hash)]
 #[ssz(enum_behaviour = "union")]
 pub enum UnionA {
     Selector0(u8),
@@ -423,6 +601,32 @@ impl tree_hash::TreeHash for UnionA {
         }
     }
 }
+impl std::fmt::Display for UnionA {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UnionA::Selector0(inner) => write!(f, "UnionA::Selector0({inner:?})"),
+            UnionA::Selector1(inner) => write!(f, "UnionA::Selector1({inner:?})"),
+            UnionA::Selector2(inner) => write!(f, "UnionA::Selector2({inner:?})"),
+        }
+    }
+}
+impl From<u16> for UnionA {
+    fn from(value: u16) -> Self {
+        UnionA::Selector2(value)
+    }
+}
+#[cfg(test)]
+impl UnionA {
+    /// Returns one value per selector, in selector order, each
+    /// holding its member's default. Lets tests check that code
+    /// matching on the union handles every member.
+    pub fn all_selector_examples() -> Vec<Self> {
+        vec![
+            UnionA::Selector0(Default::default()), UnionA::Selector1(Default::default()),
+            UnionA::Selector2(Default::default())
+        ]
+    }
+}
 #[derive(Debug, Copy, Clone)]
 pub struct UnionARef<'a> {
     bytes: &'a [u8],
@@ -439,7 +643,11 @@ impl<'a> UnionARef<'a> {
                 ),
             );
         }
-        ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+        let body = &self.bytes[1..];
+        if <u8 as ssz::Encode>::is_ssz_fixed_len() {
+            ssz::check_union_body_len(body, <u8 as ssz::Encode>::ssz_fixed_len())?;
+        }
+        ssz::view::DecodeView::from_ssz_bytes(body).map_err(|e| e.bounded(body.len()))
     }
     pub fn as_selector1(&self) -> Result<u8, ssz::DecodeError> {
         if self.selector() != 1u8 {
@@ -449,7 +657,11 @@ impl<'a> UnionARef<'a> {
                 ),
             );
         }
-        ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+        let body = &self.bytes[1..];
+        if <u8 as ssz::Encode>::is_ssz_fixed_len() {
+            ssz::check_union_body_len(body, <u8 as ssz::Encode>::ssz_fixed_len())?;
+        }
+        ssz::view::DecodeView::from_ssz_bytes(body).map_err(|e| e.bounded(body.len()))
     }
     pub fn as_selector2(&self) -> Result<u16, ssz::DecodeError> {
         if self.selector() != 2u8 {
@@ -459,7 +671,11 @@ impl<'a> UnionARef<'a> {
                 ),
             );
         }
-        ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+        let body = &self.bytes[1..];
+        if <u16 as ssz::Encode>::is_ssz_fixed_len() {
+            ssz::check_union_body_len(body, <u16 as ssz::Encode>::ssz_fixed_len())?;
+        }
+        ssz::view::DecodeView::from_ssz_bytes(body).map_err(|e| e.bounded(body.len()))
     }
     pub fn to_owned(&self) -> UnionA {
         match self.selector() {
@@ -469,6 +685,17 @@ impl<'a> UnionARef<'a> {
             _ => panic!("Invalid union selector: {}", self.selector()),
         }
     }
+    /// Validates the selector and the selected member, recursing
+    /// into nested views and walking every list and vector, and
+    /// returns the first error.
+    pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+        match self.selector() {
+            0u8 => self.as_selector0().map(|_| ()),
+            1u8 => self.as_selector1().map(|_| ()),
+            2u8 => self.as_selector2().map(|_| ()),
+            selector => Err(ssz::DecodeError::UnionSelectorInvalid(selector)),
+        }
+    }
 }
 impl<'a> ssz::view::DecodeView<'a> for UnionARef<'a> {
     fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
@@ -526,7 +753,7 @@ impl<'a> tree_hash::TreeHash for UnionARef<'a> {
         }
     }
 }
-#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, Hash)]
 #[ssz(enum_behaviour = "union")]
 pub enum UnionB {
     Selector0(u8),
@@ -569,6 +796,36 @@ impl tree_hash::TreeHash for UnionB {
         }
     }
 }
+impl std::fmt::Display for UnionB {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UnionB::Selector0(inner) => write!(f, "UnionB::Selector0({inner:?})"),
+            UnionB::UnionA(inner) => write!(f, "UnionB::UnionA({inner:?})"),
+            UnionB::Selector2(inner) => write!(f, "UnionB::Selector2({inner:?})"),
+            UnionB::Selector3(inner) => write!(f, "UnionB::Selector3({inner:?})"),
+        }
+    }
+}
+impl From<u8> for UnionB {
+    fn from(value: u8) -> Self {
+        UnionB::Selector0(value)
+    }
+}
+impl From<UnionA> for UnionB {
+    fn from(value: UnionA) -> Self {
+        UnionB::UnionA(value)
+    }
+}
+impl From<u32> for UnionB {
+    fn from(value: u32) -> Self {
+        UnionB::Selector2(value)
+    }
+}
+impl From<VariableList<u8, 12usize>> for UnionB {
+    fn from(value: VariableList<u8, 12usize>) -> Self {
+        UnionB::Selector3(value)
+    }
+}
 #[derive(Debug, Copy, Clone)]
 pub struct UnionBRef<'a> {
     bytes: &'a [u8],
@@ -585,7 +842,11 @@ impl<'a> UnionBRef<'a> {
                 ),
             );
         }
-        ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+        let body = &self.bytes[1..];
+        if <u8 as ssz::Encode>::is_ssz_fixed_len() {
+            ssz::check_union_body_len(body, <u8 as ssz::Encode>::ssz_fixed_len())?;
+        }
+        ssz::view::DecodeView::from_ssz_bytes(body).map_err(|e| e.bounded(body.len()))
     }
     pub fn as_selector1(&self) -> Result<UnionARef<'a>, ssz::DecodeError> {
         if self.selector() != 1u8 {
@@ -595,7 +856,11 @@ impl<'a> UnionBRef<'a> {
                 ),
             );
         }
-        ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+        let body = &self.bytes[1..];
+        if <UnionA as ssz::Encode>::is_ssz_fixed_len() {
+            ssz::check_union_body_len(body, <UnionA as ssz::Encode>::ssz_fixed_len())?;
+        }
+        ssz::view::DecodeView::from_ssz_bytes(body).map_err(|e| e.bounded(body.len()))
     }
     pub fn as_selector2(&self) -> Result<u32, ssz::DecodeError> {
         if self.selector() != 2u8 {
@@ -605,7 +870,11 @@ impl<'a> UnionBRef<'a> {
                 ),
             );
         }
-        ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+        let body = &self.bytes[1..];
+        if <u32 as ssz::Encode>::is_ssz_fixed_len() {
+            ssz::check_union_body_len(body, <u32 as ssz::Encode>::ssz_fixed_len())?;
+        }
+        ssz::view::DecodeView::from_ssz_bytes(body).map_err(|e| e.bounded(body.len()))
     }
     pub fn as_selector3(&self) -> Result<BytesRef<'a, 12usize>, ssz::DecodeError> {
         if self.selector() != 3u8 {
@@ -615,7 +884,14 @@ impl<'a> UnionBRef<'a> {
                 ),
             );
         }
-        ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+        let body = &self.bytes[1..];
+        if <VariableList<u8, 12usize> as ssz::Encode>::is_ssz_fixed_len() {
+            ssz::check_union_body_len(
+                body,
+                <VariableList<u8, 12usize> as ssz::Encode>::ssz_fixed_len(),
+            )?;
+        }
+        ssz::view::DecodeView::from_ssz_bytes(body).map_err(|e| e.bounded(body.len()))
     }
     pub fn to_owned(&self) -> UnionB {
         match self.selector() {
@@ -636,6 +912,22 @@ impl<'a> UnionBRef<'a> {
             _ => panic!("Invalid union selector: {}", self.selector()),
         }
     }
+    /// Validates the selector and the selected member, recursing
+    /// into nested views and walking every list and vector, and
+    /// returns the first error.
+    pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+        match self.selector() {
+            0u8 => self.as_selector0().map(|_| ()),
+            1u8 => {
+                let value = self.as_selector1()?;
+                value.validate_deep()?;
+                Ok(())
+            }
+            2u8 => self.as_selector2().map(|_| ()),
+            3u8 => self.as_selector3().map(|_| ()),
+            selector => Err(ssz::DecodeError::UnionSelectorInvalid(selector)),
+        }
+    }
 }
 impl<'a> ssz::view::DecodeView<'a> for UnionBRef<'a> {
     fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
@@ -700,11 +992,11 @@ impl<'a> tree_hash::TreeHash for UnionBRef<'a> {
         }
     }
 }
-#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, Hash)]
 #[ssz(enum_behaviour = "union")]
 pub enum UnionC {
-    AliasUintAlias(AliasUintAlias),
-    AliasUintAlias(AliasUintAlias),
+    AliasUintAlias0(AliasUintAlias),
+    AliasUintAlias1(AliasUintAlias),
 }
 impl tree_hash::TreeHash for UnionC {
     fn tree_hash_type() -> tree_hash::TreeHashType {
@@ -718,12 +1010,12 @@ impl tree_hash::TreeHash for UnionC {
     }
     fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
         match self {
-            UnionC::AliasUintAlias(inner) => {
+            UnionC::AliasUintAlias0(inner) => {
                 let root = <_ as tree_hash::TreeHash>::tree_hash_root::<H>(inner);
                 tree_hash::mix_in_selector_with_hasher::<H>(&root, 0u8)
                     .expect("valid selector")
             }
-            UnionC::AliasUintAlias(inner) => {
+            UnionC::AliasUintAlias1(inner) => {
                 let root = <_ as tree_hash::TreeHash>::tree_hash_root::<H>(inner);
                 tree_hash::mix_in_selector_with_hasher::<H>(&root, 1u8)
                     .expect("valid selector")
@@ -731,6 +1023,30 @@ impl tree_hash::TreeHash for UnionC {
         }
     }
 }
+impl std::fmt::Display for UnionC {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UnionC::AliasUintAlias0(inner) => {
+                write!(f, "UnionC::AliasUintAlias0({inner:?})")
+            }
+            UnionC::AliasUintAlias1(inner) => {
+                write!(f, "UnionC::AliasUintAlias1({inner:?})")
+            }
+        }
+    }
+}
+#[cfg(test)]
+impl UnionC {
+    /// Returns one value per selector, in selector order, each
+    /// holding its member's default. Lets tests check that code
+    /// matching on the union handles every member.
+    pub fn all_selector_examples() -> Vec<Self> {
+        vec![
+            UnionC::AliasUintAlias0(Default::default()),
+            UnionC::AliasUintAlias1(Default::default())
+        ]
+    }
+}
 #[derive(Debug, Copy, Clone)]
 pub struct UnionCRef<'a> {
     bytes: &'a [u8],
@@ -747,7 +1063,14 @@ impl<'a> UnionCRef<'a> {
                 ),
             );
         }
-        ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+        let body = &self.bytes[1..];
+        if <AliasUintAlias as ssz::Encode>::is_ssz_fixed_len() {
+            ssz::check_union_body_len(
+                body,
+                <AliasUintAlias as ssz::Encode>::ssz_fixed_len(),
+            )?;
+        }
+        ssz::view::DecodeView::from_ssz_bytes(body).map_err(|e| e.bounded(body.len()))
     }
     pub fn as_selector1(&self) -> Result<u16, ssz::DecodeError> {
         if self.selector() != 1u8 {
@@ -757,15 +1080,32 @@ impl<'a> UnionCRef<'a> {
                 ),
             );
         }
-        ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+        let body = &self.bytes[1..];
+        if <AliasUintAlias as ssz::Encode>::is_ssz_fixed_len() {
+            ssz::check_union_body_len(
+                body,
+                <AliasUintAlias as ssz::Encode>::ssz_fixed_len(),
+            )?;
+        }
+        ssz::view::DecodeView::from_ssz_bytes(body).map_err(|e| e.bounded(body.len()))
     }
     pub fn to_owned(&self) -> UnionC {
         match self.selector() {
-            0u8 => UnionC::AliasUintAlias(self.as_selector0().expect("valid selector")),
-            1u8 => UnionC::AliasUintAlias(self.as_selector1().expect("valid selector")),
+            0u8 => UnionC::AliasUintAlias0(self.as_selector0().expect("valid selector")),
+            1u8 => UnionC::AliasUintAlias1(self.as_selector1().expect("valid selector")),
             _ => panic!("Invalid union selector: {}", self.selector()),
         }
     }
+    /// Validates the selector and the selected member, recursing
+    /// into nested views and walking every list and vector, and
+    /// returns the first error.
+    pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+        match self.selector() {
+            0u8 => self.as_selector0().map(|_| ()),
+            1u8 => self.as_selector1().map(|_| ()),
+            selector => Err(ssz::DecodeError::UnionSelectorInvalid(selector)),
+        }
+    }
 }
 impl<'a> ssz::view::DecodeView<'a> for UnionCRef<'a> {
     fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
@@ -816,11 +1156,11 @@ impl<'a> tree_hash::TreeHash for UnionCRef<'a> {
         }
     }
 }
-#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, Hash)]
 #[ssz(enum_behaviour = "union")]
 pub enum UnionD {
-    AliasUintAlias(AliasUintAlias),
-    AliasUintAlias(AliasUintAlias),
+    AliasUintAlias0(AliasUintAlias),
+    AliasUintAlias1(AliasUintAlias),
 }
 impl tree_hash::TreeHash for UnionD {
     fn tree_hash_type() -> tree_hash::TreeHashType {
@@ -834,12 +1174,12 @@ impl tree_hash::TreeHash for UnionD {
     }
     fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
         match self {
-            UnionD::AliasUintAlias(inner) => {
+            UnionD::AliasUintAlias0(inner) => {
                 let root = <_ as tree_hash::TreeHash>::tree_hash_root::<H>(inner);
                 tree_hash::mix_in_selector_with_hasher::<H>(&root, 0u8)
                     .expect("valid selector")
             }
-            UnionD::AliasUintAlias(inner) => {
+            UnionD::AliasUintAlias1(inner) => {
                 let root = <_ as tree_hash::TreeHash>::tree_hash_root::<H>(inner);
                 tree_hash::mix_in_selector_with_hasher::<H>(&root, 1u8)
                     .expect("valid selector")
@@ -847,6 +1187,30 @@ impl tree_hash::TreeHash for UnionD {
         }
     }
 }
+impl std::fmt::Display for UnionD {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UnionD::AliasUintAlias0(inner) => {
+                write!(f, "UnionD::AliasUintAlias0({inner:?})")
+            }
+            UnionD::AliasUintAlias1(inner) => {
+                write!(f, "UnionD::AliasUintAlias1({inner:?})")
+            }
+        }
+    }
+}
+#[cfg(test)]
+impl UnionD {
+    /// Returns one value per selector, in selector order, each
+    /// holding its member's default. Lets tests check that code
+    /// matching on the union handles every member.
+    pub fn all_selector_examples() -> Vec<Self> {
+        vec![
+            UnionD::AliasUintAlias0(Default::default()),
+            UnionD::AliasUintAlias1(Default::default())
+        ]
+    }
+}
 #[derive(Debug, Copy, Clone)]
 pub struct UnionDRef<'a> {
     bytes: &'a [u8],
@@ -863,7 +1227,14 @@ impl<'a> UnionDRef<'a> {
                 ),
             );
         }
-        ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+        let body = &self.bytes[1..];
+        if <AliasUintAlias as ssz::Encode>::is_ssz_fixed_len() {
+            ssz::check_union_body_len(
+                body,
+                <AliasUintAlias as ssz::Encode>::ssz_fixed_len(),
+            )?;
+        }
+        ssz::view::DecodeView::from_ssz_bytes(body).map_err(|e| e.bounded(body.len()))
     }
     pub fn as_selector1(&self) -> Result<u16, ssz::DecodeError> {
         if self.selector() != 1u8 {
@@ -873,15 +1244,32 @@ impl<'a> UnionDRef<'a> {
                 ),
             );
         }
-        ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+        let body = &self.bytes[1..];
+        if <AliasUintAlias as ssz::Encode>::is_ssz_fixed_len() {
+            ssz::check_union_body_len(
+                body,
+                <AliasUintAlias as ssz::Encode>::ssz_fixed_len(),
+            )?;
+        }
+        ssz::view::DecodeView::from_ssz_bytes(body).map_err(|e| e.bounded(body.len()))
     }
     pub fn to_owned(&self) -> UnionD {
         match self.selector() {
-            0u8 => UnionD::AliasUintAlias(self.as_selector0().expect("valid selector")),
-            1u8 => UnionD::AliasUintAlias(self.as_selector1().expect("valid selector")),
+            0u8 => UnionD::AliasUintAlias0(self.as_selector0().expect("valid selector")),
+            1u8 => UnionD::AliasUintAlias1(self.as_selector1().expect("valid selector")),
             _ => panic!("Invalid union selector: {}", self.selector()),
         }
     }
+    /// Validates the selector and the selected member, recursing
+    /// into nested views and walking every list and vector, and
+    /// returns the first error.
+    pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+        match self.selector() {
+            0u8 => self.as_selector0().map(|_| ()),
+            1u8 => self.as_selector1().map(|_| ()),
+            selector => Err(ssz::DecodeError::UnionSelectorInvalid(selector)),
+        }
+    }
 }
 impl<'a> ssz::view::DecodeView<'a> for UnionDRef<'a> {
     fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
@@ -959,6 +1347,12 @@ pub struct Alpha {
     pub b: u16,
     pub c: AliasVecB,
 }
+impl Alpha {
+    /// Byte offset of each field in the SSZ encoding, in declaration order.
+    pub const FIELD_OFFSETS: &[usize] = &[0, 1, 3];
+    /// Byte size of each field in the SSZ encoding, in declaration order.
+    pub const FIELD_SIZES: &[usize] = &[1, 2, 10];
+}
 impl tree_hash::TreeHash for Alpha {
     fn tree_hash_type() -> tree_hash::TreeHashType {
         tree_hash::TreeHashType::Container
@@ -984,6 +1378,22 @@ impl tree_hash::TreeHash for Alpha {
         hasher.finish().expect("tree hash derive should not have a remaining buffer")
     }
 }
+impl tree_hash::TreeHashLeaves for Alpha {
+    fn tree_hash_leaf_count() -> usize {
+        3usize
+    }
+    fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+        &self,
+        index: usize,
+    ) -> H::Output {
+        match index {
+            0usize => <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.a),
+            1usize => <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.b),
+            2usize => <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.c),
+            _ => panic!("leaf index {index} out of range for {} leaves", 3usize),
+        }
+    }
+}
 /// Zero-copy view over [`Alpha`].
 ///
 /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
@@ -1003,67 +1413,31 @@ pub struct AlphaRef<'a> {
 #[allow(dead_code, reason = "generated code using ssz-gen")]
 impl<'a> AlphaRef<'a> {
     pub fn a(&self) -> Result<u8, ssz::DecodeError> {
-        let bytes = ssz::layout::read_field_bytes(
-            self.bytes,
-            &[
-                (
-                    <u8 as ssz::Encode>::is_ssz_fixed_len(),
-                    <u8 as ssz::Encode>::ssz_fixed_len(),
-                ),
-                (
-                    <u16 as ssz::Encode>::is_ssz_fixed_len(),
-                    <u16 as ssz::Encode>::ssz_fixed_len(),
-                ),
-                (
-                    <AliasVecB as ssz::Encode>::is_ssz_fixed_len(),
-                    <AliasVecB as ssz::Encode>::ssz_fixed_len(),
-                ),
-            ],
-            0usize,
-        )?;
-        ssz::view::DecodeView::from_ssz_bytes(bytes)
+        let bytes = &self.bytes[0..1];
+        ssz::view::DecodeView::from_ssz_bytes(bytes).map_err(|e| e.bounded(bytes.len()))
     }
     pub fn b(&self) -> Result<u16, ssz::DecodeError> {
-        let bytes = ssz::layout::read_field_bytes(
-            self.bytes,
-            &[
-                (
-                    <u8 as ssz::Encode>::is_ssz_fixed_len(),
-                    <u8 as ssz::Encode>::ssz_fixed_len(),
-                ),
-                (
-                    <u16 as ssz::Encode>::is_ssz_fixed_len(),
-                    <u16 as ssz::Encode>::ssz_fixed_len(),
-                ),
-                (
-                    <AliasVecB as ssz::Encode>::is_ssz_fixed_len(),
-                    <AliasVecB as ssz::Encode>::ssz_fixed_len(),
-                ),
-            ],
-            1usize,
-        )?;
-        ssz::view::DecodeView::from_ssz_bytes(bytes)
+        let bytes = &self.bytes[1..3];
+        ssz::view::DecodeView::from_ssz_bytes(bytes).map_err(|e| e.bounded(bytes.len()))
     }
     pub fn c(&self) -> Result<FixedBytesRef<'a, 10usize>, ssz::DecodeError> {
-        let bytes = ssz::layout::read_field_bytes(
-            self.bytes,
-            &[
-                (
-                    <u8 as ssz::Encode>::is_ssz_fixed_len(),
-                    <u8 as ssz::Encode>::ssz_fixed_len(),
-                ),
-                (
-                    <u16 as ssz::Encode>::is_ssz_fixed_len(),
-                    <u16 as ssz::Encode>::ssz_fixed_len(),
-                ),
-                (
-                    <AliasVecB as ssz::Encode>::is_ssz_fixed_len(),
-                    <AliasVecB as ssz::Encode>::ssz_fixed_len(),
-                ),
-            ],
-            2usize,
-        )?;
-        ssz::view::DecodeView::from_ssz_bytes(bytes)
+        let bytes = &self.bytes[3..13];
+        ssz::view::DecodeView::from_ssz_bytes(bytes).map_err(|e| e.bounded(bytes.len()))
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> AlphaRef<'a> {
+    /// Validates every field of this view, recursing into nested
+    /// views and walking every list and vector, and returns the
+    /// first error.
+    ///
+    /// `from_ssz_bytes` only checks the view's own layout, so this
+    /// rejects bytes that would otherwise fail on a later access.
+    pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+        self.a()?;
+        self.b()?;
+        self.c()?;
+        Ok(())
     }
 }
 impl<'a> tree_hash::TreeHash for AlphaRef<'a> {
@@ -1125,6 +1499,19 @@ impl<'a> ssz::view::DecodeView<'a> for AlphaRef<'a> {
         Ok(Self { bytes })
     }
 }
+impl<'a> TryFrom<&'a [u8]> for AlphaRef<'a> {
+    type Error = ssz::DecodeError;
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> AlphaRef<'a> {
+    /// Returns the SSZ bytes this view was decoded from.
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
 impl<'a> ssz::view::SszTypeInfo for AlphaRef<'a> {
     fn is_ssz_fixed_len() -> bool {
         usize::from(!<u8 as ssz::Encode>::is_ssz_fixed_len())
@@ -1157,6 +1544,55 @@ impl<'a> AlphaRef<'a> {
             c: ssz_types::FixedBytes(self.c().expect("valid view").to_owned()),
         }
     }
+    /// Converts this view into `out`, reusing the allocations of its
+    /// list and vector fields.
+    ///
+    /// Produces the same value as `to_owned`, but avoids reallocating
+    /// when converting many views in a loop. The view is validated
+    /// with `validate_deep` before anything is written, so on error
+    /// `out` is left unchanged.
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    pub fn to_owned_into(&self, out: &mut Alpha) -> Result<(), ssz::DecodeError> {
+        self.validate_deep()?;
+        out.a = self.a()?;
+        out.b = self.b()?;
+        out.c = ssz_types::FixedBytes(self.c()?.to_owned());
+        Ok(())
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> AlphaRef<'a> {
+    fn eq_owned(&self, other: &Alpha) -> Result<bool, ssz::DecodeError> {
+        if !({
+            let value: u8 = self.a()?;
+            value == other.a
+        }) {
+            return Ok(false);
+        }
+        if !({
+            let value: u16 = self.b()?;
+            value == other.b
+        }) {
+            return Ok(false);
+        }
+        if !({
+            let value: AliasVecB = ssz_types::FixedBytes(self.c()?.to_owned());
+            value == other.c
+        }) {
+            return Ok(false);
+        }
+        Ok(true)
+    }
+}
+impl<'a> PartialEq<Alpha> for AlphaRef<'a> {
+    fn eq(&self, other: &Alpha) -> bool {
+        self.eq_owned(other).unwrap_or(false)
+    }
+}
+impl<'a> PartialEq<AlphaRef<'a>> for Alpha {
+    fn eq(&self, other: &AlphaRef<'a>) -> bool {
+        other == self
+    }
 }
 #[derive(
     std::clone::Clone,
@@ -1197,6 +1633,22 @@ impl tree_hash::TreeHash for Beta {
         hasher.finish().expect("tree hash derive should not have a remaining buffer")
     }
 }
+impl tree_hash::TreeHashLeaves for Beta {
+    fn tree_hash_leaf_count() -> usize {
+        3usize
+    }
+    fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+        &self,
+        index: usize,
+    ) -> H::Output {
+        match index {
+            0usize => <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.d),
+            1usize => <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.e),
+            2usize => <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.f),
+            _ => panic!("leaf index {index} out of range for {} leaves", 3usize),
+        }
+    }
+}
 /// Zero-copy view over [`Beta`].
 ///
 /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
@@ -1234,7 +1686,7 @@ impl<'a> BetaRef<'a> {
             ],
             0usize,
         )?;
-        ssz::view::DecodeView::from_ssz_bytes(bytes)
+        ssz::view::DecodeView::from_ssz_bytes(bytes).map_err(|e| e.bounded(bytes.len()))
     }
     pub fn e(&self) -> Result<u8, ssz::DecodeError> {
         let bytes = ssz::layout::read_field_bytes(
@@ -1255,7 +1707,7 @@ impl<'a> BetaRef<'a> {
             ],
             1usize,
         )?;
-        ssz::view::DecodeView::from_ssz_bytes(bytes)
+        ssz::view::DecodeView::from_ssz_bytes(bytes).map_err(|e| e.bounded(bytes.len()))
     }
     pub fn f(&self) -> Result<u16, ssz::DecodeError> {
         let bytes = ssz::layout::read_field_bytes(
@@ -1276,7 +1728,22 @@ impl<'a> BetaRef<'a> {
             ],
             2usize,
         )?;
-        ssz::view::DecodeView::from_ssz_bytes(bytes)
+        ssz::view::DecodeView::from_ssz_bytes(bytes).map_err(|e| e.bounded(bytes.len()))
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> BetaRef<'a> {
+    /// Validates every field of this view, recursing into nested
+    /// views and walking every list and vector, and returns the
+    /// first error.
+    ///
+    /// `from_ssz_bytes` only checks the view's own layout, so this
+    /// rejects bytes that would otherwise fail on a later access.
+    pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+        self.d()?;
+        self.e()?;
+        self.f()?;
+        Ok(())
     }
 }
 impl<'a> tree_hash::TreeHash for BetaRef<'a> {
@@ -1338,6 +1805,19 @@ impl<'a> ssz::view::DecodeView<'a> for BetaRef<'a> {
         Ok(Self { bytes })
     }
 }
+impl<'a> TryFrom<&'a [u8]> for BetaRef<'a> {
+    type Error = ssz::DecodeError;
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> BetaRef<'a> {
+    /// Returns the SSZ bytes this view was decoded from.
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
 impl<'a> ssz::view::SszTypeInfo for BetaRef<'a> {
     fn is_ssz_fixed_len() -> bool {
         usize::from(!<AliasListAlias as ssz::Encode>::is_ssz_fixed_len())
@@ -1372,6 +1852,60 @@ impl<'a> BetaRef<'a> {
             f: self.f().expect("valid view"),
         }
     }
+    /// Converts this view into `out`, reusing the allocations of its
+    /// list and vector fields.
+    ///
+    /// Produces the same value as `to_owned`, but avoids reallocating
+    /// when converting many views in a loop. The view is validated
+    /// with `validate_deep` before anything is written, so on error
+    /// `out` is left unchanged.
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    pub fn to_owned_into(&self, out: &mut Beta) -> Result<(), ssz::DecodeError> {
+        self.validate_deep()?;
+        let view = self.d()?;
+        out.d.clear();
+        out.d.try_extend_from_slice(view.as_bytes()).expect("valid view");
+        out.e = self.e()?;
+        out.f = self.f()?;
+        Ok(())
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> BetaRef<'a> {
+    fn eq_owned(&self, other: &Beta) -> Result<bool, ssz::DecodeError> {
+        if !({
+            let value: AliasListAlias = ssz_types::VariableList::new(
+                    self.d()?.to_owned(),
+                )
+                .expect("valid view");
+            value == other.d
+        }) {
+            return Ok(false);
+        }
+        if !({
+            let value: u8 = self.e()?;
+            value == other.e
+        }) {
+            return Ok(false);
+        }
+        if !({
+            let value: AliasUintAlias = self.f()?;
+            value == other.f
+        }) {
+            return Ok(false);
+        }
+        Ok(true)
+    }
+}
+impl<'a> PartialEq<Beta> for BetaRef<'a> {
+    fn eq(&self, other: &Beta) -> bool {
+        self.eq_owned(other).unwrap_or(false)
+    }
+}
+impl<'a> PartialEq<BetaRef<'a>> for Beta {
+    fn eq(&self, other: &BetaRef<'a>) -> bool {
+        other == self
+    }
 }
 #[derive(
     std::clone::Clone,
@@ -1445,9 +1979,8 @@ impl<'a> GammaRef<'a> {
         let bitvector_bytes = self
             .bytes
             .get(..6usize)
-            .ok_or(ssz::DecodeError::InvalidByteLength {
-                len: self.bytes.len(),
-                expected: 6usize,
+            .ok_or_else(|| ssz::DecodeError::Incomplete {
+                needed: 6usize - self.bytes.len(),
             })?;
         let bitvector = ssz_types::BitVector::<
             42usize,
@@ -1480,7 +2013,8 @@ impl<'a> GammaRef<'a> {
             Some(bytes) => bytes,
             None => return Ok(ssz_types::Optional::None),
         };
-        let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)?;
+        let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)
+            .map_err(|e| e.bounded(field_bytes.len()))?;
         Ok(ssz_types::Optional::Some(inner))
     }
     pub fn h(&self) -> Result<Optional<ListRef<'a, u16, 8usize>>, ssz::DecodeError> {
@@ -1488,9 +2022,8 @@ impl<'a> GammaRef<'a> {
         let bitvector_bytes = self
             .bytes
             .get(..6usize)
-            .ok_or(ssz::DecodeError::InvalidByteLength {
-                len: self.bytes.len(),
-                expected: 6usize,
+            .ok_or_else(|| ssz::DecodeError::Incomplete {
+                needed: 6usize - self.bytes.len(),
             })?;
         let bitvector = ssz_types::BitVector::<
             42usize,
@@ -1527,10 +2060,30 @@ impl<'a> GammaRef<'a> {
             'a,
             u16,
             8usize,
-        > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)?;
+        > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)
+            .map_err(|e| e.bounded(field_bytes.len()))?;
         Ok(ssz_types::Optional::Some(inner))
     }
 }
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> GammaRef<'a> {
+    /// Validates every field of this view, recursing into nested
+    /// views and walking every list and vector, and returns the
+    /// first error.
+    ///
+    /// `from_ssz_bytes` only checks the view's own layout, so this
+    /// rejects bytes that would otherwise fail on a later access.
+    pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+        self.g()?;
+        let value = self.h()?;
+        if let ssz_types::Optional::Some(inner) = value {
+            for item in inner.iter() {
+                item?;
+            }
+        }
+        Ok(())
+    }
+}
 impl<'a> tree_hash::TreeHash for GammaRef<'a> {
     fn tree_hash_type() -> tree_hash::TreeHashType {
         tree_hash::TreeHashType::StableContainer
@@ -1574,9 +2127,8 @@ impl<'a> ssz::view::DecodeView<'a> for GammaRef<'a> {
         use ssz::Decode;
         let bitvector_bytes = bytes
             .get(..6usize)
-            .ok_or(ssz::DecodeError::InvalidByteLength {
-                len: bytes.len(),
-                expected: 6usize,
+            .ok_or_else(|| ssz::DecodeError::Incomplete {
+                needed: 6usize - bytes.len(),
             })?;
         let bitvector = ssz_types::BitVector::<
             42usize,
@@ -1614,6 +2166,19 @@ impl<'a> ssz::view::DecodeView<'a> for GammaRef<'a> {
         Ok(Self { bytes })
     }
 }
+impl<'a> TryFrom<&'a [u8]> for GammaRef<'a> {
+    type Error = ssz::DecodeError;
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> GammaRef<'a> {
+    /// Returns the SSZ bytes this view was decoded from.
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
 impl<'a> ssz::view::SszTypeInfo for GammaRef<'a> {
     fn is_ssz_fixed_len() -> bool {
         false
@@ -1645,6 +2210,76 @@ impl<'a> GammaRef<'a> {
             },
         }
     }
+    /// Converts this view into `out`, reusing the allocations of its
+    /// list and vector fields.
+    ///
+    /// Produces the same value as `to_owned`, but avoids reallocating
+    /// when converting many views in a loop. The view is validated
+    /// with `validate_deep` before anything is written, so on error
+    /// `out` is left unchanged.
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    pub fn to_owned_into(&self, out: &mut Gamma) -> Result<(), ssz::DecodeError> {
+        self.validate_deep()?;
+        out.g = self.g()?;
+        out.h = match self.h()? {
+            ssz_types::Optional::Some(inner) => {
+                ssz_types::Optional::Some(ssz_types::view::ToOwnedSsz::to_owned(&inner))
+            }
+            ssz_types::Optional::None => ssz_types::Optional::None,
+        };
+        Ok(())
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> GammaRef<'a> {
+    fn eq_owned(&self, other: &Gamma) -> Result<bool, ssz::DecodeError> {
+        use ssz::Decode;
+        let bitvector_bytes = self
+            .bytes
+            .get(..6usize)
+            .ok_or_else(|| ssz::DecodeError::Incomplete {
+                needed: 6usize - self.bytes.len(),
+            })?;
+        let bitvector = ssz_types::BitVector::<
+            42usize,
+        >::from_ssz_bytes(bitvector_bytes)?;
+        if bitvector.get(0usize).unwrap_or(false) != other.g.is_some() {
+            return Ok(false);
+        }
+        if bitvector.get(1usize).unwrap_or(false) != other.h.is_some() {
+            return Ok(false);
+        }
+        if !({
+            let value: Optional<u8> = self.g()?;
+            value == other.g
+        }) {
+            return Ok(false);
+        }
+        if !({
+            let value: Optional<VariableList<AliasUintAlias, 8usize>> = match self.h()? {
+                ssz_types::Optional::Some(inner) => {
+                    ssz_types::Optional::Some(
+                        ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                    )
+                }
+                ssz_types::Optional::None => ssz_types::Optional::None,
+            };
+            value == other.h
+        }) {
+            return Ok(false);
+        }
+        Ok(true)
+    }
+}
+impl<'a> PartialEq<Gamma> for GammaRef<'a> {
+    fn eq(&self, other: &Gamma) -> bool {
+        self.eq_owned(other).unwrap_or(false)
+    }
+}
+impl<'a> PartialEq<GammaRef<'a>> for Gamma {
+    fn eq(&self, other: &GammaRef<'a>) -> bool {
+        other == self
+    }
 }
 #[derive(
     std::clone::Clone,
@@ -1659,6 +2294,12 @@ pub struct Delta {
     pub z: bool,
     pub w: u8,
 }
+impl Delta {
+    /// Byte offset of each field in the SSZ encoding, in declaration order.
+    pub const FIELD_OFFSETS: &[usize] = &[0, 1];
+    /// Byte size of each field in the SSZ encoding, in declaration order.
+    pub const FIELD_SIZES: &[usize] = &[1, 1];
+}
 impl tree_hash::TreeHash for Delta {
     fn tree_hash_type() -> tree_hash::TreeHashType {
         tree_hash::TreeHashType::Container
@@ -1681,6 +2322,21 @@ impl tree_hash::TreeHash for Delta {
         hasher.finish().expect("tree hash derive should not have a remaining buffer")
     }
 }
+impl tree_hash::TreeHashLeaves for Delta {
+    fn tree_hash_leaf_count() -> usize {
+        2usize
+    }
+    fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+        &self,
+        index: usize,
+    ) -> H::Output {
+        match index {
+            0usize => <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.z),
+            1usize => <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.w),
+            _ => panic!("leaf index {index} out of range for {} leaves", 2usize),
+        }
+    }
+}
 /// Zero-copy view over [`Delta`].
 ///
 /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
@@ -1700,38 +2356,26 @@ pub struct DeltaRef<'a> {
 #[allow(dead_code, reason = "generated code using ssz-gen")]
 impl<'a> DeltaRef<'a> {
     pub fn z(&self) -> Result<bool, ssz::DecodeError> {
-        let bytes = ssz::layout::read_field_bytes(
-            self.bytes,
-            &[
-                (
-                    <bool as ssz::Encode>::is_ssz_fixed_len(),
-                    <bool as ssz::Encode>::ssz_fixed_len(),
-                ),
-                (
-                    <u8 as ssz::Encode>::is_ssz_fixed_len(),
-                    <u8 as ssz::Encode>::ssz_fixed_len(),
-                ),
-            ],
-            0usize,
-        )?;
-        ssz::view::DecodeView::from_ssz_bytes(bytes)
+        let bytes = &self.bytes[0..1];
+        ssz::view::DecodeView::from_ssz_bytes(bytes).map_err(|e| e.bounded(bytes.len()))
     }
     pub fn w(&self) -> Result<u8, ssz::DecodeError> {
-        let bytes = ssz::layout::read_field_bytes(
-            self.bytes,
-            &[
-                (
-                    <bool as ssz::Encode>::is_ssz_fixed_len(),
-                    <bool as ssz::Encode>::ssz_fixed_len(),
-                ),
-                (
-                    <u8 as ssz::Encode>::is_ssz_fixed_len(),
-                    <u8 as ssz::Encode>::ssz_fixed_len(),
-                ),
-            ],
-            1usize,
-        )?;
-        ssz::view::DecodeView::from_ssz_bytes(bytes)
+        let bytes = &self.bytes[1..2];
+        ssz::view::DecodeView::from_ssz_bytes(bytes).map_err(|e| e.bounded(bytes.len()))
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> DeltaRef<'a> {
+    /// Validates every field of this view, recursing into nested
+    /// views and walking every list and vector, and returns the
+    /// first error.
+    ///
+    /// `from_ssz_bytes` only checks the view's own layout, so this
+    /// rejects bytes that would otherwise fail on a later access.
+    pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+        self.z()?;
+        self.w()?;
+        Ok(())
     }
 }
 impl<'a> tree_hash::TreeHash for DeltaRef<'a> {
@@ -1782,6 +2426,19 @@ impl<'a> ssz::view::DecodeView<'a> for DeltaRef<'a> {
         Ok(Self { bytes })
     }
 }
+impl<'a> TryFrom<&'a [u8]> for DeltaRef<'a> {
+    type Error = ssz::DecodeError;
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> DeltaRef<'a> {
+    /// Returns the SSZ bytes this view was decoded from.
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
 impl<'a> ssz::view::SszTypeInfo for DeltaRef<'a> {
     fn is_ssz_fixed_len() -> bool {
         usize::from(!<bool as ssz::Encode>::is_ssz_fixed_len())
@@ -1811,6 +2468,48 @@ impl<'a> DeltaRef<'a> {
             w: self.w().expect("valid view"),
         }
     }
+    /// Converts this view into `out`, reusing the allocations of its
+    /// list and vector fields.
+    ///
+    /// Produces the same value as `to_owned`, but avoids reallocating
+    /// when converting many views in a loop. The view is validated
+    /// with `validate_deep` before anything is written, so on error
+    /// `out` is left unchanged.
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    pub fn to_owned_into(&self, out: &mut Delta) -> Result<(), ssz::DecodeError> {
+        self.validate_deep()?;
+        out.z = self.z()?;
+        out.w = self.w()?;
+        Ok(())
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> DeltaRef<'a> {
+    fn eq_owned(&self, other: &Delta) -> Result<bool, ssz::DecodeError> {
+        if !({
+            let value: bool = self.z()?;
+            value == other.z
+        }) {
+            return Ok(false);
+        }
+        if !({
+            let value: u8 = self.w()?;
+            value == other.w
+        }) {
+            return Ok(false);
+        }
+        Ok(true)
+    }
+}
+impl<'a> PartialEq<Delta> for DeltaRef<'a> {
+    fn eq(&self, other: &Delta) -> bool {
+        self.eq_owned(other).unwrap_or(false)
+    }
+}
+impl<'a> PartialEq<DeltaRef<'a>> for Delta {
+    fn eq(&self, other: &DeltaRef<'a>) -> bool {
+        other == self
+    }
 }
 #[derive(
     std::clone::Clone,
@@ -1898,9 +2597,8 @@ impl<'a> EpsilonRef<'a> {
         let bitvector_bytes = self
             .bytes
             .get(..6usize)
-            .ok_or(ssz::DecodeError::InvalidByteLength {
-                len: self.bytes.len(),
-                expected: 6usize,
+            .ok_or_else(|| ssz::DecodeError::Incomplete {
+                needed: 6usize - self.bytes.len(),
             })?;
         let bitvector = ssz_types::BitVector::<
             42usize,
@@ -1943,7 +2641,8 @@ impl<'a> EpsilonRef<'a> {
             Some(bytes) => bytes,
             None => return Ok(ssz_types::Optional::None),
         };
-        let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)?;
+        let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)
+            .map_err(|e| e.bounded(field_bytes.len()))?;
         Ok(ssz_types::Optional::Some(inner))
     }
     pub fn h(&self) -> Result<Optional<ListRef<'a, u16, 8usize>>, ssz::DecodeError> {
@@ -1951,9 +2650,8 @@ impl<'a> EpsilonRef<'a> {
         let bitvector_bytes = self
             .bytes
             .get(..6usize)
-            .ok_or(ssz::DecodeError::InvalidByteLength {
-                len: self.bytes.len(),
-                expected: 6usize,
+            .ok_or_else(|| ssz::DecodeError::Incomplete {
+                needed: 6usize - self.bytes.len(),
             })?;
         let bitvector = ssz_types::BitVector::<
             42usize,
@@ -2000,7 +2698,8 @@ impl<'a> EpsilonRef<'a> {
             'a,
             u16,
             8usize,
-        > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)?;
+        > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)
+            .map_err(|e| e.bounded(field_bytes.len()))?;
         Ok(ssz_types::Optional::Some(inner))
     }
     pub fn i(&self) -> Result<Optional<u8>, ssz::DecodeError> {
@@ -2008,9 +2707,8 @@ impl<'a> EpsilonRef<'a> {
         let bitvector_bytes = self
             .bytes
             .get(..6usize)
-            .ok_or(ssz::DecodeError::InvalidByteLength {
-                len: self.bytes.len(),
-                expected: 6usize,
+            .ok_or_else(|| ssz::DecodeError::Incomplete {
+                needed: 6usize - self.bytes.len(),
             })?;
         let bitvector = ssz_types::BitVector::<
             42usize,
@@ -2053,7 +2751,8 @@ impl<'a> EpsilonRef<'a> {
             Some(bytes) => bytes,
             None => return Ok(ssz_types::Optional::None),
         };
-        let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)?;
+        let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)
+            .map_err(|e| e.bounded(field_bytes.len()))?;
         Ok(ssz_types::Optional::Some(inner))
     }
     pub fn j(&self) -> Result<Optional<u16>, ssz::DecodeError> {
@@ -2061,9 +2760,8 @@ impl<'a> EpsilonRef<'a> {
         let bitvector_bytes = self
             .bytes
             .get(..6usize)
-            .ok_or(ssz::DecodeError::InvalidByteLength {
-                len: self.bytes.len(),
-                expected: 6usize,
+            .ok_or_else(|| ssz::DecodeError::Incomplete {
+                needed: 6usize - self.bytes.len(),
             })?;
         let bitvector = ssz_types::BitVector::<
             42usize,
@@ -2106,10 +2804,32 @@ impl<'a> EpsilonRef<'a> {
             Some(bytes) => bytes,
             None => return Ok(ssz_types::Optional::None),
         };
-        let inner = <u16 as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)?;
+        let inner = <u16 as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)
+            .map_err(|e| e.bounded(field_bytes.len()))?;
         Ok(ssz_types::Optional::Some(inner))
     }
 }
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> EpsilonRef<'a> {
+    /// Validates every field of this view, recursing into nested
+    /// views and walking every list and vector, and returns the
+    /// first error.
+    ///
+    /// `from_ssz_bytes` only checks the view's own layout, so this
+    /// rejects bytes that would otherwise fail on a later access.
+    pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+        self.g()?;
+        let value = self.h()?;
+        if let ssz_types::Optional::Some(inner) = value {
+            for item in inner.iter() {
+                item?;
+            }
+        }
+        self.i()?;
+        self.j()?;
+        Ok(())
+    }
+}
 impl<'a> tree_hash::TreeHash for EpsilonRef<'a> {
     fn tree_hash_type() -> tree_hash::TreeHashType {
         tree_hash::TreeHashType::StableContainer
@@ -2167,9 +2887,8 @@ impl<'a> ssz::view::DecodeView<'a> for EpsilonRef<'a> {
         use ssz::Decode;
         let bitvector_bytes = bytes
             .get(..6usize)
-            .ok_or(ssz::DecodeError::InvalidByteLength {
-                len: bytes.len(),
-                expected: 6usize,
+            .ok_or_else(|| ssz::DecodeError::Incomplete {
+                needed: 6usize - bytes.len(),
             })?;
         let bitvector = ssz_types::BitVector::<
             42usize,
@@ -2217,6 +2936,19 @@ impl<'a> ssz::view::DecodeView<'a> for EpsilonRef<'a> {
         Ok(Self { bytes })
     }
 }
+impl<'a> TryFrom<&'a [u8]> for EpsilonRef<'a> {
+    type Error = ssz::DecodeError;
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> EpsilonRef<'a> {
+    /// Returns the SSZ bytes this view was decoded from.
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
 impl<'a> ssz::view::SszTypeInfo for EpsilonRef<'a> {
     fn is_ssz_fixed_len() -> bool {
         false
@@ -2250,6 +2982,96 @@ impl<'a> EpsilonRef<'a> {
             j: self.j().expect("valid view"),
         }
     }
+    /// Converts this view into `out`, reusing the allocations of its
+    /// list and vector fields.
+    ///
+    /// Produces the same value as `to_owned`, but avoids reallocating
+    /// when converting many views in a loop. The view is validated
+    /// with `validate_deep` before anything is written, so on error
+    /// `out` is left unchanged.
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    pub fn to_owned_into(&self, out: &mut Epsilon) -> Result<(), ssz::DecodeError> {
+        self.validate_deep()?;
+        out.g = self.g()?;
+        out.h = match self.h()? {
+            ssz_types::Optional::Some(inner) => {
+                ssz_types::Optional::Some(ssz_types::view::ToOwnedSsz::to_owned(&inner))
+            }
+            ssz_types::Optional::None => ssz_types::Optional::None,
+        };
+        out.i = self.i()?;
+        out.j = self.j()?;
+        Ok(())
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> EpsilonRef<'a> {
+    fn eq_owned(&self, other: &Epsilon) -> Result<bool, ssz::DecodeError> {
+        use ssz::Decode;
+        let bitvector_bytes = self
+            .bytes
+            .get(..6usize)
+            .ok_or_else(|| ssz::DecodeError::Incomplete {
+                needed: 6usize - self.bytes.len(),
+            })?;
+        let bitvector = ssz_types::BitVector::<
+            42usize,
+        >::from_ssz_bytes(bitvector_bytes)?;
+        if bitvector.get(0usize).unwrap_or(false) != other.g.is_some() {
+            return Ok(false);
+        }
+        if bitvector.get(1usize).unwrap_or(false) != other.h.is_some() {
+            return Ok(false);
+        }
+        if bitvector.get(2usize).unwrap_or(false) != other.i.is_some() {
+            return Ok(false);
+        }
+        if bitvector.get(3usize).unwrap_or(false) != other.j.is_some() {
+            return Ok(false);
+        }
+        if !({
+            let value: Optional<u8> = self.g()?;
+            value == other.g
+        }) {
+            return Ok(false);
+        }
+        if !({
+            let value: Optional<VariableList<AliasUintAlias, 8usize>> = match self.h()? {
+                ssz_types::Optional::Some(inner) => {
+                    ssz_types::Optional::Some(
+                        ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                    )
+                }
+                ssz_types::Optional::None => ssz_types::Optional::None,
+            };
+            value == other.h
+        }) {
+            return Ok(false);
+        }
+        if !({
+            let value: Optional<u8> = self.i()?;
+            value == other.i
+        }) {
+            return Ok(false);
+        }
+        if !({
+            let value: Optional<AliasNested> = self.j()?;
+            value == other.j
+        }) {
+            return Ok(false);
+        }
+        Ok(true)
+    }
+}
+impl<'a> PartialEq<Epsilon> for EpsilonRef<'a> {
+    fn eq(&self, other: &Epsilon) -> bool {
+        self.eq_owned(other).unwrap_or(false)
+    }
+}
+impl<'a> PartialEq<EpsilonRef<'a>> for Epsilon {
+    fn eq(&self, other: &EpsilonRef<'a>) -> bool {
+        other == self
+    }
 }
 #[derive(
     std::clone::Clone,
@@ -2323,9 +3145,8 @@ impl<'a> ZetaRef<'a> {
         let bitvector_bytes = self
             .bytes
             .get(..16usize)
-            .ok_or(ssz::DecodeError::InvalidByteLength {
-                len: self.bytes.len(),
-                expected: 16usize,
+            .ok_or_else(|| ssz::DecodeError::Incomplete {
+                needed: 16usize - self.bytes.len(),
             })?;
         let bitvector = ssz_types::BitVector::<
             128usize,
@@ -2357,7 +3178,8 @@ impl<'a> ZetaRef<'a> {
         let inner = <FixedBytesRef<
             'a,
             16usize,
-        > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)?;
+        > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)
+            .map_err(|e| e.bounded(field_bytes.len()))?;
         Ok(ssz_types::Optional::Some(inner))
     }
     pub fn v(&self) -> Result<Optional<BytesRef<'a, 5usize>>, ssz::DecodeError> {
@@ -2365,9 +3187,8 @@ impl<'a> ZetaRef<'a> {
         let bitvector_bytes = self
             .bytes
             .get(..16usize)
-            .ok_or(ssz::DecodeError::InvalidByteLength {
-                len: self.bytes.len(),
-                expected: 16usize,
+            .ok_or_else(|| ssz::DecodeError::Incomplete {
+                needed: 16usize - self.bytes.len(),
             })?;
         let bitvector = ssz_types::BitVector::<
             128usize,
@@ -2399,10 +3220,25 @@ impl<'a> ZetaRef<'a> {
         let inner = <BytesRef<
             'a,
             5usize,
-        > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)?;
+        > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)
+            .map_err(|e| e.bounded(field_bytes.len()))?;
         Ok(ssz_types::Optional::Some(inner))
     }
 }
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> ZetaRef<'a> {
+    /// Validates every field of this view, recursing into nested
+    /// views and walking every list and vector, and returns the
+    /// first error.
+    ///
+    /// `from_ssz_bytes` only checks the view's own layout, so this
+    /// rejects bytes that would otherwise fail on a later access.
+    pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+        self.u()?;
+        self.v()?;
+        Ok(())
+    }
+}
 impl<'a> tree_hash::TreeHash for ZetaRef<'a> {
     fn tree_hash_type() -> tree_hash::TreeHashType {
         tree_hash::TreeHashType::StableContainer
@@ -2446,9 +3282,8 @@ impl<'a> ssz::view::DecodeView<'a> for ZetaRef<'a> {
         use ssz::Decode;
         let bitvector_bytes = bytes
             .get(..16usize)
-            .ok_or(ssz::DecodeError::InvalidByteLength {
-                len: bytes.len(),
-                expected: 16usize,
+            .ok_or_else(|| ssz::DecodeError::Incomplete {
+                needed: 16usize - bytes.len(),
             })?;
         let bitvector = ssz_types::BitVector::<
             128usize,
@@ -2482,6 +3317,19 @@ impl<'a> ssz::view::DecodeView<'a> for ZetaRef<'a> {
         Ok(Self { bytes })
     }
 }
+impl<'a> TryFrom<&'a [u8]> for ZetaRef<'a> {
+    type Error = ssz::DecodeError;
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> ZetaRef<'a> {
+    /// Returns the SSZ bytes this view was decoded from.
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
 impl<'a> ssz::view::SszTypeInfo for ZetaRef<'a> {
     fn is_ssz_fixed_len() -> bool {
         false
@@ -2520,6 +3368,88 @@ impl<'a> ZetaRef<'a> {
             },
         }
     }
+    /// Converts this view into `out`, reusing the allocations of its
+    /// list and vector fields.
+    ///
+    /// Produces the same value as `to_owned`, but avoids reallocating
+    /// when converting many views in a loop. The view is validated
+    /// with `validate_deep` before anything is written, so on error
+    /// `out` is left unchanged.
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    pub fn to_owned_into(&self, out: &mut Zeta) -> Result<(), ssz::DecodeError> {
+        self.validate_deep()?;
+        out.u = match self.u()? {
+            ssz_types::Optional::Some(inner) => {
+                ssz_types::Optional::Some(ssz_types::view::ToOwnedSsz::to_owned(&inner))
+            }
+            ssz_types::Optional::None => ssz_types::Optional::None,
+        };
+        out.v = match self.v()? {
+            ssz_types::Optional::Some(inner) => {
+                ssz_types::Optional::Some(ssz_types::view::ToOwnedSsz::to_owned(&inner))
+            }
+            ssz_types::Optional::None => ssz_types::Optional::None,
+        };
+        Ok(())
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> ZetaRef<'a> {
+    fn eq_owned(&self, other: &Zeta) -> Result<bool, ssz::DecodeError> {
+        use ssz::Decode;
+        let bitvector_bytes = self
+            .bytes
+            .get(..16usize)
+            .ok_or_else(|| ssz::DecodeError::Incomplete {
+                needed: 16usize - self.bytes.len(),
+            })?;
+        let bitvector = ssz_types::BitVector::<
+            128usize,
+        >::from_ssz_bytes(bitvector_bytes)?;
+        if bitvector.get(0usize).unwrap_or(false) != other.u.is_some() {
+            return Ok(false);
+        }
+        if bitvector.get(1usize).unwrap_or(false) != other.v.is_some() {
+            return Ok(false);
+        }
+        if !({
+            let value: Optional<FixedBytes<16usize>> = match self.u()? {
+                ssz_types::Optional::Some(inner) => {
+                    ssz_types::Optional::Some(
+                        ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                    )
+                }
+                ssz_types::Optional::None => ssz_types::Optional::None,
+            };
+            value == other.u
+        }) {
+            return Ok(false);
+        }
+        if !({
+            let value: Optional<AliasListAlias> = match self.v()? {
+                ssz_types::Optional::Some(inner) => {
+                    ssz_types::Optional::Some(
+                        ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                    )
+                }
+                ssz_types::Optional::None => ssz_types::Optional::None,
+            };
+            value == other.v
+        }) {
+            return Ok(false);
+        }
+        Ok(true)
+    }
+}
+impl<'a> PartialEq<Zeta> for ZetaRef<'a> {
+    fn eq(&self, other: &Zeta) -> bool {
+        self.eq_owned(other).unwrap_or(false)
+    }
+}
+impl<'a> PartialEq<ZetaRef<'a>> for Zeta {
+    fn eq(&self, other: &ZetaRef<'a>) -> bool {
+        other == self
+    }
 }
 #[derive(
     std::clone::Clone,
@@ -2574,6 +3504,28 @@ impl tree_hash::TreeHash for TestType {
         hasher.finish().expect("tree hash derive should not have a remaining buffer")
     }
 }
+impl tree_hash::TreeHashLeaves for TestType {
+    fn tree_hash_leaf_count() -> usize {
+        5usize
+    }
+    fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+        &self,
+        index: usize,
+    ) -> H::Output {
+        match index {
+            0usize => <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.ccc),
+            1usize => <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.ddd),
+            2usize => <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.eee),
+            3usize => {
+                <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.large_int_128)
+            }
+            4usize => {
+                <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.large_int_256)
+            }
+            _ => panic!("leaf index {index} out of range for {} leaves", 5usize),
+        }
+    }
+}
 /// Zero-copy view over [`TestType`].
 ///
 /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
@@ -2619,7 +3571,7 @@ impl<'a> TestTypeRef<'a> {
             ],
             0usize,
         )?;
-        ssz::view::DecodeView::from_ssz_bytes(bytes)
+        ssz::view::DecodeView::from_ssz_bytes(bytes).map_err(|e| e.bounded(bytes.len()))
     }
     pub fn ddd(&self) -> Result<u8, ssz::DecodeError> {
         let bytes = ssz::layout::read_field_bytes(
@@ -2648,7 +3600,7 @@ impl<'a> TestTypeRef<'a> {
             ],
             1usize,
         )?;
-        ssz::view::DecodeView::from_ssz_bytes(bytes)
+        ssz::view::DecodeView::from_ssz_bytes(bytes).map_err(|e| e.bounded(bytes.len()))
     }
     pub fn eee(&self) -> Result<ListRef<'a, u16, 3usize>, ssz::DecodeError> {
         let bytes = ssz::layout::read_field_bytes(
@@ -2677,7 +3629,7 @@ impl<'a> TestTypeRef<'a> {
             ],
             2usize,
         )?;
-        ssz::view::DecodeView::from_ssz_bytes(bytes)
+        ssz::view::DecodeView::from_ssz_bytes(bytes).map_err(|e| e.bounded(bytes.len()))
     }
     pub fn large_int_128(&self) -> Result<U128, ssz::DecodeError> {
         let bytes = ssz::layout::read_field_bytes(
@@ -2706,7 +3658,7 @@ impl<'a> TestTypeRef<'a> {
             ],
             3usize,
         )?;
-        ssz::view::DecodeView::from_ssz_bytes(bytes)
+        ssz::view::DecodeView::from_ssz_bytes(bytes).map_err(|e| e.bounded(bytes.len()))
     }
     pub fn large_int_256(&self) -> Result<U256, ssz::DecodeError> {
         let bytes = ssz::layout::read_field_bytes(
@@ -2735,7 +3687,27 @@ impl<'a> TestTypeRef<'a> {
             ],
             4usize,
         )?;
-        ssz::view::DecodeView::from_ssz_bytes(bytes)
+        ssz::view::DecodeView::from_ssz_bytes(bytes).map_err(|e| e.bounded(bytes.len()))
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> TestTypeRef<'a> {
+    /// Validates every field of this view, recursing into nested
+    /// views and walking every list and vector, and returns the
+    /// first error.
+    ///
+    /// `from_ssz_bytes` only checks the view's own layout, so this
+    /// rejects bytes that would otherwise fail on a later access.
+    pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+        self.ccc()?;
+        self.ddd()?;
+        let value = self.eee()?;
+        for item in value.iter() {
+            item?;
+        }
+        self.large_int_128()?;
+        self.large_int_256()?;
+        Ok(())
     }
 }
 impl<'a> tree_hash::TreeHash for TestTypeRef<'a> {
@@ -2819,6 +3791,19 @@ impl<'a> ssz::view::DecodeView<'a> for TestTypeRef<'a> {
         Ok(Self { bytes })
     }
 }
+impl<'a> TryFrom<&'a [u8]> for TestTypeRef<'a> {
+    type Error = ssz::DecodeError;
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> TestTypeRef<'a> {
+    /// Returns the SSZ bytes this view was decoded from.
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
 impl<'a> ssz::view::SszTypeInfo for TestTypeRef<'a> {
     fn is_ssz_fixed_len() -> bool {
         usize::from(!<u8 as ssz::Encode>::is_ssz_fixed_len())
@@ -2864,10 +3849,90 @@ impl<'a> TestTypeRef<'a> {
                     .collect();
                 let items = items.expect("valid view");
                 ssz_types::VariableList::new(items).expect("valid view")
-            },
-            large_int_128: self.large_int_128().expect("valid view"),
-            large_int_256: self.large_int_256().expect("valid view"),
+            },
+            large_int_128: self.large_int_128().expect("valid view"),
+            large_int_256: self.large_int_256().expect("valid view"),
+        }
+    }
+    /// Converts this view into `out`, reusing the allocations of its
+    /// list and vector fields.
+    ///
+    /// Produces the same value as `to_owned`, but avoids reallocating
+    /// when converting many views in a loop. The view is validated
+    /// with `validate_deep` before anything is written, so on error
+    /// `out` is left unchanged.
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    pub fn to_owned_into(&self, out: &mut TestType) -> Result<(), ssz::DecodeError> {
+        self.validate_deep()?;
+        out.ccc = self.ccc()?;
+        out.ddd = self.ddd()?;
+        let view = self.eee()?;
+        out.eee.clear();
+        for item in view.iter() {
+            out.eee
+                .push(ssz_types::view::ToOwnedSsz::to_owned(&item?))
+                .expect("valid view");
+        }
+        out.large_int_128 = self.large_int_128()?;
+        out.large_int_256 = self.large_int_256()?;
+        Ok(())
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> TestTypeRef<'a> {
+    fn eq_owned(&self, other: &TestType) -> Result<bool, ssz::DecodeError> {
+        if !({
+            let value: u8 = self.ccc()?;
+            value == other.ccc
+        }) {
+            return Ok(false);
+        }
+        if !({
+            let value: u8 = self.ddd()?;
+            value == other.ddd
+        }) {
+            return Ok(false);
+        }
+        if !({
+            let value: VariableList<u16, 3usize> = {
+                let view = self.eee()?;
+                let items: Result<Vec<_>, _> = view
+                    .iter()
+                    .map(|item_result| {
+                        item_result
+                            .map(|item| ssz_types::view::ToOwnedSsz::to_owned(&item))
+                    })
+                    .collect();
+                let items = items.expect("valid view");
+                ssz_types::VariableList::new(items).expect("valid view")
+            };
+            value == other.eee
+        }) {
+            return Ok(false);
+        }
+        if !({
+            let value: U128 = self.large_int_128()?;
+            value == other.large_int_128
+        }) {
+            return Ok(false);
+        }
+        if !({
+            let value: U256 = self.large_int_256()?;
+            value == other.large_int_256
+        }) {
+            return Ok(false);
         }
+        Ok(true)
+    }
+}
+impl<'a> PartialEq<TestType> for TestTypeRef<'a> {
+    fn eq(&self, other: &TestType) -> bool {
+        self.eq_owned(other).unwrap_or(false)
+    }
+}
+impl<'a> PartialEq<TestTypeRef<'a>> for TestType {
+    fn eq(&self, other: &TestTypeRef<'a>) -> bool {
+        other == self
     }
 }
 #[derive(
@@ -2909,6 +3974,22 @@ impl tree_hash::TreeHash for Eta {
         hasher.finish().expect("tree hash derive should not have a remaining buffer")
     }
 }
+impl tree_hash::TreeHashLeaves for Eta {
+    fn tree_hash_leaf_count() -> usize {
+        3usize
+    }
+    fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+        &self,
+        index: usize,
+    ) -> H::Output {
+        match index {
+            0usize => <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.l),
+            1usize => <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.m),
+            2usize => <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.n),
+            _ => panic!("leaf index {index} out of range for {} leaves", 3usize),
+        }
+    }
+}
 /// Zero-copy view over [`Eta`].
 ///
 /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
@@ -2946,7 +4027,7 @@ impl<'a> EtaRef<'a> {
             ],
             0usize,
         )?;
-        ssz::view::DecodeView::from_ssz_bytes(bytes)
+        ssz::view::DecodeView::from_ssz_bytes(bytes).map_err(|e| e.bounded(bytes.len()))
     }
     pub fn m(&self) -> Result<TestTypeRef<'a>, ssz::DecodeError> {
         let bytes = ssz::layout::read_field_bytes(
@@ -2967,7 +4048,7 @@ impl<'a> EtaRef<'a> {
             ],
             1usize,
         )?;
-        ssz::view::DecodeView::from_ssz_bytes(bytes)
+        ssz::view::DecodeView::from_ssz_bytes(bytes).map_err(|e| e.bounded(bytes.len()))
     }
     pub fn n(&self) -> Result<FirstUnionRef<'a>, ssz::DecodeError> {
         let bytes = ssz::layout::read_field_bytes(
@@ -2988,7 +4069,25 @@ impl<'a> EtaRef<'a> {
             ],
             2usize,
         )?;
-        ssz::view::DecodeView::from_ssz_bytes(bytes)
+        ssz::view::DecodeView::from_ssz_bytes(bytes).map_err(|e| e.bounded(bytes.len()))
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> EtaRef<'a> {
+    /// Validates every field of this view, recursing into nested
+    /// views and walking every list and vector, and returns the
+    /// first error.
+    ///
+    /// `from_ssz_bytes` only checks the view's own layout, so this
+    /// rejects bytes that would otherwise fail on a later access.
+    pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+        let value = self.l()?;
+        value.validate_deep()?;
+        let value = self.m()?;
+        value.validate_deep()?;
+        let value = self.n()?;
+        value.validate_deep()?;
+        Ok(())
     }
 }
 impl<'a> tree_hash::TreeHash for EtaRef<'a> {
@@ -3050,6 +4149,19 @@ impl<'a> ssz::view::DecodeView<'a> for EtaRef<'a> {
         Ok(Self { bytes })
     }
 }
+impl<'a> TryFrom<&'a [u8]> for EtaRef<'a> {
+    type Error = ssz::DecodeError;
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> EtaRef<'a> {
+    /// Returns the SSZ bytes this view was decoded from.
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
 impl<'a> ssz::view::SszTypeInfo for EtaRef<'a> {
     fn is_ssz_fixed_len() -> bool {
         usize::from(!<Zeta as ssz::Encode>::is_ssz_fixed_len())
@@ -3092,6 +4204,61 @@ impl<'a> EtaRef<'a> {
             },
         }
     }
+    /// Converts this view into `out`, reusing the allocations of its
+    /// list and vector fields.
+    ///
+    /// Produces the same value as `to_owned`, but avoids reallocating
+    /// when converting many views in a loop. The view is validated
+    /// with `validate_deep` before anything is written, so on error
+    /// `out` is left unchanged.
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    pub fn to_owned_into(&self, out: &mut Eta) -> Result<(), ssz::DecodeError> {
+        self.validate_deep()?;
+        out.l = {
+            let view = self.l()?;
+            ssz_types::view::ToOwnedSsz::to_owned(&view)
+        };
+        out.m = {
+            let view = self.m()?;
+            ssz_types::view::ToOwnedSsz::to_owned(&view)
+        };
+        out.n = {
+            let view = self.n()?;
+            ssz_types::view::ToOwnedSsz::to_owned(&view)
+        };
+        Ok(())
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> EtaRef<'a> {
+    fn eq_owned(&self, other: &Eta) -> Result<bool, ssz::DecodeError> {
+        if !(self.l()? == other.l) {
+            return Ok(false);
+        }
+        if !(self.m()? == other.m) {
+            return Ok(false);
+        }
+        if !({
+            let value: FirstUnion = {
+                let view = self.n()?;
+                ssz_types::view::ToOwnedSsz::to_owned(&view)
+            };
+            value == other.n
+        }) {
+            return Ok(false);
+        }
+        Ok(true)
+    }
+}
+impl<'a> PartialEq<Eta> for EtaRef<'a> {
+    fn eq(&self, other: &Eta) -> bool {
+        self.eq_owned(other).unwrap_or(false)
+    }
+}
+impl<'a> PartialEq<EtaRef<'a>> for Eta {
+    fn eq(&self, other: &EtaRef<'a>) -> bool {
+        other == self
+    }
 }
 #[derive(
     std::clone::Clone,
@@ -3132,6 +4299,22 @@ impl tree_hash::TreeHash for Theta {
         hasher.finish().expect("tree hash derive should not have a remaining buffer")
     }
 }
+impl tree_hash::TreeHashLeaves for Theta {
+    fn tree_hash_leaf_count() -> usize {
+        3usize
+    }
+    fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+        &self,
+        index: usize,
+    ) -> H::Output {
+        match index {
+            0usize => <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.o),
+            1usize => <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.p),
+            2usize => <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.q),
+            _ => panic!("leaf index {index} out of range for {} leaves", 3usize),
+        }
+    }
+}
 /// Zero-copy view over [`Theta`].
 ///
 /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
@@ -3169,7 +4352,7 @@ impl<'a> ThetaRef<'a> {
             ],
             0usize,
         )?;
-        ssz::view::DecodeView::from_ssz_bytes(bytes)
+        ssz::view::DecodeView::from_ssz_bytes(bytes).map_err(|e| e.bounded(bytes.len()))
     }
     pub fn p(&self) -> Result<UnionCRef<'a>, ssz::DecodeError> {
         let bytes = ssz::layout::read_field_bytes(
@@ -3190,7 +4373,7 @@ impl<'a> ThetaRef<'a> {
             ],
             1usize,
         )?;
-        ssz::view::DecodeView::from_ssz_bytes(bytes)
+        ssz::view::DecodeView::from_ssz_bytes(bytes).map_err(|e| e.bounded(bytes.len()))
     }
     pub fn q(&self) -> Result<FixedBytesRef<'a, 10usize>, ssz::DecodeError> {
         let bytes = ssz::layout::read_field_bytes(
@@ -3211,7 +4394,24 @@ impl<'a> ThetaRef<'a> {
             ],
             2usize,
         )?;
-        ssz::view::DecodeView::from_ssz_bytes(bytes)
+        ssz::view::DecodeView::from_ssz_bytes(bytes).map_err(|e| e.bounded(bytes.len()))
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> ThetaRef<'a> {
+    /// Validates every field of this view, recursing into nested
+    /// views and walking every list and vector, and returns the
+    /// first error.
+    ///
+    /// `from_ssz_bytes` only checks the view's own layout, so this
+    /// rejects bytes that would otherwise fail on a later access.
+    pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+        let value = self.o()?;
+        value.validate_deep()?;
+        let value = self.p()?;
+        value.validate_deep()?;
+        self.q()?;
+        Ok(())
     }
 }
 impl<'a> tree_hash::TreeHash for ThetaRef<'a> {
@@ -3273,6 +4473,19 @@ impl<'a> ssz::view::DecodeView<'a> for ThetaRef<'a> {
         Ok(Self { bytes })
     }
 }
+impl<'a> TryFrom<&'a [u8]> for ThetaRef<'a> {
+    type Error = ssz::DecodeError;
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> ThetaRef<'a> {
+    /// Returns the SSZ bytes this view was decoded from.
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
 impl<'a> ssz::view::SszTypeInfo for ThetaRef<'a> {
     fn is_ssz_fixed_len() -> bool {
         usize::from(!<UnionB as ssz::Encode>::is_ssz_fixed_len())
@@ -3312,6 +4525,67 @@ impl<'a> ThetaRef<'a> {
             q: ssz_types::FixedBytes(self.q().expect("valid view").to_owned()),
         }
     }
+    /// Converts this view into `out`, reusing the allocations of its
+    /// list and vector fields.
+    ///
+    /// Produces the same value as `to_owned`, but avoids reallocating
+    /// when converting many views in a loop. The view is validated
+    /// with `validate_deep` before anything is written, so on error
+    /// `out` is left unchanged.
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    pub fn to_owned_into(&self, out: &mut Theta) -> Result<(), ssz::DecodeError> {
+        self.validate_deep()?;
+        out.o = {
+            let view = self.o()?;
+            ssz_types::view::ToOwnedSsz::to_owned(&view)
+        };
+        out.p = {
+            let view = self.p()?;
+            ssz_types::view::ToOwnedSsz::to_owned(&view)
+        };
+        out.q = ssz_types::FixedBytes(self.q()?.to_owned());
+        Ok(())
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> ThetaRef<'a> {
+    fn eq_owned(&self, other: &Theta) -> Result<bool, ssz::DecodeError> {
+        if !({
+            let value: UnionB = {
+                let view = self.o()?;
+                ssz_types::view::ToOwnedSsz::to_owned(&view)
+            };
+            value == other.o
+        }) {
+            return Ok(false);
+        }
+        if !({
+            let value: UnionC = {
+                let view = self.p()?;
+                ssz_types::view::ToOwnedSsz::to_owned(&view)
+            };
+            value == other.p
+        }) {
+            return Ok(false);
+        }
+        if !({
+            let value: AliasVecA = ssz_types::FixedBytes(self.q()?.to_owned());
+            value == other.q
+        }) {
+            return Ok(false);
+        }
+        Ok(true)
+    }
+}
+impl<'a> PartialEq<Theta> for ThetaRef<'a> {
+    fn eq(&self, other: &Theta) -> bool {
+        self.eq_owned(other).unwrap_or(false)
+    }
+}
+impl<'a> PartialEq<ThetaRef<'a>> for Theta {
+    fn eq(&self, other: &ThetaRef<'a>) -> bool {
+        other == self
+    }
 }
 #[derive(
     std::clone::Clone,
@@ -3413,9 +4687,8 @@ impl<'a> IotaRef<'a> {
         let bitvector_bytes = self
             .bytes
             .get(..6usize)
-            .ok_or(ssz::DecodeError::InvalidByteLength {
-                len: self.bytes.len(),
-                expected: 6usize,
+            .ok_or_else(|| ssz::DecodeError::Incomplete {
+                needed: 6usize - self.bytes.len(),
             })?;
         let bitvector = ssz_types::BitVector::<
             42usize,
@@ -3472,7 +4745,8 @@ impl<'a> IotaRef<'a> {
             Some(bytes) => bytes,
             None => return Ok(ssz_types::Optional::None),
         };
-        let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)?;
+        let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)
+            .map_err(|e| e.bounded(field_bytes.len()))?;
         Ok(ssz_types::Optional::Some(inner))
     }
     pub fn h(&self) -> Result<Optional<ListRef<'a, u16, 8usize>>, ssz::DecodeError> {
@@ -3480,9 +4754,8 @@ impl<'a> IotaRef<'a> {
         let bitvector_bytes = self
             .bytes
             .get(..6usize)
-            .ok_or(ssz::DecodeError::InvalidByteLength {
-                len: self.bytes.len(),
-                expected: 6usize,
+            .ok_or_else(|| ssz::DecodeError::Incomplete {
+                needed: 6usize - self.bytes.len(),
             })?;
         let bitvector = ssz_types::BitVector::<
             42usize,
@@ -3543,7 +4816,8 @@ impl<'a> IotaRef<'a> {
             'a,
             u16,
             8usize,
-        > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)?;
+        > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)
+            .map_err(|e| e.bounded(field_bytes.len()))?;
         Ok(ssz_types::Optional::Some(inner))
     }
     pub fn i(&self) -> Result<Optional<u8>, ssz::DecodeError> {
@@ -3551,9 +4825,8 @@ impl<'a> IotaRef<'a> {
         let bitvector_bytes = self
             .bytes
             .get(..6usize)
-            .ok_or(ssz::DecodeError::InvalidByteLength {
-                len: self.bytes.len(),
-                expected: 6usize,
+            .ok_or_else(|| ssz::DecodeError::Incomplete {
+                needed: 6usize - self.bytes.len(),
             })?;
         let bitvector = ssz_types::BitVector::<
             42usize,
@@ -3610,7 +4883,8 @@ impl<'a> IotaRef<'a> {
             Some(bytes) => bytes,
             None => return Ok(ssz_types::Optional::None),
         };
-        let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)?;
+        let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)
+            .map_err(|e| e.bounded(field_bytes.len()))?;
         Ok(ssz_types::Optional::Some(inner))
     }
     pub fn j(&self) -> Result<Optional<u16>, ssz::DecodeError> {
@@ -3618,9 +4892,8 @@ impl<'a> IotaRef<'a> {
         let bitvector_bytes = self
             .bytes
             .get(..6usize)
-            .ok_or(ssz::DecodeError::InvalidByteLength {
-                len: self.bytes.len(),
-                expected: 6usize,
+            .ok_or_else(|| ssz::DecodeError::Incomplete {
+                needed: 6usize - self.bytes.len(),
             })?;
         let bitvector = ssz_types::BitVector::<
             42usize,
@@ -3677,7 +4950,8 @@ impl<'a> IotaRef<'a> {
             Some(bytes) => bytes,
             None => return Ok(ssz_types::Optional::None),
         };
-        let inner = <u16 as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)?;
+        let inner = <u16 as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)
+            .map_err(|e| e.bounded(field_bytes.len()))?;
         Ok(ssz_types::Optional::Some(inner))
     }
     pub fn r(&self) -> Result<Optional<ListRef<'a, u16, 2usize>>, ssz::DecodeError> {
@@ -3685,9 +4959,8 @@ impl<'a> IotaRef<'a> {
         let bitvector_bytes = self
             .bytes
             .get(..6usize)
-            .ok_or(ssz::DecodeError::InvalidByteLength {
-                len: self.bytes.len(),
-                expected: 6usize,
+            .ok_or_else(|| ssz::DecodeError::Incomplete {
+                needed: 6usize - self.bytes.len(),
             })?;
         let bitvector = ssz_types::BitVector::<
             42usize,
@@ -3748,7 +5021,8 @@ impl<'a> IotaRef<'a> {
             'a,
             u16,
             2usize,
-        > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)?;
+        > as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)
+            .map_err(|e| e.bounded(field_bytes.len()))?;
         Ok(ssz_types::Optional::Some(inner))
     }
     pub fn s(&self) -> Result<Optional<u8>, ssz::DecodeError> {
@@ -3756,9 +5030,8 @@ impl<'a> IotaRef<'a> {
         let bitvector_bytes = self
             .bytes
             .get(..6usize)
-            .ok_or(ssz::DecodeError::InvalidByteLength {
-                len: self.bytes.len(),
-                expected: 6usize,
+            .ok_or_else(|| ssz::DecodeError::Incomplete {
+                needed: 6usize - self.bytes.len(),
             })?;
         let bitvector = ssz_types::BitVector::<
             42usize,
@@ -3815,10 +5088,39 @@ impl<'a> IotaRef<'a> {
             Some(bytes) => bytes,
             None => return Ok(ssz_types::Optional::None),
         };
-        let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)?;
+        let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)
+            .map_err(|e| e.bounded(field_bytes.len()))?;
         Ok(ssz_types::Optional::Some(inner))
     }
 }
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> IotaRef<'a> {
+    /// Validates every field of this view, recursing into nested
+    /// views and walking every list and vector, and returns the
+    /// first error.
+    ///
+    /// `from_ssz_bytes` only checks the view's own layout, so this
+    /// rejects bytes that would otherwise fail on a later access.
+    pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+        self.g()?;
+        let value = self.h()?;
+        if let ssz_types::Optional::Some(inner) = value {
+            for item in inner.iter() {
+                item?;
+            }
+        }
+        self.i()?;
+        self.j()?;
+        let value = self.r()?;
+        if let ssz_types::Optional::Some(inner) = value {
+            for item in inner.iter() {
+                item?;
+            }
+        }
+        self.s()?;
+        Ok(())
+    }
+}
 impl<'a> tree_hash::TreeHash for IotaRef<'a> {
     fn tree_hash_type() -> tree_hash::TreeHashType {
         tree_hash::TreeHashType::StableContainer
@@ -3890,9 +5192,8 @@ impl<'a> ssz::view::DecodeView<'a> for IotaRef<'a> {
         use ssz::Decode;
         let bitvector_bytes = bytes
             .get(..6usize)
-            .ok_or(ssz::DecodeError::InvalidByteLength {
-                len: bytes.len(),
-                expected: 6usize,
+            .ok_or_else(|| ssz::DecodeError::Incomplete {
+                needed: 6usize - bytes.len(),
             })?;
         let bitvector = ssz_types::BitVector::<
             42usize,
@@ -3954,6 +5255,19 @@ impl<'a> ssz::view::DecodeView<'a> for IotaRef<'a> {
         Ok(Self { bytes })
     }
 }
+impl<'a> TryFrom<&'a [u8]> for IotaRef<'a> {
+    type Error = ssz::DecodeError;
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> IotaRef<'a> {
+    /// Returns the SSZ bytes this view was decoded from.
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
 impl<'a> ssz::view::SszTypeInfo for IotaRef<'a> {
     fn is_ssz_fixed_len() -> bool {
         false
@@ -3996,6 +5310,128 @@ impl<'a> IotaRef<'a> {
             s: self.s().expect("valid view"),
         }
     }
+    /// Converts this view into `out`, reusing the allocations of its
+    /// list and vector fields.
+    ///
+    /// Produces the same value as `to_owned`, but avoids reallocating
+    /// when converting many views in a loop. The view is validated
+    /// with `validate_deep` before anything is written, so on error
+    /// `out` is left unchanged.
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    pub fn to_owned_into(&self, out: &mut Iota) -> Result<(), ssz::DecodeError> {
+        self.validate_deep()?;
+        out.g = self.g()?;
+        out.h = match self.h()? {
+            ssz_types::Optional::Some(inner) => {
+                ssz_types::Optional::Some(ssz_types::view::ToOwnedSsz::to_owned(&inner))
+            }
+            ssz_types::Optional::None => ssz_types::Optional::None,
+        };
+        out.i = self.i()?;
+        out.j = self.j()?;
+        out.r = match self.r()? {
+            ssz_types::Optional::Some(inner) => {
+                ssz_types::Optional::Some(ssz_types::view::ToOwnedSsz::to_owned(&inner))
+            }
+            ssz_types::Optional::None => ssz_types::Optional::None,
+        };
+        out.s = self.s()?;
+        Ok(())
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> IotaRef<'a> {
+    fn eq_owned(&self, other: &Iota) -> Result<bool, ssz::DecodeError> {
+        use ssz::Decode;
+        let bitvector_bytes = self
+            .bytes
+            .get(..6usize)
+            .ok_or_else(|| ssz::DecodeError::Incomplete {
+                needed: 6usize - self.bytes.len(),
+            })?;
+        let bitvector = ssz_types::BitVector::<
+            42usize,
+        >::from_ssz_bytes(bitvector_bytes)?;
+        if bitvector.get(0usize).unwrap_or(false) != other.g.is_some() {
+            return Ok(false);
+        }
+        if bitvector.get(1usize).unwrap_or(false) != other.h.is_some() {
+            return Ok(false);
+        }
+        if bitvector.get(2usize).unwrap_or(false) != other.i.is_some() {
+            return Ok(false);
+        }
+        if bitvector.get(3usize).unwrap_or(false) != other.j.is_some() {
+            return Ok(false);
+        }
+        if bitvector.get(4usize).unwrap_or(false) != other.r.is_some() {
+            return Ok(false);
+        }
+        if bitvector.get(5usize).unwrap_or(false) != other.s.is_some() {
+            return Ok(false);
+        }
+        if !({
+            let value: Optional<u8> = self.g()?;
+            value == other.g
+        }) {
+            return Ok(false);
+        }
+        if !({
+            let value: Optional<VariableList<AliasUintAlias, 8usize>> = match self.h()? {
+                ssz_types::Optional::Some(inner) => {
+                    ssz_types::Optional::Some(
+                        ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                    )
+                }
+                ssz_types::Optional::None => ssz_types::Optional::None,
+            };
+            value == other.h
+        }) {
+            return Ok(false);
+        }
+        if !({
+            let value: Optional<u8> = self.i()?;
+            value == other.i
+        }) {
+            return Ok(false);
+        }
+        if !({
+            let value: Optional<AliasNested> = self.j()?;
+            value == other.j
+        }) {
+            return Ok(false);
+        }
+        if !({
+            let value: Optional<VariableList<AliasNested, 2usize>> = match self.r()? {
+                ssz_types::Optional::Some(inner) => {
+                    ssz_types::Optional::Some(
+                        ssz_types::view::ToOwnedSsz::to_owned(&inner),
+                    )
+                }
+                ssz_types::Optional::None => ssz_types::Optional::None,
+            };
+            value == other.r
+        }) {
+            return Ok(false);
+        }
+        if !({
+            let value: Optional<u8> = self.s()?;
+            value == other.s
+        }) {
+            return Ok(false);
+        }
+        Ok(true)
+    }
+}
+impl<'a> PartialEq<Iota> for IotaRef<'a> {
+    fn eq(&self, other: &Iota) -> bool {
+        self.eq_owned(other).unwrap_or(false)
+    }
+}
+impl<'a> PartialEq<IotaRef<'a>> for Iota {
+    fn eq(&self, other: &IotaRef<'a>) -> bool {
+        other == self
+    }
 }
 #[derive(
     std::clone::Clone,
@@ -4036,6 +5472,22 @@ impl tree_hash::TreeHash for Kappa {
         hasher.finish().expect("tree hash derive should not have a remaining buffer")
     }
 }
+impl tree_hash::TreeHashLeaves for Kappa {
+    fn tree_hash_leaf_count() -> usize {
+        3usize
+    }
+    fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+        &self,
+        index: usize,
+    ) -> H::Output {
+        match index {
+            0usize => <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.t),
+            1usize => <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.u),
+            2usize => <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.v),
+            _ => panic!("leaf index {index} out of range for {} leaves", 3usize),
+        }
+    }
+}
 /// Zero-copy view over [`Kappa`].
 ///
 /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
@@ -4073,7 +5525,7 @@ impl<'a> KappaRef<'a> {
             ],
             0usize,
         )?;
-        ssz::view::DecodeView::from_ssz_bytes(bytes)
+        ssz::view::DecodeView::from_ssz_bytes(bytes).map_err(|e| e.bounded(bytes.len()))
     }
     pub fn u(&self) -> Result<BetaRef<'a>, ssz::DecodeError> {
         let bytes = ssz::layout::read_field_bytes(
@@ -4094,7 +5546,7 @@ impl<'a> KappaRef<'a> {
             ],
             1usize,
         )?;
-        ssz::view::DecodeView::from_ssz_bytes(bytes)
+        ssz::view::DecodeView::from_ssz_bytes(bytes).map_err(|e| e.bounded(bytes.len()))
     }
     pub fn v(&self) -> Result<BitVectorRef<'a, 64usize>, ssz::DecodeError> {
         let bytes = ssz::layout::read_field_bytes(
@@ -4115,7 +5567,24 @@ impl<'a> KappaRef<'a> {
             ],
             2usize,
         )?;
-        ssz::view::DecodeView::from_ssz_bytes(bytes)
+        ssz::view::DecodeView::from_ssz_bytes(bytes).map_err(|e| e.bounded(bytes.len()))
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> KappaRef<'a> {
+    /// Validates every field of this view, recursing into nested
+    /// views and walking every list and vector, and returns the
+    /// first error.
+    ///
+    /// `from_ssz_bytes` only checks the view's own layout, so this
+    /// rejects bytes that would otherwise fail on a later access.
+    pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+        let value = self.t()?;
+        value.validate_deep()?;
+        let value = self.u()?;
+        value.validate_deep()?;
+        self.v()?;
+        Ok(())
     }
 }
 impl<'a> tree_hash::TreeHash for KappaRef<'a> {
@@ -4177,6 +5646,19 @@ impl<'a> ssz::view::DecodeView<'a> for KappaRef<'a> {
         Ok(Self { bytes })
     }
 }
+impl<'a> TryFrom<&'a [u8]> for KappaRef<'a> {
+    type Error = ssz::DecodeError;
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> KappaRef<'a> {
+    /// Returns the SSZ bytes this view was decoded from.
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
 impl<'a> ssz::view::SszTypeInfo for KappaRef<'a> {
     fn is_ssz_fixed_len() -> bool {
         usize::from(!<Alpha as ssz::Encode>::is_ssz_fixed_len())
@@ -4216,6 +5698,55 @@ impl<'a> KappaRef<'a> {
             v: self.v().expect("valid view").to_owned(),
         }
     }
+    /// Converts this view into `out`, reusing the allocations of its
+    /// list and vector fields.
+    ///
+    /// Produces the same value as `to_owned`, but avoids reallocating
+    /// when converting many views in a loop. The view is validated
+    /// with `validate_deep` before anything is written, so on error
+    /// `out` is left unchanged.
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    pub fn to_owned_into(&self, out: &mut Kappa) -> Result<(), ssz::DecodeError> {
+        self.validate_deep()?;
+        out.t = {
+            let view = self.t()?;
+            ssz_types::view::ToOwnedSsz::to_owned(&view)
+        };
+        out.u = {
+            let view = self.u()?;
+            ssz_types::view::ToOwnedSsz::to_owned(&view)
+        };
+        out.v = self.v()?.to_owned();
+        Ok(())
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> KappaRef<'a> {
+    fn eq_owned(&self, other: &Kappa) -> Result<bool, ssz::DecodeError> {
+        if !(self.t()? == other.t) {
+            return Ok(false);
+        }
+        if !(self.u()? == other.u) {
+            return Ok(false);
+        }
+        if !({
+            let value: BitVector<64usize> = self.v()?.to_owned();
+            value == other.v
+        }) {
+            return Ok(false);
+        }
+        Ok(true)
+    }
+}
+impl<'a> PartialEq<Kappa> for KappaRef<'a> {
+    fn eq(&self, other: &Kappa) -> bool {
+        self.eq_owned(other).unwrap_or(false)
+    }
+}
+impl<'a> PartialEq<KappaRef<'a>> for Kappa {
+    fn eq(&self, other: &KappaRef<'a>) -> bool {
+        other == self
+    }
 }
 #[derive(
     std::clone::Clone,
@@ -4289,9 +5820,8 @@ impl<'a> LambdaRef<'a> {
         let bitvector_bytes = self
             .bytes
             .get(..1usize)
-            .ok_or(ssz::DecodeError::InvalidByteLength {
-                len: self.bytes.len(),
-                expected: 1usize,
+            .ok_or_else(|| ssz::DecodeError::Incomplete {
+                needed: 1usize - self.bytes.len(),
             })?;
         let bitvector = ssz_types::BitVector::<4usize>::from_ssz_bytes(bitvector_bytes)?;
         let body = &self.bytes[1usize..];
@@ -4318,7 +5848,8 @@ impl<'a> LambdaRef<'a> {
             Some(bytes) => bytes,
             None => return Ok(ssz_types::Optional::None),
         };
-        let inner = <u16 as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)?;
+        let inner = <u16 as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)
+            .map_err(|e| e.bounded(field_bytes.len()))?;
         Ok(ssz_types::Optional::Some(inner))
     }
     pub fn x(&self) -> Result<Optional<u8>, ssz::DecodeError> {
@@ -4326,9 +5857,8 @@ impl<'a> LambdaRef<'a> {
         let bitvector_bytes = self
             .bytes
             .get(..1usize)
-            .ok_or(ssz::DecodeError::InvalidByteLength {
-                len: self.bytes.len(),
-                expected: 1usize,
+            .ok_or_else(|| ssz::DecodeError::Incomplete {
+                needed: 1usize - self.bytes.len(),
             })?;
         let bitvector = ssz_types::BitVector::<4usize>::from_ssz_bytes(bitvector_bytes)?;
         let body = &self.bytes[1usize..];
@@ -4355,10 +5885,25 @@ impl<'a> LambdaRef<'a> {
             Some(bytes) => bytes,
             None => return Ok(ssz_types::Optional::None),
         };
-        let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)?;
+        let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(field_bytes)
+            .map_err(|e| e.bounded(field_bytes.len()))?;
         Ok(ssz_types::Optional::Some(inner))
     }
 }
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> LambdaRef<'a> {
+    /// Validates every field of this view, recursing into nested
+    /// views and walking every list and vector, and returns the
+    /// first error.
+    ///
+    /// `from_ssz_bytes` only checks the view's own layout, so this
+    /// rejects bytes that would otherwise fail on a later access.
+    pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+        self.w()?;
+        self.x()?;
+        Ok(())
+    }
+}
 impl<'a> tree_hash::TreeHash for LambdaRef<'a> {
     fn tree_hash_type() -> tree_hash::TreeHashType {
         tree_hash::TreeHashType::StableContainer
@@ -4402,9 +5947,8 @@ impl<'a> ssz::view::DecodeView<'a> for LambdaRef<'a> {
         use ssz::Decode;
         let bitvector_bytes = bytes
             .get(..1usize)
-            .ok_or(ssz::DecodeError::InvalidByteLength {
-                len: bytes.len(),
-                expected: 1usize,
+            .ok_or_else(|| ssz::DecodeError::Incomplete {
+                needed: 1usize - bytes.len(),
             })?;
         let bitvector = ssz_types::BitVector::<4usize>::from_ssz_bytes(bitvector_bytes)?;
         let body = &bytes[1usize..];
@@ -4436,6 +5980,19 @@ impl<'a> ssz::view::DecodeView<'a> for LambdaRef<'a> {
         Ok(Self { bytes })
     }
 }
+impl<'a> TryFrom<&'a [u8]> for LambdaRef<'a> {
+    type Error = ssz::DecodeError;
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> LambdaRef<'a> {
+    /// Returns the SSZ bytes this view was decoded from.
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
 impl<'a> ssz::view::SszTypeInfo for LambdaRef<'a> {
     fn is_ssz_fixed_len() -> bool {
         false
@@ -4460,6 +6017,62 @@ impl<'a> LambdaRef<'a> {
             x: self.x().expect("valid view"),
         }
     }
+    /// Converts this view into `out`, reusing the allocations of its
+    /// list and vector fields.
+    ///
+    /// Produces the same value as `to_owned`, but avoids reallocating
+    /// when converting many views in a loop. The view is validated
+    /// with `validate_deep` before anything is written, so on error
+    /// `out` is left unchanged.
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    pub fn to_owned_into(&self, out: &mut Lambda) -> Result<(), ssz::DecodeError> {
+        self.validate_deep()?;
+        out.w = self.w()?;
+        out.x = self.x()?;
+        Ok(())
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> LambdaRef<'a> {
+    fn eq_owned(&self, other: &Lambda) -> Result<bool, ssz::DecodeError> {
+        use ssz::Decode;
+        let bitvector_bytes = self
+            .bytes
+            .get(..1usize)
+            .ok_or_else(|| ssz::DecodeError::Incomplete {
+                needed: 1usize - self.bytes.len(),
+            })?;
+        let bitvector = ssz_types::BitVector::<4usize>::from_ssz_bytes(bitvector_bytes)?;
+        if bitvector.get(0usize).unwrap_or(false) != other.w.is_some() {
+            return Ok(false);
+        }
+        if bitvector.get(1usize).unwrap_or(false) != other.x.is_some() {
+            return Ok(false);
+        }
+        if !({
+            let value: Optional<u16> = self.w()?;
+            value == other.w
+        }) {
+            return Ok(false);
+        }
+        if !({
+            let value: Optional<u8> = self.x()?;
+            value == other.x
+        }) {
+            return Ok(false);
+        }
+        Ok(true)
+    }
+}
+impl<'a> PartialEq<Lambda> for LambdaRef<'a> {
+    fn eq(&self, other: &Lambda) -> bool {
+        self.eq_owned(other).unwrap_or(false)
+    }
+}
+impl<'a> PartialEq<LambdaRef<'a>> for Lambda {
+    fn eq(&self, other: &LambdaRef<'a>) -> bool {
+        other == self
+    }
 }
 #[derive(
     std::clone::Clone,
@@ -4496,6 +6109,21 @@ impl tree_hash::TreeHash for Mu {
         hasher.finish().expect("tree hash derive should not have a remaining buffer")
     }
 }
+impl tree_hash::TreeHashLeaves for Mu {
+    fn tree_hash_leaf_count() -> usize {
+        2usize
+    }
+    fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+        &self,
+        index: usize,
+    ) -> H::Output {
+        match index {
+            0usize => <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.y),
+            1usize => <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.z),
+            _ => panic!("leaf index {index} out of range for {} leaves", 2usize),
+        }
+    }
+}
 /// Zero-copy view over [`Mu`].
 ///
 /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
@@ -4529,7 +6157,7 @@ impl<'a> MuRef<'a> {
             ],
             0usize,
         )?;
-        ssz::view::DecodeView::from_ssz_bytes(bytes)
+        ssz::view::DecodeView::from_ssz_bytes(bytes).map_err(|e| e.bounded(bytes.len()))
     }
     pub fn z(&self) -> Result<UnionARef<'a>, ssz::DecodeError> {
         let bytes = ssz::layout::read_field_bytes(
@@ -4546,7 +6174,23 @@ impl<'a> MuRef<'a> {
             ],
             1usize,
         )?;
-        ssz::view::DecodeView::from_ssz_bytes(bytes)
+        ssz::view::DecodeView::from_ssz_bytes(bytes).map_err(|e| e.bounded(bytes.len()))
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> MuRef<'a> {
+    /// Validates every field of this view, recursing into nested
+    /// views and walking every list and vector, and returns the
+    /// first error.
+    ///
+    /// `from_ssz_bytes` only checks the view's own layout, so this
+    /// rejects bytes that would otherwise fail on a later access.
+    pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+        let value = self.y()?;
+        value.validate_deep()?;
+        let value = self.z()?;
+        value.validate_deep()?;
+        Ok(())
     }
 }
 impl<'a> tree_hash::TreeHash for MuRef<'a> {
@@ -4597,6 +6241,19 @@ impl<'a> ssz::view::DecodeView<'a> for MuRef<'a> {
         Ok(Self { bytes })
     }
 }
+impl<'a> TryFrom<&'a [u8]> for MuRef<'a> {
+    type Error = ssz::DecodeError;
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> MuRef<'a> {
+    /// Returns the SSZ bytes this view was decoded from.
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
 impl<'a> ssz::view::SszTypeInfo for MuRef<'a> {
     fn is_ssz_fixed_len() -> bool {
         usize::from(!<Lambda as ssz::Encode>::is_ssz_fixed_len())
@@ -4633,6 +6290,54 @@ impl<'a> MuRef<'a> {
             },
         }
     }
+    /// Converts this view into `out`, reusing the allocations of its
+    /// list and vector fields.
+    ///
+    /// Produces the same value as `to_owned`, but avoids reallocating
+    /// when converting many views in a loop. The view is validated
+    /// with `validate_deep` before anything is written, so on error
+    /// `out` is left unchanged.
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    pub fn to_owned_into(&self, out: &mut Mu) -> Result<(), ssz::DecodeError> {
+        self.validate_deep()?;
+        out.y = {
+            let view = self.y()?;
+            ssz_types::view::ToOwnedSsz::to_owned(&view)
+        };
+        out.z = {
+            let view = self.z()?;
+            ssz_types::view::ToOwnedSsz::to_owned(&view)
+        };
+        Ok(())
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> MuRef<'a> {
+    fn eq_owned(&self, other: &Mu) -> Result<bool, ssz::DecodeError> {
+        if !(self.y()? == other.y) {
+            return Ok(false);
+        }
+        if !({
+            let value: UnionA = {
+                let view = self.z()?;
+                ssz_types::view::ToOwnedSsz::to_owned(&view)
+            };
+            value == other.z
+        }) {
+            return Ok(false);
+        }
+        Ok(true)
+    }
+}
+impl<'a> PartialEq<Mu> for MuRef<'a> {
+    fn eq(&self, other: &Mu) -> bool {
+        self.eq_owned(other).unwrap_or(false)
+    }
+}
+impl<'a> PartialEq<MuRef<'a>> for Mu {
+    fn eq(&self, other: &MuRef<'a>) -> bool {
+        other == self
+    }
 }
 pub type AliasMu = Mu;
 #[derive(
@@ -4678,6 +6383,23 @@ impl tree_hash::TreeHash for Nu {
         hasher.finish().expect("tree hash derive should not have a remaining buffer")
     }
 }
+impl tree_hash::TreeHashLeaves for Nu {
+    fn tree_hash_leaf_count() -> usize {
+        4usize
+    }
+    fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+        &self,
+        index: usize,
+    ) -> H::Output {
+        match index {
+            0usize => <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.zz),
+            1usize => <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.aaa),
+            2usize => <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.bbb),
+            3usize => <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.test),
+            _ => panic!("leaf index {index} out of range for {} leaves", 4usize),
+        }
+    }
+}
 /// Zero-copy view over [`Nu`].
 ///
 /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
@@ -4719,7 +6441,7 @@ impl<'a> NuRef<'a> {
             ],
             0usize,
         )?;
-        ssz::view::DecodeView::from_ssz_bytes(bytes)
+        ssz::view::DecodeView::from_ssz_bytes(bytes).map_err(|e| e.bounded(bytes.len()))
     }
     pub fn aaa(&self) -> Result<FixedVectorRef<'a, bool, 4usize>, ssz::DecodeError> {
         let bytes = ssz::layout::read_field_bytes(
@@ -4744,7 +6466,7 @@ impl<'a> NuRef<'a> {
             ],
             1usize,
         )?;
-        ssz::view::DecodeView::from_ssz_bytes(bytes)
+        ssz::view::DecodeView::from_ssz_bytes(bytes).map_err(|e| e.bounded(bytes.len()))
     }
     pub fn bbb(&self) -> Result<BitListRef<'a, 42usize>, ssz::DecodeError> {
         let bytes = ssz::layout::read_field_bytes(
@@ -4769,7 +6491,7 @@ impl<'a> NuRef<'a> {
             ],
             2usize,
         )?;
-        ssz::view::DecodeView::from_ssz_bytes(bytes)
+        ssz::view::DecodeView::from_ssz_bytes(bytes).map_err(|e| e.bounded(bytes.len()))
     }
     pub fn test(&self) -> Result<Option<AliasMuRef<'a>>, ssz::DecodeError> {
         let bytes = ssz::layout::read_field_bytes(
@@ -4794,19 +6516,22 @@ impl<'a> NuRef<'a> {
             ],
             3usize,
         )?;
-        if bytes.is_empty() {
-            return Err(ssz::DecodeError::InvalidByteLength {
-                len: 0,
-                expected: 1,
-            });
-        }
+        ssz::view::reject_empty(bytes, 1)?;
         let selector = bytes[0];
         match selector {
-            0 => Ok(None),
+            0 => ssz::check_union_body_len(&bytes[1..], 0).map(|()| None),
             1 => {
+                if <AliasMu as ssz::Encode>::is_ssz_fixed_len() {
+                    ssz::check_union_body_len(
+                        &bytes[1..],
+                        <AliasMu as ssz::Encode>::ssz_fixed_len(),
+                    )?;
+                }
+                let body = &bytes[1..];
                 let inner = <AliasMuRef<
                     'a,
-                > as ssz::view::DecodeView>::from_ssz_bytes(&bytes[1..])?;
+                > as ssz::view::DecodeView>::from_ssz_bytes(body)
+                    .map_err(|e| e.bounded(body.len()))?;
                 Ok(Some(inner))
             }
             _ => {
@@ -4819,6 +6544,29 @@ impl<'a> NuRef<'a> {
         }
     }
 }
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> NuRef<'a> {
+    /// Validates every field of this view, recursing into nested
+    /// views and walking every list and vector, and returns the
+    /// first error.
+    ///
+    /// `from_ssz_bytes` only checks the view's own layout, so this
+    /// rejects bytes that would otherwise fail on a later access.
+    pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+        let value = self.zz()?;
+        value.validate_deep()?;
+        let value = self.aaa()?;
+        for item in value.iter() {
+            item?;
+        }
+        self.bbb()?;
+        let value = self.test()?;
+        if let Some(inner) = value {
+            inner.validate_deep()?;
+        }
+        Ok(())
+    }
+}
 impl<'a> tree_hash::TreeHash for NuRef<'a> {
     fn tree_hash_type() -> tree_hash::TreeHashType {
         tree_hash::TreeHashType::StableContainer
@@ -4889,6 +6637,19 @@ impl<'a> ssz::view::DecodeView<'a> for NuRef<'a> {
         Ok(Self { bytes })
     }
 }
+impl<'a> TryFrom<&'a [u8]> for NuRef<'a> {
+    type Error = ssz::DecodeError;
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> NuRef<'a> {
+    /// Returns the SSZ bytes this view was decoded from.
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
 impl<'a> ssz::view::SszTypeInfo for NuRef<'a> {
     fn is_ssz_fixed_len() -> bool {
         usize::from(!<AliasMu as ssz::Encode>::is_ssz_fixed_len())
@@ -4932,4 +6693,69 @@ impl<'a> NuRef<'a> {
                 .map(|inner| ssz_types::view::ToOwnedSsz::to_owned(&inner)),
         }
     }
+    /// Converts this view into `out`, reusing the allocations of its
+    /// list and vector fields.
+    ///
+    /// Produces the same value as `to_owned`, but avoids reallocating
+    /// when converting many views in a loop. The view is validated
+    /// with `validate_deep` before anything is written, so on error
+    /// `out` is left unchanged.
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    pub fn to_owned_into(&self, out: &mut Nu) -> Result<(), ssz::DecodeError> {
+        self.validate_deep()?;
+        out.zz = {
+            let view = self.zz()?;
+            ssz_types::view::ToOwnedSsz::to_owned(&view)
+        };
+        let view = self.aaa()?;
+        for (slot, item) in out.aaa.iter_mut().zip(view.iter()) {
+            *slot = ssz_types::view::ToOwnedSsz::to_owned(&item?);
+        }
+        out.bbb = self.bbb()?.to_owned();
+        out.test = self
+            .test()?
+            .map(|inner| ssz_types::view::ToOwnedSsz::to_owned(&inner));
+        Ok(())
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> NuRef<'a> {
+    fn eq_owned(&self, other: &Nu) -> Result<bool, ssz::DecodeError> {
+        if !(self.zz()? == other.zz) {
+            return Ok(false);
+        }
+        if !({
+            let value: FixedVector<bool, 4usize> = self
+                .aaa()?
+                .to_owned()
+                .expect("valid view");
+            value == other.aaa
+        }) {
+            return Ok(false);
+        }
+        if !({
+            let value: BitAlias = self.bbb()?.to_owned();
+            value == other.bbb
+        }) {
+            return Ok(false);
+        }
+        if !(match (self.test()?, &other.test) {
+            (Some(view), Some(owned)) => view == *owned,
+            (None, None) => true,
+            _ => false,
+        }) {
+            return Ok(false);
+        }
+        Ok(true)
+    }
+}
+impl<'a> PartialEq<Nu> for NuRef<'a> {
+    fn eq(&self, other: &Nu) -> bool {
+        self.eq_owned(other).unwrap_or(false)
+    }
+}
+impl<'a> PartialEq<NuRef<'a>> for Nu {
+    fn eq(&self, other: &NuRef<'a>) -> bool {
+        other == self
+    }
 }
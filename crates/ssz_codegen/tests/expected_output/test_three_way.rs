@@ -6,6 +6,12 @@ use ssz_derive::{Encode, Decode};
 use tree_hash::TreeHashDigest;
 use tree_hash_derive::TreeHash;
 use ssz::view::*;
+/// Hash of the normalized schema this module was generated from.
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+pub const SCHEMA_HASH: [u8; 32] = [
+    187, 18, 72, 178, 252, 232, 253, 236, 0, 127, 203, 222, 135, 67, 81, 34, 72, 33, 148,
+    245, 161, 104, 25, 122, 76, 10, 229, 53, 7, 26, 111, 178,
+];
 #[allow(dead_code, reason = "generated code using ssz-gen")]
 pub const CONST_A: u64 = 100u64;
 #[derive(
@@ -43,6 +49,21 @@ impl tree_hash::TreeHash for ContainerA {
         hasher.finish().expect("tree hash derive should not have a remaining buffer")
     }
 }
+impl tree_hash::TreeHashLeaves for ContainerA {
+    fn tree_hash_leaf_count() -> usize {
+        2usize
+    }
+    fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+        &self,
+        index: usize,
+    ) -> H::Output {
+        match index {
+            0usize => <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.value),
+            1usize => <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.b_ref),
+            _ => panic!("leaf index {index} out of range for {} leaves", 2usize),
+        }
+    }
+}
 /// Zero-copy view over [`ContainerA`].
 ///
 /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
@@ -76,7 +97,7 @@ impl<'a> ContainerARef<'a> {
             ],
             0usize,
         )?;
-        ssz::view::DecodeView::from_ssz_bytes(bytes)
+        ssz::view::DecodeView::from_ssz_bytes(bytes).map_err(|e| e.bounded(bytes.len()))
     }
     pub fn b_ref(
         &self,
@@ -98,7 +119,22 @@ impl<'a> ContainerARef<'a> {
             ],
             1usize,
         )?;
-        ssz::view::DecodeView::from_ssz_bytes(bytes)
+        ssz::view::DecodeView::from_ssz_bytes(bytes).map_err(|e| e.bounded(bytes.len()))
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> ContainerARef<'a> {
+    /// Validates every field of this view, recursing into nested
+    /// views and walking every list and vector, and returns the
+    /// first error.
+    ///
+    /// `from_ssz_bytes` only checks the view's own layout, so this
+    /// rejects bytes that would otherwise fail on a later access.
+    pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+        self.value()?;
+        let value = self.b_ref()?;
+        value.validate_deep()?;
+        Ok(())
     }
 }
 impl<'a> tree_hash::TreeHash for ContainerARef<'a> {
@@ -149,6 +185,19 @@ impl<'a> ssz::view::DecodeView<'a> for ContainerARef<'a> {
         Ok(Self { bytes })
     }
 }
+impl<'a> TryFrom<&'a [u8]> for ContainerARef<'a> {
+    type Error = ssz::DecodeError;
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> ContainerARef<'a> {
+    /// Returns the SSZ bytes this view was decoded from.
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
 impl<'a> ssz::view::SszTypeInfo for ContainerARef<'a> {
     fn is_ssz_fixed_len() -> bool {
         usize::from(!<u8 as ssz::Encode>::is_ssz_fixed_len())
@@ -184,6 +233,54 @@ impl<'a> ContainerARef<'a> {
             },
         }
     }
+    /// Converts this view into `out`, reusing the allocations of its
+    /// list and vector fields.
+    ///
+    /// Produces the same value as `to_owned`, but avoids reallocating
+    /// when converting many views in a loop. The view is validated
+    /// with `validate_deep` before anything is written, so on error
+    /// `out` is left unchanged.
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    pub fn to_owned_into(&self, out: &mut ContainerA) -> Result<(), ssz::DecodeError> {
+        self.validate_deep()?;
+        out.value = self.value()?;
+        out.b_ref = {
+            let view = self.b_ref()?;
+            ssz_types::view::ToOwnedSsz::to_owned(&view)
+        };
+        Ok(())
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> ContainerARef<'a> {
+    fn eq_owned(&self, other: &ContainerA) -> Result<bool, ssz::DecodeError> {
+        if !({
+            let value: u8 = self.value()?;
+            value == other.value
+        }) {
+            return Ok(false);
+        }
+        if !({
+            let value: crate::tests::input::test_three_way_b::ContainerB = {
+                let view = self.b_ref()?;
+                ssz_types::view::ToOwnedSsz::to_owned(&view)
+            };
+            value == other.b_ref
+        }) {
+            return Ok(false);
+        }
+        Ok(true)
+    }
+}
+impl<'a> PartialEq<ContainerA> for ContainerARef<'a> {
+    fn eq(&self, other: &ContainerA) -> bool {
+        self.eq_owned(other).unwrap_or(false)
+    }
+}
+impl<'a> PartialEq<ContainerARef<'a>> for ContainerA {
+    fn eq(&self, other: &ContainerARef<'a>) -> bool {
+        other == self
+    }
 }
 #[allow(dead_code, reason = "generated code using ssz-gen")]
 pub const CONST_B: u64 = 200u64;
@@ -222,6 +319,21 @@ impl tree_hash::TreeHash for ContainerB {
         hasher.finish().expect("tree hash derive should not have a remaining buffer")
     }
 }
+impl tree_hash::TreeHashLeaves for ContainerB {
+    fn tree_hash_leaf_count() -> usize {
+        2usize
+    }
+    fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+        &self,
+        index: usize,
+    ) -> H::Output {
+        match index {
+            0usize => <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.value),
+            1usize => <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.c_ref),
+            _ => panic!("leaf index {index} out of range for {} leaves", 2usize),
+        }
+    }
+}
 /// Zero-copy view over [`ContainerB`].
 ///
 /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
@@ -255,7 +367,7 @@ impl<'a> ContainerBRef<'a> {
             ],
             0usize,
         )?;
-        ssz::view::DecodeView::from_ssz_bytes(bytes)
+        ssz::view::DecodeView::from_ssz_bytes(bytes).map_err(|e| e.bounded(bytes.len()))
     }
     pub fn c_ref(
         &self,
@@ -277,7 +389,22 @@ impl<'a> ContainerBRef<'a> {
             ],
             1usize,
         )?;
-        ssz::view::DecodeView::from_ssz_bytes(bytes)
+        ssz::view::DecodeView::from_ssz_bytes(bytes).map_err(|e| e.bounded(bytes.len()))
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> ContainerBRef<'a> {
+    /// Validates every field of this view, recursing into nested
+    /// views and walking every list and vector, and returns the
+    /// first error.
+    ///
+    /// `from_ssz_bytes` only checks the view's own layout, so this
+    /// rejects bytes that would otherwise fail on a later access.
+    pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+        self.value()?;
+        let value = self.c_ref()?;
+        value.validate_deep()?;
+        Ok(())
     }
 }
 impl<'a> tree_hash::TreeHash for ContainerBRef<'a> {
@@ -328,6 +455,19 @@ impl<'a> ssz::view::DecodeView<'a> for ContainerBRef<'a> {
         Ok(Self { bytes })
     }
 }
+impl<'a> TryFrom<&'a [u8]> for ContainerBRef<'a> {
+    type Error = ssz::DecodeError;
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> ContainerBRef<'a> {
+    /// Returns the SSZ bytes this view was decoded from.
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
 impl<'a> ssz::view::SszTypeInfo for ContainerBRef<'a> {
     fn is_ssz_fixed_len() -> bool {
         usize::from(!<u16 as ssz::Encode>::is_ssz_fixed_len())
@@ -363,6 +503,54 @@ impl<'a> ContainerBRef<'a> {
             },
         }
     }
+    /// Converts this view into `out`, reusing the allocations of its
+    /// list and vector fields.
+    ///
+    /// Produces the same value as `to_owned`, but avoids reallocating
+    /// when converting many views in a loop. The view is validated
+    /// with `validate_deep` before anything is written, so on error
+    /// `out` is left unchanged.
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    pub fn to_owned_into(&self, out: &mut ContainerB) -> Result<(), ssz::DecodeError> {
+        self.validate_deep()?;
+        out.value = self.value()?;
+        out.c_ref = {
+            let view = self.c_ref()?;
+            ssz_types::view::ToOwnedSsz::to_owned(&view)
+        };
+        Ok(())
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> ContainerBRef<'a> {
+    fn eq_owned(&self, other: &ContainerB) -> Result<bool, ssz::DecodeError> {
+        if !({
+            let value: u16 = self.value()?;
+            value == other.value
+        }) {
+            return Ok(false);
+        }
+        if !({
+            let value: crate::tests::input::test_three_way_c::ContainerC = {
+                let view = self.c_ref()?;
+                ssz_types::view::ToOwnedSsz::to_owned(&view)
+            };
+            value == other.c_ref
+        }) {
+            return Ok(false);
+        }
+        Ok(true)
+    }
+}
+impl<'a> PartialEq<ContainerB> for ContainerBRef<'a> {
+    fn eq(&self, other: &ContainerB) -> bool {
+        self.eq_owned(other).unwrap_or(false)
+    }
+}
+impl<'a> PartialEq<ContainerBRef<'a>> for ContainerB {
+    fn eq(&self, other: &ContainerBRef<'a>) -> bool {
+        other == self
+    }
 }
 #[allow(dead_code, reason = "generated code using ssz-gen")]
 pub const CONST_C: u64 = 300u64;
@@ -378,6 +566,12 @@ pub const CONST_C: u64 = 300u64;
 pub struct ContainerC {
     pub value: u32,
 }
+impl ContainerC {
+    /// Byte offset of each field in the SSZ encoding, in declaration order.
+    pub const FIELD_OFFSETS: &[usize] = &[0];
+    /// Byte size of each field in the SSZ encoding, in declaration order.
+    pub const FIELD_SIZES: &[usize] = &[4];
+}
 impl tree_hash::TreeHash for ContainerC {
     fn tree_hash_type() -> tree_hash::TreeHashType {
         tree_hash::TreeHashType::Container
@@ -397,6 +591,20 @@ impl tree_hash::TreeHash for ContainerC {
         hasher.finish().expect("tree hash derive should not have a remaining buffer")
     }
 }
+impl tree_hash::TreeHashLeaves for ContainerC {
+    fn tree_hash_leaf_count() -> usize {
+        1usize
+    }
+    fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+        &self,
+        index: usize,
+    ) -> H::Output {
+        match index {
+            0usize => <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.value),
+            _ => panic!("leaf index {index} out of range for {} leaves", 1usize),
+        }
+    }
+}
 /// Zero-copy view over [`ContainerC`].
 ///
 /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
@@ -416,17 +624,21 @@ pub struct ContainerCRef<'a> {
 #[allow(dead_code, reason = "generated code using ssz-gen")]
 impl<'a> ContainerCRef<'a> {
     pub fn value(&self) -> Result<u32, ssz::DecodeError> {
-        let bytes = ssz::layout::read_field_bytes(
-            self.bytes,
-            &[
-                (
-                    <u32 as ssz::Encode>::is_ssz_fixed_len(),
-                    <u32 as ssz::Encode>::ssz_fixed_len(),
-                ),
-            ],
-            0usize,
-        )?;
-        ssz::view::DecodeView::from_ssz_bytes(bytes)
+        let bytes = &self.bytes[0..4];
+        ssz::view::DecodeView::from_ssz_bytes(bytes).map_err(|e| e.bounded(bytes.len()))
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> ContainerCRef<'a> {
+    /// Validates every field of this view, recursing into nested
+    /// views and walking every list and vector, and returns the
+    /// first error.
+    ///
+    /// `from_ssz_bytes` only checks the view's own layout, so this
+    /// rejects bytes that would otherwise fail on a later access.
+    pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+        self.value()?;
+        Ok(())
     }
 }
 impl<'a> tree_hash::TreeHash for ContainerCRef<'a> {
@@ -466,6 +678,19 @@ impl<'a> ssz::view::DecodeView<'a> for ContainerCRef<'a> {
         Ok(Self { bytes })
     }
 }
+impl<'a> TryFrom<&'a [u8]> for ContainerCRef<'a> {
+    type Error = ssz::DecodeError;
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> ContainerCRef<'a> {
+    /// Returns the SSZ bytes this view was decoded from.
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
 impl<'a> ssz::view::SszTypeInfo for ContainerCRef<'a> {
     fn is_ssz_fixed_len() -> bool {
         usize::from(!<u32 as ssz::Encode>::is_ssz_fixed_len()) == 0
@@ -493,4 +718,39 @@ impl<'a> ContainerCRef<'a> {
             value: self.value().expect("valid view"),
         }
     }
+    /// Converts this view into `out`, reusing the allocations of its
+    /// list and vector fields.
+    ///
+    /// Produces the same value as `to_owned`, but avoids reallocating
+    /// when converting many views in a loop. The view is validated
+    /// with `validate_deep` before anything is written, so on error
+    /// `out` is left unchanged.
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    pub fn to_owned_into(&self, out: &mut ContainerC) -> Result<(), ssz::DecodeError> {
+        self.validate_deep()?;
+        out.value = self.value()?;
+        Ok(())
+    }
+}
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+impl<'a> ContainerCRef<'a> {
+    fn eq_owned(&self, other: &ContainerC) -> Result<bool, ssz::DecodeError> {
+        if !({
+            let value: u32 = self.value()?;
+            value == other.value
+        }) {
+            return Ok(false);
+        }
+        Ok(true)
+    }
+}
+impl<'a> PartialEq<ContainerC> for ContainerCRef<'a> {
+    fn eq(&self, other: &ContainerC) -> bool {
+        self.eq_owned(other).unwrap_or(false)
+    }
+}
+impl<'a> PartialEq<ContainerCRef<'a>> for ContainerC {
+    fn eq(&self, other: &ContainerCRef<'a>) -> bool {
+        other == self
+    }
 }
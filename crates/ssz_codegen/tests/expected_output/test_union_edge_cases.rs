@@ -11,7 +11,13 @@ pub mod tests {
             use tree_hash::TreeHashDigest;
             use tree_hash_derive::TreeHash;
             use ssz::view::*;
-            #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                2, 156, 94, 29, 25, 5, 75, 232, 98, 180, 126, 231, 78, 54, 78, 82, 88,
+                185, 16, 0, 16, 123, 75, 100, 59, 68, 255, 6, 134, 175, 175, 56,
+            ];
+            #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, Hash)]
             #[ssz(enum_behaviour = "union")]
             pub enum AnotherSimple {
                 Selector0(bool),
@@ -46,6 +52,40 @@ pub mod tests {
                     }
                 }
             }
+            impl std::fmt::Display for AnotherSimple {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    match self {
+                        AnotherSimple::Selector0(inner) => {
+                            write!(f, "AnotherSimple::Selector0({inner:?})")
+                        }
+                        AnotherSimple::Selector1(inner) => {
+                            write!(f, "AnotherSimple::Selector1({inner:?})")
+                        }
+                    }
+                }
+            }
+            impl From<bool> for AnotherSimple {
+                fn from(value: bool) -> Self {
+                    AnotherSimple::Selector0(value)
+                }
+            }
+            impl From<u32> for AnotherSimple {
+                fn from(value: u32) -> Self {
+                    AnotherSimple::Selector1(value)
+                }
+            }
+            #[cfg(test)]
+            impl AnotherSimple {
+                /// Returns one value per selector, in selector order, each
+                /// holding its member's default. Lets tests check that code
+                /// matching on the union handles every member.
+                pub fn all_selector_examples() -> Vec<Self> {
+                    vec![
+                        AnotherSimple::Selector0(Default::default()),
+                        AnotherSimple::Selector1(Default::default())
+                    ]
+                }
+            }
             #[derive(Debug, Copy, Clone)]
             pub struct AnotherSimpleRef<'a> {
                 bytes: &'a [u8],
@@ -62,7 +102,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <bool as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <bool as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn as_selector1(&self) -> Result<u32, ssz::DecodeError> {
                     if self.selector() != 1u8 {
@@ -72,7 +120,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <u32 as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <u32 as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn to_owned(&self) -> AnotherSimple {
                     match self.selector() {
@@ -89,6 +145,16 @@ pub mod tests {
                         _ => panic!("Invalid union selector: {}", self.selector()),
                     }
                 }
+                /// Validates the selector and the selected member, recursing
+                /// into nested views and walking every list and vector, and
+                /// returns the first error.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    match self.selector() {
+                        0u8 => self.as_selector0().map(|_| ()),
+                        1u8 => self.as_selector1().map(|_| ()),
+                        selector => Err(ssz::DecodeError::UnionSelectorInvalid(selector)),
+                    }
+                }
             }
             impl<'a> ssz::view::DecodeView<'a> for AnotherSimpleRef<'a> {
                 fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
@@ -146,7 +212,7 @@ pub mod tests {
                     }
                 }
             }
-            #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+            #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, Hash)]
             #[ssz(enum_behaviour = "union")]
             pub enum ComplexUnion {
                 Selector0(VariableList<u8, 10usize>),
@@ -197,6 +263,44 @@ pub mod tests {
                     }
                 }
             }
+            impl std::fmt::Display for ComplexUnion {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    match self {
+                        ComplexUnion::Selector0(inner) => {
+                            write!(f, "ComplexUnion::Selector0({inner:?})")
+                        }
+                        ComplexUnion::Selector1(inner) => {
+                            write!(f, "ComplexUnion::Selector1({inner:?})")
+                        }
+                        ComplexUnion::SimpleUnion(inner) => {
+                            write!(f, "ComplexUnion::SimpleUnion({inner:?})")
+                        }
+                        ComplexUnion::Selector3(inner) => {
+                            write!(f, "ComplexUnion::Selector3({inner:?})")
+                        }
+                    }
+                }
+            }
+            impl From<VariableList<u8, 10usize>> for ComplexUnion {
+                fn from(value: VariableList<u8, 10usize>) -> Self {
+                    ComplexUnion::Selector0(value)
+                }
+            }
+            impl From<FixedVector<u16, 5usize>> for ComplexUnion {
+                fn from(value: FixedVector<u16, 5usize>) -> Self {
+                    ComplexUnion::Selector1(value)
+                }
+            }
+            impl From<SimpleUnion> for ComplexUnion {
+                fn from(value: SimpleUnion) -> Self {
+                    ComplexUnion::SimpleUnion(value)
+                }
+            }
+            impl From<BitVector<32usize>> for ComplexUnion {
+                fn from(value: BitVector<32usize>) -> Self {
+                    ComplexUnion::Selector3(value)
+                }
+            }
             #[derive(Debug, Copy, Clone)]
             pub struct ComplexUnionRef<'a> {
                 bytes: &'a [u8],
@@ -215,7 +319,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <VariableList<u8, 10usize> as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <VariableList<u8, 10usize> as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn as_selector1(
                     &self,
@@ -227,7 +339,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <FixedVector<u16, 5usize> as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <FixedVector<u16, 5usize> as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn as_selector2(
                     &self,
@@ -239,7 +359,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <SimpleUnion as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <SimpleUnion as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn as_selector3(
                     &self,
@@ -251,7 +379,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <BitVector<32usize> as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <BitVector<32usize> as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn to_owned(&self) -> ComplexUnion {
                     match self.selector() {
@@ -282,6 +418,28 @@ pub mod tests {
                         _ => panic!("Invalid union selector: {}", self.selector()),
                     }
                 }
+                /// Validates the selector and the selected member, recursing
+                /// into nested views and walking every list and vector, and
+                /// returns the first error.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    match self.selector() {
+                        0u8 => self.as_selector0().map(|_| ()),
+                        1u8 => {
+                            let value = self.as_selector1()?;
+                            for item in value.iter() {
+                                item?;
+                            }
+                            Ok(())
+                        }
+                        2u8 => {
+                            let value = self.as_selector2()?;
+                            value.validate_deep()?;
+                            Ok(())
+                        }
+                        3u8 => self.as_selector3().map(|_| ()),
+                        selector => Err(ssz::DecodeError::UnionSelectorInvalid(selector)),
+                    }
+                }
             }
             impl<'a> ssz::view::DecodeView<'a> for ComplexUnionRef<'a> {
                 fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
@@ -358,7 +516,7 @@ pub mod tests {
                     }
                 }
             }
-            #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+            #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, Hash)]
             #[ssz(enum_behaviour = "union")]
             pub enum MixedOptional {
                 Selector0,
@@ -399,6 +557,44 @@ pub mod tests {
                     }
                 }
             }
+            impl std::fmt::Display for MixedOptional {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    match self {
+                        MixedOptional::Selector0 => {
+                            f.write_str("MixedOptional::Selector0")
+                        }
+                        MixedOptional::Selector1(inner) => {
+                            write!(f, "MixedOptional::Selector1({inner:?})")
+                        }
+                        MixedOptional::Selector2(inner) => {
+                            write!(f, "MixedOptional::Selector2({inner:?})")
+                        }
+                    }
+                }
+            }
+            impl From<u8> for MixedOptional {
+                fn from(value: u8) -> Self {
+                    MixedOptional::Selector1(value)
+                }
+            }
+            impl From<u16> for MixedOptional {
+                fn from(value: u16) -> Self {
+                    MixedOptional::Selector2(value)
+                }
+            }
+            #[cfg(test)]
+            impl MixedOptional {
+                /// Returns one value per selector, in selector order, each
+                /// holding its member's default. Lets tests check that code
+                /// matching on the union handles every member.
+                pub fn all_selector_examples() -> Vec<Self> {
+                    vec![
+                        MixedOptional::Selector0,
+                        MixedOptional::Selector1(Default::default()),
+                        MixedOptional::Selector2(Default::default())
+                    ]
+                }
+            }
             #[derive(Debug, Copy, Clone)]
             pub struct MixedOptionalRef<'a> {
                 bytes: &'a [u8],
@@ -415,7 +611,7 @@ pub mod tests {
                             ),
                         );
                     }
-                    Ok(())
+                    ssz::check_union_body_len(&self.bytes[1..], 0)
                 }
                 pub fn as_selector1(&self) -> Result<u8, ssz::DecodeError> {
                     if self.selector() != 1u8 {
@@ -425,7 +621,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <u8 as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <u8 as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn as_selector2(&self) -> Result<u16, ssz::DecodeError> {
                     if self.selector() != 2u8 {
@@ -435,7 +639,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <u16 as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <u16 as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn to_owned(&self) -> MixedOptional {
                     match self.selector() {
@@ -456,6 +668,17 @@ pub mod tests {
                         _ => panic!("Invalid union selector: {}", self.selector()),
                     }
                 }
+                /// Validates the selector and the selected member, recursing
+                /// into nested views and walking every list and vector, and
+                /// returns the first error.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    match self.selector() {
+                        0u8 => self.as_selector0(),
+                        1u8 => self.as_selector1().map(|_| ()),
+                        2u8 => self.as_selector2().map(|_| ()),
+                        selector => Err(ssz::DecodeError::UnionSelectorInvalid(selector)),
+                    }
+                }
             }
             impl<'a> ssz::view::DecodeView<'a> for MixedOptionalRef<'a> {
                 fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
@@ -518,7 +741,7 @@ pub mod tests {
                     }
                 }
             }
-            #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+            #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, Hash)]
             #[ssz(enum_behaviour = "union")]
             pub enum NestedUnion {
                 SimpleUnion(SimpleUnion),
@@ -561,6 +784,36 @@ pub mod tests {
                     }
                 }
             }
+            impl std::fmt::Display for NestedUnion {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    match self {
+                        NestedUnion::SimpleUnion(inner) => {
+                            write!(f, "NestedUnion::SimpleUnion({inner:?})")
+                        }
+                        NestedUnion::AnotherSimple(inner) => {
+                            write!(f, "NestedUnion::AnotherSimple({inner:?})")
+                        }
+                        NestedUnion::Selector2(inner) => {
+                            write!(f, "NestedUnion::Selector2({inner:?})")
+                        }
+                    }
+                }
+            }
+            impl From<SimpleUnion> for NestedUnion {
+                fn from(value: SimpleUnion) -> Self {
+                    NestedUnion::SimpleUnion(value)
+                }
+            }
+            impl From<AnotherSimple> for NestedUnion {
+                fn from(value: AnotherSimple) -> Self {
+                    NestedUnion::AnotherSimple(value)
+                }
+            }
+            impl From<u64> for NestedUnion {
+                fn from(value: u64) -> Self {
+                    NestedUnion::Selector2(value)
+                }
+            }
             #[derive(Debug, Copy, Clone)]
             pub struct NestedUnionRef<'a> {
                 bytes: &'a [u8],
@@ -579,7 +832,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <SimpleUnion as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <SimpleUnion as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn as_selector1(
                     &self,
@@ -591,7 +852,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <AnotherSimple as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <AnotherSimple as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn as_selector2(&self) -> Result<u64, ssz::DecodeError> {
                     if self.selector() != 2u8 {
@@ -601,7 +870,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <u64 as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <u64 as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn to_owned(&self) -> NestedUnion {
                     match self.selector() {
@@ -625,6 +902,25 @@ pub mod tests {
                         _ => panic!("Invalid union selector: {}", self.selector()),
                     }
                 }
+                /// Validates the selector and the selected member, recursing
+                /// into nested views and walking every list and vector, and
+                /// returns the first error.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    match self.selector() {
+                        0u8 => {
+                            let value = self.as_selector0()?;
+                            value.validate_deep()?;
+                            Ok(())
+                        }
+                        1u8 => {
+                            let value = self.as_selector1()?;
+                            value.validate_deep()?;
+                            Ok(())
+                        }
+                        2u8 => self.as_selector2().map(|_| ()),
+                        selector => Err(ssz::DecodeError::UnionSelectorInvalid(selector)),
+                    }
+                }
             }
             impl<'a> ssz::view::DecodeView<'a> for NestedUnionRef<'a> {
                 fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
@@ -691,7 +987,7 @@ pub mod tests {
                     }
                 }
             }
-            #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+            #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, Hash)]
             #[ssz(enum_behaviour = "union")]
             pub enum SimpleUnion {
                 Selector0(u8),
@@ -726,6 +1022,40 @@ pub mod tests {
                     }
                 }
             }
+            impl std::fmt::Display for SimpleUnion {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    match self {
+                        SimpleUnion::Selector0(inner) => {
+                            write!(f, "SimpleUnion::Selector0({inner:?})")
+                        }
+                        SimpleUnion::Selector1(inner) => {
+                            write!(f, "SimpleUnion::Selector1({inner:?})")
+                        }
+                    }
+                }
+            }
+            impl From<u8> for SimpleUnion {
+                fn from(value: u8) -> Self {
+                    SimpleUnion::Selector0(value)
+                }
+            }
+            impl From<u16> for SimpleUnion {
+                fn from(value: u16) -> Self {
+                    SimpleUnion::Selector1(value)
+                }
+            }
+            #[cfg(test)]
+            impl SimpleUnion {
+                /// Returns one value per selector, in selector order, each
+                /// holding its member's default. Lets tests check that code
+                /// matching on the union handles every member.
+                pub fn all_selector_examples() -> Vec<Self> {
+                    vec![
+                        SimpleUnion::Selector0(Default::default()),
+                        SimpleUnion::Selector1(Default::default())
+                    ]
+                }
+            }
             #[derive(Debug, Copy, Clone)]
             pub struct SimpleUnionRef<'a> {
                 bytes: &'a [u8],
@@ -742,7 +1072,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <u8 as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <u8 as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn as_selector1(&self) -> Result<u16, ssz::DecodeError> {
                     if self.selector() != 1u8 {
@@ -752,7 +1090,15 @@ pub mod tests {
                             ),
                         );
                     }
-                    ssz::view::DecodeView::from_ssz_bytes(&self.bytes[1..])
+                    let body = &self.bytes[1..];
+                    if <u16 as ssz::Encode>::is_ssz_fixed_len() {
+                        ssz::check_union_body_len(
+                            body,
+                            <u16 as ssz::Encode>::ssz_fixed_len(),
+                        )?;
+                    }
+                    ssz::view::DecodeView::from_ssz_bytes(body)
+                        .map_err(|e| e.bounded(body.len()))
                 }
                 pub fn to_owned(&self) -> SimpleUnion {
                     match self.selector() {
@@ -769,6 +1115,16 @@ pub mod tests {
                         _ => panic!("Invalid union selector: {}", self.selector()),
                     }
                 }
+                /// Validates the selector and the selected member, recursing
+                /// into nested views and walking every list and vector, and
+                /// returns the first error.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    match self.selector() {
+                        0u8 => self.as_selector0().map(|_| ()),
+                        1u8 => self.as_selector1().map(|_| ()),
+                        selector => Err(ssz::DecodeError::UnionSelectorInvalid(selector)),
+                    }
+                }
             }
             impl<'a> ssz::view::DecodeView<'a> for SimpleUnionRef<'a> {
                 fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
@@ -907,6 +1263,49 @@ pub mod tests {
                         .expect("tree hash derive should not have a remaining buffer")
                 }
             }
+            impl tree_hash::TreeHashLeaves for UnionEdgeCases {
+                fn tree_hash_leaf_count() -> usize {
+                    6usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.simple)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.nested)
+                        }
+                        2usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<
+                                H,
+                            >(&self.complex)
+                        }
+                        3usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<
+                                H,
+                            >(&self.opt_simple)
+                        }
+                        4usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<
+                                H,
+                            >(&self.opt_complex)
+                        }
+                        5usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<
+                                H,
+                            >(&self.opt_union)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 6usize
+                            )
+                        }
+                    }
+                }
+            }
             /// Zero-copy view over [`UnionEdgeCases`].
             ///
             /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
@@ -957,6 +1356,7 @@ pub mod tests {
                         0usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn nested(&self) -> Result<NestedUnionRef<'a>, ssz::DecodeError> {
                     let bytes = ssz::layout::read_field_bytes(
@@ -990,6 +1390,7 @@ pub mod tests {
                         1usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn complex(&self) -> Result<ComplexUnionRef<'a>, ssz::DecodeError> {
                     let bytes = ssz::layout::read_field_bytes(
@@ -1023,6 +1424,7 @@ pub mod tests {
                         2usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn opt_simple(&self) -> Result<Option<u8>, ssz::DecodeError> {
                     let bytes = ssz::layout::read_field_bytes(
@@ -1055,19 +1457,22 @@ pub mod tests {
                         ],
                         3usize,
                     )?;
-                    if bytes.is_empty() {
-                        return Err(ssz::DecodeError::InvalidByteLength {
-                            len: 0,
-                            expected: 1,
-                        });
-                    }
+                    ssz::view::reject_empty(bytes, 1)?;
                     let selector = bytes[0];
                     match selector {
-                        0 => Ok(None),
+                        0 => ssz::check_union_body_len(&bytes[1..], 0).map(|()| None),
                         1 => {
+                            if <u8 as ssz::Encode>::is_ssz_fixed_len() {
+                                ssz::check_union_body_len(
+                                    &bytes[1..],
+                                    <u8 as ssz::Encode>::ssz_fixed_len(),
+                                )?;
+                            }
+                            let body = &bytes[1..];
                             let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(
-                                &bytes[1..],
-                            )?;
+                                    body,
+                                )
+                                .map_err(|e| e.bounded(body.len()))?;
                             Ok(Some(inner))
                         }
                         _ => {
@@ -1112,21 +1517,27 @@ pub mod tests {
                         ],
                         4usize,
                     )?;
-                    if bytes.is_empty() {
-                        return Err(ssz::DecodeError::InvalidByteLength {
-                            len: 0,
-                            expected: 1,
-                        });
-                    }
+                    ssz::view::reject_empty(bytes, 1)?;
                     let selector = bytes[0];
                     match selector {
-                        0 => Ok(None),
+                        0 => ssz::check_union_body_len(&bytes[1..], 0).map(|()| None),
                         1 => {
+                            if <VariableList<
+                                u16,
+                                8usize,
+                            > as ssz::Encode>::is_ssz_fixed_len() {
+                                ssz::check_union_body_len(
+                                    &bytes[1..],
+                                    <VariableList<u16, 8usize> as ssz::Encode>::ssz_fixed_len(),
+                                )?;
+                            }
+                            let body = &bytes[1..];
                             let inner = <ListRef<
                                 'a,
                                 u16,
                                 8usize,
-                            > as ssz::view::DecodeView>::from_ssz_bytes(&bytes[1..])?;
+                            > as ssz::view::DecodeView>::from_ssz_bytes(body)
+                                .map_err(|e| e.bounded(body.len()))?;
                             Ok(Some(inner))
                         }
                         _ => {
@@ -1171,19 +1582,22 @@ pub mod tests {
                         ],
                         5usize,
                     )?;
-                    if bytes.is_empty() {
-                        return Err(ssz::DecodeError::InvalidByteLength {
-                            len: 0,
-                            expected: 1,
-                        });
-                    }
+                    ssz::view::reject_empty(bytes, 1)?;
                     let selector = bytes[0];
                     match selector {
-                        0 => Ok(None),
+                        0 => ssz::check_union_body_len(&bytes[1..], 0).map(|()| None),
                         1 => {
+                            if <SimpleUnion as ssz::Encode>::is_ssz_fixed_len() {
+                                ssz::check_union_body_len(
+                                    &bytes[1..],
+                                    <SimpleUnion as ssz::Encode>::ssz_fixed_len(),
+                                )?;
+                            }
+                            let body = &bytes[1..];
                             let inner = <SimpleUnionRef<
                                 'a,
-                            > as ssz::view::DecodeView>::from_ssz_bytes(&bytes[1..])?;
+                            > as ssz::view::DecodeView>::from_ssz_bytes(body)
+                                .map_err(|e| e.bounded(body.len()))?;
                             Ok(Some(inner))
                         }
                         _ => {
@@ -1196,6 +1610,35 @@ pub mod tests {
                     }
                 }
             }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> UnionEdgeCasesRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    let value = self.simple()?;
+                    value.validate_deep()?;
+                    let value = self.nested()?;
+                    value.validate_deep()?;
+                    let value = self.complex()?;
+                    value.validate_deep()?;
+                    self.opt_simple()?;
+                    let value = self.opt_complex()?;
+                    if let Some(inner) = value {
+                        for item in inner.iter() {
+                            item?;
+                        }
+                    }
+                    let value = self.opt_union()?;
+                    if let Some(inner) = value {
+                        inner.validate_deep()?;
+                    }
+                    Ok(())
+                }
+            }
             impl<'a> tree_hash::TreeHash for UnionEdgeCasesRef<'a> {
                 fn tree_hash_type() -> tree_hash::TreeHashType {
                     tree_hash::TreeHashType::StableContainer
@@ -1288,6 +1731,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for UnionEdgeCasesRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> UnionEdgeCasesRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for UnionEdgeCasesRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     usize::from(!<SimpleUnion as ssz::Encode>::is_ssz_fixed_len())
@@ -1361,6 +1817,115 @@ pub mod tests {
                             .map(|inner| ssz_types::view::ToOwnedSsz::to_owned(&inner)),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut UnionEdgeCases,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.simple = {
+                        let view = self.simple()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    out.nested = {
+                        let view = self.nested()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    out.complex = {
+                        let view = self.complex()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    out.opt_simple = self
+                        .opt_simple()?
+                        .map(|inner| ssz_types::view::ToOwnedSsz::to_owned(&inner));
+                    out.opt_complex = self
+                        .opt_complex()?
+                        .map(|inner| ssz_types::view::ToOwnedSsz::to_owned(&inner));
+                    out.opt_union = self
+                        .opt_union()?
+                        .map(|inner| ssz_types::view::ToOwnedSsz::to_owned(&inner));
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> UnionEdgeCasesRef<'a> {
+                fn eq_owned(
+                    &self,
+                    other: &UnionEdgeCases,
+                ) -> Result<bool, ssz::DecodeError> {
+                    if !({
+                        let value: SimpleUnion = {
+                            let view = self.simple()?;
+                            ssz_types::view::ToOwnedSsz::to_owned(&view)
+                        };
+                        value == other.simple
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: NestedUnion = {
+                            let view = self.nested()?;
+                            ssz_types::view::ToOwnedSsz::to_owned(&view)
+                        };
+                        value == other.nested
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: ComplexUnion = {
+                            let view = self.complex()?;
+                            ssz_types::view::ToOwnedSsz::to_owned(&view)
+                        };
+                        value == other.complex
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: OptionalSimple = self
+                            .opt_simple()?
+                            .map(|inner| ssz_types::view::ToOwnedSsz::to_owned(&inner));
+                        value == other.opt_simple
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: OptionalComplex = self
+                            .opt_complex()?
+                            .map(|inner| ssz_types::view::ToOwnedSsz::to_owned(&inner));
+                        value == other.opt_complex
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: OptionalUnion = self
+                            .opt_union()?
+                            .map(|inner| ssz_types::view::ToOwnedSsz::to_owned(&inner));
+                        value == other.opt_union
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<UnionEdgeCases> for UnionEdgeCasesRef<'a> {
+                fn eq(&self, other: &UnionEdgeCases) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<UnionEdgeCasesRef<'a>> for UnionEdgeCases {
+                fn eq(&self, other: &UnionEdgeCasesRef<'a>) -> bool {
+                    other == self
+                }
             }
             #[derive(
                 std::clone::Clone,
@@ -1412,6 +1977,32 @@ pub mod tests {
                         .expect("tree hash derive should not have a remaining buffer")
                 }
             }
+            impl tree_hash::TreeHashLeaves for AllUnions {
+                fn tree_hash_leaf_count() -> usize {
+                    3usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.union1)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.union2)
+                        }
+                        2usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.union3)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 3usize
+                            )
+                        }
+                    }
+                }
+            }
             /// Zero-copy view over [`AllUnions`].
             ///
             /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
@@ -1450,6 +2041,7 @@ pub mod tests {
                         0usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn union2(&self) -> Result<NestedUnionRef<'a>, ssz::DecodeError> {
                     let bytes = ssz::layout::read_field_bytes(
@@ -1471,6 +2063,7 @@ pub mod tests {
                         1usize,
                     )?;
                     ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
                 }
                 pub fn union3(&self) -> Result<Option<u8>, ssz::DecodeError> {
                     let bytes = ssz::layout::read_field_bytes(
@@ -1491,19 +2084,22 @@ pub mod tests {
                         ],
                         2usize,
                     )?;
-                    if bytes.is_empty() {
-                        return Err(ssz::DecodeError::InvalidByteLength {
-                            len: 0,
-                            expected: 1,
-                        });
-                    }
+                    ssz::view::reject_empty(bytes, 1)?;
                     let selector = bytes[0];
                     match selector {
-                        0 => Ok(None),
+                        0 => ssz::check_union_body_len(&bytes[1..], 0).map(|()| None),
                         1 => {
+                            if <u8 as ssz::Encode>::is_ssz_fixed_len() {
+                                ssz::check_union_body_len(
+                                    &bytes[1..],
+                                    <u8 as ssz::Encode>::ssz_fixed_len(),
+                                )?;
+                            }
+                            let body = &bytes[1..];
                             let inner = <u8 as ssz::view::DecodeView>::from_ssz_bytes(
-                                &bytes[1..],
-                            )?;
+                                    body,
+                                )
+                                .map_err(|e| e.bounded(body.len()))?;
                             Ok(Some(inner))
                         }
                         _ => {
@@ -1516,6 +2112,23 @@ pub mod tests {
                     }
                 }
             }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> AllUnionsRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    let value = self.union1()?;
+                    value.validate_deep()?;
+                    let value = self.union2()?;
+                    value.validate_deep()?;
+                    self.union3()?;
+                    Ok(())
+                }
+            }
             impl<'a> tree_hash::TreeHash for AllUnionsRef<'a> {
                 fn tree_hash_type() -> tree_hash::TreeHashType {
                     tree_hash::TreeHashType::StableContainer
@@ -1575,6 +2188,19 @@ pub mod tests {
                     Ok(Self { bytes })
                 }
             }
+            impl<'a> TryFrom<&'a [u8]> for AllUnionsRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> AllUnionsRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
             impl<'a> ssz::view::SszTypeInfo for AllUnionsRef<'a> {
                 fn is_ssz_fixed_len() -> bool {
                     usize::from(!<SimpleUnion as ssz::Encode>::is_ssz_fixed_len())
@@ -1625,6 +2251,77 @@ pub mod tests {
                             .map(|inner| ssz_types::view::ToOwnedSsz::to_owned(&inner)),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut AllUnions,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.union1 = {
+                        let view = self.union1()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    out.union2 = {
+                        let view = self.union2()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    out.union3 = self
+                        .union3()?
+                        .map(|inner| ssz_types::view::ToOwnedSsz::to_owned(&inner));
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> AllUnionsRef<'a> {
+                fn eq_owned(&self, other: &AllUnions) -> Result<bool, ssz::DecodeError> {
+                    if !({
+                        let value: SimpleUnion = {
+                            let view = self.union1()?;
+                            ssz_types::view::ToOwnedSsz::to_owned(&view)
+                        };
+                        value == other.union1
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: NestedUnion = {
+                            let view = self.union2()?;
+                            ssz_types::view::ToOwnedSsz::to_owned(&view)
+                        };
+                        value == other.union2
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: OptionalSimple = self
+                            .union3()?
+                            .map(|inner| ssz_types::view::ToOwnedSsz::to_owned(&inner));
+                        value == other.union3
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<AllUnions> for AllUnionsRef<'a> {
+                fn eq(&self, other: &AllUnions) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<AllUnionsRef<'a>> for AllUnions {
+                fn eq(&self, other: &AllUnionsRef<'a>) -> bool {
+                    other == self
+                }
             }
         }
     }
@@ -11,6 +11,12 @@ pub mod tests {
             use tree_hash::TreeHashDigest;
             use tree_hash_derive::TreeHash;
             use ssz::view::*;
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                23, 85, 176, 54, 95, 207, 90, 58, 25, 164, 210, 10, 95, 112, 185, 114,
+                211, 49, 165, 53, 161, 179, 166, 61, 217, 207, 180, 113, 208, 149, 87, 92,
+            ];
             /// Union with both empty and data variants
             #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
             #[ssz(enum_behaviour = "union")]
@@ -283,6 +289,22 @@ pub mod tests {
                         value: self.value().expect("valid view"),
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut DataVariant,
+                ) -> Result<(), ssz::DecodeError> {
+                    out.value = self.value()?;
+                    Ok(())
+                }
             }
             /// Container using the union
             #[derive(
@@ -427,6 +449,25 @@ pub mod tests {
                         },
                     }
                 }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut TestContainer,
+                ) -> Result<(), ssz::DecodeError> {
+                    out.state = {
+                        let view = self.state()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    Ok(())
+                }
             }
         }
     }
@@ -0,0 +1,1103 @@
+pub mod tests {
+    #![allow(unused_imports, reason = "generated code using ssz-gen")]
+    pub mod input {
+        #![allow(unused_imports, reason = "generated code using ssz-gen")]
+        pub mod test_validate_and_to_owned {
+            #![allow(unused_imports, reason = "generated code using ssz-gen")]
+            use ssz_types::*;
+            use ssz_types::view::{FixedVectorRef, VariableListRef};
+            use ssz_primitives::{U128, U256};
+            use ssz_derive::{Encode, Decode};
+            use tree_hash::TreeHashDigest;
+            use tree_hash_derive::TreeHash;
+            use ssz::view::*;
+            /// Hash of the normalized schema this module was generated from.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            pub const SCHEMA_HASH: [u8; 32] = [
+                76, 118, 72, 245, 118, 221, 149, 215, 245, 254, 149, 13, 79, 5, 29, 13,
+                23, 7, 85, 226, 159, 30, 23, 83, 137, 134, 41, 166, 156, 243, 100, 58,
+            ];
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                ssz_derive::Encode,
+                ssz_derive::Decode
+            )]
+            #[ssz(struct_behaviour = "container")]
+            pub struct Inner {
+                pub flag: bool,
+                pub values: VariableList<u16, 4usize>,
+            }
+            impl Default for Inner {
+                fn default() -> Self {
+                    Self {
+                        flag: Default::default(),
+                        values: Default::default(),
+                    }
+                }
+            }
+            impl tree_hash::TreeHash for Inner {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::Container
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(2usize);
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.flag)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.values)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .finish()
+                        .expect("tree hash derive should not have a remaining buffer")
+                }
+            }
+            impl tree_hash::TreeHashLeaves for Inner {
+                fn tree_hash_leaf_count() -> usize {
+                    2usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.flag)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.values)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 2usize
+                            )
+                        }
+                    }
+                }
+            }
+            /// Zero-copy view over [`Inner`].
+            ///
+            /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
+            /// via lazy getter methods. Use `.to_owned()` to convert to the owned type when
+            /// needed.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                std::marker::Copy
+            )]
+            pub struct InnerRef<'a> {
+                bytes: &'a [u8],
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> InnerRef<'a> {
+                pub fn flag(&self) -> Result<bool, ssz::DecodeError> {
+                    let bytes = ssz::layout::read_field_bytes(
+                        self.bytes,
+                        &[
+                            (
+                                <bool as ssz::Encode>::is_ssz_fixed_len(),
+                                <bool as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <VariableList<
+                                    u16,
+                                    4usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <VariableList<u16, 4usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                        0usize,
+                    )?;
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+                pub fn values(
+                    &self,
+                ) -> Result<ListRef<'a, u16, 4usize>, ssz::DecodeError> {
+                    let bytes = ssz::layout::read_field_bytes(
+                        self.bytes,
+                        &[
+                            (
+                                <bool as ssz::Encode>::is_ssz_fixed_len(),
+                                <bool as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <VariableList<
+                                    u16,
+                                    4usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <VariableList<u16, 4usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                        1usize,
+                    )?;
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> InnerRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.flag()?;
+                    let value = self.values()?;
+                    for item in value.iter() {
+                        item?;
+                    }
+                    Ok(())
+                }
+            }
+            impl<'a> tree_hash::TreeHash for InnerRef<'a> {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::StableContainer
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(2usize);
+                    {
+                        let flag = self.flag().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&flag);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    {
+                        let values = self.values().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&values);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    hasher.finish().expect("finish hasher")
+                }
+            }
+            impl<'a> ssz::view::DecodeView<'a> for InnerRef<'a> {
+                fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
+                    ssz::layout::validate_container(
+                        bytes,
+                        &[
+                            (
+                                <bool as ssz::Encode>::is_ssz_fixed_len(),
+                                <bool as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <VariableList<
+                                    u16,
+                                    4usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <VariableList<u16, 4usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                    )?;
+                    Ok(Self { bytes })
+                }
+            }
+            impl<'a> TryFrom<&'a [u8]> for InnerRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> InnerRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
+            impl<'a> ssz::view::SszTypeInfo for InnerRef<'a> {
+                fn is_ssz_fixed_len() -> bool {
+                    usize::from(!<bool as ssz::Encode>::is_ssz_fixed_len())
+                        + usize::from(
+                            !<VariableList<
+                                u16,
+                                4usize,
+                            > as ssz::Encode>::is_ssz_fixed_len(),
+                        ) == 0
+                }
+                fn ssz_fixed_len() -> usize {
+                    if <Self as ssz::view::SszTypeInfo>::is_ssz_fixed_len() {
+                        <bool as ssz::Encode>::ssz_fixed_len()
+                            + <VariableList<u16, 4usize> as ssz::Encode>::ssz_fixed_len()
+                    } else {
+                        0
+                    }
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ssz_types::view::ToOwnedSsz<Inner> for InnerRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                fn to_owned(&self) -> Inner {
+                    <InnerRef<'a>>::to_owned(self)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> InnerRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned(&self) -> Inner {
+                    Inner {
+                        flag: self.flag().expect("valid view"),
+                        values: {
+                            let view = self.values().expect("valid view");
+                            let items: Result<Vec<_>, _> = view
+                                .iter()
+                                .map(|item_result| {
+                                    item_result
+                                        .map(|item| ssz_types::view::ToOwnedSsz::to_owned(&item))
+                                })
+                                .collect();
+                            let items = items.expect("valid view");
+                            ssz_types::VariableList::new(items).expect("valid view")
+                        },
+                    }
+                }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Inner,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.flag = self.flag()?;
+                    let view = self.values()?;
+                    out.values.clear();
+                    for item in view.iter() {
+                        out.values
+                            .push(ssz_types::view::ToOwnedSsz::to_owned(&item?))
+                            .expect("valid view");
+                    }
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> InnerRef<'a> {
+                /// Converts this view to an owned value, reading every field and
+                /// collecting the ones that fail to decode.
+                ///
+                /// Each error is paired with the path of its field, such as
+                /// `inner.x` for a field of a nested container, and the failed
+                /// field takes its default value in the returned owned value.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn validate_and_to_owned(
+                    &self,
+                ) -> (Inner, Vec<(String, ssz::DecodeError)>) {
+                    let mut errors = Vec::new();
+                    let value = Inner {
+                        flag: match ssz::layout::read_field_bytes(
+                                self.bytes,
+                                &[
+                                    (
+                                        <bool as ssz::Encode>::is_ssz_fixed_len(),
+                                        <bool as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <VariableList<
+                                            u16,
+                                            4usize,
+                                        > as ssz::Encode>::is_ssz_fixed_len(),
+                                        <VariableList<u16, 4usize> as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                ],
+                                0usize,
+                            )
+                            .and_then(|bytes| {
+                                <bool as ssz::Decode>::from_ssz_bytes(bytes)
+                                    .map_err(|e| e.bounded(bytes.len()))
+                            })
+                        {
+                            Ok(value) => value,
+                            Err(error) => {
+                                errors.push(("flag".to_string(), error));
+                                Default::default()
+                            }
+                        },
+                        values: match ssz::layout::read_field_bytes(
+                                self.bytes,
+                                &[
+                                    (
+                                        <bool as ssz::Encode>::is_ssz_fixed_len(),
+                                        <bool as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <VariableList<
+                                            u16,
+                                            4usize,
+                                        > as ssz::Encode>::is_ssz_fixed_len(),
+                                        <VariableList<u16, 4usize> as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                ],
+                                1usize,
+                            )
+                            .and_then(|bytes| {
+                                <VariableList<
+                                    u16,
+                                    4usize,
+                                > as ssz::Decode>::from_ssz_bytes(bytes)
+                                    .map_err(|e| e.bounded(bytes.len()))
+                            })
+                        {
+                            Ok(value) => value,
+                            Err(error) => {
+                                errors.push(("values".to_string(), error));
+                                Default::default()
+                            }
+                        },
+                    };
+                    (value, errors)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> InnerRef<'a> {
+                fn eq_owned(&self, other: &Inner) -> Result<bool, ssz::DecodeError> {
+                    if !({
+                        let value: bool = self.flag()?;
+                        value == other.flag
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: VariableList<u16, 4usize> = {
+                            let view = self.values()?;
+                            let items: Result<Vec<_>, _> = view
+                                .iter()
+                                .map(|item_result| {
+                                    item_result
+                                        .map(|item| ssz_types::view::ToOwnedSsz::to_owned(&item))
+                                })
+                                .collect();
+                            let items = items.expect("valid view");
+                            ssz_types::VariableList::new(items).expect("valid view")
+                        };
+                        value == other.values
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<Inner> for InnerRef<'a> {
+                fn eq(&self, other: &Inner) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<InnerRef<'a>> for Inner {
+                fn eq(&self, other: &InnerRef<'a>) -> bool {
+                    other == self
+                }
+            }
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                ssz_derive::Encode,
+                ssz_derive::Decode
+            )]
+            #[ssz(struct_behaviour = "container")]
+            pub struct Outer {
+                pub id: u32,
+                pub ok: bool,
+                pub inner: Inner,
+                pub tags: VariableList<bool, 8usize>,
+            }
+            impl Default for Outer {
+                fn default() -> Self {
+                    Self {
+                        id: Default::default(),
+                        ok: Default::default(),
+                        inner: Default::default(),
+                        tags: Default::default(),
+                    }
+                }
+            }
+            impl tree_hash::TreeHash for Outer {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::Container
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(4usize);
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.id)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.ok)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.inner)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .write(
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.tags)
+                                .as_ref(),
+                        )
+                        .expect("tree hash derive should not apply too many leaves");
+                    hasher
+                        .finish()
+                        .expect("tree hash derive should not have a remaining buffer")
+                }
+            }
+            impl tree_hash::TreeHashLeaves for Outer {
+                fn tree_hash_leaf_count() -> usize {
+                    4usize
+                }
+                fn tree_hash_leaf_root<H: tree_hash::TreeHashDigest>(
+                    &self,
+                    index: usize,
+                ) -> H::Output {
+                    match index {
+                        0usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.id)
+                        }
+                        1usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.ok)
+                        }
+                        2usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.inner)
+                        }
+                        3usize => {
+                            <_ as tree_hash::TreeHash>::tree_hash_root::<H>(&self.tags)
+                        }
+                        _ => {
+                            panic!(
+                                "leaf index {index} out of range for {} leaves", 4usize
+                            )
+                        }
+                    }
+                }
+            }
+            /// Zero-copy view over [`Outer`].
+            ///
+            /// This type wraps SSZ-encoded bytes without allocating. Fields are accessed
+            /// via lazy getter methods. Use `.to_owned()` to convert to the owned type when
+            /// needed.
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            #[derive(
+                std::clone::Clone,
+                std::fmt::Debug,
+                std::cmp::PartialEq,
+                std::cmp::Eq,
+                std::marker::Copy
+            )]
+            pub struct OuterRef<'a> {
+                bytes: &'a [u8],
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> OuterRef<'a> {
+                pub fn id(&self) -> Result<u32, ssz::DecodeError> {
+                    let bytes = ssz::layout::read_field_bytes(
+                        self.bytes,
+                        &[
+                            (
+                                <u32 as ssz::Encode>::is_ssz_fixed_len(),
+                                <u32 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <bool as ssz::Encode>::is_ssz_fixed_len(),
+                                <bool as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <Inner as ssz::Encode>::is_ssz_fixed_len(),
+                                <Inner as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <VariableList<
+                                    bool,
+                                    8usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <VariableList<bool, 8usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                        0usize,
+                    )?;
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+                pub fn ok(&self) -> Result<bool, ssz::DecodeError> {
+                    let bytes = ssz::layout::read_field_bytes(
+                        self.bytes,
+                        &[
+                            (
+                                <u32 as ssz::Encode>::is_ssz_fixed_len(),
+                                <u32 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <bool as ssz::Encode>::is_ssz_fixed_len(),
+                                <bool as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <Inner as ssz::Encode>::is_ssz_fixed_len(),
+                                <Inner as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <VariableList<
+                                    bool,
+                                    8usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <VariableList<bool, 8usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                        1usize,
+                    )?;
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+                pub fn inner(&self) -> Result<InnerRef<'a>, ssz::DecodeError> {
+                    let bytes = ssz::layout::read_field_bytes(
+                        self.bytes,
+                        &[
+                            (
+                                <u32 as ssz::Encode>::is_ssz_fixed_len(),
+                                <u32 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <bool as ssz::Encode>::is_ssz_fixed_len(),
+                                <bool as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <Inner as ssz::Encode>::is_ssz_fixed_len(),
+                                <Inner as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <VariableList<
+                                    bool,
+                                    8usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <VariableList<bool, 8usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                        2usize,
+                    )?;
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+                pub fn tags(
+                    &self,
+                ) -> Result<ListRef<'a, bool, 8usize>, ssz::DecodeError> {
+                    let bytes = ssz::layout::read_field_bytes(
+                        self.bytes,
+                        &[
+                            (
+                                <u32 as ssz::Encode>::is_ssz_fixed_len(),
+                                <u32 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <bool as ssz::Encode>::is_ssz_fixed_len(),
+                                <bool as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <Inner as ssz::Encode>::is_ssz_fixed_len(),
+                                <Inner as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <VariableList<
+                                    bool,
+                                    8usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <VariableList<bool, 8usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                        3usize,
+                    )?;
+                    ssz::view::DecodeView::from_ssz_bytes(bytes)
+                        .map_err(|e| e.bounded(bytes.len()))
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> OuterRef<'a> {
+                /// Validates every field of this view, recursing into nested
+                /// views and walking every list and vector, and returns the
+                /// first error.
+                ///
+                /// `from_ssz_bytes` only checks the view's own layout, so this
+                /// rejects bytes that would otherwise fail on a later access.
+                pub fn validate_deep(&self) -> Result<(), ssz::DecodeError> {
+                    self.id()?;
+                    self.ok()?;
+                    let value = self.inner()?;
+                    value.validate_deep()?;
+                    let value = self.tags()?;
+                    for item in value.iter() {
+                        item?;
+                    }
+                    Ok(())
+                }
+            }
+            impl<'a> tree_hash::TreeHash for OuterRef<'a> {
+                fn tree_hash_type() -> tree_hash::TreeHashType {
+                    tree_hash::TreeHashType::StableContainer
+                }
+                fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_packing_factor() -> usize {
+                    unreachable!("Container should never be packed")
+                }
+                fn tree_hash_root<H: tree_hash::TreeHashDigest>(&self) -> H::Output {
+                    use tree_hash::TreeHash;
+                    let mut hasher = tree_hash::MerkleHasher::<H>::with_leaves(4usize);
+                    {
+                        let id = self.id().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&id);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    {
+                        let ok = self.ok().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&ok);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    {
+                        let inner = self.inner().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&inner);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    {
+                        let tags = self.tags().expect("valid view");
+                        let root: <H as tree_hash::TreeHashDigest>::Output = <_ as tree_hash::TreeHash>::tree_hash_root::<
+                            H,
+                        >(&tags);
+                        hasher.write(root.as_ref()).expect("write field");
+                    }
+                    hasher.finish().expect("finish hasher")
+                }
+            }
+            impl<'a> ssz::view::DecodeView<'a> for OuterRef<'a> {
+                fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, ssz::DecodeError> {
+                    ssz::layout::validate_container(
+                        bytes,
+                        &[
+                            (
+                                <u32 as ssz::Encode>::is_ssz_fixed_len(),
+                                <u32 as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <bool as ssz::Encode>::is_ssz_fixed_len(),
+                                <bool as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <Inner as ssz::Encode>::is_ssz_fixed_len(),
+                                <Inner as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                            (
+                                <VariableList<
+                                    bool,
+                                    8usize,
+                                > as ssz::Encode>::is_ssz_fixed_len(),
+                                <VariableList<bool, 8usize> as ssz::Encode>::ssz_fixed_len(),
+                            ),
+                        ],
+                    )?;
+                    Ok(Self { bytes })
+                }
+            }
+            impl<'a> TryFrom<&'a [u8]> for OuterRef<'a> {
+                type Error = ssz::DecodeError;
+                fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                    <Self as ssz::view::DecodeView<'a>>::from_ssz_bytes(bytes)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> OuterRef<'a> {
+                /// Returns the SSZ bytes this view was decoded from.
+                pub const fn as_bytes(&self) -> &'a [u8] {
+                    self.bytes
+                }
+            }
+            impl<'a> ssz::view::SszTypeInfo for OuterRef<'a> {
+                fn is_ssz_fixed_len() -> bool {
+                    usize::from(!<u32 as ssz::Encode>::is_ssz_fixed_len())
+                        + usize::from(!<bool as ssz::Encode>::is_ssz_fixed_len())
+                        + usize::from(!<Inner as ssz::Encode>::is_ssz_fixed_len())
+                        + usize::from(
+                            !<VariableList<
+                                bool,
+                                8usize,
+                            > as ssz::Encode>::is_ssz_fixed_len(),
+                        ) == 0
+                }
+                fn ssz_fixed_len() -> usize {
+                    if <Self as ssz::view::SszTypeInfo>::is_ssz_fixed_len() {
+                        <u32 as ssz::Encode>::ssz_fixed_len()
+                            + <bool as ssz::Encode>::ssz_fixed_len()
+                            + <Inner as ssz::Encode>::ssz_fixed_len()
+                            + <VariableList<
+                                bool,
+                                8usize,
+                            > as ssz::Encode>::ssz_fixed_len()
+                    } else {
+                        0
+                    }
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> ssz_types::view::ToOwnedSsz<Outer> for OuterRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                fn to_owned(&self) -> Outer {
+                    <OuterRef<'a>>::to_owned(self)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> OuterRef<'a> {
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned(&self) -> Outer {
+                    Outer {
+                        id: self.id().expect("valid view"),
+                        ok: self.ok().expect("valid view"),
+                        inner: {
+                            let view = self.inner().expect("valid view");
+                            ssz_types::view::ToOwnedSsz::to_owned(&view)
+                        },
+                        tags: {
+                            let view = self.tags().expect("valid view");
+                            let items: Result<Vec<_>, _> = view
+                                .iter()
+                                .map(|item_result| {
+                                    item_result
+                                        .map(|item| ssz_types::view::ToOwnedSsz::to_owned(&item))
+                                })
+                                .collect();
+                            let items = items.expect("valid view");
+                            ssz_types::VariableList::new(items).expect("valid view")
+                        },
+                    }
+                }
+                /// Converts this view into `out`, reusing the allocations of its
+                /// list and vector fields.
+                ///
+                /// Produces the same value as `to_owned`, but avoids reallocating
+                /// when converting many views in a loop. The view is validated
+                /// with `validate_deep` before anything is written, so on error
+                /// `out` is left unchanged.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn to_owned_into(
+                    &self,
+                    out: &mut Outer,
+                ) -> Result<(), ssz::DecodeError> {
+                    self.validate_deep()?;
+                    out.id = self.id()?;
+                    out.ok = self.ok()?;
+                    out.inner = {
+                        let view = self.inner()?;
+                        ssz_types::view::ToOwnedSsz::to_owned(&view)
+                    };
+                    let view = self.tags()?;
+                    out.tags.clear();
+                    for item in view.iter() {
+                        out.tags
+                            .push(ssz_types::view::ToOwnedSsz::to_owned(&item?))
+                            .expect("valid view");
+                    }
+                    Ok(())
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> OuterRef<'a> {
+                /// Converts this view to an owned value, reading every field and
+                /// collecting the ones that fail to decode.
+                ///
+                /// Each error is paired with the path of its field, such as
+                /// `inner.x` for a field of a nested container, and the failed
+                /// field takes its default value in the returned owned value.
+                #[allow(
+                    clippy::wrong_self_convention,
+                    reason = "API convention for view types"
+                )]
+                pub fn validate_and_to_owned(
+                    &self,
+                ) -> (Outer, Vec<(String, ssz::DecodeError)>) {
+                    let mut errors = Vec::new();
+                    let value = Outer {
+                        id: match ssz::layout::read_field_bytes(
+                                self.bytes,
+                                &[
+                                    (
+                                        <u32 as ssz::Encode>::is_ssz_fixed_len(),
+                                        <u32 as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <bool as ssz::Encode>::is_ssz_fixed_len(),
+                                        <bool as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <Inner as ssz::Encode>::is_ssz_fixed_len(),
+                                        <Inner as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <VariableList<
+                                            bool,
+                                            8usize,
+                                        > as ssz::Encode>::is_ssz_fixed_len(),
+                                        <VariableList<bool, 8usize> as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                ],
+                                0usize,
+                            )
+                            .and_then(|bytes| {
+                                <u32 as ssz::Decode>::from_ssz_bytes(bytes)
+                                    .map_err(|e| e.bounded(bytes.len()))
+                            })
+                        {
+                            Ok(value) => value,
+                            Err(error) => {
+                                errors.push(("id".to_string(), error));
+                                Default::default()
+                            }
+                        },
+                        ok: match ssz::layout::read_field_bytes(
+                                self.bytes,
+                                &[
+                                    (
+                                        <u32 as ssz::Encode>::is_ssz_fixed_len(),
+                                        <u32 as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <bool as ssz::Encode>::is_ssz_fixed_len(),
+                                        <bool as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <Inner as ssz::Encode>::is_ssz_fixed_len(),
+                                        <Inner as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <VariableList<
+                                            bool,
+                                            8usize,
+                                        > as ssz::Encode>::is_ssz_fixed_len(),
+                                        <VariableList<bool, 8usize> as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                ],
+                                1usize,
+                            )
+                            .and_then(|bytes| {
+                                <bool as ssz::Decode>::from_ssz_bytes(bytes)
+                                    .map_err(|e| e.bounded(bytes.len()))
+                            })
+                        {
+                            Ok(value) => value,
+                            Err(error) => {
+                                errors.push(("ok".to_string(), error));
+                                Default::default()
+                            }
+                        },
+                        inner: match ssz::layout::read_field_bytes(
+                                self.bytes,
+                                &[
+                                    (
+                                        <u32 as ssz::Encode>::is_ssz_fixed_len(),
+                                        <u32 as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <bool as ssz::Encode>::is_ssz_fixed_len(),
+                                        <bool as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <Inner as ssz::Encode>::is_ssz_fixed_len(),
+                                        <Inner as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <VariableList<
+                                            bool,
+                                            8usize,
+                                        > as ssz::Encode>::is_ssz_fixed_len(),
+                                        <VariableList<bool, 8usize> as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                ],
+                                2usize,
+                            )
+                            .and_then(|bytes| {
+                                <InnerRef<
+                                    'a,
+                                > as ssz::view::DecodeView>::from_ssz_bytes(bytes)
+                                    .map_err(|e| e.bounded(bytes.len()))
+                            })
+                        {
+                            Ok(view) => {
+                                let (value, nested) = view.validate_and_to_owned();
+                                errors
+                                    .extend(
+                                        nested
+                                            .into_iter()
+                                            .map(|(path, error)| (
+                                                format!("{}.{}", "inner", path),
+                                                error,
+                                            )),
+                                    );
+                                value
+                            }
+                            Err(error) => {
+                                errors.push(("inner".to_string(), error));
+                                Default::default()
+                            }
+                        },
+                        tags: match ssz::layout::read_field_bytes(
+                                self.bytes,
+                                &[
+                                    (
+                                        <u32 as ssz::Encode>::is_ssz_fixed_len(),
+                                        <u32 as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <bool as ssz::Encode>::is_ssz_fixed_len(),
+                                        <bool as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <Inner as ssz::Encode>::is_ssz_fixed_len(),
+                                        <Inner as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                    (
+                                        <VariableList<
+                                            bool,
+                                            8usize,
+                                        > as ssz::Encode>::is_ssz_fixed_len(),
+                                        <VariableList<bool, 8usize> as ssz::Encode>::ssz_fixed_len(),
+                                    ),
+                                ],
+                                3usize,
+                            )
+                            .and_then(|bytes| {
+                                <VariableList<
+                                    bool,
+                                    8usize,
+                                > as ssz::Decode>::from_ssz_bytes(bytes)
+                                    .map_err(|e| e.bounded(bytes.len()))
+                            })
+                        {
+                            Ok(value) => value,
+                            Err(error) => {
+                                errors.push(("tags".to_string(), error));
+                                Default::default()
+                            }
+                        },
+                    };
+                    (value, errors)
+                }
+            }
+            #[allow(dead_code, reason = "generated code using ssz-gen")]
+            impl<'a> OuterRef<'a> {
+                fn eq_owned(&self, other: &Outer) -> Result<bool, ssz::DecodeError> {
+                    if !({
+                        let value: u32 = self.id()?;
+                        value == other.id
+                    }) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: bool = self.ok()?;
+                        value == other.ok
+                    }) {
+                        return Ok(false);
+                    }
+                    if !(self.inner()? == other.inner) {
+                        return Ok(false);
+                    }
+                    if !({
+                        let value: VariableList<bool, 8usize> = {
+                            let view = self.tags()?;
+                            let items: Result<Vec<_>, _> = view
+                                .iter()
+                                .map(|item_result| {
+                                    item_result
+                                        .map(|item| ssz_types::view::ToOwnedSsz::to_owned(&item))
+                                })
+                                .collect();
+                            let items = items.expect("valid view");
+                            ssz_types::VariableList::new(items).expect("valid view")
+                        };
+                        value == other.tags
+                    }) {
+                        return Ok(false);
+                    }
+                    Ok(true)
+                }
+            }
+            impl<'a> PartialEq<Outer> for OuterRef<'a> {
+                fn eq(&self, other: &Outer) -> bool {
+                    self.eq_owned(other).unwrap_or(false)
+                }
+            }
+            impl<'a> PartialEq<OuterRef<'a>> for Outer {
+                fn eq(&self, other: &OuterRef<'a>) -> bool {
+                    other == self
+                }
+            }
+        }
+    }
+}
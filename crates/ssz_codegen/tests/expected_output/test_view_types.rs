@@ -6,6 +6,12 @@ use ssz_derive::{Encode, Decode};
 use tree_hash::TreeHashDigest;
 use tree_hash_derive::TreeHash;
 use ssz::view::*;
+/// Hash of the normalized schema this module was generated from.
+#[allow(dead_code, reason = "generated code using ssz-gen")]
+pub const SCHEMA_HASH: [u8; 32] = [
+    160, 13, 139, 239, 15, 137, 112, 72, 191, 11, 37, 137, 75, 190, 136, 105, 51, 44,
+    176, 207, 230, 214, 214, 39, 237, 46, 100, 57, 94, 19, 171, 143,
+];
 #[derive(
     std::clone::Clone,
     std::fmt::Debug,
@@ -171,6 +177,17 @@ impl<'a> ExportEntryRef<'a> {
             value: self.value().expect("valid view"),
         }
     }
+    /// Converts this view into `out`, reusing the allocations of its
+    /// list and vector fields.
+    ///
+    /// Produces the same value as `to_owned`, but avoids reallocating
+    /// when converting many views in a loop.
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    pub fn to_owned_into(&self, out: &mut ExportEntry) -> Result<(), ssz::DecodeError> {
+        out.key = self.key()?;
+        out.value = self.value()?;
+        Ok(())
+    }
 }
 #[derive(
     std::clone::Clone,
@@ -419,4 +436,24 @@ impl<'a> ViewTypeTestRef<'a> {
             hash: ssz_types::FixedBytes(self.hash().expect("valid view").to_owned()),
         }
     }
+    /// Converts this view into `out`, reusing the allocations of its
+    /// list and vector fields.
+    ///
+    /// Produces the same value as `to_owned`, but avoids reallocating
+    /// when converting many views in a loop.
+    #[allow(clippy::wrong_self_convention, reason = "API convention for view types")]
+    pub fn to_owned_into(&self, out: &mut ViewTypeTest) -> Result<(), ssz::DecodeError> {
+        let view = self.payload()?;
+        out.payload.clear();
+        out.payload.try_extend_from_slice(view.as_bytes()).expect("valid view");
+        let view = self.entries()?;
+        out.entries.clear();
+        for item in view.iter() {
+            out.entries
+                .push(ssz_types::view::ToOwnedSsz::to_owned(&item?))
+                .expect("valid view");
+        }
+        out.hash = ssz_types::FixedBytes(self.hash()?.to_owned());
+        Ok(())
+    }
 }
@@ -0,0 +1,60 @@
+//! Containers generated with `DeriveConfig::with_field_info(true)` list their
+//! field names and per-field SSZ layout.
+
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs)]
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+include!("expected_output/test_field_info.rs");
+
+use ssz_types::FieldInfo;
+use tests::input::test_field_info::{Alpha, Beta, Gamma};
+use tree_hash::TreeHashType;
+
+#[test]
+fn field_names_follow_declaration_order() {
+    assert_eq!(Alpha::FIELD_NAMES, ["a", "b", "c"]);
+    assert_eq!(Beta::FIELD_NAMES, ["alpha", "flags"]);
+    assert_eq!(Gamma::FIELD_NAMES, ["g", "h"]);
+}
+
+#[test]
+fn fields_report_ssz_layout() {
+    assert_eq!(
+        Alpha::fields(),
+        [
+            FieldInfo {
+                name: "a",
+                is_fixed_size: true,
+                tree_hash_type: TreeHashType::Basic,
+            },
+            FieldInfo {
+                name: "b",
+                is_fixed_size: false,
+                tree_hash_type: TreeHashType::List,
+            },
+            FieldInfo {
+                name: "c",
+                is_fixed_size: true,
+                tree_hash_type: TreeHashType::Vector,
+            },
+        ]
+    );
+
+    let [alpha, flags] = Beta::fields();
+    assert!(!alpha.is_fixed_size);
+    assert_eq!(alpha.tree_hash_type, TreeHashType::Container);
+    assert!(flags.is_fixed_size);
+    assert_eq!(flags.tree_hash_type, TreeHashType::Vector);
+
+    let [g, h] = Gamma::fields();
+    assert!(g.is_fixed_size);
+    assert_eq!(g.tree_hash_type, TreeHashType::Basic);
+    assert_eq!(h.tree_hash_type, TreeHashType::Container);
+}
@@ -0,0 +1,82 @@
+//! All-fixed containers expose `FIELD_OFFSETS` and `FIELD_SIZES`, which must
+//! locate the same bytes the view getters read.
+
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs)]
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+include!("expected_output/test_field_offsets.rs");
+
+use ssz::{Decode, Encode};
+use ssz_types::{BitVector, FixedBytes, FixedVector};
+use tests::input::test_field_offsets::{Header, HeaderRef, Point};
+
+fn sample_header() -> Header {
+    let mut flags = BitVector::new();
+    flags.set(3, true).unwrap();
+    flags.set(11, true).unwrap();
+    Header {
+        version: 3,
+        slot: 0x0102_0304_0506_0708,
+        root: FixedBytes([0x5c; 32]),
+        flags,
+        origin: Point { x: 10, y: 20 },
+        history: FixedVector::new(vec![7, 8, 9]).unwrap(),
+        ok: true,
+    }
+}
+
+#[test]
+fn offsets_cover_the_encoding() {
+    let bytes = sample_header().as_ssz_bytes();
+    assert_eq!(Header::FIELD_OFFSETS.len(), Header::FIELD_SIZES.len());
+    assert_eq!(
+        Header::FIELD_OFFSETS.last().unwrap() + Header::FIELD_SIZES.last().unwrap(),
+        bytes.len()
+    );
+    assert_eq!(Point::FIELD_OFFSETS, [0, 4]);
+    assert_eq!(Point::FIELD_SIZES, [4, 4]);
+}
+
+#[test]
+fn offsets_match_getters() {
+    let bytes = sample_header().as_ssz_bytes();
+    let view = <HeaderRef<'_> as ssz::view::DecodeView>::from_ssz_bytes(&bytes).unwrap();
+    let field = |i: usize| &bytes[Header::FIELD_OFFSETS[i]..][..Header::FIELD_SIZES[i]];
+
+    assert_eq!(
+        <u8 as Decode>::from_ssz_bytes(field(0)).unwrap(),
+        view.version().unwrap()
+    );
+    assert_eq!(
+        <u64 as Decode>::from_ssz_bytes(field(1)).unwrap(),
+        view.slot().unwrap()
+    );
+    // Byte views borrow the input, so the getter's slice must start at the offset.
+    assert!(std::ptr::eq(
+        field(2).as_ptr(),
+        view.root().unwrap().as_bytes().as_ptr()
+    ));
+    assert_eq!(
+        BitVector::<12>::from_ssz_bytes(field(3)).unwrap(),
+        view.flags().unwrap().to_owned()
+    );
+    assert_eq!(
+        Point::from_ssz_bytes(field(4)).unwrap(),
+        view.origin().unwrap().to_owned()
+    );
+    assert_eq!(
+        FixedVector::<u16, 3>::from_ssz_bytes(field(5)).unwrap(),
+        view.history().unwrap().to_owned().unwrap()
+    );
+    assert_eq!(
+        <bool as Decode>::from_ssz_bytes(field(6)).unwrap(),
+        view.ok().unwrap()
+    );
+}
@@ -0,0 +1,54 @@
+//! Schemas pulling a class in with `from module import Name` compile, and the
+//! imported class's view is reachable under the imported name.
+
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+include!("expected_output/test_from_import.rs");
+
+use ssz::{Encode, view::DecodeView};
+use ssz_types::{Optional, VariableList};
+use tests::input::test_from_import::{
+    CONSTANT_VALUE, FromImported, FromImportedRef, StableContainerClass, StableContainerClassRef,
+};
+use tree_hash::TreeHash;
+
+fn sample() -> FromImported {
+    FromImported {
+        a: 3,
+        b: VariableList::new(vec![1, 2, 3]).unwrap(),
+        c: StableContainerClass {
+            a: Optional::Some(9),
+            b: Optional::Some(Some(4)),
+        },
+    }
+}
+
+#[test]
+fn imported_constant_bounds_list() {
+    assert_eq!(CONSTANT_VALUE, 5);
+    assert!(VariableList::<u8, 5>::new(vec![0; CONSTANT_VALUE as usize]).is_ok());
+}
+
+#[test]
+fn imported_class_view_round_trips() {
+    let owned = sample();
+    let bytes = owned.as_ssz_bytes();
+
+    let view = FromImportedRef::from_ssz_bytes(&bytes).unwrap();
+    let c: StableContainerClassRef<'_> = view.c().unwrap();
+    assert_eq!(c.a().unwrap(), Optional::Some(9));
+    assert_eq!(c.b().unwrap(), Optional::Some(Some(4)));
+    assert_eq!(view.to_owned(), owned);
+    assert_eq!(
+        view.tree_hash_root::<tree_hash::Sha256Hasher>(),
+        owned.tree_hash_root::<tree_hash::Sha256Hasher>()
+    );
+}
@@ -0,0 +1,46 @@
+//! With `hex_debug`, containers print byte fields as `0x`-prefixed hex in
+//! `Debug` and every other field as usual.
+
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+include!("expected_output/test_hex_debug.rs");
+
+use ssz_types::{FixedBytes, VariableList};
+use tests::input::test_hex_debug::{Alpha, Beta};
+
+fn alpha() -> Alpha {
+    let mut c = [0u8; 48];
+    c[0] = 0x0c;
+    c[1] = 0xff;
+    Alpha {
+        a: 7,
+        b: VariableList::new(vec![0xde, 0xad]).expect("within bound"),
+        c: FixedBytes(c),
+    }
+}
+
+#[test]
+fn byte_fields_debug_as_hex() {
+    let c = format!("0x0cff{}", "00".repeat(46));
+    assert_eq!(
+        format!("{:?}", alpha()),
+        format!("Alpha {{ a: 7, b: 0xdead, c: {c} }}")
+    );
+}
+
+#[test]
+fn containers_without_byte_fields_keep_derived_debug() {
+    let beta = Beta {
+        alpha: alpha(),
+        d: VariableList::new(vec![1, 2]).expect("within bound"),
+    };
+    let debug = format!("{beta:?}");
+    assert!(debug.starts_with("Beta { alpha: Alpha { a: 7, b: 0xdead, c: 0x0cff"));
+    assert!(debug.contains("d: VariableList"));
+}
@@ -0,0 +1,74 @@
+//! Classes marked `mix_in_aux` hash to `mix_in_aux(body_root, aux_root)`,
+//! where the body is every field but the one marked `aux`.
+
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+include!("expected_output/test_mix_in_aux.rs");
+
+use ssz::{Encode, view::DecodeView};
+use ssz_types::VariableList;
+use tests::input::test_mix_in_aux::{Envelope, EnvelopeRef, Header};
+use tree_hash::{MerkleHasher, Sha256Hasher, TreeHash, mix_in_aux_with_hasher};
+
+fn sample_envelope() -> Envelope {
+    Envelope {
+        payload: VariableList::new(b"hello".to_vec()).unwrap(),
+        nonce: 9,
+        header: Header {
+            slot: 12,
+            proposer: 3,
+        },
+    }
+}
+
+fn expected_root(envelope: &Envelope) -> tree_hash::Hash256 {
+    let mut hasher = MerkleHasher::<Sha256Hasher>::with_leaves(2);
+    hasher
+        .write(envelope.payload.tree_hash_root::<Sha256Hasher>().as_ref())
+        .unwrap();
+    hasher
+        .write(envelope.nonce.tree_hash_root::<Sha256Hasher>().as_ref())
+        .unwrap();
+    let body_root = hasher.finish().unwrap();
+    mix_in_aux_with_hasher::<Sha256Hasher>(
+        &body_root,
+        &envelope.header.tree_hash_root::<Sha256Hasher>(),
+    )
+}
+
+#[test]
+fn owned_root_mixes_in_aux() {
+    let envelope = sample_envelope();
+    assert_eq!(
+        envelope.tree_hash_root::<Sha256Hasher>(),
+        expected_root(&envelope)
+    );
+}
+
+#[test]
+fn view_root_matches_owned() {
+    let envelope = sample_envelope();
+    let bytes = envelope.as_ssz_bytes();
+    let view = EnvelopeRef::from_ssz_bytes(&bytes).unwrap();
+    assert_eq!(
+        view.tree_hash_root::<Sha256Hasher>(),
+        expected_root(&envelope)
+    );
+}
+
+#[test]
+fn aux_change_changes_root() {
+    let envelope = sample_envelope();
+    let mut other = envelope.clone();
+    other.header.slot += 1;
+    assert_ne!(
+        other.tree_hash_root::<Sha256Hasher>(),
+        envelope.tree_hash_root::<Sha256Hasher>()
+    );
+}
@@ -0,0 +1,48 @@
+//! Files written by `build_ssz_files_multi` compile when included as sibling
+//! modules, with cross-file references going through `super::`.
+
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+#[allow(unused_imports)]
+pub mod test_multi_import_base {
+    include!("expected_output/multi/test_multi_import_base.rs");
+}
+
+#[allow(unused_imports)]
+pub mod test_multi_import_a {
+    include!("expected_output/multi/test_multi_import_a.rs");
+}
+
+#[allow(unused_imports)]
+pub mod test_multi_import_b {
+    include!("expected_output/multi/test_multi_import_b.rs");
+}
+
+use ssz::{Decode, Encode, view::DecodeView};
+use test_multi_import_a::TypeA;
+use test_multi_import_b::{TypeB, TypeBRef};
+use test_multi_import_base::BaseType;
+
+#[test]
+fn cross_file_types_round_trip() {
+    let value = TypeB {
+        base: BaseType { value: 1 },
+        type_a: TypeA {
+            base: BaseType { value: 2 },
+            data: 3,
+        },
+        extra: 4,
+    };
+
+    let bytes = value.as_ssz_bytes();
+    assert_eq!(TypeB::from_ssz_bytes(&bytes).expect("decode"), value);
+
+    let view = TypeBRef::from_ssz_bytes(&bytes).expect("view");
+    assert_eq!(view.type_a().expect("type_a").data().expect("data"), 3);
+}
@@ -54,6 +54,29 @@ fn mixed_view_agrees_with_owned_encoding() {
     assert_eq!(view.to_owned(), owned);
 }
 
+#[test]
+fn view_compares_equal_to_owned() {
+    let owned = sample_mixed();
+    let mut bytes = owned.as_ssz_bytes();
+
+    let view = MixedOuterRef::from_ssz_bytes(&bytes).expect("view decode");
+    assert!(view == owned);
+    assert!(owned == view);
+    assert!(view.pair().expect("pair") == owned.pair);
+
+    // Byte 1 is the low byte of `count`, right after `inner.tag`.
+    bytes[1] ^= 0xff;
+    let view = MixedOuterRef::from_ssz_bytes(&bytes).expect("view decode");
+    assert!(view != owned);
+    assert!(owned != view);
+
+    // A change in the nested container's bytes is caught through its view.
+    let mut bytes = owned.as_ssz_bytes();
+    bytes[5] ^= 0x01;
+    let view = MixedOuterRef::from_ssz_bytes(&bytes).expect("view decode");
+    assert!(view != owned);
+}
+
 #[test]
 fn fully_fixed_view_agrees_with_owned_encoding() {
     let owned = FixedOuter {
@@ -0,0 +1,56 @@
+//! `newtype` classes implement `AsRef`, `Borrow` and `Deref` to their single
+//! field, so they can be passed wherever the inner type is expected.
+
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+include!("expected_output/test_newtype.rs");
+
+use std::borrow::Borrow;
+
+use ssz_types::{FixedBytes, VariableList};
+use tests::input::test_newtype::{BlockRoot, Slots};
+
+fn first_byte(root: &FixedBytes<32>) -> u8 {
+    root.0[0]
+}
+
+fn total(values: impl AsRef<VariableList<u64, 8>>) -> u64 {
+    values.as_ref().iter().sum()
+}
+
+fn block_root() -> BlockRoot {
+    BlockRoot {
+        root: FixedBytes([7; 32]),
+    }
+}
+
+#[test]
+fn derefs_to_inner_type() {
+    let root = block_root();
+    assert_eq!(first_byte(&root), 7);
+    assert_eq!(root.0.len(), 32);
+    let inner: &FixedBytes<32> = &root;
+    assert_eq!(inner, &root.root);
+}
+
+#[test]
+fn as_ref_to_inner_type() {
+    let slots = Slots {
+        values: VariableList::new(vec![1, 2, 3]).expect("within bound"),
+    };
+    assert_eq!(total(&slots), 6);
+    assert_eq!(total(slots), 6);
+}
+
+#[test]
+fn borrows_as_inner_type() {
+    let root = block_root();
+    let borrowed: &FixedBytes<32> = root.borrow();
+    assert_eq!(borrowed, &root.root);
+}
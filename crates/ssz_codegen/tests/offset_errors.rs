@@ -0,0 +1,58 @@
+//! A corrupted offset in a generated view names the field it belongs to, by its
+//! position among the container's variable-size fields.
+
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+include!("expected_output/test_default_impl.rs");
+
+use ssz::{DecodeError, Encode, view::DecodeView};
+use ssz_types::VariableList;
+use tests::input::test_default_impl::{Alpha, Beta, BetaRef};
+
+/// Position of the offset of `Beta::values` (variable-size field 1): after
+/// `alpha` (58 bytes), `roots` (128 bytes) and the offset of `bits`.
+const VALUES_OFFSET_POS: usize = 58 + 128 + 4;
+
+fn beta_bytes() -> Vec<u8> {
+    Beta {
+        values: VariableList::new(vec![1, 2, 3]).unwrap(),
+        ..Beta::default()
+    }
+    .as_ssz_bytes()
+}
+
+#[test]
+fn out_of_bounds_offset_names_field() {
+    let mut bytes = beta_bytes();
+    bytes[VALUES_OFFSET_POS..VALUES_OFFSET_POS + 4].copy_from_slice(&0xffffu32.to_le_bytes());
+
+    assert_eq!(
+        BetaRef::from_ssz_bytes(&bytes).unwrap_err(),
+        DecodeError::OffsetFieldInvalid {
+            index: 1,
+            offset: 0xffff
+        }
+    );
+}
+
+#[test]
+fn decreasing_offset_names_field() {
+    let mut bytes = beta_bytes();
+    let fixed_len = <Alpha as Encode>::ssz_fixed_len() + 128 + 4 + 4 + 32;
+    let before_bits = (fixed_len - 1) as u32;
+    bytes[VALUES_OFFSET_POS..VALUES_OFFSET_POS + 4].copy_from_slice(&before_bits.to_le_bytes());
+
+    assert_eq!(
+        BetaRef::from_ssz_bytes(&bytes).unwrap_err(),
+        DecodeError::OffsetFieldInvalid {
+            index: 1,
+            offset: fixed_len - 1
+        }
+    );
+}
@@ -0,0 +1,80 @@
+//! `Optional[T]` fields of plain containers are `Option<T>`, encoded like
+//! `Union[null, T]` as a selector byte followed by the value when present.
+
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+include!("expected_output/test_optional_field_container.rs");
+
+use ssz::{Decode, Encode, view::DecodeView};
+use ssz_types::VariableList;
+use tests::input::test_optional_field_container::{Reading, ReadingRef};
+use tree_hash::{Hash256, Sha256Hasher, TreeHash, TreeHashDigest};
+
+fn sample() -> Reading {
+    Reading {
+        sensor: 0x0102,
+        value: Some(9),
+        label: None,
+    }
+}
+
+#[test]
+fn encodes_selector_prefixed_values() {
+    // Both optionals are variable-size, so they sit behind offsets: `value`
+    // is `[1, 9]` and `label` is the lone `[0]` selector.
+    let bytes = sample().as_ssz_bytes();
+    assert_eq!(bytes, [0x02, 0x01, 10, 0, 0, 0, 12, 0, 0, 0, 1, 9, 0]);
+    assert_eq!(Reading::from_ssz_bytes(&bytes).expect("decode"), sample());
+
+    let swapped = Reading {
+        sensor: 0x0102,
+        value: None,
+        label: Some(VariableList::new(b"ok".to_vec()).expect("within bound")),
+    };
+    let bytes = swapped.as_ssz_bytes();
+    assert_eq!(&bytes[10..], [0, 1, b'o', b'k']);
+    assert_eq!(Reading::from_ssz_bytes(&bytes).expect("decode"), swapped);
+}
+
+#[test]
+fn view_getters_read_optionals() {
+    let bytes = sample().as_ssz_bytes();
+    let view = ReadingRef::from_ssz_bytes(&bytes).expect("view");
+    assert_eq!(view.sensor().expect("sensor"), 0x0102);
+    assert_eq!(view.value().expect("value"), Some(9));
+    assert!(view.label().expect("label").is_none());
+}
+
+#[test]
+fn rejects_invalid_selector() {
+    let mut bytes = sample().as_ssz_bytes();
+    bytes[10] = 2;
+    assert!(Reading::from_ssz_bytes(&bytes).is_err());
+}
+
+#[test]
+fn hashes_optionals_as_unions() {
+    let present = tree_hash::mix_in_selector_with_hasher::<Sha256Hasher>(
+        &9u8.tree_hash_root::<Sha256Hasher>(),
+        1,
+    )
+    .expect("valid selector");
+    let absent = tree_hash::mix_in_selector_with_hasher::<Sha256Hasher>(&Hash256::zero(), 0)
+        .expect("valid selector");
+    let sensor = 0x0102u16.tree_hash_root::<Sha256Hasher>();
+
+    let left = Sha256Hasher::hash32_concat(sensor.as_ref(), present.as_ref());
+    let right = Sha256Hasher::hash32_concat(absent.as_ref(), Hash256::zero().as_ref());
+    let expected = Sha256Hasher::hash32_concat(left.as_ref(), right.as_ref());
+    assert_eq!(sample().tree_hash_root::<Sha256Hasher>(), expected);
+
+    let bytes = sample().as_ssz_bytes();
+    let view = ReadingRef::from_ssz_bytes(&bytes).expect("view");
+    assert_eq!(view.tree_hash_root::<Sha256Hasher>(), expected);
+}
@@ -0,0 +1,91 @@
+//! Containers with `optional_tail` fields decode both their own encoding and
+//! older encodings that end before those fields.
+
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+include!("expected_output/test_optional_tail.rs");
+
+use ssz::{Decode, Encode};
+use ssz_types::VariableList;
+use tests::input::test_optional_tail::{Config, ConfigV1};
+
+fn name() -> VariableList<u8, 32> {
+    VariableList::new(b"node".to_vec()).unwrap()
+}
+
+#[test]
+fn decodes_old_short_encoding() {
+    let old = ConfigV1 {
+        version: 1,
+        name: name(),
+    };
+
+    let decoded = Config::from_ssz_bytes(&old.as_ssz_bytes()).unwrap();
+    assert_eq!(decoded.version, 1);
+    assert_eq!(decoded.name, name());
+    assert_eq!(decoded.flags, 0);
+    assert!(decoded.peers.is_empty());
+}
+
+#[test]
+fn decodes_new_long_encoding() {
+    let new = Config {
+        version: 2,
+        name: name(),
+        flags: 7,
+        peers: VariableList::new(vec![10, 11]).unwrap(),
+    };
+
+    let decoded = Config::from_ssz_bytes(&new.as_ssz_bytes()).unwrap();
+    assert_eq!(decoded.version, 2);
+    assert_eq!(decoded.name, name());
+    assert_eq!(decoded.flags, 7);
+    assert_eq!(decoded.peers.to_vec(), vec![10, 11]);
+}
+
+#[test]
+fn decodes_encoding_ending_mid_tail() {
+    // version, offset of name, flags, then name: written by a release that
+    // had `flags` but not `peers`.
+    let mut bytes = vec![3, 0, 10, 0, 0, 0, 9, 0, 0, 0];
+    bytes.extend_from_slice(b"node");
+
+    let decoded = Config::from_ssz_bytes(&bytes).unwrap();
+    assert_eq!(decoded.version, 3);
+    assert_eq!(decoded.name, name());
+    assert_eq!(decoded.flags, 9);
+    assert!(decoded.peers.is_empty());
+}
+
+#[test]
+fn rejects_truncated_required_field() {
+    let old = ConfigV1 {
+        version: 1,
+        name: name(),
+    }
+    .as_ssz_bytes();
+
+    // Ends inside `version`, then inside the offset of `name`.
+    assert!(Config::from_ssz_bytes(&old[..1]).is_err());
+    assert!(Config::from_ssz_bytes(&old[..4]).is_err());
+}
+
+#[test]
+fn rejects_truncated_tail_field() {
+    let new = Config {
+        version: 2,
+        name: name(),
+        flags: 7,
+        peers: VariableList::new(vec![10]).unwrap(),
+    }
+    .as_ssz_bytes();
+
+    // Ends inside `flags`: neither the full nor any shorter layout matches.
+    assert!(Config::from_ssz_bytes(&new[..8]).is_err());
+}
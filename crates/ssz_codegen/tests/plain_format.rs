@@ -0,0 +1,50 @@
+//! Code laid out by `format_plain` instead of `prettyplease` must compile
+//! and behave like the pretty-printed code.
+
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+// Include generated code
+include!("expected_output/test_plain_format.rs");
+
+use ssz::{Decode, Encode, view::DecodeView};
+use ssz_types::{FixedVector, VariableList};
+use tests::input::test_vector_view_iter::{Delta, Entry, Holder, HolderRef};
+
+#[test]
+fn plain_formatted_code_round_trips() {
+    let holder = Holder {
+        deltas: FixedVector::new(vec![
+            Delta { z: true, w: 1 },
+            Delta { z: false, w: 2 },
+            Delta { z: true, w: 3 },
+            Delta { z: false, w: 4 },
+        ])
+        .expect("exact length"),
+        entries: FixedVector::new(vec![
+            Entry {
+                id: 10,
+                data: VariableList::new(vec![1, 2]).expect("within bound"),
+            },
+            Entry {
+                id: 11,
+                data: VariableList::new(vec![]).expect("within bound"),
+            },
+            Entry {
+                id: 12,
+                data: VariableList::new(vec![3, 4, 5, 6]).expect("within bound"),
+            },
+        ])
+        .expect("exact length"),
+    };
+    let bytes = holder.as_ssz_bytes();
+
+    assert_eq!(Holder::from_ssz_bytes(&bytes).expect("decode"), holder);
+    let view = HolderRef::from_ssz_bytes(&bytes).expect("view decode");
+    assert_eq!(view.to_owned(), holder);
+}
@@ -0,0 +1,58 @@
+//! Containers generated with `pooled_tree_hash` hash to the same roots as
+//! those generated without it.
+
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+pub mod pooled {
+    include!("expected_output/test_pooled_tree_hash.rs");
+}
+
+pub mod plain {
+    include!("expected_output/test_vector_view_iter.rs");
+}
+
+use plain::tests::input::test_vector_view_iter as plain_types;
+use pooled::tests::input::test_pooled_tree_hash::{Batch, BatchRef, Delta};
+use ssz::{Encode, view::DecodeView};
+use ssz_types::VariableList;
+use tree_hash::{Sha256Hasher, TreeHash};
+
+#[test]
+fn pooled_root_matches_plain_root() {
+    for (z, w) in [(false, 0), (true, 7), (true, 255)] {
+        assert_eq!(
+            Delta { z, w }.tree_hash_root::<Sha256Hasher>(),
+            plain_types::Delta { z, w }.tree_hash_root::<Sha256Hasher>()
+        );
+    }
+}
+
+#[test]
+fn pooled_root_matches_view_root() {
+    let batch = Batch {
+        first: Delta { z: true, w: 1 },
+        deltas: VariableList::new(
+            (0..5)
+                .map(|i| Delta {
+                    z: i % 2 == 0,
+                    w: i,
+                })
+                .collect(),
+        )
+        .unwrap(),
+        note: VariableList::new(b"pooled".to_vec()).unwrap(),
+    };
+    let bytes = batch.as_ssz_bytes();
+    let view = BatchRef::from_ssz_bytes(&bytes).unwrap();
+
+    // Hashing twice reuses the pooled hasher for both containers.
+    let root = batch.tree_hash_root::<Sha256Hasher>();
+    assert_eq!(root, batch.tree_hash_root::<Sha256Hasher>());
+    assert_eq!(root, view.tree_hash_root::<Sha256Hasher>());
+}
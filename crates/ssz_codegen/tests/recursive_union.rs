@@ -0,0 +1,54 @@
+//! A union may refer back to itself through a list: a tree of leaves and
+//! nodes round-trips through the owned and view types.
+
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+include!("expected_output/test_recursive_union.rs");
+
+use ssz::{Decode, Encode, view::DecodeView};
+use ssz_types::VariableList;
+use tests::input::test_recursive_union::{Leaf, Tree, TreeRef};
+use tree_hash::{Sha256Hasher, TreeHash};
+
+fn leaf(value: u64) -> Tree {
+    Tree::Leaf(Leaf { value })
+}
+
+fn node(children: Vec<Tree>) -> Tree {
+    Tree::Node(VariableList::new(children).expect("within bound"))
+}
+
+fn sample_tree() -> Tree {
+    node(vec![leaf(1), node(vec![leaf(2), leaf(3)]), node(vec![])])
+}
+
+#[test]
+fn owned_round_trip() {
+    let tree = sample_tree();
+    let bytes = tree.as_ssz_bytes();
+    assert_eq!(Tree::from_ssz_bytes(&bytes).expect("decode"), tree);
+}
+
+#[test]
+fn view_round_trip() {
+    let tree = sample_tree();
+    let bytes = tree.as_ssz_bytes();
+    let view = TreeRef::from_ssz_bytes(&bytes).expect("view");
+    view.validate_deep().expect("valid tree");
+    assert_eq!(view.to_owned(), tree);
+    assert_eq!(
+        view.tree_hash_root::<Sha256Hasher>(),
+        tree.tree_hash_root::<Sha256Hasher>()
+    );
+
+    let children = view.as_selector1().expect("node");
+    assert_eq!(children.len(), 3);
+    let first = children.get(0).expect("first child");
+    assert_eq!(first.as_selector0().expect("leaf").value(), Ok(1));
+}
@@ -0,0 +1,52 @@
+//! With `root_eq`, containers compare by tree hash root, agreeing with `==`.
+
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+include!("expected_output/test_root_eq.rs");
+
+use ssz_types::{FixedBytes, Optional, VariableList};
+use tests::input::test_root_eq::{Alpha, Beta};
+
+fn alpha() -> Alpha {
+    Alpha {
+        a: 7,
+        b: VariableList::new(vec![0xde, 0xad]).expect("within bound"),
+        c: FixedBytes([0x0c; 48]),
+    }
+}
+
+#[test]
+fn equal_alphas_are_root_eq() {
+    assert!(alpha().root_eq(&alpha()));
+}
+
+#[test]
+fn one_byte_changes_root_eq() {
+    let mut other = alpha();
+    other.c.0[47] = 0x0d;
+    assert!(!alpha().root_eq(&other));
+    assert_ne!(alpha(), other);
+
+    let mut other = alpha();
+    other.b = VariableList::new(vec![0xde, 0xae]).expect("within bound");
+    assert!(!alpha().root_eq(&other));
+}
+
+#[test]
+fn stable_container_root_eq() {
+    let beta = Beta {
+        alpha: Optional::Some(alpha()),
+        d: Optional::None,
+    };
+    assert!(beta.root_eq(&beta.clone()));
+    assert!(!beta.root_eq(&Beta {
+        alpha: Optional::Some(alpha()),
+        d: Optional::Some(0),
+    }));
+}
@@ -0,0 +1,66 @@
+//! Containers generated with `DeriveConfig::with_serde(true)` read and write
+//! the consensus spec's JSON format.
+
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+include!("expected_output/test_serde_config.rs");
+
+use ssz::{Decode, Encode};
+use ssz_types::{FixedBytes, FixedVector, Optional, U256, VariableList};
+use tests::input::test_serde_config::{Alpha, Beta, Gamma};
+
+#[test]
+fn gamma_json_round_trip() {
+    let json = r#"{"g":1,"h":[2,3],"i":{"a":4,"b":"18446744073709551615","c":"0x0a0b0c0d"}}"#;
+
+    let gamma: Gamma = serde_json::from_str(json).expect("parse json");
+    assert_eq!(
+        gamma,
+        Gamma {
+            g: Optional::Some(1),
+            h: Optional::Some(VariableList::new(vec![2, 3]).unwrap()),
+            i: Optional::Some(Alpha {
+                a: 4,
+                b: u64::MAX,
+                c: FixedBytes([0x0a, 0x0b, 0x0c, 0x0d]),
+            }),
+        }
+    );
+    assert_eq!(serde_json::to_string(&gamma).expect("write json"), json);
+
+    // The JSON and SSZ encodings describe the same value.
+    assert_eq!(
+        Gamma::from_ssz_bytes(&gamma.as_ssz_bytes()).expect("decode"),
+        gamma
+    );
+
+    let empty: Gamma = serde_json::from_str(r#"{"g":null,"h":null,"i":null}"#).expect("parse json");
+    assert_eq!(empty.g, Optional::None);
+}
+
+#[test]
+fn beta_wide_integers_and_bytes_use_spec_format() {
+    let beta = Beta {
+        d: VariableList::new(vec![0xde, 0xad]).unwrap(),
+        e: VariableList::new(vec![1, 2]).unwrap(),
+        f: FixedVector::new(vec![3, 4]).unwrap(),
+        g: U256::from(5u64),
+        h: true,
+    };
+
+    let json = serde_json::to_string(&beta).expect("write json");
+    assert_eq!(
+        json,
+        r#"{"d":"0xdead","e":["1","2"],"f":["3","4"],"g":"5","h":true}"#
+    );
+    assert_eq!(
+        serde_json::from_str::<Beta>(&json).expect("parse json"),
+        beta
+    );
+}
@@ -0,0 +1,65 @@
+//! Generated containers derive `ssz::Encode`, whose `ssz_append` writes the
+//! encoding straight into a caller's buffer, so batches of containers can be
+//! built without an intermediate `as_ssz_bytes` copy per item.
+
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+include!("expected_output/test_vector_view_iter.rs");
+
+use ssz::Encode;
+use ssz_types::VariableList;
+use tests::input::test_vector_view_iter::{Delta, Entry};
+
+#[test]
+fn appends_fixed_size_containers() {
+    let deltas = [
+        Delta { z: true, w: 1 },
+        Delta { z: false, w: 2 },
+        Delta { z: true, w: 3 },
+    ];
+
+    let mut buf = Vec::new();
+    for delta in &deltas {
+        delta.ssz_append(&mut buf);
+    }
+    let concatenated = deltas
+        .iter()
+        .flat_map(|delta| delta.as_ssz_bytes())
+        .collect::<Vec<_>>();
+    assert_eq!(buf, concatenated);
+
+    let mut buf = Vec::new();
+    deltas[0].ssz_append(&mut buf);
+    assert_eq!(buf, deltas[0].as_ssz_bytes());
+}
+
+#[test]
+fn appends_variable_size_containers_after_existing_bytes() {
+    let entries = [
+        Entry {
+            id: 10,
+            data: VariableList::new(vec![1, 2]).expect("within bound"),
+        },
+        Entry {
+            id: 11,
+            data: VariableList::new(vec![]).expect("within bound"),
+        },
+    ];
+
+    // Offsets are relative to each container's own start, not the buffer's.
+    let mut buf = vec![0xaa; 3];
+    for entry in &entries {
+        entry.ssz_append(&mut buf);
+    }
+    let mut expected = vec![0xaa; 3];
+    for entry in &entries {
+        expected.extend(entry.as_ssz_bytes());
+    }
+    assert_eq!(buf, expected);
+}
@@ -0,0 +1,67 @@
+//! Generated containers report their encoded length through
+//! `Encode::ssz_bytes_len` without encoding, and it always agrees with the
+//! length of `as_ssz_bytes`.
+
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+include!("expected_output/test_default_impl.rs");
+
+use ssz::Encode;
+use ssz_types::{BitList, Optional, VariableList};
+use tests::input::test_default_impl::{Alpha, Beta, Choice, Gamma, GammaProfile, WithUnion};
+
+fn assert_len_matches(value: &impl Encode) {
+    assert_eq!(value.ssz_bytes_len(), value.as_ssz_bytes().len());
+}
+
+#[test]
+fn fixed_container() {
+    let alpha = Alpha {
+        a: 1,
+        b: 2,
+        d: true,
+        ..Alpha::default()
+    };
+    assert_len_matches(&alpha);
+    assert_eq!(alpha.ssz_bytes_len(), <Alpha as Encode>::ssz_fixed_len());
+}
+
+#[test]
+fn variable_container() {
+    let mut beta = Beta::default();
+    assert_len_matches(&beta);
+
+    beta.values = VariableList::new(vec![1, 2, 3]).unwrap();
+    beta.bits = BitList::with_capacity(11).unwrap();
+    assert_len_matches(&beta);
+}
+
+#[test]
+fn stable_container_and_profile() {
+    let mut gamma = Gamma::default();
+    assert_len_matches(&gamma);
+    gamma.h = Optional::Some(Alpha::default());
+    assert_len_matches(&gamma);
+
+    let profile = GammaProfile {
+        g: 7,
+        h: Optional::Some(Alpha::default()),
+    };
+    assert_len_matches(&profile);
+}
+
+#[test]
+fn union_field() {
+    assert_len_matches(&WithUnion {
+        choice: Choice::Selector0(3),
+    });
+    assert_len_matches(&WithUnion {
+        choice: Choice::Alpha(Alpha::default()),
+    });
+}
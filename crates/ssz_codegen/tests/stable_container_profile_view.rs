@@ -30,8 +30,8 @@ use ssz::{
 };
 use ssz_types::{BitList, BitVector, Optional, VariableList};
 use tests::input::test_2::{
-    Alpha, InnerBase, InnerBaseRef, InnerProfile1, InnerProfile1Ref, InnerProfile2,
-    InnerProfile2Ref,
+    Alpha, AlphaProfile, InnerBase, InnerBaseRef, InnerProfile1, InnerProfile1Ref, InnerProfile2,
+    InnerProfile2Ref, InnerProfile3, InnerProfile3Ref,
 };
 use tree_hash::TreeHash;
 
@@ -81,6 +81,46 @@ fn stable_container_view_all_some() {
     assert_eq!(view.to_owned(), owned);
 }
 
+#[test]
+fn stable_container_view_compares_equal_to_owned() {
+    let owned = InnerBase {
+        x: Optional::Some(1),
+        y: Optional::Some(VariableList::new(vec![2, 3]).expect("within bound")),
+        z: Optional::None,
+        w: Optional::Some(sample_alpha()),
+    };
+    let mut bytes = owned.as_ssz_bytes();
+    let view = InnerBaseRef::from_ssz_bytes(&bytes).expect("view decode");
+    assert!(view == owned);
+    assert!(owned == view);
+
+    // Activating a field the view lacks fails at the bitvector.
+    let mut with_z = owned.clone();
+    with_z.z = Optional::Some(BitVector::<16>::new());
+    assert!(view != with_z);
+
+    // Byte 1 is `x`, the first field after the one-byte bitvector.
+    bytes[1] ^= 0xff;
+    let view = InnerBaseRef::from_ssz_bytes(&bytes).expect("view decode");
+    assert!(view != owned);
+
+    let profile = InnerProfile1 {
+        x: 1,
+        y: Optional::None,
+        z: Optional::None,
+        w: Optional::Some(sample_alpha()),
+    };
+    let bytes = profile.as_ssz_bytes();
+    let view = InnerProfile1Ref::from_ssz_bytes(&bytes).expect("view decode");
+    assert!(view == profile);
+    assert!(
+        view != InnerProfile1 {
+            w: Optional::None,
+            ..profile.clone()
+        }
+    );
+}
+
 /// Inactive fields shift every later field's position: `x` and `z` are
 /// absent, so `y`'s offset slot sits at position 0 of the body and `w`'s
 /// right after it.
@@ -260,3 +300,232 @@ fn stable_container_active_optional_with_empty_inner() {
     }
     assert_eq!(view.to_owned(), owned);
 }
+
+#[test]
+fn profile_narrowing_from_satisfying_stable_container() {
+    let owned = InnerBase {
+        x: Optional::Some(1),
+        y: Optional::None,
+        z: Optional::None,
+        w: Optional::Some(sample_alpha()),
+    };
+    let bytes = owned.as_ssz_bytes();
+    let view = InnerBaseRef::from_ssz_bytes(&bytes).expect("view decode");
+
+    let narrowed = InnerProfile1::try_from(view).expect("x is active");
+    assert_eq!(
+        narrowed,
+        InnerProfile1 {
+            x: 1,
+            y: Optional::None,
+            z: Optional::None,
+            w: Optional::Some(sample_alpha()),
+        }
+    );
+    assert_eq!(
+        narrowed.tree_hash_root::<tree_hash::Sha256Hasher>(),
+        owned.tree_hash_root::<tree_hash::Sha256Hasher>(),
+        "a profile merkleizes like its StableContainer"
+    );
+
+    // `w` narrows from `Alpha` to `AlphaProfile`, which requires `a`.
+    let owned = InnerBase {
+        x: Optional::None,
+        y: Optional::None,
+        z: Optional::None,
+        w: Optional::Some(sample_alpha()),
+    };
+    let bytes = owned.as_ssz_bytes();
+    let view = InnerBaseRef::from_ssz_bytes(&bytes).expect("view decode");
+    let narrowed = InnerProfile3::try_from(view).expect("w satisfies AlphaProfile");
+    let mut bits = BitList::<32>::with_capacity(3).expect("within bound");
+    bits.set(1, true).expect("within bound");
+    assert_eq!(
+        narrowed,
+        InnerProfile3 {
+            w: AlphaProfile {
+                a: 7,
+                b: Optional::Some(bits),
+            },
+        }
+    );
+}
+
+#[test]
+fn profile_to_stable_round_trip() {
+    let profile = InnerProfile1 {
+        x: 1,
+        y: Optional::Some(VariableList::new(vec![2, 3]).expect("within bound")),
+        z: Optional::None,
+        w: Optional::Some(sample_alpha()),
+    };
+    let stable = profile.to_stable();
+    assert_eq!(stable.x, Optional::Some(1));
+    assert_eq!(stable.z, Optional::None);
+    assert_eq!(
+        stable.tree_hash_root::<tree_hash::Sha256Hasher>(),
+        profile.tree_hash_root::<tree_hash::Sha256Hasher>(),
+    );
+
+    let bytes = stable.as_ssz_bytes();
+    let view = InnerBaseRef::from_ssz_bytes(&bytes).expect("view decode");
+    assert_eq!(InnerProfile1::try_from(view).expect("x is active"), profile);
+
+    // Nested profiles widen to their own StableContainer, and the view
+    // converts like its owned profile.
+    let profile = InnerProfile3 {
+        w: AlphaProfile {
+            a: 7,
+            b: Optional::None,
+        },
+    };
+    let stable = profile.to_stable();
+    assert_eq!(
+        stable.w,
+        Optional::Some(Alpha {
+            a: Optional::Some(7),
+            b: Optional::None,
+        })
+    );
+    let profile_bytes = profile.as_ssz_bytes();
+    let profile_view = InnerProfile3Ref::from_ssz_bytes(&profile_bytes).expect("view decode");
+    assert_eq!(profile_view.to_stable(), stable);
+    assert_eq!(
+        stable.tree_hash_root::<tree_hash::Sha256Hasher>(),
+        profile.tree_hash_root::<tree_hash::Sha256Hasher>(),
+    );
+
+    let bytes = stable.as_ssz_bytes();
+    let view = InnerBaseRef::from_ssz_bytes(&bytes).expect("view decode");
+    assert_eq!(InnerProfile3::try_from(view).expect("w is active"), profile);
+}
+
+#[test]
+fn profile_narrowing_rejects_unsatisfying_stable_container() {
+    // InnerProfile1 requires `x`.
+    let owned = InnerBase {
+        x: Optional::None,
+        y: Optional::Some(VariableList::new(vec![2]).expect("within bound")),
+        z: Optional::None,
+        w: Optional::None,
+    };
+    let bytes = owned.as_ssz_bytes();
+    let view = InnerBaseRef::from_ssz_bytes(&bytes).expect("view decode");
+    assert!(matches!(
+        InnerProfile1::try_from(view),
+        Err(ssz::DecodeError::BytesInvalid(_))
+    ));
+
+    // InnerProfile2 has no `w` field, so it must be inactive.
+    let owned = InnerBase {
+        x: Optional::None,
+        y: Optional::Some(VariableList::new(vec![2]).expect("within bound")),
+        z: Optional::Some(BitVector::<16>::new()),
+        w: Optional::Some(sample_alpha()),
+    };
+    let bytes = owned.as_ssz_bytes();
+    let view = InnerBaseRef::from_ssz_bytes(&bytes).expect("view decode");
+    assert!(matches!(
+        InnerProfile2::try_from(view),
+        Err(ssz::DecodeError::BytesInvalid(_))
+    ));
+
+    // The nested `Alpha` lacks the `a` that AlphaProfile requires.
+    let owned = InnerBase {
+        x: Optional::None,
+        y: Optional::None,
+        z: Optional::None,
+        w: Optional::Some(Alpha {
+            a: Optional::None,
+            b: Optional::None,
+        }),
+    };
+    let bytes = owned.as_ssz_bytes();
+    let view = InnerBaseRef::from_ssz_bytes(&bytes).expect("view decode");
+    assert!(matches!(
+        InnerProfile3::try_from(view),
+        Err(ssz::DecodeError::BytesInvalid(_))
+    ));
+}
+
+#[test]
+fn owned_profile_converts_to_stable_container_and_back() {
+    let profile = InnerProfile1 {
+        x: 1,
+        y: Optional::Some(VariableList::new(vec![2, 3]).expect("within bound")),
+        z: Optional::None,
+        w: Optional::Some(sample_alpha()),
+    };
+    let stable = InnerBase::from(profile.clone());
+    assert_eq!(stable, profile.to_stable());
+    assert_eq!(
+        InnerProfile1::try_from(stable).expect("x is present"),
+        profile
+    );
+
+    // Nested profiles convert through their own impls.
+    let profile = InnerProfile3 {
+        w: AlphaProfile {
+            a: 7,
+            b: Optional::None,
+        },
+    };
+    let stable = InnerBase::from(profile.clone());
+    assert_eq!(
+        stable.w,
+        Optional::Some(Alpha {
+            a: Optional::Some(7),
+            b: Optional::None,
+        })
+    );
+    assert_eq!(
+        InnerProfile3::try_from(stable).expect("w is present"),
+        profile
+    );
+}
+
+#[test]
+fn owned_profile_conversion_rejects_unsatisfying_stable_container() {
+    // InnerProfile1 requires `x`.
+    let stable = InnerBase {
+        x: Optional::None,
+        y: Optional::None,
+        z: Optional::None,
+        w: Optional::None,
+    };
+    assert_eq!(
+        InnerProfile1::try_from(stable),
+        Err(ssz::DecodeError::BytesInvalid(
+            "field `x` is required by profile `InnerProfile1` but inactive".to_string()
+        ))
+    );
+
+    // InnerProfile2 has no `w` field, so it must be absent.
+    let stable = InnerBase {
+        x: Optional::None,
+        y: Optional::Some(VariableList::new(vec![2]).expect("within bound")),
+        z: Optional::Some(BitVector::<16>::new()),
+        w: Optional::Some(sample_alpha()),
+    };
+    assert_eq!(
+        InnerProfile2::try_from(stable),
+        Err(ssz::DecodeError::BytesInvalid(
+            "field `w` is not part of profile `InnerProfile2` but active".to_string()
+        ))
+    );
+
+    // The nested `Alpha` lacks the `a` that AlphaProfile requires.
+    let stable = InnerBase {
+        x: Optional::None,
+        y: Optional::None,
+        z: Optional::None,
+        w: Optional::Some(Alpha {
+            a: Optional::None,
+            b: Optional::None,
+        }),
+    };
+    assert!(matches!(
+        InnerProfile3::try_from(stable),
+        Err(ssz::DecodeError::BytesInvalid(_))
+    ));
+}
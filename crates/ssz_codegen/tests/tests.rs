@@ -1,19 +1,29 @@
 //! Tests for the ssz_codegen crate.
 
 use std::{
+    collections::HashSet,
     error, fs,
     sync::{LazyLock, Mutex},
 };
 
+use arbitrary as _;
+use criterion as _;
+#[cfg(feature = "prettyplease")]
 use prettyplease as _;
 use proc_macro2 as _;
 use quote as _;
 use serde as _;
-use sizzle_parser as _;
+use serde_json as _;
+use sizzle_parser::parse_str_schema;
 use ssz as _;
 use ssz_codegen::{
     ModuleGeneration, build_ssz_files as build_ssz_files_unlocked,
+    build_ssz_files_multi as build_ssz_files_multi_unlocked,
     build_ssz_files_with_derives as build_ssz_files_with_derives_unlocked,
+    codegen::schema_map_to_rust_code,
+    derive_config::{DeriveConfig, OVERRIDABLE_CRATES},
+    files::read_entrypoint_ssz,
+    format::format_plain,
 };
 use ssz_derive as _;
 use ssz_primitives as _;
@@ -44,6 +54,19 @@ fn build_ssz_files(
     )
 }
 
+fn build_ssz_files_multi(
+    entry_points: &[&str],
+    base_dir: &str,
+    crates: &[&str],
+    out_dir: &str,
+    module_generation: ModuleGeneration,
+) -> Result<(), Box<dyn error::Error>> {
+    let _guard = CODEGEN_LOCK
+        .lock()
+        .unwrap_or_else(|poison| poison.into_inner());
+    build_ssz_files_multi_unlocked(entry_points, base_dir, crates, out_dir, module_generation)
+}
+
 fn build_ssz_files_with_derives(
     entry_points: &[&str],
     base_dir: &str,
@@ -170,6 +193,148 @@ fn test_imports() {
     assert_eq!(expected_output, actual_output);
 }
 
+#[test]
+fn test_from_import() {
+    build_ssz_files(
+        &["test_from_import.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_from_import.rs",
+        ModuleGeneration::NestedModules,
+    )
+    .expect("Failed to generate SSZ types");
+
+    let expected_output = fs::read_to_string("tests/expected_output/test_from_import.rs")
+        .expect("Failed to read expected output");
+    let actual_output = fs::read_to_string("tests/output/test_from_import.rs")
+        .expect("Failed to read actual output");
+    assert_eq!(expected_output, actual_output);
+}
+
+/// Items pulled in with `from ... import` must not be redefined when the source module is
+/// emitted into the same `SingleModule` output.
+#[test]
+fn test_from_import_single_module() {
+    build_ssz_files(
+        &["test_common.ssz", "test_from_import.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_from_import_single.rs",
+        ModuleGeneration::SingleModule,
+    )
+    .expect("Failed to generate SSZ types");
+
+    let generated = fs::read_to_string("tests/output/test_from_import_single.rs")
+        .expect("Failed to read generated output");
+    assert_eq!(generated.matches("pub const CONSTANT_VALUE:").count(), 1);
+    assert_eq!(generated.matches("pub type AliasUint8 =").count(), 1);
+    assert_eq!(
+        generated
+            .matches("pub struct StableContainerClass {")
+            .count(),
+        1
+    );
+    assert!(generated.contains("pub struct FromImported {"));
+}
+
+/// Test that each schema file is written to its own file, referencing its imports as siblings.
+#[test]
+fn test_multi_file_generation() {
+    build_ssz_files_multi(
+        &["test_multi_import_a.ssz", "test_multi_import_b.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/multi",
+        ModuleGeneration::FlatModules,
+    )
+    .expect("Failed to generate SSZ types");
+
+    for file in [
+        "test_multi_import_base.rs",
+        "test_multi_import_a.rs",
+        "test_multi_import_b.rs",
+    ] {
+        let expected_output = fs::read_to_string(format!("tests/expected_output/multi/{file}"))
+            .expect("Failed to read expected output");
+        let actual_output = fs::read_to_string(format!("tests/output/multi/{file}"))
+            .expect("Failed to read actual output");
+        assert_eq!(expected_output, actual_output, "{file} differs");
+    }
+}
+
+/// Test that schema files sharing a file name are rejected instead of overwriting each other.
+#[test]
+fn test_multi_file_duplicate_stem() {
+    let err = build_ssz_files_multi(
+        &["multi_stem/a/types.ssz", "multi_stem/b/types.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/multi_stem",
+        ModuleGeneration::NestedModules,
+    )
+    .expect_err("Duplicate file names should be rejected");
+
+    assert!(
+        err.to_string()
+            .contains("would both be written to types.rs")
+    );
+    assert!(!std::path::Path::new("tests/output/multi_stem/types.rs").exists());
+}
+
+#[test]
+fn test_bool_field() {
+    build_ssz_files(
+        &["test_bool_field.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_bool_field.rs",
+        ModuleGeneration::NestedModules,
+    )
+    .expect("Failed to generate SSZ types");
+
+    let expected_output = fs::read_to_string("tests/expected_output/test_bool_field.rs")
+        .expect("Failed to read expected output");
+    let actual_output = fs::read_to_string("tests/output/test_bool_field.rs")
+        .expect("Failed to read actual output");
+    assert_eq!(expected_output, actual_output);
+}
+
+#[test]
+fn test_to_owned_into() {
+    build_ssz_files(
+        &["test_to_owned_into.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_to_owned_into.rs",
+        ModuleGeneration::NestedModules,
+    )
+    .expect("Failed to generate SSZ types");
+
+    let expected_output = fs::read_to_string("tests/expected_output/test_to_owned_into.rs")
+        .expect("Failed to read expected output");
+    let actual_output = fs::read_to_string("tests/output/test_to_owned_into.rs")
+        .expect("Failed to read actual output");
+    assert_eq!(expected_output, actual_output);
+}
+
+#[test]
+fn test_alias_hops() {
+    build_ssz_files(
+        &["test_alias_hops.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_alias_hops.rs",
+        ModuleGeneration::NestedModules,
+    )
+    .expect("Failed to generate SSZ types");
+
+    let expected_output = fs::read_to_string("tests/expected_output/test_alias_hops.rs")
+        .expect("Failed to read expected output");
+    let actual_output = fs::read_to_string("tests/output/test_alias_hops.rs")
+        .expect("Failed to read actual output");
+    assert_eq!(expected_output, actual_output);
+}
+
 #[test]
 fn test_large_unions() {
     build_ssz_files(
@@ -445,7 +610,6 @@ fn test_duplicate_item_name() {
 }
 
 #[test]
-#[should_panic(expected = "Optional fields are not allowed in Container classes")]
 fn test_optional_field_container() {
     build_ssz_files(
         &["test_optional_field_container.ssz"],
@@ -454,7 +618,71 @@ fn test_optional_field_container() {
         "tests/output/test_optional_field_container.rs",
         ModuleGeneration::NestedModules,
     )
-    .expect("This should panic due to optional field in container");
+    .expect("Failed to generate SSZ types");
+
+    let expected_output =
+        fs::read_to_string("tests/expected_output/test_optional_field_container.rs")
+            .expect("Failed to read expected output");
+    let actual_output = fs::read_to_string("tests/output/test_optional_field_container.rs")
+        .expect("Failed to read actual output");
+    assert_eq!(expected_output, actual_output);
+}
+
+/// Test vectors and lists of fixed-size byte vectors.
+#[test]
+fn test_byte_matrix() {
+    build_ssz_files(
+        &["test_byte_matrix.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_byte_matrix.rs",
+        ModuleGeneration::NestedModules,
+    )
+    .expect("Failed to generate SSZ types");
+
+    let expected_output = fs::read_to_string("tests/expected_output/test_byte_matrix.rs")
+        .expect("Failed to read expected output");
+    let actual_output = fs::read_to_string("tests/output/test_byte_matrix.rs")
+        .expect("Failed to read actual output");
+    assert_eq!(expected_output, actual_output);
+}
+
+/// Test unions whose members are fixed-size.
+#[test]
+fn test_union_fixed_member() {
+    build_ssz_files(
+        &["test_union_fixed_member.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_union_fixed_member.rs",
+        ModuleGeneration::NestedModules,
+    )
+    .expect("Failed to generate SSZ types");
+
+    let expected_output = fs::read_to_string("tests/expected_output/test_union_fixed_member.rs")
+        .expect("Failed to read expected output");
+    let actual_output = fs::read_to_string("tests/output/test_union_fixed_member.rs")
+        .expect("Failed to read actual output");
+    assert_eq!(expected_output, actual_output);
+}
+
+/// Test the fixture covering empty-input decoding of each type category.
+#[test]
+fn test_empty_input() {
+    build_ssz_files(
+        &["test_empty_input.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_empty_input.rs",
+        ModuleGeneration::NestedModules,
+    )
+    .expect("Failed to generate SSZ types");
+
+    let expected_output = fs::read_to_string("tests/expected_output/test_empty_input.rs")
+        .expect("Failed to read expected output");
+    let actual_output = fs::read_to_string("tests/output/test_empty_input.rs")
+        .expect("Failed to read actual output");
+    assert_eq!(expected_output, actual_output);
 }
 
 #[test]
@@ -738,6 +966,40 @@ fn test_docstrings_with_comments_merge() {
     assert!(!output.is_empty(), "Generated output should not be empty");
 }
 
+#[test]
+fn test_block_doc_comments() {
+    build_ssz_files_with_derives(
+        &["test_docstrings.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_block_doc_comments.rs",
+        ModuleGeneration::NestedModules,
+        Some(
+            ssz_codegen::derive_config::DeriveConfig::default_defaults()
+                .with_doc_comment_style(ssz_codegen::derive_config::DocCommentStyle::Block),
+        ),
+        None,
+    )
+    .expect("Failed to generate SSZ types with block doc comments");
+
+    let expected_output = fs::read_to_string("tests/expected_output/test_block_doc_comments.rs")
+        .expect("Failed to read expected output");
+    let actual_output = fs::read_to_string("tests/output/test_block_doc_comments.rs")
+        .expect("Failed to read actual output");
+    assert!(actual_output.contains("/**\n * X coordinate\n */"));
+    assert!(!actual_output.contains("/// X coordinate"));
+    assert_eq!(expected_output, actual_output);
+
+    let cfg = ssz_codegen::derive_config::DeriveConfig::from_toml_str(
+        "[derives]\ncomment_style = \"block\"\n",
+    )
+    .expect("valid toml");
+    assert_eq!(
+        cfg.doc_comment_style,
+        ssz_codegen::derive_config::DocCommentStyle::Block
+    );
+}
+
 // Pragma tests
 
 #[test]
@@ -796,106 +1058,346 @@ fn test_pragmas_field() {
     assert_eq!(expected_output, actual_output);
 }
 
-/// Test that pragmas work with inheritance.
+/// Test that `error_type` classes get `Display`/`Error` impls driven by their
+/// `error_message` field.
 #[test]
-fn test_pragmas_inheritance() {
+fn test_pragmas_error_type() {
     build_ssz_files(
-        &["test_pragmas_inheritance.ssz"],
+        &["test_error_type.ssz"],
         "tests/input",
         &[],
-        "tests/output/test_pragmas_inheritance.rs",
+        "tests/output/test_error_type.rs",
         ModuleGeneration::NestedModules,
     )
-    .expect("Failed to generate SSZ types with pragmas and inheritance");
+    .expect("Failed to generate SSZ types with error_type pragmas");
 
-    let expected_output = fs::read_to_string("tests/expected_output/test_pragmas_inheritance.rs")
+    let expected_output = fs::read_to_string("tests/expected_output/test_error_type.rs")
         .expect("Failed to read expected output");
-    let actual_output = fs::read_to_string("tests/output/test_pragmas_inheritance.rs")
+    let actual_output = fs::read_to_string("tests/output/test_error_type.rs")
         .expect("Failed to read actual output");
     assert_eq!(expected_output, actual_output);
 }
 
-/// Test that a field-level `#[ssz(with = ...)]` pragma flows into the view
-/// layout: `ssz_derive` encodes such fields through `module::encode::*`
-/// instead of the field type's `Encode` impl, so the generated view layout
-/// expressions must call the same functions to agree with the owned encoding.
+/// Test that `error_type` classes without exactly one `error_message` field are
+/// rejected.
 #[test]
-fn test_view_layout_honors_field_ssz_with() {
+fn test_error_type_invalid() {
+    for (input, expected) in [
+        (
+            "test_error_type_invalid.ssz",
+            "error_type class `NoMessage` must have exactly one error_message field, found 0",
+        ),
+        (
+            "test_error_type_invalid_two.ssz",
+            "error_type class `TwoMessages` must have exactly one error_message field, found 2",
+        ),
+    ] {
+        let err = build_ssz_files(
+            &[input],
+            "tests/input",
+            &[],
+            "tests/output/test_error_type_invalid.rs",
+            ModuleGeneration::NestedModules,
+        )
+        .expect_err("An error_type class without one error_message field should be rejected");
+
+        assert_eq!(err.to_string(), expected);
+    }
+}
+
+/// Test that `collection` classes get `TryFromIter`/`Extend` impls over their
+/// single list field.
+#[test]
+fn test_pragmas_collection() {
     build_ssz_files(
-        &["test_with_pragma.ssz"],
+        &["test_collection.ssz"],
         "tests/input",
         &[],
-        "tests/output/test_with_pragma.rs",
+        "tests/output/test_collection.rs",
         ModuleGeneration::NestedModules,
     )
-    .expect("Failed to generate SSZ types with ssz(with) field pragma");
+    .expect("Failed to generate SSZ types with collection pragmas");
 
-    let generated = fs::read_to_string("tests/output/test_with_pragma.rs")
-        .expect("Failed to read generated output");
+    let expected_output = fs::read_to_string("tests/expected_output/test_collection.rs")
+        .expect("Failed to read expected output");
+    let actual_output = fs::read_to_string("tests/output/test_collection.rs")
+        .expect("Failed to read actual output");
+    assert_eq!(expected_output, actual_output);
+}
 
-    // The owned struct forwards the attribute to ssz_derive.
-    assert!(
-        generated.contains(r#"#[ssz(with = "custom_codec")]"#),
-        "owned struct should carry the ssz(with) field attribute"
-    );
+/// Test that a `collection` class with more than a single list field is
+/// rejected.
+#[test]
+#[should_panic(
+    expected = "collection class `NotACollection` must be a container with exactly one List field"
+)]
+fn test_pragmas_collection_invalid() {
+    build_ssz_files(
+        &["test_collection_invalid.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_collection_invalid.rs",
+        ModuleGeneration::NestedModules,
+    )
+    .expect("This should panic due to a collection class with extra fields");
+}
 
-    // View layout expressions must size the overridden field via the
-    // with-module's encode fns.
-    assert!(
-        generated.contains("custom_codec::encode::ssz_fixed_len()"),
-        "view layout should size the overridden field via the with-module"
-    );
-    assert!(
-        generated.contains("custom_codec::encode::is_ssz_fixed_len()"),
-        "view layout should derive the overridden field's fixedness via the with-module"
-    );
+/// Test that `mix_in_aux` classes mix the root of their `aux` field into the
+/// root of the remaining fields.
+#[test]
+fn test_pragmas_mix_in_aux() {
+    build_ssz_files(
+        &["test_mix_in_aux.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_mix_in_aux.rs",
+        ModuleGeneration::NestedModules,
+    )
+    .expect("Failed to generate SSZ types with mix_in_aux pragmas");
 
-    // The overridden field is the only u64; its bare `Encode` impl must not
-    // appear anywhere in the layout expressions.
-    assert!(
-        !generated.contains("<u64 as ssz::Encode>::ssz_fixed_len()"),
-        "view layout must not bypass the with-module via the bare field type"
-    );
-    assert!(
-        !generated.contains("<u64 as ssz::Encode>::is_ssz_fixed_len()"),
-        "view layout must not bypass the with-module via the bare field type"
-    );
+    let expected_output = fs::read_to_string("tests/expected_output/test_mix_in_aux.rs")
+        .expect("Failed to read expected output");
+    let actual_output = fs::read_to_string("tests/output/test_mix_in_aux.rs")
+        .expect("Failed to read actual output");
+    assert_eq!(expected_output, actual_output);
+}
 
-    // The getter decodes the slice through the with-module to the owned
-    // field type, mirroring ssz_derive's Decode impl.
-    assert!(
-        generated.contains("custom_codec::decode::from_ssz_bytes(bytes)"),
-        "view getter should decode the overridden field via the with-module"
-    );
-    assert!(
-        generated.contains("pub fn wrapped(&self) -> Result<u64, ssz::DecodeError>"),
-        "with-field getter should return the owned field type"
-    );
+/// Test that a `mix_in_aux` class without an `aux` field is rejected.
+#[test]
+#[should_panic(
+    expected = "mix_in_aux class `NoAux` must be a container with exactly one aux field"
+)]
+fn test_pragmas_mix_in_aux_missing_field() {
+    build_ssz_files(
+        &["test_mix_in_aux_invalid.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_mix_in_aux_invalid.rs",
+        ModuleGeneration::NestedModules,
+    )
+    .expect("This should panic due to a mix_in_aux class without an aux field");
 }
 
-/// Test edge case: empty pragmas don't break codegen.
+/// Test that `optional_tail` fields are forwarded to the `Decode` derive.
 #[test]
-fn test_pragmas_empty() {
+fn test_pragmas_optional_tail() {
     build_ssz_files(
-        &["test_pragmas_empty.ssz"],
+        &["test_optional_tail.ssz"],
         "tests/input",
         &[],
-        "tests/output/test_pragmas_empty.rs",
+        "tests/output/test_optional_tail.rs",
         ModuleGeneration::NestedModules,
     )
-    .expect("Failed to generate SSZ types with empty pragmas");
+    .expect("Failed to generate SSZ types with optional_tail pragmas");
 
-    let expected_output = fs::read_to_string("tests/expected_output/test_pragmas_empty.rs")
+    let expected_output = fs::read_to_string("tests/expected_output/test_optional_tail.rs")
         .expect("Failed to read expected output");
-    let actual_output = fs::read_to_string("tests/output/test_pragmas_empty.rs")
+    let actual_output = fs::read_to_string("tests/output/test_optional_tail.rs")
         .expect("Failed to read actual output");
     assert_eq!(expected_output, actual_output);
 }
 
-/// Test that pragmas are correctly parsed and stored in the schema.
+/// Test that a required field after an `optional_tail` field is rejected.
 #[test]
-fn test_pragmas_schema_parsing() {
-    use std::collections::HashMap;
+#[should_panic(
+    expected = "optional_tail fields of `Misordered` must be the last fields, but `name` follows them"
+)]
+fn test_pragmas_optional_tail_misordered() {
+    build_ssz_files(
+        &["test_optional_tail_invalid.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_optional_tail_invalid.rs",
+        ModuleGeneration::NestedModules,
+    )
+    .expect("This should panic due to a required field after an optional_tail field");
+}
+
+/// Test that a `derive` pragma listing an ordering derive on a union is rejected.
+#[test]
+fn test_pragmas_derive_conflict() {
+    let err = build_ssz_files(
+        &["test_derive_pragma_conflict.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_derive_pragma_conflict.rs",
+        ModuleGeneration::NestedModules,
+    )
+    .expect_err("An ordering derive on a union should be rejected");
+
+    assert_eq!(
+        err.to_string(),
+        "derive pragma on `Ranked` lists `std::cmp::PartialOrd`, which unions can't derive"
+    );
+}
+
+/// Test that `newtype` classes get `AsRef`/`Borrow`/`Deref` impls to their
+/// single field.
+#[test]
+fn test_pragmas_newtype() {
+    build_ssz_files(
+        &["test_newtype.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_newtype.rs",
+        ModuleGeneration::NestedModules,
+    )
+    .expect("Failed to generate SSZ types with newtype pragmas");
+
+    let expected_output = fs::read_to_string("tests/expected_output/test_newtype.rs")
+        .expect("Failed to read expected output");
+    let actual_output =
+        fs::read_to_string("tests/output/test_newtype.rs").expect("Failed to read actual output");
+    assert_eq!(expected_output, actual_output);
+}
+
+/// Test that a `newtype` class with more than one field is rejected.
+#[test]
+#[should_panic(expected = "newtype class `NotANewtype` must be a container with exactly one field")]
+fn test_pragmas_newtype_invalid() {
+    build_ssz_files(
+        &["test_newtype_invalid.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_newtype_invalid.rs",
+        ModuleGeneration::NestedModules,
+    )
+    .expect("This should panic due to a newtype class with extra fields");
+}
+
+/// Test `unit` pragmas.
+#[test]
+fn test_pragmas_unit() {
+    build_ssz_files(
+        &["test_unit.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_unit.rs",
+        ModuleGeneration::NestedModules,
+    )
+    .expect("Failed to generate SSZ types with unit pragmas");
+
+    let expected_output = fs::read_to_string("tests/expected_output/test_unit.rs")
+        .expect("Failed to read expected output");
+    let actual_output =
+        fs::read_to_string("tests/output/test_unit.rs").expect("Failed to read actual output");
+    assert_eq!(expected_output, actual_output);
+}
+
+/// Test that a `unit` class over a non-integer field is rejected.
+#[test]
+#[should_panic(expected = "unit class `NotAUnit` must be a container with exactly one")]
+fn test_pragmas_unit_invalid() {
+    build_ssz_files(
+        &["test_unit_invalid.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_unit_invalid.rs",
+        ModuleGeneration::NestedModules,
+    )
+    .expect("This should panic due to a unit class over a byte vector");
+}
+
+/// Test that pragmas work with inheritance.
+#[test]
+fn test_pragmas_inheritance() {
+    build_ssz_files(
+        &["test_pragmas_inheritance.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_pragmas_inheritance.rs",
+        ModuleGeneration::NestedModules,
+    )
+    .expect("Failed to generate SSZ types with pragmas and inheritance");
+
+    let expected_output = fs::read_to_string("tests/expected_output/test_pragmas_inheritance.rs")
+        .expect("Failed to read expected output");
+    let actual_output = fs::read_to_string("tests/output/test_pragmas_inheritance.rs")
+        .expect("Failed to read actual output");
+    assert_eq!(expected_output, actual_output);
+}
+
+/// Test that a field-level `#[ssz(with = ...)]` pragma flows into the view
+/// layout: `ssz_derive` encodes such fields through `module::encode::*`
+/// instead of the field type's `Encode` impl, so the generated view layout
+/// expressions must call the same functions to agree with the owned encoding.
+#[test]
+fn test_view_layout_honors_field_ssz_with() {
+    build_ssz_files(
+        &["test_with_pragma.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_with_pragma.rs",
+        ModuleGeneration::NestedModules,
+    )
+    .expect("Failed to generate SSZ types with ssz(with) field pragma");
+
+    let generated = fs::read_to_string("tests/output/test_with_pragma.rs")
+        .expect("Failed to read generated output");
+
+    // The owned struct forwards the attribute to ssz_derive.
+    assert!(
+        generated.contains(r#"#[ssz(with = "custom_codec")]"#),
+        "owned struct should carry the ssz(with) field attribute"
+    );
+
+    // View layout expressions must size the overridden field via the
+    // with-module's encode fns.
+    assert!(
+        generated.contains("custom_codec::encode::ssz_fixed_len()"),
+        "view layout should size the overridden field via the with-module"
+    );
+    assert!(
+        generated.contains("custom_codec::encode::is_ssz_fixed_len()"),
+        "view layout should derive the overridden field's fixedness via the with-module"
+    );
+
+    // The overridden field is the only u64; its bare `Encode` impl must not
+    // appear anywhere in the layout expressions.
+    assert!(
+        !generated.contains("<u64 as ssz::Encode>::ssz_fixed_len()"),
+        "view layout must not bypass the with-module via the bare field type"
+    );
+    assert!(
+        !generated.contains("<u64 as ssz::Encode>::is_ssz_fixed_len()"),
+        "view layout must not bypass the with-module via the bare field type"
+    );
+
+    // The getter decodes the slice through the with-module to the owned
+    // field type, mirroring ssz_derive's Decode impl.
+    assert!(
+        generated.contains("custom_codec::decode::from_ssz_bytes(bytes)"),
+        "view getter should decode the overridden field via the with-module"
+    );
+    assert!(
+        generated.contains("pub fn wrapped(&self) -> Result<u64, ssz::DecodeError>"),
+        "with-field getter should return the owned field type"
+    );
+}
+
+/// Test edge case: empty pragmas don't break codegen.
+#[test]
+fn test_pragmas_empty() {
+    build_ssz_files(
+        &["test_pragmas_empty.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_pragmas_empty.rs",
+        ModuleGeneration::NestedModules,
+    )
+    .expect("Failed to generate SSZ types with empty pragmas");
+
+    let expected_output = fs::read_to_string("tests/expected_output/test_pragmas_empty.rs")
+        .expect("Failed to read expected output");
+    let actual_output = fs::read_to_string("tests/output/test_pragmas_empty.rs")
+        .expect("Failed to read actual output");
+    assert_eq!(expected_output, actual_output);
+}
+
+/// Test that pragmas are correctly parsed and stored in the schema.
+#[test]
+fn test_pragmas_schema_parsing() {
+    use std::collections::HashMap;
 
     use sizzle_parser::parse_str_schema;
 
@@ -1435,9 +1937,9 @@ fn test_duplicate_entry_point() {
 
 /// Test that circular imports between entry points cause an error.
 /// When A imports B and B imports A, the circular dependency should be detected
-/// and result in an UnknownImport error.
+/// and result in a CyclicImport error.
 #[test]
-#[should_panic(expected = "UnknownImport")]
+#[should_panic(expected = "CyclicImport")]
 fn test_circular_imports() {
     build_ssz_files(
         &["test_circular_a.ssz", "test_circular_b.ssz"],
@@ -2229,6 +2731,46 @@ fn test_union_empty_variant() {
     );
 }
 
+/// Test that doc comments on union arms document both the enum variant and
+/// its `as_selectorN` getter on the view.
+#[test]
+fn test_union_variant_doc_comments() {
+    build_ssz_files(
+        &["test_union_empty_variant.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_union_variant_doc_comments.rs",
+        ModuleGeneration::NestedModules,
+    )
+    .expect("Failed to generate SSZ types for union with documented variants");
+
+    let actual_output = fs::read_to_string("tests/output/test_union_variant_doc_comments.rs")
+        .expect("Failed to read actual output");
+    let lines: Vec<&str> = actual_output.lines().map(str::trim).collect();
+    let documented = |doc: &str| -> Vec<&str> {
+        lines
+            .windows(2)
+            .filter(|pair| pair[0] == doc)
+            .map(|pair| pair[1])
+            .collect()
+    };
+
+    assert_eq!(
+        documented("/// Empty variant (no data)"),
+        [
+            "Empty,",
+            "pub fn as_selector0(&self) -> Result<(), ssz::DecodeError> {"
+        ]
+    );
+    assert_eq!(
+        documented("/// Variant with data"),
+        [
+            "Data(DataVariant),",
+            "pub fn as_selector1(&self) -> Result<DataRef<'_>, ssz::DecodeError> {"
+        ]
+    );
+}
+
 // --- Section 5A: Unit tests for fully qualified derive path support ---
 
 #[test]
@@ -2250,95 +2792,360 @@ fn test_pragma_derive_attr_emits_qualified_paths() {
 }
 
 #[test]
-fn test_owned_derive_keeps_namespace_distinct_derives() {
-    let cfg = ssz_codegen::derive_config::DeriveConfig {
-        default: vec!["serde::Serialize".into(), "rkyv::Serialize".into()],
-        types: std::collections::HashMap::new(),
-    };
+fn test_serde_config() {
+    build_ssz_files_with_derives(
+        &["test_serde_config.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_serde_config.rs",
+        ModuleGeneration::NestedModules,
+        Some(ssz_codegen::derive_config::DeriveConfig::default_defaults().with_serde(true)),
+        None,
+    )
+    .expect("Failed to generate SSZ types");
 
-    let attr = normalized_tokens(cfg.owned_derive_attr("TestType"));
-    assert!(attr.contains("serde::Serialize"));
-    assert!(attr.contains("rkyv::Serialize"));
+    let expected_output = fs::read_to_string("tests/expected_output/test_serde_config.rs")
+        .expect("Failed to read expected output");
+    let actual_output = fs::read_to_string("tests/output/test_serde_config.rs")
+        .expect("Failed to read actual output");
+    assert_eq!(expected_output, actual_output);
 }
 
 #[test]
-fn test_owned_derive_dedups_identical_full_paths() {
-    let cfg = ssz_codegen::derive_config::DeriveConfig {
-        default: vec!["serde::Serialize".into(), "serde::Serialize".into()],
-        types: std::collections::HashMap::new(),
-    };
-
-    let attr = normalized_tokens(cfg.owned_derive_attr("TestType"));
-    assert_eq!(attr.matches("serde::Serialize").count(), 1);
+fn test_serde_config_from_toml() {
+    let cfg = ssz_codegen::derive_config::DeriveConfig::from_toml_str("[derives]\nserde = true\n")
+        .expect("valid toml");
+    assert!(cfg.serde);
+    assert!(!ssz_codegen::derive_config::DeriveConfig::default_defaults().serde);
 }
 
 #[test]
-fn test_view_derive_filters_qualified_internal_derives() {
-    let cfg = ssz_codegen::derive_config::DeriveConfig {
-        default: vec![
-            "ssz_derive::Encode".into(),
-            "ssz_derive::Decode".into(),
-            "tree_hash_derive::TreeHash".into(),
-            "serde::Serialize".into(),
-        ],
-        types: std::collections::HashMap::new(),
-    };
+fn test_default_impl() {
+    build_ssz_files_with_derives(
+        &["test_default_impl.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_default_impl.rs",
+        ModuleGeneration::NestedModules,
+        Some(ssz_codegen::derive_config::DeriveConfig::default_defaults().with_default(true)),
+        None,
+    )
+    .expect("Failed to generate SSZ types");
 
-    let attr = normalized_tokens(cfg.view_derive_attr("TestType"));
-    assert!(
-        !attr.contains("ssz_derive::Encode"),
-        "ssz_derive::Encode should be filtered: {attr}"
-    );
-    assert!(
-        !attr.contains("ssz_derive::Decode"),
-        "ssz_derive::Decode should be filtered: {attr}"
-    );
-    assert!(
-        !attr.contains("tree_hash_derive::TreeHash"),
-        "tree_hash_derive::TreeHash should be filtered: {attr}"
-    );
-    assert!(
-        attr.contains("serde::Serialize"),
-        "serde::Serialize should survive filtering: {attr}"
-    );
+    let expected_output = fs::read_to_string("tests/expected_output/test_default_impl.rs")
+        .expect("Failed to read expected output");
+    let actual_output = fs::read_to_string("tests/output/test_default_impl.rs")
+        .expect("Failed to read actual output");
+    assert_eq!(expected_output, actual_output);
 }
 
 #[test]
-fn test_container_filter_removes_qualified_ordering_derives() {
-    let cfg = ssz_codegen::derive_config::DeriveConfig {
-        default: vec![
-            "std::cmp::Ord".into(),
-            "std::cmp::PartialOrd".into(),
-            "core::cmp::Ord".into(),
-            "core::cmp::PartialOrd".into(),
-            "serde::Serialize".into(),
-        ],
-        types: std::collections::HashMap::new(),
-    };
-
-    let attr = normalized_tokens(cfg.owned_derive_attr_with_pragmas_filtered(
-        "TestType",
-        &ssz_codegen::pragma::ParsedPragma::default(),
-        true,
-    ));
-
-    assert!(!attr.contains("std::cmp::Ord"));
-    assert!(!attr.contains("std::cmp::PartialOrd"));
-    assert!(!attr.contains("core::cmp::Ord"));
-    assert!(!attr.contains("core::cmp::PartialOrd"));
-    assert!(attr.contains("serde::Serialize"));
-}
+fn test_validate_and_to_owned() {
+    build_ssz_files_with_derives(
+        &["test_validate_and_to_owned.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_validate_and_to_owned.rs",
+        ModuleGeneration::NestedModules,
+        Some(ssz_codegen::derive_config::DeriveConfig::default_defaults().with_default(true)),
+        None,
+    )
+    .expect("Failed to generate SSZ types");
 
-#[test]
-#[should_panic(expected = "must specify a crate")]
-fn test_unqualified_pragma_derive_panics() {
-    ssz_codegen::pragma::ParsedPragma::parse(&["derive: Copy".to_string()]);
+    let expected_output = fs::read_to_string("tests/expected_output/test_validate_and_to_owned.rs")
+        .expect("Failed to read expected output");
+    let actual_output = fs::read_to_string("tests/output/test_validate_and_to_owned.rs")
+        .expect("Failed to read actual output");
+    assert_eq!(expected_output, actual_output);
 }
 
 #[test]
-fn test_nested_fixed_container_views() {
+fn test_validate_deep() {
     build_ssz_files(
-        &["test_nested_fixed_container.ssz"],
+        &["test_validate_deep.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_validate_deep.rs",
+        ModuleGeneration::NestedModules,
+    )
+    .expect("Failed to generate SSZ types");
+
+    let expected_output = fs::read_to_string("tests/expected_output/test_validate_deep.rs")
+        .expect("Failed to read expected output");
+    let actual_output = fs::read_to_string("tests/output/test_validate_deep.rs")
+        .expect("Failed to read actual output");
+    assert_eq!(expected_output, actual_output);
+}
+
+#[test]
+fn test_recursive_union() {
+    build_ssz_files(
+        &["test_recursive_union.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_recursive_union.rs",
+        ModuleGeneration::NestedModules,
+    )
+    .expect("Failed to generate SSZ types");
+
+    let expected_output = fs::read_to_string("tests/expected_output/test_recursive_union.rs")
+        .expect("Failed to read expected output");
+    let actual_output = fs::read_to_string("tests/output/test_recursive_union.rs")
+        .expect("Failed to read actual output");
+    assert_eq!(expected_output, actual_output);
+}
+
+#[test]
+fn test_plain_format() {
+    let files = read_entrypoint_ssz(&["test_vector_view_iter.ssz"], "tests/input")
+        .expect("Failed to read SSZ files");
+    let (parsing_order, schema_map) = parse_str_schema(&files, &[]).expect("Failed to parse");
+    let rust_code = schema_map_to_rust_code(
+        &parsing_order,
+        &schema_map,
+        ModuleGeneration::NestedModules,
+        &DeriveConfig::default_defaults(),
+        &files.keys().cloned().collect::<HashSet<_>>(),
+    );
+    fs::create_dir_all("tests/output").expect("Failed to create output directory");
+    fs::write(
+        "tests/output/test_plain_format.rs",
+        format_plain(&rust_code),
+    )
+    .expect("Failed to write output");
+
+    let expected_output = fs::read_to_string("tests/expected_output/test_plain_format.rs")
+        .expect("Failed to read expected output");
+    let actual_output = fs::read_to_string("tests/output/test_plain_format.rs")
+        .expect("Failed to read actual output");
+    assert_eq!(expected_output, actual_output);
+}
+
+#[test]
+fn test_union_list_hash() {
+    build_ssz_files(
+        &["test_union_list_hash.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_union_list_hash.rs",
+        ModuleGeneration::NestedModules,
+    )
+    .expect("Failed to generate SSZ types");
+
+    let expected_output = fs::read_to_string("tests/expected_output/test_union_list_hash.rs")
+        .expect("Failed to read expected output");
+    let actual_output = fs::read_to_string("tests/output/test_union_list_hash.rs")
+        .expect("Failed to read actual output");
+    assert_eq!(expected_output, actual_output);
+}
+
+#[test]
+fn test_union_list_view() {
+    build_ssz_files(
+        &["test_union_list_view.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_union_list_view.rs",
+        ModuleGeneration::NestedModules,
+    )
+    .expect("Failed to generate SSZ types");
+
+    let expected_output = fs::read_to_string("tests/expected_output/test_union_list_view.rs")
+        .expect("Failed to read expected output");
+    let actual_output = fs::read_to_string("tests/output/test_union_list_view.rs")
+        .expect("Failed to read actual output");
+    assert_eq!(expected_output, actual_output);
+}
+
+#[test]
+fn test_field_offsets() {
+    build_ssz_files(
+        &["test_field_offsets.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_field_offsets.rs",
+        ModuleGeneration::NestedModules,
+    )
+    .expect("Failed to generate SSZ types");
+
+    let expected_output = fs::read_to_string("tests/expected_output/test_field_offsets.rs")
+        .expect("Failed to read expected output");
+    let actual_output = fs::read_to_string("tests/output/test_field_offsets.rs")
+        .expect("Failed to read actual output");
+    assert_eq!(expected_output, actual_output);
+}
+
+#[test]
+fn test_field_info() {
+    build_ssz_files_with_derives(
+        &["test_field_info.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_field_info.rs",
+        ModuleGeneration::NestedModules,
+        Some(ssz_codegen::derive_config::DeriveConfig::default_defaults().with_field_info(true)),
+        None,
+    )
+    .expect("Failed to generate SSZ types");
+
+    let expected_output = fs::read_to_string("tests/expected_output/test_field_info.rs")
+        .expect("Failed to read expected output");
+    let actual_output = fs::read_to_string("tests/output/test_field_info.rs")
+        .expect("Failed to read actual output");
+    assert_eq!(expected_output, actual_output);
+}
+
+#[test]
+fn test_arbitrary_config() {
+    build_ssz_files_with_derives(
+        &["test_arbitrary.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_arbitrary.rs",
+        ModuleGeneration::NestedModules,
+        Some(ssz_codegen::derive_config::DeriveConfig::default_defaults().with_arbitrary(true)),
+        None,
+    )
+    .expect("Failed to generate SSZ types");
+
+    let expected_output = fs::read_to_string("tests/expected_output/test_arbitrary.rs")
+        .expect("Failed to read expected output");
+    let actual_output =
+        fs::read_to_string("tests/output/test_arbitrary.rs").expect("Failed to read actual output");
+    assert_eq!(expected_output, actual_output);
+}
+
+#[test]
+fn test_union_hash() {
+    build_ssz_files(
+        &["test_union_hash.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_union_hash.rs",
+        ModuleGeneration::NestedModules,
+    )
+    .expect("Failed to generate SSZ types");
+
+    let expected_output = fs::read_to_string("tests/expected_output/test_union_hash.rs")
+        .expect("Failed to read expected output");
+    let actual_output = fs::read_to_string("tests/output/test_union_hash.rs")
+        .expect("Failed to read actual output");
+    assert_eq!(expected_output, actual_output);
+}
+
+#[test]
+fn test_vector_view_iter() {
+    build_ssz_files(
+        &["test_vector_view_iter.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_vector_view_iter.rs",
+        ModuleGeneration::NestedModules,
+    )
+    .expect("Failed to generate SSZ types");
+
+    let expected_output = fs::read_to_string("tests/expected_output/test_vector_view_iter.rs")
+        .expect("Failed to read expected output");
+    let actual_output = fs::read_to_string("tests/output/test_vector_view_iter.rs")
+        .expect("Failed to read actual output");
+    assert_eq!(expected_output, actual_output);
+}
+
+#[test]
+fn test_owned_derive_keeps_namespace_distinct_derives() {
+    let cfg = ssz_codegen::derive_config::DeriveConfig::default()
+        .with_derives(&["serde::Serialize", "rkyv::Serialize"]);
+
+    let attr = normalized_tokens(cfg.owned_derive_attr("TestType"));
+    assert!(attr.contains("serde::Serialize"));
+    assert!(attr.contains("rkyv::Serialize"));
+}
+
+#[test]
+fn test_type_derives_replace_defaults() {
+    let cfg = ssz_codegen::derive_config::DeriveConfig::default()
+        .with_derives(&["std::fmt::Debug"])
+        .with_type_derives("Special", &["std::hash::Hash"]);
+
+    let special = normalized_tokens(cfg.owned_derive_attr("Special"));
+    assert!(special.contains("std::hash::Hash"));
+    assert!(!special.contains("std::fmt::Debug"));
+
+    let other = normalized_tokens(cfg.owned_derive_attr("Other"));
+    assert!(other.contains("std::fmt::Debug"));
+    assert!(!other.contains("std::hash::Hash"));
+}
+
+#[test]
+fn test_owned_derive_dedups_identical_full_paths() {
+    let cfg = ssz_codegen::derive_config::DeriveConfig::default()
+        .with_derives(&["serde::Serialize", "serde::Serialize"]);
+
+    let attr = normalized_tokens(cfg.owned_derive_attr("TestType"));
+    assert_eq!(attr.matches("serde::Serialize").count(), 1);
+}
+
+#[test]
+fn test_view_derive_filters_qualified_internal_derives() {
+    let cfg = ssz_codegen::derive_config::DeriveConfig::default().with_derives(&[
+        "ssz_derive::Encode",
+        "ssz_derive::Decode",
+        "tree_hash_derive::TreeHash",
+        "serde::Serialize",
+    ]);
+
+    let attr = normalized_tokens(cfg.view_derive_attr("TestType"));
+    assert!(
+        !attr.contains("ssz_derive::Encode"),
+        "ssz_derive::Encode should be filtered: {attr}"
+    );
+    assert!(
+        !attr.contains("ssz_derive::Decode"),
+        "ssz_derive::Decode should be filtered: {attr}"
+    );
+    assert!(
+        !attr.contains("tree_hash_derive::TreeHash"),
+        "tree_hash_derive::TreeHash should be filtered: {attr}"
+    );
+    assert!(
+        attr.contains("serde::Serialize"),
+        "serde::Serialize should survive filtering: {attr}"
+    );
+}
+
+#[test]
+fn test_container_filter_removes_qualified_ordering_derives() {
+    let cfg = ssz_codegen::derive_config::DeriveConfig::default().with_derives(&[
+        "std::cmp::Ord",
+        "std::cmp::PartialOrd",
+        "core::cmp::Ord",
+        "core::cmp::PartialOrd",
+        "serde::Serialize",
+    ]);
+
+    let attr = normalized_tokens(cfg.owned_derive_attr_with_pragmas_filtered(
+        "TestType",
+        &ssz_codegen::pragma::ParsedPragma::default(),
+        true,
+    ));
+
+    assert!(!attr.contains("std::cmp::Ord"));
+    assert!(!attr.contains("std::cmp::PartialOrd"));
+    assert!(!attr.contains("core::cmp::Ord"));
+    assert!(!attr.contains("core::cmp::PartialOrd"));
+    assert!(attr.contains("serde::Serialize"));
+}
+
+#[test]
+#[should_panic(expected = "must specify a crate")]
+fn test_unqualified_pragma_derive_panics() {
+    ssz_codegen::pragma::ParsedPragma::parse(&["derive: Copy".to_string()]);
+}
+
+#[test]
+fn test_nested_fixed_container_views() {
+    build_ssz_files(
+        &["test_nested_fixed_container.ssz"],
         "tests/input",
         &[],
         "tests/output/test_nested_fixed_container.rs",
@@ -2353,3 +3160,376 @@ fn test_nested_fixed_container_views() {
         .expect("Failed to read actual output");
     assert_eq!(expected_output, actual_output);
 }
+
+/// Test that the schema hash only changes when the schema itself does.
+#[test]
+fn test_schema_hash() {
+    use std::collections::HashMap;
+
+    use sizzle_parser::parse_str_schema;
+    use ssz_codegen::codegen::schema_hash;
+
+    let hash = |input: &str| {
+        let files = HashMap::from([(std::path::Path::new("").to_path_buf(), input.to_string())]);
+        let (_, schema_map) = parse_str_schema(&files, &[]).expect("Failed to parse schema");
+        let path = schema_map.keys().next().expect("Should have one schema");
+        schema_hash(path, &schema_map)
+    };
+
+    let original = hash(
+        r"
+MAX_LEN = 16
+
+class Point(Container):
+    x: uint32
+    y: List[uint8, MAX_LEN]
+",
+    );
+
+    // Comments, doc comments and pragmas are not part of the schema.
+    let reformatted = hash(
+        r"
+# Maximum length.
+MAX_LEN = 16
+### A point.
+#~# derive: serde::Serialize
+class Point(Container):
+    x: uint32
+    y: List[uint8, MAX_LEN]
+",
+    );
+    assert_eq!(original, reformatted);
+
+    let modified = hash(
+        r"
+MAX_LEN = 17
+
+class Point(Container):
+    x: uint32
+    y: List[uint8, MAX_LEN]
+",
+    );
+    assert_ne!(original, modified);
+
+    let retyped = hash(
+        r"
+MAX_LEN = 16
+
+class Point(Container):
+    x: uint64
+    y: List[uint8, MAX_LEN]
+",
+    );
+    assert_ne!(original, retyped);
+
+    // Pragmas that change hashing or decoding are part of the schema.
+    let tail = hash(
+        r"
+MAX_LEN = 16
+
+class Point(Container):
+    x: uint32
+    #~# optional_tail
+    y: List[uint8, MAX_LEN]
+",
+    );
+    assert_ne!(original, tail);
+}
+
+/// Test that changing an imported definition changes the hash of the importing schema.
+#[test]
+fn test_schema_hash_imported_definition() {
+    use std::{collections::HashMap, path::PathBuf};
+
+    use sizzle_parser::parse_str_schema;
+    use ssz_codegen::codegen::schema_hash;
+
+    let hash = |common: &str| {
+        let files = HashMap::from([
+            (PathBuf::from("common"), common.to_string()),
+            (
+                PathBuf::from("main"),
+                "import common\n\nclass Line(Container):\n    start: common.Point\n".to_string(),
+            ),
+        ]);
+        let (_, schema_map) = parse_str_schema(&files, &[]).expect("Failed to parse schema");
+        schema_hash(&PathBuf::from("main"), &schema_map)
+    };
+
+    let original = hash("class Point(Container):\n    x: uint32\n");
+    let retyped = hash("class Point(Container):\n    x: uint64\n");
+    assert_ne!(original, retyped);
+}
+
+/// Test that the `feature` pragma gates a class, its view and every companion
+/// impl (including unions over the class) behind `#[cfg(feature = ...)]`.
+#[test]
+fn test_feature_gate() {
+    build_ssz_files(
+        &["test_feature_gate.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_feature_gate.rs",
+        ModuleGeneration::NestedModules,
+    )
+    .expect("Failed to generate SSZ types with feature pragmas");
+
+    let expected_output = fs::read_to_string("tests/expected_output/test_feature_gate.rs")
+        .expect("Failed to read expected output");
+    let actual_output = fs::read_to_string("tests/output/test_feature_gate.rs")
+        .expect("Failed to read actual output");
+    assert_eq!(expected_output, actual_output);
+}
+
+#[test]
+#[should_panic(
+    expected = "type `Block` references `AltairBody`, which is gated behind feature `altair`; add `#~# feature: altair` to `Block`"
+)]
+fn test_feature_gate_ungated_reference() {
+    build_ssz_files(
+        &["test_feature_gate_ungated.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_feature_gate_ungated.rs",
+        ModuleGeneration::NestedModules,
+    )
+    .expect("This should panic due to an ungated reference to a gated type");
+}
+
+#[test]
+fn test_crate_paths() {
+    let derive_cfg = OVERRIDABLE_CRATES.iter().fold(
+        ssz_codegen::derive_config::DeriveConfig::default_defaults(),
+        |cfg, krate| cfg.with_crate_path(krate, &format!("crate::reexports::{krate}")),
+    );
+    build_ssz_files_with_derives(
+        &["test_alias_hops.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_crate_paths.rs",
+        ModuleGeneration::NestedModules,
+        Some(derive_cfg),
+        None,
+    )
+    .expect("Failed to generate SSZ types");
+
+    let expected_output = fs::read_to_string("tests/expected_output/test_crate_paths.rs")
+        .expect("Failed to read expected output");
+    let actual_output = fs::read_to_string("tests/output/test_crate_paths.rs")
+        .expect("Failed to read actual output");
+    assert_eq!(expected_output, actual_output);
+
+    assert!(actual_output.contains("use crate::reexports::ssz_types::*;"));
+    assert!(actual_output.contains("use crate::reexports::ssz as ssz;"));
+    assert!(actual_output.contains("impl crate::reexports::tree_hash::TreeHash for AliasHops"));
+    for krate in OVERRIDABLE_CRATES {
+        assert!(
+            !actual_output.contains(&format!("use {krate}::")),
+            "`{krate}` imported directly"
+        );
+    }
+}
+
+#[test]
+#[should_panic(expected = "crate path override for `serde`")]
+fn test_crate_paths_unknown_crate() {
+    let _ = ssz_codegen::derive_config::DeriveConfig::default_defaults()
+        .with_crate_path("serde", "my_crate::serde");
+}
+
+#[test]
+fn test_uint_n() {
+    build_ssz_files(
+        &["test_uint_n.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_uint_n.rs",
+        ModuleGeneration::NestedModules,
+    )
+    .expect("Failed to generate SSZ types");
+
+    let expected_output = fs::read_to_string("tests/expected_output/test_uint_n.rs")
+        .expect("Failed to read expected output");
+    let actual_output =
+        fs::read_to_string("tests/output/test_uint_n.rs").expect("Failed to read actual output");
+    assert_eq!(expected_output, actual_output);
+}
+
+#[test]
+fn test_union_from() {
+    build_ssz_files(
+        &["test_union_from.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_union_from.rs",
+        ModuleGeneration::NestedModules,
+    )
+    .expect("Failed to generate SSZ types");
+
+    let expected_output = fs::read_to_string("tests/expected_output/test_union_from.rs")
+        .expect("Failed to read expected output");
+    let actual_output = fs::read_to_string("tests/output/test_union_from.rs")
+        .expect("Failed to read actual output");
+    assert_eq!(expected_output, actual_output);
+}
+
+#[test]
+fn test_union_duplicate() {
+    build_ssz_files(
+        &["test_union_duplicate.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_union_duplicate.rs",
+        ModuleGeneration::NestedModules,
+    )
+    .expect("Failed to generate SSZ types");
+
+    let expected_output = fs::read_to_string("tests/expected_output/test_union_duplicate.rs")
+        .expect("Failed to read expected output");
+    let actual_output = fs::read_to_string("tests/output/test_union_duplicate.rs")
+        .expect("Failed to read actual output");
+    assert_eq!(expected_output, actual_output);
+}
+
+#[test]
+fn test_const_array() {
+    build_ssz_files(
+        &["test_const_array.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_const_array.rs",
+        ModuleGeneration::NestedModules,
+    )
+    .expect("Failed to generate SSZ types");
+
+    let expected_output = fs::read_to_string("tests/expected_output/test_const_array.rs")
+        .expect("Failed to read expected output");
+    let actual_output = fs::read_to_string("tests/output/test_const_array.rs")
+        .expect("Failed to read actual output");
+    assert_eq!(expected_output, actual_output);
+}
+
+#[test]
+fn test_const_bytes() {
+    build_ssz_files(
+        &["test_const_bytes.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_const_bytes.rs",
+        ModuleGeneration::NestedModules,
+    )
+    .expect("Failed to generate SSZ types");
+
+    let expected_output = fs::read_to_string("tests/expected_output/test_const_bytes.rs")
+        .expect("Failed to read expected output");
+    let actual_output = fs::read_to_string("tests/output/test_const_bytes.rs")
+        .expect("Failed to read actual output");
+    assert_eq!(expected_output, actual_output);
+}
+
+#[test]
+fn test_hex_debug() {
+    build_ssz_files_with_derives(
+        &["test_hex_debug.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_hex_debug.rs",
+        ModuleGeneration::NestedModules,
+        Some(ssz_codegen::derive_config::DeriveConfig::default_defaults().with_hex_debug(true)),
+        None,
+    )
+    .expect("Failed to generate SSZ types");
+
+    let expected_output = fs::read_to_string("tests/expected_output/test_hex_debug.rs")
+        .expect("Failed to read expected output");
+    let actual_output =
+        fs::read_to_string("tests/output/test_hex_debug.rs").expect("Failed to read actual output");
+    assert_eq!(expected_output, actual_output);
+}
+
+#[test]
+fn test_pooled_tree_hash() {
+    build_ssz_files_with_derives(
+        &["test_pooled_tree_hash.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_pooled_tree_hash.rs",
+        ModuleGeneration::NestedModules,
+        Some(
+            ssz_codegen::derive_config::DeriveConfig::default_defaults()
+                .with_pooled_tree_hash(true),
+        ),
+        None,
+    )
+    .expect("Failed to generate SSZ types");
+
+    let expected_output = fs::read_to_string("tests/expected_output/test_pooled_tree_hash.rs")
+        .expect("Failed to read expected output");
+    let actual_output = fs::read_to_string("tests/output/test_pooled_tree_hash.rs")
+        .expect("Failed to read actual output");
+    assert_eq!(expected_output, actual_output);
+}
+
+#[test]
+fn test_root_eq() {
+    build_ssz_files_with_derives(
+        &["test_root_eq.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_root_eq.rs",
+        ModuleGeneration::NestedModules,
+        Some(ssz_codegen::derive_config::DeriveConfig::default_defaults().with_root_eq(true)),
+        None,
+    )
+    .expect("Failed to generate SSZ types");
+
+    let expected_output = fs::read_to_string("tests/expected_output/test_root_eq.rs")
+        .expect("Failed to read expected output");
+    let actual_output =
+        fs::read_to_string("tests/output/test_root_eq.rs").expect("Failed to read actual output");
+    assert_eq!(expected_output, actual_output);
+
+    let cfg =
+        ssz_codegen::derive_config::DeriveConfig::from_toml_str("[derives]\nroot_eq = true\n")
+            .expect("valid toml");
+    assert!(cfg.root_eq);
+}
+
+#[test]
+fn test_diff() {
+    build_ssz_files_with_derives(
+        &["test_diff.ssz"],
+        "tests/input",
+        &[],
+        "tests/output/test_diff.rs",
+        ModuleGeneration::NestedModules,
+        Some(ssz_codegen::derive_config::DeriveConfig::default_defaults().with_diff(true)),
+        None,
+    )
+    .expect("Failed to generate SSZ types");
+
+    let expected_output = fs::read_to_string("tests/expected_output/test_diff.rs")
+        .expect("Failed to read expected output");
+    let actual_output =
+        fs::read_to_string("tests/output/test_diff.rs").expect("Failed to read actual output");
+    assert_eq!(expected_output, actual_output);
+
+    let cfg = ssz_codegen::derive_config::DeriveConfig::from_toml_str("[derives]\ndiff = true\n")
+        .expect("valid toml");
+    assert!(cfg.diff);
+}
+
+#[test]
+fn test_hex_debug_from_toml() {
+    let cfg =
+        ssz_codegen::derive_config::DeriveConfig::from_toml_str("[derives]\nhex_debug = true\n")
+            .expect("valid toml");
+    assert!(cfg.hex_debug);
+    assert!(!ssz_codegen::derive_config::DeriveConfig::default_defaults().hex_debug);
+
+    let cfg = ssz_codegen::derive_config::DeriveConfig::from_toml_str(
+        "[derives]\npooled_tree_hash = true\n",
+    )
+    .expect("valid toml");
+    assert!(cfg.pooled_tree_hash);
+}
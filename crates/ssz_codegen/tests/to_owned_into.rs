@@ -0,0 +1,81 @@
+//! `to_owned_into` produces the same value as `to_owned` while reusing the
+//! allocations of the output's list fields, and reports invalid nested data
+//! as an error without touching the output.
+
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+include!("expected_output/test_to_owned_into.rs");
+
+use ssz::{Encode, view::DecodeView};
+use ssz_types::{FixedBytes, FixedVector, VariableList};
+use tests::input::test_to_owned_into::{Batch, BatchRef, Item};
+
+fn batch(index: u32, len: usize) -> Batch {
+    Batch {
+        index,
+        payload: VariableList::new(vec![index as u8; len * 4]).unwrap(),
+        values: VariableList::new((0..len as u64).collect()).unwrap(),
+        items: VariableList::new(
+            (0..len as u64)
+                .map(|id| Item {
+                    id,
+                    flag: id % 2 == 0,
+                })
+                .collect(),
+        )
+        .unwrap(),
+        roots: FixedVector::new(vec![index as u64; 4]).unwrap(),
+        tag: FixedBytes([index as u8; 32]),
+        parent: Item {
+            id: index as u64,
+            flag: true,
+        },
+    }
+}
+
+#[test]
+fn to_owned_into_matches_to_owned() {
+    let mut out = batch(0, 0);
+
+    // Shrinking and growing lists must both leave `out` equal to a fresh conversion.
+    for (index, len) in [(1, 10), (2, 3), (3, 0), (4, 16)] {
+        let bytes = batch(index, len).as_ssz_bytes();
+        let view = BatchRef::from_ssz_bytes(&bytes).expect("view");
+
+        view.to_owned_into(&mut out).expect("to_owned_into");
+        assert_eq!(out, view.to_owned());
+    }
+}
+
+#[test]
+fn to_owned_into_reuses_list_storage() {
+    let mut out = batch(1, 16);
+    let payload_ptr = out.payload.as_ptr();
+    let values_ptr = out.values.as_ptr();
+
+    let bytes = batch(2, 8).as_ssz_bytes();
+    let view = BatchRef::from_ssz_bytes(&bytes).expect("view");
+    view.to_owned_into(&mut out).expect("to_owned_into");
+
+    assert_eq!(out, batch(2, 8));
+    assert_eq!(out.payload.as_ptr(), payload_ptr);
+    assert_eq!(out.values.as_ptr(), values_ptr);
+}
+
+#[test]
+fn to_owned_into_rejects_invalid_nested_field() {
+    let mut out = batch(1, 4);
+    let mut bytes = batch(2, 4).as_ssz_bytes();
+    // `parent.flag` follows the index, three offsets, `roots`, `tag` and `parent.id`.
+    bytes[4 + 3 * 4 + 32 + 32 + 8] = 2;
+    let view = BatchRef::from_ssz_bytes(&bytes).expect("view");
+
+    assert!(view.to_owned_into(&mut out).is_err());
+    assert_eq!(out, batch(1, 4));
+}
@@ -0,0 +1,94 @@
+//! `uint24` and `uint48` fields map to `UintN`, encoding as 3 and 6
+//! little-endian bytes and hashing as a right-padded chunk.
+
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+include!("expected_output/test_uint_n.rs");
+
+use ssz::{Decode, Encode, view::DecodeView};
+use ssz_types::{UintN, VariableList};
+use tests::input::test_uint_n::{Compact, CompactRef};
+use tree_hash::{Sha256Hasher, TreeHash};
+
+fn sample() -> Compact {
+    Compact {
+        height: UintN::new(0x01_0203).expect("fits in 24 bits"),
+        timestamp: UintN::new(0x0a0b_0c0d_0e0f).expect("fits in 48 bits"),
+        flags: 7,
+        recent: VariableList::new(vec![UintN::MAX, UintN::ZERO]).expect("within bound"),
+    }
+}
+
+#[test]
+fn encodes_odd_widths_little_endian() {
+    let bytes = sample().as_ssz_bytes();
+    // 3 + 6 + 1 fixed bytes, then the list offset.
+    assert_eq!(&bytes[..3], &[0x03, 0x02, 0x01]);
+    assert_eq!(&bytes[3..9], &[0x0f, 0x0e, 0x0d, 0x0c, 0x0b, 0x0a]);
+    assert_eq!(bytes[9], 7);
+    assert_eq!(&bytes[10..14], &14u32.to_le_bytes());
+    assert_eq!(&bytes[14..], &[0xff, 0xff, 0xff, 0, 0, 0]);
+    assert_eq!(Compact::from_ssz_bytes(&bytes), Ok(sample()));
+}
+
+#[test]
+fn view_reads_odd_widths() {
+    let bytes = sample().as_ssz_bytes();
+    let view = CompactRef::from_ssz_bytes(&bytes).expect("view decode");
+    assert_eq!(view.height().expect("height").get(), 0x01_0203);
+    assert_eq!(view.timestamp().expect("timestamp").get(), 0x0a0b_0c0d_0e0f);
+    let recent: Vec<_> = view
+        .recent()
+        .expect("recent")
+        .iter()
+        .map(|item| item.expect("item"))
+        .collect();
+    assert_eq!(recent, vec![UintN::MAX, UintN::ZERO]);
+    assert_eq!(view.to_owned(), sample());
+}
+
+#[test]
+fn truncated_odd_width_fails_to_decode() {
+    let mut bytes = sample().as_ssz_bytes();
+    bytes.truncate(8);
+    assert!(Compact::from_ssz_bytes(&bytes).is_err());
+}
+
+#[test]
+fn tree_hash_pads_odd_widths_to_a_chunk() {
+    let value = sample();
+    let mut height = [0u8; 32];
+    height[..3].copy_from_slice(&[0x03, 0x02, 0x01]);
+    let mut timestamp = [0u8; 32];
+    timestamp[..6].copy_from_slice(&[0x0f, 0x0e, 0x0d, 0x0c, 0x0b, 0x0a]);
+    assert_eq!(
+        value.height.tree_hash_root::<Sha256Hasher>().as_ref(),
+        &height
+    );
+    assert_eq!(
+        value.timestamp.tree_hash_root::<Sha256Hasher>().as_ref(),
+        &timestamp
+    );
+
+    let expected = tree_hash::merkle_root_with_hasher::<Sha256Hasher>(
+        &[
+            height.as_slice(),
+            timestamp.as_slice(),
+            value.flags.tree_hash_root::<Sha256Hasher>().as_ref(),
+            value.recent.tree_hash_root::<Sha256Hasher>().as_ref(),
+        ]
+        .concat(),
+        4,
+    );
+    assert_eq!(value.tree_hash_root::<Sha256Hasher>(), expected);
+
+    let bytes = value.as_ssz_bytes();
+    let view = CompactRef::from_ssz_bytes(&bytes).expect("view decode");
+    assert_eq!(view.tree_hash_root::<Sha256Hasher>(), expected);
+}
@@ -0,0 +1,33 @@
+//! Owned union enums implement `Display`, naming the union and the active
+//! schema member followed by the payload's `Debug` form.
+
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+include!("expected_output/test_union_list_hash.rs");
+
+use tests::input::test_union_list_hash::{Pair, Token};
+
+#[test]
+fn unit_member() {
+    assert_eq!(Token::Empty.to_string(), "Token::Empty");
+}
+
+#[test]
+fn basic_member() {
+    assert_eq!(Token::Amount(42).to_string(), "Token::Amount(42)");
+}
+
+#[test]
+fn container_member() {
+    let token = Token::Pair(Pair { a: 1, b: 2 });
+
+    let shown = token.to_string();
+    assert!(shown.starts_with("Token::Pair("), "{shown}");
+    assert_eq!(shown, format!("Token::Pair({:?})", Pair { a: 1, b: 2 }));
+}
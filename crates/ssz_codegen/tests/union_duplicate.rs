@@ -0,0 +1,67 @@
+//! Union members of the same type get distinct variants, suffixed with their
+//! selector (or the next free number when that name is taken), and round-trip
+//! through their own selector.
+
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+include!("expected_output/test_union_duplicate.rs");
+
+use ssz::{Decode, Encode, view::DecodeView};
+use tests::input::test_union_duplicate::{
+    Account, AccountRef, Balance, BalanceRef, Checkpoint, Finality, Ledger,
+};
+use tree_hash::{Sha256Hasher, TreeHash};
+
+#[test]
+fn duplicate_members_keep_their_selector() {
+    let first = Balance::Amount0(5);
+    let second = Balance::Amount1(5);
+    assert_eq!(first.as_ssz_bytes(), [0, 5, 0, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(second.as_ssz_bytes(), [1, 5, 0, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(
+        Balance::from_ssz_bytes(&second.as_ssz_bytes()),
+        Ok(second.clone())
+    );
+    assert_ne!(
+        first.tree_hash_root::<Sha256Hasher>(),
+        second.tree_hash_root::<Sha256Hasher>()
+    );
+
+    let bytes = second.as_ssz_bytes();
+    let view = BalanceRef::from_ssz_bytes(&bytes).unwrap();
+    assert_eq!(view.as_selector1().unwrap(), 5);
+    assert!(view.as_selector0().is_err());
+}
+
+#[test]
+fn container_with_duplicate_member_unions_round_trips() {
+    let account = Account {
+        balance: Balance::Amount1(7),
+        finality: Finality::Anchor2(Checkpoint { epoch: 3 }),
+    };
+    let bytes = account.as_ssz_bytes();
+    assert_eq!(Account::from_ssz_bytes(&bytes), Ok(account.clone()));
+
+    let view = AccountRef::from_ssz_bytes(&bytes).unwrap();
+    assert_eq!(view.finality().unwrap().selector(), 2);
+    assert_eq!(view.to_owned(), account);
+}
+
+#[test]
+fn suffix_skips_taken_names() {
+    for (ledger, selector) in [
+        (Ledger::Amount1(5), 0),
+        (Ledger::Amount2(5), 1),
+        (Ledger::Amount3(5), 2),
+    ] {
+        let bytes = ledger.as_ssz_bytes();
+        assert_eq!(bytes[0], selector);
+        assert_eq!(Ledger::from_ssz_bytes(&bytes), Ok(ledger));
+    }
+}
@@ -0,0 +1,152 @@
+//! A union member of fixed size decodes only from a body of exactly that
+//! size, and a `None` member only from an empty body, both as owned values
+//! and through views.
+
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+include!("expected_output/test_union_fixed_member.rs");
+
+use ssz::{Decode, DecodeError, Encode, view::DecodeView};
+use ssz_types::{FixedVector, VariableList};
+use tests::input::test_union_fixed_member::{Drawing, DrawingRef, Point, Shape, ShapeRef};
+
+fn with_trailing_byte(mut bytes: Vec<u8>) -> Vec<u8> {
+    bytes.push(0xff);
+    bytes
+}
+
+fn shapes() -> Vec<Shape> {
+    vec![
+        Shape::Selector0,
+        Shape::Selector1(7),
+        Shape::Point(Point { x: 1, y: 2 }),
+        Shape::Selector3(FixedVector::new(vec![1, 2, 3]).unwrap()),
+    ]
+}
+
+#[test]
+fn exact_length_accepted() {
+    for shape in shapes() {
+        let bytes = shape.as_ssz_bytes();
+        assert_eq!(Shape::from_ssz_bytes(&bytes), Ok(shape.clone()));
+        assert_eq!(ShapeRef::from_ssz_bytes(&bytes).unwrap().to_owned(), shape);
+    }
+}
+
+#[test]
+fn owned_rejects_trailing_byte() {
+    let bytes = with_trailing_byte(Shape::Selector0.as_ssz_bytes());
+    assert_eq!(
+        Shape::from_ssz_bytes(&bytes),
+        Err(DecodeError::InvalidByteLength {
+            len: 1,
+            expected: 0
+        })
+    );
+
+    let bytes = with_trailing_byte(Shape::Selector1(7).as_ssz_bytes());
+    assert_eq!(
+        Shape::from_ssz_bytes(&bytes),
+        Err(DecodeError::InvalidByteLength {
+            len: 5,
+            expected: 4
+        })
+    );
+
+    let bytes = with_trailing_byte(Shape::Point(Point { x: 1, y: 2 }).as_ssz_bytes());
+    assert_eq!(
+        Shape::from_ssz_bytes(&bytes),
+        Err(DecodeError::InvalidByteLength {
+            len: 5,
+            expected: 4
+        })
+    );
+
+    let vector = Shape::Selector3(FixedVector::new(vec![1, 2, 3]).unwrap());
+    assert_eq!(
+        Shape::from_ssz_bytes(&with_trailing_byte(vector.as_ssz_bytes())),
+        Err(DecodeError::InvalidByteLength {
+            len: 7,
+            expected: 6
+        })
+    );
+}
+
+#[test]
+fn view_rejects_trailing_byte() {
+    let bytes = with_trailing_byte(Shape::Selector0.as_ssz_bytes());
+    let view = ShapeRef::from_ssz_bytes(&bytes).unwrap();
+    assert_eq!(
+        view.as_selector0(),
+        Err(DecodeError::InvalidByteLength {
+            len: 1,
+            expected: 0
+        })
+    );
+
+    let bytes = with_trailing_byte(Shape::Selector1(7).as_ssz_bytes());
+    let view = ShapeRef::from_ssz_bytes(&bytes).unwrap();
+    assert_eq!(
+        view.as_selector1(),
+        Err(DecodeError::InvalidByteLength {
+            len: 5,
+            expected: 4
+        })
+    );
+
+    let bytes = with_trailing_byte(Shape::Point(Point { x: 1, y: 2 }).as_ssz_bytes());
+    let view = ShapeRef::from_ssz_bytes(&bytes).unwrap();
+    assert!(matches!(
+        view.as_selector2(),
+        Err(DecodeError::InvalidByteLength {
+            len: 5,
+            expected: 4
+        })
+    ));
+    assert!(view.validate_deep().is_err());
+}
+
+#[test]
+fn variable_member_takes_any_length() {
+    let list = Shape::Selector4(VariableList::new(vec![1, 2, 3]).unwrap());
+    let bytes = list.as_ssz_bytes();
+    assert_eq!(Shape::from_ssz_bytes(&bytes), Ok(list));
+    // One more list item rather than a trailing byte.
+    let longer = with_trailing_byte(bytes);
+    assert!(Shape::from_ssz_bytes(&longer).is_ok());
+}
+
+#[test]
+fn option_rejects_trailing_byte() {
+    for count in [None, Some(9u32)] {
+        let bytes = count.as_ssz_bytes();
+        assert_eq!(Option::<u32>::from_ssz_bytes(&bytes), Ok(count));
+
+        let bytes = with_trailing_byte(bytes);
+        assert!(matches!(
+            Option::<u32>::from_ssz_bytes(&bytes),
+            Err(DecodeError::InvalidByteLength { .. })
+        ));
+    }
+
+    // The same through a container's view getter.
+    let mut bytes = Drawing { count: Some(9) }.as_ssz_bytes();
+    assert_eq!(
+        DrawingRef::from_ssz_bytes(&bytes).unwrap().count(),
+        Ok(Some(9))
+    );
+    bytes.push(0xff);
+    assert_eq!(
+        DrawingRef::from_ssz_bytes(&bytes).unwrap().count(),
+        Err(DecodeError::InvalidByteLength {
+            len: 5,
+            expected: 4
+        })
+    );
+}
@@ -0,0 +1,32 @@
+//! Unions implement `From` for every member type no other member shares, so
+//! a member value converts with `.into()`.
+
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+include!("expected_output/test_union_from.rs");
+
+use tests::input::test_union_from::{Action, Deposit, Transfer, Wrapped};
+
+#[test]
+fn container_member_converts_into_union() {
+    let action: Action = Transfer { amount: 5 }.into();
+    assert_eq!(action, Action::Transfer(Transfer { amount: 5 }));
+
+    let action: Action = Deposit { amount: 9 }.into();
+    assert_eq!(action, Action::Deposit(Deposit { amount: 9 }));
+}
+
+#[test]
+fn nested_union_converts_into_outer_union() {
+    let wrapped: Wrapped = Action::Low(1).into();
+    assert_eq!(wrapped, Wrapped::Action(Action::Low(1)));
+
+    let wrapped: Wrapped = Transfer { amount: 3 }.into();
+    assert_eq!(wrapped, Wrapped::Transfer(Transfer { amount: 3 }));
+}
@@ -0,0 +1,54 @@
+//! Union enums whose members all implement `Hash` derive it too, hashing the
+//! selector together with the payload. Containers gain it through a
+//! `derive` pragma.
+
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+include!("expected_output/test_union_hash.rs");
+
+use std::collections::HashSet;
+
+use ssz_types::VariableList;
+use tests::input::test_union_hash::{Point, Shape, Small};
+
+#[test]
+fn derive_pragma_adds_hash_to_container() {
+    let points: HashSet<Point> = [
+        Point { x: 1, y: 2 },
+        Point { x: 3, y: 4 },
+        Point { x: 1, y: 2 },
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(points.len(), 2);
+    assert!(points.contains(&Point { x: 3, y: 4 }));
+}
+
+#[test]
+fn hash_set_deduplicates_equal_unions() {
+    let point = Point { x: 1, y: 2 };
+    let path = VariableList::new(vec![point.clone(), Point { x: 3, y: 4 }]).expect("within bound");
+
+    let shapes = [
+        Shape::Dot(point.clone()),
+        Shape::Tag(Small::Selector0(7)),
+        Shape::Path(path.clone()),
+        Shape::Dot(point.clone()),
+        Shape::Tag(Small::Selector0(7)),
+        Shape::Path(path),
+        // Same payload value under a different selector stays distinct.
+        Shape::Tag(Small::Selector1(7)),
+    ];
+
+    let unique: HashSet<Shape> = shapes.iter().cloned().collect();
+    assert_eq!(unique.len(), 4);
+    assert!(unique.contains(&Shape::Dot(point)));
+    assert!(unique.contains(&Shape::Tag(Small::Selector0(7))));
+    assert!(unique.contains(&Shape::Tag(Small::Selector1(7))));
+}
@@ -0,0 +1,71 @@
+//! Lists and vectors of unions hash each element as its value root mixed
+//! with that element's own selector, on both the owned and view paths.
+
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+include!("expected_output/test_union_list_hash.rs");
+
+use ssz::{Encode, view::DecodeView};
+use ssz_types::{FixedVector, VariableList};
+use tests::input::test_union_list_hash::{Holder, HolderRef, Pair, Token};
+use tree_hash::{Sha256Hasher, TreeHash};
+
+/// `tokens` root computed by hand: each element is
+/// `sha256(value_root || selector)`, with a zero chunk as the `Empty` value
+/// root, merkleized to the limit of 4 and mixed with the length 3.
+const TOKENS_ROOT: &str = "4d120d88c03533b70ae4a719a0fabda41f47fceb6dfa146921c6b01f6e0b7442";
+/// `fixed` root computed by hand, as above without the length mix-in.
+const FIXED_ROOT: &str = "64430edc36de07c04297dcaa0325f9abb38b991463d0c28b9c9e623c13848a89";
+/// `sha256(TOKENS_ROOT || FIXED_ROOT)`.
+const HOLDER_ROOT: &str = "bb080c866f32940e50fd71bd838187bfeeee482ded6b48ff54cf8da9771fa51c";
+
+fn sample_holder() -> Holder {
+    Holder {
+        tokens: VariableList::new(vec![
+            Token::Empty,
+            Token::Amount(5),
+            Token::Pair(Pair { a: 1, b: 0x0203 }),
+        ])
+        .unwrap(),
+        fixed: FixedVector::new(vec![Token::Amount(7), Token::Empty]).unwrap(),
+    }
+}
+
+fn hex(root: impl AsRef<[u8]>) -> String {
+    root.as_ref().iter().map(|b| format!("{b:02x}")).collect()
+}
+
+#[test]
+fn owned_roots_mix_per_element_selectors() {
+    let holder = sample_holder();
+    assert_eq!(
+        hex(holder.tokens.tree_hash_root::<Sha256Hasher>()),
+        TOKENS_ROOT
+    );
+    assert_eq!(
+        hex(holder.fixed.tree_hash_root::<Sha256Hasher>()),
+        FIXED_ROOT
+    );
+    assert_eq!(hex(holder.tree_hash_root::<Sha256Hasher>()), HOLDER_ROOT);
+}
+
+#[test]
+fn view_roots_mix_per_element_selectors() {
+    let bytes = sample_holder().as_ssz_bytes();
+    let view = HolderRef::from_ssz_bytes(&bytes).unwrap();
+    assert_eq!(
+        hex(view.tokens().unwrap().tree_hash_root::<Sha256Hasher>()),
+        TOKENS_ROOT
+    );
+    assert_eq!(
+        hex(view.fixed().unwrap().tree_hash_root::<Sha256Hasher>()),
+        FIXED_ROOT
+    );
+    assert_eq!(hex(view.tree_hash_root::<Sha256Hasher>()), HOLDER_ROOT);
+}
@@ -0,0 +1,74 @@
+//! A `List[FirstUnion, 4]` decodes as a list view of `FirstUnionRef`s, both
+//! through the generated getter and as a `VariableListRef` over the list's
+//! own bytes.
+
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+include!("expected_output/test_union_list_view.rs");
+
+use ssz::{Encode, view::DecodeView};
+use ssz_types::{VariableList, view::VariableListRef};
+use tests::input::test_union_list_view::{Batch, BatchRef, FirstUnion, FirstUnionRef};
+
+fn sample_unions() -> VariableList<FirstUnion, 4> {
+    VariableList::new(vec![
+        FirstUnion::Selector0(7),
+        FirstUnion::Selector1(0x0102),
+        FirstUnion::Selector0(9),
+    ])
+    .unwrap()
+}
+
+#[test]
+fn getter_iterates_union_views() {
+    let batch = Batch {
+        unions: sample_unions(),
+        tail: 5,
+    };
+    let bytes = batch.as_ssz_bytes();
+    let view = BatchRef::from_ssz_bytes(&bytes).unwrap();
+
+    let unions = view.unions().unwrap();
+    assert_eq!(unions.len(), 3);
+    let selectors: Vec<u8> = unions
+        .iter()
+        .map(|union| union.unwrap().selector())
+        .collect();
+    assert_eq!(selectors, vec![0, 1, 0]);
+    assert_eq!(unions.get(1).unwrap().as_selector1(), Ok(0x0102));
+    assert_eq!(view.tail(), Ok(5));
+    assert_eq!(view.to_owned(), batch);
+}
+
+#[test]
+fn variable_list_ref_iterates_union_views() {
+    let unions = sample_unions();
+    let bytes = unions.as_ssz_bytes();
+    let view = VariableListRef::<FirstUnionRef<'_>, 4>::new(&bytes).unwrap();
+
+    let values: Vec<u16> = view
+        .iter()
+        .map(|union| {
+            let union = union.unwrap();
+            match union.selector() {
+                0 => u16::from(union.as_selector0().unwrap()),
+                _ => union.as_selector1().unwrap(),
+            }
+        })
+        .collect();
+    assert_eq!(values, vec![7, 0x0102, 9]);
+    assert_eq!(view.to_owned::<FirstUnion>().unwrap(), unions);
+}
+
+#[test]
+fn rejects_too_many_unions() {
+    let unions: Vec<FirstUnion> = (0..5).map(FirstUnion::Selector0).collect();
+    let bytes = unions.as_ssz_bytes();
+    assert!(VariableListRef::<FirstUnionRef<'_>, 4>::new(&bytes).is_err());
+}
@@ -0,0 +1,47 @@
+//! Owned union enums get a test-only `all_selector_examples` returning one
+//! value per selector, built from each member's default.
+
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs)]
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+include!("expected_output/test_union_edge_cases.rs");
+
+use ssz::{Decode, Encode};
+use tests::input::test_union_edge_cases::{MixedOptional, SimpleUnion};
+
+/// Asserts that `examples` holds one value per selector, in order, and that
+/// each round-trips through its encoding.
+fn assert_one_per_selector<T: Encode + Decode + PartialEq + std::fmt::Debug>(
+    examples: &[T],
+    selectors: usize,
+) {
+    assert_eq!(examples.len(), selectors);
+    for (selector, example) in examples.iter().enumerate() {
+        let bytes = example.as_ssz_bytes();
+        assert_eq!(usize::from(bytes[0]), selector);
+        assert_eq!(
+            &T::from_ssz_bytes(&bytes).expect("example decodes"),
+            example
+        );
+    }
+}
+
+#[test]
+fn examples_cover_every_selector() {
+    assert_one_per_selector(&SimpleUnion::all_selector_examples(), 2);
+    assert_one_per_selector(&MixedOptional::all_selector_examples(), 3);
+}
+
+#[test]
+fn none_member_example_is_the_unit_variant() {
+    let examples = MixedOptional::all_selector_examples();
+    assert_eq!(examples[0], MixedOptional::Selector0);
+    assert_eq!(examples[0].as_ssz_bytes(), [0]);
+}
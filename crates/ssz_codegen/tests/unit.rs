@@ -0,0 +1,86 @@
+//! `unit` classes add, subtract and compare against themselves only, and
+//! encode and hash exactly like their integer field.
+
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+include!("expected_output/test_unit.rs");
+
+use std::ops::Add;
+
+use ssz::{Decode, Encode};
+use tests::input::test_unit::{Deposit, Gwei, Slot};
+use tree_hash::{Sha256Hasher, TreeHash};
+
+/// Fails to compile if `T: Add<Rhs>`: with such an impl, `_` below could be
+/// either `()` or `u8`, which is ambiguous.
+macro_rules! assert_not_add {
+    ($t:ty, $rhs:ty) => {
+        const _: fn() = || {
+            trait AmbiguousIfAdd<A> {
+                fn check() {}
+            }
+            impl<T: ?Sized> AmbiguousIfAdd<()> for T {}
+            impl<T: ?Sized + Add<$rhs>> AmbiguousIfAdd<u8> for T {}
+            <$t as AmbiguousIfAdd<_>>::check();
+        };
+    };
+}
+
+assert_not_add!(Gwei, Slot);
+assert_not_add!(Slot, Gwei);
+assert_not_add!(Gwei, u64);
+
+fn gwei(value: u64) -> Gwei {
+    Gwei { value }
+}
+
+#[test]
+fn arithmetic_within_unit() {
+    assert_eq!(gwei(3) + gwei(4), gwei(7));
+    assert_eq!(gwei(10) - gwei(4), gwei(6));
+
+    let mut total = gwei(1);
+    total += gwei(2);
+    total -= gwei(1);
+    assert_eq!(total, gwei(2));
+
+    assert_eq!(Slot { value: 5 } + Slot { value: 1 }, Slot { value: 6 });
+}
+
+#[test]
+fn ordered_by_value() {
+    assert!(gwei(1) < gwei(2));
+    assert_eq!(gwei(9).max(gwei(3)), gwei(9));
+
+    let mut amounts = vec![gwei(3), gwei(1), gwei(2)];
+    amounts.sort();
+    assert_eq!(amounts, vec![gwei(1), gwei(2), gwei(3)]);
+}
+
+#[test]
+fn encodes_and_hashes_as_u64() {
+    let amount = gwei(32_000_000_000);
+    assert_eq!(amount.as_ssz_bytes(), 32_000_000_000u64.as_ssz_bytes());
+    assert_eq!(
+        Gwei::from_ssz_bytes(&32_000_000_000u64.as_ssz_bytes()).unwrap(),
+        amount
+    );
+    assert_eq!(
+        amount.tree_hash_root::<Sha256Hasher>(),
+        32_000_000_000u64.tree_hash_root::<Sha256Hasher>()
+    );
+
+    let deposit = Deposit {
+        amount,
+        slot: Slot { value: 7 },
+    };
+    let mut expected = 32_000_000_000u64.as_ssz_bytes();
+    expected.extend(7u64.as_ssz_bytes());
+    assert_eq!(deposit.as_ssz_bytes(), expected);
+}
@@ -0,0 +1,68 @@
+//! `validate_and_to_owned` reports every field of a view that fails to
+//! decode, by path, and defaults those fields in the owned value.
+
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+#![allow(clippy::derivable_impls)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+include!("expected_output/test_validate_and_to_owned.rs");
+
+use ssz::{DecodeError, Encode, view::DecodeView};
+use ssz_types::VariableList;
+use tests::input::test_validate_and_to_owned::{Inner, Outer, OuterRef};
+
+fn sample() -> Outer {
+    Outer {
+        id: 7,
+        ok: true,
+        inner: Inner {
+            flag: true,
+            values: VariableList::new(vec![1, 2]).expect("within bound"),
+        },
+        tags: VariableList::new(vec![true, false]).expect("within bound"),
+    }
+}
+
+#[test]
+fn valid_view_has_no_errors() {
+    let bytes = sample().as_ssz_bytes();
+    let view = OuterRef::from_ssz_bytes(&bytes).expect("valid view");
+    let (value, errors) = view.validate_and_to_owned();
+    assert!(errors.is_empty(), "{errors:?}");
+    assert_eq!(value, sample());
+}
+
+#[test]
+fn corrupt_fields_are_collected_by_path() {
+    let mut bytes = sample().as_ssz_bytes();
+    // Fixed part: id (4), ok (1), inner offset (4), tags offset (4); then
+    // inner's flag (1), its values offset (4) and values (4); then tags (2).
+    bytes[4] = 2;
+    bytes[13] = 3;
+    bytes[23] = 5;
+
+    // The container's layout is intact, so the view still decodes.
+    let view = OuterRef::from_ssz_bytes(&bytes).expect("layout is valid");
+    let (value, errors) = view.validate_and_to_owned();
+
+    let paths: Vec<&str> = errors.iter().map(|(path, _)| path.as_str()).collect();
+    assert_eq!(paths, ["ok", "inner.flag", "tags"]);
+    assert!(
+        errors
+            .iter()
+            .all(|(_, error)| matches!(error, DecodeError::BytesInvalid(_))),
+        "{errors:?}"
+    );
+
+    // Valid fields keep their values; failed ones are defaulted.
+    assert_eq!(value.id, 7);
+    assert!(!value.ok);
+    assert!(!value.inner.flag);
+    assert_eq!(&value.inner.values[..], &[1, 2]);
+    assert!(value.tags.is_empty());
+}
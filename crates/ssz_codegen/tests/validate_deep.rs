@@ -0,0 +1,97 @@
+//! `validate_deep` rejects bytes whose nested contents are malformed even
+//! though the view's own layout, all that `from_ssz_bytes` checks, is valid.
+
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+include!("expected_output/test_validate_deep.rs");
+
+use ssz::{Decode, DecodeError, Encode, view::DecodeView};
+use ssz_types::{FixedBytes, Optional, VariableList};
+use tests::input::test_validate_deep::{
+    Ballot, BallotRef, Eta, EtaRef, FirstUnion, TestType, Vote, Zeta,
+};
+
+fn sample_eta() -> Eta {
+    Eta {
+        l: Zeta {
+            u: Optional::Some(FixedBytes([1; 16])),
+            v: Optional::None,
+        },
+        m: TestType {
+            ccc: 2,
+            ddd: 3,
+            eee: VariableList::new(vec![4, 5]).expect("within bound"),
+        },
+        n: FirstUnion::Selector0(6),
+    }
+}
+
+fn sample_ballot() -> Ballot {
+    Ballot {
+        votes: VariableList::new(vec![Vote::No(1), Vote::Yes(2)]).expect("within bound"),
+        flags: VariableList::new(vec![true, false]).expect("within bound"),
+    }
+}
+
+#[test]
+fn valid_bytes_pass() {
+    let eta = sample_eta().as_ssz_bytes();
+    EtaRef::from_ssz_bytes(&eta)
+        .expect("valid view")
+        .validate_deep()
+        .expect("valid contents");
+
+    let ballot = sample_ballot().as_ssz_bytes();
+    BallotRef::from_ssz_bytes(&ballot)
+        .expect("valid view")
+        .validate_deep()
+        .expect("valid contents");
+}
+
+#[test]
+fn corrupt_union_selector_fails_deep_validation_only() {
+    let mut bytes = sample_eta().as_ssz_bytes();
+    // `n` is last: its selector followed by the one-byte `uint8` member.
+    let selector = bytes.len() - 2;
+    assert_eq!(bytes[selector], 0);
+    bytes[selector] = 7;
+
+    let view = EtaRef::from_ssz_bytes(&bytes).expect("layout is valid");
+    assert_eq!(
+        view.validate_deep(),
+        Err(DecodeError::UnionSelectorInvalid(7))
+    );
+    assert!(Eta::from_ssz_bytes(&bytes).is_err());
+}
+
+#[test]
+fn corrupt_list_items_fail_deep_validation() {
+    // The last vote's selector, inside the `votes` list.
+    let mut bytes = sample_ballot().as_ssz_bytes();
+    let vote_selector = bytes.len() - 4;
+    assert_eq!(bytes[vote_selector], 0);
+    bytes[vote_selector] = 9;
+    let view = BallotRef::from_ssz_bytes(&bytes).expect("layout is valid");
+    assert_eq!(
+        view.validate_deep(),
+        Err(DecodeError::UnionSelectorInvalid(9))
+    );
+    assert!(Ballot::from_ssz_bytes(&bytes).is_err());
+
+    // The last boolean of `flags`.
+    let mut bytes = sample_ballot().as_ssz_bytes();
+    let last = bytes.len() - 1;
+    bytes[last] = 2;
+    let view = BallotRef::from_ssz_bytes(&bytes).expect("layout is valid");
+    assert!(matches!(
+        view.validate_deep(),
+        Err(DecodeError::BytesInvalid(_))
+    ));
+    assert!(Ballot::from_ssz_bytes(&bytes).is_err());
+}
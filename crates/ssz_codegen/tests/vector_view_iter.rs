@@ -0,0 +1,82 @@
+//! Iterating `FixedVectorRef` views of containers must yield the same
+//! elements as indexed access, for both fixed- and variable-size elements.
+
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+// Include generated code
+include!("expected_output/test_vector_view_iter.rs");
+
+use ssz::{Encode, view::DecodeView};
+use ssz_types::{FixedVector, VariableList};
+use tests::input::test_vector_view_iter::{Delta, Entry, Holder, HolderRef};
+
+fn sample_holder() -> Holder {
+    Holder {
+        deltas: FixedVector::new(vec![
+            Delta { z: true, w: 1 },
+            Delta { z: false, w: 2 },
+            Delta { z: true, w: 3 },
+            Delta { z: false, w: 4 },
+        ])
+        .expect("exact length"),
+        entries: FixedVector::new(vec![
+            Entry {
+                id: 10,
+                data: VariableList::new(vec![1, 2]).expect("within bound"),
+            },
+            Entry {
+                id: 11,
+                data: VariableList::new(vec![]).expect("within bound"),
+            },
+            Entry {
+                id: 12,
+                data: VariableList::new(vec![3, 4, 5, 6]).expect("within bound"),
+            },
+        ])
+        .expect("exact length"),
+    }
+}
+
+#[test]
+fn fixed_size_elements_iterate_like_indexed_getters() {
+    let holder = sample_holder();
+    let bytes = holder.as_ssz_bytes();
+    let view = HolderRef::from_ssz_bytes(&bytes).expect("view decode");
+    let deltas = view.deltas().expect("deltas");
+
+    let mut count = 0;
+    for (i, elem) in deltas.iter().enumerate() {
+        let elem = elem.expect("iterated element");
+        let indexed = deltas.get(i).expect("indexed element");
+        assert_eq!(elem.z().unwrap(), indexed.z().unwrap());
+        assert_eq!(elem.w().unwrap(), indexed.w().unwrap());
+        assert_eq!(elem.to_owned(), holder.deltas[i]);
+        count += 1;
+    }
+    assert_eq!(count, 4);
+    assert_eq!(deltas.iter().len(), 4);
+}
+
+#[test]
+fn variable_size_elements_iterate_like_indexed_getters() {
+    let holder = sample_holder();
+    let bytes = holder.as_ssz_bytes();
+    let view = HolderRef::from_ssz_bytes(&bytes).expect("view decode");
+    let entries = view.entries().expect("entries");
+
+    let iterated: Vec<Entry> = entries
+        .iter()
+        .map(|elem| elem.expect("iterated element").to_owned())
+        .collect();
+    let indexed: Vec<Entry> = (0..3)
+        .map(|i| entries.get(i).expect("indexed element").to_owned())
+        .collect();
+    assert_eq!(iterated, indexed);
+    assert_eq!(iterated, holder.entries.to_vec());
+}
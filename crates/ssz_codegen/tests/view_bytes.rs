@@ -0,0 +1,64 @@
+//! Generated views convert from byte slices with `TryFrom` and hand back the
+//! exact bytes they were decoded from through `as_bytes`.
+
+#![allow(dead_code)]
+#![allow(unused_crate_dependencies)]
+#![allow(missing_docs)]
+
+use ssz_derive as _;
+use ssz_primitives as _;
+use tree_hash_derive as _;
+
+include!("expected_output/test_default_impl.rs");
+
+use ssz::Encode;
+use ssz_types::{Optional, VariableList};
+use tests::input::test_default_impl::{Alpha, AlphaRef, Beta, BetaRef, Gamma, GammaRef};
+
+#[test]
+fn fixed_container_round_trip() {
+    let alpha = Alpha {
+        a: 1,
+        b: 2,
+        d: true,
+        ..Alpha::default()
+    };
+    let bytes = alpha.as_ssz_bytes();
+
+    let view = AlphaRef::try_from(&bytes[..]).unwrap();
+    assert_eq!(view.as_bytes(), &bytes[..]);
+    assert_eq!(view.to_owned(), alpha);
+}
+
+#[test]
+fn variable_container_round_trip() {
+    let beta = Beta {
+        values: VariableList::new(vec![1, 2, 3]).unwrap(),
+        ..Beta::default()
+    };
+    let bytes = beta.as_ssz_bytes();
+
+    let view = BetaRef::try_from(bytes.as_slice()).unwrap();
+    assert_eq!(view.as_bytes(), bytes.as_slice());
+    assert_eq!(view.to_owned(), beta);
+}
+
+#[test]
+fn stable_container_round_trip() {
+    let gamma = Gamma {
+        h: Optional::Some(Alpha::default()),
+        ..Gamma::default()
+    };
+    let bytes = gamma.as_ssz_bytes();
+
+    let view = GammaRef::try_from(&bytes[..]).unwrap();
+    assert_eq!(view.as_bytes(), &bytes[..]);
+    assert_eq!(view.to_owned(), gamma);
+}
+
+#[test]
+fn try_from_rejects_invalid_bytes() {
+    let bytes = Alpha::default().as_ssz_bytes();
+
+    assert!(AlphaRef::try_from(&bytes[..bytes.len() - 1]).is_err());
+}
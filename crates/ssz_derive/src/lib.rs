@@ -29,6 +29,11 @@
 //! - `#[ssz(skip_serializing)]`: this field will not be included in the serialized SSZ vector.
 //! - `#[ssz(skip_deserializing)]`: this field will not be expected in the serialized SSZ vector and
 //!   it will be initialized from a `Default` implementation.
+//! - `#[ssz(skip)]`: shorthand for `#[ssz(skip_serializing, skip_deserializing)]`, e.g. for
+//!   `PhantomData` fields.
+//! - `#[ssz(optional_tail)]`: on a trailing run of container fields, accepts encodings that end
+//!   before those fields (e.g. written before they were added) and initializes the missing ones
+//!   from a `Default` implementation.
 //!
 //! ## Examples
 //!
@@ -197,6 +202,10 @@ struct FieldOpts {
     skip_serializing: bool,
     #[darling(default)]
     skip_deserializing: bool,
+    #[darling(default)]
+    skip: bool,
+    #[darling(default)]
+    optional_tail: bool,
 }
 
 enum Procedure<'a> {
@@ -330,10 +339,14 @@ fn parse_ssz_fields(
                 panic!("more than one field-level \"ssz\" attribute provided")
             }
 
-            let field_opts = field_opts_candidates
+            let mut field_opts: FieldOpts = field_opts_candidates
                 .first()
                 .map(|attr| FieldOpts::from_meta(&attr.meta).unwrap())
                 .unwrap_or_default();
+            if field_opts.skip {
+                field_opts.skip_serializing = true;
+                field_opts.skip_deserializing = true;
+            }
 
             (ty, ident, field_opts)
         })
@@ -378,6 +391,7 @@ fn ssz_encode_derive_struct(derive_input: &DeriveInput, struct_data: &DataStruct
     let field_fixed_len = &mut vec![];
     let field_ssz_bytes_len = &mut vec![];
     let field_encoder_append = &mut vec![];
+    let field_write = &mut vec![];
 
     for (ty, ident, field_opts) in parse_ssz_fields(struct_data) {
         if field_opts.skip_serializing {
@@ -402,11 +416,18 @@ fn ssz_encode_derive_struct(derive_input: &DeriveInput, struct_data: &DataStruct
                     |buf| #module::ssz_append(&self.#ident, buf)
                 )
             });
+            // `with` modules only provide `ssz_append`, so buffer just this field.
+            field_write.push(quote! {
+                let mut buf = ssz::__std::Vec::with_capacity(#module::ssz_bytes_len(&self.#ident));
+                #module::ssz_append(&self.#ident, &mut buf);
+                w.write_all(&buf)?;
+            });
         } else {
             field_is_ssz_fixed_len.push(quote! { <#ty as ssz::Encode>::is_ssz_fixed_len() });
             field_fixed_len.push(quote! { <#ty as ssz::Encode>::ssz_fixed_len() });
             field_ssz_bytes_len.push(quote! { self.#ident.ssz_bytes_len() });
             field_encoder_append.push(quote! { encoder.append(&self.#ident) });
+            field_write.push(quote! { ssz::Encode::ssz_write(&self.#ident, w)?; });
         }
     }
 
@@ -473,6 +494,36 @@ fn ssz_encode_derive_struct(derive_input: &DeriveInput, struct_data: &DataStruct
 
                 encoder.finalize();
             }
+
+            ssz::__std_only! {
+                fn ssz_write<__W: ssz::__std::io::Write>(&self, w: &mut __W) -> ssz::__std::io::Result<()> {
+                    let mut offset: usize = 0;
+                    #(
+                        offset = offset
+                            .checked_add(#field_fixed_len)
+                            .expect("encode ssz_write offset overflow");
+                    )*
+
+                    // Fixed parts and offsets first, then the variable parts in field order.
+                    #(
+                        if #field_is_ssz_fixed_len {
+                            #field_write
+                        } else {
+                            w.write_all(&ssz::encode_length(offset))?;
+                            offset = offset
+                                .checked_add(#field_ssz_bytes_len)
+                                .expect("encode ssz_write offset overflow");
+                        }
+                    )*
+                    #(
+                        if !#field_is_ssz_fixed_len {
+                            #field_write
+                        }
+                    )*
+
+                    Ok(())
+                }
+            }
         }
     };
     output.into()
@@ -896,6 +947,12 @@ fn ssz_encode_derive_struct_transparent(
                 fn ssz_append(&self, buf: &mut Vec<u8>) {
                     self.#field_name.ssz_append(buf)
                 }
+
+                ssz::__std_only! {
+                    fn ssz_write<__W: ssz::__std::io::Write>(&self, w: &mut __W) -> ssz::__std::io::Result<()> {
+                        self.#field_name.ssz_write(w)
+                    }
+                }
             }
         }
     } else {
@@ -916,6 +973,12 @@ fn ssz_encode_derive_struct_transparent(
                 fn ssz_append(&self, buf: &mut Vec<u8>) {
                     self.#index.ssz_append(buf)
                 }
+
+                ssz::__std_only! {
+                    fn ssz_write<__W: ssz::__std::io::Write>(&self, w: &mut __W) -> ssz::__std::io::Result<()> {
+                        self.#index.ssz_write(w)
+                    }
+                }
             }
         }
     };
@@ -1076,55 +1139,69 @@ fn ssz_encode_derive_enum_union(derive_input: &DeriveInput, enum_data: &DataEnum
     let name = &derive_input.ident;
     let (impl_generics, ty_generics, where_clause) = &derive_input.generics.split_for_impl();
 
-    let (bytes_len_patterns, append_patterns): (Vec<_>, Vec<_>) = enum_data
-        .variants
-        .iter()
-        .enumerate()
-        .map(|(i, variant)| {
-            let variant_name = &variant.ident;
-            let fields_len = variant.fields.len();
-            let selector_index: u8 = i
-                .try_into()
-                .expect("union selector exceeds u8::max_value, union has too many variants");
-
-            // First variant can have no fields (Union[None, ...])
-            if fields_len != 1 && !(fields_len == 0 && i == 0) {
-                panic!("ssz::Encode can only be derived for enums with 1 field per variant");
-            }
+    let (bytes_len_patterns, (append_patterns, write_patterns)): (Vec<_>, (Vec<_>, Vec<_>)) =
+        enum_data
+            .variants
+            .iter()
+            .enumerate()
+            .map(|(i, variant)| {
+                let variant_name = &variant.ident;
+                let fields_len = variant.fields.len();
+                let selector_index: u8 = i
+                    .try_into()
+                    .expect("union selector exceeds u8::max_value, union has too many variants");
+
+                // First variant can have no fields (Union[None, ...])
+                if fields_len != 1 && !(fields_len == 0 && i == 0) {
+                    panic!("ssz::Encode can only be derived for enums with 1 field per variant");
+                }
 
-            if fields_len == 0 {
-                (
-                    quote! {
-                        #name::#variant_name => 1
-                    },
-                    quote! {
-                        #name::#variant_name => {
-                            let union_selector = #selector_index;
-                            debug_assert!(union_selector <= ssz::MAX_UNION_SELECTOR);
-                            buf.push(union_selector);
-                        }
-                    },
-                )
-            } else {
-                (
-                    quote! {
-                        #name::#variant_name(inner) => inner
-                            .ssz_bytes_len()
-                            .checked_add(1)
-                            .expect("encoded length must be less than usize::max_value")
-                    },
-                    quote! {
-                        #name::#variant_name(inner) => {
-                            let union_selector = #selector_index;
-                            debug_assert!(union_selector <= ssz::MAX_UNION_SELECTOR);
-                            buf.push(union_selector);
-                            inner.ssz_append(buf)
-                        }
-                    },
-                )
-            }
-        })
-        .unzip();
+                if fields_len == 0 {
+                    (
+                        quote! {
+                            #name::#variant_name => 1
+                        },
+                        (
+                            quote! {
+                                #name::#variant_name => {
+                                    let union_selector = #selector_index;
+                                    debug_assert!(union_selector <= ssz::MAX_UNION_SELECTOR);
+                                    buf.push(union_selector);
+                                }
+                            },
+                            quote! {
+                                #name::#variant_name => w.write_all(&[#selector_index])
+                            },
+                        ),
+                    )
+                } else {
+                    (
+                        quote! {
+                            #name::#variant_name(inner) => inner
+                                .ssz_bytes_len()
+                                .checked_add(1)
+                                .expect("encoded length must be less than usize::max_value")
+                        },
+                        (
+                            quote! {
+                                #name::#variant_name(inner) => {
+                                    let union_selector = #selector_index;
+                                    debug_assert!(union_selector <= ssz::MAX_UNION_SELECTOR);
+                                    buf.push(union_selector);
+                                    inner.ssz_append(buf)
+                                }
+                            },
+                            quote! {
+                                #name::#variant_name(inner) => {
+                                    w.write_all(&[#selector_index])?;
+                                    inner.ssz_write(w)
+                                }
+                            },
+                        ),
+                    )
+                }
+            })
+            .unzip();
 
     let _ = compute_union_selectors(bytes_len_patterns.len());
 
@@ -1145,6 +1222,14 @@ fn ssz_encode_derive_enum_union(derive_input: &DeriveInput, enum_data: &DataEnum
                     #(#append_patterns),*
                 }
             }
+
+            ssz::__std_only! {
+                fn ssz_write<__W: ssz::__std::io::Write>(&self, w: &mut __W) -> ssz::__std::io::Result<()> {
+                    match self {
+                        #(#write_patterns),*
+                    }
+                }
+            }
         }
     };
     output.into()
@@ -1182,6 +1267,10 @@ pub fn ssz_decode_derive(input: TokenStream) -> TokenStream {
 /// - `#[ssz(skip_deserializing)]`: during de-serialization the field will be instantiated from a
 ///   `Default` implementation. The decoder will assume that the field was not serialized at all
 ///   (e.g., if it has been serialized, an error will be raised instead of `Default` overriding it).
+/// - `#[ssz(optional_tail)]`: if the full encoding does not decode, shorter encodings that stop
+///   before some of these trailing fields are tried, longest first, and the absent fields are
+///   instantiated from a `Default` implementation. The error from the full decode is returned if
+///   none of them decode.
 fn ssz_decode_derive_struct(item: &DeriveInput, struct_data: &DataStruct) -> TokenStream {
     let name = &item.ident;
     let (impl_generics, ty_generics, where_clause) = &item.generics.split_for_impl();
@@ -1192,6 +1281,9 @@ fn ssz_decode_derive_struct(item: &DeriveInput, struct_data: &DataStruct) -> Tok
     let mut decodes = vec![];
     let mut is_fixed_lens = vec![];
     let mut fixed_lens = vec![];
+    // For each field: its ident and, unless skipped, its position among the decoded fields.
+    let mut tail_fields = vec![];
+    let mut required_count = None;
 
     for (ty, ident, field_opts) in parse_ssz_fields(struct_data) {
         let ident = match ident {
@@ -1207,6 +1299,10 @@ fn ssz_decode_derive_struct(item: &DeriveInput, struct_data: &DataStruct) -> Tok
 
         // Field should not be deserialized; use a `Default` impl to instantiate.
         if field_opts.skip_deserializing {
+            if field_opts.optional_tail {
+                panic!("\"optional_tail\" cannot be combined with \"skip_deserializing\"");
+            }
+
             decodes.push(quote! {
                 let #ident = <_>::default();
             });
@@ -1215,9 +1311,21 @@ fn ssz_decode_derive_struct(item: &DeriveInput, struct_data: &DataStruct) -> Tok
                 let #ident = <_>::default();
             });
 
+            tail_fields.push((ident, None));
             continue;
         }
 
+        let position = register_types.len();
+        if field_opts.optional_tail {
+            if position == 0 {
+                panic!("\"optional_tail\" requires at least one decoded field before it");
+            }
+            required_count.get_or_insert(position);
+        } else if required_count.is_some() {
+            panic!("\"optional_tail\" fields must come after all other decoded fields");
+        }
+        tail_fields.push((ident, Some(position)));
+
         let is_ssz_fixed_len;
         let ssz_fixed_len;
         let from_ssz_bytes;
@@ -1255,6 +1363,113 @@ fn ssz_decode_derive_struct(item: &DeriveInput, struct_data: &DataStruct) -> Tok
         fixed_lens.push(ssz_fixed_len);
     }
 
+    let full_decode = quote! {
+        if <Self as ssz::Decode>::is_ssz_fixed_len() {
+            let expected = <Self as ssz::Decode>::ssz_fixed_len();
+            if bytes.len() < expected {
+                return Err(ssz::DecodeError::Incomplete {
+                    needed: expected - bytes.len(),
+                });
+            }
+            if bytes.len() > expected {
+                return Err(ssz::DecodeError::InvalidByteLength {
+                    len: bytes.len(),
+                    expected,
+                });
+            }
+
+            #(
+                #fixed_decodes
+            )*
+
+            Ok(Self {
+                #(
+                    #field_names,
+                )*
+            })
+        } else {
+            let mut builder = ssz::SszDecoderBuilder::new(bytes);
+
+            #(
+                #register_types
+            )*
+
+            let mut decoder = builder.build()?;
+
+            #(
+                #decodes
+            )*
+
+
+            Ok(Self {
+                #(
+                    #field_names,
+                )*
+            })
+        }
+    };
+
+    let from_ssz_bytes = match required_count {
+        None => full_decode,
+        Some(required_count) => {
+            let prefix_decodes = (required_count..register_types.len()).rev().map(|present| {
+                let mut prefix_register_types = vec![];
+                let mut prefix_decodes = vec![];
+                for (index, (ident, position)) in tail_fields.iter().enumerate() {
+                    match position {
+                        Some(position) if *position < present => {
+                            prefix_register_types.push(&register_types[*position]);
+                            prefix_decodes.push(decodes[index].clone());
+                        }
+                        _ => prefix_decodes.push(quote! {
+                            let #ident = <_>::default();
+                        }),
+                    }
+                }
+
+                quote! {
+                    let prefix = (|| -> core::result::Result<Self, ssz::DecodeError> {
+                        let mut builder = ssz::SszDecoderBuilder::new(bytes);
+
+                        #(
+                            #prefix_register_types
+                        )*
+
+                        let mut decoder = builder.build()?;
+
+                        #(
+                            #prefix_decodes
+                        )*
+
+                        Ok(Self {
+                            #(
+                                #field_names,
+                            )*
+                        })
+                    })();
+                    if prefix.is_ok() {
+                        return prefix;
+                    }
+                }
+            });
+
+            quote! {
+                let full = (|| -> core::result::Result<Self, ssz::DecodeError> {
+                    #full_decode
+                })();
+                if full.is_ok() {
+                    return full;
+                }
+
+                #(
+                    #prefix_decodes
+                )*
+
+                full
+            }
+        }
+    };
+
     let output = quote! {
         impl #impl_generics ssz::Decode for #name #ty_generics #where_clause {
             fn is_ssz_fixed_len() -> bool {
@@ -1278,44 +1493,8 @@ fn ssz_decode_derive_struct(item: &DeriveInput, struct_data: &DataStruct) -> Tok
                 }
             }
 
-            fn from_ssz_bytes(bytes: &[u8]) -> std::result::Result<Self, ssz::DecodeError> {
-                if <Self as ssz::Decode>::is_ssz_fixed_len() {
-                    if bytes.len() != <Self as ssz::Decode>::ssz_fixed_len() {
-                        return Err(ssz::DecodeError::InvalidByteLength {
-                            len: bytes.len(),
-                            expected: <Self as ssz::Decode>::ssz_fixed_len(),
-                        });
-                    }
-
-                    #(
-                        #fixed_decodes
-                    )*
-
-                    Ok(Self {
-                        #(
-                            #field_names,
-                        )*
-                    })
-                } else {
-                    let mut builder = ssz::SszDecoderBuilder::new(bytes);
-
-                    #(
-                        #register_types
-                    )*
-
-                    let mut decoder = builder.build()?;
-
-                    #(
-                        #decodes
-                    )*
-
-
-                    Ok(Self {
-                        #(
-                            #field_names,
-                        )*
-                    })
-                }
+            fn from_ssz_bytes(bytes: &[u8]) -> core::result::Result<Self, ssz::DecodeError> {
+                #from_ssz_bytes
             }
         }
     };
@@ -1392,7 +1571,7 @@ fn ssz_decode_derive_struct_transparent(
                 <#ty as ssz::Decode>::ssz_fixed_len()
             }
 
-            fn from_ssz_bytes(bytes: &[u8]) -> std::result::Result<Self, ssz::DecodeError> {
+            fn from_ssz_bytes(bytes: &[u8]) -> core::result::Result<Self, ssz::DecodeError> {
                 Ok(Self {
                     #(
                         #fields
@@ -1511,9 +1690,8 @@ fn ssz_decode_derive_stable_container(
                         i: usize::max_value()
                     })?;
                 let slice = bytes.get(start..end)
-                    .ok_or_else(|| ssz::DecodeError::InvalidByteLength {
-                        len: bytes.len(),
-                        expected: end
+                    .ok_or_else(|| ssz::DecodeError::Incomplete {
+                        needed: end - bytes.len(),
                     })?;
                 Optional::Some(#from_ssz_bytes?)
             } else {
@@ -1541,13 +1719,12 @@ fn ssz_decode_derive_stable_container(
                 ssz::BYTES_PER_LENGTH_OFFSET
             }
 
-            fn from_ssz_bytes(bytes: &[u8]) -> std::result::Result<Self, ssz::DecodeError> {
+            fn from_ssz_bytes(bytes: &[u8]) -> core::result::Result<Self, ssz::DecodeError> {
                 // Decode the leading BitVector first.
                 let bitvector_length: usize = #max_fields.div_ceil(8);
                 if bytes.len() < bitvector_length {
-                    return Err(ssz::DecodeError::InvalidByteLength {
-                        len: bytes.len(),
-                        expected: bitvector_length,
+                    return Err(ssz::DecodeError::Incomplete {
+                        needed: bitvector_length - bytes.len(),
                     });
                 }
                 let bitvector =
@@ -1734,9 +1911,8 @@ fn ssz_decode_derive_profile_container(
                                 i: usize::max_value()
                             })?;
                         let slice = bytes.get(start..end)
-                            .ok_or_else(|| ssz::DecodeError::InvalidByteLength {
-                                len: bytes.len(),
-                                expected: end
+                            .ok_or_else(|| ssz::DecodeError::Incomplete {
+                                needed: end - bytes.len(),
                             })?;
                         #from_ssz_bytes?
                 } else {
@@ -1757,9 +1933,8 @@ fn ssz_decode_derive_profile_container(
                             i: usize::max_value()
                         })?;
                     let slice = bytes.get(start..end)
-                        .ok_or_else(|| ssz::DecodeError::InvalidByteLength {
-                            len: bytes.len(),
-                            expected: end
+                        .ok_or_else(|| ssz::DecodeError::Incomplete {
+                            needed: end - bytes.len(),
                         })?;
                     #from_ssz_bytes?
             };
@@ -1810,13 +1985,12 @@ fn ssz_decode_derive_profile_container(
                 }
             }
 
-            fn from_ssz_bytes(bytes: &[u8]) -> std::result::Result<Self, ssz::DecodeError> {
+            fn from_ssz_bytes(bytes: &[u8]) -> core::result::Result<Self, ssz::DecodeError> {
                 // Decode the leading BitVector first.
                 let bitvector_length: usize = #max_optional_fields.div_ceil(8);
                 if bytes.len() < bitvector_length {
-                    return Err(ssz::DecodeError::InvalidByteLength {
-                        len: bytes.len(),
-                        expected: bitvector_length,
+                    return Err(ssz::DecodeError::Incomplete {
+                        needed: bitvector_length - bytes.len(),
                     });
                 }
                 let bitvector = if bitvector_length == 0 {
@@ -1899,7 +2073,7 @@ fn ssz_decode_derive_enum_tag(derive_input: &DeriveInput, enum_data: &DataEnum)
                 1
             }
 
-            fn from_ssz_bytes(bytes: &[u8]) -> std::result::Result<Self, ssz::DecodeError> {
+            fn from_ssz_bytes(bytes: &[u8]) -> core::result::Result<Self, ssz::DecodeError> {
                 let byte = bytes
                     .first()
                     .copied()
@@ -1962,7 +2136,7 @@ fn ssz_decode_derive_enum_union(derive_input: &DeriveInput, enum_data: &DataEnum
     let none_constructor = if start_index == 1 {
         let variant_name = &enum_data.variants[0].ident;
         quote! {
-            0 => Ok(#name::#variant_name),
+            0 => ssz::check_union_body_len(body, 0).map(|()| #name::#variant_name),
         }
     } else {
         quote! {}
@@ -1976,8 +2150,8 @@ fn ssz_decode_derive_enum_union(derive_input: &DeriveInput, enum_data: &DataEnum
 
             fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, ssz::DecodeError> {
                 // Sanity check to ensure the definition here does not drift from the one defined in
-                // `ssz`.
-                debug_assert_eq!(#MAX_UNION_SELECTOR, ssz::MAX_UNION_SELECTOR);
+                // `ssz`, which the `union_extended` feature may raise.
+                debug_assert!(#MAX_UNION_SELECTOR <= ssz::MAX_UNION_SELECTOR);
 
                 let (selector, body) = ssz::split_union_bytes(bytes)?;
 
@@ -1985,7 +2159,15 @@ fn ssz_decode_derive_enum_union(derive_input: &DeriveInput, enum_data: &DataEnum
                     #none_constructor
                     #(
                         #union_selectors => {
-                            <#var_types as ssz::Decode>::from_ssz_bytes(body).map(#constructors)
+                            if <#var_types as ssz::Decode>::is_ssz_fixed_len() {
+                                ssz::check_union_body_len(
+                                    body,
+                                    <#var_types as ssz::Decode>::ssz_fixed_len(),
+                                )?;
+                            }
+                            <#var_types as ssz::Decode>::from_ssz_bytes(body)
+                                .map(#constructors)
+                                .map_err(|e| e.bounded(body.len()))
                         },
                     )*
                     other => Err(ssz::DecodeError::UnionSelectorInvalid(other))
@@ -14,6 +14,10 @@ use syn as _;
 
 fn assert_encode<T: Encode>(item: &T, bytes: &[u8]) {
     assert_eq!(item.as_ssz_bytes(), bytes);
+
+    let mut written = vec![];
+    item.ssz_write(&mut written).unwrap();
+    assert_eq!(written, bytes);
 }
 
 fn assert_encode_decode<T: Encode + Decode + PartialEq + Debug>(item: &T, bytes: &[u8]) {
@@ -267,6 +271,59 @@ fn transparent_struct_newtype_skipped_field_reverse() {
     );
 }
 
+#[derive(PartialEq, Debug, Encode, Decode)]
+struct GenericStructSkippedPhantom<T> {
+    a: u16,
+    b: Vec<u8>,
+    #[ssz(skip)]
+    marker: PhantomData<T>,
+}
+
+#[test]
+fn generic_struct_skipped_phantom_field() {
+    // `T` needs no SSZ impls since the marker field is skipped.
+    #[derive(PartialEq, Debug)]
+    struct NotSsz;
+
+    let item = GenericStructSkippedPhantom::<NotSsz> {
+        a: 42,
+        b: vec![1, 2],
+        marker: PhantomData,
+    };
+    assert_encode_decode(&item, &[42, 0, 6, 0, 0, 0, 1, 2]);
+}
+
+// A type parameter named `W` must not clash with the generic of `ssz_write`.
+#[derive(PartialEq, Debug, Encode, Decode)]
+struct GenericStructW<W: Encode + Decode> {
+    a: u16,
+    b: W,
+}
+
+#[derive(PartialEq, Debug, Encode, Decode)]
+#[ssz(struct_behaviour = "transparent")]
+struct TransparentStructW<W: Encode + Decode>(W);
+
+#[derive(PartialEq, Debug, Encode, Decode)]
+#[ssz(enum_behaviour = "union")]
+enum GenericUnionW<W: Encode + Decode> {
+    A(u8),
+    B(W),
+}
+
+#[test]
+fn generic_param_named_w() {
+    assert_encode_decode(
+        &GenericStructW::<Vec<u8>> {
+            a: 42,
+            b: vec![1, 2],
+        },
+        &[42, 0, 6, 0, 0, 0, 1, 2],
+    );
+    assert_encode_decode(&TransparentStructW(7_u16), &[7, 0]);
+    assert_encode_decode(&GenericUnionW::<u16>::B(7), &[1, 7, 0]);
+}
+
 // Shape tests from EIP.
 #[derive(PartialEq, Debug, Encode, Decode)]
 #[ssz(struct_behaviour = "stable_container")]
@@ -505,3 +562,53 @@ fn explicit_transparent_newtype() {
         &vec![42_u8].as_ssz_bytes(),
     );
 }
+
+#[derive(PartialEq, Debug, Encode, Decode)]
+struct OptionalTailFixed {
+    a: u16,
+    #[ssz(optional_tail)]
+    b: u8,
+    #[ssz(optional_tail)]
+    c: u8,
+}
+
+#[test]
+fn optional_tail_fixed() {
+    assert_encode_decode(&OptionalTailFixed { a: 1, b: 2, c: 3 }, &[1, 0, 2, 3]);
+    assert_eq!(
+        OptionalTailFixed::from_ssz_bytes(&[1, 0, 2]).unwrap(),
+        OptionalTailFixed { a: 1, b: 2, c: 0 }
+    );
+    assert_eq!(
+        OptionalTailFixed::from_ssz_bytes(&[1, 0]).unwrap(),
+        OptionalTailFixed { a: 1, b: 0, c: 0 }
+    );
+    assert_eq!(
+        OptionalTailFixed::from_ssz_bytes(&[1]),
+        Err(DecodeError::Incomplete { needed: 3 })
+    );
+}
+
+#[derive(PartialEq, Debug, Encode, Decode)]
+struct OptionalTailVariable {
+    a: Vec<u8>,
+    #[ssz(optional_tail)]
+    b: Vec<u8>,
+}
+
+#[test]
+fn optional_tail_variable() {
+    let item = OptionalTailVariable {
+        a: vec![1],
+        b: vec![2, 3],
+    };
+    assert_encode_decode(&item, &[8, 0, 0, 0, 9, 0, 0, 0, 1, 2, 3]);
+    assert_eq!(
+        OptionalTailVariable::from_ssz_bytes(&[4, 0, 0, 0, 1]).unwrap(),
+        OptionalTailVariable {
+            a: vec![1],
+            b: vec![],
+        }
+    );
+    assert!(OptionalTailVariable::from_ssz_bytes(&[4, 0, 0]).is_err());
+}
@@ -6,11 +6,20 @@
 //! This module provides primitive types that were previously imported from alloy-primitives,
 //! now implemented locally to remove the ethereum-specific dependency.
 
-use std::str::FromStr;
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "serde")]
+extern crate alloc;
+
+#[cfg(feature = "serde")]
+use alloc::{format, string::String, vec::Vec};
+use core::{fmt, str::FromStr};
 
 #[cfg(feature = "rand")]
 use rand::RngCore;
 use ruint::Uint;
+#[cfg(test)]
+use serde_json as _;
 
 /// A 256-bit unsigned integer type.
 pub type U256 = Uint<256, 4>;
@@ -18,6 +27,43 @@ pub type U256 = Uint<256, 4>;
 /// A 128-bit unsigned integer type.
 pub type U128 = Uint<128, 2>;
 
+/// Conversions between a [`U256`] and its four 64-bit limbs, least
+/// significant limb first.
+///
+/// The limb order is defined here rather than taken from `ruint`'s internal
+/// representation, so it does not change with the `ruint` version. `ruint`'s
+/// inherent `Uint::from_limbs` shadows [`U256Limbs::from_limbs`] in
+/// `U256::from_limbs(..)`; call `<U256 as U256Limbs>::from_limbs(..)` to use
+/// this one.
+pub trait U256Limbs: Sized {
+    /// Builds the value from little-endian limbs: `limbs[0]` holds bits
+    /// 0..64 and `limbs[3]` holds bits 192..256.
+    fn from_limbs(limbs: [u64; 4]) -> Self;
+
+    /// Returns the value's little-endian limbs, the inverse of
+    /// [`U256Limbs::from_limbs`].
+    fn to_limbs(&self) -> [u64; 4];
+}
+
+impl U256Limbs for U256 {
+    fn from_limbs(limbs: [u64; 4]) -> Self {
+        let mut bytes = [0u8; 32];
+        for (chunk, limb) in bytes.as_chunks_mut::<8>().0.iter_mut().zip(limbs) {
+            *chunk = limb.to_le_bytes();
+        }
+        Self::from_le_bytes(bytes)
+    }
+
+    fn to_limbs(&self) -> [u64; 4] {
+        let bytes = self.to_le_bytes::<32>();
+        let mut limbs = [0u64; 4];
+        for (limb, chunk) in limbs.iter_mut().zip(bytes.as_chunks::<8>().0) {
+            *limb = u64::from_le_bytes(*chunk);
+        }
+        limbs
+    }
+}
+
 /// A fixed-size byte array type.
 ///
 /// This replaces `alloy_primitives::FixedBytes<N>` to remove the Ethereum dependency.
@@ -85,6 +131,11 @@ impl<const N: usize> FixedBytes<N> {
         self.0
     }
 
+    /// Iterate over the bytes
+    pub fn iter(&self) -> core::slice::Iter<'_, u8> {
+        self.0.iter()
+    }
+
     /// Create from a hex string (with or without 0x prefix)
     pub fn from_hex_str(s: &str) -> Result<Self, hex::FromHexError> {
         let s = s.strip_prefix("0x").unwrap_or(s);
@@ -104,6 +155,24 @@ impl<const N: usize> From<[u8; N]> for FixedBytes<N> {
     }
 }
 
+impl<const N: usize> IntoIterator for FixedBytes<N> {
+    type Item = u8;
+    type IntoIter = core::array::IntoIter<u8, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, const N: usize> IntoIterator for &'a FixedBytes<N> {
+    type Item = &'a u8;
+    type IntoIter = core::slice::Iter<'a, u8>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
 impl<const N: usize> AsRef<[u8]> for FixedBytes<N> {
     fn as_ref(&self) -> &[u8] {
         &self.0
@@ -124,6 +193,48 @@ impl<const N: usize> FromStr for FixedBytes<N> {
     }
 }
 
+/// Formats as `0x`-prefixed lowercase hex.
+impl<const N: usize> fmt::LowerHex for FixedBytes<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x{}", hex::encode(self.0))
+    }
+}
+
+impl<const N: usize> fmt::Display for FixedBytes<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(self, f)
+    }
+}
+
+/// Serializes as a `0x`-prefixed hex string, matching the consensus spec's JSON format.
+#[cfg(feature = "serde")]
+impl<const N: usize> serde::Serialize for FixedBytes<N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Deserializes from a hex string of exactly `N` bytes, with or without the `0x` prefix.
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> serde::Deserialize<'de> for FixedBytes<N> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        let bytes =
+            hex::decode(s.strip_prefix("0x").unwrap_or(&s)).map_err(serde::de::Error::custom)?;
+        let bytes: [u8; N] = bytes.try_into().map_err(|bytes: Vec<u8>| {
+            serde::de::Error::custom(format!("expected {N} bytes of hex, got {}", bytes.len()))
+        })?;
+        Ok(Self(bytes))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, const N: usize> arbitrary::Arbitrary<'a> for FixedBytes<N> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        <[u8; N]>::arbitrary(u).map(Self)
+    }
+}
+
 /// A 256-bit hash type (32 bytes).
 pub type Hash256 = FixedBytes<32>;
 
@@ -139,3 +250,100 @@ impl From<U256> for Hash256 {
         Self(value.to_le_bytes::<32>())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed_bytes_into_iter() {
+        let bytes = FixedBytes::<4>([1, 2, 3, 4]);
+        let collected: Vec<u8> = bytes.into_iter().collect();
+        assert_eq!(collected, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn fixed_bytes_iter_by_ref() {
+        let bytes = FixedBytes::<4>([1, 2, 3, 4]);
+
+        let mut sum = 0;
+        for byte in &bytes {
+            sum += *byte;
+        }
+        assert_eq!(sum, 10);
+        assert_eq!(
+            bytes.iter().rev().copied().collect::<Vec<_>>(),
+            vec![4, 3, 2, 1]
+        );
+    }
+
+    #[test]
+    fn fixed_bytes_display() {
+        let bytes = FixedBytes::<4>([0xde, 0xad, 0xbe, 0xef]);
+        assert_eq!(bytes.to_string(), "0xdeadbeef");
+        assert_eq!(format!("{bytes:x}"), "0xdeadbeef");
+    }
+
+    #[test]
+    fn u256_from_limbs_little_endian() {
+        assert_eq!(
+            <U256 as U256Limbs>::from_limbs([1, 0, 0, 0]),
+            U256::from(1u64)
+        );
+        assert_eq!(
+            <U256 as U256Limbs>::from_limbs([0, 1, 0, 0]),
+            U256::from(1u128 << 64)
+        );
+        assert_eq!(
+            <U256 as U256Limbs>::from_limbs([0, 0, 0, 1 << 63]),
+            U256::from(1u64) << 255
+        );
+        assert_eq!(<U256 as U256Limbs>::from_limbs([u64::MAX; 4]), U256::MAX);
+    }
+
+    #[test]
+    fn u256_to_limbs_little_endian() {
+        let value =
+            U256::from_str_radix("0004000000000000000300000000000000020000000000000001", 16)
+                .unwrap();
+        assert_eq!(value.to_limbs(), [1, 2, 3, 4]);
+        assert_eq!(U256::ZERO.to_limbs(), [0; 4]);
+        assert_eq!(U256::MAX.to_limbs(), [u64::MAX; 4]);
+    }
+
+    #[test]
+    fn u256_limbs_round_trip() {
+        let limbs = [
+            0x0123_4567_89ab_cdef,
+            0xfedc_ba98_7654_3210,
+            0,
+            0x8000_0000_0000_0001,
+        ];
+        let value = <U256 as U256Limbs>::from_limbs(limbs);
+        assert_eq!(value.to_limbs(), limbs);
+        assert_eq!(<U256 as U256Limbs>::from_limbs(value.to_limbs()), value);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn fixed_bytes_serde_round_trip() {
+        let bytes = FixedBytes::<4>([0xde, 0xad, 0xbe, 0xef]);
+        let json = serde_json::to_string(&bytes).unwrap();
+        assert_eq!(json, r#""0xdeadbeef""#);
+        assert_eq!(serde_json::from_str::<FixedBytes<4>>(&json).unwrap(), bytes);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn fixed_bytes_serde_wrong_length() {
+        let err = serde_json::from_str::<FixedBytes<4>>(r#""0xdeadbe""#).unwrap_err();
+        assert!(err.to_string().contains("expected 4 bytes of hex, got 3"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn fixed_bytes_serde_without_prefix() {
+        let bytes: FixedBytes<4> = serde_json::from_str(r#""deadbeef""#).unwrap();
+        assert_eq!(bytes.0, [0xde, 0xad, 0xbe, 0xef]);
+    }
+}
@@ -0,0 +1,43 @@
+//! Benchmarks comparing `as_ssz_bytes` followed by `write_all` against streaming with
+//! `ssz_write`.
+
+#![allow(missing_docs, reason = "criterion macros are annoying clippy")]
+#![allow(
+    unused_crate_dependencies,
+    reason = "criterion macros are annoying clippy"
+)]
+
+use std::{hint::black_box, io::Write};
+
+use criterion::{Criterion, Throughput, criterion_group, criterion_main};
+use ssz::Encode;
+use ssz_types::VariableList;
+
+const ONE_MIB: usize = 1 << 20;
+
+type ByteList = VariableList<u8, ONE_MIB>;
+
+fn bench_write_byte_list(c: &mut Criterion) {
+    let list = ByteList::new((0..ONE_MIB).map(|i| (i % 256) as u8).collect()).unwrap();
+
+    let mut group = c.benchmark_group("write_byte_list_1mib");
+    group.throughput(Throughput::Bytes(ONE_MIB as u64));
+
+    group.bench_function("as_ssz_bytes_write_all", |b| {
+        let mut sink = std::io::sink();
+        b.iter(|| {
+            let bytes = black_box(&list).as_ssz_bytes();
+            sink.write_all(&bytes).unwrap();
+        })
+    });
+
+    group.bench_function("ssz_write", |b| {
+        let mut sink = std::io::sink();
+        b.iter(|| black_box(&list).ssz_write(&mut sink).unwrap())
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_write_byte_list);
+criterion_main!(benches);
@@ -0,0 +1,23 @@
+//! Runtime description of the fields of generated containers.
+
+use alloc::string::String;
+
+use tree_hash::TreeHashType;
+
+/// SSZ layout of one field of a generated container, as returned by the
+/// container's `fields()` method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldInfo {
+    /// The field name, as declared in the schema.
+    pub name: &'static str,
+    /// Whether the field has a fixed SSZ length and is encoded inline rather
+    /// than behind an offset.
+    pub is_fixed_size: bool,
+    /// How the field is merkleized.
+    pub tree_hash_type: TreeHashType,
+}
+
+/// One differing field of two generated containers, as returned by the
+/// container's `diff()` method: the field name, then the `Debug` output of
+/// the field in `self` and in `other`.
+pub type FieldDiff = (&'static str, String, String);
@@ -1,7 +1,8 @@
 // Modified in 2025 from the original version
 // Original source licensed under the Apache License 2.0
 
-use std::{
+use alloc::{format, vec, vec::Vec};
+use core::{
     ops::{Deref, DerefMut, Index, IndexMut},
     slice::SliceIndex,
 };
@@ -61,13 +62,16 @@ impl<T: PartialEq, const N: usize> PartialEq for FixedVector<T, N> {
     }
 }
 impl<T: Eq, const N: usize> Eq for FixedVector<T, N> {}
-impl<T: std::hash::Hash, const N: usize> std::hash::Hash for FixedVector<T, N> {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+impl<T: core::hash::Hash, const N: usize> core::hash::Hash for FixedVector<T, N> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         self.vec.hash(state);
     }
 }
 
 impl<T, const N: usize> FixedVector<T, N> {
+    /// The type-level constant length, usable in const contexts such as array lengths.
+    pub const LEN: usize = N;
+
     /// Returns `Ok` if the given `vec` equals the fixed length of `Self`. Otherwise returns
     /// `Err`.
     pub fn new(vec: Vec<T>) -> Result<Self, Error> {
@@ -102,7 +106,7 @@ impl<T, const N: usize> FixedVector<T, N> {
     }
 
     /// Returns the type-level constant length.
-    pub fn capacity() -> usize {
+    pub const fn capacity() -> usize {
         N
     }
 }
@@ -179,7 +183,7 @@ impl<T, const N: usize> DerefMut for FixedVector<T, N> {
 
 impl<'a, T, const N: usize> IntoIterator for &'a FixedVector<T, N> {
     type Item = &'a T;
-    type IntoIter = std::slice::Iter<'a, T>;
+    type IntoIter = core::slice::Iter<'a, T>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
@@ -188,7 +192,7 @@ impl<'a, T, const N: usize> IntoIterator for &'a FixedVector<T, N> {
 
 impl<T, const N: usize> IntoIterator for FixedVector<T, N> {
     type Item = T;
-    type IntoIter = std::vec::IntoIter<T>;
+    type IntoIter = vec::IntoIter<T>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.vec.into_iter()
@@ -254,6 +258,11 @@ where
             encoder.finalize();
         }
     }
+
+    #[cfg(feature = "std")]
+    fn ssz_write<W: std::io::Write>(&self, w: &mut W) -> std::io::Result<()> {
+        self.vec.ssz_write(w)
+    }
 }
 
 impl<T, const N: usize> ssz::TryFromIter<T> for FixedVector<T, N> {
@@ -268,7 +277,7 @@ impl<T, const N: usize> ssz::TryFromIter<T> for FixedVector<T, N> {
 
         let (_, opt_max_len) = iter.size_hint();
         let mut vec =
-            Vec::with_capacity(opt_max_len.map_or(n, |max_len| std::cmp::min(n, max_len)));
+            Vec::with_capacity(opt_max_len.map_or(n, |max_len| core::cmp::min(n, max_len)));
 
         for item in iter {
             // Bail out as soon as the length tries to exceed the limit. This guards against
@@ -324,7 +333,7 @@ where
             let vec = bytes.chunks(T::ssz_fixed_len()).try_fold(
                 Vec::with_capacity(num_items),
                 |mut vec, chunk| {
-                    vec.push(T::from_ssz_bytes(chunk)?);
+                    vec.push(T::from_ssz_bytes(chunk).map_err(|e| e.bounded(chunk.len()))?);
                     Ok(vec)
                 },
             )?;
@@ -390,6 +399,18 @@ mod test {
 
     use super::*;
 
+    #[test]
+    fn const_len() {
+        const LEN: usize = FixedVector::<u8, 4>::LEN;
+        const CAPACITY: usize = FixedVector::<u8, 4>::capacity();
+        assert_eq!(LEN, 4);
+        assert_eq!(CAPACITY, 4);
+
+        let bytes = [0u8; FixedVector::<u8, 4>::LEN];
+        let vector = FixedVector::<u8, 4>::from(bytes);
+        assert_eq!(vector.len(), FixedVector::<u8, 4>::LEN);
+    }
+
     #[test]
     fn new() {
         let vec = vec![42; 5];
@@ -445,6 +466,27 @@ mod test {
         assert_eq!(fixed.get(4), None);
     }
 
+    #[test]
+    fn deref_coercion() {
+        fn sum(values: &[u64]) -> u64 {
+            values.iter().sum()
+        }
+
+        let mut fixed: FixedVector<u64, 4> = FixedVector::from(vec![1, 2, 3, 4]);
+        assert_eq!(sum(&fixed), 10);
+        assert_eq!(fixed.len(), 4);
+
+        fixed.reverse();
+        assert_eq!(fixed.iter().copied().collect::<Vec<_>>(), vec![4, 3, 2, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn index_out_of_bounds() {
+        let fixed: FixedVector<u64, 4> = FixedVector::from(vec![0; 4]);
+        let _ = fixed[4];
+    }
+
     #[test]
     fn iterator() {
         let vec = vec![0, 2, 4, 6];
@@ -463,7 +505,7 @@ mod test {
         assert_eq!(<FixedVector<u16, 2> as Encode>::ssz_fixed_len(), 4);
     }
 
-    fn ssz_round_trip<T: Encode + Decode + std::fmt::Debug + PartialEq>(item: T) {
+    fn ssz_round_trip<T: Encode + Decode + core::fmt::Debug + PartialEq>(item: T) {
         let encoded = &item.as_ssz_bytes();
         assert_eq!(item.ssz_bytes_len(), encoded.len());
         assert_eq!(T::from_ssz_bytes(encoded), Ok(item));
@@ -0,0 +1,30 @@
+//! Hex rendering of byte fields in the `Debug` output of generated
+//! containers.
+
+use core::fmt;
+
+/// Bytes whose `Debug` output is `0x`-prefixed lowercase hex, as used by the
+/// hex `Debug` impls of generated containers.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct HexBytes<'a>(pub &'a [u8]);
+
+impl fmt::Debug for HexBytes<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("0x")?;
+        for byte in self.0 {
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn debug_is_prefixed_hex() {
+        assert_eq!(format!("{:?}", HexBytes(&[0x0c, 0xff, 0x00])), "0x0cff00");
+        assert_eq!(format!("{:?}", HexBytes(&[])), "0x");
+    }
+}
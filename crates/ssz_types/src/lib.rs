@@ -15,6 +15,8 @@
 //! specification](https://github.com/ethereum/eth2.0-specs/blob/v0.12.1/ssz/simple-serialize.md)
 //! at v0.12.1.
 //!
+//! The `std` feature is on by default. Without it the crate builds on `core` and `alloc`.
+//!
 //! ## Example
 //! ```
 //! use ssz_types::*;
@@ -44,27 +46,36 @@
     incomplete_features,
     reason = "we need generic const exprs for BitVectorRef"
 )]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
 
+mod field_info;
 #[macro_use]
 mod fixed_vector;
+mod hex_bytes;
 mod optional;
 #[cfg(feature = "serde")]
 pub mod serde_utils;
 mod tree_hash;
+mod uint_n;
 mod variable_list;
 pub mod view;
 
 #[cfg(test)]
 use criterion as _;
+pub use field_info::{FieldDiff, FieldInfo};
 pub use fixed_vector::FixedVector;
+pub use hex_bytes::HexBytes;
 pub use optional::Optional;
 #[cfg(test)]
 use rand as _;
 #[cfg(any(test, doctest))]
 use serde_json as _;
 pub use ssz::{BitList, BitVector, Bitfield};
-pub use ssz_primitives::{FixedBytes, Hash256, U128, U256};
+pub use ssz_primitives::{FixedBytes, Hash256, U128, U256, U256Limbs};
 use thiserror::Error;
+pub use uint_n::UintN;
 pub use variable_list::VariableList;
 
 pub mod length {
@@ -102,4 +113,13 @@ pub enum Error {
         /// The number of bytes expected.
         expected: usize,
     },
+
+    /// A value does not fit in a `UintN`.
+    #[error("value {value} does not fit in {bits} bits")]
+    ValueTooLarge {
+        /// The value given.
+        value: u64,
+        /// The width of the integer.
+        bits: usize,
+    },
 }
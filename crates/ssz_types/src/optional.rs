@@ -1,6 +1,8 @@
 // Modified in 2025 from the original version
 // Original source licensed under the Apache License 2.0
 
+use alloc::vec::Vec;
+
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -58,6 +60,16 @@ impl<T> Optional<T> {
         }
     }
 
+    /// Returns the contained value or the default of `T`.
+    ///
+    /// Consumes the `self` value.
+    pub fn unwrap_or_default(self) -> T
+    where
+        T: Default,
+    {
+        self.unwrap_or_else(T::default)
+    }
+
     /// Converts from `&Optional<T>` to `Optional<&T>`.
     pub fn as_ref(&self) -> Optional<&T> {
         match self {
@@ -65,6 +77,32 @@ impl<T> Optional<T> {
             Optional::None => Optional::None,
         }
     }
+
+    /// Converts from `&mut Optional<T>` to `Optional<&mut T>`.
+    pub fn as_mut(&mut self) -> Optional<&mut T> {
+        match self {
+            Optional::Some(value) => Optional::Some(value),
+            Optional::None => Optional::None,
+        }
+    }
+
+    /// Maps an `Optional<T>` to `Optional<U>` by applying a function to the
+    /// contained value.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Optional<U> {
+        match self {
+            Optional::Some(value) => Optional::Some(f(value)),
+            Optional::None => Optional::None,
+        }
+    }
+
+    /// Returns `None` if the optional is `None`, otherwise calls `f` with the
+    /// contained value and returns the result.
+    pub fn and_then<U>(self, f: impl FnOnce(T) -> Optional<U>) -> Optional<U> {
+        match self {
+            Optional::Some(value) => f(value),
+            Optional::None => Optional::None,
+        }
+    }
 }
 
 impl<T> From<Option<T>> for Optional<T> {
@@ -76,7 +114,7 @@ impl<T> From<Option<T>> for Optional<T> {
     }
 }
 
-impl<T: Clone> From<Optional<T>> for Option<T> {
+impl<T> From<Optional<T>> for Option<T> {
     fn from(optional: Optional<T>) -> Self {
         match optional {
             Optional::Some(value) => Some(value),
@@ -154,6 +192,14 @@ where
             Optional::Some(inner) => inner.ssz_bytes_len(),
         }
     }
+
+    #[cfg(feature = "std")]
+    fn ssz_write<W: std::io::Write>(&self, w: &mut W) -> std::io::Result<()> {
+        match self {
+            Optional::None => Ok(()),
+            Optional::Some(inner) => inner.ssz_write(w),
+        }
+    }
 }
 
 impl<T> ssz::Decode for Optional<T>
@@ -240,7 +286,7 @@ mod test {
         );
     }
 
-    fn ssz_round_trip<T: Encode + Decode + std::fmt::Debug + PartialEq>(item: T) {
+    fn ssz_round_trip<T: Encode + Decode + core::fmt::Debug + PartialEq>(item: T) {
         let encoded = &item.as_ssz_bytes();
         assert_eq!(item.ssz_bytes_len(), encoded.len());
         assert_eq!(T::from_ssz_bytes(encoded), Ok(item));
@@ -252,6 +298,54 @@ mod test {
         ssz_round_trip::<Optional<u64>>(Optional::None);
     }
 
+    #[test]
+    fn combinators() {
+        let some: Optional<u64> = Optional::Some(21);
+        let none: Optional<u64> = Optional::None;
+
+        assert!(some.is_some() && !some.is_none());
+        assert!(none.is_none() && !none.is_some());
+
+        assert_eq!(some.map(|v| v * 2), Optional::Some(42));
+        assert_eq!(none.map(|v| v * 2), Optional::None);
+
+        let halve = |v: u64| {
+            if v.is_multiple_of(2) {
+                Optional::Some(v / 2)
+            } else {
+                Optional::None
+            }
+        };
+        assert_eq!(Optional::Some(42).and_then(halve), Optional::Some(21));
+        assert_eq!(some.and_then(halve), Optional::None);
+        assert_eq!(none.and_then(halve), Optional::None);
+
+        assert_eq!(some.unwrap_or(7), 21);
+        assert_eq!(none.unwrap_or(7), 7);
+        assert_eq!(some.unwrap_or_default(), 21);
+        assert_eq!(none.unwrap_or_default(), 0);
+
+        let owned: Optional<String> = Optional::Some("abc".to_string());
+        assert_eq!(owned.as_ref().map(|s| s.len()), Optional::Some(3));
+
+        let mut counter: Optional<u64> = Optional::Some(1);
+        if let Optional::Some(value) = counter.as_mut() {
+            *value += 1;
+        }
+        assert_eq!(counter, Optional::Some(2));
+    }
+
+    #[test]
+    fn option_conversions() {
+        assert_eq!(Optional::from(Some(5u8)), Optional::Some(5));
+        assert_eq!(Optional::<u8>::from(None), Optional::None);
+
+        let option: Option<String> = Optional::Some("abc".to_string()).into();
+        assert_eq!(option.as_deref(), Some("abc"));
+        let option: Option<u8> = Optional::None.into();
+        assert_eq!(option, None);
+    }
+
     #[test]
     fn tree_hash_none() {
         use tree_hash::{Sha256Hasher, TreeHashDigest};
@@ -0,0 +1,58 @@
+//! Serialize `BitList<N>` as a 0x-prefixed hex string of its SSZ encoding.
+use alloc::format;
+
+use serde::{Deserializer, Serializer};
+use ssz::{
+    Decode, Encode,
+    serde_utils::hex::{self, PrefixedHexVisitor},
+};
+
+use crate::BitList;
+
+/// Serialize a `BitList<N>` as a 0x-prefixed hex string, length bit included.
+pub fn serialize<S, const N: usize>(bits: &BitList<N>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&hex::encode(bits.as_ssz_bytes()))
+}
+
+/// Deserialize a `BitList<N>` from a 0x-prefixed hex string of its SSZ encoding.
+pub fn deserialize<'de, D, const N: usize>(deserializer: D) -> Result<BitList<N>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let bytes = deserializer.deserialize_str(PrefixedHexVisitor)?;
+    BitList::from_ssz_bytes(&bytes)
+        .map_err(|e| serde::de::Error::custom(format!("invalid bitlist: {e:?}")))
+}
+
+#[cfg(test)]
+mod test {
+    use serde_derive::{Deserialize, Serialize};
+
+    use crate::BitList;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Obj {
+        #[serde(with = "crate::serde_utils::hex_bitlist")]
+        bits: BitList<16>,
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut bits = BitList::<16>::with_capacity(10).unwrap();
+        bits.set(0, true).unwrap();
+        bits.set(9, true).unwrap();
+        let obj = Obj { bits };
+
+        let json = serde_json::to_string(&obj).unwrap();
+        assert_eq!(json, r#"{"bits":"0x0106"}"#);
+        assert_eq!(serde_json::from_str::<Obj>(&json).unwrap(), obj);
+    }
+
+    #[test]
+    fn rejects_missing_length_bit() {
+        serde_json::from_str::<Obj>(r#"{"bits":"0x0100"}"#).unwrap_err();
+    }
+}
@@ -0,0 +1,58 @@
+//! Serialize `BitVector<N>` as a 0x-prefixed hex string of its SSZ encoding.
+use alloc::format;
+
+use serde::{Deserializer, Serializer};
+use ssz::{
+    Decode, Encode,
+    serde_utils::hex::{self, PrefixedHexVisitor},
+};
+
+use crate::BitVector;
+
+/// Serialize a `BitVector<N>` as a 0x-prefixed hex string.
+pub fn serialize<S, const N: usize>(bits: &BitVector<N>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&hex::encode(bits.as_ssz_bytes()))
+}
+
+/// Deserialize a `BitVector<N>` from a 0x-prefixed hex string of exactly `N` bits.
+pub fn deserialize<'de, D, const N: usize>(deserializer: D) -> Result<BitVector<N>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let bytes = deserializer.deserialize_str(PrefixedHexVisitor)?;
+    BitVector::from_ssz_bytes(&bytes)
+        .map_err(|e| serde::de::Error::custom(format!("invalid bitvector: {e:?}")))
+}
+
+#[cfg(test)]
+mod test {
+    use serde_derive::{Deserialize, Serialize};
+
+    use crate::BitVector;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Obj {
+        #[serde(with = "crate::serde_utils::hex_bitvector")]
+        bits: BitVector<16>,
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut bits = BitVector::<16>::new();
+        bits.set(1, true).unwrap();
+        bits.set(15, true).unwrap();
+        let obj = Obj { bits };
+
+        let json = serde_json::to_string(&obj).unwrap();
+        assert_eq!(json, r#"{"bits":"0x0280"}"#);
+        assert_eq!(serde_json::from_str::<Obj>(&json).unwrap(), obj);
+    }
+
+    #[test]
+    fn rejects_wrong_length() {
+        serde_json::from_str::<Obj>(r#"{"bits":"0x02"}"#).unwrap_err();
+    }
+}
@@ -3,6 +3,8 @@
 
 //! Serde utilities for `FixedVector`
 
+use alloc::format;
+
 use serde::{Deserializer, Serializer};
 use ssz::serde_utils::hex::{self, PrefixedHexVisitor};
 
@@ -2,6 +2,8 @@
 // Original source licensed under the Apache License 2.0
 
 //! Serialize `VariableList<u8, N>` as 0x-prefixed hex string.
+use alloc::format;
+
 use serde::{Deserializer, Serializer};
 use ssz::serde_utils::hex::{self, PrefixedHexVisitor};
 
@@ -2,6 +2,8 @@
 // Original source licensed under the Apache License 2.0
 
 //! Serialize `VaraibleList<VariableList<u8, M>, N>` as list of 0x-prefixed hex string.
+use alloc::format;
+
 use serde::{Deserializer, Serializer, ser::SerializeSeq};
 use serde_derive::{Deserialize, Serialize};
 
@@ -43,7 +45,7 @@ pub struct Visitor<const M: usize, const N: usize>;
 impl<'a, const M: usize, const N: usize> serde::de::Visitor<'a> for Visitor<M, N> {
     type Value = VariableList<VariableList<u8, M>, N>;
 
-    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(formatter, "a list of 0x-prefixed hex bytes")
     }
 
@@ -3,6 +3,8 @@
 
 //! Serde utilities for SSZ types.
 
+pub mod hex_bitlist;
+pub mod hex_bitvector;
 pub mod hex_fixed_vec;
 pub mod hex_var_list;
 pub mod list_of_hex_fixed_vec;
@@ -12,3 +14,5 @@ pub mod quoted_u64_fixed_vec;
 pub mod quoted_u64_var_list;
 /// Wrapper for quoted u64 serialization support.
 pub mod quoted_u64_vec;
+/// Quoted decimal string serialization for `U128` and `U256`.
+pub mod quoted_uint;
@@ -7,7 +7,8 @@
 //!
 //! Quotes can be optional during decoding.
 
-use std::{convert::TryFrom, marker::PhantomData};
+use alloc::format;
+use core::{convert::TryFrom, marker::PhantomData};
 
 use serde::{Deserializer, Serializer};
 use serde_derive::{Deserialize, Serialize};
@@ -29,7 +30,7 @@ macro_rules! define_mod {
         {
             type Value = T;
 
-            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
                 if self.require_quotes {
                     write!(formatter, "a quoted integer")
                 } else {
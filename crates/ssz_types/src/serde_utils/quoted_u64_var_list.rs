@@ -8,7 +8,8 @@
 //! Quotes can be optional during decoding. If the length of the `Vec` is greater than `N`,
 //! deserialization fails.
 
-use std::{iter, marker::PhantomData};
+use alloc::format;
+use core::{iter, marker::PhantomData};
 
 use itertools::process_results;
 use serde::{Deserializer, Serializer, de::Error, ser::SerializeSeq};
@@ -28,7 +29,7 @@ where
 {
     type Value = C;
 
-    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(formatter, "a list of quoted or unquoted integers")
     }
 
@@ -0,0 +1,60 @@
+//! Formats wide unsigned integers such as `U128` and `U256` as quoted decimal strings.
+//!
+//! E.g., `U256::from(1)` serializes as `"1"`, matching how the consensus spec's JSON encodes
+//! integers that don't fit in a JSON number. Deserialization requires quotes.
+
+use alloc::string::String;
+use core::{fmt::Display, str::FromStr};
+
+use serde::{Deserialize, Deserializer, Serializer};
+
+/// Serialize as a quoted decimal string.
+pub fn serialize<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Display,
+{
+    serializer.collect_str(value)
+}
+
+/// Deserialize from a quoted decimal string.
+pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: Display,
+{
+    let s = String::deserialize(deserializer)?;
+    s.parse().map_err(serde::de::Error::custom)
+}
+
+#[cfg(test)]
+mod test {
+    use serde_derive::{Deserialize, Serialize};
+
+    use crate::{U128, U256};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Obj {
+        #[serde(with = "crate::serde_utils::quoted_uint")]
+        small: U128,
+        #[serde(with = "crate::serde_utils::quoted_uint")]
+        large: U256,
+    }
+
+    #[test]
+    fn round_trip() {
+        let obj = Obj {
+            small: U128::from(7u64),
+            large: U256::MAX,
+        };
+        let json = serde_json::to_string(&obj).unwrap();
+        assert_eq!(json, format!(r#"{{"small":"7","large":"{}"}}"#, U256::MAX));
+        assert_eq!(serde_json::from_str::<Obj>(&json).unwrap(), obj);
+    }
+
+    #[test]
+    fn requires_quotes() {
+        serde_json::from_str::<Obj>(r#"{"small":7,"large":"1"}"#).unwrap_err();
+    }
+}
@@ -0,0 +1,260 @@
+//! Unsigned integers of widths without a Rust primitive, such as `uint24`.
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use ssz::{Decode, DecodeError, Encode};
+use tree_hash::{PackedEncoding, TreeHash, TreeHashDigest, TreeHashType};
+
+use crate::Error;
+
+/// An unsigned integer of `BITS` bits, for schema widths such as `uint24` or
+/// `uint48` that have no Rust primitive.
+///
+/// The value is kept in a `u64`, so `BITS` must be a multiple of 8 no larger
+/// than 64. It encodes as `BITS / 8` little-endian bytes and tree-hashes as
+/// those bytes right-padded to a 32-byte chunk. Since such widths don't divide
+/// a chunk evenly, a list or vector of them puts every value in its own chunk
+/// instead of packing them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct UintN<const BITS: usize>(u64);
+
+impl<const BITS: usize> UintN<BITS> {
+    /// Number of bytes in the SSZ encoding.
+    pub const BYTES: usize = {
+        assert!(
+            BITS > 0 && BITS <= 64 && BITS.is_multiple_of(8),
+            "UintN width must be a multiple of 8 between 8 and 64"
+        );
+        BITS / 8
+    };
+
+    /// The largest representable value.
+    pub const MAX: Self = Self(u64::MAX >> (64 - Self::BYTES * 8));
+
+    /// The value zero.
+    pub const ZERO: Self = Self(0);
+
+    /// Returns `value` as a `BITS`-bit integer, or `None` if it doesn't fit.
+    pub const fn new(value: u64) -> Option<Self> {
+        if value <= Self::MAX.0 {
+            Some(Self(value))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value as a `u64`.
+    pub const fn get(self) -> u64 {
+        self.0
+    }
+}
+
+impl<const BITS: usize> TryFrom<u64> for UintN<BITS> {
+    type Error = Error;
+
+    fn try_from(value: u64) -> Result<Self, Error> {
+        Self::new(value).ok_or(Error::ValueTooLarge { value, bits: BITS })
+    }
+}
+
+impl<const BITS: usize> From<UintN<BITS>> for u64 {
+    fn from(value: UintN<BITS>) -> Self {
+        value.0
+    }
+}
+
+impl<const BITS: usize> fmt::Display for UintN<BITS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<const BITS: usize> Encode for UintN<BITS> {
+    fn is_ssz_fixed_len() -> bool {
+        true
+    }
+
+    fn ssz_fixed_len() -> usize {
+        Self::BYTES
+    }
+
+    fn ssz_append(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.0.to_le_bytes()[..Self::BYTES]);
+    }
+
+    fn ssz_bytes_len(&self) -> usize {
+        Self::BYTES
+    }
+}
+
+impl<const BITS: usize> Decode for UintN<BITS> {
+    fn is_ssz_fixed_len() -> bool {
+        true
+    }
+
+    fn ssz_fixed_len() -> usize {
+        Self::BYTES
+    }
+
+    fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        if bytes.len() != Self::BYTES {
+            return Err(DecodeError::InvalidByteLength {
+                len: bytes.len(),
+                expected: Self::BYTES,
+            });
+        }
+        let mut le = [0u8; 8];
+        le[..Self::BYTES].copy_from_slice(bytes);
+        Ok(Self(u64::from_le_bytes(le)))
+    }
+}
+
+impl<'a, const BITS: usize> ssz::view::DecodeView<'a> for UintN<BITS> {
+    fn from_ssz_bytes(bytes: &'a [u8]) -> Result<Self, DecodeError> {
+        ssz::view::reject_empty(bytes, Self::BYTES)?;
+        Decode::from_ssz_bytes(bytes)
+    }
+}
+
+impl<const BITS: usize> ssz::view::SszTypeInfo for UintN<BITS> {
+    fn is_ssz_fixed_len() -> bool {
+        true
+    }
+
+    fn ssz_fixed_len() -> usize {
+        Self::BYTES
+    }
+}
+
+impl<const BITS: usize> TreeHash for UintN<BITS> {
+    fn tree_hash_type() -> TreeHashType {
+        TreeHashType::Basic
+    }
+
+    fn tree_hash_packed_encoding(&self) -> PackedEncoding {
+        let mut chunk = PackedEncoding::from_slice(&self.0.to_le_bytes()[..Self::BYTES]);
+        chunk.resize(tree_hash::BYTES_PER_CHUNK, 0);
+        chunk
+    }
+
+    fn tree_hash_packing_factor() -> usize {
+        1
+    }
+
+    fn tree_hash_root<H: TreeHashDigest>(&self) -> H::Output {
+        H::from_bytes(&self.tree_hash_packed_encoding())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<const BITS: usize> serde::Serialize for UintN<BITS> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_u64(self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const BITS: usize> serde::Deserialize<'de> for UintN<BITS> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = u64::deserialize(deserializer)?;
+        Self::try_from(value).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, const BITS: usize> arbitrary::Arbitrary<'a> for UintN<BITS> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self(u64::arbitrary(u)? & Self::MAX.0))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use tree_hash::Sha256Hasher;
+
+    use super::*;
+
+    #[test]
+    fn bounds() {
+        assert_eq!(UintN::<24>::MAX.get(), 0xff_ffff);
+        assert_eq!(UintN::<48>::MAX.get(), 0xffff_ffff_ffff);
+        assert_eq!(UintN::<24>::new(0x100_0000), None);
+        assert_eq!(
+            UintN::<24>::try_from(0x100_0000),
+            Err(Error::ValueTooLarge {
+                value: 0x100_0000,
+                bits: 24
+            })
+        );
+        assert_eq!(u64::from(UintN::<48>::new(7).unwrap()), 7);
+    }
+
+    #[test]
+    fn ssz_round_trip() {
+        let value = UintN::<24>::new(0x01_0203).unwrap();
+        assert_eq!(value.as_ssz_bytes(), vec![0x03, 0x02, 0x01]);
+        assert_eq!(<UintN<24> as Decode>::ssz_fixed_len(), 3);
+        assert_eq!(UintN::<24>::from_ssz_bytes(&[0x03, 0x02, 0x01]), Ok(value));
+
+        let value = UintN::<48>::new(0x0102_0304_0506).unwrap();
+        assert_eq!(value.as_ssz_bytes(), vec![6, 5, 4, 3, 2, 1]);
+        assert_eq!(UintN::<48>::from_ssz_bytes(&[6, 5, 4, 3, 2, 1]), Ok(value));
+    }
+
+    #[test]
+    fn ssz_decode_wrong_length() {
+        assert_eq!(
+            UintN::<24>::from_ssz_bytes(&[1, 2, 3, 4]),
+            Err(DecodeError::InvalidByteLength {
+                len: 4,
+                expected: 3
+            })
+        );
+        assert_eq!(
+            UintN::<48>::from_ssz_bytes(&[1, 2]),
+            Err(DecodeError::InvalidByteLength {
+                len: 2,
+                expected: 6
+            })
+        );
+    }
+
+    #[test]
+    fn tree_hash_pads_chunk() {
+        let mut expected = [0u8; 32];
+        expected[..3].copy_from_slice(&[0x03, 0x02, 0x01]);
+        let value = UintN::<24>::new(0x01_0203).unwrap();
+        assert_eq!(value.tree_hash_root::<Sha256Hasher>().as_ref(), &expected);
+
+        let mut expected = [0u8; 32];
+        expected[..6].copy_from_slice(&[6, 5, 4, 3, 2, 1]);
+        let value = UintN::<48>::new(0x0102_0304_0506).unwrap();
+        assert_eq!(value.tree_hash_root::<Sha256Hasher>().as_ref(), &expected);
+
+        // A value of the same width as a primitive hashes like the primitive.
+        let value = UintN::<32>::new(0xdead_beef).unwrap();
+        assert_eq!(
+            value.tree_hash_root::<Sha256Hasher>(),
+            0xdead_beef_u32.tree_hash_root::<Sha256Hasher>()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let value: UintN<24> = serde_json::from_value(serde_json::json!(65536)).unwrap();
+        assert_eq!(value.get(), 65536);
+        assert_eq!(
+            serde_json::to_value(value).unwrap(),
+            serde_json::json!(65536)
+        );
+        assert!(serde_json::from_value::<UintN<24>>(serde_json::json!(0x100_0000)).is_err());
+    }
+}
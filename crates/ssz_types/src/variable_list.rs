@@ -1,7 +1,8 @@
 // Modified in 2025 from the original version
 // Original source licensed under the Apache License 2.0
 
-use std::{
+use alloc::{format, vec, vec::Vec};
+use core::{
     ops::{Deref, DerefMut, Index, IndexMut},
     slice::SliceIndex,
 };
@@ -62,8 +63,8 @@ impl<T: PartialEq, const N: usize> PartialEq for VariableList<T, N> {
     }
 }
 impl<T: Eq, const N: usize> Eq for VariableList<T, N> {}
-impl<T: std::hash::Hash, const N: usize> std::hash::Hash for VariableList<T, N> {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+impl<T: core::hash::Hash, const N: usize> core::hash::Hash for VariableList<T, N> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         self.vec.hash(state);
     }
 }
@@ -110,6 +111,11 @@ impl<T, const N: usize> VariableList<T, N> {
         N
     }
 
+    /// Removes all values, keeping the allocated capacity for reuse.
+    pub fn clear(&mut self) {
+        self.vec.clear();
+    }
+
     /// Appends `value` to the back of `self`.
     ///
     /// Returns `Err(())` when appending `value` would exceed the maximum length.
@@ -124,6 +130,25 @@ impl<T, const N: usize> VariableList<T, N> {
             })
         }
     }
+
+    /// Appends clones of all `items` to the back of `self`.
+    ///
+    /// The combined length is checked up front, so on error `self` is left unchanged.
+    pub fn try_extend_from_slice(&mut self, items: &[T]) -> Result<(), Error>
+    where
+        T: Clone,
+    {
+        let new_len = self.vec.len().saturating_add(items.len());
+        if new_len <= Self::max_len() {
+            self.vec.extend_from_slice(items);
+            Ok(())
+        } else {
+            Err(Error::OutOfBounds {
+                i: new_len,
+                len: Self::max_len(),
+            })
+        }
+    }
 }
 
 impl<T, const N: usize> TryFrom<Vec<T>> for VariableList<T, N> {
@@ -134,6 +159,31 @@ impl<T, const N: usize> TryFrom<Vec<T>> for VariableList<T, N> {
     }
 }
 
+/// Converts an array that fits within the maximum length, checked at compile time.
+///
+/// ```compile_fail
+/// use ssz_types::VariableList;
+///
+/// let list: VariableList<u8, 2> = [1, 2, 3].into();
+/// ```
+impl<T, const N: usize, const M: usize> From<[T; M]> for VariableList<T, N> {
+    fn from(array: [T; M]) -> Self {
+        #[allow(
+            clippy::let_unit_value,
+            reason = "forces the compile-time length check"
+        )]
+        let () = ArrayFits::<M, N>::OK;
+        Self { vec: array.into() }
+    }
+}
+
+/// Compile-time check that an array of length `M` fits in a list of maximum length `N`.
+struct ArrayFits<const M: usize, const N: usize>;
+
+impl<const M: usize, const N: usize> ArrayFits<M, N> {
+    const OK: () = assert!(M <= N, "array is longer than the list's maximum length");
+}
+
 impl<T, const N: usize> From<VariableList<T, N>> for Vec<T> {
     fn from(list: VariableList<T, N>) -> Vec<T> {
         list.vec
@@ -180,7 +230,7 @@ impl<T, const N: usize> DerefMut for VariableList<T, N> {
 
 impl<'a, T, const N: usize> IntoIterator for &'a VariableList<T, N> {
     type Item = &'a T;
-    type IntoIter = std::slice::Iter<'a, T>;
+    type IntoIter = core::slice::Iter<'a, T>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
@@ -189,7 +239,7 @@ impl<'a, T, const N: usize> IntoIterator for &'a VariableList<T, N> {
 
 impl<T, const N: usize> IntoIterator for VariableList<T, N> {
     type Item = T;
-    type IntoIter = std::vec::IntoIter<T>;
+    type IntoIter = vec::IntoIter<T>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.vec.into_iter()
@@ -238,6 +288,11 @@ where
     fn ssz_append(&self, buf: &mut Vec<u8>) {
         self.vec.ssz_append(buf)
     }
+
+    #[cfg(feature = "std")]
+    fn ssz_write<W: std::io::Write>(&self, w: &mut W) -> std::io::Result<()> {
+        self.vec.ssz_write(w)
+    }
 }
 
 impl<T, const N: usize> ssz::TryFromIter<T> for VariableList<T, N> {
@@ -248,13 +303,13 @@ impl<T, const N: usize> ssz::TryFromIter<T> for VariableList<T, N> {
         I: IntoIterator<Item = T>,
     {
         let n = N;
-        let clamped_n = std::cmp::min(MAX_ELEMENTS_TO_PRE_ALLOCATE, n);
+        let clamped_n = core::cmp::min(MAX_ELEMENTS_TO_PRE_ALLOCATE, n);
         let iter = value.into_iter();
 
         // Pre-allocate up to `N` elements based on the iterator size hint.
         let (_, opt_max_len) = iter.size_hint();
         let mut l = Self::new(Vec::with_capacity(
-            opt_max_len.map_or(clamped_n, |max_len| std::cmp::min(clamped_n, max_len)),
+            opt_max_len.map_or(clamped_n, |max_len| core::cmp::min(clamped_n, max_len)),
         ))?;
         for item in iter {
             l.push(item)?;
@@ -291,7 +346,7 @@ where
             bytes
                 .chunks(T::ssz_fixed_len())
                 .try_fold(Vec::with_capacity(num_items), |mut vec, chunk| {
-                    vec.push(T::from_ssz_bytes(chunk)?);
+                    vec.push(T::from_ssz_bytes(chunk).map_err(|e| e.bounded(chunk.len()))?);
                     Ok(vec)
                 })
                 .and_then(|vec| {
@@ -342,7 +397,7 @@ impl<'a, T: arbitrary::Arbitrary<'a>, const N: usize> arbitrary::Arbitrary<'a>
     fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
         let max_size = N;
         let rand = usize::arbitrary(u)?;
-        let size = std::cmp::min(rand, max_size);
+        let size = core::cmp::min(rand, max_size);
         let mut vec: Vec<T> = Vec::with_capacity(size);
         for _ in 0..size {
             vec.push(<T>::arbitrary(u)?);
@@ -412,6 +467,88 @@ mod test {
         assert_eq!(&fixed[..], &[] as &[u64]);
     }
 
+    #[test]
+    fn try_from_vec_capacity() {
+        let exact = VariableList::<u64, 4>::try_from(vec![1, 2, 3, 4]).unwrap();
+        assert_eq!(&exact[..], &[1, 2, 3, 4]);
+
+        let under = VariableList::<u64, 4>::try_from(vec![1, 2]).unwrap();
+        assert_eq!(&under[..], &[1, 2]);
+
+        assert_eq!(
+            VariableList::<u64, 4>::try_from(vec![1, 2, 3, 4, 5]),
+            Err(Error::OutOfBounds { i: 5, len: 4 })
+        );
+    }
+
+    #[test]
+    fn from_array() {
+        let exact: VariableList<u64, 3> = [1, 2, 3].into();
+        assert_eq!(&exact[..], &[1, 2, 3]);
+
+        let under = VariableList::<u64, 3>::from([7]);
+        assert_eq!(&under[..], &[7]);
+
+        let empty = VariableList::<u64, 3>::from([]);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn try_extend_from_slice() {
+        let mut list = VariableList::<u64, 5>::try_from(vec![1, 2]).unwrap();
+
+        list.try_extend_from_slice(&[3, 4]).unwrap();
+        assert_eq!(&list[..], &[1, 2, 3, 4]);
+
+        list.try_extend_from_slice(&[]).unwrap();
+        list.try_extend_from_slice(&[5]).unwrap();
+        assert_eq!(&list[..], &[1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn try_extend_from_slice_overflow() {
+        let mut list = VariableList::<u64, 4>::try_from(vec![1, 2]).unwrap();
+
+        assert_eq!(
+            list.try_extend_from_slice(&[3, 4, 5]),
+            Err(Error::OutOfBounds { i: 5, len: 4 })
+        );
+        assert_eq!(&list[..], &[1, 2]);
+    }
+
+    #[test]
+    fn clear_keeps_capacity() {
+        let mut list = VariableList::<u64, 4>::try_from(vec![1, 2, 3]).unwrap();
+        let capacity = list.vec.capacity();
+
+        list.clear();
+        assert!(list.is_empty());
+        assert_eq!(list.vec.capacity(), capacity);
+
+        list.push(4).unwrap();
+        assert_eq!(&list[..], &[4]);
+    }
+
+    #[test]
+    fn decode_reserves_exact_len() {
+        // Both list shapes know their length before decoding any element (from the byte length
+        // or the offset table), so decoding allocates once and never grows the `Vec`.
+        for len in [1, 3, 100, 1024] {
+            let variable: VariableList<VariableList<u8, 4>, 1024> =
+                vec![VariableList::try_from(vec![7, 8]).unwrap(); len]
+                    .try_into()
+                    .unwrap();
+            let decoded =
+                VariableList::<VariableList<u8, 4>, 1024>::from_ssz_bytes(&variable.as_ssz_bytes())
+                    .unwrap();
+            assert_eq!(Vec::from(decoded).capacity(), len, "len = {len}");
+
+            let fixed: VariableList<u64, 1024> = vec![9; len].try_into().unwrap();
+            let decoded = VariableList::<u64, 1024>::from_ssz_bytes(&fixed.as_ssz_bytes()).unwrap();
+            assert_eq!(Vec::from(decoded).capacity(), len, "len = {len}");
+        }
+    }
+
     #[test]
     fn deref() {
         let vec = vec![0, 2, 4, 6];
@@ -422,6 +559,35 @@ mod test {
         assert_eq!(fixed.get(4), None);
     }
 
+    #[test]
+    fn deref_coercion() {
+        fn sum(values: &[u64]) -> u64 {
+            values.iter().sum()
+        }
+
+        let mut list = VariableList::<u64, 8>::try_from(vec![1, 2, 3]).unwrap();
+        assert_eq!(sum(&list), 6);
+        assert_eq!(list.len(), 3);
+
+        list.sort_by(|a, b| b.cmp(a));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![3, 2, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn index_out_of_bounds() {
+        let list = VariableList::<u64, 8>::try_from(vec![1, 2, 3]).unwrap();
+        let _ = list[3];
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn index_mut_does_not_grow() {
+        // Spare capacity below the maximum length is not addressable.
+        let mut list = VariableList::<u64, 8>::try_from(vec![1, 2, 3]).unwrap();
+        list[3] = 4;
+    }
+
     #[test]
     fn encode() {
         let vec = VariableList::<u16, 2>::try_from(vec![0; 2]).unwrap();
@@ -429,7 +595,7 @@ mod test {
         assert_eq!(<VariableList<u16, 2> as Encode>::ssz_fixed_len(), 4);
     }
 
-    fn round_trip<T: Encode + Decode + std::fmt::Debug + PartialEq>(item: T) {
+    fn round_trip<T: Encode + Decode + core::fmt::Debug + PartialEq>(item: T) {
         let encoded = &item.as_ssz_bytes();
         assert_eq!(item.ssz_bytes_len(), encoded.len());
         assert_eq!(T::from_ssz_bytes(encoded), Ok(item));
@@ -593,7 +759,7 @@ mod test {
 
     #[test]
     fn large_list_pre_allocation() {
-        use std::iter;
+        use core::iter;
         type List = VariableList<u64, 1099511627776>;
 
         // Iterator that hints the upper bound on its length as `hint`.
@@ -25,9 +25,12 @@
 //! assert_eq!(&owned[..], &values[..]);
 //! ```
 
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
 use ssz::{
     DecodeError,
-    view::{DecodeView, ListRef, SszTypeInfo, VectorRef},
+    view::{DecodeView, ListRef, SszTypeInfo, VectorRef, VectorRefIter},
 };
 use ssz_primitives::FixedBytes;
 use tree_hash::{PackedEncoding, TreeHash, TreeHashDigest, TreeHashType};
@@ -100,13 +103,34 @@ impl<'a, TRef: SszTypeInfo, const N: usize> VariableListRef<'a, TRef, N> {
 
     /// Returns an [`Iterator`] over the list items.
     ///
-    /// Each item is decoded lazily as the iterator advances.
+    /// Each item is decoded lazily as the iterator advances. Prefer this over [`Self::get`] in
+    /// loops: the item stride, or for variable-size items the offset table, is only walked once.
     pub fn iter(&self) -> impl Iterator<Item = Result<TRef, DecodeError>> + '_
     where
         TRef: DecodeView<'a>,
     {
         self.inner.iter()
     }
+
+    /// Gets the item at the specified index.
+    pub fn get(&self, index: usize) -> Result<TRef, DecodeError>
+    where
+        TRef: DecodeView<'a>,
+    {
+        self.inner.get(index)
+    }
+
+    /// Binary searches this sorted list for `target`, decoding only the probed items.
+    ///
+    /// Like [`slice::binary_search`], the inner result is `Ok` with the index of a matching item,
+    /// or `Err` with the index where `target` could be inserted to keep the list sorted. If the
+    /// list isn't sorted, the result is unspecified. Fails if a probed item doesn't decode.
+    pub fn binary_search(&self, target: &TRef) -> Result<Result<usize, usize>, DecodeError>
+    where
+        TRef: DecodeView<'a> + Ord,
+    {
+        binary_search_by_index(self.len(), target, |index| self.get(index))
+    }
 }
 
 impl<'a, TRef, const N: usize> VariableListRef<'a, TRef, N>
@@ -295,7 +319,10 @@ impl<'a, TRef: SszTypeInfo, const N: usize> FixedVectorRef<'a, TRef, N> {
     }
 
     /// Returns an [`Iterator`] over the vector items.
-    pub fn iter(&self) -> impl Iterator<Item = Result<TRef, DecodeError>> + '_
+    ///
+    /// Fixed-size items are read at a stride computed once, and variable-size
+    /// items by walking the offset table; neither allocates.
+    pub fn iter(&self) -> VectorRefIter<'a, TRef, N>
     where
         TRef: DecodeView<'a>,
     {
@@ -309,6 +336,18 @@ impl<'a, TRef: SszTypeInfo, const N: usize> FixedVectorRef<'a, TRef, N> {
     {
         self.inner.get(index)
     }
+
+    /// Binary searches this sorted vector for `target`, decoding only the probed items.
+    ///
+    /// Like [`slice::binary_search`], the inner result is `Ok` with the index of a matching item,
+    /// or `Err` with the index where `target` could be inserted to keep the vector sorted. If the
+    /// vector isn't sorted, the result is unspecified. Fails if a probed item doesn't decode.
+    pub fn binary_search(&self, target: &TRef) -> Result<Result<usize, usize>, DecodeError>
+    where
+        TRef: DecodeView<'a> + Ord,
+    {
+        binary_search_by_index(self.len(), target, |index| self.get(index))
+    }
 }
 
 impl<'a, TRef, const N: usize> FixedVectorRef<'a, TRef, N>
@@ -343,6 +382,33 @@ where
     }
 }
 
+impl<'a, TRef, T, const N: usize> ToOwnedSsz<FixedVector<T, N>> for FixedVectorRef<'a, TRef, N>
+where
+    TRef: DecodeView<'a> + SszTypeInfo + ToOwnedSsz<T>,
+{
+    fn to_owned(&self) -> FixedVector<T, N> {
+        FixedVectorRef::to_owned(self).expect("valid view")
+    }
+}
+
+/// Binary searches `len` sorted items, read by index with `get`, for `target`.
+fn binary_search_by_index<T: Ord>(
+    len: usize,
+    target: &T,
+    get: impl Fn(usize) -> Result<T, DecodeError>,
+) -> Result<Result<usize, usize>, DecodeError> {
+    let (mut low, mut high) = (0, len);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        match get(mid)?.cmp(target) {
+            Ordering::Less => low = mid + 1,
+            Ordering::Greater => high = mid,
+            Ordering::Equal => return Ok(Ok(mid)),
+        }
+    }
+    Ok(Err(low))
+}
+
 impl<'a, TRef, const N: usize> TreeHash for VariableListRef<'a, TRef, N>
 where
     TRef: DecodeView<'a> + TreeHash + SszTypeInfo,
@@ -448,6 +514,120 @@ mod tests {
         assert_eq!(decoded, values);
     }
 
+    #[test]
+    fn empty_input() {
+        assert!(
+            VariableListRef::<u64, 4>::from_ssz_bytes(&[])
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(
+            FixedVectorRef::<u32, 4>::from_ssz_bytes(&[]).unwrap_err(),
+            DecodeError::InvalidByteLength {
+                len: 0,
+                expected: 16
+            }
+        );
+        assert_eq!(
+            <crate::Optional<u64> as DecodeView>::from_ssz_bytes(&[]),
+            Ok(crate::Optional::None)
+        );
+        assert_eq!(
+            <crate::UintN<24> as DecodeView>::from_ssz_bytes(&[]),
+            Err(DecodeError::InvalidByteLength {
+                len: 0,
+                expected: 3
+            })
+        );
+    }
+
+    #[test]
+    fn binary_search_sorted_list() {
+        let list = VariableList::<u64, 16>::try_from(vec![2u64, 3, 5, 7, 11, 13]).unwrap();
+        let encoded = list.as_ssz_bytes();
+        let view = VariableListRef::<u64, 16>::from_ssz_bytes(&encoded).unwrap();
+
+        for (index, value) in list.iter().enumerate() {
+            assert_eq!(view.binary_search(value), Ok(Ok(index)));
+        }
+        assert_eq!(view.binary_search(&1), Ok(Err(0)));
+        assert_eq!(view.binary_search(&6), Ok(Err(3)));
+        assert_eq!(view.binary_search(&17), Ok(Err(6)));
+
+        let empty = VariableListRef::<u64, 16>::from_ssz_bytes(&[]).unwrap();
+        assert_eq!(empty.binary_search(&1), Ok(Err(0)));
+    }
+
+    #[test]
+    fn binary_search_sorted_vector() {
+        let vector = FixedVector::<u32, 4>::new(vec![10, 20, 30, 40]).unwrap();
+        let encoded = vector.as_ssz_bytes();
+        let view = FixedVectorRef::<u32, 4>::from_ssz_bytes(&encoded).unwrap();
+
+        assert_eq!(view.binary_search(&30), Ok(Ok(2)));
+        assert_eq!(view.binary_search(&25), Ok(Err(2)));
+
+        // A probed item that doesn't decode is reported rather than skipped.
+        let view = FixedVectorRef::<bool, 3>::from_ssz_bytes(&[0, 2, 1]).unwrap();
+        assert!(view.binary_search(&true).is_err());
+    }
+
+    type DecodedItems = Vec<Result<Vec<u8>, DecodeError>>;
+
+    /// Decodes every item both through the iterator and by index.
+    fn iter_and_get<const M: usize>(bytes: &[u8]) -> (DecodedItems, DecodedItems) {
+        let view = VariableListRef::<ssz::view::BytesRef<'_, 8>, M>::from_ssz_bytes(bytes).unwrap();
+        let iterated = view
+            .iter()
+            .map(|item| item.map(|bytes| bytes.as_bytes().to_vec()))
+            .collect();
+        let indexed = (0..view.len())
+            .map(|i| view.get(i).map(|bytes| bytes.as_bytes().to_vec()))
+            .collect();
+        (iterated, indexed)
+    }
+
+    #[test]
+    fn variable_list_ref_iter_matches_get() {
+        let values: Vec<u64> = (0..7).collect();
+        let encoded = values.as_ssz_bytes();
+        let view = VariableListRef::<u64, 10>::from_ssz_bytes(&encoded).unwrap();
+        let iterated: Vec<_> = view.iter().collect();
+        let indexed: Vec<_> = (0..view.len()).map(|i| view.get(i)).collect();
+        assert_eq!(iterated, indexed);
+        assert!(view.get(view.len()).is_err());
+
+        let list: VariableList<VariableList<u8, 8>, 4> = VariableList::new(vec![
+            VariableList::new(vec![1, 2]).unwrap(),
+            VariableList::new(vec![]).unwrap(),
+            VariableList::new(vec![3, 4, 5]).unwrap(),
+        ])
+        .unwrap();
+        let encoded = list.as_ssz_bytes();
+        let (iterated, indexed) = iter_and_get::<4>(&encoded);
+        assert_eq!(iterated, indexed);
+        assert_eq!(
+            iterated,
+            vec![Ok(vec![1, 2]), Ok(vec![]), Ok(vec![3, 4, 5])]
+        );
+    }
+
+    #[test]
+    fn variable_list_ref_iter_matches_get_on_truncated_bytes() {
+        let list: VariableList<VariableList<u8, 8>, 4> = VariableList::new(vec![
+            VariableList::new(vec![1, 2]).unwrap(),
+            VariableList::new(vec![]).unwrap(),
+            VariableList::new(vec![3, 4, 5]).unwrap(),
+        ])
+        .unwrap();
+        let encoded = list.as_ssz_bytes();
+
+        // Cut into the first item so later offsets point past the end.
+        let (iterated, indexed) = iter_and_get::<4>(&encoded[..13]);
+        assert_eq!(iterated, indexed);
+        assert!(iterated.iter().all(Result::is_err));
+    }
+
     #[test]
     fn variable_list_ref_empty() {
         let bytes: &[u8] = &[];
@@ -505,6 +685,17 @@ mod tests {
         assert!(view.get(4).is_err());
     }
 
+    #[test]
+    fn fixed_vector_ref_to_owned_ssz() {
+        let vec: FixedVector<u64, 2> = vec![7u64, 8].into();
+        let encoded = vec.as_ssz_bytes();
+
+        let view = FixedVectorRef::<u64, 2>::from_ssz_bytes(&encoded).unwrap();
+        let owned: FixedVector<u64, 2> = ToOwnedSsz::to_owned(&view);
+
+        assert_eq!(vec, owned);
+    }
+
     #[test]
     fn fixed_vector_ref_wrong_length() {
         // Create a vector with 3 items
@@ -0,0 +1,79 @@
+//! Encoding and decoding through `core` and `alloc` alone.
+//!
+//! The test crate is `no_std`, so it only reaches the API a `no_std` consumer
+//! has. Run it against the `alloc`-only build with
+//! `cargo test -p ssz_types --no-default-features --test no_std`.
+
+#![no_std]
+#![allow(unused_crate_dependencies)]
+
+extern crate alloc;
+// The test harness itself needs `std`.
+extern crate std;
+
+use alloc::vec;
+
+use ssz::{Decode, DecodeError, Encode};
+use ssz_types::{FixedVector, VariableList};
+use tree_hash::{Sha256Hasher, TreeHash};
+
+#[test]
+fn fixed_vector_round_trip() {
+    let vector = FixedVector::<u16, 4>::new(vec![1, 2, 3, 0xffff]).unwrap();
+    let bytes = vector.as_ssz_bytes();
+    assert_eq!(bytes, [1, 0, 2, 0, 3, 0, 0xff, 0xff]);
+    assert_eq!(
+        FixedVector::<u16, 4>::from_ssz_bytes(&bytes).unwrap(),
+        vector
+    );
+    assert!(matches!(
+        FixedVector::<u16, 4>::from_ssz_bytes(&bytes[..6]),
+        Err(DecodeError::BytesInvalid(_))
+    ));
+}
+
+#[test]
+fn variable_list_round_trip() {
+    let list = VariableList::<FixedVector<u8, 2>, 3>::new(vec![
+        FixedVector::new(vec![1, 2]).unwrap(),
+        FixedVector::new(vec![3, 4]).unwrap(),
+    ])
+    .unwrap();
+    let bytes = list.as_ssz_bytes();
+    assert_eq!(bytes, [1, 2, 3, 4]);
+    assert_eq!(
+        VariableList::<FixedVector<u8, 2>, 3>::from_ssz_bytes(&bytes).unwrap(),
+        list
+    );
+
+    let nested = VariableList::<VariableList<u8, 4>, 2>::new(vec![
+        VariableList::new(vec![5]).unwrap(),
+        VariableList::new(vec![6, 7]).unwrap(),
+    ])
+    .unwrap();
+    let bytes = nested.as_ssz_bytes();
+    assert_eq!(
+        VariableList::<VariableList<u8, 4>, 2>::from_ssz_bytes(&bytes).unwrap(),
+        nested
+    );
+
+    // Three items in a list of at most two.
+    let too_long = VariableList::<u8, 3>::new(vec![1, 2, 3])
+        .unwrap()
+        .as_ssz_bytes();
+    assert!(VariableList::<u8, 2>::from_ssz_bytes(&too_long).is_err());
+}
+
+#[test]
+fn roots_are_available() {
+    let list = VariableList::<u64, 4>::new(vec![1, 2]).unwrap();
+    let vector = FixedVector::<u64, 4>::new(vec![1, 2, 0, 0]).unwrap();
+    // The list mixes its length into the same chunk the vector hashes to.
+    assert_eq!(
+        list.tree_hash_root::<Sha256Hasher>(),
+        tree_hash::mix_in_length_with_hasher::<Sha256Hasher>(
+            &vector.tree_hash_root::<Sha256Hasher>(),
+            2
+        )
+    );
+}
@@ -0,0 +1,112 @@
+//! Incremental tree hashing for containers whose fields change one at a time.
+
+use alloc::{vec, vec::Vec};
+use core::{fmt, marker::PhantomData};
+
+use crate::{TreeHash, TreeHashDigest};
+
+/// A type whose root is the merkleization of one leaf per field, such as a
+/// container.
+///
+/// Implemented by `#[derive(TreeHash)]` on container structs and by the
+/// containers `ssz_codegen` generates.
+pub trait TreeHashLeaves: TreeHash {
+    /// Returns the number of leaves, i.e. hashed fields.
+    fn tree_hash_leaf_count() -> usize;
+
+    /// Returns the root of the field at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not below [`Self::tree_hash_leaf_count`].
+    fn tree_hash_leaf_root<H: TreeHashDigest>(&self, index: usize) -> H::Output;
+}
+
+/// A value together with its merkle tree, so that changing one field only
+/// rehashes the path from that field's leaf to the root.
+///
+/// The tree is kept as a binary heap: node `1` is the root, node `i` has
+/// children `2i` and `2i + 1`, and the leaves start at the padded leaf
+/// count. After mutating the value through [`CachedTreeHash::value_mut`],
+/// call [`CachedTreeHash::update_field`] for every field that changed;
+/// otherwise [`CachedTreeHash::root`] is stale.
+pub struct CachedTreeHash<T, H: TreeHashDigest> {
+    value: T,
+    cache: Vec<H::Output>,
+    _hasher: PhantomData<H>,
+}
+
+impl<T: TreeHashLeaves, H: TreeHashDigest> CachedTreeHash<T, H> {
+    /// Hashes every field of `value` and builds its tree.
+    pub fn new(value: T) -> Self {
+        let width = Self::width();
+        let mut cache = vec![H::get_zero_hash(0); 2 * width];
+        for index in 0..T::tree_hash_leaf_count() {
+            cache[width + index] = value.tree_hash_leaf_root::<H>(index);
+        }
+        for node in (1..width).rev() {
+            cache[node] = H::hash32_concat(cache[2 * node].as_ref(), cache[2 * node + 1].as_ref());
+        }
+        Self {
+            value,
+            cache,
+            _hasher: PhantomData,
+        }
+    }
+
+    /// Returns the cached value.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns the cached value for mutation. Call
+    /// [`CachedTreeHash::update_field`] for each changed field afterwards.
+    pub fn value_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+
+    /// Rehashes the field at `leaf_index` and the path from it to the root.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `leaf_index` is not below the value's leaf count.
+    pub fn update_field(&mut self, leaf_index: usize) {
+        let leaf_count = T::tree_hash_leaf_count();
+        assert!(
+            leaf_index < leaf_count,
+            "leaf index {leaf_index} out of range for {leaf_count} leaves"
+        );
+        let mut node = Self::width() + leaf_index;
+        self.cache[node] = self.value.tree_hash_leaf_root::<H>(leaf_index);
+        while node > 1 {
+            node /= 2;
+            self.cache[node] = H::hash32_concat(
+                self.cache[2 * node].as_ref(),
+                self.cache[2 * node + 1].as_ref(),
+            );
+        }
+    }
+
+    /// Returns the root of the value as of the last update.
+    pub fn root(&self) -> H::Output {
+        self.cache[1].clone()
+    }
+
+    /// Returns the value, dropping the tree.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Leaf count padded to a power of two, and at least one.
+    fn width() -> usize {
+        T::tree_hash_leaf_count().next_power_of_two()
+    }
+}
+
+impl<T: fmt::Debug, H: TreeHashDigest> fmt::Debug for CachedTreeHash<T, H> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CachedTreeHash")
+            .field("value", &self.value)
+            .finish_non_exhaustive()
+    }
+}
@@ -3,7 +3,7 @@
 
 //! Tree hash implementations for different types
 
-use std::sync::Arc;
+use alloc::sync::Arc;
 
 use ssz::{
     Bitfield, Fixed, Variable,
@@ -0,0 +1,39 @@
+//! Stand-in for `std::sync::LazyLock` in builds without `std`.
+
+use alloc::boxed::Box;
+use core::{fmt, ops::Deref};
+
+use once_cell::race::OnceBox;
+
+/// A value computed on first access.
+///
+/// Threads racing on the first access may each compute the value; the first
+/// to finish stores it and the others drop theirs.
+pub struct LazyLock<T> {
+    cell: OnceBox<T>,
+    init: fn() -> T,
+}
+
+impl<T> LazyLock<T> {
+    /// Creates a value computed by `init` on first access.
+    pub const fn new(init: fn() -> T) -> Self {
+        Self {
+            cell: OnceBox::new(),
+            init,
+        }
+    }
+}
+
+impl<T> Deref for LazyLock<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.cell.get_or_init(|| Box::new((self.init)()))
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for LazyLock<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("LazyLock").field(&**self).finish()
+    }
+}
@@ -5,21 +5,49 @@
 #![allow(incomplete_features)]
 
 //! Tree hash implementation
+//!
+//! The `std` feature is on by default. Without it the crate builds on `core` and `alloc`.
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
+use once_cell as _;
+#[cfg(test)]
+use serde_yaml as _;
+#[cfg(test)]
+use snap as _;
 #[cfg(test)]
 use ssz_types as _;
 
+mod cached;
 pub mod impls;
+#[cfg(not(feature = "std"))]
+mod lazy;
 mod merkle_hasher;
 mod merkleize_padded;
 mod merkleize_standard;
-
+#[cfg(feature = "std")]
+mod pool;
+
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec,
+    vec::Vec,
+};
+#[cfg(feature = "std")]
 use std::sync::LazyLock;
 
+pub use cached::{CachedTreeHash, TreeHashLeaves};
 use digest::Digest;
+#[cfg(not(feature = "std"))]
+use lazy::LazyLock;
 pub use merkle_hasher::{Error, MerkleHasher};
 pub use merkleize_padded::merkleize_padded_with_hasher;
 pub use merkleize_standard::merkleize_standard_with_hasher;
+#[cfg(feature = "std")]
+pub use pool::with_pooled_hasher;
 use sha2 as _;
 use smallvec::SmallVec;
 
@@ -27,8 +55,9 @@ use smallvec::SmallVec;
 pub const BYTES_PER_CHUNK: usize = 32;
 /// Size of a merkle hash chunk
 pub const MERKLE_HASH_CHUNK: usize = 2 * BYTES_PER_CHUNK;
-/// Maximum union selector
-pub const MAX_UNION_SELECTOR: u8 = 127;
+/// Maximum union selector, extended to `u8::MAX` by the `union_extended`
+/// feature.
+pub const MAX_UNION_SELECTOR: u8 = ssz::MAX_UNION_SELECTOR;
 /// Size of a smallvec
 pub const SMALLVEC_SIZE: usize = 32;
 /// Maximum index for zero hashes
@@ -55,8 +84,28 @@ pub static ZERO_HASHES_SHA256: LazyLock<Vec<Hash256>> = LazyLock::new(|| {
     hashes
 });
 
+/// MerkleHasher using Blake3
+#[cfg(feature = "blake3")]
+pub type Blake3MerkleHasher = MerkleHasher<Blake3Hasher>;
+
+/// Zero hashes for Blake3
+#[cfg(feature = "blake3")]
+pub static ZERO_HASHES_BLAKE3: LazyLock<Vec<Hash256>> =
+    LazyLock::new(|| get_zero_hashes::<Blake3Hasher>(Blake3Hasher::HASH_LEN));
+
+/// MerkleHasher using Keccak-256
+#[cfg(feature = "keccak")]
+pub type Keccak256MerkleHasher = MerkleHasher<Keccak256Hasher>;
+
+/// Zero hashes for Keccak-256
+#[cfg(feature = "keccak")]
+pub static ZERO_HASHES_KECCAK256: LazyLock<Vec<Hash256>> =
+    LazyLock::new(|| get_zero_hashes::<Keccak256Hasher>(Keccak256Hasher::HASH_LEN));
+
 /// Trait for tree hash digests with incremental hashing support
-pub trait TreeHashDigest {
+///
+/// Implementors are `'static` so that `with_pooled_hasher` can keep hashers per digest type.
+pub trait TreeHashDigest: 'static {
     /// Output type
     type Output: AsRef<[u8]> + Clone;
 
@@ -127,7 +176,7 @@ impl TreeHashDigest for Sha256Hasher {
 
     fn from_bytes(bytes: &[u8]) -> Self::Output {
         let mut padded = [0u8; Self::HASH_SIZE];
-        let len = std::cmp::min(bytes.len(), Self::HASH_SIZE);
+        let len = core::cmp::min(bytes.len(), Self::HASH_SIZE);
         padded[..len].copy_from_slice(&bytes[..len]);
         Hash256::from_slice(&padded)
     }
@@ -150,6 +199,130 @@ impl TreeHashDigest for Sha256Hasher {
     }
 }
 
+/// Blake3 hasher with incremental support
+#[cfg(feature = "blake3")]
+#[derive(Debug)]
+pub struct Blake3Hasher {
+    hasher: blake3::Hasher,
+}
+
+/// Blake3 hasher implementation
+#[cfg(feature = "blake3")]
+impl TreeHashDigest for Blake3Hasher {
+    type Output = Hash256;
+    const HASH_SIZE: usize = 32;
+    const HASH_LEN: usize = 32;
+
+    fn zero_hashes() -> &'static [Self::Output] {
+        &ZERO_HASHES_BLAKE3
+    }
+
+    fn hash(data: &[u8]) -> Self::Output {
+        Self::hash_fixed(data)
+    }
+
+    fn hash_fixed(data: &[u8]) -> Self::Output {
+        Hash256::from(*blake3::hash(data).as_bytes())
+    }
+
+    fn hash32_concat(left: &[u8], right: &[u8]) -> Self::Output {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(left);
+        hasher.update(right);
+        Hash256::from(*hasher.finalize().as_bytes())
+    }
+
+    fn get_zero_hash(depth: usize) -> Self::Output {
+        Self::zero_hashes()[depth]
+    }
+
+    fn get_zero_hash_slice(depth: usize) -> &'static [u8] {
+        Self::zero_hashes()[depth].as_ref()
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Self::Output {
+        let mut padded = [0u8; Self::HASH_SIZE];
+        let len = core::cmp::min(bytes.len(), Self::HASH_SIZE);
+        padded[..len].copy_from_slice(&bytes[..len]);
+        Hash256::from_slice(&padded)
+    }
+
+    fn new_context() -> Self {
+        Self {
+            hasher: blake3::Hasher::new(),
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.hasher.update(data);
+    }
+
+    fn finalize(self) -> Self::Output {
+        Hash256::from(*self.hasher.finalize().as_bytes())
+    }
+}
+
+/// Keccak-256 hasher with incremental support, for data hashed the way the
+/// Ethereum execution layer does
+#[cfg(feature = "keccak")]
+#[derive(Debug)]
+pub struct Keccak256Hasher {
+    hasher: sha3::Keccak256,
+}
+
+/// Keccak-256 hasher implementation
+#[cfg(feature = "keccak")]
+impl TreeHashDigest for Keccak256Hasher {
+    type Output = Hash256;
+    const HASH_SIZE: usize = 32;
+    const HASH_LEN: usize = 32;
+
+    fn zero_hashes() -> &'static [Self::Output] {
+        &ZERO_HASHES_KECCAK256
+    }
+
+    fn hash(data: &[u8]) -> Self::Output {
+        Self::hash_fixed(data)
+    }
+
+    fn hash_fixed(data: &[u8]) -> Self::Output {
+        Hash256::from(hash_fixed_with_digest::<sha3::Keccak256>(data))
+    }
+
+    fn hash32_concat(left: &[u8], right: &[u8]) -> Self::Output {
+        Hash256::from_slice(&hash32_concat::<sha3::Keccak256>(left, right))
+    }
+
+    fn get_zero_hash(depth: usize) -> Self::Output {
+        Self::zero_hashes()[depth]
+    }
+
+    fn get_zero_hash_slice(depth: usize) -> &'static [u8] {
+        Self::zero_hashes()[depth].as_ref()
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Self::Output {
+        let mut padded = [0u8; Self::HASH_SIZE];
+        let len = core::cmp::min(bytes.len(), Self::HASH_SIZE);
+        padded[..len].copy_from_slice(&bytes[..len]);
+        Hash256::from_slice(&padded)
+    }
+
+    fn new_context() -> Self {
+        Self {
+            hasher: sha3::Keccak256::new(),
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.hasher.update(data);
+    }
+
+    fn finalize(self) -> Self::Output {
+        Hash256::from_slice(&self.hasher.finalize())
+    }
+}
+
 /// Generate zero hashes for a specific hasher up to the maximum depth
 pub fn get_zero_hashes<H: TreeHashDigest>(hash_len: usize) -> Vec<H::Output> {
     let mut hashes = Vec::with_capacity(ZERO_HASHES_MAX_INDEX + 1);
@@ -168,6 +341,45 @@ pub fn get_zero_hashes<H: TreeHashDigest>(hash_len: usize) -> Vec<H::Output> {
     hashes
 }
 
+/// Expands to the zero hashes of the hasher type `$hasher` as a
+/// `&'static [Output]`, computed with [`get_zero_hashes`] on first use and
+/// cached in a static, for implementing [`TreeHashDigest::zero_hashes`].
+///
+/// Each expansion site has its own cache, so it should be used once per
+/// hasher, inside its `zero_hashes`. The hasher must be named explicitly,
+/// since statics can't refer to `Self`:
+///
+/// ```ignore
+/// impl TreeHashDigest for MyHasher {
+///     fn zero_hashes() -> &'static [Self::Output] {
+///         tree_hash::static_zero_hashes!(MyHasher)
+///     }
+///     // ...
+/// }
+/// ```
+#[macro_export]
+macro_rules! static_zero_hashes {
+    ($hasher: ty) => {{
+        static ZERO_HASHES: $crate::__private::LazyLock<
+            $crate::__private::Vec<<$hasher as $crate::TreeHashDigest>::Output>,
+        > = $crate::__private::LazyLock::new(|| {
+            $crate::get_zero_hashes::<$hasher>(<$hasher as $crate::TreeHashDigest>::HASH_LEN)
+        });
+        ZERO_HASHES.as_slice()
+    }};
+}
+
+/// Items used by the exported macros.
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::vec::Vec;
+    #[cfg(feature = "std")]
+    pub use std::sync::LazyLock;
+
+    #[cfg(not(feature = "std"))]
+    pub use crate::lazy::LazyLock;
+}
+
 /// Generic hash32_concat function using Digest trait
 pub fn hash32_concat<D: Digest + Default>(left: &[u8], right: &[u8]) -> Vec<u8> {
     let mut hasher = D::default();
@@ -201,7 +413,7 @@ pub fn merkle_root_with_hasher<H: TreeHashDigest>(
     bytes: &[u8],
     minimum_leaf_count: usize,
 ) -> H::Output {
-    let leaves = std::cmp::max(bytes.len().div_ceil(H::HASH_SIZE), minimum_leaf_count);
+    let leaves = core::cmp::max(bytes.len().div_ceil(H::HASH_SIZE), minimum_leaf_count);
 
     if leaves == 0 {
         H::get_zero_hash(0)
@@ -223,6 +435,44 @@ pub fn merkle_root_with_hasher<H: TreeHashDigest>(
     }
 }
 
+/// Merkleize leaves that are already hashes, such as precomputed subtree roots,
+/// without writing them to a byte buffer first.
+///
+/// The tree is padded with zero hashes to the next power of two of the
+/// number of leaves or `min_leaves`, whichever is larger. With no leaves and
+/// `min_leaves <= 1` the root is the zero chunk.
+///
+/// # Panics
+///
+/// Panics if the padded tree is deeper than [`ZERO_HASHES_MAX_INDEX`].
+pub fn merkleize_leaves_with_hasher<H: TreeHashDigest>(
+    leaves: impl ExactSizeIterator<Item = H::Output>,
+    min_leaves: usize,
+) -> H::Output {
+    let depth = core::cmp::max(leaves.len(), min_leaves)
+        .next_power_of_two()
+        .trailing_zeros() as usize;
+    let mut layer = leaves.collect::<Vec<_>>();
+    if layer.is_empty() {
+        return H::get_zero_hash(depth);
+    }
+
+    // Hash each layer in place into the front half of the buffer, padding
+    // an odd layer with the zero hash of its height.
+    for height in 0..depth {
+        if layer.len() % 2 == 1 {
+            layer.push(H::get_zero_hash(height));
+        }
+        let parents = layer.len() / 2;
+        for i in 0..parents {
+            layer[i] = H::hash32_concat(layer[2 * i].as_ref(), layer[2 * i + 1].as_ref());
+        }
+        layer.truncate(parents);
+    }
+
+    layer.swap_remove(0)
+}
+
 /// Merkleize a list of 32-byte chunks using the progressive tree shape.
 ///
 /// See EIP-7916 for details on progressive merkleization.
@@ -238,7 +488,7 @@ fn merkleize_progressive_with_hasher_inner<H: TreeHashDigest>(
         return H::get_zero_hash(0);
     }
 
-    let left_len = std::cmp::min(num_leaves, chunks.len());
+    let left_len = core::cmp::min(num_leaves, chunks.len());
     let left_root = merkleize_progressive_subtree::<H>(&chunks[..left_len], num_leaves);
     let right_root = merkleize_progressive_with_hasher_inner::<H>(
         &chunks[left_len..],
@@ -263,11 +513,146 @@ fn merkleize_progressive_subtree<H: TreeHashDigest>(
         .expect("progressive merkleize leaves within limit")
 }
 
+/// Returns the generalized index of the leaf at `leaf_index` in a tree of `num_leaves` leaves.
+///
+/// `num_leaves` is rounded up to the next power of two, as in [`MerkleHasher::with_leaves`]. For
+/// a container this is the index of field `leaf_index` out of `num_leaves` fields; the data root
+/// of a list sits at generalized index `2` below the list's own root, left of the length mix-in.
+pub fn generalized_index(leaf_index: usize, num_leaves: usize) -> usize {
+    let width = num_leaves.next_power_of_two();
+    assert!(
+        leaf_index < width,
+        "leaf index {leaf_index} out of bounds for a tree of {width} leaves"
+    );
+    width + leaf_index
+}
+
+/// Combines the generalized indices along a path into nested subtrees into a single generalized
+/// index relative to the outermost root.
+///
+/// E.g. field `1` of a 4-field container, itself field `2` of a 3-field container, is at
+/// `concat_generalized_indices(&[6, 5])`.
+pub fn concat_generalized_indices(indices: &[usize]) -> usize {
+    indices.iter().fold(1, |acc, &index| {
+        assert!(index > 0, "generalized indices start at 1");
+        let depth = index.ilog2();
+        (acc << depth) | (index - (1 << depth))
+    })
+}
+
+/// Returns whether `proof` proves `leaf` at generalized index `index` under `root`.
+///
+/// `proof` lists the sibling of each node on the path from the leaf up to the root, as produced
+/// by [`MerkleHasher::prove`], and must be exactly as long as the depth of `index`.
+pub fn verify_merkle_proof<H: TreeHashDigest>(
+    leaf: &H::Output,
+    proof: &[H::Output],
+    index: usize,
+    root: &H::Output,
+) -> bool {
+    if index == 0 || proof.len() != index.ilog2() as usize {
+        return false;
+    }
+
+    let mut node = leaf.clone();
+    let mut index = index;
+    for sibling in proof {
+        node = if index.is_multiple_of(2) {
+            H::hash32_concat(node.as_ref(), sibling.as_ref())
+        } else {
+            H::hash32_concat(sibling.as_ref(), node.as_ref())
+        };
+        index /= 2;
+    }
+
+    node.as_ref() == root.as_ref()
+}
+
+/// Returns the generalized indices of the nodes a multiproof of the leaves at `indices` must
+/// provide, in the order [`verify_merkle_multiproof`] expects them: descending.
+///
+/// These are the siblings of every node on the leaves' paths to the root, except those that can
+/// be computed from the leaves themselves.
+pub fn merkle_multiproof_helper_indices(indices: &[usize]) -> Vec<usize> {
+    let mut branch = BTreeSet::new();
+    let mut path = BTreeSet::new();
+    for &index in indices {
+        let mut index = index;
+        while index > 1 {
+            branch.insert(index ^ 1);
+            path.insert(index);
+            index /= 2;
+        }
+    }
+
+    branch
+        .into_iter()
+        .rev()
+        .filter(|index| !path.contains(index))
+        .collect()
+}
+
+/// Returns whether `proof` proves every `(index, leaf)` in `leaves` under `root`, where each
+/// index is a generalized index.
+///
+/// `proof` holds the nodes at [`merkle_multiproof_helper_indices`] in that order. Nodes shared
+/// by several leaves' paths are hashed once, so this is cheaper than checking each leaf with
+/// [`verify_merkle_proof`]. A proof with no leaves, a zero or repeated index, or a leaf on the
+/// path of another is rejected.
+pub fn verify_merkle_multiproof<H: TreeHashDigest>(
+    root: &H::Output,
+    leaves: &[(usize, H::Output)],
+    proof: &[H::Output],
+) -> bool {
+    let indices = leaves.iter().map(|(index, _)| *index).collect::<Vec<_>>();
+    if indices.is_empty() || indices.contains(&0) {
+        return false;
+    }
+    let helpers = merkle_multiproof_helper_indices(&indices);
+    if helpers.len() != proof.len() {
+        return false;
+    }
+
+    let mut nodes = BTreeMap::new();
+    for (index, node) in leaves
+        .iter()
+        .cloned()
+        .chain(helpers.into_iter().zip(proof.iter().cloned()))
+    {
+        if nodes.insert(index, node).is_some() {
+            return false;
+        }
+    }
+
+    // Every node deeper than the deepest remaining one has been combined already, so its
+    // sibling is either known or missing from the proof.
+    while let Some((&index, _)) = nodes.last_key_value()
+        && index > 1
+    {
+        let node = nodes.remove(&index).expect("index is the last key");
+        let Some(sibling) = nodes.remove(&(index ^ 1)) else {
+            return false;
+        };
+        let parent = if index.is_multiple_of(2) {
+            H::hash32_concat(node.as_ref(), sibling.as_ref())
+        } else {
+            H::hash32_concat(sibling.as_ref(), node.as_ref())
+        };
+        if nodes.insert(index / 2, parent).is_some() {
+            return false;
+        }
+    }
+
+    nodes
+        .get(&1)
+        .is_some_and(|node| node.as_ref() == root.as_ref())
+}
+
 /// Returns the node created by hashing `root` and `length`.
 ///
 /// Used in `TreeHash` for inserting the length of a list above it's root.
 pub fn mix_in_length_with_hasher<H: TreeHashDigest>(root: &H::Output, length: usize) -> H::Output {
-    let usize_len = std::mem::size_of::<usize>();
+    let usize_len = core::mem::size_of::<usize>();
 
     let mut length_bytes = [0; BYTES_PER_CHUNK];
     length_bytes[0..usize_len].copy_from_slice(&length.to_le_bytes());
@@ -275,6 +660,40 @@ pub fn mix_in_length_with_hasher<H: TreeHashDigest>(root: &H::Output, length: us
     H::hash32_concat(root.as_ref(), &length_bytes)
 }
 
+/// Returns the root of `map` as an SSZ `List[Container[key, value], max_len]`,
+/// the list of `(key, value)` containers `ssz` encodes a `BTreeMap` as.
+///
+/// `BTreeMap` has no type-level length limit, so it has no `TreeHash` impl
+/// and the limit is passed as `max_len` instead.
+///
+/// # Panics
+///
+/// Panics if `map` has more than `max_len` entries.
+pub fn btree_map_root_with_hasher<H, K, V>(map: &BTreeMap<K, V>, max_len: usize) -> H::Output
+where
+    H: TreeHashDigest,
+    K: TreeHash,
+    V: TreeHash,
+{
+    assert!(
+        map.len() <= max_len,
+        "map has {} entries, more than the limit of {max_len}",
+        map.len()
+    );
+    let mut hasher = MerkleHasher::<H>::with_leaves(max_len);
+    for (key, value) in map {
+        let entry_root = H::hash32_concat(
+            key.tree_hash_root::<H>().as_ref(),
+            value.tree_hash_root::<H>().as_ref(),
+        );
+        hasher
+            .write(entry_root.as_ref())
+            .expect("entries are within max_len");
+    }
+    let root = hasher.finish().expect("entry roots are whole chunks");
+    mix_in_length_with_hasher::<H>(&root, map.len())
+}
+
 /// Returns `Some(root)` created by hashing `root` and `selector`, if `selector <=
 /// MAX_UNION_SELECTOR`. Otherwise, returns `None`.
 ///
@@ -288,6 +707,10 @@ pub fn mix_in_length_with_hasher<H: TreeHashDigest>(root: &H::Output, length: us
 /// ```
 ///
 /// <https://github.com/ethereum/consensus-specs/blob/v1.1.0-beta.3/ssz/simple-serialize.md#union>
+#[allow(
+    clippy::absurd_extreme_comparisons,
+    reason = "MAX_UNION_SELECTOR is u8::MAX under the union_extended feature"
+)]
 pub fn mix_in_selector_with_hasher<H: TreeHashDigest>(
     root: &H::Output,
     selector: u8,
@@ -308,7 +731,7 @@ pub fn mix_in_aux_with_hasher<H: TreeHashDigest>(root: &H::Output, aux: &H::Outp
 }
 
 /// Type of the tree hash.
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum TreeHashType {
     /// Basic tree hash.
     Basic,
@@ -418,6 +841,66 @@ macro_rules! tree_hash_ssz_encoding_as_list {
     };
 }
 
+/// Macro for implementing `TreeHash` for a type that hashes as a container.
+///
+/// Each accessor names one container field relative to `self`, in field order: a field name, a
+/// tuple index or a path through nested fields such as `header.slot`. The root merkleizes one
+/// leaf per accessor holding that field's root, as `#[derive(TreeHash)]` does for a struct with
+/// the same fields.
+///
+/// ```rust
+/// # use ssz_derive as _;
+/// # use ssz_primitives as _;
+/// # use tree_hash_derive as _;
+/// use tree_hash::{Sha256Hasher, TreeHash};
+///
+/// struct Checkpoint {
+///     epoch: u64,
+///     root: [u8; 32],
+/// }
+///
+/// tree_hash::tree_hash_as_container!(Checkpoint, epoch, root);
+///
+/// let checkpoint = Checkpoint {
+///     epoch: 3,
+///     root: [1; 32],
+/// };
+/// let expected = tree_hash::merkleize_leaves_with_hasher::<Sha256Hasher>(
+///     [
+///         3u64.tree_hash_root::<Sha256Hasher>(),
+///         [1u8; 32].tree_hash_root::<Sha256Hasher>(),
+///     ]
+///     .into_iter(),
+///     0,
+/// );
+/// assert_eq!(checkpoint.tree_hash_root::<Sha256Hasher>(), expected);
+/// ```
+#[macro_export]
+macro_rules! tree_hash_as_container {
+    ($type: ty, $($field: tt $(. $path: tt)*),+ $(,)?) => {
+        impl $crate::TreeHash for $type {
+            fn tree_hash_type() -> $crate::TreeHashType {
+                $crate::TreeHashType::Container
+            }
+
+            fn tree_hash_packed_encoding(&self) -> $crate::PackedEncoding {
+                unreachable!("Container should never be packed.")
+            }
+
+            fn tree_hash_packing_factor() -> usize {
+                unreachable!("Container should never be packed.")
+            }
+
+            fn tree_hash_root<H: $crate::TreeHashDigest>(&self) -> H::Output {
+                let leaves = [$(
+                    $crate::TreeHash::tree_hash_root::<H>(&self.$field $(.$path)*)
+                ),+];
+                $crate::merkleize_leaves_with_hasher::<H>(leaves.into_iter(), 0)
+            }
+        }
+    };
+}
+
 #[cfg(test)]
 mod test {
     use ssz_derive as _;
@@ -452,4 +935,152 @@ mod test {
             Sha256Hasher::hash32_concat(&[0; 32], &[0; 32])
         );
     }
+
+    #[test]
+    fn generalized_indices() {
+        assert_eq!(generalized_index(0, 1), 1);
+        assert_eq!(generalized_index(2, 3), 6);
+        assert_eq!(generalized_index(1, 4), 5);
+        assert_eq!(concat_generalized_indices(&[]), 1);
+        assert_eq!(concat_generalized_indices(&[6]), 6);
+        assert_eq!(concat_generalized_indices(&[6, 5]), 25);
+        assert_eq!(concat_generalized_indices(&[1, 6, 1]), 6);
+    }
+
+    #[test]
+    fn merkleize_leaves_pads_to_power_of_two() {
+        let leaves = [1u8, 2, 3].map(|byte| Hash256::from([byte; 32]));
+        let left = Sha256Hasher::hash32_concat(leaves[0].as_ref(), leaves[1].as_ref());
+        let right = Sha256Hasher::hash32_concat(leaves[2].as_ref(), Hash256::zero().as_ref());
+        let expected = Sha256Hasher::hash32_concat(left.as_ref(), right.as_ref());
+        assert_eq!(
+            merkleize_leaves_with_hasher::<Sha256Hasher>(leaves.into_iter(), 0),
+            expected
+        );
+
+        // Padding with `min_leaves` matches merkleizing the same bytes.
+        let bytes = leaves.map(|leaf| leaf.0).concat();
+        assert_eq!(
+            merkleize_leaves_with_hasher::<Sha256Hasher>(leaves.into_iter(), 16),
+            merkle_root_with_hasher::<Sha256Hasher>(&bytes, 16)
+        );
+        assert_eq!(
+            merkleize_leaves_with_hasher::<Sha256Hasher>(leaves[..1].iter().copied(), 0),
+            leaves[0]
+        );
+        assert_eq!(
+            merkleize_leaves_with_hasher::<Sha256Hasher>(core::iter::empty(), 8),
+            Sha256Hasher::get_zero_hash(3)
+        );
+    }
+
+    #[test]
+    fn merkle_multiproof() {
+        let leaves = (0..8u8)
+            .map(|byte| Hash256::from([byte; 32]))
+            .collect::<Vec<_>>();
+        let root = merkleize_leaves_with_hasher::<Sha256Hasher>(leaves.iter().copied(), 0);
+        let pair =
+            |i: usize| Sha256Hasher::hash32_concat(leaves[i].as_ref(), leaves[i + 1].as_ref());
+
+        // Leaves 1 and 6 share the root's path, so only four helpers are needed.
+        let indices = [generalized_index(1, 8), generalized_index(6, 8)];
+        assert_eq!(indices, [9, 14]);
+        assert_eq!(merkle_multiproof_helper_indices(&indices), [15, 8, 6, 5]);
+        let proof = [leaves[7], leaves[0], pair(4), pair(2)];
+        let proven = [(9, leaves[1]), (14, leaves[6])];
+        assert!(verify_merkle_multiproof::<Sha256Hasher>(
+            &root, &proven, &proof
+        ));
+
+        // A tampered leaf or proof node, or a wrong proof length, fails.
+        let tampered = [(9, leaves[1]), (14, leaves[5])];
+        assert!(!verify_merkle_multiproof::<Sha256Hasher>(
+            &root, &tampered, &proof
+        ));
+        let bad_proof = [leaves[7], leaves[0], pair(2), pair(4)];
+        assert!(!verify_merkle_multiproof::<Sha256Hasher>(
+            &root, &proven, &bad_proof
+        ));
+        assert!(!verify_merkle_multiproof::<Sha256Hasher>(
+            &root,
+            &proven,
+            &proof[..3]
+        ));
+
+        // A leaf on the path of another, or a repeated leaf, is rejected
+        // even though the proof is otherwise valid for leaf 1 alone.
+        let right = Sha256Hasher::hash32_concat(pair(4).as_ref(), pair(6).as_ref());
+        let proof = [leaves[0], pair(2), right];
+        assert_eq!(merkle_multiproof_helper_indices(&[9]), [8, 5, 3]);
+        assert!(verify_merkle_multiproof::<Sha256Hasher>(
+            &root,
+            &[(9, leaves[1])],
+            &proof
+        ));
+        assert!(!verify_merkle_multiproof::<Sha256Hasher>(
+            &root,
+            &[(9, leaves[1]), (4, pair(0))],
+            &proof
+        ));
+        assert!(!verify_merkle_multiproof::<Sha256Hasher>(
+            &root,
+            &[(9, leaves[1]), (9, leaves[1])],
+            &proof
+        ));
+
+        // No leaves prove nothing.
+        assert_eq!(merkle_multiproof_helper_indices(&[]), Vec::<usize>::new());
+        assert!(!verify_merkle_multiproof::<Sha256Hasher>(&root, &[], &[]));
+    }
+
+    #[test]
+    fn mix_in_selector_range() {
+        let root = Hash256::zero();
+        assert!(mix_in_selector_with_hasher::<Sha256Hasher>(&root, 127).is_some());
+        assert_eq!(
+            mix_in_selector_with_hasher::<Sha256Hasher>(&root, 200).is_some(),
+            cfg!(feature = "union_extended")
+        );
+    }
+
+    #[cfg(feature = "blake3")]
+    #[test]
+    fn zero_hashes_blake3() {
+        let zero_hashes = Blake3Hasher::zero_hashes();
+        assert_eq!(zero_hashes.len(), ZERO_HASHES_MAX_INDEX + 1);
+        assert_eq!(zero_hashes[0], Hash256::zero());
+        assert_eq!(
+            zero_hashes[1],
+            Blake3Hasher::hash32_concat(&[0; 32], &[0; 32])
+        );
+        assert_eq!(zero_hashes[1].as_ref(), blake3::hash(&[0; 64]).as_bytes());
+    }
+
+    #[cfg(feature = "keccak")]
+    #[test]
+    fn zero_hashes_keccak256() {
+        let zero_hashes = Keccak256Hasher::zero_hashes();
+        assert_eq!(zero_hashes.len(), ZERO_HASHES_MAX_INDEX + 1);
+        assert_eq!(zero_hashes[0], Hash256::zero());
+        // keccak256 of 64 zero bytes, the first zero hash of the deposit
+        // contract tree.
+        assert_eq!(
+            zero_hashes[1],
+            Hash256::from_hex_str(
+                "ad3228b676f7d3cd4284a5443f17f1962b36e491b30a40b2405849e597ba5fb5"
+            )
+            .unwrap()
+        );
+    }
+
+    #[cfg(feature = "keccak")]
+    #[test]
+    fn keccak256_incremental_matches_one_shot() {
+        let data = [7u8; 100];
+        let mut hasher = Keccak256Hasher::new_context();
+        hasher.update(&data[..33]);
+        hasher.update(&data[33..]);
+        assert_eq!(hasher.finalize(), Keccak256Hasher::hash(&data));
+    }
 }
@@ -1,7 +1,8 @@
 // Modified in 2025 from the original version
 // Original source licensed under the Apache License 2.0
 
-use std::mem;
+use alloc::{vec, vec::Vec};
+use core::mem;
 
 use smallvec::{SmallVec, smallvec};
 use thiserror::Error;
@@ -67,8 +68,8 @@ impl<H: TreeHashDigest> HalfNode<H> {
     }
 }
 
-impl<H: TreeHashDigest> std::fmt::Debug for HalfNode<H> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<H: TreeHashDigest> core::fmt::Debug for HalfNode<H> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "HalfNode {{ id: {}, context variant: Sha2 }}", self.id)
     }
 }
@@ -155,6 +156,9 @@ pub struct MerkleHasher<H: TreeHashDigest> {
     buffer: SmallVec<[u8; 32]>,
     /// Set to Some(root) when the root of the tree is known.
     root: Option<H::Output>,
+    /// Every leaf written so far, kept only when proofs were requested via
+    /// [`MerkleHasher::with_proofs`].
+    leaves: Option<Vec<H::Output>>,
 }
 
 /// Returns the parent of node with id `i`.
@@ -184,8 +188,25 @@ impl<H: TreeHashDigest> MerkleHasher<H> {
     /// If `num_leaves == 0`, a tree of depth 1 will be created. If no leaves are provided it will
     /// return a root of `[0; 32]`.
     pub fn with_leaves(num_leaves: usize) -> Self {
-        let depth = get_depth(num_leaves.next_power_of_two()) + 1;
-        Self::with_depth(depth)
+        Self::with_depth(Self::depth_for(num_leaves))
+    }
+
+    /// Returns the depth of a tree with capacity for `num_leaves` leaves.
+    fn depth_for(num_leaves: usize) -> usize {
+        get_depth(num_leaves.next_power_of_two()) + 1
+    }
+
+    /// Empties the hasher for a new tree with `num_leaves` leaves, keeping the capacity it has
+    /// allocated, so that it behaves like `Self::with_leaves(num_leaves)`.
+    #[cfg(feature = "std")]
+    pub(crate) fn reset(&mut self, num_leaves: usize) {
+        let depth = Self::depth_for(num_leaves);
+        self.half_nodes.clear();
+        self.depth = depth;
+        self.next_leaf = 1 << (depth - 1);
+        self.buffer.clear();
+        self.root = None;
+        self.leaves = None;
     }
 
     /// Instantiates a new, empty hasher for a tree with `depth` layers which will have capacity
@@ -205,9 +226,93 @@ impl<H: TreeHashDigest> MerkleHasher<H> {
             next_leaf: 1 << (depth - 1),
             buffer: SmallVec::with_capacity(32),
             root: None,
+            leaves: None,
         }
     }
 
+    /// Retains every written leaf so that [`Self::prove`] can produce Merkle proofs.
+    ///
+    /// Without this the hasher only keeps the half-complete nodes along the right edge of the
+    /// tree, which is not enough to recover the siblings of earlier leaves.
+    pub fn with_proofs(mut self) -> Self {
+        self.leaves = Some(Vec::new());
+        self
+    }
+
+    /// Returns the Merkle proof for the leaf at `leaf_index`: the sibling of each node on the
+    /// path from that leaf up to the root, starting with the leaf's own sibling.
+    ///
+    /// Leaves not written yet are treated as zero, matching [`Self::finish`], and bytes still
+    /// buffered towards a partial leaf are included as the next leaf. The proof verifies with
+    /// [`crate::verify_merkle_proof`] at generalized index `expected_leaves() + leaf_index`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the hasher was not created [`Self::with_proofs`], or if `leaf_index` is not
+    /// less than [`Self::expected_leaves`].
+    pub fn prove(&self, leaf_index: usize) -> Vec<H::Output> {
+        let leaves = self
+            .leaves
+            .as_ref()
+            .expect("proofs require a hasher created `with_proofs`");
+        assert!(
+            leaf_index < self.expected_leaves(),
+            "leaf index {leaf_index} out of bounds for a tree of {} leaves",
+            self.expected_leaves()
+        );
+
+        let mut layer = leaves.clone();
+        if !self.buffer.is_empty() {
+            let mut leaf = vec![0; H::HASH_SIZE];
+            leaf[..self.buffer.len()].copy_from_slice(&self.buffer);
+            layer.push(H::from_bytes(&leaf));
+        }
+
+        // Nodes past the end of `layer` are roots of all-zero subtrees of `height`.
+        let mut proof = Vec::with_capacity(self.depth - 1);
+        let mut index = leaf_index;
+        for height in 0..self.depth - 1 {
+            let sibling = layer
+                .get(index ^ 1)
+                .cloned()
+                .unwrap_or_else(|| H::get_zero_hash(height));
+            proof.push(sibling);
+
+            layer = layer
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => H::hash32_concat(left.as_ref(), right.as_ref()),
+                    [left] => H::hash32_concat(left.as_ref(), H::get_zero_hash_slice(height)),
+                    _ => unreachable!("chunks of two are never empty"),
+                })
+                .collect();
+            index /= 2;
+        }
+
+        proof
+    }
+
+    /// Returns the number of complete leaves written so far.
+    ///
+    /// Bytes still buffered towards a partial leaf are not counted until the leaf is completed
+    /// by further writes or by [`Self::finish`].
+    pub fn leaves_written(&self) -> usize {
+        self.next_leaf - self.first_leaf()
+    }
+
+    /// Returns the number of leaves the tree holds, i.e. the `num_leaves` given to
+    /// [`Self::with_leaves`] rounded up to the next power of two.
+    ///
+    /// Leaves not written by the time [`Self::finish`] is called are treated as zero.
+    pub fn expected_leaves(&self) -> usize {
+        self.first_leaf()
+    }
+
+    /// Node id of the first leaf, which is also the leaf capacity of the tree.
+    fn first_leaf(&self) -> usize {
+        1 << (self.depth - 1)
+    }
+
     /// Write some bytes to the hasher.
     ///
     /// ## Errors
@@ -218,7 +323,7 @@ impl<H: TreeHashDigest> MerkleHasher<H> {
     pub fn write(&mut self, bytes: &[u8]) -> Result<(), Error> {
         let mut ptr = 0;
         while ptr <= bytes.len() {
-            let slice = &bytes[ptr..std::cmp::min(bytes.len(), ptr + H::HASH_SIZE)];
+            let slice = &bytes[ptr..core::cmp::min(bytes.len(), ptr + H::HASH_SIZE)];
 
             if self.buffer.is_empty() && slice.len() == H::HASH_SIZE {
                 self.process_leaf(slice)?;
@@ -258,7 +363,13 @@ impl<H: TreeHashDigest> MerkleHasher<H> {
 
         if self.next_leaf > max_leaves {
             return Err(Error::MaximumLeavesExceeded { max_leaves });
-        } else if self.next_leaf == 1 {
+        }
+
+        if let Some(leaves) = &mut self.leaves {
+            leaves.push(H::from_bytes(leaf));
+        }
+
+        if self.next_leaf == 1 {
             // A tree of depth one has a root that is equal to the first given leaf.
             self.root = Some(H::from_bytes(leaf))
         } else if self.next_leaf.is_multiple_of(2) {
@@ -282,6 +393,12 @@ impl<H: TreeHashDigest> MerkleHasher<H> {
     /// Returns an error if the bytes remaining in the buffer would create a leaf that would exceed
     /// the maximum permissible number of leaves defined by the initialization `depth`.
     pub fn finish(mut self) -> Result<H::Output, Error> {
+        self.finish_in_place()
+    }
+
+    /// Completes the tree like [`Self::finish`], leaving the hasher to be [`Self::reset`]
+    /// before reuse.
+    pub(crate) fn finish_in_place(&mut self) -> Result<H::Output, Error> {
         if !self.buffer.is_empty() {
             let mut leaf = vec![0; H::HASH_SIZE];
             leaf[..self.buffer.len()].copy_from_slice(&self.buffer);
@@ -290,7 +407,7 @@ impl<H: TreeHashDigest> MerkleHasher<H> {
 
         // If the tree is incomplete, we must complete it by providing zero-hashes.
         loop {
-            if let Some(root) = self.root {
+            if let Some(root) = self.root.take() {
                 break Ok(root);
             } else if let Some(node) = self.half_nodes.last() {
                 let right_child = node.id * 2 + 1;
@@ -578,6 +695,134 @@ mod test {
         compare_reference_with_len(8191, 14);
     }
 
+    #[test]
+    fn leaf_counters() {
+        let mut m = MerkleHasher::<Sha256Hasher>::with_leaves(5);
+        assert_eq!(m.expected_leaves(), 8);
+        assert_eq!(m.leaves_written(), 0);
+
+        for i in 1..=5 {
+            m.write(&[i as u8; 32]).expect("should write");
+            assert_eq!(m.leaves_written(), i);
+        }
+
+        // A partial leaf only counts once it is completed.
+        m.write(&[6; 16]).expect("should write");
+        assert_eq!(m.leaves_written(), 5);
+        m.write(&[6; 16]).expect("should write");
+        assert_eq!(m.leaves_written(), 6);
+        assert_eq!(m.expected_leaves(), 8);
+    }
+
+    #[test]
+    fn leaf_counters_single_leaf() {
+        let mut m = MerkleHasher::<Sha256Hasher>::with_leaves(1);
+        assert_eq!(m.expected_leaves(), 1);
+        m.write(&[1; 32]).expect("should write");
+        assert_eq!(m.leaves_written(), 1);
+    }
+
+    #[test]
+    fn finish_pads_missing_leaves() {
+        let leaves = [
+            Hash256::repeat_byte(1),
+            Hash256::repeat_byte(2),
+            Hash256::repeat_byte(3),
+        ];
+
+        let mut m = MerkleHasher::<Sha256Hasher>::with_leaves(leaves.len());
+        for leaf in &leaves {
+            m.write(leaf.as_slice()).expect("should write");
+        }
+        assert_eq!(m.leaves_written(), 3);
+        assert_eq!(m.expected_leaves(), 4);
+        let root = m.finish().expect("should finish");
+
+        let mut padded = MerkleHasher::<Sha256Hasher>::with_leaves(4);
+        for leaf in leaves.iter().chain([&Hash256::ZERO]) {
+            padded.write(leaf.as_slice()).expect("should write");
+        }
+        assert_eq!(padded.leaves_written(), padded.expected_leaves());
+        assert_eq!(root, padded.finish().expect("should finish"));
+    }
+
+    #[test]
+    fn proof_for_leaf_of_four_leaf_tree() {
+        let leaves: Vec<Hash256> = (1..=4).map(Hash256::repeat_byte).collect();
+
+        let mut m = MerkleHasher::<Sha256Hasher>::with_leaves(4).with_proofs();
+        for leaf in &leaves {
+            m.write(leaf.as_slice()).expect("should write");
+        }
+        let proof = m.prove(2);
+        let root = m.finish().expect("should finish");
+
+        assert_eq!(proof.len(), 2);
+        assert_eq!(proof[0], leaves[3]);
+        assert_eq!(
+            proof[1],
+            Sha256Hasher::hash32_concat(leaves[0].as_slice(), leaves[1].as_slice())
+        );
+        let gindex = crate::generalized_index(2, 4);
+        assert_eq!(gindex, 6);
+        assert!(crate::verify_merkle_proof::<Sha256Hasher>(
+            &leaves[2], &proof, gindex, &root
+        ));
+        assert!(!crate::verify_merkle_proof::<Sha256Hasher>(
+            &leaves[2], &proof, 7, &root
+        ));
+        assert!(!crate::verify_merkle_proof::<Sha256Hasher>(
+            &leaves[1], &proof, gindex, &root
+        ));
+    }
+
+    #[test]
+    fn proofs_with_missing_and_partial_leaves() {
+        for (num_leaves, written) in [(1, 1), (2, 1), (5, 3), (8, 0), (16, 11)] {
+            let mut m = MerkleHasher::<Sha256Hasher>::with_leaves(num_leaves).with_proofs();
+            for i in 0..written {
+                m.write(&[i as u8 + 1; 32]).expect("should write");
+            }
+            // A trailing partial leaf is zero-padded like `finish` does.
+            if written < m.expected_leaves() {
+                m.write(&[0xff; 7]).expect("should write");
+            }
+
+            let expected_leaves = m.expected_leaves();
+            let proofs: Vec<_> = (0..expected_leaves).map(|i| m.prove(i)).collect();
+            let root = m.finish().expect("should finish");
+
+            for (i, proof) in proofs.iter().enumerate() {
+                let leaf = if i < written {
+                    Hash256::repeat_byte(i as u8 + 1)
+                } else if i == written {
+                    let mut leaf = [0; 32];
+                    leaf[..7].fill(0xff);
+                    Hash256::from(leaf)
+                } else {
+                    Hash256::ZERO
+                };
+                assert!(
+                    crate::verify_merkle_proof::<Sha256Hasher>(
+                        &leaf,
+                        proof,
+                        crate::generalized_index(i, expected_leaves),
+                        &root
+                    ),
+                    "leaf {i} of {num_leaves} with {written} written"
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "with_proofs")]
+    fn prove_requires_retained_leaves() {
+        let mut m = MerkleHasher::<Sha256Hasher>::with_leaves(2);
+        m.write(&[1; 32]).expect("should write");
+        m.prove(0);
+    }
+
     #[test]
     fn remaining_buffer() {
         let a = {
@@ -1,6 +1,8 @@
 // Modified in 2025 from the original version
 // Original source licensed under the Apache License 2.0
 
+use alloc::{vec, vec::Vec};
+
 use super::BYTES_PER_CHUNK;
 use crate::{TreeHashDigest, hash_fixed_with_digest, hash32_concat};
 
@@ -58,10 +60,10 @@ pub fn merkleize_padded_with_hasher<H: TreeHashDigest>(
     //
     // Since there is more than one node in this tree (see prior assertion), there should always be
     // one or more initial parent nodes.
-    let initial_parents_with_values = std::cmp::max(1, next_even_number(leaves_with_values) / 2);
+    let initial_parents_with_values = core::cmp::max(1, next_even_number(leaves_with_values) / 2);
 
     // The number of leaves in the full tree (including padding nodes).
-    let num_leaves = std::cmp::max(leaves_with_values, min_leaves).next_power_of_two();
+    let num_leaves = core::cmp::max(leaves_with_values, min_leaves).next_power_of_two();
 
     // The number of levels in the tree.
     //
@@ -320,7 +322,7 @@ mod test {
     fn test_against_reference(input: &[u8], min_nodes: usize) {
         let mut reference_input = input.to_vec();
         reference_input.resize(
-            std::cmp::max(
+            core::cmp::max(
                 reference_input.len(),
                 min_nodes.next_power_of_two() * BYTES_PER_CHUNK,
             ),
@@ -30,7 +30,7 @@ pub fn merkleize_standard_with_hasher<H: TreeHashDigest>(bytes: &[u8]) -> H::Out
     let nodes = num_nodes(leaves);
     let internal_nodes = nodes - leaves;
 
-    let num_bytes = std::cmp::max(internal_nodes, 1) * H::HASH_SIZE + bytes.len();
+    let num_bytes = core::cmp::max(internal_nodes, 1) * H::HASH_SIZE + bytes.len();
 
     let mut o: Vec<u8> = vec![0; internal_nodes * H::HASH_SIZE];
 
@@ -0,0 +1,71 @@
+//! Per-thread reuse of [`MerkleHasher`]s across `tree_hash_root` calls.
+
+use std::{
+    any::{Any, TypeId},
+    cell::RefCell,
+    collections::HashMap,
+};
+
+use crate::{Error, MerkleHasher, TreeHashDigest};
+
+thread_local! {
+    /// Idle hashers of this thread, a `Vec<MerkleHasher<H>>` per hasher type `H`.
+    static POOL: RefCell<HashMap<TypeId, Box<dyn Any>>> = RefCell::new(HashMap::new());
+}
+
+/// Hashes a tree of `num_leaves` leaves with a [`MerkleHasher`] taken from a per-thread pool,
+/// returning the root.
+///
+/// `write` fills the hasher, like the writes between [`MerkleHasher::with_leaves`] and
+/// [`MerkleHasher::finish`]. The hasher goes back to the pool afterwards, keeping whatever
+/// capacity it allocated, so hashing many values in a row allocates hashers only once per
+/// nesting level. `write` may itself call `with_pooled_hasher`, e.g. to hash a nested container,
+/// and gets a separate hasher then.
+///
+/// A [`MerkleHasher`] only allocates for trees of more than 256 leaves, so for smaller trees
+/// the pool lookup costs more than it saves.
+///
+/// ```rust
+/// use tree_hash::{Sha256Hasher, Sha256MerkleHasher, with_pooled_hasher};
+///
+/// let leaves = [[1; 32], [2; 32]];
+/// let pooled = with_pooled_hasher::<Sha256Hasher, _>(2, |hasher| {
+///     leaves.iter().try_for_each(|leaf| hasher.write(leaf))
+/// })
+/// .unwrap();
+///
+/// let mut hasher = Sha256MerkleHasher::with_leaves(2);
+/// for leaf in &leaves {
+///     hasher.write(leaf).unwrap();
+/// }
+/// assert_eq!(pooled, hasher.finish().unwrap());
+/// ```
+pub fn with_pooled_hasher<H, F>(num_leaves: usize, write: F) -> Result<H::Output, Error>
+where
+    H: TreeHashDigest,
+    F: FnOnce(&mut MerkleHasher<H>) -> Result<(), Error>,
+{
+    let pooled = POOL.with_borrow_mut(|pool| {
+        pool.get_mut(&TypeId::of::<H>())
+            .and_then(|hashers| hashers.downcast_mut::<Vec<MerkleHasher<H>>>())
+            .and_then(Vec::pop)
+    });
+    let mut hasher = match pooled {
+        Some(mut hasher) => {
+            hasher.reset(num_leaves);
+            hasher
+        }
+        None => MerkleHasher::with_leaves(num_leaves),
+    };
+
+    let root = write(&mut hasher).and_then(|()| hasher.finish_in_place());
+
+    POOL.with_borrow_mut(|pool| {
+        pool.entry(TypeId::of::<H>())
+            .or_insert_with(|| Box::new(Vec::<MerkleHasher<H>>::new()))
+            .downcast_mut::<Vec<MerkleHasher<H>>>()
+            .expect("pool entries hold hashers of their type")
+            .push(hasher);
+    });
+    root
+}
@@ -0,0 +1,278 @@
+//! Round-trip conformance runner for consensus-spec-tests `ssz_static` vectors.
+//!
+//! The spec vectors are laid out as
+//! `ssz_static/<Type>/<suite>/<case>/{value.yaml, serialized.ssz_snappy, roots.yaml}`.
+//! For every case the runner checks that the YAML value encodes to the
+//! serialized bytes, that those bytes decode back to the value, and that the
+//! value's `hash_tree_root` matches `roots.yaml`. An uncompressed
+//! `serialized.ssz` is accepted in place of the snappy file.
+//!
+//! A couple of small vectors are bundled under `tests/spec_vectors`; see the
+//! README there for where they come from. To run
+//! against a full checkout of `ethereum/consensus-spec-tests`, point
+//! `SSZ_SPEC_TESTS_DIR` at an `ssz_static` directory:
+//!
+//! ```text
+//! SSZ_SPEC_TESTS_DIR=/path/to/consensus-spec-tests/tests/mainnet/phase0/ssz_static \
+//!     cargo test -p tree_hash --test spec_conformance
+//! ```
+//!
+//! Only the types declared below are run; other type directories are skipped.
+
+use std::{
+    fmt::Debug,
+    fs,
+    path::{Path, PathBuf},
+};
+
+#[cfg(feature = "blake3")]
+use blake3 as _;
+use digest as _;
+use once_cell as _;
+use rand as _;
+use serde_yaml::Value;
+use sha2 as _;
+#[cfg(feature = "keccak")]
+use sha3 as _;
+use smallvec as _;
+use ssz::{Decode, Encode};
+use ssz_derive::{Decode, Encode};
+use ssz_primitives::FixedBytes;
+use ssz_types as _;
+use thiserror as _;
+use tree_hash::{Hash256, Sha256Hasher, TreeHash};
+use tree_hash_derive::TreeHash;
+
+/// Environment variable naming an external `ssz_static` directory.
+const SPEC_TESTS_DIR_VAR: &str = "SSZ_SPEC_TESTS_DIR";
+
+// =============================================================================
+// Spec types
+// =============================================================================
+
+#[derive(Debug, PartialEq, Encode, Decode, TreeHash)]
+struct Checkpoint {
+    epoch: u64,
+    root: Hash256,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode, TreeHash)]
+struct Fork {
+    previous_version: FixedBytes<4>,
+    current_version: FixedBytes<4>,
+    epoch: u64,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode, TreeHash)]
+struct Eth1Data {
+    deposit_root: Hash256,
+    deposit_count: u64,
+    block_hash: Hash256,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode, TreeHash)]
+struct AttestationData {
+    slot: u64,
+    index: u64,
+    beacon_block_root: Hash256,
+    source: Checkpoint,
+    target: Checkpoint,
+}
+
+/// Runs the cases of one type directory, returning how many ran.
+type Runner = fn(&Path) -> usize;
+
+/// A spec type that can be built from its `value.yaml` representation.
+trait SpecType: Encode + Decode + TreeHash + PartialEq + Debug {
+    fn from_yaml(value: &Value) -> Self;
+}
+
+impl SpecType for Checkpoint {
+    fn from_yaml(value: &Value) -> Self {
+        Self {
+            epoch: yaml_u64(value, "epoch"),
+            root: yaml_fixed_bytes(value, "root"),
+        }
+    }
+}
+
+impl SpecType for Fork {
+    fn from_yaml(value: &Value) -> Self {
+        Self {
+            previous_version: yaml_fixed_bytes(value, "previous_version"),
+            current_version: yaml_fixed_bytes(value, "current_version"),
+            epoch: yaml_u64(value, "epoch"),
+        }
+    }
+}
+
+impl SpecType for Eth1Data {
+    fn from_yaml(value: &Value) -> Self {
+        Self {
+            deposit_root: yaml_fixed_bytes(value, "deposit_root"),
+            deposit_count: yaml_u64(value, "deposit_count"),
+            block_hash: yaml_fixed_bytes(value, "block_hash"),
+        }
+    }
+}
+
+impl SpecType for AttestationData {
+    fn from_yaml(value: &Value) -> Self {
+        Self {
+            slot: yaml_u64(value, "slot"),
+            index: yaml_u64(value, "index"),
+            beacon_block_root: yaml_fixed_bytes(value, "beacon_block_root"),
+            source: Checkpoint::from_yaml(yaml_get(value, "source")),
+            target: Checkpoint::from_yaml(yaml_get(value, "target")),
+        }
+    }
+}
+
+// =============================================================================
+// Runner
+// =============================================================================
+
+/// Runs every case below `type_dir` (`<type_dir>/<suite>/<case>`), returning
+/// the number of cases run.
+fn run_ssz_static<T: SpecType>(type_dir: &Path) -> usize {
+    let mut cases = 0;
+    for suite in sorted_dirs(type_dir) {
+        for case in sorted_dirs(&suite) {
+            run_case::<T>(&case);
+            cases += 1;
+        }
+    }
+    cases
+}
+
+fn run_case<T: SpecType>(case: &Path) {
+    let value = T::from_yaml(&read_yaml(&case.join("value.yaml")));
+    let serialized = read_serialized(case);
+    let root = yaml_bytes(&read_yaml(&case.join("roots.yaml")), "root");
+
+    assert_eq!(
+        value.as_ssz_bytes(),
+        serialized,
+        "{}: encoding mismatch",
+        case.display()
+    );
+    let decoded = T::from_ssz_bytes(&serialized)
+        .unwrap_or_else(|e| panic!("{}: decoding failed: {e:?}", case.display()));
+    assert_eq!(decoded, value, "{}: decoding mismatch", case.display());
+    assert_eq!(
+        value.tree_hash_root::<Sha256Hasher>().as_ref(),
+        root.as_slice(),
+        "{}: root mismatch",
+        case.display()
+    );
+}
+
+fn read_serialized(case: &Path) -> Vec<u8> {
+    let snappy = case.join("serialized.ssz_snappy");
+    if snappy.exists() {
+        return snap::raw::Decoder::new()
+            .decompress_vec(&read(&snappy))
+            .unwrap_or_else(|e| panic!("{}: {e}", snappy.display()));
+    }
+    read(&case.join("serialized.ssz"))
+}
+
+fn sorted_dirs(dir: &Path) -> Vec<PathBuf> {
+    let mut dirs: Vec<PathBuf> = fs::read_dir(dir)
+        .unwrap_or_else(|e| panic!("{}: {e}", dir.display()))
+        .map(|entry| entry.expect("readable directory entry").path())
+        .filter(|path| path.is_dir())
+        .collect();
+    dirs.sort();
+    dirs
+}
+
+fn read(path: &Path) -> Vec<u8> {
+    fs::read(path).unwrap_or_else(|e| panic!("{}: {e}", path.display()))
+}
+
+fn read_yaml(path: &Path) -> Value {
+    let src = fs::read_to_string(path).unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+    serde_yaml::from_str(&src).unwrap_or_else(|e| panic!("{}: {e}", path.display()))
+}
+
+// =============================================================================
+// YAML values
+// =============================================================================
+
+fn yaml_get<'a>(value: &'a Value, key: &str) -> &'a Value {
+    value
+        .get(key)
+        .unwrap_or_else(|| panic!("missing key `{key}` in {value:?}"))
+}
+
+fn yaml_u64(value: &Value, key: &str) -> u64 {
+    let field = yaml_get(value, key);
+    field
+        .as_u64()
+        .unwrap_or_else(|| panic!("`{key}`: expected an integer, got {field:?}"))
+}
+
+fn yaml_bytes(value: &Value, key: &str) -> Vec<u8> {
+    let field = yaml_get(value, key);
+    let hex = field
+        .as_str()
+        .and_then(|s| s.strip_prefix("0x"))
+        .unwrap_or_else(|| panic!("`{key}`: expected 0x-prefixed hex, got {field:?}"));
+    assert!(
+        hex.len().is_multiple_of(2),
+        "`{key}`: odd-length hex {hex:?}"
+    );
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| {
+            u8::from_str_radix(&hex[i..i + 2], 16)
+                .unwrap_or_else(|e| panic!("`{key}`: invalid hex {hex:?}: {e}"))
+        })
+        .collect()
+}
+
+fn yaml_fixed_bytes<const N: usize>(value: &Value, key: &str) -> FixedBytes<N> {
+    let bytes = yaml_bytes(value, key);
+    assert_eq!(
+        bytes.len(),
+        N,
+        "`{key}`: expected {N} bytes, got {}",
+        bytes.len()
+    );
+    FixedBytes::from_slice(&bytes)
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[test]
+fn bundled_vectors() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/spec_vectors/ssz_static");
+    assert_eq!(run_ssz_static::<Checkpoint>(&dir.join("Checkpoint")), 1);
+    assert_eq!(
+        run_ssz_static::<AttestationData>(&dir.join("AttestationData")),
+        1
+    );
+}
+
+#[test]
+fn external_vectors() {
+    let Some(dir) = std::env::var_os(SPEC_TESTS_DIR_VAR) else {
+        return;
+    };
+    let dir = PathBuf::from(dir);
+    let runners: [(&str, Runner); 4] = [
+        ("Checkpoint", run_ssz_static::<Checkpoint>),
+        ("Fork", run_ssz_static::<Fork>),
+        ("Eth1Data", run_ssz_static::<Eth1Data>),
+        ("AttestationData", run_ssz_static::<AttestationData>),
+    ];
+    let cases: usize = runners
+        .iter()
+        .filter(|(name, _)| dir.join(name).is_dir())
+        .map(|(name, run)| run(&dir.join(name)))
+        .sum();
+    assert!(cases > 0, "no known types found in {}", dir.display());
+}
@@ -0,0 +1,100 @@
+#!/usr/bin/env python3
+"""Generates the bundled `ssz_static` vectors in this directory.
+
+Run from anywhere; the files are written next to this script. Only the
+Python standard library is used, so the output can be checked against the
+committed files with `git diff` after running it.
+"""
+
+import hashlib
+import os
+import struct
+
+ROOT = os.path.join(os.path.dirname(os.path.abspath(__file__)), "ssz_static")
+
+
+def sha256(a, b):
+    return hashlib.sha256(a + b).digest()
+
+
+def u64(x):
+    return struct.pack("<Q", x)
+
+
+def chunk(b):
+    return b + b"\0" * (32 - len(b))
+
+
+def merkleize(chunks):
+    n = 1
+    while n < len(chunks):
+        n *= 2
+    layer = chunks + [b"\0" * 32] * (n - len(chunks))
+    while len(layer) > 1:
+        layer = [sha256(layer[i], layer[i + 1]) for i in range(0, len(layer), 2)]
+    return layer[0]
+
+
+def snappy_literal(data):
+    """Encodes `data` as a raw snappy block holding a single literal."""
+    out = bytearray()
+    n = len(data)
+    while True:
+        b = n & 0x7F
+        n >>= 7
+        if n:
+            out.append(b | 0x80)
+        else:
+            out.append(b)
+            break
+    n = len(data)
+    assert 0 < n <= 256
+    if n <= 60:
+        out.append((n - 1) << 2)
+    else:
+        out.append(60 << 2)
+        out.append(n - 1)
+    out += data
+    return bytes(out)
+
+
+def write(case, value_yaml, serialized, root):
+    path = os.path.join(ROOT, case)
+    os.makedirs(path, exist_ok=True)
+    with open(os.path.join(path, "value.yaml"), "w") as f:
+        f.write(value_yaml)
+    with open(os.path.join(path, "serialized.ssz_snappy"), "wb") as f:
+        f.write(snappy_literal(serialized))
+    with open(os.path.join(path, "roots.yaml"), "w") as f:
+        f.write("{root: '0x%s'}\n" % root.hex())
+
+
+def checkpoint(epoch, root):
+    return u64(epoch) + root, merkleize([chunk(u64(epoch)), root])
+
+
+root = bytes(range(1, 33))
+serialized, hash_root = checkpoint(12345, root)
+write(
+    "Checkpoint/ssz_one/case_0",
+    "epoch: 12345\nroot: '0x%s'\n" % root.hex(),
+    serialized,
+    hash_root,
+)
+
+source = (7, bytes([0xAA]) * 32)
+target = (8, bytes([0xBB]) * 32)
+block_root = bytes([0x5C]) * 32
+source_bytes, source_root = checkpoint(*source)
+target_bytes, target_root = checkpoint(*target)
+write(
+    "AttestationData/ssz_one/case_0",
+    "slot: 33\nindex: 2\nbeacon_block_root: '0x%s'\n"
+    "source:\n  epoch: 7\n  root: '0x%s'\n"
+    "target:\n  epoch: 8\n  root: '0x%s'\n"
+    % (block_root.hex(), source[1].hex(), target[1].hex()),
+    u64(33) + u64(2) + block_root + source_bytes + target_bytes,
+    merkleize(
+        [chunk(u64(33)), chunk(u64(2)), block_root, source_root, target_root]
+    ),
+)
@@ -3,10 +3,17 @@
 //! These vectors can be independently verified using any SSZ implementation
 //! (remerkleable, Nimbus, Lodestar, etc.).
 
+#[cfg(feature = "blake3")]
+use blake3 as _;
 use digest as _;
+use once_cell as _;
 use rand as _;
+use serde_yaml as _;
 use sha2 as _;
+#[cfg(feature = "keccak")]
+use sha3 as _;
 use smallvec as _;
+use snap as _;
 use ssz::{BitList, BitVector};
 use ssz_derive as _;
 use ssz_primitives as _;
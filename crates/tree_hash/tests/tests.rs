@@ -3,13 +3,21 @@
 
 //! Tree hash tests
 
+#[cfg(feature = "blake3")]
+use blake3 as _;
 use digest as _;
+use once_cell as _;
 use rand as _;
+use serde_yaml as _;
 use sha2 as _;
+#[cfg(feature = "keccak")]
+use sha3::{Digest, Keccak256};
 use smallvec as _;
+use snap as _;
+use ssz::view::{BytesRef, DecodeView, FixedBytesRef};
 use ssz_derive::Encode;
-use ssz_primitives::{U128, U256};
-use ssz_types::{BitVector, Optional, VariableList};
+use ssz_primitives::{FixedBytes, U128, U256};
+use ssz_types::{BitVector, FixedVector, Optional, VariableList};
 use thiserror as _;
 use tree_hash::{
     self, BYTES_PER_CHUNK, Hash256, MerkleHasher, PackedEncoding, Sha256Hasher, TreeHash,
@@ -95,6 +103,44 @@ fn u8_hash(x: u8) -> Hash256 {
     Hash256::from_slice(&a)
 }
 
+#[derive(TreeHash)]
+struct DerivedCheckpoint {
+    epoch: u64,
+    root: Hash256,
+    votes: VariableList<u16, 8>,
+}
+
+struct Header {
+    epoch: u64,
+}
+
+/// Hand-written equivalent of [`DerivedCheckpoint`], whose fields are laid
+/// out differently.
+struct ManualCheckpoint(Header, VariableList<u16, 8>, Hash256);
+
+tree_hash::tree_hash_as_container!(ManualCheckpoint, 0.epoch, 2, 1);
+
+#[test]
+fn tree_hash_as_container_matches_derive() {
+    let votes = VariableList::new(vec![4, 5, 6]).unwrap();
+    let root = Hash256::from([7; 32]);
+    let derived = DerivedCheckpoint {
+        epoch: 12,
+        root,
+        votes: votes.clone(),
+    };
+    let manual = ManualCheckpoint(Header { epoch: 12 }, votes, root);
+
+    assert_eq!(
+        ManualCheckpoint::tree_hash_type(),
+        DerivedCheckpoint::tree_hash_type()
+    );
+    assert_eq!(
+        manual.tree_hash_root::<Sha256Hasher>(),
+        derived.tree_hash_root::<Sha256Hasher>()
+    );
+}
+
 #[derive(TreeHash)]
 #[tree_hash(enum_behaviour = "transparent")]
 enum FixedTrans {
@@ -561,3 +607,291 @@ fn union_empty_variant_tree_hash_vector() {
         "Data variant hash should differ from empty variant"
     );
 }
+
+#[derive(Clone, TreeHash)]
+struct CachedAlpha {
+    a: u8,
+    b: VariableList<u16, 8>,
+    c: Hash256,
+}
+
+#[test]
+fn cached_tree_hash_matches_fresh_root() {
+    let alpha = CachedAlpha {
+        a: 1,
+        b: VariableList::new(vec![1, 2, 3]).unwrap(),
+        c: Hash256::from_slice(&[0x42; 32]),
+    };
+    let mut cached = tree_hash::CachedTreeHash::<_, Sha256Hasher>::new(alpha.clone());
+    assert_eq!(cached.root(), alpha.tree_hash_root::<Sha256Hasher>());
+
+    cached.value_mut().b.push(4).unwrap();
+    cached.update_field(1);
+    assert_eq!(
+        cached.root(),
+        cached.value().tree_hash_root::<Sha256Hasher>()
+    );
+    assert_ne!(cached.root(), alpha.tree_hash_root::<Sha256Hasher>());
+}
+
+#[test]
+#[should_panic(expected = "leaf index 3 out of range for 3 leaves")]
+fn cached_tree_hash_rejects_out_of_range_leaf() {
+    let alpha = CachedAlpha {
+        a: 1,
+        b: VariableList::empty(),
+        c: Hash256::ZERO,
+    };
+    tree_hash::CachedTreeHash::<_, Sha256Hasher>::new(alpha).update_field(3);
+}
+
+/// Hashes [`CachedAlpha`] with pooled hashers, nesting a pooled hasher for the list field.
+fn pooled_alpha_root(alpha: &CachedAlpha) -> Hash256 {
+    tree_hash::with_pooled_hasher::<Sha256Hasher, _>(3, |hasher| {
+        hasher.write(alpha.a.tree_hash_root::<Sha256Hasher>().as_ref())?;
+        let chunks = alpha.b.len().div_ceil(16);
+        let list_root = tree_hash::with_pooled_hasher::<Sha256Hasher, _>(chunks.max(1), |list| {
+            alpha
+                .b
+                .iter()
+                .try_for_each(|item| list.write(&item.to_le_bytes()))
+        })?;
+        let list_root = tree_hash::mix_in_length_with_hasher::<Sha256Hasher>(
+            &tree_hash::merkle_root_with_hasher::<Sha256Hasher>(list_root.as_ref(), 1),
+            alpha.b.len(),
+        );
+        hasher.write(list_root.as_ref())?;
+        hasher.write(alpha.c.as_ref())
+    })
+    .unwrap()
+}
+
+#[test]
+fn pooled_hasher_matches_fresh_hasher() {
+    let alpha = CachedAlpha {
+        a: 1,
+        b: VariableList::new(vec![1, 2, 3]).unwrap(),
+        c: Hash256::from_slice(&[0x42; 32]),
+    };
+    // The second round reuses the hashers the first round put back.
+    for _ in 0..2 {
+        assert_eq!(
+            pooled_alpha_root(&alpha),
+            alpha.tree_hash_root::<Sha256Hasher>()
+        );
+    }
+
+    // Enough leaves to spill the hasher's inline storage.
+    let leaves: Vec<[u8; 32]> = (0..300u16).map(|i| [i as u8; 32]).collect();
+    let mut fresh = MerkleHasher::<Sha256Hasher>::with_leaves(leaves.len());
+    for leaf in &leaves {
+        fresh.write(leaf).unwrap();
+    }
+    let fresh = fresh.finish().unwrap();
+    for _ in 0..2 {
+        let pooled = tree_hash::with_pooled_hasher::<Sha256Hasher, _>(leaves.len(), |hasher| {
+            leaves.iter().try_for_each(|leaf| hasher.write(leaf))
+        });
+        assert_eq!(pooled, Ok(fresh));
+    }
+}
+
+#[test]
+fn pooled_hasher_is_reset_after_error() {
+    // A failing write leaves a leaf and a partial leaf behind in the hasher.
+    let failed = tree_hash::with_pooled_hasher::<Sha256Hasher, _>(2, |hasher| {
+        hasher.write(&[1; 48])?;
+        Err(tree_hash::Error::MaximumLeavesExceeded { max_leaves: 0 })
+    });
+    assert!(failed.is_err());
+
+    let root = tree_hash::with_pooled_hasher::<Sha256Hasher, _>(2, |hasher| hasher.write(&[3; 32]));
+    assert_eq!(root, Ok(Sha256Hasher::hash32_concat(&[3; 32], &[0; 32])));
+}
+
+#[derive(TreeHash)]
+struct MapEntry {
+    key: u64,
+    value: VariableList<u8, 4>,
+}
+
+#[test]
+fn btree_map_root_matches_list_of_entry_containers() {
+    let map = std::collections::BTreeMap::from([
+        (3u64, VariableList::<u8, 4>::new(vec![1, 2]).unwrap()),
+        (1, VariableList::empty()),
+        (7, VariableList::new(vec![9]).unwrap()),
+    ]);
+    let entries: Vec<MapEntry> = map
+        .iter()
+        .map(|(key, value)| MapEntry {
+            key: *key,
+            value: value.clone(),
+        })
+        .collect();
+    let list = VariableList::<MapEntry, 16>::new(entries).unwrap();
+
+    assert_eq!(
+        tree_hash::btree_map_root_with_hasher::<Sha256Hasher, _, _>(&map, 16),
+        list.tree_hash_root::<Sha256Hasher>()
+    );
+
+    let empty = std::collections::BTreeMap::<u64, VariableList<u8, 4>>::new();
+    assert_eq!(
+        tree_hash::btree_map_root_with_hasher::<Sha256Hasher, _, _>(&empty, 16),
+        VariableList::<MapEntry, 16>::empty().tree_hash_root::<Sha256Hasher>()
+    );
+}
+
+#[test]
+#[should_panic(expected = "map has 2 entries, more than the limit of 1")]
+fn btree_map_root_rejects_too_many_entries() {
+    let map = std::collections::BTreeMap::from([(0u64, 0u64), (1, 1)]);
+    tree_hash::btree_map_root_with_hasher::<Sha256Hasher, _, _>(&map, 1);
+}
+
+/// `Vector[byte, 48]` holding bytes 0..48 packs into two chunks, the second
+/// padded with 16 zero bytes, and its root is `hash(chunk0 || chunk1)` with no
+/// length mixin. The same bytes as `List[byte, 48]` mix in the length 48.
+#[test]
+fn byte_vector_root_has_no_length_mixin() {
+    let bytes: Vec<u8> = (0..48).collect();
+    let vector_root: Hash256 = "0xb976c9abe97b4f03d7e4058246713687379d2718a829ab66e2a93aa924e43c1d"
+        .parse()
+        .unwrap();
+    let list_root: Hash256 = "0x73888604777cd230240ec30272e95816bb0391869a2b3b12c49161f20c89c173"
+        .parse()
+        .unwrap();
+
+    let fixed_bytes = FixedBytes::<48>::from_slice(&bytes);
+    assert_eq!(fixed_bytes.tree_hash_root::<Sha256Hasher>(), vector_root);
+    let fixed_vector = FixedVector::<u8, 48>::new(bytes.clone()).unwrap();
+    assert_eq!(fixed_vector.tree_hash_root::<Sha256Hasher>(), vector_root);
+    let fixed_bytes_view = FixedBytesRef::<48>::from_ssz_bytes(&bytes).unwrap();
+    assert_eq!(
+        fixed_bytes_view.tree_hash_root::<Sha256Hasher>(),
+        vector_root
+    );
+
+    let list = VariableList::<u8, 48>::new(bytes.clone()).unwrap();
+    assert_eq!(list.tree_hash_root::<Sha256Hasher>(), list_root);
+    let list_view = BytesRef::<48>::from_ssz_bytes(&bytes).unwrap();
+    assert_eq!(list_view.tree_hash_root::<Sha256Hasher>(), list_root);
+    assert_eq!(
+        list_root,
+        tree_hash::mix_in_length_with_hasher::<Sha256Hasher>(&vector_root, 48)
+    );
+}
+
+#[cfg(feature = "keccak")]
+#[derive(TreeHash)]
+struct KeccakPair {
+    a: u64,
+    b: u64,
+}
+
+#[cfg(feature = "keccak")]
+#[test]
+fn keccak256_container_root_matches_direct_keccak() {
+    use tree_hash::Keccak256Hasher;
+
+    // Each field is one leaf, right-padded to a chunk.
+    let mut preimage = [0u8; 64];
+    preimage[..8].copy_from_slice(&1u64.to_le_bytes());
+    preimage[32..40].copy_from_slice(&2u64.to_le_bytes());
+    let expected = Keccak256::digest(preimage);
+
+    let root = KeccakPair { a: 1, b: 2 }.tree_hash_root::<Keccak256Hasher>();
+    assert_eq!(root.as_ref(), expected.as_slice());
+}
+
+#[cfg(feature = "keccak")]
+#[test]
+fn keccak256_single_leaf_list_root_matches_direct_keccak() {
+    use tree_hash::Keccak256Hasher;
+
+    // A one-chunk list root is the padded leaf mixed with the length.
+    let mut preimage = [0u8; 64];
+    preimage[..8].copy_from_slice(&42u64.to_le_bytes());
+    preimage[32] = 1;
+    let expected = Keccak256::digest(preimage);
+
+    let list = VariableList::<u64, 4>::new(vec![42]).unwrap();
+    let root = list.tree_hash_root::<Keccak256Hasher>();
+    assert_eq!(root.as_ref(), expected.as_slice());
+}
+
+/// A toy digest whose "hash" of `left || right` is their bytewise sum plus
+/// one, to check `static_zero_hashes!` against a hasher the crate doesn't know.
+#[derive(Debug)]
+struct SumHasher;
+
+impl TreeHashDigest for SumHasher {
+    type Output = Hash256;
+    const HASH_SIZE: usize = 32;
+    const HASH_LEN: usize = 32;
+
+    fn zero_hashes() -> &'static [Self::Output] {
+        tree_hash::static_zero_hashes!(SumHasher)
+    }
+
+    fn hash(data: &[u8]) -> Self::Output {
+        Self::hash_fixed(data)
+    }
+
+    fn hash_fixed(data: &[u8]) -> Self::Output {
+        let mut out = [1u8; 32];
+        for (i, byte) in data.iter().enumerate() {
+            out[i % 32] = out[i % 32].wrapping_add(*byte);
+        }
+        Hash256::from(out)
+    }
+
+    fn hash32_concat(left: &[u8], right: &[u8]) -> Self::Output {
+        Self::hash_fixed(&[left, right].concat())
+    }
+
+    fn get_zero_hash(depth: usize) -> Self::Output {
+        Self::zero_hashes()[depth]
+    }
+
+    fn get_zero_hash_slice(depth: usize) -> &'static [u8] {
+        Self::zero_hashes()[depth].as_ref()
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Self::Output {
+        let mut padded = [0u8; 32];
+        let len = bytes.len().min(32);
+        padded[..len].copy_from_slice(&bytes[..len]);
+        Hash256::from(padded)
+    }
+
+    fn new_context() -> Self {
+        Self
+    }
+
+    fn update(&mut self, _data: &[u8]) {
+        unimplemented!("not used by the test")
+    }
+
+    fn finalize(self) -> Self::Output {
+        unimplemented!("not used by the test")
+    }
+}
+
+#[test]
+fn static_zero_hashes_custom_digest() {
+    let fresh = tree_hash::get_zero_hashes::<SumHasher>(SumHasher::HASH_LEN);
+    let cached = SumHasher::zero_hashes();
+    assert_eq!(cached, fresh.as_slice());
+    assert_eq!(cached[1], Hash256::from([1u8; 32]));
+    assert_eq!(cached[2], Hash256::from([3u8; 32]));
+
+    // Every thread sees the same table.
+    let tables = (0..4)
+        .map(|_| std::thread::spawn(|| SumHasher::zero_hashes().as_ptr() as usize))
+        .collect::<Vec<_>>();
+    for table in tables {
+        assert_eq!(table.join().unwrap(), cached.as_ptr() as usize);
+    }
+}
@@ -222,6 +222,7 @@ fn tree_hash_derive_struct_container(item: &DeriveInput, struct_data: &DataStruc
 
     let idents = get_hashable_fields(struct_data);
     let num_leaves = idents.len();
+    let leaf_indices = 0..num_leaves;
 
     let output = quote! {
         impl #impl_generics tree_hash::TreeHash for #name #ty_generics #where_clause {
@@ -249,6 +250,21 @@ fn tree_hash_derive_struct_container(item: &DeriveInput, struct_data: &DataStruc
                 result
             }
         }
+
+        impl #impl_generics tree_hash::TreeHashLeaves for #name #ty_generics #where_clause {
+            fn tree_hash_leaf_count() -> usize {
+                #num_leaves
+            }
+
+            fn tree_hash_leaf_root<__H: tree_hash::TreeHashDigest>(&self, index: usize) -> __H::Output {
+                match index {
+                    #(
+                        #leaf_indices => <_ as tree_hash::TreeHash>::tree_hash_root::<__H>(&self.#idents),
+                    )*
+                    _ => panic!("leaf index {} out of range for {} leaves", index, #num_leaves),
+                }
+            }
+        }
     };
     output.into()
 }